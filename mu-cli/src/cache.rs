@@ -10,7 +10,7 @@
 //!
 //! ```json
 //! {
-//!   "version": "1",
+//!   "version": "2",
 //!   "entries": {
 //!     "src/main.py": {
 //!       "hash": "xxh3:abc123...",
@@ -28,7 +28,7 @@ use std::path::Path;
 use mu_core::types::ModuleDef;
 
 /// Current cache format version. Increment when cache format changes.
-const CACHE_VERSION: &str = "1";
+const CACHE_VERSION: &str = "2";
 
 /// Default cache file path relative to project root.
 const DEFAULT_CACHE_PATH: &str = ".mu/cache/parse_cache.json";
@@ -114,8 +114,15 @@ impl ParseCache {
             fs::create_dir_all(parent)?;
         }
 
+        // Write to a temp file and rename, so an interrupted save never
+        // leaves a truncated cache behind
         let content = serde_json::to_string_pretty(self)?;
-        fs::write(&cache_path, content)?;
+        let tmp_path = cache_path.with_extension("json.tmp");
+        if let Err(e) = fs::write(&tmp_path, content) {
+            let _ = fs::remove_file(&tmp_path);
+            return Err(e.into());
+        }
+        fs::rename(&tmp_path, &cache_path)?;
 
         tracing::debug!(
             "Saved parse cache with {} entries to {:?}",
@@ -148,6 +155,14 @@ impl ParseCache {
         })
     }
 
+    /// The cache entry for a file, whatever its hash.
+    ///
+    /// Only for callers that already know the file is unchanged, such as
+    /// `mu bootstrap --since`.
+    pub fn get_unchecked(&self, path: &str) -> Option<&CacheEntry> {
+        self.entries.get(path)
+    }
+
     /// Insert or update a cache entry.
     pub fn insert(&mut self, path: String, hash: String, module: ModuleDef) {
         self.entries.insert(path, CacheEntry { hash, module });
@@ -233,6 +248,7 @@ mod tests {
 
         // Non-matching hash should return None
         assert!(cache.get("test.py", "xxh3:different").is_none());
+        assert!(cache.get_unchecked("test.py").is_some());
 
         // Non-existent file should return None
         assert!(cache.get("other.py", "xxh3:abc123").is_none());
@@ -0,0 +1,133 @@
+//! Backup and restore commands - Save the MUbase and recover it later
+//!
+//! `mu backup <dir>` exports the whole database (graph, embeddings, file
+//! hashes, metadata and indexes) with DuckDB's `EXPORT DATABASE`.
+//! `mu restore <dir>` replaces the current database with such a backup,
+//! so a corrupted `.mu/mubase` can be recovered without re-bootstrapping.
+
+use std::io::{self, Write};
+use std::path::Path;
+
+use colored::Colorize;
+use mu_daemon::storage::MUbase;
+use serde::Serialize;
+
+use crate::output::{Output, OutputFormat, TableDisplay};
+
+/// Outcome of a backup or restore.
+#[derive(Debug, Serialize)]
+pub struct BackupReport {
+    /// "backup" or "restore"
+    pub action: &'static str,
+    pub database: String,
+    pub backup: String,
+    pub node_count: usize,
+    pub edge_count: usize,
+}
+
+impl TableDisplay for BackupReport {
+    fn to_table(&self) -> String {
+        let (verb, from, to) = match self.action {
+            "backup" => ("Backed up:", &self.database, &self.backup),
+            _ => ("Restored:", &self.backup, &self.database),
+        };
+        format!(
+            "{} {} -> {}\n  {} nodes, {} edges\n",
+            verb.green().bold(),
+            from,
+            to,
+            self.node_count.to_string().cyan(),
+            self.edge_count.to_string().cyan()
+        )
+    }
+
+    fn to_mu(&self) -> String {
+        format!(
+            ":: {}\n# database: {}\n# backup: {}\n# nodes: {} edges: {}",
+            self.action, self.database, self.backup, self.node_count, self.edge_count
+        )
+    }
+}
+
+/// Run the backup command
+pub async fn run_backup(path: &str, dest: &str, format: OutputFormat) -> anyhow::Result<()> {
+    let db_path = crate::mubase::find_mubase(path)?;
+    let mubase = MUbase::open_read_only(&db_path)?;
+
+    let dest = Path::new(dest);
+    mubase.backup(dest)?;
+    let stats = mubase.stats()?;
+
+    let report = BackupReport {
+        action: "backup",
+        database: db_path.display().to_string(),
+        backup: dest.display().to_string(),
+        node_count: stats.node_count,
+        edge_count: stats.edge_count,
+    };
+    Output::new(report, format).render()
+}
+
+/// Run the restore command
+///
+/// Restores into the project's database, creating it if it is missing.
+pub async fn run_restore(
+    path: &str,
+    src: &str,
+    yes: bool,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    let root = Path::new(path)
+        .canonicalize()
+        .unwrap_or_else(|_| Path::new(path).to_path_buf());
+    let src = Path::new(src);
+    if !src.is_dir() {
+        anyhow::bail!("Backup directory not found: {}", src.display());
+    }
+
+    let db_path =
+        crate::mubase::find_mubase_in(&root).unwrap_or_else(|| crate::mubase::mubase_path(&root));
+
+    if !yes && format != OutputFormat::Json && db_path.exists() {
+        println!();
+        println!("{}", "Restore".red().bold());
+        println!();
+        println!(
+            "{}",
+            format!(
+                "WARNING: This will replace everything in {} with the backup!",
+                db_path.display()
+            )
+            .yellow()
+        );
+        println!();
+
+        print!("{} Continue? [y/N] ", "?".cyan().bold());
+        io::stdout().flush()?;
+
+        let mut input = String::new();
+        io::stdin().read_line(&mut input)?;
+        let input = input.trim().to_lowercase();
+
+        if input != "y" && input != "yes" {
+            println!("{}", "Cancelled.".dimmed());
+            return Ok(());
+        }
+    }
+
+    if let Some(parent) = db_path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mubase = MUbase::open(&db_path)?;
+    mubase.restore(src)?;
+    let stats = mubase.stats()?;
+
+    let report = BackupReport {
+        action: "restore",
+        database: db_path.display().to_string(),
+        backup: src.display().to_string(),
+        node_count: stats.node_count,
+        edge_count: stats.edge_count,
+    };
+    Output::new(report, format).render()
+}
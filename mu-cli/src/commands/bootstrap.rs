@@ -10,6 +10,8 @@ use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::io::{self, IsTerminal};
 use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
 use std::time::Instant;
 
 use colored::Colorize;
@@ -18,11 +20,16 @@ use indicatif::{ProgressBar, ProgressStyle};
 use serde::Serialize;
 use serde_json::json;
 
+use mu_daemon::storage::EmbeddingSpace;
+
 use crate::cache::{CacheStats, ParseCache};
 use crate::config::MuConfig;
 use crate::output::{Output, OutputFormat, TableDisplay};
 use crate::tsconfig::{PathAliasResolver, WorkspaceResolver};
 
+/// Number of files parsed per batch between cancellation checks.
+const PARSE_CHUNK_SIZE: usize = 256;
+
 /// Result of bootstrap operation
 #[derive(Debug, Serialize)]
 pub struct BootstrapResult {
@@ -32,6 +39,12 @@ pub struct BootstrapResult {
     pub files_scanned: usize,
     pub files_parsed: usize,
     pub files_cached: usize,
+    /// Identical-content files that were aliased instead of parsed.
+    pub duplicates: Vec<mu_core::scanner::DuplicateFile>,
+    /// Binary or minified files left out of the graph.
+    pub generated: Vec<mu_core::scanner::SkippedFile>,
+    /// Files that parsed with warnings, such as non-UTF-8 sources.
+    pub warnings: Vec<FileWarning>,
     pub node_count: usize,
     pub edge_count: usize,
     pub nodes_by_type: HashMap<String, usize>,
@@ -41,6 +54,13 @@ pub struct BootstrapResult {
     pub embeddings_generated: usize,
 }
 
+/// A problem that didn't keep a file out of the graph.
+#[derive(Debug, Serialize)]
+pub struct FileWarning {
+    pub path: String,
+    pub message: String,
+}
+
 impl TableDisplay for BootstrapResult {
     fn to_table(&self) -> String {
         let mut output = String::new();
@@ -89,6 +109,42 @@ impl TableDisplay for BootstrapResult {
                 self.files_cached.to_string().green()
             ));
         }
+        if !self.duplicates.is_empty() {
+            output.push_str(&format!(
+                "  Duplicates: {} (identical content, aliased)\n",
+                self.duplicates.len().to_string().yellow()
+            ));
+            for dup in &self.duplicates {
+                output.push_str(&format!(
+                    "    {} {} {}\n",
+                    dup.path,
+                    "->".dimmed(),
+                    dup.duplicate_of
+                ));
+            }
+        }
+        if !self.generated.is_empty() {
+            output.push_str(&format!(
+                "  Generated: {} (binary or minified, skipped)\n",
+                self.generated.len().to_string().yellow()
+            ));
+            for file in &self.generated {
+                output.push_str(&format!("    {} {}\n", file.path, file.reason.dimmed()));
+            }
+        }
+        if !self.warnings.is_empty() {
+            output.push_str(&format!(
+                "  Warnings: {}\n",
+                self.warnings.len().to_string().yellow()
+            ));
+            for warning in &self.warnings {
+                output.push_str(&format!(
+                    "    {} {}\n",
+                    warning.path,
+                    warning.message.dimmed()
+                ));
+            }
+        }
 
         if self.config_created || self.gitignore_updated {
             output.push_str(&format!("\n{}\n", "Setup".cyan().bold()));
@@ -133,6 +189,142 @@ impl TableDisplay for BootstrapResult {
     }
 }
 
+/// Result of reconciling the graph with files deleted from disk
+#[derive(Debug, Serialize)]
+pub struct SyncResult {
+    pub mubase_path: String,
+    pub removed_files: Vec<String>,
+    pub nodes_removed: usize,
+    pub hashes_removed: usize,
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub duration_ms: u64,
+}
+
+impl TableDisplay for SyncResult {
+    fn to_table(&self) -> String {
+        let mut output = String::new();
+
+        if self.removed_files.is_empty() {
+            output.push_str(&format!(
+                "{} No deleted files to sync\n",
+                "OK:".green().bold()
+            ));
+        } else {
+            output.push_str(&format!(
+                "{} Removed {} deleted file(s) ({} nodes)\n",
+                "SUCCESS:".green().bold(),
+                self.removed_files.len().to_string().yellow(),
+                self.nodes_removed
+            ));
+            for file in &self.removed_files {
+                output.push_str(&format!("  - {}\n", file));
+            }
+        }
+
+        output.push_str(&format!(
+            "  Graph: {} nodes, {} edges ({}ms)\n",
+            self.node_count, self.edge_count, self.duration_ms
+        ));
+        output
+    }
+
+    fn to_mu(&self) -> String {
+        let mut lines = vec![
+            ":: bootstrap --sync".to_string(),
+            format!("# mubase: {}", self.mubase_path),
+            format!(
+                "# removed: {} files, {} nodes",
+                self.removed_files.len(),
+                self.nodes_removed
+            ),
+        ];
+        lines.extend(self.removed_files.iter().map(|f| format!("- {}", f)));
+        lines.join("\n")
+    }
+}
+
+/// Tracks Ctrl-C during bootstrap so an interrupted build can stop at the
+/// next safe point instead of leaving a half-written database behind.
+struct CancelFlag {
+    cancelled: Arc<AtomicBool>,
+    /// Once all database writes are committed there is nothing to roll back,
+    /// so a Ctrl-C simply exits.
+    committed: Arc<AtomicBool>,
+}
+
+impl CancelFlag {
+    /// Install a SIGINT handler that records the request instead of killing
+    /// the process. A second Ctrl-C forces an immediate exit.
+    fn install() -> Self {
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let committed = Arc::new(AtomicBool::new(false));
+
+        let cancelled_flag = Arc::clone(&cancelled);
+        let committed_flag = Arc::clone(&committed);
+        tokio::spawn(async move {
+            while tokio::signal::ctrl_c().await.is_ok() {
+                if committed_flag.load(Ordering::SeqCst)
+                    || cancelled_flag.swap(true, Ordering::SeqCst)
+                {
+                    std::process::exit(130);
+                }
+                eprintln!(
+                    "\n{} Cancelling bootstrap (press Ctrl-C again to force quit)...",
+                    "INFO:".yellow().bold()
+                );
+            }
+        });
+
+        Self {
+            cancelled,
+            committed,
+        }
+    }
+
+    /// Whether the user asked to cancel.
+    fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Return an error if the user asked to cancel.
+    fn check(&self) -> anyhow::Result<()> {
+        if self.is_cancelled() {
+            anyhow::bail!("Bootstrap cancelled. The existing database was left unchanged.");
+        }
+        Ok(())
+    }
+
+    /// Like [`CancelFlag::check`], for the embedding phase that runs after
+    /// the graph has already been committed.
+    fn check_embeddings(&self) -> anyhow::Result<()> {
+        if self.is_cancelled() {
+            anyhow::bail!(
+                "Bootstrap cancelled. The graph was updated, but existing embeddings were left unchanged."
+            );
+        }
+        Ok(())
+    }
+
+    /// Mark all database writes as committed.
+    fn mark_committed(&self) {
+        self.committed.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Remove a mubase created by an unsuccessful bootstrap, along with its WAL.
+fn remove_mubase_files(mubase_path: &Path) {
+    let mut wal_path = mubase_path.as_os_str().to_owned();
+    wal_path.push(".wal");
+    for file in [mubase_path.to_path_buf(), wal_path.into()] {
+        if file.exists() {
+            if let Err(e) = fs::remove_file(&file) {
+                tracing::warn!("Failed to remove {}: {}", file.display(), e);
+            }
+        }
+    }
+}
+
 /// Default .murc.toml content
 fn get_default_config() -> &'static str {
     r#"# MU Configuration
@@ -248,6 +440,36 @@ fn should_embed(embed_flag: bool, no_embed_flag: bool) -> bool {
     }
 }
 
+/// Embed node docstrings for the separate docs index.
+///
+/// Nodes without a docstring are left out, so `mu search --over docs` only
+/// matches documented code.
+fn embed_docstrings(
+    model: &mu_embeddings::MuSigmaModel,
+    nodes: &[&mu_daemon::storage::Node],
+) -> Vec<(String, Vec<f32>, Option<String>)> {
+    let documented: Vec<(&str, &str)> = nodes
+        .iter()
+        .filter_map(|n| n.docstring().map(|doc| (n.id.as_str(), doc)))
+        .collect();
+
+    let mut embeddings_batch = Vec::new();
+    for batch in documented.chunks(model.config().batch_size) {
+        let texts: Vec<&str> = batch.iter().map(|(_, doc)| *doc).collect();
+        match model.embed(&texts) {
+            Ok(batch_embeddings) => {
+                for ((id, doc), embedding) in batch.iter().zip(batch_embeddings) {
+                    embeddings_batch.push((id.to_string(), embedding, Some(doc.to_string())));
+                }
+            }
+            Err(e) => {
+                tracing::warn!("Failed to embed docstring batch: {}", e);
+            }
+        }
+    }
+    embeddings_batch
+}
+
 /// Run embeddings only on an existing database (without rebuilding the graph)
 async fn run_embeddings_only(mubase_path: &Path, format: OutputFormat) -> anyhow::Result<()> {
     let start = Instant::now();
@@ -267,7 +489,7 @@ async fn run_embeddings_only(mubase_path: &Path, format: OutputFormat) -> anyhow
 
     spinner.set_message("Loading embedding model...");
 
-    let embeddings_generated = match mu_embeddings::MuSigmaModel::embedded() {
+    let embeddings_generated = match crate::commands::embed::load_index_model() {
         Ok(model) => {
             spinner.set_message("Loading nodes...");
 
@@ -285,7 +507,7 @@ async fn run_embeddings_only(mubase_path: &Path, format: OutputFormat) -> anyhow
             let mut embedded_count = 0;
 
             // Process in batches for better progress feedback
-            let batch_size = 32;
+            let batch_size = model.config().batch_size;
             for (batch_idx, batch) in nodes_to_embed.chunks(batch_size).enumerate() {
                 spinner.set_message(format!(
                     "Generating embeddings... {}/{}",
@@ -300,6 +522,8 @@ async fn run_embeddings_only(mubase_path: &Path, format: OutputFormat) -> anyhow
                         let type_prefix = match n.node_type {
                             mu_daemon::storage::NodeType::Module => "module",
                             mu_daemon::storage::NodeType::Class => "class",
+                            mu_daemon::storage::NodeType::Interface => "interface",
+                            mu_daemon::storage::NodeType::Enum => "enum",
                             mu_daemon::storage::NodeType::Function => "function",
                             mu_daemon::storage::NodeType::External => "external",
                         };
@@ -332,14 +556,24 @@ async fn run_embeddings_only(mubase_path: &Path, format: OutputFormat) -> anyhow
             // Clear existing embeddings and insert new ones
             if !embeddings_batch.is_empty() {
                 spinner.set_message("Storing embeddings...");
-                mubase.clear_embeddings()?;
                 if let Err(e) =
-                    mubase.insert_embeddings_batch(&embeddings_batch, Some("mu-sigma-v2"))
+                    mubase.replace_embeddings(&embeddings_batch, Some("mu-sigma-v2"), || Ok(()))
                 {
                     tracing::warn!("Failed to store embeddings: {}", e);
                 }
             }
 
+            spinner.set_message("Generating docstring embeddings...");
+            let doc_embeddings = embed_docstrings(&model, &nodes_to_embed);
+            if let Err(e) = mubase.replace_embeddings_in(
+                EmbeddingSpace::Docs,
+                &doc_embeddings,
+                Some("mu-sigma-v2"),
+                || Ok(()),
+            ) {
+                tracing::warn!("Failed to store docstring embeddings: {}", e);
+            }
+
             embedded_count
         }
         Err(e) => {
@@ -364,6 +598,9 @@ async fn run_embeddings_only(mubase_path: &Path, format: OutputFormat) -> anyhow
         files_scanned: 0,
         files_parsed: 0,
         files_cached: 0,
+        duplicates: Vec::new(),
+        generated: Vec::new(),
+        warnings: Vec::new(),
         node_count: stats.node_count,
         edge_count: stats.edge_count,
         nodes_by_type: stats.type_counts,
@@ -391,13 +628,80 @@ async fn run_embeddings_only(mubase_path: &Path, format: OutputFormat) -> anyhow
     Ok(())
 }
 
+/// Files under `root` that differ from `git_ref`: modified, added, deleted
+/// or untracked, as paths relative to `root`.
+///
+/// Fails when `root` is not in a git work tree or the ref does not resolve.
+fn git_changed_files(root: &Path, git_ref: &str) -> anyhow::Result<HashSet<String>> {
+    let git = |args: &[&str]| -> anyhow::Result<String> {
+        let output = std::process::Command::new("git")
+            .arg("-C")
+            .arg(root)
+            .args(["-c", "core.quotePath=false"])
+            .args(args)
+            .output()?;
+        if !output.status.success() {
+            anyhow::bail!("{}", String::from_utf8_lossy(&output.stderr).trim());
+        }
+        Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+    };
+
+    let changed = git(&["diff", "--name-only", "--relative", git_ref, "--"])?;
+    let untracked = git(&["ls-files", "--others", "--exclude-standard"])?;
+    Ok(changed
+        .lines()
+        .chain(untracked.lines())
+        .filter(|line| !line.is_empty())
+        .map(str::to_string)
+        .collect())
+}
+
+/// Remove nodes, edges, embeddings and file hashes for files that are no
+/// longer on disk, without rebuilding the rest of the graph.
+pub fn sync_deleted_files(root: &Path, mubase_path: &Path) -> anyhow::Result<SyncResult> {
+    let start = Instant::now();
+    let mubase = mu_daemon::storage::MUbase::open(mubase_path)?;
+
+    let mut removed_files = Vec::new();
+    let mut nodes_removed = 0;
+
+    for file_path in mubase.indexed_files()? {
+        if !root.join(&file_path).exists() {
+            nodes_removed += mubase.delete_nodes_for_file(&file_path)?;
+            removed_files.push(file_path);
+        }
+    }
+
+    let present: HashSet<String> = mubase
+        .get_all_file_hashes()?
+        .into_keys()
+        .filter(|file_path| root.join(file_path).exists())
+        .collect();
+    let hashes_removed = mubase.cleanup_stale_file_hashes(&present)?;
+    let stats = mubase.stats()?;
+
+    Ok(SyncResult {
+        mubase_path: mubase_path.to_string_lossy().to_string(),
+        removed_files,
+        nodes_removed,
+        hashes_removed,
+        node_count: stats.node_count,
+        edge_count: stats.edge_count,
+        duration_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
 /// Run the bootstrap command
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     path: &str,
     force: bool,
     embed: bool,
     no_embed: bool,
     strict: bool,
+    sync: bool,
+    since: Option<&str>,
+    live_embed: bool,
     format: OutputFormat,
 ) -> anyhow::Result<()> {
     let start = Instant::now();
@@ -428,12 +732,36 @@ pub async fn run(
     let ignore_patterns = config.ignore_patterns();
     tracing::debug!("Loaded ignore patterns: {:?}", ignore_patterns);
 
-    // Determine mubase path
-    let mu_dir = root.join(".mu");
-    let mubase_path = mu_dir.join("mubase");
+    // Determine mubase path (honors MU_MUBASE and [storage].path)
+    let mubase_path = crate::mubase::mubase_path(&root);
+
+    // With --since, files git reports unchanged reuse their cached parse
+    // without being read or hashed. A fresh database needs a full build.
+    let changed_since = match since {
+        Some(git_ref) if mubase_path.exists() => match git_changed_files(&root, git_ref) {
+            Ok(changed) => Some(changed),
+            Err(e) => {
+                eprintln!(
+                    "{} --since {} unavailable ({}), doing a full build",
+                    "WARNING:".yellow().bold(),
+                    git_ref,
+                    e
+                );
+                None
+            }
+        },
+        _ => None,
+    };
+    let rebuild = force || since.is_some();
 
     // Check if rebuild is needed
-    if mubase_path.exists() && !force {
+    if mubase_path.exists() && !rebuild {
+        // If --sync is passed, only drop what was deleted from disk
+        if sync {
+            let result = sync_deleted_files(&root, &mubase_path)?;
+            return Output::new(result, format).render();
+        }
+
         // If --embed is passed, run embedding on existing database without rebuild
         if embed {
             return run_embeddings_only(&mubase_path, format).await;
@@ -452,14 +780,17 @@ pub async fn run(
         return Ok(());
     }
 
-    // Create .mu directory if needed
-    if !mu_dir.exists() {
-        fs::create_dir_all(&mu_dir)?;
+    // Create the database directory if needed
+    if let Some(db_dir) = mubase_path.parent() {
+        fs::create_dir_all(db_dir)?;
     }
 
     // Determine whether to generate embeddings (prompt if interactive)
     let do_embed = should_embed(embed, no_embed);
 
+    // From here on, Ctrl-C requests a clean cancellation
+    let cancel = CancelFlag::install();
+
     // Show progress
     let spinner = ProgressBar::new_spinner();
     spinner.set_style(
@@ -481,7 +812,11 @@ pub async fn run(
     let mut scan_options = mu_core::scanner::ScanOptions::new()
         .with_ignore_patterns(ignore_patterns)
         .include_hidden(config.scanner.include_hidden)
-        .compute_hashes(cache_enabled); // Enable hash computation for caching
+        // Enable hash computation for caching; --since hashes only what changed
+        .compute_hashes(cache_enabled && changed_since.is_none())
+        .dedupe_identical(config.scanner.dedupe_identical)
+        .respect_gitignore(config.scanner.respect_gitignore)
+        .skip_generated(config.scanner.skip_generated);
 
     // Apply max file size if configured
     if let Some(max_size) = config.max_file_size_bytes() {
@@ -496,8 +831,9 @@ pub async fn run(
     let scan_result = mu_core::scanner::scan_with_options(root_str, scan_options)
         .map_err(|e| anyhow::anyhow!(e))?;
 
-    let files_scanned = scan_result.files.len();
+    let files_scanned = scan_result.files.len() + scan_result.duplicates.len();
     spinner.set_message(format!("Found {} files", files_scanned));
+    cancel.check()?;
 
     if files_scanned == 0 {
         spinner.finish_and_clear();
@@ -533,22 +869,48 @@ pub async fn run(
 
     // Separate files into cached (with matching hash) and needs-parsing
     spinner.set_message("Checking cache...");
+    // Content hash of every indexed file, stored on its module node for `mu stale`
+    let mut file_hashes: HashMap<String, String> = HashMap::new();
     let mut cached_modules: Vec<mu_core::types::ParseResult> = Vec::new();
-    let mut files_to_parse: Vec<(mu_core::scanner::ScannedFile, String)> = Vec::new(); // (file, content)
+    let mut files_to_parse: Vec<(mu_core::scanner::ScannedFile, Vec<u8>)> = Vec::new(); // (file, content)
 
     for scanned_file in &scan_result.files {
+        cancel.check()?;
+
+        if let Some(ref changed) = changed_since {
+            if !changed.contains(&scanned_file.path) {
+                if let Some(entry) = cache.get_unchecked(&scanned_file.path) {
+                    cached_modules.push(mu_core::types::ParseResult::ok(entry.module.clone()));
+                    file_hashes.insert(scanned_file.path.clone(), entry.hash.clone());
+                    cache_stats.hits += 1;
+                    continue;
+                }
+            }
+        }
+
         let full_path = root.join(&scanned_file.path);
-        let content = match fs::read_to_string(&full_path) {
+        let content = match fs::read(&full_path) {
             Ok(c) => c,
             Err(_) => continue, // Skip unreadable files
         };
+        file_hashes.insert(
+            scanned_file.path.clone(),
+            scanned_file
+                .hash
+                .clone()
+                .unwrap_or_else(|| mu_core::scanner::content_hash(&content)),
+        );
 
         // Check cache if enabled and hash is available
         if cache_enabled {
             if let Some(hash) = &scanned_file.hash {
                 if let Some(cached_module) = cache.get(&scanned_file.path, hash) {
-                    // Cache hit - use cached result
-                    cached_modules.push(mu_core::types::ParseResult::ok(cached_module.clone()));
+                    // Cache hit - use cached result, keeping its decoding warning
+                    let mut cached = mu_core::types::ParseResult::ok(cached_module.clone());
+                    cached
+                        .warnings
+                        .extend(mu_core::parser::decode_source(&content).1);
+                    cached_modules.push(cached);
                     cache_stats.hits += 1;
                     continue;
                 }
@@ -567,25 +929,41 @@ pub async fn run(
         cache_stats.hits
     ));
 
-    let file_infos: Vec<mu_core::types::FileInfo> = files_to_parse
-        .iter()
-        .map(|(f, content)| mu_core::types::FileInfo {
-            path: f.path.clone(),
-            source: content.clone(),
-            language: f.language.clone(),
-        })
-        .collect();
+    // Parse in chunks so a Ctrl-C doesn't have to wait for the whole codebase
+    let mut fresh_parse_results = Vec::with_capacity(files_to_parse.len());
+    for chunk in files_to_parse.chunks(PARSE_CHUNK_SIZE) {
+        cancel.check()?;
+        // Decode up front (as `parse_bytes` does) so non-UTF-8 files still parse
+        let mut decode_warnings = Vec::with_capacity(chunk.len());
+        let file_infos: Vec<mu_core::types::FileInfo> = chunk
+            .iter()
+            .map(|(f, content)| {
+                let (source, warning) = mu_core::parser::decode_source(content);
+                decode_warnings.push(warning);
+                mu_core::types::FileInfo {
+                    path: f.path.clone(),
+                    source: source.into_owned(),
+                    language: f.language.clone(),
+                }
+            })
+            .collect();
 
-    let fresh_parse_results = mu_core::parser::parse_files_parallel(file_infos, None);
+        let results = mu_core::parser::parse_files_parallel(file_infos, None);
+        fresh_parse_results.extend(results.into_iter().zip(decode_warnings).map(
+            |(mut result, warning)| {
+                result.warnings.extend(warning);
+                result
+            },
+        ));
+    }
 
     // Update cache with freshly parsed results
     if cache_enabled {
-        for ((scanned_file, _content), result) in
-            files_to_parse.iter().zip(fresh_parse_results.iter())
-        {
+        for ((scanned_file, _), result) in files_to_parse.iter().zip(fresh_parse_results.iter()) {
             if result.success {
-                if let (Some(hash), Some(module)) = (&scanned_file.hash, &result.module) {
-                    cache.insert(scanned_file.path.clone(), hash.clone(), module.clone());
+                let hash = file_hashes[&scanned_file.path].clone();
+                if let Some(module) = &result.module {
+                    cache.insert(scanned_file.path.clone(), hash, module.clone());
                 }
             }
         }
@@ -597,6 +975,18 @@ pub async fn run(
         .chain(fresh_parse_results)
         .collect();
 
+    let mut warnings: Vec<FileWarning> = parse_results
+        .iter()
+        .filter_map(|result| Some((&result.module.as_ref()?.path, &result.warnings)))
+        .flat_map(|(path, messages)| {
+            messages.iter().map(|message| FileWarning {
+                path: path.clone(),
+                message: message.clone(),
+            })
+        })
+        .collect();
+    warnings.sort_by(|a, b| a.path.cmp(&b.path));
+
     let files_parsed = cache_stats.misses;
     let files_cached = cache_stats.hits;
 
@@ -625,6 +1015,8 @@ pub async fn run(
         }
     ));
 
+    cancel.check()?;
+
     // Step 3: Build graph
     spinner.set_message("Building graph...");
 
@@ -649,12 +1041,28 @@ pub async fn run(
         );
     }
 
+    // PHP fully qualified class names for resolving `use` statements
+    let php_class_map = build_php_class_map(&parse_results);
+
+    // C/C++ files for resolving quoted #include directives
+    let c_source_files: HashSet<String> = parse_results
+        .iter()
+        .filter_map(|r| r.module.as_ref())
+        .filter(|m| matches!(m.language.as_str(), "c" | "cpp"))
+        .map(|m| m.path.clone())
+        .collect();
+
     let mut nodes = Vec::new();
     let mut edges = Vec::new();
 
     // Pre-pass: Build class/interface lookup map for inheritance resolution
     // Maps simple name (e.g., "BaseService") to full node ID (e.g., "cls:src/.../BaseService.cs:BaseService")
     let mut class_lookup: HashMap<String, String> = HashMap::new();
+    // Rust types alone, so trait impls never resolve into another language
+    let mut rust_types: HashMap<&str, String> = HashMap::new();
+    // TypeScript/JavaScript interface members by node ID, the prop names of
+    // components that don't destructure their props
+    let mut interface_members: HashMap<String, &[String]> = HashMap::new();
     for result in &parse_results {
         if !result.success {
             continue;
@@ -663,8 +1071,30 @@ pub async fn run(
             let rel_path = &module.path;
             for class in &module.classes {
                 let class_id = format!("cls:{}:{}", rel_path, class.name);
+                // Rust impl blocks for a type declared elsewhere must not
+                // shadow the declaration itself
+                if is_rust_impl_block(module, class) {
+                    rust_types
+                        .entry(&class.name)
+                        .or_insert_with(|| class_id.clone());
+                    class_lookup.entry(class.name.clone()).or_insert(class_id);
+                    continue;
+                }
+                if module.language == "rust" {
+                    rust_types.insert(&class.name, class_id.clone());
+                }
+                if matches!(module.language.as_str(), "typescript" | "javascript") {
+                    interface_members.insert(class_id.clone(), &class.attributes);
+                }
                 // Map by simple name (may have collisions, last one wins)
                 class_lookup.insert(class.name.clone(), class_id.clone());
+                // PHP bases are fully qualified (App\Models\User)
+                if module.language == "php" {
+                    if let Some(ref namespace) = module.namespace {
+                        let qualified = format!("{}\\{}", namespace, class.name);
+                        class_lookup.insert(qualified, class_id.clone());
+                    }
+                }
                 // Also map interface names (IFoo -> cls:...:IFoo)
                 // This helps resolve interface implementations
             }
@@ -673,6 +1103,7 @@ pub async fn run(
     tracing::debug!("Built class lookup with {} entries", class_lookup.len());
 
     for result in &parse_results {
+        cancel.check()?;
         if !result.success {
             continue;
         }
@@ -682,21 +1113,47 @@ pub async fn run(
             let rel_path = &module.path;
 
             // Create module node
-            let module_node = mu_daemon::storage::Node::module(rel_path);
+            let mut module_node = mu_daemon::storage::Node::module(rel_path);
+            if let Some(hash) = file_hashes.get(rel_path) {
+                module_node = module_node.with_properties(json!({ "content_hash": hash }));
+            }
+            if module.total_lines > 0 {
+                module_node.line_end = Some(module.total_lines);
+            }
             let module_id = module_node.id.clone();
             nodes.push(module_node);
 
             // Create class nodes
             for class in &module.classes {
-                let mut class_node = mu_daemon::storage::Node::class(
-                    rel_path,
-                    &class.name,
-                    class.start_line,
-                    class.end_line,
-                );
+                // Enums and interfaces get their own node types
+                let mut class_node = match module.enum_named(&class.name) {
+                    Some(enum_def) => mu_daemon::storage::Node::enumeration(
+                        rel_path,
+                        &class.name,
+                        &enum_def.variants,
+                        class.start_line,
+                        class.end_line,
+                    ),
+                    None if class.is_interface(&module.language) => {
+                        mu_daemon::storage::Node::interface(
+                            rel_path,
+                            &class.name,
+                            class.start_line,
+                            class.end_line,
+                        )
+                    }
+                    None => mu_daemon::storage::Node::class(
+                        rel_path,
+                        &class.name,
+                        class.start_line,
+                        class.end_line,
+                    ),
+                };
                 // Add docstring to properties if present
                 if let Some(ref docstring) = class.docstring {
-                    class_node = class_node.with_properties(json!({"docstring": docstring}));
+                    let mut properties = class_node.properties.take().unwrap_or_else(|| json!({}));
+                    properties["docstring"] = json!(docstring);
+                    class_node = class_node.with_properties(properties);
                 }
                 let class_id = class_node.id.clone();
                 nodes.push(class_node);
@@ -716,6 +1173,30 @@ pub async fn run(
                     edges.push(mu_daemon::storage::Edge::inherits(&class_id, &base_id));
                 }
 
+                // Rust trait impls, recorded by the parser as `impl:Trait`
+                if module.language == "rust" {
+                    // Impls in another file belong to the type's own node
+                    let implementor = if is_rust_impl_block(module, class) {
+                        resolve_rust_type(&class.name, module, &rust_types)
+                            .unwrap_or_else(|| class_id.clone())
+                    } else {
+                        class_id.clone()
+                    };
+                    for trait_path in class
+                        .decorators
+                        .iter()
+                        .filter_map(|d| d.strip_prefix("impl:"))
+                    {
+                        let trait_name = trait_path.rsplit("::").next().unwrap_or(trait_path);
+                        let trait_id = resolve_rust_type(trait_name, module, &rust_types)
+                            .unwrap_or_else(|| format!("ext:{}", trait_path));
+                        edges.push(mu_daemon::storage::Edge::implements(
+                            &implementor,
+                            &trait_id,
+                        ));
+                    }
+                }
+
                 // Create method nodes
                 for method in &class.methods {
                     let mut method_node = mu_daemon::storage::Node::function(
@@ -726,10 +1207,8 @@ pub async fn run(
                         method.end_line,
                         method.body_complexity,
                     );
-                    // Add docstring to properties if present
-                    if let Some(ref docstring) = method.docstring {
-                        method_node =
-                            method_node.with_properties(json!({"docstring": docstring}));
+                    if let Some(properties) = function_properties(method, &module.language, &[]) {
+                        method_node = method_node.with_properties(properties);
                     }
                     let method_id = method_node.id.clone();
                     nodes.push(method_node);
@@ -749,32 +1228,51 @@ pub async fn run(
                     func.end_line,
                     func.body_complexity,
                 );
-                // Add docstring to properties if present
-                if let Some(ref docstring) = func.docstring {
-                    func_node = func_node.with_properties(json!({"docstring": docstring}));
+                // Components use their props type; its members are the props
+                // unless the parameter destructures them
+                let props_type = component_props_type(func, &module.language)
+                    .and_then(|name| class_lookup.get(name));
+                let props = match props_type.and_then(|id| interface_members.get(id)) {
+                    Some(members) if func.props.is_empty() => members,
+                    _ => func.props.as_slice(),
+                };
+                if let Some(properties) = function_properties(func, &module.language, props) {
+                    func_node = func_node.with_properties(properties);
                 }
                 let func_id = func_node.id.clone();
                 nodes.push(func_node);
 
                 // Module contains function
                 edges.push(mu_daemon::storage::Edge::contains(&module_id, &func_id));
+                if let Some(props_id) = props_type {
+                    edges.push(mu_daemon::storage::Edge::uses(&func_id, props_id));
+                }
             }
 
             // Create import edges
             for import in &module.imports {
-                let target_id = resolve_import(
-                    &import.module,
-                    rel_path,
-                    &module.language,
-                    path_alias_resolver.as_ref(),
-                    workspace_resolver.as_ref(),
-                    Some(&csharp_namespace_map),
-                );
+                let target_id = if matches!(module.language.as_str(), "c" | "cpp") {
+                    resolve_c_include(&import.module, rel_path, &c_source_files)
+                } else if module.language == "php" {
+                    resolve_php_import(&import.module, rel_path, &php_class_map)
+                } else {
+                    resolve_import(
+                        &import.module,
+                        rel_path,
+                        &module.language,
+                        path_alias_resolver.as_ref(),
+                        workspace_resolver.as_ref(),
+                        Some(&csharp_namespace_map),
+                    )
+                };
                 edges.push(mu_daemon::storage::Edge::imports(&module_id, &target_id));
             }
         }
     }
 
+    // Go interfaces are satisfied implicitly; match method sets per package
+    edges.extend(build_go_implements_edges(&parse_results));
+
     // Build func_lookup map for call resolution
     // Maps: simple name, qualified name, and full ID -> node ID
     let mut func_lookup: HashMap<String, String> = HashMap::new();
@@ -792,12 +1290,32 @@ pub async fn run(
     }
     tracing::debug!("Built function lookup with {} entries", func_lookup.len());
 
+    // Definitions sharing a simple name, so calls can prefer the one whose
+    // parameters fit their arguments
+    let mut overloads: Overloads = HashMap::new();
+    for module in parse_results.iter().filter_map(|r| r.module.as_ref()) {
+        for class in &module.classes {
+            for method in &class.methods {
+                let id = format!("fn:{}:{}.{}", module.path, class.name, method.name);
+                overloads
+                    .entry(&method.name)
+                    .or_default()
+                    .push((id, method));
+            }
+        }
+        for func in &module.functions {
+            let id = format!("fn:{}:{}", module.path, func.name);
+            overloads.entry(&func.name).or_default().push((id, func));
+        }
+    }
+
     // Pass 2: Create CALLS edges from call_sites
     spinner.set_message("Resolving call sites...");
     let mut total_call_sites = 0usize;
     let mut resolved_call_sites = 0usize;
 
     for result in &parse_results {
+        cancel.check()?;
         if !result.success {
             continue;
         }
@@ -815,6 +1333,7 @@ pub async fn run(
                             rel_path,
                             Some(&class.name),
                             &func_lookup,
+                            &overloads,
                             &module.imports,
                         ) {
                             edges.push(mu_daemon::storage::Edge::calls(&method_id, &target_id));
@@ -829,9 +1348,14 @@ pub async fn run(
                 let func_id = format!("fn:{}:{}", rel_path, func.name);
                 total_call_sites += func.call_sites.len();
                 for call in &func.call_sites {
-                    if let Some(target_id) =
-                        resolve_call_site(call, rel_path, None, &func_lookup, &module.imports)
-                    {
+                    if let Some(target_id) = resolve_call_site(
+                        call,
+                        rel_path,
+                        None,
+                        &func_lookup,
+                        &overloads,
+                        &module.imports,
+                    ) {
                         edges.push(mu_daemon::storage::Edge::calls(&func_id, &target_id));
                         resolved_call_sites += 1;
                     }
@@ -896,13 +1420,33 @@ pub async fn run(
         }
     }
 
+    // Identical copies get a lightweight module node pointing at the canonical parse
+    for dup in &scan_result.duplicates {
+        nodes.push(
+            mu_daemon::storage::Node::module(&dup.path).with_properties(json!({
+                "duplicate_of": format!("mod:{}", dup.duplicate_of),
+                "content_hash": file_hashes.get(&dup.duplicate_of),
+            })),
+        );
+    }
+
+    cancel.check()?;
     spinner.set_message("Writing database...");
 
-    // Step 4: Write to database
-    let mubase = mu_daemon::storage::MUbase::open(&mubase_path)?;
-    mubase.clear()?;
-    mubase.insert_nodes(&nodes)?;
-    mubase.insert_edges(&edges)?;
+    // Step 4: Write to database in a single transaction. Any failure or
+    // cancellation before the commit leaves the previous graph intact.
+    let mubase_existed = mubase_path.exists();
+    let mut mubase = mu_daemon::storage::MUbase::open(&mubase_path)?;
+    if let Err(e) = mubase.replace_graph(&nodes, &edges, || cancel.check()) {
+        spinner.finish_and_clear();
+        drop(mubase);
+        if !mubase_existed {
+            remove_mubase_files(&mubase_path);
+        }
+        return Err(e);
+    }
+    // Lets readers resolve file paths when the database lives elsewhere
+    mubase.set_project_root(&root)?;
 
     // Get final stats
     let stats = mubase.stats()?;
@@ -912,7 +1456,7 @@ pub async fn run(
         spinner.set_message("Loading embedding model...");
 
         // Use embedded model weights (compiled into the binary)
-        match mu_embeddings::MuSigmaModel::embedded() {
+        match crate::commands::embed::load_index_model() {
             Ok(model) => {
                 spinner.set_message("Generating embeddings...");
 
@@ -927,8 +1471,12 @@ pub async fn run(
                 let mut embedded_count = 0;
 
                 // Process in batches for better progress feedback
-                let batch_size = 32;
+                let batch_size = model.config().batch_size;
                 for (batch_idx, batch) in nodes_to_embed.chunks(batch_size).enumerate() {
+                    if let Err(e) = cancel.check_embeddings() {
+                        spinner.finish_and_clear();
+                        return Err(e);
+                    }
                     spinner.set_message(format!(
                         "Generating embeddings... {}/{}",
                         (batch_idx * batch_size).min(total),
@@ -943,6 +1491,8 @@ pub async fn run(
                             let type_prefix = match n.node_type {
                                 mu_daemon::storage::NodeType::Module => "module",
                                 mu_daemon::storage::NodeType::Class => "class",
+                                mu_daemon::storage::NodeType::Interface => "interface",
+                                mu_daemon::storage::NodeType::Enum => "enum",
                                 mu_daemon::storage::NodeType::Function => "function",
                                 mu_daemon::storage::NodeType::External => "external",
                             };
@@ -977,15 +1527,36 @@ pub async fn run(
                     }
                 }
 
-                // Insert all embeddings
+                spinner.set_message("Generating docstring embeddings...");
+                let doc_embeddings = embed_docstrings(&model, &nodes_to_embed);
+                if let Err(e) = cancel.check_embeddings() {
+                    spinner.finish_and_clear();
+                    return Err(e);
+                }
+
+                // Replace all embeddings in one transaction
                 if !embeddings_batch.is_empty() {
                     spinner.set_message("Storing embeddings...");
                     if let Err(e) =
-                        mubase.insert_embeddings_batch(&embeddings_batch, Some("mu-sigma-v2"))
+                        mubase.replace_embeddings(&embeddings_batch, Some("mu-sigma-v2"), || {
+                            cancel.check_embeddings()
+                        })
                     {
+                        if cancel.is_cancelled() {
+                            spinner.finish_and_clear();
+                            return Err(e);
+                        }
                         tracing::warn!("Failed to store embeddings: {}", e);
                     }
                 }
+                if let Err(e) = mubase.replace_embeddings_in(
+                    EmbeddingSpace::Docs,
+                    &doc_embeddings,
+                    Some("mu-sigma-v2"),
+                    || Ok(()),
+                ) {
+                    tracing::warn!("Failed to store docstring embeddings: {}", e);
+                }
 
                 embedded_count
             }
@@ -999,9 +1570,19 @@ pub async fn run(
                 0
             }
         }
+    } else if let (true, Some(changed)) = (live_embed, &changed_since) {
+        spinner.set_message("Re-embedding changed nodes...");
+        match embed_changed_nodes(&mubase, &root, &nodes, changed) {
+            Ok(count) => count,
+            Err(e) => {
+                tracing::warn!("Failed to re-embed changed nodes: {}", e);
+                0
+            }
+        }
     } else {
         0
     };
+    cancel.mark_committed();
 
     spinner.finish_and_clear();
 
@@ -1014,6 +1595,9 @@ pub async fn run(
         files_scanned,
         files_parsed,
         files_cached,
+        duplicates: scan_result.duplicates,
+        generated: scan_result.generated,
+        warnings,
         node_count: stats.node_count,
         edge_count: stats.edge_count,
         nodes_by_type: stats.type_counts,
@@ -1026,6 +1610,65 @@ pub async fn run(
     Output::new(result, format).render()
 }
 
+/// Text a node is embedded from: its type, name and qualified name.
+fn embedding_text(node: &mu_daemon::storage::Node) -> String {
+    format!(
+        "{} {} {}",
+        node.node_type.as_str(),
+        node.name,
+        node.qualified_name.as_deref().unwrap_or("")
+    )
+}
+
+/// Re-embed the nodes of `changed` files after a `--since` rebuild, keeping
+/// every other stored embedding, and drop embeddings of removed nodes.
+///
+/// The files' hashes are recorded so `mu embed` won't redo them. Returns
+/// the number of nodes embedded.
+fn embed_changed_nodes(
+    mubase: &mu_daemon::storage::MUbase,
+    root: &Path,
+    nodes: &[mu_daemon::storage::Node],
+    changed: &HashSet<String>,
+) -> anyhow::Result<usize> {
+    let affected: Vec<&mu_daemon::storage::Node> = nodes
+        .iter()
+        .filter(|n| n.node_type != mu_daemon::storage::NodeType::External)
+        .filter(|n| n.file_path.as_ref().is_some_and(|p| changed.contains(p)))
+        .collect();
+
+    let mut embeddings_batch = Vec::new();
+    if !affected.is_empty() {
+        let model = crate::commands::embed::load_index_model()?;
+        for batch in affected.chunks(model.config().batch_size) {
+            let texts: Vec<String> = batch.iter().map(|n| embedding_text(n)).collect();
+            let text_refs: Vec<&str> = texts.iter().map(|s| s.as_str()).collect();
+            let embeddings = model.embed(&text_refs)?;
+            for ((node, text), embedding) in batch.iter().zip(&texts).zip(embeddings) {
+                embeddings_batch.push((node.id.clone(), embedding, Some(text.clone())));
+            }
+        }
+        mubase.insert_embeddings_batch(&embeddings_batch, Some("mu-sigma-v2"))?;
+        mubase.insert_embeddings_batch_in(
+            EmbeddingSpace::Docs,
+            &embed_docstrings(&model, &affected),
+            Some("mu-sigma-v2"),
+        )?;
+    }
+    mubase.cleanup_orphaned_embeddings()?;
+
+    let hash_updates: Vec<(String, String)> = changed
+        .iter()
+        .filter_map(|path| {
+            let hash = crate::commands::embed::compute_file_hash(&root.join(path)).ok()?;
+            Some((path.clone(), hash))
+        })
+        .collect();
+    mubase.set_file_hashes_batch(&hash_updates)?;
+
+    Ok(embeddings_batch.len())
+}
+
 /// Build a namespace-to-file mapping for C# modules.
 /// This enables resolving C# `using` statements to actual source files.
 fn build_csharp_namespace_map(
@@ -1051,6 +1694,255 @@ fn build_csharp_namespace_map(
     map
 }
 
+/// Node properties for a function: its docstring, `exported` when it is
+/// visible outside its module (`pub` in Rust, `export` in TypeScript and
+/// JavaScript, a capitalized name in Go), and a component's `props`.
+fn function_properties(
+    func: &mu_core::types::FunctionDef,
+    language: &str,
+    props: &[String],
+) -> Option<serde_json::Value> {
+    let exported = match language {
+        "go" => func.name.starts_with(|c: char| c.is_ascii_uppercase()),
+        _ => func.decorators.iter().any(|d| d == "pub" || d == "export"),
+    };
+
+    let mut properties = serde_json::Map::new();
+    if let Some(ref docstring) = func.docstring {
+        properties.insert("docstring".to_string(), json!(docstring));
+    }
+    if exported {
+        properties.insert("exported".to_string(), json!(true));
+    }
+    if !props.is_empty() {
+        properties.insert("props".to_string(), json!(props));
+    }
+    (!properties.is_empty()).then_some(serde_json::Value::Object(properties))
+}
+
+/// The type a TypeScript/JavaScript component takes its props as, e.g.
+/// `ButtonProps` for `function Button({ label }: ButtonProps)`.
+///
+/// Components are PascalCase functions; `Readonly<...>` and
+/// `PropsWithChildren<...>` wrappers are looked through. Inline object
+/// types have no name and give `None`.
+fn component_props_type<'a>(
+    func: &'a mu_core::types::FunctionDef,
+    language: &str,
+) -> Option<&'a str> {
+    if !matches!(language, "typescript" | "javascript")
+        || !func.name.starts_with(|c: char| c.is_ascii_uppercase())
+    {
+        return None;
+    }
+    let mut type_name = func.parameters.first()?.type_annotation.as_deref()?.trim();
+    while let Some((wrapper, inner)) = type_name.split_once('<') {
+        let wrapper = wrapper.rsplit('.').next().unwrap_or(wrapper);
+        if !matches!(wrapper, "Readonly" | "PropsWithChildren") {
+            break;
+        }
+        type_name = inner.strip_suffix('>')?.trim();
+    }
+    let base = type_name.split('<').next().unwrap_or(type_name);
+    let name = base.rsplit('.').next().unwrap_or(base);
+    name.chars()
+        .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+        .then_some(name)
+}
+
+/// Whether `class` is the parser's stand-in for impl blocks on a Rust type
+/// declared in another file.
+fn is_rust_impl_block(
+    module: &mu_core::types::ModuleDef,
+    class: &mu_core::types::ClassDef,
+) -> bool {
+    module.language == "rust" && class.decorators.first().is_some_and(|d| d == "impl")
+}
+
+/// Resolve a type named in a Rust module to its node ID.
+///
+/// A declaration in the same file wins, then one anywhere else in the Rust
+/// code. Types of other languages are never considered, so an unresolved
+/// name is left for the caller to mark external.
+fn resolve_rust_type(
+    name: &str,
+    module: &mu_core::types::ModuleDef,
+    rust_types: &HashMap<&str, String>,
+) -> Option<String> {
+    if module
+        .classes
+        .iter()
+        .any(|class| class.name == name && !is_rust_impl_block(module, class))
+    {
+        return Some(format!("cls:{}:{}", module.path, name));
+    }
+    rust_types.get(name).cloned()
+}
+
+/// Build `implements` edges from Go structs to the interfaces they satisfy.
+///
+/// A struct implements an interface when it declares a method with the same
+/// name, parameter types and result types for every method of the interface,
+/// including methods of embedded interfaces. Only structs and interfaces in the
+/// same package (directory and package name) are compared. Interfaces with no
+/// methods, or that embed an interface from another package, are skipped, and
+/// methods promoted from embedded structs are not counted.
+fn build_go_implements_edges(
+    parse_results: &[mu_core::types::ParseResult],
+) -> Vec<mu_daemon::storage::Edge> {
+    let mut packages: HashMap<(&str, &str), Vec<&mu_core::types::ModuleDef>> = HashMap::new();
+    for result in parse_results {
+        if !result.success {
+            continue;
+        }
+        if let Some(ref module) = result.module {
+            if module.language == "go" {
+                let dir = module.path.rsplit_once('/').map_or("", |(dir, _)| dir);
+                packages
+                    .entry((dir, module.name.as_str()))
+                    .or_default()
+                    .push(module);
+            }
+        }
+    }
+
+    let mut edges = Vec::new();
+    for modules in packages.values() {
+        // Receiver type -> signatures of its methods
+        let mut method_sets: HashMap<&str, HashSet<String>> = HashMap::new();
+        let mut interfaces = HashMap::new();
+        let mut structs = Vec::new();
+
+        for module in modules {
+            for func in &module.functions {
+                let receiver = func
+                    .decorators
+                    .iter()
+                    .find_map(|d| d.strip_prefix("receiver:"));
+                if let Some(receiver) = receiver {
+                    let receiver = receiver.trim_start_matches('*');
+                    let receiver = receiver.split('[').next().unwrap_or(receiver);
+                    method_sets
+                        .entry(receiver)
+                        .or_default()
+                        .insert(go_method_signature(func));
+                }
+            }
+            for class in &module.classes {
+                if class.decorators.iter().any(|d| d == "interface") {
+                    interfaces.insert(class.name.as_str(), (module.path.as_str(), class));
+                } else if class.decorators.iter().any(|d| d == "struct") {
+                    structs.push((module.path.as_str(), class.name.as_str()));
+                }
+            }
+        }
+
+        for (name, (interface_path, _)) in &interfaces {
+            let Some(required) = go_interface_methods(name, &interfaces, &mut HashSet::new())
+            else {
+                continue;
+            };
+            if required.is_empty() {
+                continue;
+            }
+            for (struct_path, struct_name) in &structs {
+                let satisfied = method_sets
+                    .get(struct_name)
+                    .is_some_and(|methods| required.is_subset(methods));
+                if satisfied {
+                    edges.push(mu_daemon::storage::Edge::implements(
+                        &format!("cls:{}:{}", struct_path, struct_name),
+                        &format!("cls:{}:{}", interface_path, name),
+                    ));
+                }
+            }
+        }
+    }
+
+    edges
+}
+
+/// Method signatures of a Go interface, with embedded interfaces expanded.
+///
+/// Returns `None` when an embedded interface isn't declared in the package,
+/// since its methods are unknown.
+fn go_interface_methods<'a>(
+    name: &'a str,
+    interfaces: &HashMap<&'a str, (&str, &'a mu_core::types::ClassDef)>,
+    visiting: &mut HashSet<&'a str>,
+) -> Option<HashSet<String>> {
+    let (_, interface) = interfaces.get(name)?;
+    if !visiting.insert(name) {
+        return Some(HashSet::new());
+    }
+
+    let mut methods: HashSet<String> = interface.methods.iter().map(go_method_signature).collect();
+    for base in &interface.bases {
+        methods.extend(go_interface_methods(base, interfaces, visiting)?);
+    }
+    Some(methods)
+}
+
+/// `Name(T1,T2)R` with whitespace removed, so formatting differences don't matter.
+fn go_method_signature(func: &mu_core::types::FunctionDef) -> String {
+    let params: Vec<&str> = func
+        .parameters
+        .iter()
+        .map(|p| p.type_annotation.as_deref().unwrap_or(""))
+        .collect();
+    let signature = format!(
+        "{}({}){}",
+        func.name,
+        params.join(","),
+        func.return_type.as_deref().unwrap_or("")
+    );
+    signature.split_whitespace().collect()
+}
+
+/// Map fully qualified PHP class names (`App\Models\User`) to their files.
+fn build_php_class_map(parse_results: &[mu_core::types::ParseResult]) -> HashMap<String, String> {
+    let mut map = HashMap::new();
+
+    for result in parse_results {
+        if !result.success {
+            continue;
+        }
+        if let Some(ref module) = result.module {
+            if module.language == "php" {
+                for class in &module.classes {
+                    let name = match module.namespace {
+                        Some(ref namespace) => format!("{}\\{}", namespace, class.name),
+                        None => class.name.clone(),
+                    };
+                    map.insert(name, module.path.clone());
+                }
+            }
+        }
+    }
+
+    map
+}
+
+/// Resolve a PHP `use` or `require`/`include` to a module ID.
+///
+/// Included paths resolve against the including file. A `use` resolves to the
+/// file declaring that class; anything else (vendor classes, functions and
+/// constants) is external.
+fn resolve_php_import(
+    import_path: &str,
+    source_file: &str,
+    class_map: &HashMap<String, String>,
+) -> String {
+    if import_path.contains('/') || import_path.ends_with(".php") {
+        return format!("mod:{}", join_relative(source_file, import_path));
+    }
+
+    match class_map.get(import_path) {
+        Some(path) => format!("mod:{}", path),
+        None => format!("ext:{}", import_path),
+    }
+}
+
 /// Resolve a C# using statement to a module ID.
 /// Tries to match the using statement against known namespaces from the project.
 fn resolve_csharp_import(using_stmt: &str, namespace_map: &HashMap<String, Vec<String>>) -> String {
@@ -1232,6 +2124,10 @@ fn resolve_import(
         return format!("ext:{}", import_path);
     }
 
+    if language == "ruby" {
+        return resolve_ruby_import(import_path, source_file);
+    }
+
     // First, try TypeScript path aliases (e.g., @/lib/logger, @components/Button)
     // This takes priority for non-relative imports
     if let Some(resolver) = path_alias_resolver {
@@ -1268,6 +2164,126 @@ fn resolve_import(
     }
 }
 
+/// Resolve a Ruby `require` or `require_relative`.
+///
+/// Relative requires (emitted as `./x` or `../x` by the parser) resolve against
+/// the requiring file with an implied `.rb`; anything else comes from the load
+/// path and is treated as an external gem.
+fn resolve_ruby_import(import_path: &str, source_file: &str) -> String {
+    if !is_typescript_style_import(import_path) {
+        return format!("ext:{}", import_path);
+    }
+
+    let mut path = join_relative(source_file, import_path);
+    if !path.ends_with(".rb") {
+        path.push_str(".rb");
+    }
+    format!("mod:{}", path)
+}
+
+/// Resolve a C/C++ `#include` to a module ID.
+///
+/// `<x.h>` is a system header. A quoted include is looked up next to the
+/// including file first, as the preprocessor does, then as a path suffix of
+/// any parsed header (`"drivers/uart.h"` finding `src/drivers/uart.h`),
+/// shortest match first.
+fn resolve_c_include(include: &str, source_file: &str, known_files: &HashSet<String>) -> String {
+    if let Some(system) = include.strip_prefix('<') {
+        return format!("ext:{}", system.trim_end_matches('>'));
+    }
+
+    let sibling = join_relative(source_file, include);
+    if known_files.contains(&sibling) {
+        return format!("mod:{}", sibling);
+    }
+
+    let suffix = format!("/{}", include.trim_start_matches("./"));
+    let found = known_files
+        .iter()
+        .filter(|path| **path == suffix[1..] || path.ends_with(&suffix))
+        .min_by_key(|path| (path.len(), path.as_str()));
+
+    match found {
+        Some(path) => format!("mod:{}", path),
+        None => format!("mod:{}", sibling),
+    }
+}
+
+/// Join a `./`/`../` relative path onto the directory of `source_file`.
+fn join_relative(source_file: &str, relative: &str) -> String {
+    let source_dir = std::path::Path::new(source_file)
+        .parent()
+        .and_then(|p| p.to_str())
+        .unwrap_or("");
+    let mut parts: Vec<&str> = source_dir.split('/').filter(|s| !s.is_empty()).collect();
+    for segment in relative.split('/') {
+        match segment {
+            "" | "." => {}
+            ".." => {
+                parts.pop();
+            }
+            s => parts.push(s),
+        }
+    }
+    parts.join("/")
+}
+
+/// Function and method definitions by simple name, with their node IDs.
+type Overloads<'a> = HashMap<&'a str, Vec<(String, &'a mu_core::types::FunctionDef)>>;
+
+/// Whether `func` can be called with `arg_count` arguments.
+///
+/// A leading `self`/`cls`/`this` receiver doesn't count; defaulted
+/// parameters are optional and variadic or `**kwargs` ones take any number.
+fn accepts_arg_count(func: &mu_core::types::FunctionDef, arg_count: usize) -> bool {
+    let mut params = func.parameters.as_slice();
+    if let Some(first) = params.first() {
+        if matches!(first.name.as_str(), "self" | "cls" | "this") {
+            params = &params[1..];
+        }
+    }
+    let unbounded = params.iter().any(|p| p.is_variadic || p.is_keyword);
+    let required = params
+        .iter()
+        .filter(|p| !p.is_variadic && !p.is_keyword && p.default_value.is_none())
+        .count();
+    arg_count >= required && (unbounded || arg_count <= params.len())
+}
+
+/// How many of the call's known argument types match the declared
+/// parameter types, compared by base name (`List<int>` matches `List`).
+fn matching_arg_types(
+    call: &mu_core::types::CallSiteDef,
+    func: &mu_core::types::FunctionDef,
+) -> usize {
+    let base = |ty: &str| {
+        let ty = ty.split('<').next().unwrap_or(ty).trim_end_matches("...");
+        ty.rsplit('.').next().unwrap_or(ty).to_string()
+    };
+    call.arg_types
+        .iter()
+        .zip(&func.parameters)
+        .filter(|(arg, param)| match (arg, &param.type_annotation) {
+            (Some(arg), Some(param)) => base(arg) == base(param),
+            _ => false,
+        })
+        .count()
+}
+
+/// Among definitions named like the callee, the one whose parameters fit
+/// the call's arguments best, preferring matching argument types and then
+/// the last definition seen. None if no definition accepts the arity.
+fn best_overload(
+    call: &mu_core::types::CallSiteDef,
+    candidates: &[(String, &mu_core::types::FunctionDef)],
+) -> Option<String> {
+    candidates
+        .iter()
+        .filter(|(_, func)| accepts_arg_count(func, call.arg_count))
+        .max_by_key(|(_, func)| matching_arg_types(call, func))
+        .map(|(id, _)| id.clone())
+}
+
 /// Resolve a call site to a function/method node ID.
 /// Returns None if the call cannot be resolved (external function, unresolvable reference).
 fn resolve_call_site(
@@ -1275,6 +2291,7 @@ fn resolve_call_site(
     current_module: &str,
     current_class: Option<&str>,
     func_lookup: &HashMap<String, String>,
+    overloads: &Overloads,
     imports: &[mu_core::types::ImportDef],
 ) -> Option<String> {
     let callee = &call.callee;
@@ -1314,9 +2331,12 @@ fn resolve_call_site(
         }
     }
 
-    // 4. Check by simple name (may match if unique in codebase)
+    // 4. Check by simple name, preferring a definition that fits the arguments
     if let Some(target_id) = func_lookup.get(callee) {
-        return Some(target_id.clone());
+        let best = overloads
+            .get(callee.as_str())
+            .and_then(|candidates| best_overload(call, candidates));
+        return Some(best.unwrap_or_else(|| target_id.clone()));
     }
 
     // 5. Check imported names
@@ -1371,6 +2391,109 @@ fn resolve_call_site(
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_resolve_call_site_prefers_matching_arity() {
+        use mu_core::types::{CallSiteDef, FunctionDef, ParameterDef};
+
+        let param = |name: &str, ty: &str| ParameterDef {
+            name: name.to_string(),
+            type_annotation: Some(ty.to_string()),
+            ..Default::default()
+        };
+        let one = FunctionDef {
+            name: "notify".to_string(),
+            parameters: vec![param("user", "User")],
+            ..Default::default()
+        };
+        let two = FunctionDef {
+            name: "notify".to_string(),
+            parameters: vec![param("user", "User"), param("message", "String")],
+            ..Default::default()
+        };
+        let by_channel = FunctionDef {
+            name: "notify".to_string(),
+            parameters: vec![param("user", "User"), param("channel", "Channel")],
+            ..Default::default()
+        };
+
+        let mut func_lookup = HashMap::new();
+        let mut overloads: Overloads = HashMap::new();
+        for (id, func) in [
+            ("fn:Mailer.java:Mailer.notify", &two),
+            ("fn:Pager.java:Pager.notify", &one),
+            ("fn:Chat.java:Chat.notify", &by_channel),
+        ] {
+            func_lookup.insert("notify".to_string(), id.to_string());
+            overloads
+                .entry("notify")
+                .or_default()
+                .push((id.to_string(), func));
+        }
+        let resolve = |call: &CallSiteDef| {
+            resolve_call_site(call, "App.java", None, &func_lookup, &overloads, &[])
+        };
+
+        let call = CallSiteDef::new("notify".to_string(), 1, false, None);
+        assert_eq!(
+            resolve(&call.clone().with_arg_count(1)).as_deref(),
+            Some("fn:Pager.java:Pager.notify")
+        );
+        // Same arity: the argument types decide
+        let typed = call
+            .clone()
+            .with_args(vec![None, Some("String".to_string())]);
+        assert_eq!(
+            resolve(&typed).as_deref(),
+            Some("fn:Mailer.java:Mailer.notify")
+        );
+        // No definition fits: fall back to the simple-name match
+        assert_eq!(
+            resolve(&call.with_arg_count(3)).as_deref(),
+            Some("fn:Chat.java:Chat.notify")
+        );
+    }
+
+    #[test]
+    fn test_component_props_type() {
+        use mu_core::types::{FunctionDef, ParameterDef};
+
+        let component = |name: &str, props: &str| FunctionDef {
+            name: name.to_string(),
+            parameters: vec![ParameterDef {
+                name: "props".to_string(),
+                type_annotation: Some(props.to_string()),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+        fn props_type(func: &FunctionDef) -> Option<&str> {
+            component_props_type(func, "typescript")
+        }
+
+        assert_eq!(
+            props_type(&component("Button", "ButtonProps")),
+            Some("ButtonProps")
+        );
+        assert_eq!(
+            props_type(&component(
+                "Card",
+                "Readonly<PropsWithChildren<ui.CardProps>>"
+            )),
+            Some("CardProps")
+        );
+        assert_eq!(
+            props_type(&component("List", "ListProps<Item>")),
+            Some("ListProps")
+        );
+        assert_eq!(props_type(&component("Badge", "{ text: string }")), None);
+        // Not a component: lowercase name or another language
+        assert_eq!(props_type(&component("render", "Options")), None);
+        assert_eq!(
+            component_props_type(&component("Button", "ButtonProps"), "python"),
+            None
+        );
+    }
+
     #[test]
     fn test_resolve_import_external() {
         // External single-name imports
@@ -1589,9 +2712,205 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_resolve_ruby_import() {
+        assert_eq!(
+            resolve_import("./helper", "app/models/user.rb", "ruby", None, None, None),
+            "mod:app/models/helper.rb"
+        );
+        assert_eq!(
+            resolve_import(
+                "../../lib/auth",
+                "app/models/user.rb",
+                "ruby",
+                None,
+                None,
+                None
+            ),
+            "mod:lib/auth.rb"
+        );
+        assert_eq!(
+            resolve_import("json", "app/models/user.rb", "ruby", None, None, None),
+            "ext:json"
+        );
+    }
+
+    #[test]
+    fn test_go_implements_edges() {
+        let shapes = r#"package shapes
+
+type Shape interface {
+    Area() float64
+}
+
+type Named interface {
+    Shape
+    Name() string
+}
+
+type Empty interface{}
+
+type Remote interface {
+    io.Reader
+}
+
+type Square struct{ side float64 }
+
+type Circle struct{ r float64 }
+"#;
+        let methods = r#"package shapes
+
+func (s *Square) Area() float64 { return s.side * s.side }
+func (s Square) Name() string { return "square" }
+func (c Circle) Area() int { return 0 }
+"#;
+        let other = "package other\n\ntype Shape interface {\n    Area() float64\n}\n";
+        let results = vec![
+            mu_core::parser::parse_source(shapes, "shapes/types.go", "go"),
+            mu_core::parser::parse_source(methods, "shapes/methods.go", "go"),
+            mu_core::parser::parse_source(other, "other/shape.go", "go"),
+        ];
+
+        let mut edges: Vec<(String, String)> = build_go_implements_edges(&results)
+            .into_iter()
+            .map(|e| (e.source_id, e.target_id))
+            .collect();
+        edges.sort();
+
+        // Circle.Area has the wrong result type; other.Shape is another package
+        assert_eq!(
+            edges,
+            vec![
+                (
+                    "cls:shapes/types.go:Square".to_string(),
+                    "cls:shapes/types.go:Named".to_string()
+                ),
+                (
+                    "cls:shapes/types.go:Square".to_string(),
+                    "cls:shapes/types.go:Shape".to_string()
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_resolve_php_import() {
+        let class_map = HashMap::from([(
+            "App\\Models\\User".to_string(),
+            "app/Models/User.php".to_string(),
+        )]);
+        let source = "app/Http/UserController.php";
+
+        assert_eq!(
+            resolve_php_import("App\\Models\\User", source, &class_map),
+            "mod:app/Models/User.php"
+        );
+        assert_eq!(
+            resolve_php_import("./helpers.php", source, &class_map),
+            "mod:app/Http/helpers.php"
+        );
+        assert_eq!(
+            resolve_php_import("Illuminate\\Support\\Str", source, &class_map),
+            "ext:Illuminate\\Support\\Str"
+        );
+    }
+
+    #[test]
+    fn test_resolve_c_include() {
+        let known: HashSet<String> = ["src/ring.h", "src/drivers/uart.h", "vendor/drivers/uart.h"]
+            .iter()
+            .map(|s| s.to_string())
+            .collect();
+
+        assert_eq!(
+            resolve_c_include("ring.h", "src/ring.c", &known),
+            "mod:src/ring.h"
+        );
+        assert_eq!(
+            resolve_c_include("drivers/uart.h", "app/main.c", &known),
+            "mod:src/drivers/uart.h"
+        );
+        assert_eq!(
+            resolve_c_include("<stdint.h>", "src/ring.c", &known),
+            "ext:stdint.h"
+        );
+        assert_eq!(
+            resolve_c_include("missing.h", "src/ring.c", &known),
+            "mod:src/missing.h"
+        );
+    }
+
     #[test]
     fn test_default_config_is_valid_toml() {
         let config = get_default_config();
         toml::from_str::<toml::Value>(config).expect("Default config should be valid TOML");
     }
+
+    #[test]
+    fn test_failed_bootstrap_removes_new_mubase() {
+        let dir = tempfile::tempdir().unwrap();
+        let mubase_path = dir.path().join(".mu").join("mubase");
+        fs::create_dir_all(mubase_path.parent().unwrap()).unwrap();
+
+        let mubase = mu_daemon::storage::MUbase::open(&mubase_path).unwrap();
+        let nodes = vec![mu_daemon::storage::Node::module("src/a.py")];
+        let result = mubase.replace_graph(&nodes, &[], || anyhow::bail!("interrupted"));
+        assert!(result.is_err());
+        drop(mubase);
+
+        remove_mubase_files(&mubase_path);
+        assert!(!mubase_path.exists());
+        assert!(!dir.path().join(".mu").join("mubase.wal").exists());
+    }
+
+    #[test]
+    fn test_sync_removes_deleted_file_nodes_and_edges() {
+        use mu_daemon::storage::{Edge, MUbase, Node};
+
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        fs::write(root.join("app.py"), "import util\n").unwrap();
+        fs::write(root.join("util.py"), "def helper():\n    pass\n").unwrap();
+        let mubase_path = root.join(".mu").join("mubase");
+        fs::create_dir_all(mubase_path.parent().unwrap()).unwrap();
+
+        {
+            let mubase = MUbase::open(&mubase_path).unwrap();
+            let app = Node::module("app.py");
+            let util = Node::module("util.py");
+            let helper = Node::function("util.py", "helper", None, 1, 2, 1);
+            mubase
+                .insert_nodes(&[app.clone(), util.clone(), helper.clone()])
+                .unwrap();
+            mubase
+                .insert_edge(&Edge::imports(&app.id, &util.id))
+                .unwrap();
+            mubase
+                .insert_edge(&Edge::contains(&util.id, &helper.id))
+                .unwrap();
+            mubase
+                .set_file_hashes_batch(&[
+                    ("app.py".to_string(), "a".to_string()),
+                    ("util.py".to_string(), "b".to_string()),
+                ])
+                .unwrap();
+        }
+
+        fs::remove_file(root.join("util.py")).unwrap();
+
+        let result = sync_deleted_files(root, &mubase_path).unwrap();
+        assert_eq!(result.removed_files, vec!["util.py"]);
+        assert_eq!(result.nodes_removed, 2);
+        assert_eq!(result.hashes_removed, 1);
+        assert_eq!(result.node_count, 1);
+        // app.py's import of the deleted module goes with it
+        assert_eq!(result.edge_count, 0);
+
+        let mubase = MUbase::open_read_only(&mubase_path).unwrap();
+        assert_eq!(mubase.indexed_files().unwrap(), vec!["app.py"]);
+        assert!(!mubase
+            .get_all_file_hashes()
+            .unwrap()
+            .contains_key("util.py"));
+    }
 }
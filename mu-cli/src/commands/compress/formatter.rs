@@ -6,6 +6,9 @@ use super::models::{
 };
 use crate::output::TableDisplay;
 
+/// Enum variants listed on an enum's line before the rest are counted.
+const MAX_VARIANTS: usize = 12;
+
 impl CompressedCodebase {
     /// Generate MU format output
     pub fn to_mu_format(&self, detail: DetailLevel) -> String {
@@ -187,7 +190,21 @@ impl CompressedCodebase {
         } else {
             String::new()
         };
-        out.push_str(&format!("{}$ {}{}\n", indent, class.name, bases_str));
+        // Enum variants, capped so huge enums stay one line
+        let variants_str = if class.variants.is_empty() {
+            String::new()
+        } else {
+            let mut shown: Vec<String> =
+                class.variants.iter().take(MAX_VARIANTS).cloned().collect();
+            if class.variants.len() > MAX_VARIANTS {
+                shown.push(format!("+{}", class.variants.len() - MAX_VARIANTS));
+            }
+            format!(" = {}", shown.join(" | "))
+        };
+        out.push_str(&format!(
+            "{}$ {}{}{}\n",
+            indent, class.name, bases_str, variants_str
+        ));
 
         // Relationships (medium+)
         if detail >= DetailLevel::Medium {
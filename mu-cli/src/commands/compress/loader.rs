@@ -4,35 +4,11 @@ use super::models::*;
 use anyhow::{Context, Result};
 use duckdb::Connection;
 use std::collections::{HashMap, HashSet};
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 /// Type alias for relationship maps: node_id -> [(related_node_id, edge_type)]
 type RelationshipMap = HashMap<String, Vec<(String, String)>>;
 
-/// Find the MUbase database in the given directory or its parents
-pub fn find_mubase(start_path: &str) -> Option<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize().ok()?;
-    let mut current = start.as_path();
-
-    loop {
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Some(db_path);
-        }
-
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Some(legacy_path);
-        }
-
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => return None,
-        }
-    }
-}
-
 /// Raw node from the database
 #[derive(Debug)]
 pub struct RawNode {
@@ -195,6 +171,21 @@ pub fn extract_attributes(node: &RawNode) -> Vec<String> {
     Vec::new()
 }
 
+/// Extract enum variants from properties
+fn extract_variants(node: &RawNode) -> Vec<String> {
+    if let Some(ref props) = node.properties {
+        if let Ok(json) = serde_json::from_str::<serde_json::Value>(props) {
+            if let Some(variants) = json.get("variants").and_then(|v| v.as_array()) {
+                return variants
+                    .iter()
+                    .filter_map(|v| v.as_str().map(|s| s.to_string()))
+                    .collect();
+            }
+        }
+    }
+    Vec::new()
+}
+
 /// Extract docstring from properties
 fn extract_docstring(node: &RawNode) -> Option<String> {
     if let Some(ref props) = node.properties {
@@ -226,7 +217,10 @@ pub fn load_from_database(db_path: &Path, source: &str) -> Result<CompressedCode
     let node_by_id: HashMap<&str, &RawNode> = nodes.iter().map(|n| (n.id.as_str(), n)).collect();
 
     let modules: Vec<&RawNode> = nodes.iter().filter(|n| n.node_type == "module").collect();
-    let classes: Vec<&RawNode> = nodes.iter().filter(|n| n.node_type == "class").collect();
+    let classes: Vec<&RawNode> = nodes
+        .iter()
+        .filter(|n| matches!(n.node_type.as_str(), "class" | "interface" | "enum"))
+        .collect();
     let functions: Vec<&RawNode> = nodes.iter().filter(|n| n.node_type == "function").collect();
 
     let mut class_methods: HashMap<String, Vec<String>> = HashMap::new();
@@ -239,7 +233,10 @@ pub fn load_from_database(db_path: &Path, source: &str) -> Result<CompressedCode
                 match target_node.node_type.as_str() {
                     "function" => {
                         if let Some(source_node) = node_by_id.get(edge.source_id.as_str()) {
-                            if source_node.node_type == "class" {
+                            if matches!(
+                                source_node.node_type.as_str(),
+                                "class" | "interface" | "enum"
+                            ) {
                                 class_methods
                                     .entry(edge.source_id.clone())
                                     .or_default()
@@ -252,7 +249,7 @@ pub fn load_from_database(db_path: &Path, source: &str) -> Result<CompressedCode
                             }
                         }
                     }
-                    "class" => {
+                    "class" | "interface" | "enum" => {
                         module_classes
                             .entry(edge.source_id.clone())
                             .or_default()
@@ -350,6 +347,7 @@ pub fn load_from_database(db_path: &Path, source: &str) -> Result<CompressedCode
                         used_by,
                         methods,
                         attributes: extract_attributes(class_node),
+                        variants: extract_variants(class_node),
                     });
                 }
             }
@@ -491,7 +489,7 @@ fn build_relationship_clusters(
         })
         .collect();
 
-    class_rel_counts.sort_by(|a, b| b.1.cmp(&a.1));
+    class_rel_counts.sort_by_key(|c| std::cmp::Reverse(c.1));
 
     for (class, rel_count) in class_rel_counts.into_iter().take(10) {
         if rel_count == 0 {
@@ -532,7 +530,7 @@ fn build_relationship_clusters(
 
         clusters.push(RelationshipCluster {
             entity: class.name.clone(),
-            entity_type: "class".to_string(),
+            entity_type: class.node_type.clone(),
             relationship_count: rel_count,
             outgoing,
             incoming,
@@ -1144,7 +1142,7 @@ fn build_domain_overview(
         .filter(|(_, score)| *score > 0)
         .collect();
 
-    scored_classes.sort_by(|a, b| b.1.cmp(&a.1));
+    scored_classes.sort_by_key(|c| std::cmp::Reverse(c.1));
 
     let mut seen_names: HashSet<String> = HashSet::new();
     scored_classes.retain(|(node, _)| {
@@ -1312,6 +1310,10 @@ pub fn load_from_source(path: &Path) -> Result<CompressedCodebase> {
                     used_by: Vec::new(),
                     methods,
                     attributes: class.attributes.clone(),
+                    variants: module
+                        .enum_named(&class.name)
+                        .map(|e| e.variants.clone())
+                        .unwrap_or_default(),
                 });
             }
             total_classes += classes.len();
@@ -1368,7 +1370,7 @@ pub fn load_from_source(path: &Path) -> Result<CompressedCodebase> {
 
     let tree = build_folder_tree(&compressed_modules);
 
-    hot_paths.sort_by(|a, b| b.complexity.cmp(&a.complexity));
+    hot_paths.sort_by_key(|h| std::cmp::Reverse(h.complexity));
     hot_paths.truncate(20);
 
     let stats = CodebaseStats {
@@ -28,7 +28,7 @@ pub async fn run(
         .with_context(|| format!("Path not found: {}", path))?;
 
     // Try to load from database first
-    let codebase = if let Some(db_path) = loader::find_mubase(path) {
+    let codebase = if let Some(db_path) = crate::mubase::find_mubase_optional(path) {
         eprintln!(
             "{} Using graph database for rich relationships",
             "INFO:".cyan()
@@ -56,6 +56,9 @@ pub struct CompressedClass {
     pub used_by: Vec<String>,
     pub methods: Vec<CompressedFunction>,
     pub attributes: Vec<String>,
+    /// Variant names when this is an enum
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub variants: Vec<String>,
 }
 
 /// A compressed module representation
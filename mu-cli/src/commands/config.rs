@@ -0,0 +1,211 @@
+//! Config command - Validate `.murc.toml` and show the effective configuration
+//!
+//! - `mu config validate` reports unknown keys, type errors, and invalid glob
+//!   patterns instead of silently falling back to defaults.
+//! - `mu config show` prints the fully-resolved configuration (file merged
+//!   with built-in defaults).
+
+use crate::config::{ConfigIssue, MuConfig};
+use crate::output::{Output, OutputFormat, TableDisplay};
+use anyhow::{Context, Result};
+use colored::Colorize;
+use serde::Serialize;
+use std::path::Path;
+
+/// Result of validating a `.murc.toml` file.
+#[derive(Debug, Serialize)]
+pub struct ValidationReport {
+    pub path: String,
+    pub exists: bool,
+    pub valid: bool,
+    pub issues: Vec<ConfigIssue>,
+}
+
+impl TableDisplay for ValidationReport {
+    fn to_table(&self) -> String {
+        if !self.exists {
+            return format!(
+                "{} No config file at {} (defaults apply)",
+                "OK:".green().bold(),
+                self.path
+            );
+        }
+        if self.valid {
+            return format!("{} {} is valid", "OK:".green().bold(), self.path);
+        }
+
+        let mut lines = vec![format!(
+            "{} {} has {} issue(s)",
+            "ERROR:".red().bold(),
+            self.path,
+            self.issues.len()
+        )];
+        for issue in &self.issues {
+            if issue.key.is_empty() {
+                lines.push(format!("  - {}", issue.message));
+            } else {
+                lines.push(format!("  - {}: {}", issue.key.yellow(), issue.message));
+            }
+        }
+        lines.join("\n")
+    }
+
+    fn to_mu(&self) -> String {
+        let mut lines = vec![format!(":: config validate {}", self.path)];
+        lines.push(format!("# valid: {}", self.valid));
+        for issue in &self.issues {
+            lines.push(format!(
+                "! {} {}",
+                issue.key,
+                issue.message.replace('\n', " ")
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// The effective configuration after merging defaults.
+#[derive(Debug, Serialize)]
+pub struct EffectiveConfig {
+    pub path: String,
+    pub exists: bool,
+    pub config: MuConfig,
+}
+
+impl EffectiveConfig {
+    fn to_toml(&self) -> String {
+        toml::to_string_pretty(&self.config).unwrap_or_default()
+    }
+}
+
+impl TableDisplay for EffectiveConfig {
+    fn to_table(&self) -> String {
+        let source = if self.exists {
+            self.path.clone()
+        } else {
+            format!("{} (not found, defaults only)", self.path)
+        };
+        format!(
+            "{} {}\n\n{}",
+            "Effective config:".cyan().bold(),
+            source,
+            self.to_toml().trim_end()
+        )
+    }
+
+    fn to_mu(&self) -> String {
+        format!(":: config {}\n{}", self.path, self.to_toml().trim_end())
+    }
+}
+
+/// Validate the `.murc.toml` in `root`.
+pub fn validate_config(root: &Path) -> Result<ValidationReport> {
+    let config_path = root.join(".murc.toml");
+    let path = config_path.to_string_lossy().to_string();
+
+    if !config_path.exists() {
+        return Ok(ValidationReport {
+            path,
+            exists: false,
+            valid: true,
+            issues: Vec::new(),
+        });
+    }
+
+    let content = std::fs::read_to_string(&config_path)
+        .with_context(|| format!("Failed to read {}", config_path.display()))?;
+    let issues = MuConfig::validate(&content);
+
+    Ok(ValidationReport {
+        path,
+        exists: true,
+        valid: issues.is_empty(),
+        issues,
+    })
+}
+
+/// Load the effective configuration for `root`.
+pub fn effective_config(root: &Path) -> Result<EffectiveConfig> {
+    let config_path = root.join(".murc.toml");
+    let config = MuConfig::load_strict(root)?;
+
+    Ok(EffectiveConfig {
+        path: config_path.to_string_lossy().to_string(),
+        exists: config_path.exists(),
+        config: config.resolved(),
+    })
+}
+
+/// Run `mu config validate`. Exits with status 1 if any issue is found.
+pub async fn run_validate(path: &str, format: OutputFormat) -> Result<()> {
+    let report = validate_config(Path::new(path))?;
+    let valid = report.valid;
+    Output::new(report, format).render()?;
+
+    if !valid {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Run `mu config show`.
+pub async fn run_show(path: &str, format: OutputFormat) -> Result<()> {
+    let effective = effective_config(Path::new(path))?;
+    Output::new(effective, format).render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_validate_config_reports_unknown_key() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join(".murc.toml"),
+            "[scanner]\nmax_file_size = 512\n",
+        )
+        .unwrap();
+
+        let report = validate_config(dir.path()).unwrap();
+        assert!(report.exists);
+        assert!(!report.valid);
+        assert_eq!(report.issues.len(), 1);
+        assert_eq!(report.issues[0].key, "scanner.max_file_size");
+    }
+
+    #[test]
+    fn test_effective_config_shows_resolved_values() {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join(".murc.toml"),
+            "[scanner]\nignore = [\"vendor/\"]\nmax_file_size_kb = 256\n",
+        )
+        .unwrap();
+
+        assert!(validate_config(dir.path()).unwrap().valid);
+
+        let effective = effective_config(dir.path()).unwrap();
+        assert!(effective.exists);
+        assert_eq!(effective.config.scanner.max_file_size_kb, Some(256));
+        assert!(effective
+            .config
+            .scanner
+            .ignore
+            .contains(&"target/".to_string()));
+
+        let toml = effective.to_toml();
+        assert!(toml.contains("max_file_size_kb = 256"));
+        assert!(toml.contains("\"vendor/\""));
+        assert!(toml.contains("enabled = true"));
+    }
+
+    #[test]
+    fn test_missing_config_is_valid() {
+        let dir = TempDir::new().unwrap();
+        let report = validate_config(dir.path()).unwrap();
+        assert!(!report.exists);
+        assert!(report.valid);
+    }
+}
@@ -0,0 +1,223 @@
+//! Context command - Token-budgeted context for a question
+//!
+//! Finds the code relevant to a question the way `mu grok` does, then
+//! encodes it for an LLM: MU text with source by default, or the OMEGA
+//! S-expressions of `mu omg` with `--omega`. The least relevant nodes are
+//! dropped until the output fits `--max-tokens`, and a budget report follows
+//! the text. Reads the database directly, so no daemon is needed.
+//!
+//! Examples:
+//!   mu context "how does auth work"
+//!   mu context "retry logic" --max-tokens 2000
+//!   mu context "parser" --omega --format mu > context.omega
+
+use std::collections::HashSet;
+
+use colored::Colorize;
+use mu_daemon::storage::{MUbase, Node};
+use serde::Serialize;
+
+use crate::commands::grok::{self, GrokContext, GrokResult, SearchMethod};
+use crate::commands::vibes::omg;
+use crate::output::{Output, OutputFormat, TableDisplay};
+
+/// Dependency hops pulled in around the matches, as `mu grok`'s default
+const DEPTH: u8 = 2;
+
+/// Encoded context and how it fits the budget.
+#[derive(Debug, Serialize)]
+pub struct ContextResult {
+    pub question: String,
+    /// `mu` or `omega`
+    pub encoding: &'static str,
+    pub text: String,
+    pub tokens: usize,
+    pub max_tokens: usize,
+    /// Nodes in the text
+    pub node_count: usize,
+    /// Relevant nodes left out to fit the budget
+    pub dropped: usize,
+    pub search_method: SearchMethod,
+}
+
+impl TableDisplay for ContextResult {
+    fn to_table(&self) -> String {
+        let mut lines = vec![self.text.clone(), String::new()];
+        if self.search_method == SearchMethod::Keyword {
+            lines.push(format!(
+                "{} Using keyword search (run '{}' for better semantic results).",
+                "NOTE:".yellow().bold(),
+                "mu embed".cyan()
+            ));
+        }
+
+        let used = format!("{}/{}", self.tokens, self.max_tokens);
+        lines.push(format!(
+            "{} {} tokens ({} encoding)",
+            "Budget:".dimmed(),
+            used.cyan().bold(),
+            self.encoding
+        ));
+        let mut nodes = format!("{} {} nodes", "Nodes:".dimmed(), self.node_count);
+        if self.dropped > 0 {
+            nodes.push_str(&format!(
+                ", {} dropped to fit",
+                self.dropped.to_string().yellow()
+            ));
+        }
+        lines.push(nodes);
+        lines.join("\n")
+    }
+
+    /// The bare text, ready to paste into a prompt
+    fn to_mu(&self) -> String {
+        self.text.clone()
+    }
+}
+
+/// OMEGA S-expressions for the context nodes and the edges touching them.
+fn omega_text(nodes: &[Node], edges: &[(String, String, String)], max_tokens: usize) -> String {
+    let ids: HashSet<String> = nodes.iter().map(|n| n.id.clone()).collect();
+    let edges = omg::select_edges(edges, &ids, max_tokens);
+    format!(
+        "{}\n\n{}",
+        omg::generate_schema_seed(true),
+        omg::generate_compressed_body(nodes, &edges)
+    )
+}
+
+/// Extract the context for a question within `max_tokens`.
+pub fn build_context(
+    mubase: &MUbase,
+    question: &str,
+    max_tokens: usize,
+    omega: bool,
+) -> anyhow::Result<ContextResult> {
+    let mut found: GrokResult = grok::extract_context(mubase, question, DEPTH)?;
+    let relevant = found.contexts.len();
+
+    let (mut nodes, edges) = if omega {
+        let mut nodes = Vec::with_capacity(relevant);
+        for context in &found.contexts {
+            nodes.extend(mubase.get_node(&context.node_id)?);
+        }
+        (nodes, mubase.load_graph()?.get_edges().to_vec())
+    } else {
+        (Vec::new(), Vec::new())
+    };
+
+    // Contexts are sorted most relevant first: drop a tenth from the end at
+    // a time, as `mu omg` does, until the measured output fits. Measuring
+    // after every single drop would be quadratic on large extractions.
+    let (text, tokens) = loop {
+        let text = if omega {
+            omega_text(&nodes, &edges, max_tokens)
+        } else {
+            found.to_mu()
+        };
+        let tokens = omg::estimate_tokens(&text);
+        if tokens <= max_tokens || found.contexts.is_empty() {
+            break (text, tokens);
+        }
+        let keep = found.contexts.len() - (found.contexts.len() / 10).max(1);
+        let dropped: HashSet<String> = found
+            .contexts
+            .drain(keep..)
+            .map(|context| context.node_id)
+            .collect();
+        nodes.retain(|n| !dropped.contains(&n.id));
+        found.total_nodes = found.contexts.len();
+        found.total_lines = found.contexts.iter().map(source_lines).sum();
+    };
+
+    Ok(ContextResult {
+        question: question.to_string(),
+        encoding: if omega { "omega" } else { "mu" },
+        text,
+        tokens,
+        max_tokens,
+        node_count: found.contexts.len(),
+        dropped: relevant - found.contexts.len(),
+        search_method: found.search_method,
+    })
+}
+
+fn source_lines(context: &GrokContext) -> usize {
+    context
+        .source_code
+        .as_ref()
+        .map_or(0, |code| code.lines().count())
+}
+
+/// Run the context command
+pub async fn run(
+    question: &str,
+    max_tokens: usize,
+    omega: bool,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    if question.trim().is_empty() {
+        anyhow::bail!("Question cannot be empty. Please provide a question or topic.");
+    }
+
+    let mubase = grok::open_mubase()?;
+    Output::new(build_context(&mubase, question, max_tokens, omega)?, format).render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mu_daemon::storage::Edge;
+    use tempfile::TempDir;
+
+    fn seeded() -> (TempDir, MUbase) {
+        let dir = TempDir::new().unwrap();
+        std::fs::write(
+            dir.path().join("auth.py"),
+            "def login(user):\n    return check_password(user)\n\n\
+             def check_password(user):\n    return user.password == 'secret'\n",
+        )
+        .unwrap();
+        let mubase = MUbase::open(dir.path().join("mubase")).unwrap();
+        mubase
+            .insert_nodes(&[
+                Node::module("auth.py"),
+                Node::function("auth.py", "login", None, 1, 2, 1),
+                Node::function("auth.py", "check_password", None, 4, 5, 1),
+            ])
+            .unwrap();
+        mubase
+            .insert_edges(&[Edge::calls("fn:auth.py:login", "fn:auth.py:check_password")])
+            .unwrap();
+        (dir, mubase)
+    }
+
+    #[test]
+    fn test_context_fits_budget() {
+        let (_dir, mubase) = seeded();
+
+        let full = build_context(&mubase, "login", 8000, false).unwrap();
+        assert_eq!(full.encoding, "mu");
+        assert!(full.node_count > 0);
+        assert_eq!(full.dropped, 0);
+        assert!(full.text.contains("login"));
+        assert!(full.tokens <= full.max_tokens);
+
+        // A tight budget drops the least relevant nodes
+        let tight = build_context(&mubase, "login", full.tokens - 1, false).unwrap();
+        assert!(tight.dropped > 0);
+        assert_eq!(tight.node_count + tight.dropped, full.node_count);
+        assert!(tight.tokens <= tight.max_tokens || tight.node_count == 0);
+    }
+
+    #[test]
+    fn test_context_omega_encoding() {
+        let (_dir, mubase) = seeded();
+
+        let result = build_context(&mubase, "login", 8000, true).unwrap();
+        assert_eq!(result.encoding, "omega");
+        assert!(result.text.starts_with(";; OMEGA Schema"));
+        assert!(result.text.contains("(fn \"login\")"), "{}", result.text);
+        assert_eq!(result.to_mu(), result.text);
+    }
+}
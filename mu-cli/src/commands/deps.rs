@@ -2,50 +2,36 @@
 //!
 //! Analyzes the dependency graph to show what a node depends on (ancestors)
 //! or what depends on it (dependents/reverse).
-
-use crate::output::{Output, OutputFormat, TableDisplay};
+//!
+//! Edges point from the dependent to its dependency, so `mu deps` follows
+//! outgoing edges and `mu usedby` (`--reverse`) follows incoming ones.
+//!
+//! `--format tree` nests each node under the one it was reached from, with
+//! the edge type on each branch. Edges back to a node already on the path
+//! are shown once, marked `(cycle)`.
+
+use crate::commands::graph::resolve_node;
+use crate::mubase::find_mubase;
+use crate::output::{
+    Column, CsvOutput, NodeKind, Output, OutputConfig, OutputFormat, Outputter, TreeNode,
+    TreeOutput,
+};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use duckdb::{params, Connection};
+use mu_core::graph::TraversalDirection;
+use mu_daemon::storage::EdgeType;
 use serde::Serialize;
-use std::collections::{HashSet, VecDeque};
-use std::path::PathBuf;
-
-/// Find the MUbase database in the given directory or its parents.
-fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize()?;
-    let mut current = start.as_path();
-
-    loop {
-        // New standard path: .mu/mubase
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Ok(db_path);
-        }
-
-        // Legacy path: .mubase
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Ok(legacy_path);
-        }
-
-        // Move up to parent
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "No MUbase found. Run 'mu bootstrap' first to create the database."
-                ))
-            }
-        }
-    }
-}
+use std::collections::{HashMap, HashSet, VecDeque};
 
 /// Dependency information for a node
+///
+/// Serializes as `{node, node_name, direction, depth, edges: [{target,
+/// edge_type, depth, ..}], total_count}` for scripts reading `--format json`.
 #[derive(Debug, Serialize)]
 pub struct DependencyInfo {
     /// The node being analyzed
+    #[serde(rename = "node")]
     pub node_id: String,
     /// Node name (human readable)
     pub node_name: String,
@@ -54,7 +40,11 @@ pub struct DependencyInfo {
     /// Depth of traversal
     pub depth: u8,
     /// Dependencies found
+    #[serde(rename = "edges")]
     pub dependencies: Vec<DependencyNode>,
+    /// Edges back to a node already on the traversal path
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub cycles: Vec<DependencyCycle>,
     /// Total count
     pub total_count: usize,
 }
@@ -63,6 +53,7 @@ pub struct DependencyInfo {
 #[derive(Debug, Serialize, Clone)]
 pub struct DependencyNode {
     /// Node ID
+    #[serde(rename = "target")]
     pub id: String,
     /// Node name
     pub name: String,
@@ -74,10 +65,29 @@ pub struct DependencyNode {
     pub depth: u8,
     /// File path if available
     pub file_path: Option<String>,
+    /// Node this one was reached from (the start node or its module at depth 1)
+    pub parent: String,
+}
+
+/// An edge from a traversed node back to one of its ancestors
+#[derive(Debug, Serialize, Clone)]
+pub struct DependencyCycle {
+    /// Node the edge was followed from
+    #[serde(rename = "source")]
+    pub from: String,
+    /// Ancestor it leads back to
+    #[serde(rename = "target")]
+    pub to: String,
+    /// Ancestor name
+    pub name: String,
+    /// Ancestor node type
+    pub node_type: String,
+    /// Edge type
+    pub edge_type: String,
 }
 
-impl TableDisplay for DependencyInfo {
-    fn to_table(&self) -> String {
+impl Outputter for DependencyInfo {
+    fn to_table(&self, _config: &OutputConfig) -> String {
         let mut output = String::new();
 
         // Header
@@ -132,7 +142,19 @@ impl TableDisplay for DependencyInfo {
         output
     }
 
-    fn to_mu(&self) -> String {
+    fn to_csv(&self, config: &OutputConfig) -> String {
+        let columns = [
+            Column::new("depth", "depth"),
+            Column::new("target", "target"),
+            Column::new("name", "name"),
+            Column::new("node_type", "node_type"),
+            Column::new("edge_type", "edge_type"),
+            Column::new("file_path", "file_path"),
+        ];
+        CsvOutput::format_with_columns(&self.dependencies, &columns, config)
+    }
+
+    fn to_mu(&self, _config: &OutputConfig) -> String {
         let mut output = String::new();
         let sigil = if self.direction == "outgoing" {
             "deps"
@@ -155,6 +177,77 @@ impl TableDisplay for DependencyInfo {
         output.push_str(&format!("# total: {}\n", self.total_count));
         output
     }
+
+    fn to_tree(&self, config: &OutputConfig) -> String {
+        TreeOutput::from_nodes(&self.node_name, &self.tree(), config)
+    }
+}
+
+impl DependencyInfo {
+    /// The traversal as a tree under the start node.
+    ///
+    /// Depth-1 nodes hang off the root even when reached through the start
+    /// node's module; each deeper node hangs off its parent.
+    fn tree(&self) -> Vec<TreeNode> {
+        let mut children: HashMap<&str, Vec<&DependencyNode>> = HashMap::new();
+        for dep in &self.dependencies {
+            let parent = if dep.depth <= 1 {
+                self.node_id.as_str()
+            } else {
+                dep.parent.as_str()
+            };
+            children.entry(parent).or_default().push(dep);
+        }
+        let mut cycles: HashMap<&str, Vec<&DependencyCycle>> = HashMap::new();
+        let depth_of: HashMap<&str, u8> = self
+            .dependencies
+            .iter()
+            .map(|d| (d.id.as_str(), d.depth))
+            .collect();
+        for cycle in &self.cycles {
+            // Cycles from the start node's module belong to the root
+            let from = if depth_of.contains_key(cycle.from.as_str()) {
+                cycle.from.as_str()
+            } else {
+                self.node_id.as_str()
+            };
+            cycles.entry(from).or_default().push(cycle);
+        }
+
+        fn build(
+            id: &str,
+            children: &HashMap<&str, Vec<&DependencyNode>>,
+            cycles: &HashMap<&str, Vec<&DependencyCycle>>,
+        ) -> Vec<TreeNode> {
+            let mut nodes: Vec<TreeNode> = children
+                .get(id)
+                .into_iter()
+                .flatten()
+                .map(|dep| {
+                    TreeNode::new(&dep.name, node_kind(&dep.node_type))
+                        .with_suffix(format!("({})", dep.edge_type))
+                        .with_children(build(&dep.id, children, cycles))
+                })
+                .collect();
+            nodes.extend(cycles.get(id).into_iter().flatten().map(|cycle| {
+                TreeNode::new(&cycle.name, node_kind(&cycle.node_type))
+                    .with_suffix(format!("({}) (cycle)", cycle.edge_type))
+            }));
+            nodes
+        }
+
+        build(&self.node_id, &children, &cycles)
+    }
+}
+
+/// Tree styling for a graph node type.
+fn node_kind(node_type: &str) -> NodeKind {
+    match node_type {
+        "module" => NodeKind::Module,
+        "class" | "interface" | "enum" => NodeKind::Type,
+        "function" => NodeKind::Function,
+        _ => NodeKind::Other,
+    }
 }
 
 /// Find the parent module for a class or function node.
@@ -197,14 +290,50 @@ fn find_parent_module(conn: &Connection, node_id: &str) -> Option<String> {
 }
 
 /// Load nodes and edges from the database and perform BFS traversal
-fn find_dependencies(
+pub(crate) fn find_dependencies(
     conn: &Connection,
     node_id: &str,
-    reverse: bool,
+    direction: TraversalDirection,
+    max_depth: u8,
+    include_contains: bool,
+) -> Result<Vec<DependencyNode>> {
+    find_dependencies_via(conn, node_id, direction, max_depth, include_contains, None)
+}
+
+/// BFS traversal like [`find_dependencies`], following only `edge_types`
+/// when given (which may include `contains`).
+pub(crate) fn find_dependencies_via(
+    conn: &Connection,
+    node_id: &str,
+    direction: TraversalDirection,
     max_depth: u8,
     include_contains: bool,
+    edge_types: Option<&[String]>,
 ) -> Result<Vec<DependencyNode>> {
+    traverse(
+        conn,
+        node_id,
+        direction,
+        max_depth,
+        include_contains,
+        edge_types,
+    )
+    .map(|(dependencies, _)| dependencies)
+}
+
+/// BFS traversal returning the nodes reached and the edges that lead back
+/// to a node on the path to the one they leave.
+fn traverse(
+    conn: &Connection,
+    node_id: &str,
+    direction: TraversalDirection,
+    max_depth: u8,
+    include_contains: bool,
+    edge_types: Option<&[String]>,
+) -> Result<(Vec<DependencyNode>, Vec<DependencyCycle>)> {
     let mut visited: HashSet<String> = HashSet::new();
+    let mut parents: HashMap<String, String> = HashMap::new();
+    let mut cycles: Vec<DependencyCycle> = Vec::new();
     let mut result: Vec<DependencyNode> = Vec::new();
     let mut queue: VecDeque<(String, u8)> = VecDeque::new();
 
@@ -214,12 +343,13 @@ fn find_dependencies(
 
     // For class/function nodes (outgoing direction), also include parent module's imports
     // since classes don't have direct import edges - those are on the module
-    if !reverse {
+    if direction == TraversalDirection::Dependencies {
         if let Some(module_id) = find_parent_module(conn, node_id) {
             if !visited.contains(&module_id) {
                 // Don't add the module itself, but queue it for edge traversal
                 // This allows finding module-level imports
                 queue.push_back((module_id.clone(), 0));
+                parents.insert(module_id.clone(), node_id.to_string());
                 visited.insert(module_id);
             }
         }
@@ -228,16 +358,29 @@ fn find_dependencies(
     // By default, exclude 'contains' edges which represent structural containment
     // (e.g., module contains class, class contains function) rather than actual dependencies.
     // Use --include-contains to see these edges.
-    let contains_filter = if include_contains {
-        ""
-    } else {
-        " AND e.type != 'contains'"
+    let contains_filter = match edge_types {
+        Some(types) => {
+            // Validated, so the names are safe to inline
+            let types = types
+                .iter()
+                .map(|t| match EdgeType::parse(t) {
+                    Some(edge_type) => Ok(format!("'{}'", edge_type.as_str())),
+                    None => Err(anyhow::anyhow!(
+                        "Unknown edge type '{}' (expected contains, imports, inherits, implements, calls or uses)",
+                        t
+                    )),
+                })
+                .collect::<Result<Vec<_>>>()?;
+            format!(" AND e.type IN ({})", types.join(", "))
+        }
+        None if include_contains => String::new(),
+        None => " AND e.type != 'contains'".to_string(),
     };
 
     // Prepare query based on direction
-    // reverse=false: what does this node depend on (follow outgoing edges: source -> target)
-    // reverse=true: what depends on this node (follow incoming edges: target <- source)
-    let edge_query = if reverse {
+    // Dependencies: what does this node depend on (follow outgoing edges: source -> target)
+    // Dependents: what depends on this node (follow incoming edges: target <- source)
+    let edge_query = if direction == TraversalDirection::Dependents {
         // Find nodes that point TO this node (dependents)
         format!(
             "SELECT e.source_id, e.type, n.name, n.type as node_type, n.file_path
@@ -274,6 +417,7 @@ fn find_dependencies(
 
             if !visited.contains(&neighbor_id) {
                 visited.insert(neighbor_id.clone());
+                parents.insert(neighbor_id.clone(), current_id.clone());
 
                 let dep = DependencyNode {
                     id: neighbor_id.clone(),
@@ -282,26 +426,45 @@ fn find_dependencies(
                     edge_type,
                     depth: current_depth + 1,
                     file_path,
+                    parent: current_id.clone(),
                 };
                 result.push(dep);
 
                 queue.push_back((neighbor_id, current_depth + 1));
+            } else {
+                let mut ancestor = Some(&current_id);
+                while let Some(id) = ancestor {
+                    if *id == neighbor_id {
+                        cycles.push(DependencyCycle {
+                            from: current_id.clone(),
+                            to: neighbor_id.clone(),
+                            name: name.clone(),
+                            node_type: node_type.clone(),
+                            edge_type: edge_type.clone(),
+                        });
+                        break;
+                    }
+                    ancestor = parents.get(id);
+                }
             }
         }
     }
 
     // Sort by depth, then by name
     result.sort_by(|a, b| a.depth.cmp(&b.depth).then(a.name.cmp(&b.name)));
+    cycles.sort_by(|a, b| a.from.cmp(&b.from).then(a.to.cmp(&b.to)));
 
-    Ok(result)
+    Ok((result, cycles))
 }
 
 /// Run the deps command
 pub async fn run(
     node: &str,
+    prefer: Option<&str>,
     reverse: bool,
     depth: u8,
     include_contains: bool,
+    explain_direction: bool,
     format: OutputFormat,
 ) -> Result<()> {
     // Validate node name is not empty or whitespace-only
@@ -309,15 +472,32 @@ pub async fn run(
         return Err(anyhow::anyhow!("Node name cannot be empty"));
     }
 
-    run_direct(node, reverse, depth, include_contains, format).await
+    let direction = if reverse {
+        TraversalDirection::Dependents
+    } else {
+        TraversalDirection::Dependencies
+    };
+
+    run_direct(
+        node,
+        prefer,
+        direction,
+        depth,
+        include_contains,
+        explain_direction,
+        format,
+    )
+    .await
 }
 
 /// Run deps command with direct database access
 async fn run_direct(
     node: &str,
-    reverse: bool,
+    prefer: Option<&str>,
+    direction: TraversalDirection,
     depth: u8,
     include_contains: bool,
+    explain_direction: bool,
     format: OutputFormat,
 ) -> Result<()> {
     // Find the MUbase database
@@ -331,82 +511,33 @@ async fn run_direct(
     .with_context(|| format!("Failed to open database: {:?}", db_path))?;
 
     // Try to resolve the node ID if it's a partial match
-    let node_id = resolve_node_id(&conn, node)?;
+    let node_id = resolve_node(&conn, node, prefer)?;
 
     // Get node info for display
     let node_info = get_node_info(&conn, &node_id)?;
 
+    // Explain on stderr so JSON/CSV output stays machine-readable
+    if explain_direction {
+        eprintln!("{}\n", direction.explain(&node_id));
+    }
+
     // Find dependencies
-    let dependencies = find_dependencies(&conn, &node_id, reverse, depth, include_contains)?;
+    let (dependencies, cycles) =
+        traverse(&conn, &node_id, direction, depth, include_contains, None)?;
 
     let info = DependencyInfo {
         node_id: node_id.clone(),
         node_name: node_info.0,
-        direction: if reverse { "incoming" } else { "outgoing" }.to_string(),
+        direction: direction.as_str().to_string(),
         depth,
         total_count: dependencies.len(),
         dependencies,
+        cycles,
     };
 
     Output::new(info, format).render()
 }
 
-/// Try to resolve a partial node ID to a full node ID using fuzzy matching
-fn resolve_node_id(conn: &Connection, query: &str) -> Result<String> {
-    // 1. Try exact match on id or name first
-    let mut stmt = conn.prepare("SELECT id FROM nodes WHERE id = ?1 OR name = ?1")?;
-    let mut rows = stmt.query(params![query])?;
-    if let Some(row) = rows.next()? {
-        return Ok(row.get(0)?);
-    }
-
-    // 2. Try fuzzy match on both name and id (case-insensitive)
-    let pattern = format!("%{}%", query.to_lowercase());
-    let mut stmt = conn.prepare(
-        "SELECT id, name, type FROM nodes WHERE LOWER(name) LIKE ?1 OR LOWER(id) LIKE ?1 LIMIT 10",
-    )?;
-    let mut rows = stmt.query(params![pattern])?;
-
-    let mut matches: Vec<(String, String, String)> = Vec::new();
-    while let Some(row) = rows.next()? {
-        let id: String = row.get(0)?;
-        let name: String = row.get(1)?;
-        let node_type: String = row.get(2)?;
-        matches.push((id, name, node_type));
-    }
-
-    match matches.len() {
-        0 => Err(anyhow::anyhow!("Node not found: {}", query)),
-        1 => Ok(matches.into_iter().next().unwrap().0),
-        _ => {
-            // Sort matches by type priority (class > module > function) then by name
-            let mut matches = matches;
-            matches.sort_by(|a, b| {
-                let type_priority = |t: &str| match t {
-                    "class" => 0,
-                    "module" => 1,
-                    "function" => 2,
-                    _ => 3,
-                };
-                type_priority(&a.2)
-                    .cmp(&type_priority(&b.2))
-                    .then_with(|| a.1.cmp(&b.1))
-            });
-
-            // Multiple matches - show sorted suggestions
-            let suggestions: Vec<String> = matches
-                .iter()
-                .map(|(id, name, typ)| format!("  {} [{}] {}", name, typ, id))
-                .collect();
-            Err(anyhow::anyhow!(
-                "Multiple nodes match '{}'. Be more specific:\n{}",
-                query,
-                suggestions.join("\n")
-            ))
-        }
-    }
-}
-
 /// Get node name and type for display
 fn get_node_info(conn: &Connection, node_id: &str) -> Result<(String, String)> {
     let mut stmt = conn.prepare("SELECT name, type FROM nodes WHERE id = ?")?;
@@ -424,6 +555,7 @@ fn get_node_info(conn: &Connection, node_id: &str) -> Result<(String, String)> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::path::PathBuf;
     use tempfile::tempdir;
 
     fn create_test_db() -> (Connection, PathBuf) {
@@ -494,7 +626,14 @@ mod tests {
         let (conn, _) = create_test_db();
 
         // include_contains=false by default (excludes 'contains' edges)
-        let deps = find_dependencies(&conn, "mod:src/a.py", false, 2, false).unwrap();
+        let deps = find_dependencies(
+            &conn,
+            "mod:src/a.py",
+            TraversalDirection::Dependencies,
+            2,
+            false,
+        )
+        .unwrap();
 
         assert_eq!(deps.len(), 2);
         assert!(deps.iter().any(|d| d.id == "mod:src/b.py" && d.depth == 1));
@@ -506,24 +645,107 @@ mod tests {
         let (conn, _) = create_test_db();
 
         // include_contains=false by default (excludes 'contains' edges)
-        let deps = find_dependencies(&conn, "mod:src/c.py", true, 2, false).unwrap();
+        let deps = find_dependencies(
+            &conn,
+            "mod:src/c.py",
+            TraversalDirection::Dependents,
+            2,
+            false,
+        )
+        .unwrap();
 
         assert_eq!(deps.len(), 2);
         assert!(deps.iter().any(|d| d.id == "mod:src/b.py" && d.depth == 1));
         assert!(deps.iter().any(|d| d.id == "mod:src/a.py" && d.depth == 2));
     }
 
+    #[test]
+    fn test_deps_and_usedby_are_complementary() {
+        let (conn, _) = create_test_db();
+        let deps = |id: &str, direction| {
+            find_dependencies(&conn, id, direction, 1, false)
+                .unwrap()
+                .into_iter()
+                .map(|d| d.id)
+                .collect::<Vec<_>>()
+        };
+
+        // a imports b: b is a dependency of a, and a is a dependent of b
+        assert_eq!(
+            deps("mod:src/a.py", TraversalDirection::Dependencies),
+            vec!["mod:src/b.py"]
+        );
+        assert_eq!(
+            deps("mod:src/b.py", TraversalDirection::Dependents),
+            vec!["mod:src/a.py"]
+        );
+
+        // ...and never the other way round
+        assert!(deps("mod:src/a.py", TraversalDirection::Dependents).is_empty());
+        assert!(!deps("mod:src/b.py", TraversalDirection::Dependencies)
+            .contains(&"mod:src/a.py".to_string()));
+    }
+
     #[test]
     fn test_depth_limiting() {
         let (conn, _) = create_test_db();
 
         // include_contains=false by default (excludes 'contains' edges)
-        let deps = find_dependencies(&conn, "mod:src/a.py", false, 1, false).unwrap();
+        let deps = find_dependencies(
+            &conn,
+            "mod:src/a.py",
+            TraversalDirection::Dependencies,
+            1,
+            false,
+        )
+        .unwrap();
 
         assert_eq!(deps.len(), 1);
         assert!(deps.iter().any(|d| d.id == "mod:src/b.py"));
     }
 
+    #[test]
+    fn test_tree_nests_by_parent_and_marks_cycles() {
+        let (conn, _) = create_test_db();
+        // c imports a, closing the loop a -> b -> c -> a
+        conn.execute(
+            "INSERT INTO edges (id, source_id, target_id, type) VALUES (?, ?, ?, ?)",
+            params!["e3", "mod:src/c.py", "mod:src/a.py", "imports"],
+        )
+        .unwrap();
+
+        let (dependencies, cycles) = traverse(
+            &conn,
+            "mod:src/a.py",
+            TraversalDirection::Dependencies,
+            5,
+            false,
+            None,
+        )
+        .unwrap();
+        assert_eq!(dependencies.len(), 2);
+        assert_eq!(cycles.len(), 1);
+        assert_eq!(
+            (cycles[0].from.as_str(), cycles[0].to.as_str()),
+            ("mod:src/c.py", "mod:src/a.py")
+        );
+
+        let info = DependencyInfo {
+            node_id: "mod:src/a.py".to_string(),
+            node_name: "a".to_string(),
+            direction: "outgoing".to_string(),
+            depth: 5,
+            total_count: dependencies.len(),
+            dependencies,
+            cycles,
+        };
+        let config = OutputConfig::new(OutputFormat::Tree).without_colors();
+        assert_eq!(
+            info.to_tree(&config),
+            "a\n\u{2514}\u{2500}\u{2500} b (imports)\n    \u{2514}\u{2500}\u{2500} c (imports)\n        \u{2514}\u{2500}\u{2500} a (imports) (cycle)"
+        );
+    }
+
     #[test]
     fn test_contains_edges_excluded_by_default() {
         let (conn, _) = create_test_db();
@@ -541,12 +763,26 @@ mod tests {
         .unwrap();
 
         // With include_contains=false, should NOT include the contains edge
-        let deps = find_dependencies(&conn, "mod:src/a.py", false, 2, false).unwrap();
+        let deps = find_dependencies(
+            &conn,
+            "mod:src/a.py",
+            TraversalDirection::Dependencies,
+            2,
+            false,
+        )
+        .unwrap();
         assert!(!deps.iter().any(|d| d.id == "cls:src/a.py::Foo"));
         assert_eq!(deps.len(), 2); // Only b and c via imports
 
         // With include_contains=true, SHOULD include the contains edge
-        let deps = find_dependencies(&conn, "mod:src/a.py", false, 2, true).unwrap();
+        let deps = find_dependencies(
+            &conn,
+            "mod:src/a.py",
+            TraversalDirection::Dependencies,
+            2,
+            true,
+        )
+        .unwrap();
         assert!(deps.iter().any(|d| d.id == "cls:src/a.py::Foo"));
         assert_eq!(deps.len(), 3); // b, c via imports, Foo via contains
     }
@@ -556,7 +792,7 @@ mod tests {
         use crate::output::OutputFormat;
 
         // Empty string should fail
-        let result = super::run("", false, 1, false, OutputFormat::Table).await;
+        let result = super::run("", None, false, 1, false, false, OutputFormat::Table).await;
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -564,7 +800,7 @@ mod tests {
             .contains("Node name cannot be empty"));
 
         // Whitespace-only should fail
-        let result = super::run("   ", false, 1, false, OutputFormat::Table).await;
+        let result = super::run("   ", None, false, 1, false, false, OutputFormat::Table).await;
         assert!(result.is_err());
         assert!(result
             .unwrap_err()
@@ -573,25 +809,25 @@ mod tests {
     }
 
     #[test]
-    fn test_resolve_node_id_exact_match_by_id() {
+    fn test_resolve_node_exact_match_by_id() {
         let (conn, _) = create_test_db();
 
         // Exact match by id should work
-        let result = resolve_node_id(&conn, "mod:src/a.py").unwrap();
+        let result = resolve_node(&conn, "mod:src/a.py", None).unwrap();
         assert_eq!(result, "mod:src/a.py");
     }
 
     #[test]
-    fn test_resolve_node_id_exact_match_by_name() {
+    fn test_resolve_node_exact_match_by_name() {
         let (conn, _) = create_test_db();
 
         // Exact match by name should work
-        let result = resolve_node_id(&conn, "a").unwrap();
+        let result = resolve_node(&conn, "a", None).unwrap();
         assert_eq!(result, "mod:src/a.py");
     }
 
     #[test]
-    fn test_resolve_node_id_fuzzy_match_single() {
+    fn test_resolve_node_fuzzy_match_single() {
         let (conn, _) = create_test_db();
 
         // Add a node with a unique name
@@ -607,12 +843,12 @@ mod tests {
         .unwrap();
 
         // Fuzzy match should find the single result
-        let result = resolve_node_id(&conn, "UserServ").unwrap();
+        let result = resolve_node(&conn, "UserServ", None).unwrap();
         assert_eq!(result, "cls:src/services/UserService.ts");
     }
 
     #[test]
-    fn test_resolve_node_id_fuzzy_match_case_insensitive() {
+    fn test_resolve_node_fuzzy_match_case_insensitive() {
         let (conn, _) = create_test_db();
 
         // Add a node with a unique name
@@ -628,12 +864,12 @@ mod tests {
         .unwrap();
 
         // Case-insensitive fuzzy match should work
-        let result = resolve_node_id(&conn, "authman").unwrap();
+        let result = resolve_node(&conn, "authman", None).unwrap();
         assert_eq!(result, "cls:src/auth/AuthManager.ts");
     }
 
     #[test]
-    fn test_resolve_node_id_multiple_matches_error() {
+    fn test_resolve_node_multiple_matches_error() {
         let (conn, _) = create_test_db();
 
         // Add nodes with similar names
@@ -659,7 +895,7 @@ mod tests {
         .unwrap();
 
         // Multiple matches should return an error with suggestions
-        let result = resolve_node_id(&conn, "User");
+        let result = resolve_node(&conn, "User", None);
         assert!(result.is_err());
         let err_msg = result.unwrap_err().to_string();
         assert!(err_msg.contains("Multiple nodes match"));
@@ -668,11 +904,11 @@ mod tests {
     }
 
     #[test]
-    fn test_resolve_node_id_no_match() {
+    fn test_resolve_node_no_match() {
         let (conn, _) = create_test_db();
 
         // No match should return an error
-        let result = resolve_node_id(&conn, "NonExistent");
+        let result = resolve_node(&conn, "NonExistent", None);
         assert!(result.is_err());
         assert!(result.unwrap_err().to_string().contains("Node not found"));
     }
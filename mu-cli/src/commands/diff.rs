@@ -10,7 +10,17 @@ use std::time::Instant;
 use colored::Colorize;
 use serde::Serialize;
 
-use crate::output::{Output, OutputFormat, TableDisplay};
+use crate::gate::{self, WarningLevel};
+use crate::output::{
+    Column, CsvOutput, Output, OutputConfig, OutputFormat, Outputter, SarifOutput, SarifResult,
+    SarifRule,
+};
+
+/// SARIF rule for breaking changes.
+const BREAKING_RULE: SarifRule = SarifRule {
+    id: "breaking-change",
+    description: "Removed file or public entity that dependents may rely on",
+};
 
 /// A single semantic change
 #[derive(Debug, Clone, Serialize)]
@@ -19,10 +29,29 @@ pub struct SemanticChange {
     pub entity_type: String,
     pub entity_name: String,
     pub file_path: Option<String>,
+    /// Lines of the entity: in the head ref, or the base ref for removals
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_start: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line_end: Option<u32>,
     pub is_breaking: bool,
     pub description: Option<String>,
 }
 
+impl SemanticChange {
+    /// Severity for `--fail-on`: breaking changes are errors, other
+    /// removals warnings and additions info.
+    fn level(&self) -> WarningLevel {
+        if self.is_breaking {
+            WarningLevel::Error
+        } else if self.change_type == "removed" {
+            WarningLevel::Warn
+        } else {
+            WarningLevel::Info
+        }
+    }
+}
+
 /// Diff result collection
 #[derive(Debug, Serialize)]
 pub struct DiffResult {
@@ -34,8 +63,8 @@ pub struct DiffResult {
     pub duration_ms: u64,
 }
 
-impl TableDisplay for DiffResult {
-    fn to_table(&self) -> String {
+impl Outputter for DiffResult {
+    fn to_table(&self, _config: &OutputConfig) -> String {
         let mut output = String::new();
 
         output.push_str(&format!(
@@ -166,7 +195,44 @@ impl TableDisplay for DiffResult {
         output
     }
 
-    fn to_mu(&self) -> String {
+    fn to_csv(&self, config: &OutputConfig) -> String {
+        let columns = [
+            Column::new("change_type", "change_type"),
+            Column::new("entity_type", "entity_type"),
+            Column::new("entity_name", "entity_name"),
+            Column::new("file_path", "file_path"),
+            Column::new("line_start", "line_start"),
+            Column::new("is_breaking", "is_breaking"),
+        ];
+        CsvOutput::format_with_columns(&self.changes, &columns, config)
+    }
+
+    /// One SARIF error per breaking change, at the entity's lines.
+    fn to_sarif(&self, config: &OutputConfig) -> String {
+        let results: Vec<SarifResult> = self
+            .breaking_changes
+            .iter()
+            .map(|change| SarifResult {
+                rule_id: BREAKING_RULE.id,
+                level: "error",
+                message: match &change.description {
+                    Some(description) => format!("{}: {}", change.entity_name, description),
+                    None => format!(
+                        "{} {} {}",
+                        change.change_type, change.entity_type, change.entity_name
+                    ),
+                },
+                file: change
+                    .file_path
+                    .clone()
+                    .unwrap_or_else(|| change.entity_name.clone()),
+                lines: change.line_start.zip(change.line_end),
+            })
+            .collect();
+        SarifOutput::format(&[BREAKING_RULE], &results, config)
+    }
+
+    fn to_mu(&self, _config: &OutputConfig) -> String {
         let mut output = String::new();
 
         output.push_str(&format!(":: diff {}..{}\n", self.base_ref, self.head_ref));
@@ -260,12 +326,43 @@ fn detect_language(file_path: &str) -> Option<&'static str> {
         "rs" => Some("rust"),
         "java" => Some("java"),
         "cs" => Some("csharp"),
+        "dart" => Some("dart"),
+        "scala" | "sc" => Some("scala"),
+        "kt" | "kts" => Some("kotlin"),
+        "php" => Some("php"),
+        "rb" => Some("ruby"),
+        "c" | "h" => Some("c"),
+        "cpp" | "hpp" | "cc" | "cxx" | "hh" | "hxx" => Some("cpp"),
         _ => None,
     }
 }
 
-/// Parse file and extract entity names using mu-core
-fn extract_entities(content: &str, file_path: &str, language: &str) -> Vec<(String, String)> {
+/// An entity in one version of a file
+#[derive(Debug, Clone)]
+struct Entity {
+    name: String,
+    entity_type: String,
+    line_start: u32,
+    line_end: u32,
+}
+
+impl Entity {
+    fn new(name: String, entity_type: &str, line_start: u32, line_end: u32) -> Self {
+        Self {
+            name,
+            entity_type: entity_type.to_string(),
+            line_start,
+            line_end,
+        }
+    }
+
+    fn key(&self) -> (&str, &str) {
+        (&self.name, &self.entity_type)
+    }
+}
+
+/// Parse file and extract entities using mu-core
+fn extract_entities(content: &str, file_path: &str, language: &str) -> Vec<Entity> {
     // Use mu_core to parse and extract entities
     let result = mu_core::parser::parse_source(content, file_path, language);
 
@@ -280,20 +377,37 @@ fn extract_entities(content: &str, file_path: &str, language: &str) -> Vec<(Stri
     let mut entities = Vec::new();
 
     // Add module itself
-    entities.push((module.name.clone(), "module".to_string()));
+    entities.push(Entity::new(
+        module.name.clone(),
+        "module",
+        1,
+        module.total_lines.max(1),
+    ));
 
     // Add functions
     for func in &module.functions {
-        entities.push((func.name.clone(), "function".to_string()));
+        entities.push(Entity::new(
+            func.name.clone(),
+            "function",
+            func.start_line,
+            func.end_line,
+        ));
     }
 
     // Add classes and their methods
     for class in &module.classes {
-        entities.push((class.name.clone(), "class".to_string()));
+        entities.push(Entity::new(
+            class.name.clone(),
+            "class",
+            class.start_line,
+            class.end_line,
+        ));
         for method in &class.methods {
-            entities.push((
+            entities.push(Entity::new(
                 format!("{}.{}", class.name, method.name),
-                "method".to_string(),
+                "method",
+                method.start_line,
+                method.end_line,
             ));
         }
     }
@@ -303,25 +417,27 @@ fn extract_entities(content: &str, file_path: &str, language: &str) -> Vec<(Stri
 
 /// Compare entities between base and head versions
 fn diff_entities(
-    base_entities: &[(String, String)],
-    head_entities: &[(String, String)],
+    base_entities: &[Entity],
+    head_entities: &[Entity],
     file_path: &str,
 ) -> Vec<SemanticChange> {
     let mut changes = Vec::new();
 
-    let base_set: std::collections::HashSet<(&String, &String)> =
-        base_entities.iter().map(|(a, b)| (a, b)).collect();
-    let head_set: std::collections::HashSet<(&String, &String)> =
-        head_entities.iter().map(|(a, b)| (a, b)).collect();
+    let base_set: std::collections::HashSet<(&str, &str)> =
+        base_entities.iter().map(Entity::key).collect();
+    let head_set: std::collections::HashSet<(&str, &str)> =
+        head_entities.iter().map(Entity::key).collect();
 
     // Find added entities
-    for (name, entity_type) in head_entities {
-        if !base_set.contains(&(name, entity_type)) {
+    for entity in head_entities {
+        if !base_set.contains(&entity.key()) {
             changes.push(SemanticChange {
                 change_type: "added".to_string(),
-                entity_type: entity_type.clone(),
-                entity_name: name.clone(),
+                entity_type: entity.entity_type.clone(),
+                entity_name: entity.name.clone(),
                 file_path: Some(file_path.to_string()),
+                line_start: Some(entity.line_start),
+                line_end: Some(entity.line_end),
                 is_breaking: false,
                 description: None,
             });
@@ -329,17 +445,23 @@ fn diff_entities(
     }
 
     // Find removed entities
-    for (name, entity_type) in base_entities {
-        if !head_set.contains(&(name, entity_type)) {
-            let is_breaking = matches!(entity_type.as_str(), "function" | "class" | "method");
+    for entity in base_entities {
+        if !head_set.contains(&entity.key()) {
+            let is_breaking =
+                matches!(entity.entity_type.as_str(), "function" | "class" | "method");
             changes.push(SemanticChange {
                 change_type: "removed".to_string(),
-                entity_type: entity_type.clone(),
-                entity_name: name.clone(),
+                entity_type: entity.entity_type.clone(),
+                entity_name: entity.name.clone(),
                 file_path: Some(file_path.to_string()),
+                line_start: Some(entity.line_start),
+                line_end: Some(entity.line_end),
                 is_breaking,
                 description: if is_breaking {
-                    Some(format!("Removed {} may break dependents", entity_type))
+                    Some(format!(
+                        "Removed {} may break dependents",
+                        entity.entity_type
+                    ))
                 } else {
                     None
                 },
@@ -351,7 +473,12 @@ fn diff_entities(
 }
 
 /// Run the diff command
-pub async fn run(base_ref: &str, head_ref: &str, format: OutputFormat) -> anyhow::Result<()> {
+pub async fn run(
+    base_ref: &str,
+    head_ref: &str,
+    fail_on: Option<WarningLevel>,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
     let start = Instant::now();
 
     // Get changed files
@@ -387,12 +514,14 @@ pub async fn run(base_ref: &str, head_ref: &str, format: OutputFormat) -> anyhow
             (None, Some(head)) => {
                 // New file
                 let entities = extract_entities(head, file_path, language);
-                for (name, entity_type) in entities {
+                for entity in entities {
                     all_changes.push(SemanticChange {
                         change_type: "added".to_string(),
-                        entity_type,
-                        entity_name: name,
+                        entity_type: entity.entity_type,
+                        entity_name: entity.name,
                         file_path: Some(file_path.clone()),
+                        line_start: Some(entity.line_start),
+                        line_end: Some(entity.line_end),
                         is_breaking: false,
                         description: Some("New file".to_string()),
                     });
@@ -405,6 +534,8 @@ pub async fn run(base_ref: &str, head_ref: &str, format: OutputFormat) -> anyhow
                     entity_type: "module".to_string(),
                     entity_name: file_path.clone(),
                     file_path: Some(file_path.clone()),
+                    line_start: None,
+                    line_end: None,
                     is_breaking: true,
                     description: Some("File deleted".to_string()),
                 });
@@ -440,7 +571,10 @@ pub async fn run(base_ref: &str, head_ref: &str, format: OutputFormat) -> anyhow
         duration_ms,
     };
 
-    Output::new(result, format).render()
+    let levels: Vec<WarningLevel> = result.changes.iter().map(SemanticChange::level).collect();
+    Output::new(result, format).render()?;
+    gate::exit_on_findings(levels, fail_on);
+    Ok(())
 }
 
 #[cfg(test)]
@@ -457,14 +591,10 @@ mod tests {
 
     #[test]
     fn test_diff_entities() {
-        let base = vec![
-            ("foo".to_string(), "function".to_string()),
-            ("bar".to_string(), "function".to_string()),
-        ];
-        let head = vec![
-            ("foo".to_string(), "function".to_string()),
-            ("baz".to_string(), "function".to_string()),
-        ];
+        let function =
+            |name: &str, line: u32| Entity::new(name.to_string(), "function", line, line + 1);
+        let base = vec![function("foo", 1), function("bar", 4)];
+        let head = vec![function("foo", 1), function("baz", 7)];
 
         let changes = diff_entities(&base, &head, "test.py");
 
@@ -486,5 +616,43 @@ mod tests {
         assert_eq!(removed.len(), 1);
         assert_eq!(removed[0].entity_name, "bar");
         assert!(removed[0].is_breaking);
+
+        assert_eq!(added[0].level(), WarningLevel::Info);
+        assert_eq!(removed[0].level(), WarningLevel::Error);
+
+        // Removals point at the base lines, additions at the head lines
+        assert_eq!(
+            (removed[0].line_start, removed[0].line_end),
+            (Some(4), Some(5))
+        );
+        assert_eq!(added[0].line_start, Some(7));
+    }
+
+    #[test]
+    fn test_sarif_lists_breaking_changes() {
+        let base = vec![Entity::new("parse".to_string(), "function", 3, 9)];
+        let changes = diff_entities(&base, &[], "src/app.py");
+        let result = DiffResult {
+            base_ref: "main".to_string(),
+            head_ref: "HEAD".to_string(),
+            breaking_changes: changes.clone(),
+            changes,
+            files_changed: 1,
+            duration_ms: 0,
+        };
+
+        let config = OutputConfig::new(OutputFormat::Sarif);
+        let log: serde_json::Value = serde_json::from_str(&result.to_sarif(&config)).unwrap();
+        let sarif = &log["runs"][0]["results"][0];
+        assert_eq!(sarif["ruleId"], "breaking-change");
+        assert_eq!(sarif["level"], "error");
+        assert_eq!(
+            sarif["message"]["text"],
+            "parse: Removed function may break dependents"
+        );
+        let location = &sarif["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/app.py");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["endLine"], 9);
     }
 }
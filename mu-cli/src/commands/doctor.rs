@@ -6,18 +6,43 @@
 //! - Graph statistics
 //! - Embeddings coverage
 //! - MCP configuration
+//! - Parser capabilities per language (`--capabilities`)
+//! - Syntax errors in source files (`--syntax`)
 
-use std::path::{Path, PathBuf};
+use std::path::Path;
 
 use colored::Colorize;
 use duckdb::Connection;
 use serde::Serialize;
 
+use mu_core::parser::{capabilities, ParserCapabilities};
+use mu_core::types::ParseError;
+
+use crate::config::MuConfig;
+use crate::mubase::find_mubase_in;
 use crate::output::{Output, OutputFormat, TableDisplay};
 
 /// Current schema version expected by this CLI
 const CURRENT_SCHEMA_VERSION: &str = "1.0.0";
 
+/// Languages listed in the capability matrix, one entry per extractor
+const PARSER_LANGUAGES: &[&str] = &[
+    "python",
+    "typescript",
+    "javascript",
+    "go",
+    "java",
+    "rust",
+    "csharp",
+    "dart",
+    "scala",
+    "kotlin",
+    "php",
+    "ruby",
+    "c",
+    "cpp",
+];
+
 /// Status of a health check item
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
 pub enum CheckStatus {
@@ -70,11 +95,21 @@ impl CheckItem {
     }
 }
 
+/// A source file with syntax errors
+#[derive(Debug, Clone, Serialize)]
+pub struct FileSyntaxErrors {
+    pub path: String,
+    pub errors: Vec<ParseError>,
+}
+
 /// Result of health check
 #[derive(Debug, Serialize)]
 pub struct DoctorResult {
     pub checks: Vec<CheckItem>,
     pub recommendations: Vec<String>,
+    /// Files with syntax errors (only checked with `--syntax`)
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub syntax_errors: Vec<FileSyntaxErrors>,
 }
 
 impl TableDisplay for DoctorResult {
@@ -93,6 +128,21 @@ impl TableDisplay for DoctorResult {
             ));
         }
 
+        if !self.syntax_errors.is_empty() {
+            output.push_str(&format!("\n{}\n", "Syntax errors:".yellow().bold()));
+            for file in &self.syntax_errors {
+                let first = &file.errors[0];
+                let more = match file.errors.len() {
+                    1 => String::new(),
+                    n => format!(" (+{} more)", n - 1).dimmed().to_string(),
+                };
+                output.push_str(&format!(
+                    "  {}:{}:{} {}{}\n",
+                    file.path, first.line, first.column, first.message, more
+                ));
+            }
+        }
+
         if !self.recommendations.is_empty() {
             output.push_str(&format!("\n{}\n", "Recommendations:".yellow().bold()));
             for rec in &self.recommendations {
@@ -118,6 +168,15 @@ impl TableDisplay for DoctorResult {
             ));
         }
 
+        for file in &self.syntax_errors {
+            for error in &file.errors {
+                lines.push(format!(
+                    "! {}:{}:{} {}",
+                    file.path, error.line, error.column, error.message
+                ));
+            }
+        }
+
         if !self.recommendations.is_empty() {
             lines.push("# recommendations:".to_string());
             for rec in &self.recommendations {
@@ -129,21 +188,6 @@ impl TableDisplay for DoctorResult {
     }
 }
 
-/// Find the mubase database path
-fn find_mubase_path(root: &Path) -> Option<PathBuf> {
-    let new_path = root.join(".mu").join("mubase");
-    if new_path.exists() {
-        return Some(new_path);
-    }
-
-    let legacy_path = root.join(".mubase");
-    if legacy_path.exists() {
-        return Some(legacy_path);
-    }
-
-    None
-}
-
 /// Get file size in human-readable format
 fn format_file_size(bytes: u64) -> String {
     const KB: u64 = 1024;
@@ -267,8 +311,139 @@ fn get_embeddings_stats(conn: &Connection) -> Option<(usize, usize)> {
     Some((embedding_count, node_count))
 }
 
+/// Capabilities of one language extractor
+#[derive(Debug, Serialize)]
+pub struct LanguageCapabilities {
+    pub language: String,
+    #[serde(flatten)]
+    pub capabilities: ParserCapabilities,
+}
+
+/// What each language parser extracts
+#[derive(Debug, Serialize)]
+pub struct CapabilityMatrix {
+    pub languages: Vec<LanguageCapabilities>,
+}
+
+impl CapabilityMatrix {
+    fn collect() -> Self {
+        let languages = PARSER_LANGUAGES
+            .iter()
+            .filter_map(|lang| {
+                capabilities(lang).map(|capabilities| LanguageCapabilities {
+                    language: lang.to_string(),
+                    capabilities,
+                })
+            })
+            .collect();
+        Self { languages }
+    }
+}
+
+impl TableDisplay for CapabilityMatrix {
+    fn to_table(&self) -> String {
+        let mut output = String::new();
+
+        output.push_str(&format!("{}\n", "Parser Capabilities".cyan().bold()));
+        output.push_str(&format!("{}\n", "\u{2500}".repeat(40).dimmed()));
+
+        for lang in &self.languages {
+            let flags: Vec<String> = lang
+                .capabilities
+                .flags()
+                .iter()
+                .map(|(name, supported)| {
+                    if *supported {
+                        name.green().to_string()
+                    } else {
+                        name.dimmed().strikethrough().to_string()
+                    }
+                })
+                .collect();
+            output.push_str(&format!(
+                "{:<12} {}\n",
+                lang.language.bold(),
+                flags.join(" ")
+            ));
+        }
+
+        output
+    }
+
+    fn to_mu(&self) -> String {
+        let mut lines = vec![":: capabilities".to_string()];
+
+        for lang in &self.languages {
+            let missing = lang.capabilities.missing();
+            if missing.is_empty() {
+                lines.push(format!("# {}: full", lang.language));
+            } else {
+                lines.push(format!(
+                    "# {}: missing {}",
+                    lang.language,
+                    missing.join(", ")
+                ));
+            }
+        }
+
+        lines.join("\n")
+    }
+}
+
+/// Parse every source file under `root` the way bootstrap would, returning
+/// how many files were checked and those with syntax errors, by path.
+fn check_syntax(root: &Path) -> anyhow::Result<(usize, Vec<FileSyntaxErrors>)> {
+    let config = MuConfig::load(root);
+    let mut scan_options = mu_core::scanner::ScanOptions::new()
+        .with_ignore_patterns(config.ignore_patterns())
+        .include_hidden(config.scanner.include_hidden)
+        .respect_gitignore(config.scanner.respect_gitignore)
+        .skip_generated(config.scanner.skip_generated);
+    if let Some(languages) = config.languages() {
+        scan_options = scan_options.with_languages(languages.to_vec());
+    }
+    let scan_result = mu_core::scanner::scan_with_options(&root.to_string_lossy(), scan_options)
+        .map_err(|e| anyhow::anyhow!(e))?;
+
+    let file_infos: Vec<mu_core::types::FileInfo> = scan_result
+        .files
+        .iter()
+        .filter_map(|f| {
+            let content = std::fs::read(root.join(&f.path)).ok()?;
+            let (source, _) = mu_core::parser::decode_source(&content);
+            Some(mu_core::types::FileInfo::new(
+                f.path.clone(),
+                source.into_owned(),
+                f.language.clone(),
+            ))
+        })
+        .collect();
+    let paths: Vec<String> = file_infos.iter().map(|info| info.path.clone()).collect();
+
+    let mut files: Vec<FileSyntaxErrors> = paths
+        .into_iter()
+        .zip(mu_core::parser::parse_files_parallel(file_infos, None))
+        .filter(|(_, result)| !result.errors.is_empty())
+        .map(|(path, result)| FileSyntaxErrors {
+            path,
+            errors: result.errors,
+        })
+        .collect();
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok((scan_result.files.len(), files))
+}
+
 /// Run the doctor command
-pub async fn run(path: &str, format: OutputFormat) -> anyhow::Result<()> {
+pub async fn run(
+    path: &str,
+    show_capabilities: bool,
+    syntax: bool,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
+    if show_capabilities {
+        return Output::new(CapabilityMatrix::collect(), format).render();
+    }
+
     let root = Path::new(path)
         .canonicalize()
         .unwrap_or_else(|_| Path::new(path).to_path_buf());
@@ -277,7 +452,7 @@ pub async fn run(path: &str, format: OutputFormat) -> anyhow::Result<()> {
     let mut recommendations = Vec::new();
 
     // Check 1: Database existence
-    let mubase_path = find_mubase_path(&root);
+    let mubase_path = find_mubase_in(&root);
     match &mubase_path {
         Some(path) => {
             // Get file size
@@ -413,9 +588,37 @@ pub async fn run(path: &str, format: OutputFormat) -> anyhow::Result<()> {
         }
     }
 
+    // Check 7: Parsers
+    checks.push(CheckItem::ok(
+        "Parsers",
+        format!(
+            "{} languages (mu doctor --capabilities for details)",
+            PARSER_LANGUAGES.len()
+        ),
+    ));
+
+    // Check 8: Syntax errors (opt-in, parses the whole codebase)
+    let mut syntax_errors = Vec::new();
+    if syntax {
+        let (checked, files) = check_syntax(&root)?;
+        if files.is_empty() {
+            checks.push(CheckItem::ok(
+                "Syntax",
+                format!("no errors in {} files", checked),
+            ));
+        } else {
+            checks.push(CheckItem::warning(
+                "Syntax",
+                format!("errors in {} of {} files", files.len(), checked),
+            ));
+        }
+        syntax_errors = files;
+    }
+
     let result = DoctorResult {
         checks,
         recommendations,
+        syntax_errors,
     };
 
     Output::new(result, format).render()
@@ -488,4 +691,20 @@ mod tests {
         assert_eq!(msg, "newer than CLI");
         assert!(!is_ok);
     }
+
+    #[test]
+    fn test_capability_matrix_lists_every_parser() {
+        let matrix = CapabilityMatrix::collect();
+        assert_eq!(matrix.languages.len(), PARSER_LANGUAGES.len());
+
+        let mu = matrix.to_mu();
+        assert!(mu.starts_with(":: capabilities"));
+        assert!(
+            mu.contains("# go: missing dynamic_imports, decorators, docstrings, async_functions")
+        );
+
+        let json = serde_json::to_value(&matrix).unwrap();
+        assert_eq!(json["languages"][0]["language"], "python");
+        assert_eq!(json["languages"][0]["docstrings"], true);
+    }
 }
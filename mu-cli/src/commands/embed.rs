@@ -178,6 +178,17 @@ impl TableDisplay for EmbedStatusResult {
     }
 }
 
+/// Load the embedding model used to index code nodes
+///
+/// Long functions are embedded as the mean of overlapping windows rather
+/// than truncated, so search still matches code near their end.
+pub fn load_index_model() -> mu_embeddings::Result<mu_embeddings::MuSigmaModel> {
+    mu_embeddings::MuSigmaModel::embedded_with_config(mu_embeddings::ModelConfig {
+        chunk_overflow: true,
+        ..mu_embeddings::ModelConfig::default()
+    })
+}
+
 /// Compute blake3 hash of a file's content
 pub fn compute_file_hash(path: &Path) -> anyhow::Result<String> {
     let content = fs::read(path)?;
@@ -215,8 +226,7 @@ pub async fn run_incremental(path: &str, force: bool, format: OutputFormat) -> a
     }
 
     // Check if mubase exists
-    let mu_dir = root.join(".mu");
-    let mubase_path = mu_dir.join("mubase");
+    let mubase_path = crate::mubase::mubase_path(&root);
 
     if !mubase_path.exists() {
         anyhow::bail!(
@@ -293,7 +303,7 @@ pub async fn run_incremental(path: &str, force: bool, format: OutputFormat) -> a
 
     // Step 4: Load embedding model
     spinner.set_message("Loading embedding model...");
-    let model = match mu_embeddings::MuSigmaModel::embedded() {
+    let model = match load_index_model() {
         Ok(m) => m,
         Err(e) => {
             spinner.finish_and_clear();
@@ -328,7 +338,7 @@ pub async fn run_incremental(path: &str, force: bool, format: OutputFormat) -> a
         .collect();
 
     let total_to_embed = nodes_to_embed.len();
-    let batch_size = 32;
+    let batch_size = model.config().batch_size;
 
     for (batch_idx, batch) in nodes_to_embed.chunks(batch_size).enumerate() {
         spinner.set_message(format!(
@@ -434,8 +444,7 @@ pub async fn run_status(path: &str, format: OutputFormat) -> anyhow::Result<()>
     }
 
     // Check if mubase exists
-    let mu_dir = root.join(".mu");
-    let mubase_path = mu_dir.join("mubase");
+    let mubase_path = crate::mubase::mubase_path(&root);
 
     if !mubase_path.exists() {
         anyhow::bail!(
@@ -6,47 +6,24 @@
 //! - mermaid: Mermaid diagram syntax
 //! - d2: D2 diagram syntax
 //! - cytoscape: Cytoscape.js JSON format
+//! - dot: Graphviz DOT syntax
+//! - graphml: GraphML XML (Gephi, yEd)
+//! - plantuml: PlantUML class diagram
+//! - jsonl: JSON Lines, one node or edge per line, streamed to the output
+//!
+//! `--node X` exports the matching nodes and their direct edges; adding
+//! `--depth N` exports everything within N hops of them instead.
 
+use crate::commands::graph::GraphData;
+use crate::mubase::find_mubase;
 use crate::output::{Output, OutputFormat, TableDisplay};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use duckdb::Connection;
 use serde::Serialize;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::io::Write;
-use std::path::PathBuf;
-
-/// Find the MUbase database in the given directory or its parents.
-fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize()?;
-    let mut current = start.as_path();
-
-    loop {
-        // New standard path: .mu/mubase
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Ok(db_path);
-        }
-
-        // Legacy path: .mubase
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Ok(legacy_path);
-        }
-
-        // Move up to parent
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "No MUbase found. Run 'mu bootstrap' first to create the database."
-                ))
-            }
-        }
-    }
-}
+use std::io::{self, BufWriter, Write};
 
 /// Export format
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -56,6 +33,10 @@ pub enum ExportFormat {
     Mermaid,
     D2,
     Cytoscape,
+    Dot,
+    GraphMl,
+    PlantUml,
+    Jsonl,
 }
 
 impl ExportFormat {
@@ -66,6 +47,10 @@ impl ExportFormat {
             "mermaid" => Some(Self::Mermaid),
             "d2" => Some(Self::D2),
             "cytoscape" => Some(Self::Cytoscape),
+            "dot" => Some(Self::Dot),
+            "graphml" => Some(Self::GraphMl),
+            "plantuml" => Some(Self::PlantUml),
+            "jsonl" => Some(Self::Jsonl),
             _ => None,
         }
     }
@@ -77,6 +62,10 @@ impl ExportFormat {
             Self::Mermaid => "mermaid",
             Self::D2 => "d2",
             Self::Cytoscape => "cytoscape",
+            Self::Dot => "dot",
+            Self::GraphMl => "graphml",
+            Self::PlantUml => "plantuml",
+            Self::Jsonl => "jsonl",
         }
     }
 }
@@ -172,14 +161,9 @@ fn load_nodes_by_ids(conn: &Connection, ids: &[String]) -> Result<Vec<GraphNode>
         return Ok(Vec::new());
     }
 
-    let id_list: Vec<String> = ids
-        .iter()
-        .map(|id| format!("'{}'", id.replace('\'', "''")))
-        .collect();
-
     let sql = format!(
         "SELECT id, name, type, file_path, complexity FROM nodes WHERE id IN ({})",
-        id_list.join(",")
+        sql_list(ids)
     );
 
     let mut stmt = conn.prepare(&sql)?;
@@ -199,21 +183,40 @@ fn load_nodes_by_ids(conn: &Connection, ids: &[String]) -> Result<Vec<GraphNode>
     Ok(nodes)
 }
 
-/// Load edges from the database
-fn load_edges(conn: &Connection, node_ids: Option<&[String]>) -> Result<Vec<GraphEdge>> {
-    let sql = if let Some(ids) = node_ids {
-        let id_list: Vec<String> = ids
-            .iter()
-            .map(|id| format!("'{}'", id.replace('\'', "''")))
-            .collect();
-        format!(
-            "SELECT source_id, target_id, type FROM edges WHERE source_id IN ({}) OR target_id IN ({})",
-            id_list.join(","),
-            id_list.join(",")
-        )
-    } else {
-        "SELECT source_id, target_id, type FROM edges".to_string()
-    };
+/// Quote values for a SQL `IN (...)` list
+fn sql_list(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|v| format!("'{}'", v.replace('\'', "''")))
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// Load edges from the database, optionally only those touching `node_ids`
+/// and only those of the given types
+fn load_edges(
+    conn: &Connection,
+    node_ids: Option<&[String]>,
+    edge_types: Option<&[String]>,
+) -> Result<Vec<GraphEdge>> {
+    let mut conditions = Vec::new();
+    if let Some(ids) = node_ids {
+        let id_list = sql_list(ids);
+        conditions.push(format!(
+            "(source_id IN ({}) OR target_id IN ({}))",
+            id_list, id_list
+        ));
+    }
+    if let Some(types) = edge_types {
+        let types: Vec<String> = types.iter().map(|t| t.to_lowercase()).collect();
+        conditions.push(format!("type IN ({})", sql_list(&types)));
+    }
+
+    let mut sql = "SELECT source_id, target_id, type FROM edges".to_string();
+    if !conditions.is_empty() {
+        sql.push_str(" WHERE ");
+        sql.push_str(&conditions.join(" AND "));
+    }
 
     let mut stmt = conn.prepare(&sql)?;
     let mut rows = stmt.query([])?;
@@ -230,6 +233,34 @@ fn load_edges(conn: &Connection, node_ids: Option<&[String]>) -> Result<Vec<Grap
     Ok(edges)
 }
 
+/// IDs within `depth` hops of the seeds, following the same traversal as
+/// `mu impact` and `mu ancestors` (edges in either direction). Seeds come
+/// first, then reached nodes by depth and ID.
+fn neighborhood(
+    graph: &GraphData,
+    seeds: &[String],
+    edge_types: Option<&[String]>,
+    depth: u8,
+) -> Vec<String> {
+    let mut reached: Vec<_> = seeds
+        .iter()
+        .flat_map(|seed| {
+            let mut around = graph.impact(seed, edge_types, Some(depth));
+            around.extend(graph.ancestors(seed, edge_types, Some(depth)));
+            around
+        })
+        .collect();
+    reached.sort_by(|a, b| a.depth.cmp(&b.depth).then_with(|| a.id.cmp(&b.id)));
+
+    let mut seen = HashSet::new();
+    seeds
+        .iter()
+        .cloned()
+        .chain(reached.into_iter().map(|r| r.id))
+        .filter(|id| seen.insert(id.clone()))
+        .collect()
+}
+
 /// Export to MU sigil format
 fn export_mu_format(nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
     let mut output = String::new();
@@ -256,16 +287,22 @@ fn export_mu_format(nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
         output.push('\n');
     }
 
-    // Export classes
-    if let Some(classes) = by_type.get("class") {
-        output.push_str("## Classes\n");
-        for node in classes {
-            output.push_str(&format!("$ {}\n", node.name));
-            if let Some(ref path) = node.file_path {
-                output.push_str(&format!("  | {}\n", path));
+    // Export classes, interfaces and enums
+    for (node_type, heading) in [
+        ("class", "Classes"),
+        ("interface", "Interfaces"),
+        ("enum", "Enums"),
+    ] {
+        if let Some(types) = by_type.get(node_type) {
+            output.push_str(&format!("## {}\n", heading));
+            for node in types {
+                output.push_str(&format!("$ {}\n", node.name));
+                if let Some(ref path) = node.file_path {
+                    output.push_str(&format!("  | {}\n", path));
+                }
             }
+            output.push('\n');
         }
-        output.push('\n');
     }
 
     // Export functions
@@ -345,7 +382,9 @@ fn export_mermaid_format(nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
             let safe_name = node.name.replace('"', "'");
             let shape = match node.node_type.as_str() {
                 "module" => format!("{}[[\"{}  📦 module\"]]", clean_id, safe_name),
-                "class" => format!("{}[/\"{}  📦 class\"/]", clean_id, safe_name),
+                "class" | "interface" | "enum" => {
+                    format!("{}[/\"{}  📦 {}\"/]", clean_id, safe_name, node.node_type)
+                }
                 "function" => format!("{}(\"{}  ⚙️ fn\")", clean_id, safe_name),
                 _ => format!("{}[\"{}\"]", clean_id, safe_name),
             };
@@ -365,7 +404,7 @@ fn export_mermaid_format(nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
             let arrow = match edge.edge_type.as_str() {
                 "imports" => "-->",
                 "calls" => "-.->",
-                "inherits" => "==>",
+                "inherits" | "implements" => "==>",
                 _ => "-->",
             };
             output.push_str(&format!(
@@ -396,7 +435,7 @@ fn export_d2_format(nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
         if let Some(clean_id) = id_map.get(node.id.as_str()) {
             let shape = match node.node_type.as_str() {
                 "module" => "rectangle",
-                "class" => "class",
+                "class" | "interface" | "enum" => "class",
                 "function" => "oval",
                 _ => "rectangle",
             };
@@ -512,23 +551,303 @@ fn export_cytoscape_format(nodes: &[GraphNode], edges: &[GraphEdge]) -> Result<S
         .map_err(|e| anyhow::anyhow!("JSON serialization failed: {}", e))
 }
 
+/// Quote a string as a DOT identifier
+fn dot_quote(s: &str) -> String {
+    let escaped = s
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n");
+    format!("\"{}\"", escaped)
+}
+
+/// Export to Graphviz DOT format
+fn export_dot_format(nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
+    let mut output = String::new();
+
+    output.push_str("digraph mu {\n");
+    output.push_str("    // MU Graph Export\n");
+    output.push_str("    rankdir=LR;\n\n");
+
+    // Node IDs are quoted as-is, so edges can reference them directly
+    let known: std::collections::HashSet<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+
+    for node in nodes {
+        let shape = match node.node_type.as_str() {
+            "module" => "folder",
+            "class" | "interface" | "enum" => "box",
+            "function" => "ellipse",
+            _ => "plaintext",
+        };
+        let mut attrs = vec![
+            format!("label={}", dot_quote(&node.name)),
+            format!("shape={}", shape),
+        ];
+        if let Some(ref path) = node.file_path {
+            attrs.push(format!("tooltip={}", dot_quote(path)));
+        }
+        output.push_str(&format!(
+            "    {} [{}];\n",
+            dot_quote(&node.id),
+            attrs.join(", ")
+        ));
+    }
+
+    output.push('\n');
+
+    for edge in edges {
+        if !known.contains(edge.source.as_str()) || !known.contains(edge.target.as_str()) {
+            continue;
+        }
+        let style = match edge.edge_type.as_str() {
+            "inherits" | "implements" => "dashed",
+            "contains" => "dotted",
+            _ => "solid",
+        };
+        output.push_str(&format!(
+            "    {} -> {} [label={}, style={}];\n",
+            dot_quote(&edge.source),
+            dot_quote(&edge.target),
+            dot_quote(&edge.edge_type),
+            style
+        ));
+    }
+
+    output.push_str("}\n");
+    output
+}
+
+/// Escape text for XML attributes and content
+fn xml_escape(s: &str) -> String {
+    let mut escaped = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&apos;"),
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Export to GraphML format
+fn export_graphml_format(nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
+    let mut output = String::new();
+
+    output.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    output.push_str("<graphml xmlns=\"http://graphml.graphdrawing.org/xmlns\">\n");
+    output.push_str("  <key id=\"type\" for=\"node\" attr.name=\"type\" attr.type=\"string\"/>\n");
+    output.push_str("  <key id=\"name\" for=\"node\" attr.name=\"name\" attr.type=\"string\"/>\n");
+    output.push_str(
+        "  <key id=\"file_path\" for=\"node\" attr.name=\"file_path\" attr.type=\"string\"/>\n",
+    );
+    output.push_str(
+        "  <key id=\"complexity\" for=\"node\" attr.name=\"complexity\" attr.type=\"int\"/>\n",
+    );
+    output.push_str(
+        "  <key id=\"edge_type\" for=\"edge\" attr.name=\"type\" attr.type=\"string\"/>\n",
+    );
+    output.push_str("  <graph id=\"mu\" edgedefault=\"directed\">\n");
+
+    // Edges to nodes outside the export would make the document invalid
+    let known: std::collections::HashSet<&str> = nodes.iter().map(|n| n.id.as_str()).collect();
+
+    for node in nodes {
+        output.push_str(&format!("    <node id=\"{}\">\n", xml_escape(&node.id)));
+        output.push_str(&format!(
+            "      <data key=\"type\">{}</data>\n",
+            xml_escape(&node.node_type)
+        ));
+        output.push_str(&format!(
+            "      <data key=\"name\">{}</data>\n",
+            xml_escape(&node.name)
+        ));
+        if let Some(ref path) = node.file_path {
+            output.push_str(&format!(
+                "      <data key=\"file_path\">{}</data>\n",
+                xml_escape(path)
+            ));
+        }
+        if let Some(complexity) = node.complexity {
+            output.push_str(&format!(
+                "      <data key=\"complexity\">{}</data>\n",
+                complexity
+            ));
+        }
+        output.push_str("    </node>\n");
+    }
+
+    let drawn = edges
+        .iter()
+        .filter(|e| known.contains(e.source.as_str()) && known.contains(e.target.as_str()));
+    for (i, edge) in drawn.enumerate() {
+        output.push_str(&format!(
+            "    <edge id=\"e{}\" source=\"{}\" target=\"{}\">\n",
+            i,
+            xml_escape(&edge.source),
+            xml_escape(&edge.target)
+        ));
+        output.push_str(&format!(
+            "      <data key=\"edge_type\">{}</data>\n",
+            xml_escape(&edge.edge_type)
+        ));
+        output.push_str("    </edge>\n");
+    }
+
+    output.push_str("  </graph>\n");
+    output.push_str("</graphml>\n");
+    output
+}
+
+/// Export to a PlantUML class diagram
+///
+/// Classes, interfaces and enums are grouped into one package per file,
+/// with the methods they contain listed inside. `contains`, `inherits` and
+/// `implements` edges between them become `-->` and `..|>` relationships;
+/// modules and free functions are left out.
+fn export_plantuml_format(nodes: &[GraphNode], edges: &[GraphEdge]) -> String {
+    let mut output = String::new();
+
+    output.push_str("@startuml\n");
+    output.push_str("' MU Graph Export\n\n");
+
+    // PlantUML aliases must be plain identifiers
+    // Sorted so aliases don't depend on the order nodes were loaded in
+    let mut types: Vec<&GraphNode> = nodes
+        .iter()
+        .filter(|n| matches!(n.node_type.as_str(), "class" | "interface" | "enum"))
+        .collect();
+    types.sort_by(|a, b| a.id.cmp(&b.id));
+    let mut alias: HashMap<&str, String> = HashMap::new();
+    for node in &types {
+        alias.insert(&node.id, format!("c{}", alias.len()));
+    }
+
+    let functions: HashMap<&str, &str> = nodes
+        .iter()
+        .filter(|n| n.node_type == "function")
+        .map(|n| (n.id.as_str(), n.name.as_str()))
+        .collect();
+    let mut methods: HashMap<&str, Vec<&str>> = HashMap::new();
+    for edge in edges.iter().filter(|e| e.edge_type == "contains") {
+        if !alias.contains_key(edge.source.as_str()) {
+            continue;
+        }
+        if let Some(name) = functions.get(edge.target.as_str()) {
+            methods.entry(edge.source.as_str()).or_default().push(name);
+        }
+    }
+
+    let mut packages: std::collections::BTreeMap<&str, Vec<&GraphNode>> =
+        std::collections::BTreeMap::new();
+    for node in types {
+        packages
+            .entry(node.file_path.as_deref().unwrap_or(""))
+            .or_default()
+            .push(node);
+    }
+
+    for (path, classes) in &packages {
+        let indent = if path.is_empty() { "" } else { "  " };
+        if !path.is_empty() {
+            output.push_str(&format!("package \"{}\" {{\n", path.replace('"', "'")));
+        }
+        for class in classes {
+            // The node types match PlantUML's element keywords
+            output.push_str(&format!(
+                "{}{} \"{}\" as {} {{\n",
+                indent,
+                class.node_type,
+                class.name.replace('"', "'"),
+                alias[class.id.as_str()]
+            ));
+            for method in methods.get(class.id.as_str()).into_iter().flatten() {
+                output.push_str(&format!("{}  {}()\n", indent, method));
+            }
+            output.push_str(&format!("{}}}\n", indent));
+        }
+        if !path.is_empty() {
+            output.push_str("}\n");
+        }
+    }
+
+    output.push('\n');
+
+    for edge in edges {
+        let (Some(source), Some(target)) = (
+            alias.get(edge.source.as_str()),
+            alias.get(edge.target.as_str()),
+        ) else {
+            continue;
+        };
+        let arrow = match edge.edge_type.as_str() {
+            "inherits" | "implements" => "..|>",
+            "contains" => "-->",
+            _ => continue,
+        };
+        output.push_str(&format!("{} {} {}\n", source, arrow, target));
+    }
+
+    output.push_str("@enduml\n");
+    output
+}
+
+/// One line of JSONL output
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum JsonlRecord<'a> {
+    Node(&'a GraphNode),
+    Edge(&'a GraphEdge),
+}
+
+/// Stream nodes then edges as JSON Lines, one record at a time
+fn export_jsonl_stream<W: Write>(
+    nodes: &[GraphNode],
+    edges: &[GraphEdge],
+    writer: &mut W,
+) -> Result<()> {
+    let records = nodes
+        .iter()
+        .map(JsonlRecord::Node)
+        .chain(edges.iter().map(JsonlRecord::Edge));
+    for record in records {
+        serde_json::to_writer(&mut *writer, &record)?;
+        writer.write_all(b"\n")?;
+    }
+    writer.flush()?;
+    Ok(())
+}
+
 /// Run the export command
 pub async fn run(
     export_format: &str,
     output_path: Option<&str>,
     node_filter: Option<&str>,
+    depth: Option<u8>,
     limit: Option<usize>,
+    edge_types: Option<&[String]>,
     format: OutputFormat,
 ) -> Result<()> {
     // Parse export format
     let exp_format = ExportFormat::from_str(export_format).ok_or_else(|| {
         anyhow::anyhow!(
-            "Unknown export format: {}. Valid formats: mu, json, mermaid, d2, cytoscape",
+            "Unknown export format: {}. Valid formats: mu, json, mermaid, d2, cytoscape, dot, graphml, plantuml, jsonl",
             export_format
         )
     })?;
 
-    run_direct(exp_format, output_path, node_filter, limit, format).await
+    run_direct(
+        exp_format,
+        output_path,
+        node_filter,
+        depth,
+        limit,
+        edge_types,
+        format,
+    )
+    .await
 }
 
 /// Run export command with direct database access
@@ -536,7 +855,9 @@ async fn run_direct(
     exp_format: ExportFormat,
     output_path: Option<&str>,
     node_filter: Option<&str>,
+    depth: Option<u8>,
     limit: Option<usize>,
+    edge_types: Option<&[String]>,
     format: OutputFormat,
 ) -> Result<()> {
     // Find the MUbase database
@@ -556,12 +877,29 @@ async fn run_direct(
 
         if filtered_nodes.is_empty() {
             (filtered_nodes, Vec::new())
+        } else if let Some(depth) = depth {
+            // Expand to the neighborhood and keep only the edges inside it
+            let seeds: Vec<String> = filtered_nodes.iter().map(|n| n.id.clone()).collect();
+            let edge_types: Option<Vec<String>> =
+                edge_types.map(|types| types.iter().map(|t| t.to_lowercase()).collect());
+            let graph = GraphData::from_db(&conn)?;
+            let ids = neighborhood(&graph, &seeds, edge_types.as_deref(), depth);
+
+            let inside: HashSet<&str> = ids.iter().map(|id| id.as_str()).collect();
+            let mut edges = load_edges(&conn, Some(&ids), edge_types.as_deref())?;
+            edges.retain(|e| {
+                inside.contains(e.source.as_str()) && inside.contains(e.target.as_str())
+            });
+
+            let mut all_nodes = filtered_nodes;
+            all_nodes.extend(load_nodes_by_ids(&conn, &ids[seeds.len()..])?);
+            (all_nodes, edges)
         } else {
             // Get IDs of filtered nodes
             let filtered_ids: Vec<String> = filtered_nodes.iter().map(|n| n.id.clone()).collect();
 
             // Load edges involving filtered nodes
-            let edges = load_edges(&conn, Some(&filtered_ids))?;
+            let edges = load_edges(&conn, Some(&filtered_ids), edge_types)?;
 
             // Find neighbor node IDs from edges (nodes not in our filtered set)
             let existing_ids: std::collections::HashSet<&str> =
@@ -596,11 +934,20 @@ async fn run_direct(
         // Load edges for the (potentially limited) node set
         let node_ids: Vec<String> = nodes.iter().map(|n| n.id.clone()).collect();
         let edges = if limit.is_some() {
-            load_edges(&conn, Some(&node_ids))?
+            load_edges(&conn, Some(&node_ids), edge_types)?
         } else {
-            load_edges(&conn, None)?
+            load_edges(&conn, None, edge_types)?
         };
 
+        // With an edge type filter, keep only the nodes those edges connect
+        if edge_types.is_some() {
+            let connected: std::collections::HashSet<&str> = edges
+                .iter()
+                .flat_map(|e| [e.source.as_str(), e.target.as_str()])
+                .collect();
+            nodes.retain(|n| connected.contains(n.id.as_str()));
+        }
+
         (nodes, edges)
     };
 
@@ -611,6 +958,25 @@ async fn run_direct(
         }
     }
 
+    // JSONL goes straight to the destination without building a String
+    if exp_format == ExportFormat::Jsonl {
+        let Some(path) = output_path else {
+            let stdout = io::stdout();
+            return export_jsonl_stream(&nodes, &edges, &mut BufWriter::new(stdout.lock()));
+        };
+        let file = fs::File::create(path)?;
+        export_jsonl_stream(&nodes, &edges, &mut BufWriter::new(file))?;
+
+        let result = ExportResult {
+            format: exp_format.as_str().to_string(),
+            node_count: nodes.len(),
+            edge_count: edges.len(),
+            output_path: Some(path.to_string()),
+            content: String::new(),
+        };
+        return Output::new(result, format).render();
+    }
+
     // Generate export content
     let content = match exp_format {
         ExportFormat::Mu => export_mu_format(&nodes, &edges),
@@ -618,6 +984,10 @@ async fn run_direct(
         ExportFormat::Mermaid => export_mermaid_format(&nodes, &edges),
         ExportFormat::D2 => export_d2_format(&nodes, &edges),
         ExportFormat::Cytoscape => export_cytoscape_format(&nodes, &edges)?,
+        ExportFormat::Dot => export_dot_format(&nodes, &edges),
+        ExportFormat::GraphMl => export_graphml_format(&nodes, &edges),
+        ExportFormat::PlantUml => export_plantuml_format(&nodes, &edges),
+        ExportFormat::Jsonl => unreachable!("jsonl is streamed above"),
     };
 
     // Write to file or stdout
@@ -661,6 +1031,16 @@ mod tests {
             ExportFormat::from_str("cytoscape"),
             Some(ExportFormat::Cytoscape)
         );
+        assert_eq!(ExportFormat::from_str("dot"), Some(ExportFormat::Dot));
+        assert_eq!(
+            ExportFormat::from_str("graphml"),
+            Some(ExportFormat::GraphMl)
+        );
+        assert_eq!(
+            ExportFormat::from_str("plantuml"),
+            Some(ExportFormat::PlantUml)
+        );
+        assert_eq!(ExportFormat::from_str("jsonl"), Some(ExportFormat::Jsonl));
         assert_eq!(
             ExportFormat::from_str("MERMAID"),
             Some(ExportFormat::Mermaid)
@@ -736,4 +1116,186 @@ mod tests {
         assert!(parsed["elements"]["nodes"].is_array());
         assert_eq!(parsed["elements"]["nodes"][0]["data"]["label"], "test");
     }
+
+    #[test]
+    fn test_dot_export() {
+        let nodes = vec![
+            GraphNode {
+                id: "mod:src/app.py".to_string(),
+                name: "app".to_string(),
+                node_type: "module".to_string(),
+                file_path: Some("src/app.py".to_string()),
+                complexity: None,
+            },
+            GraphNode {
+                id: "cls:src/app.py:Say\"Hi\"".to_string(),
+                name: "Say\"Hi\"".to_string(),
+                node_type: "class".to_string(),
+                file_path: Some("src/app.py".to_string()),
+                complexity: None,
+            },
+        ];
+        let edges = vec![
+            GraphEdge {
+                source: "mod:src/app.py".to_string(),
+                target: "cls:src/app.py:Say\"Hi\"".to_string(),
+                edge_type: "contains".to_string(),
+            },
+            GraphEdge {
+                source: "mod:src/app.py".to_string(),
+                target: "mod:missing".to_string(),
+                edge_type: "imports".to_string(),
+            },
+        ];
+
+        let output = export_dot_format(&nodes, &edges);
+
+        assert!(output.starts_with("digraph mu {"));
+        assert!(output.trim_end().ends_with('}'));
+        assert!(output.contains(r#""mod:src/app.py" [label="app", shape=folder"#));
+        assert!(output.contains(r#""cls:src/app.py:Say\"Hi\"" [label="Say\"Hi\"", shape=box"#));
+        assert!(output.contains(
+            r#""mod:src/app.py" -> "cls:src/app.py:Say\"Hi\"" [label="contains", style=dotted];"#
+        ));
+        // Edges to nodes outside the export are dropped
+        assert!(!output.contains("mod:missing"));
+    }
+
+    #[test]
+    fn test_graphml_export() {
+        let nodes = vec![
+            GraphNode {
+                id: "mod:src/a&b.py".to_string(),
+                name: "a&b".to_string(),
+                node_type: "module".to_string(),
+                file_path: Some("src/a&b.py".to_string()),
+                complexity: None,
+            },
+            GraphNode {
+                id: "fn:src/a&b.py:<lambda>".to_string(),
+                name: "<lambda>".to_string(),
+                node_type: "function".to_string(),
+                file_path: None,
+                complexity: Some(3),
+            },
+        ];
+        let edges = vec![GraphEdge {
+            source: "mod:src/a&b.py".to_string(),
+            target: "fn:src/a&b.py:<lambda>".to_string(),
+            edge_type: "contains".to_string(),
+        }];
+
+        let output = export_graphml_format(&nodes, &edges);
+
+        assert!(output.contains("<graphml xmlns="));
+        assert!(output.contains(r#"<key id="complexity" for="node""#));
+        assert!(output.contains(r#"<node id="mod:src/a&amp;b.py">"#));
+        assert!(output.contains(r#"<data key="name">&lt;lambda&gt;</data>"#));
+        assert!(output.contains(r#"<data key="complexity">3</data>"#));
+        assert!(output.contains(
+            r#"<edge id="e0" source="mod:src/a&amp;b.py" target="fn:src/a&amp;b.py:&lt;lambda&gt;">"#
+        ));
+        assert!(output.contains(r#"<data key="edge_type">contains</data>"#));
+        assert!(output.trim_end().ends_with("</graphml>"));
+    }
+
+    #[test]
+    fn test_plantuml_export() {
+        let node = |id: &str, name: &str, node_type: &str, file_path: &str| GraphNode {
+            id: id.to_string(),
+            name: name.to_string(),
+            node_type: node_type.to_string(),
+            file_path: Some(file_path.to_string()),
+            complexity: None,
+        };
+        let edge = |source: &str, target: &str, edge_type: &str| GraphEdge {
+            source: source.to_string(),
+            target: target.to_string(),
+            edge_type: edge_type.to_string(),
+        };
+        let nodes = vec![
+            node("mod:src/app.py", "app", "module", "src/app.py"),
+            node("cls:src/app.py:App", "App", "class", "src/app.py"),
+            node("fn:src/app.py:App.run", "run", "function", "src/app.py"),
+            node("cls:src/base.py:Base", "Base", "class", "src/base.py"),
+        ];
+        let edges = vec![
+            edge("mod:src/app.py", "cls:src/app.py:App", "contains"),
+            edge("cls:src/app.py:App", "fn:src/app.py:App.run", "contains"),
+            edge("cls:src/app.py:App", "cls:src/base.py:Base", "inherits"),
+            edge("cls:src/app.py:App", "cls:missing:Gone", "inherits"),
+        ];
+
+        let output = export_plantuml_format(&nodes, &edges);
+
+        assert!(output.starts_with("@startuml"));
+        assert!(output.trim_end().ends_with("@enduml"));
+        assert!(
+            output.contains("package \"src/app.py\" {\n  class \"App\" as c0 {\n    run()\n  }\n}")
+        );
+        assert!(output.contains("package \"src/base.py\" {\n  class \"Base\" as c1 {\n  }\n}"));
+        assert!(output.contains("c0 ..|> c1\n"));
+        // Module containment and edges to unknown classes are not drawn
+        assert_eq!(output.matches("-->").count(), 0);
+        assert_eq!(output.matches("..|>").count(), 1);
+    }
+
+    #[test]
+    fn test_plantuml_export_interfaces_and_enums() {
+        let node = |id: &str, name: &str, node_type: &str| GraphNode {
+            id: id.to_string(),
+            name: name.to_string(),
+            node_type: node_type.to_string(),
+            file_path: Some("src/shapes.ts".to_string()),
+            complexity: None,
+        };
+        let nodes = vec![
+            node("cls:src/shapes.ts:Shape", "Shape", "interface"),
+            node("cls:src/shapes.ts:Square", "Square", "class"),
+            node("cls:src/shapes.ts:Color", "Color", "enum"),
+        ];
+        let edges = vec![GraphEdge {
+            source: "cls:src/shapes.ts:Square".to_string(),
+            target: "cls:src/shapes.ts:Shape".to_string(),
+            edge_type: "implements".to_string(),
+        }];
+
+        let output = export_plantuml_format(&nodes, &edges);
+
+        assert!(output.contains("  enum \"Color\" as c0 {\n"), "{}", output);
+        assert!(output.contains("  interface \"Shape\" as c1 {\n"));
+        assert!(output.contains("  class \"Square\" as c2 {\n"));
+        assert!(output.contains("c2 ..|> c1\n"));
+    }
+
+    #[test]
+    fn test_jsonl_export() {
+        let nodes = vec![GraphNode {
+            id: "mod:src/app.py".to_string(),
+            name: "app".to_string(),
+            node_type: "module".to_string(),
+            file_path: Some("src/app.py".to_string()),
+            complexity: None,
+        }];
+        let edges = vec![GraphEdge {
+            source: "mod:src/app.py".to_string(),
+            target: "mod:src/util.py".to_string(),
+            edge_type: "imports".to_string(),
+        }];
+
+        let mut buffer = Vec::new();
+        export_jsonl_stream(&nodes, &edges, &mut buffer).unwrap();
+        let output = String::from_utf8(buffer).unwrap();
+        let lines: Vec<serde_json::Value> = output
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["kind"], "node");
+        assert_eq!(lines[0]["id"], "mod:src/app.py");
+        assert_eq!(lines[1]["kind"], "edge");
+        assert_eq!(lines[1]["edge_type"], "imports");
+        assert!(output.ends_with('\n'));
+    }
 }
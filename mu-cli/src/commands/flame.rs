@@ -0,0 +1,354 @@
+//! Flame command - Export a call tree for flamegraph tools
+//!
+//! `mu flame <node>` walks `calls` edges depth-first from a function and
+//! emits a hierarchical `{name, value, children}` tree as consumed by
+//! d3-flamegraph (`--format json`), or folded stacks for `flamegraph.pl`
+//! (`--folded`).
+//!
+//! Each frame is weighted by its cyclomatic complexity (minimum 1, so trivial
+//! functions stay visible) and a frame's `value` includes all of its callees.
+//! A callee already on the current stack is emitted as a leaf marked
+//! `recursive`; frames at the depth limit that still have callees are marked
+//! `truncated`. Shared callees are expanded under every caller, so a tree
+//! stops growing at [`MAX_FRAMES`] frames and the frames whose callees were
+//! cut off are marked `truncated` too.
+
+use crate::commands::graph::{open_db, resolve_node};
+use crate::output::{Output, OutputFormat, TableDisplay};
+use anyhow::Result;
+use colored::Colorize;
+use duckdb::Connection;
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// Most frames in one flame tree. Call graphs with shared callees expand
+/// exponentially with depth, and flamegraph tools choke long before this.
+pub const MAX_FRAMES: usize = 10_000;
+
+/// A single frame in the flame tree.
+#[derive(Debug, Serialize)]
+pub struct FlameNode {
+    pub name: String,
+    pub id: String,
+    /// Inclusive weight: this frame's complexity plus all of its children
+    pub value: u64,
+    pub children: Vec<FlameNode>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub recursive: bool,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    pub truncated: bool,
+    /// Exclusive weight, used for folded stacks
+    #[serde(skip)]
+    pub self_value: u64,
+}
+
+impl FlameNode {
+    /// Render as folded stacks (`root;child;grandchild weight`).
+    pub fn folded(&self) -> Vec<String> {
+        let mut lines = Vec::new();
+        let mut stack = Vec::new();
+        self.fold_into(&mut stack, &mut lines);
+        lines
+    }
+
+    fn fold_into<'a>(&'a self, stack: &mut Vec<&'a str>, lines: &mut Vec<String>) {
+        stack.push(&self.name);
+        lines.push(format!("{} {}", stack.join(";"), self.self_value));
+        for child in &self.children {
+            child.fold_into(stack, lines);
+        }
+        stack.pop();
+    }
+
+    fn write_tree(&self, depth: usize, output: &mut String) {
+        let mut marker = String::new();
+        if self.recursive {
+            marker.push_str(&format!(" {}", "[recursive]".yellow()));
+        }
+        if self.truncated {
+            marker.push_str(&format!(" {}", "[truncated]".dimmed()));
+        }
+        output.push_str(&format!(
+            "{}{} {}{}\n",
+            "  ".repeat(depth),
+            self.name.cyan(),
+            format!("({})", self.value).dimmed(),
+            marker
+        ));
+        for child in &self.children {
+            child.write_tree(depth + 1, output);
+        }
+    }
+}
+
+impl TableDisplay for FlameNode {
+    fn to_table(&self) -> String {
+        let mut output = String::new();
+        self.write_tree(0, &mut output);
+        output
+    }
+
+    fn to_mu(&self) -> String {
+        let mut lines = vec![format!(":: flame {}", self.id)];
+        lines.extend(self.folded());
+        lines.join("\n")
+    }
+}
+
+/// The `calls` subgraph with per-node complexity.
+pub struct CallGraph {
+    names: HashMap<String, String>,
+    complexity: HashMap<String, u64>,
+    callees: HashMap<String, Vec<String>>,
+}
+
+impl CallGraph {
+    /// Load call edges and node complexity from the database
+    pub fn from_db(conn: &Connection) -> Result<Self> {
+        let mut names = HashMap::new();
+        let mut complexity = HashMap::new();
+
+        let mut stmt = conn.prepare("SELECT id, name, COALESCE(complexity, 0) FROM nodes")?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let id: String = row.get(0)?;
+            let name: String = row.get(1)?;
+            let value: i64 = row.get(2)?;
+            complexity.insert(id.clone(), value.max(0) as u64);
+            names.insert(id, name);
+        }
+
+        let mut callees: HashMap<String, Vec<String>> = HashMap::new();
+        let mut stmt = conn.prepare(
+            "SELECT DISTINCT source_id, target_id FROM edges WHERE type = 'calls' ORDER BY target_id",
+        )?;
+        let mut rows = stmt.query([])?;
+        while let Some(row) = rows.next()? {
+            let source: String = row.get(0)?;
+            let target: String = row.get(1)?;
+            if names.contains_key(&target) {
+                callees.entry(source).or_default().push(target);
+            }
+        }
+
+        Ok(Self {
+            names,
+            complexity,
+            callees,
+        })
+    }
+
+    /// Build the flame tree rooted at `root`, descending at most `max_depth`
+    /// calls and emitting at most [`MAX_FRAMES`] frames.
+    pub fn flame(&self, root: &str, max_depth: usize) -> FlameNode {
+        let mut stack = Vec::new();
+        let mut frames_left = MAX_FRAMES - 1;
+        self.visit(root, max_depth, &mut stack, &mut frames_left)
+    }
+
+    fn visit<'a>(
+        &'a self,
+        id: &'a str,
+        depth_left: usize,
+        stack: &mut Vec<&'a str>,
+        frames_left: &mut usize,
+    ) -> FlameNode {
+        let self_value = self.complexity.get(id).copied().unwrap_or(0).max(1);
+        let callees = self.callees.get(id).map(Vec::as_slice).unwrap_or(&[]);
+        let mut node = FlameNode {
+            name: self
+                .names
+                .get(id)
+                .cloned()
+                .unwrap_or_else(|| id.to_string()),
+            id: id.to_string(),
+            value: self_value,
+            children: Vec::new(),
+            recursive: false,
+            truncated: false,
+            self_value,
+        };
+
+        if stack.contains(&id) {
+            node.recursive = true;
+            return node;
+        }
+        if depth_left == 0 {
+            node.truncated = !callees.is_empty();
+            return node;
+        }
+
+        stack.push(id);
+        for callee in callees {
+            if *frames_left == 0 {
+                node.truncated = true;
+                break;
+            }
+            *frames_left -= 1;
+            let child = self.visit(callee, depth_left - 1, stack, frames_left);
+            node.value += child.value;
+            node.children.push(child);
+        }
+        stack.pop();
+
+        node
+    }
+}
+
+/// Run the flame command
+pub async fn run(
+    node: &str,
+    prefer: Option<&str>,
+    depth: usize,
+    folded: bool,
+    format: OutputFormat,
+) -> Result<()> {
+    let conn = open_db()?;
+    let node_id = resolve_node(&conn, node, prefer)?;
+    let graph = CallGraph::from_db(&conn)?;
+    let flame = graph.flame(&node_id, depth);
+
+    if folded {
+        println!("{}", flame.folded().join("\n"));
+        return Ok(());
+    }
+
+    Output::new(flame, format).render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use duckdb::params;
+    use tempfile::TempDir;
+
+    fn seeded_conn(dir: &TempDir, nodes: &[(&str, i64)], calls: &[(&str, &str)]) -> Connection {
+        let conn = Connection::open(dir.path().join("test.mubase")).unwrap();
+        conn.execute_batch(
+            r#"
+            CREATE TABLE nodes (
+                id VARCHAR PRIMARY KEY,
+                type VARCHAR NOT NULL,
+                name VARCHAR NOT NULL,
+                file_path VARCHAR,
+                complexity INTEGER DEFAULT 0
+            );
+            CREATE TABLE edges (
+                id VARCHAR PRIMARY KEY,
+                source_id VARCHAR NOT NULL,
+                target_id VARCHAR NOT NULL,
+                type VARCHAR NOT NULL
+            );
+            "#,
+        )
+        .unwrap();
+
+        for (name, complexity) in nodes {
+            conn.execute(
+                "INSERT INTO nodes (id, type, name, file_path, complexity) VALUES (?, 'function', ?, 'app.py', ?)",
+                params![format!("fn:app.py:{}", name), name, complexity],
+            )
+            .unwrap();
+        }
+        for (i, (from, to)) in calls.iter().enumerate() {
+            conn.execute(
+                "INSERT INTO edges (id, source_id, target_id, type) VALUES (?, ?, ?, 'calls')",
+                params![
+                    format!("e{}", i),
+                    format!("fn:app.py:{}", from),
+                    format!("fn:app.py:{}", to)
+                ],
+            )
+            .unwrap();
+        }
+        conn
+    }
+
+    #[test]
+    fn test_flame_root_with_two_callees() {
+        let dir = TempDir::new().unwrap();
+        let conn = seeded_conn(
+            &dir,
+            &[("main", 5), ("load", 3), ("save", 0)],
+            &[("main", "load"), ("main", "save")],
+        );
+
+        let flame = CallGraph::from_db(&conn)
+            .unwrap()
+            .flame("fn:app.py:main", 10);
+
+        assert_eq!(flame.name, "main");
+        assert_eq!(flame.children.len(), 2);
+        assert_eq!(flame.children[0].name, "load");
+        assert_eq!(flame.children[0].value, 3);
+        // Zero-complexity frames still weigh 1 so they show up
+        assert_eq!(flame.children[1].name, "save");
+        assert_eq!(flame.children[1].value, 1);
+        assert_eq!(flame.value, 5 + 3 + 1);
+
+        assert_eq!(flame.folded(), vec!["main 5", "main;load 3", "main;save 1"]);
+    }
+
+    #[test]
+    fn test_flame_marks_recursion_and_truncation() {
+        let dir = TempDir::new().unwrap();
+        let conn = seeded_conn(
+            &dir,
+            &[("walk", 2), ("visit", 1), ("leaf", 1)],
+            &[("walk", "visit"), ("visit", "walk"), ("visit", "leaf")],
+        );
+        let graph = CallGraph::from_db(&conn).unwrap();
+
+        let flame = graph.flame("fn:app.py:walk", 10);
+        let visit = &flame.children[0];
+        assert_eq!(visit.children.len(), 2);
+        let back_edge = visit.children.iter().find(|c| c.name == "walk").unwrap();
+        assert!(back_edge.recursive);
+        assert!(back_edge.children.is_empty());
+
+        let shallow = graph.flame("fn:app.py:walk", 1);
+        assert!(shallow.children[0].truncated);
+        assert!(shallow.children[0].children.is_empty());
+        assert_eq!(shallow.value, 3);
+    }
+
+    #[test]
+    fn test_flame_caps_layered_diamonds() {
+        fn frames(node: &FlameNode) -> usize {
+            1 + node.children.iter().map(frames).sum::<usize>()
+        }
+
+        // Ten layers of three functions, each calling all of the next layer:
+        // 3^10 paths at the default depth
+        let names: Vec<String> = (0..10)
+            .flat_map(|layer| (0..3).map(move |i| format!("f{}_{}", layer, i)))
+            .collect();
+        let mut calls = vec![];
+        for layer in 0..9 {
+            for from in 0..3 {
+                for to in 0..3 {
+                    calls.push((
+                        format!("f{}_{}", layer, from),
+                        format!("f{}_{}", layer + 1, to),
+                    ));
+                }
+            }
+        }
+        let nodes: Vec<(&str, i64)> = names.iter().map(|n| (n.as_str(), 1)).collect();
+        let calls: Vec<(&str, &str)> = calls
+            .iter()
+            .map(|(from, to)| (from.as_str(), to.as_str()))
+            .collect();
+        let dir = TempDir::new().unwrap();
+        let conn = seeded_conn(&dir, &nodes, &calls);
+
+        let flame = CallGraph::from_db(&conn)
+            .unwrap()
+            .flame("fn:app.py:f0_0", 10);
+
+        assert_eq!(frames(&flame), MAX_FRAMES);
+        assert_eq!(flame.value, MAX_FRAMES as u64);
+        assert!(flame.truncated);
+        assert_eq!(flame.folded().len(), MAX_FRAMES);
+    }
+}
@@ -5,52 +5,27 @@
 //! - `mu graph ancestors <node>` - Find upstream dependencies (what this depends on)
 //! - `mu graph cycles` - Detect circular dependencies
 //! - `mu graph path <from> <to>` - Find shortest path between nodes
+//!
+//! Edges point from the dependent to its dependency (`A -> B`: A imports B),
+//! so impact follows incoming edges and ancestors follow outgoing ones. See
+//! [`TraversalDirection`] for the shared semantics.
 
+use crate::mubase::find_mubase;
 use crate::output::{Output, OutputFormat, TableDisplay};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use duckdb::{params, Connection};
+use mu_core::graph::TraversalDirection;
 use petgraph::algo::kosaraju_scc;
 use petgraph::graph::{DiGraph, NodeIndex};
 use petgraph::visit::EdgeRef;
 use petgraph::Direction;
 use serde::Serialize;
 use std::collections::{HashMap, HashSet, VecDeque};
-use std::path::PathBuf;
-
-/// Find the MUbase database in the given directory or its parents.
-fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize()?;
-    let mut current = start.as_path();
-
-    loop {
-        // New standard path: .mu/mubase
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Ok(db_path);
-        }
-
-        // Legacy path: .mubase
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Ok(legacy_path);
-        }
-
-        // Move up to parent
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "No MUbase found. Run 'mu bootstrap' first to create the database."
-                ))
-            }
-        }
-    }
-}
+use std::io::IsTerminal;
 
 /// Open database connection in read-only mode.
-fn open_db() -> Result<Connection> {
+pub(crate) fn open_db() -> Result<Connection> {
     let db_path = find_mubase(".")?;
     Connection::open_with_flags(
         &db_path,
@@ -171,24 +146,50 @@ impl GraphData {
         sccs
     }
 
-    /// Find impact (downstream reachable nodes)
+    /// Find impact (downstream nodes that depend on this one, via incoming edges)
     pub fn impact(
         &self,
         node_id: &str,
         edge_types: Option<&[String]>,
         max_depth: Option<u8>,
-    ) -> Vec<String> {
-        self.traverse_bfs(node_id, Direction::Outgoing, edge_types, max_depth)
+    ) -> Vec<Reached> {
+        self.traverse(
+            node_id,
+            TraversalDirection::Dependents,
+            edge_types,
+            max_depth,
+        )
     }
 
-    /// Find ancestors (upstream reachable nodes)
+    /// Find ancestors (upstream nodes this one depends on, via outgoing edges)
     pub fn ancestors(
         &self,
         node_id: &str,
         edge_types: Option<&[String]>,
         max_depth: Option<u8>,
-    ) -> Vec<String> {
-        self.traverse_bfs(node_id, Direction::Incoming, edge_types, max_depth)
+    ) -> Vec<Reached> {
+        self.traverse(
+            node_id,
+            TraversalDirection::Dependencies,
+            edge_types,
+            max_depth,
+        )
+    }
+
+    /// Breadth-first traversal recording how each node was reached.
+    ///
+    /// Results are ordered by depth, then ID, so output is stable across runs.
+    fn traverse(
+        &self,
+        node_id: &str,
+        direction: TraversalDirection,
+        edge_types: Option<&[String]>,
+        max_depth: Option<u8>,
+    ) -> Vec<Reached> {
+        let mut reached =
+            self.traverse_bfs(node_id, direction.edge_direction(), edge_types, max_depth);
+        reached.sort_by(|a, b| a.depth.cmp(&b.depth).then_with(|| a.id.cmp(&b.id)));
+        reached
     }
 
     /// Find shortest path between two nodes (bidirectional search).
@@ -350,7 +351,7 @@ impl GraphData {
         direction: Direction,
         edge_types: Option<&[String]>,
         max_depth: Option<u8>,
-    ) -> Vec<String> {
+    ) -> Vec<Reached> {
         let start = match self.node_map.get(node_id) {
             Some(&idx) => idx,
             None => return vec![],
@@ -388,7 +389,11 @@ impl GraphData {
 
                 if !visited.contains(&neighbor) {
                     visited.insert(neighbor);
-                    result.push(self.reverse_map[&neighbor].clone());
+                    result.push(Reached {
+                        id: self.reverse_map[&neighbor].clone(),
+                        edge_type: edge.weight().clone(),
+                        depth: depth + 1,
+                    });
                     queue.push_back((neighbor, depth + 1));
                 }
             }
@@ -420,23 +425,41 @@ impl GraphData {
     }
 }
 
+/// A node found by [`GraphData::traverse`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct Reached {
+    pub id: String,
+    /// Type of the edge the node was first reached through
+    pub edge_type: String,
+    /// Hops from the start node
+    pub depth: u8,
+}
+
 // ============== Output Types ==============
 
 /// Impact analysis result
+///
+/// Serializes as `{node, node_name, direction, edges: [{target, edge_type,
+/// depth, ..}], total_count}`, the same shape `mu deps` emits.
 #[derive(Debug, Serialize)]
 pub struct ImpactResult {
+    #[serde(rename = "node")]
     pub node_id: String,
     pub node_name: String,
     pub direction: String,
+    #[serde(rename = "edges")]
     pub affected_nodes: Vec<AffectedNode>,
     pub total_count: usize,
 }
 
 #[derive(Debug, Serialize)]
 pub struct AffectedNode {
+    #[serde(rename = "target")]
     pub id: String,
     pub name: String,
     pub node_type: String,
+    pub edge_type: String,
+    pub depth: u8,
     pub file_path: Option<String>,
 }
 
@@ -681,16 +704,18 @@ impl TableDisplay for PathResult {
 /// Run the impact command
 pub async fn run_impact(
     node: &str,
+    prefer: Option<&str>,
     edge_types: Option<Vec<String>>,
     depth: Option<u8>,
     format: OutputFormat,
 ) -> Result<()> {
-    run_impact_direct(node, edge_types, depth, format).await
+    run_impact_direct(node, prefer, edge_types, depth, format).await
 }
 
 /// Run impact command with direct database access
 async fn run_impact_direct(
     node: &str,
+    prefer: Option<&str>,
     edge_types: Option<Vec<String>>,
     depth: Option<u8>,
     format: OutputFormat,
@@ -699,22 +724,24 @@ async fn run_impact_direct(
     let graph = GraphData::from_db(&conn)?;
 
     // Resolve node ID
-    let node_id = resolve_node_id(&conn, node)?;
+    let node_id = resolve_node(&conn, node, prefer)?;
 
     if !graph.has_node(&node_id) {
         return Err(anyhow::anyhow!("Node not found: {}", node));
     }
 
-    let affected_ids = graph.impact(&node_id, edge_types.as_deref(), depth);
+    let reached = graph.impact(&node_id, edge_types.as_deref(), depth);
 
-    let affected_nodes: Vec<AffectedNode> = affected_ids
-        .iter()
-        .filter_map(|id| {
-            graph.get_info(id).map(|info| AffectedNode {
-                id: id.clone(),
+    let affected_nodes: Vec<AffectedNode> = reached
+        .into_iter()
+        .filter_map(|reached| {
+            graph.get_info(&reached.id).map(|info| AffectedNode {
                 name: info.name.clone(),
                 node_type: info.node_type.clone(),
                 file_path: info.file_path.clone(),
+                id: reached.id,
+                edge_type: reached.edge_type,
+                depth: reached.depth,
             })
         })
         .collect();
@@ -734,16 +761,18 @@ async fn run_impact_direct(
 /// Run the ancestors command
 pub async fn run_ancestors(
     node: &str,
+    prefer: Option<&str>,
     edge_types: Option<Vec<String>>,
     depth: Option<u8>,
     format: OutputFormat,
 ) -> Result<()> {
-    run_ancestors_direct(node, edge_types, depth, format).await
+    run_ancestors_direct(node, prefer, edge_types, depth, format).await
 }
 
 /// Run ancestors command with direct database access
 async fn run_ancestors_direct(
     node: &str,
+    prefer: Option<&str>,
     edge_types: Option<Vec<String>>,
     depth: Option<u8>,
     format: OutputFormat,
@@ -752,22 +781,24 @@ async fn run_ancestors_direct(
     let graph = GraphData::from_db(&conn)?;
 
     // Resolve node ID
-    let node_id = resolve_node_id(&conn, node)?;
+    let node_id = resolve_node(&conn, node, prefer)?;
 
     if !graph.has_node(&node_id) {
         return Err(anyhow::anyhow!("Node not found: {}", node));
     }
 
-    let ancestor_ids = graph.ancestors(&node_id, edge_types.as_deref(), depth);
+    let reached = graph.ancestors(&node_id, edge_types.as_deref(), depth);
 
-    let affected_nodes: Vec<AffectedNode> = ancestor_ids
-        .iter()
-        .filter_map(|id| {
-            graph.get_info(id).map(|info| AffectedNode {
-                id: id.clone(),
+    let affected_nodes: Vec<AffectedNode> = reached
+        .into_iter()
+        .filter_map(|reached| {
+            graph.get_info(&reached.id).map(|info| AffectedNode {
                 name: info.name.clone(),
                 node_type: info.node_type.clone(),
                 file_path: info.file_path.clone(),
+                id: reached.id,
+                edge_type: reached.edge_type,
+                depth: reached.depth,
             })
         })
         .collect();
@@ -830,6 +861,7 @@ async fn run_cycles_direct(edge_types: Option<Vec<String>>, format: OutputFormat
 pub async fn run_path(
     from: &str,
     to: &str,
+    prefer: Option<&str>,
     edge_types: Option<Vec<String>>,
     format: OutputFormat,
 ) -> Result<()> {
@@ -837,8 +869,8 @@ pub async fn run_path(
     let graph = GraphData::from_db(&conn)?;
 
     // Resolve node IDs
-    let from_id = resolve_node_id(&conn, from)?;
-    let to_id = resolve_node_id(&conn, to)?;
+    let from_id = resolve_node(&conn, from, prefer)?;
+    let to_id = resolve_node(&conn, to, prefer)?;
 
     let path_ids = graph.shortest_path(&from_id, &to_id, edge_types.as_deref());
 
@@ -869,60 +901,113 @@ pub async fn run_path(
     Output::new(result, format).render()
 }
 
-/// Try to resolve a partial node ID to a full node ID using fuzzy matching
-fn resolve_node_id(conn: &Connection, query: &str) -> Result<String> {
-    // 1. Try exact match on id or name first
-    let mut stmt = conn.prepare("SELECT id FROM nodes WHERE id = ?1 OR name = ?1")?;
+/// Node types accepted by `--prefer`
+pub const PREFER_TYPES: [&str; 3] = ["module", "class", "function"];
+
+/// Resolve a node name, ID, file path or fragment to a full node ID.
+///
+/// Shared by every command that takes a node argument. When several nodes
+/// match, `prefer` picks the first of that type; without it the module wins
+/// when not attached to a terminal, so scripts get an answer instead of an
+/// error. Interactive runs list the candidates so the user can choose.
+pub(crate) fn resolve_node(conn: &Connection, query: &str, prefer: Option<&str>) -> Result<String> {
+    resolve_node_as(conn, query, prefer, std::io::stdin().is_terminal())
+}
+
+fn resolve_node_as(
+    conn: &Connection,
+    query: &str,
+    prefer: Option<&str>,
+    interactive: bool,
+) -> Result<String> {
+    // 1. An exact ID is never ambiguous
+    let mut stmt = conn.prepare("SELECT id FROM nodes WHERE id = ?1")?;
     let mut rows = stmt.query(params![query])?;
     if let Some(row) = rows.next()? {
         return Ok(row.get(0)?);
     }
 
-    // 2. Try fuzzy match on both name and id (case-insensitive)
-    let pattern = format!("%{}%", query.to_lowercase());
-    let mut stmt = conn.prepare(
-        "SELECT id, name, type FROM nodes WHERE LOWER(name) LIKE ?1 OR LOWER(id) LIKE ?1 LIMIT 10",
+    // A file path names its module (`mu impact src/uart.h`)
+    let module_id = format!("mod:{}", query.trim_start_matches("./"));
+    let mut rows = stmt.query(params![module_id])?;
+    if let Some(row) = rows.next()? {
+        return Ok(row.get(0)?);
+    }
+
+    // 2. Exact name, then fuzzy match on both name and id (case-insensitive)
+    let mut matches = find_matches(
+        conn,
+        "SELECT id, name, type FROM nodes WHERE name = ?1",
+        query,
     )?;
-    let mut rows = stmt.query(params![pattern])?;
+    if matches.is_empty() {
+        let pattern = format!("%{}%", query.to_lowercase());
+        matches = find_matches(
+            conn,
+            "SELECT id, name, type FROM nodes WHERE LOWER(name) LIKE ?1 OR LOWER(id) LIKE ?1 LIMIT 10",
+            &pattern,
+        )?;
+    }
 
-    let mut matches: Vec<(String, String, String)> = Vec::new();
-    while let Some(row) = rows.next()? {
-        let id: String = row.get(0)?;
-        let name: String = row.get(1)?;
-        let node_type: String = row.get(2)?;
-        matches.push((id, name, node_type));
+    if matches.is_empty() {
+        return Err(anyhow::anyhow!("Node not found: {}", query));
+    }
+    if matches.len() == 1 {
+        return Ok(matches.remove(0).0);
     }
 
-    match matches.len() {
-        0 => Err(anyhow::anyhow!("Node not found: {}", query)),
-        1 => Ok(matches.into_iter().next().unwrap().0),
-        _ => {
-            // Sort matches by type priority (class > module > function) then by name
-            let mut matches = matches;
-            matches.sort_by(|a, b| {
-                let type_priority = |t: &str| match t {
-                    "class" => 0,
-                    "module" => 1,
-                    "function" => 2,
-                    _ => 3,
-                };
-                type_priority(&a.2)
-                    .cmp(&type_priority(&b.2))
-                    .then_with(|| a.1.cmp(&b.1))
-            });
+    // Sort matches by type priority (class > module > function) then by name
+    matches.sort_by(|a, b| {
+        let type_priority = |t: &str| match t {
+            "class" | "interface" | "enum" => 0,
+            "module" => 1,
+            "function" => 2,
+            _ => 3,
+        };
+        type_priority(&a.2)
+            .cmp(&type_priority(&b.2))
+            .then_with(|| a.1.cmp(&b.1))
+            .then_with(|| a.0.cmp(&b.0))
+    });
 
-            // Multiple matches - return error with sorted suggestions
-            let suggestions: Vec<String> = matches
-                .iter()
-                .map(|(id, name, typ)| format!("  {} [{}] {}", name, typ, id))
-                .collect();
-            Err(anyhow::anyhow!(
-                "Multiple nodes match '{}'. Be more specific:\n{}",
-                query,
-                suggestions.join("\n")
-            ))
+    let wanted = prefer.or((!interactive).then_some("module"));
+    if let Some(wanted) = wanted {
+        if let Some((id, _, _)) = matches.iter().find(|(_, _, typ)| typ == wanted) {
+            return Ok(id.clone());
         }
     }
+
+    // Multiple matches - return error with sorted suggestions
+    let suggestions: Vec<String> = matches
+        .iter()
+        .map(|(id, name, typ)| format!("  {} [{}] {}", name, typ, id))
+        .collect();
+    let hint = match prefer {
+        Some(wanted) => format!("None of them is a {}", wanted),
+        None => "Be more specific or pass --prefer <module|class|function>".to_string(),
+    };
+    Err(anyhow::anyhow!(
+        "Multiple nodes match '{}'. {}:\n{}",
+        query,
+        hint,
+        suggestions.join("\n")
+    ))
+}
+
+/// Run a node lookup, returning (id, name, type) rows
+fn find_matches(
+    conn: &Connection,
+    sql: &str,
+    param: &str,
+) -> Result<Vec<(String, String, String)>> {
+    let mut stmt = conn.prepare(sql)?;
+    let mut rows = stmt.query(params![param])?;
+
+    let mut matches = Vec::new();
+    while let Some(row) = rows.next()? {
+        matches.push((row.get(0)?, row.get(1)?, row.get(2)?));
+    }
+    Ok(matches)
 }
 
 #[cfg(test)]
@@ -1009,6 +1094,10 @@ mod tests {
         conn
     }
 
+    fn ids(reached: Vec<Reached>) -> Vec<String> {
+        reached.into_iter().map(|r| r.id).collect()
+    }
+
     #[test]
     fn test_find_cycles() {
         let conn = create_test_db();
@@ -1030,13 +1119,13 @@ mod tests {
         let conn = create_test_db();
         let graph = GraphData::from_db(&conn).unwrap();
 
-        let impact = graph.impact("mod:a", None, None);
+        let impact = ids(graph.impact("mod:d", None, None));
 
         // a -> b, b -> c, c -> a (cycle), b -> d
-        // So from a, we can reach b, c, d
+        // So d is used by b, and transitively by a and c
+        assert!(impact.contains(&"mod:a".to_string()));
         assert!(impact.contains(&"mod:b".to_string()));
         assert!(impact.contains(&"mod:c".to_string()));
-        assert!(impact.contains(&"mod:d".to_string()));
     }
 
     #[test]
@@ -1044,10 +1133,53 @@ mod tests {
         let conn = create_test_db();
         let graph = GraphData::from_db(&conn).unwrap();
 
-        let ancestors = graph.ancestors("mod:d", None, None);
+        let ancestors = ids(graph.ancestors("mod:a", None, None));
 
-        // d is only reached from b, and b from a and c
+        // a depends on b, and transitively on c and d
         assert!(ancestors.contains(&"mod:b".to_string()));
+        assert!(ancestors.contains(&"mod:c".to_string()));
+        assert!(ancestors.contains(&"mod:d".to_string()));
+        assert!(graph.ancestors("mod:d", None, None).is_empty());
+    }
+
+    #[test]
+    fn test_traverse_records_depth_and_edge_type() {
+        let conn = create_test_db();
+        let graph = GraphData::from_db(&conn).unwrap();
+
+        let reached = graph.ancestors("mod:a", None, None);
+        let summary: Vec<(&str, &str, u8)> = reached
+            .iter()
+            .map(|r| (r.id.as_str(), r.edge_type.as_str(), r.depth))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("mod:b", "imports", 1),
+                ("mod:c", "imports", 2),
+                ("mod:d", "calls", 2)
+            ]
+        );
+
+        let result = ImpactResult {
+            node_id: "mod:a".to_string(),
+            node_name: "a".to_string(),
+            direction: "upstream".to_string(),
+            affected_nodes: vec![AffectedNode {
+                id: "mod:b".to_string(),
+                name: "b".to_string(),
+                node_type: "module".to_string(),
+                edge_type: "imports".to_string(),
+                depth: 1,
+                file_path: None,
+            }],
+            total_count: 1,
+        };
+        let json = serde_json::to_value(&result).unwrap();
+        assert_eq!(json["node"], "mod:a");
+        assert_eq!(json["edges"][0]["target"], "mod:b");
+        assert_eq!(json["edges"][0]["edge_type"], "imports");
+        assert_eq!(json["edges"][0]["depth"], 1);
     }
 
     #[test]
@@ -1070,10 +1202,12 @@ mod tests {
         let conn = create_test_db();
         let graph = GraphData::from_db(&conn).unwrap();
 
-        // With only "imports", we shouldn't reach d (connected via "calls")
+        // With only "imports", nothing reaches d (connected via "calls")
         let imports_only = vec!["imports".to_string()];
-        let impact = graph.impact("mod:a", Some(&imports_only), None);
+        let impact = ids(graph.impact("mod:d", Some(&imports_only), None));
+        assert!(impact.is_empty());
 
+        let impact = ids(graph.impact("mod:a", Some(&imports_only), None));
         assert!(impact.contains(&"mod:b".to_string()));
         assert!(impact.contains(&"mod:c".to_string()));
         assert!(!impact.contains(&"mod:d".to_string()));
@@ -1133,4 +1267,40 @@ mod tests {
         assert_eq!(path[0], "mod:c");
         assert_eq!(path[path.len() - 1], "mod:d");
     }
+
+    #[test]
+    fn test_resolve_node_disambiguates_by_type() {
+        let conn = create_test_db();
+        for (id, node_type) in [("mod:app.py", "module"), ("fn:app.py:app", "function")] {
+            conn.execute(
+                "INSERT INTO nodes (id, type, name, file_path) VALUES (?, ?, ?, ?)",
+                params![id, node_type, "app", "app.py"],
+            )
+            .unwrap();
+        }
+
+        // Unambiguous IDs and names resolve directly
+        assert_eq!(
+            resolve_node_as(&conn, "fn:app.py:app", None, true).unwrap(),
+            "fn:app.py:app"
+        );
+        assert_eq!(resolve_node_as(&conn, "b", None, true).unwrap(), "mod:b");
+
+        // `app` names both the module and the function
+        assert_eq!(
+            resolve_node_as(&conn, "app", None, false).unwrap(),
+            "mod:app.py"
+        );
+        assert_eq!(
+            resolve_node_as(&conn, "app", Some("function"), true).unwrap(),
+            "fn:app.py:app"
+        );
+
+        let err = resolve_node_as(&conn, "app", None, true)
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("Multiple nodes match"), "{}", err);
+        assert!(err.contains("--prefer"), "{}", err);
+        assert!(resolve_node_as(&conn, "app", Some("class"), false).is_err());
+    }
 }
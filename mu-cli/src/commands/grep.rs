@@ -0,0 +1,330 @@
+//! Grep command - Regex search over node names with graph context
+//!
+//! Sits between literal grep and `mu search`: matches a regex against node
+//! names and qualified names (and, with `--source`, the node's source lines),
+//! then shows each match with its immediate dependencies and dependents the
+//! way `mu deps` does.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use crate::commands::deps::{find_dependencies, DependencyNode};
+use crate::mubase::find_mubase;
+use crate::output::{Output, OutputFormat, TableDisplay};
+use anyhow::{Context, Result};
+use colored::Colorize;
+use duckdb::Connection;
+use mu_core::graph::TraversalDirection;
+use regex::{Regex, RegexBuilder};
+use serde::Serialize;
+
+/// Grep result
+#[derive(Debug, Serialize)]
+pub struct GrepResult {
+    pub pattern: String,
+    pub matches: Vec<GrepMatch>,
+    /// Matches found before `--limit` was applied
+    pub total_count: usize,
+}
+
+/// A node matching the pattern
+#[derive(Debug, Serialize)]
+pub struct GrepMatch {
+    pub id: String,
+    pub name: String,
+    pub node_type: String,
+    pub file_path: Option<String>,
+    /// Line of the first matching source line, or where the node starts
+    pub line: Option<u32>,
+    /// What matched: "name", "qualified_name" or "source"
+    pub matched: String,
+    /// What this node depends on (one hop)
+    pub dependencies: Vec<DependencyNode>,
+    /// What depends on this node (one hop)
+    pub used_by: Vec<DependencyNode>,
+}
+
+impl TableDisplay for GrepResult {
+    fn to_table(&self) -> String {
+        let mut output = String::new();
+        output.push_str(&format!("{} /{}/\n", "Grep".bold(), self.pattern.cyan()));
+        output.push_str(&format!("{}\n", "-".repeat(60)));
+
+        if self.matches.is_empty() {
+            output.push_str(&"  No matching nodes.\n".dimmed().to_string());
+        }
+
+        for m in &self.matches {
+            let type_badge = match m.node_type.as_str() {
+                "module" => "[mod]".blue(),
+                "class" => "[cls]".yellow(),
+                "function" => "[fn]".green(),
+                _ => format!("[{}]", m.node_type).normal(),
+            };
+            let location = match (&m.file_path, m.line) {
+                (Some(path), Some(line)) => format!("{}:{}", path, line),
+                (Some(path), None) => path.clone(),
+                _ => String::new(),
+            };
+            output.push_str(&format!(
+                "\n  {} {} {}\n",
+                type_badge,
+                m.name.bold(),
+                location.dimmed()
+            ));
+            for dep in &m.dependencies {
+                output.push_str(&format!(
+                    "     {} {} {}\n",
+                    "->".dimmed(),
+                    dep.name,
+                    format!("({})", dep.edge_type).dimmed()
+                ));
+            }
+            for user in &m.used_by {
+                output.push_str(&format!(
+                    "     {} {} {}\n",
+                    "<-".dimmed(),
+                    user.name,
+                    format!("({})", user.edge_type).dimmed()
+                ));
+            }
+        }
+
+        let shown = if self.matches.len() < self.total_count {
+            format!("{} of {}", self.matches.len(), self.total_count)
+        } else {
+            self.total_count.to_string()
+        };
+        output.push_str(&format!("\n{}: {}\n", "Total".bold(), shown));
+        output
+    }
+
+    fn to_mu(&self) -> String {
+        let mut output = format!(":: grep /{}/\n", self.pattern);
+        for m in &self.matches {
+            output.push_str(&format!("- {} [{}] via:{}\n", m.id, m.node_type, m.matched));
+            for dep in &m.dependencies {
+                output.push_str(&format!("  -> {} via:{}\n", dep.id, dep.edge_type));
+            }
+            for user in &m.used_by {
+                output.push_str(&format!("  <- {} via:{}\n", user.id, user.edge_type));
+            }
+        }
+        output.push_str(&format!("# total: {}\n", self.total_count));
+        output
+    }
+}
+
+/// A node row considered for matching
+struct Candidate {
+    id: String,
+    name: String,
+    qualified_name: Option<String>,
+    node_type: String,
+    file_path: Option<String>,
+    line_start: Option<u32>,
+    line_end: Option<u32>,
+}
+
+/// Run the grep command
+pub async fn run(
+    pattern: &str,
+    source: bool,
+    ignore_case: bool,
+    limit: usize,
+    format: OutputFormat,
+) -> Result<()> {
+    let regex = RegexBuilder::new(pattern)
+        .case_insensitive(ignore_case)
+        .build()
+        .with_context(|| format!("Invalid regex: {}", pattern))?;
+
+    let db_path = find_mubase(".")?;
+    let conn = Connection::open_with_flags(
+        &db_path,
+        duckdb::Config::default().access_mode(duckdb::AccessMode::ReadOnly)?,
+    )
+    .with_context(|| format!("Failed to open database: {:?}", db_path))?;
+    let root = source.then(|| crate::mubase::project_root(&conn, &db_path));
+
+    let mut matches = grep_nodes(&conn, &regex, root.as_deref())?;
+    let total_count = matches.len();
+    matches.truncate(limit);
+
+    let matches = matches
+        .into_iter()
+        .map(|(candidate, matched, line)| {
+            let dependencies = find_dependencies(
+                &conn,
+                &candidate.id,
+                TraversalDirection::Dependencies,
+                1,
+                false,
+            )?;
+            let used_by = find_dependencies(
+                &conn,
+                &candidate.id,
+                TraversalDirection::Dependents,
+                1,
+                false,
+            )?;
+            Ok(GrepMatch {
+                id: candidate.id,
+                name: candidate.name,
+                node_type: candidate.node_type,
+                file_path: candidate.file_path,
+                line: line.or(candidate.line_start),
+                matched: matched.to_string(),
+                dependencies,
+                used_by,
+            })
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let result = GrepResult {
+        pattern: pattern.to_string(),
+        matches,
+        total_count,
+    };
+    Output::new(result, format).render()
+}
+
+/// Find nodes whose name, qualified name or (when `root` is given) source
+/// matches, ordered by file and line.
+fn grep_nodes(
+    conn: &Connection,
+    regex: &Regex,
+    root: Option<&Path>,
+) -> Result<Vec<(Candidate, &'static str, Option<u32>)>> {
+    let mut stmt = conn.prepare(
+        "SELECT id, name, qualified_name, type, file_path, line_start, line_end
+         FROM nodes
+         WHERE type != 'external'
+         ORDER BY file_path, line_start, id",
+    )?;
+    let mut rows = stmt.query([])?;
+
+    let mut files: HashMap<String, Option<Vec<String>>> = HashMap::new();
+    let mut matches = Vec::new();
+    while let Some(row) = rows.next()? {
+        let candidate = Candidate {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            qualified_name: row.get(2)?,
+            node_type: row.get(3)?,
+            file_path: row.get(4)?,
+            line_start: row.get(5)?,
+            line_end: row.get(6)?,
+        };
+
+        if regex.is_match(&candidate.name) {
+            matches.push((candidate, "name", None));
+            continue;
+        }
+        if candidate
+            .qualified_name
+            .as_deref()
+            .is_some_and(|q| regex.is_match(q))
+        {
+            matches.push((candidate, "qualified_name", None));
+            continue;
+        }
+
+        // Modules span their whole file; only their members are searched
+        let Some(root) = root else { continue };
+        if candidate.node_type == "module" {
+            continue;
+        }
+        let (Some(path), Some(start)) = (&candidate.file_path, candidate.line_start) else {
+            continue;
+        };
+        let lines = files.entry(path.clone()).or_insert_with(|| {
+            std::fs::read_to_string(root.join(path))
+                .ok()
+                .map(|s| s.lines().map(str::to_string).collect())
+        });
+        let Some(lines) = lines else { continue };
+
+        let end = candidate.line_end.unwrap_or(start).max(start);
+        let hit = (start..=end).find(|&n| {
+            n >= 1
+                && lines
+                    .get(n as usize - 1)
+                    .is_some_and(|line| regex.is_match(line))
+        });
+        if let Some(line) = hit {
+            matches.push((candidate, "source", Some(line)));
+        }
+    }
+    Ok(matches)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    fn create_test_db(dir: &Path) -> Connection {
+        let conn = Connection::open(dir.join("test.mubase")).unwrap();
+        conn.execute_batch(
+            r#"
+            CREATE TABLE nodes (
+                id VARCHAR PRIMARY KEY,
+                type VARCHAR NOT NULL,
+                name VARCHAR NOT NULL,
+                qualified_name VARCHAR,
+                file_path VARCHAR,
+                line_start INTEGER,
+                line_end INTEGER
+            );
+            INSERT INTO nodes VALUES
+                ('mod:app.py', 'module', 'app', NULL, 'app.py', 1, 6),
+                ('fn:app.py:parse_config', 'function', 'parse_config', 'app.parse_config', 'app.py', 1, 2),
+                ('fn:app.py:run', 'function', 'run', 'app.run', 'app.py', 4, 6),
+                ('ext:yaml', 'external', 'yaml', NULL, NULL, NULL, NULL);
+            "#,
+        )
+        .unwrap();
+        conn
+    }
+
+    fn ids<'a>(
+        matches: &'a [(Candidate, &'static str, Option<u32>)],
+    ) -> Vec<(&'a str, &'static str)> {
+        matches
+            .iter()
+            .map(|(c, matched, _)| (c.id.as_str(), *matched))
+            .collect()
+    }
+
+    #[test]
+    fn test_grep_names_and_source() {
+        let dir = tempdir().unwrap();
+        let conn = create_test_db(dir.path());
+        std::fs::write(
+            dir.path().join("app.py"),
+            "def parse_config(path):\n    return yaml.load(path)\n\ndef run():\n    cfg = parse_config('x')\n    return cfg\n",
+        )
+        .unwrap();
+
+        let regex = Regex::new("^parse_").unwrap();
+        let matches = grep_nodes(&conn, &regex, None).unwrap();
+        assert_eq!(ids(&matches), vec![("fn:app.py:parse_config", "name")]);
+
+        let regex = Regex::new(r"app\.run").unwrap();
+        let matches = grep_nodes(&conn, &regex, None).unwrap();
+        assert_eq!(ids(&matches), vec![("fn:app.py:run", "qualified_name")]);
+
+        // Source matching reports the first matching line of the body
+        let regex = Regex::new(r"cfg = parse").unwrap();
+        assert!(grep_nodes(&conn, &regex, None).unwrap().is_empty());
+        let matches = grep_nodes(&conn, &regex, Some(dir.path())).unwrap();
+        assert_eq!(ids(&matches), vec![("fn:app.py:run", "source")]);
+        assert_eq!(matches[0].2, Some(5));
+
+        // External nodes are never matched
+        let regex = Regex::new("yaml").unwrap();
+        let matches = grep_nodes(&conn, &regex, Some(dir.path())).unwrap();
+        assert_eq!(ids(&matches), vec![("fn:app.py:parse_config", "source")]);
+    }
+}
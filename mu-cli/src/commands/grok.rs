@@ -11,6 +11,7 @@ use std::time::Instant;
 use colored::Colorize;
 use serde::Serialize;
 
+use crate::mubase::find_mubase_from;
 use crate::output::{Output, OutputFormat, TableDisplay};
 
 /// Context item containing code snippet
@@ -176,7 +177,7 @@ impl TableDisplay for GrokResult {
         for ctx in &self.contexts {
             let sigil = match ctx.node_type.as_str() {
                 "module" => "!",
-                "class" => "@",
+                "class" | "interface" | "enum" => "@",
                 "function" => "$",
                 _ => "#",
             };
@@ -224,34 +225,6 @@ impl TableDisplay for GrokResult {
     }
 }
 
-/// Find the mubase path starting from the given directory
-fn find_mubase_path(start_dir: &Path) -> Option<std::path::PathBuf> {
-    let mut current = start_dir.to_path_buf();
-    loop {
-        let mubase_path = current.join(".mu").join("mubase");
-        if mubase_path.exists() {
-            return Some(mubase_path);
-        }
-        if !current.pop() {
-            return None;
-        }
-    }
-}
-
-/// Find the project root (where .mu directory is located)
-fn find_project_root(start_dir: &Path) -> Option<std::path::PathBuf> {
-    let mut current = start_dir.to_path_buf();
-    loop {
-        let mu_dir = current.join(".mu");
-        if mu_dir.exists() {
-            return Some(current);
-        }
-        if !current.pop() {
-            return None;
-        }
-    }
-}
-
 /// Extract source code from file given line range
 fn extract_source_code(
     project_root: &Path,
@@ -374,6 +347,22 @@ fn get_related_nodes(
     Ok(all_nodes.into_iter().collect())
 }
 
+/// Open the project's database read-only.
+pub(crate) fn open_mubase() -> anyhow::Result<mu_daemon::storage::MUbase> {
+    let cwd = std::env::current_dir()?;
+    let mubase_path = match find_mubase_from(&cwd) {
+        Some(path) => path,
+        None => {
+            anyhow::bail!(
+                "No .mu/mubase found. Run 'mu bootstrap' first to initialize MU for this project."
+            );
+        }
+    };
+
+    // Read-only: context extraction never writes
+    mu_daemon::storage::MUbase::open_read_only(&mubase_path)
+}
+
 /// Run the grok command
 pub async fn run(question: &str, depth: u8, format: OutputFormat) -> anyhow::Result<()> {
     // Validate question is not empty
@@ -381,42 +370,37 @@ pub async fn run(question: &str, depth: u8, format: OutputFormat) -> anyhow::Res
         anyhow::bail!("Question cannot be empty. Please provide a question or topic.");
     }
 
+    let mubase = open_mubase()?;
+    Output::new(extract_context(&mubase, question, depth)?, format).render()
+}
+
+/// Find the nodes relevant to a question, with their source and
+/// dependencies, most relevant first.
+///
+/// `depth` (1-3) also pulls in dependencies of the matches, `depth - 1`
+/// hops out.
+pub(crate) fn extract_context(
+    mubase: &mu_daemon::storage::MUbase,
+    question: &str,
+    depth: u8,
+) -> anyhow::Result<GrokResult> {
     let start = Instant::now();
 
     // Validate depth
     let depth = depth.clamp(1, 3);
 
-    // Find mubase
-    let cwd = std::env::current_dir()?;
-    let mubase_path = match find_mubase_path(&cwd) {
-        Some(path) => path,
-        None => {
-            anyhow::bail!(
-                "No .mu/mubase found. Run 'mu bootstrap' first to initialize MU for this project."
-            );
-        }
-    };
-
-    let project_root = match find_project_root(&cwd) {
-        Some(path) => path,
-        None => {
-            anyhow::bail!("Could not determine project root. Ensure .mu directory exists.");
-        }
-    };
-
-    // Open database in read-only mode (grok only reads, doesn't write)
-    let mubase = mu_daemon::storage::MUbase::open_read_only(&mubase_path)?;
+    let project_root = mubase.project_root().to_path_buf();
 
     // Check if we have embeddings
     let has_embeddings = mubase.has_embeddings()?;
 
     // Get initial relevant nodes - try semantic search first, fall back to keywords
     let (initial_results, search_method) = if has_embeddings {
-        let results = run_semantic_search(&mubase, question, 5)?;
+        let results = run_semantic_search(mubase, question, 5)?;
         if results.is_empty() {
             // Semantic search returned nothing, fall back to keyword search
             (
-                run_keyword_search(&mubase, question, 10)?,
+                run_keyword_search(mubase, question, 10)?,
                 SearchMethod::Keyword,
             )
         } else {
@@ -425,14 +409,14 @@ pub async fn run(question: &str, depth: u8, format: OutputFormat) -> anyhow::Res
     } else {
         // No embeddings available, use keyword search
         (
-            run_keyword_search(&mubase, question, 10)?,
+            run_keyword_search(mubase, question, 10)?,
             SearchMethod::Keyword,
         )
     };
 
     if initial_results.is_empty() {
         let duration_ms = start.elapsed().as_millis() as u64;
-        let result = GrokResult {
+        return Ok(GrokResult {
             question: question.to_string(),
             contexts: Vec::new(),
             total_nodes: 0,
@@ -441,9 +425,7 @@ pub async fn run(question: &str, depth: u8, format: OutputFormat) -> anyhow::Res
             search_method,
             duration_ms,
             total_lines: 0,
-        };
-
-        return Output::new(result, format).render();
+        });
     }
 
     // Extract node IDs from initial results
@@ -454,7 +436,7 @@ pub async fn run(question: &str, depth: u8, format: OutputFormat) -> anyhow::Res
 
     // Get related nodes based on depth
     let all_node_ids = if depth > 1 {
-        get_related_nodes(&mubase, &initial_node_ids, depth - 1)?
+        get_related_nodes(mubase, &initial_node_ids, depth - 1)?
     } else {
         initial_node_ids.clone()
     };
@@ -485,7 +467,7 @@ pub async fn run(question: &str, depth: u8, format: OutputFormat) -> anyhow::Res
             }
 
             // Get dependencies
-            let dependencies = get_node_dependencies(&mubase, &node.id).unwrap_or_default();
+            let dependencies = get_node_dependencies(mubase, &node.id).unwrap_or_default();
 
             contexts.push(GrokContext {
                 node_id: node.id,
@@ -510,7 +492,7 @@ pub async fn run(question: &str, depth: u8, format: OutputFormat) -> anyhow::Res
 
     let duration_ms = start.elapsed().as_millis() as u64;
 
-    let result = GrokResult {
+    Ok(GrokResult {
         question: question.to_string(),
         total_nodes: contexts.len(),
         contexts,
@@ -519,9 +501,7 @@ pub async fn run(question: &str, depth: u8, format: OutputFormat) -> anyhow::Res
         search_method,
         duration_ms,
         total_lines,
-    };
-
-    Output::new(result, format).render()
+    })
 }
 
 /// Run semantic search using embeddings
@@ -530,11 +510,8 @@ fn run_semantic_search(
     query: &str,
     limit: usize,
 ) -> anyhow::Result<Vec<mu_daemon::storage::VectorSearchResult>> {
-    // Load the embedding model from embedded weights (zero-config)
-    let model = mu_embeddings::MuSigmaModel::embedded()?;
-
-    // Embed the query
-    let query_embedding = model.embed_one(query)?;
+    // Embed the query, or reuse the vector from a recent identical search
+    let query_embedding = crate::query_cache::embed_query(mubase.project_root(), query)?;
 
     // Perform vector search with lower threshold for broader context
     let results = mubase.vector_search(&query_embedding, limit, Some(0.1))?;
@@ -0,0 +1,201 @@
+//! Heatmap command - Per-function complexity keyed by line range
+//!
+//! Returns the stored complexity of every function in a file along with its
+//! line range, so editors can color the gutter by complexity in one call.
+
+use crate::mubase::find_mubase_from;
+use crate::output::{Output, OutputFormat, TableDisplay};
+use anyhow::Result;
+use colored::Colorize;
+use mu_daemon::storage::{MUbase, NodeType};
+use serde::Serialize;
+use std::path::Path;
+
+/// Complexity of one function's line range.
+#[derive(Debug, Clone, Serialize)]
+pub struct HeatmapRange {
+    pub start_line: u32,
+    pub end_line: u32,
+    pub complexity: u32,
+    pub name: String,
+}
+
+/// Heatmap for a single file. Serializes as a bare array of ranges.
+#[derive(Debug, Clone, Serialize)]
+#[serde(transparent)]
+pub struct Heatmap {
+    #[serde(skip)]
+    pub file: String,
+    pub ranges: Vec<HeatmapRange>,
+}
+
+impl TableDisplay for Heatmap {
+    fn to_table(&self) -> String {
+        let mut lines = Vec::new();
+        lines.push(format!("{} {}", "Heatmap:".cyan().bold(), self.file));
+
+        if self.ranges.is_empty() {
+            lines.push("  No functions found.".dimmed().to_string());
+            return lines.join("\n");
+        }
+
+        for range in &self.ranges {
+            let complexity = format!("{:>4}", range.complexity);
+            let complexity = match range.complexity {
+                0..=5 => complexity.green(),
+                6..=10 => complexity.yellow(),
+                _ => complexity.red(),
+            };
+            lines.push(format!(
+                "  {} {:>5}-{:<5} {}",
+                complexity, range.start_line, range.end_line, range.name
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    fn to_mu(&self) -> String {
+        let mut lines = vec![format!(":: heatmap {}", self.file)];
+        for range in &self.ranges {
+            lines.push(format!(
+                "$ {} L{}-{} c={}",
+                range.name, range.start_line, range.end_line, range.complexity
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+/// Convert a user-supplied path to the project-relative form stored in nodes.
+fn normalize_file_path(file: &str, project_root: &Path) -> String {
+    let path = Path::new(file);
+    let relative = if path.is_absolute() {
+        let root = project_root
+            .canonicalize()
+            .unwrap_or_else(|_| project_root.to_path_buf());
+        path.strip_prefix(&root).unwrap_or(path).to_path_buf()
+    } else {
+        path.to_path_buf()
+    };
+
+    relative
+        .to_string_lossy()
+        .trim_start_matches("./")
+        .replace('\\', "/")
+}
+
+/// Build the heatmap for a file from the function nodes stored in the MUbase.
+pub fn load_heatmap(mubase: &MUbase, file: &str) -> Result<Heatmap> {
+    let file_path = normalize_file_path(file, mubase.project_root());
+    let prefix = format!("{}:", file_path);
+
+    let ranges = mubase
+        .get_nodes_in_file(&file_path, NodeType::Function)?
+        .into_iter()
+        .filter_map(|node| {
+            let start_line = node.line_start?;
+            // Qualified names are "path:Class.method"; drop the path
+            let name = node
+                .qualified_name
+                .as_deref()
+                .and_then(|q| q.strip_prefix(&prefix))
+                .map(str::to_string)
+                .unwrap_or(node.name);
+            Some(HeatmapRange {
+                start_line,
+                end_line: node.line_end.unwrap_or(start_line),
+                complexity: node.complexity,
+                name,
+            })
+        })
+        .collect();
+
+    Ok(Heatmap {
+        file: file_path,
+        ranges,
+    })
+}
+
+/// Run the heatmap command.
+pub async fn run(file: &str, format: OutputFormat) -> Result<()> {
+    let cwd = std::env::current_dir()?;
+    let mubase_path = find_mubase_from(&cwd)
+        .ok_or_else(|| anyhow::anyhow!("No .mu/mubase found. Run 'mu bootstrap' first."))?;
+
+    let mubase = MUbase::open_read_only(&mubase_path)?;
+
+    // Relative paths are resolved against the current directory
+    let absolute = cwd.join(file);
+    let absolute = absolute.canonicalize().unwrap_or(absolute);
+
+    let heatmap = load_heatmap(&mubase, &absolute.to_string_lossy())?;
+    Output::new(heatmap, format).render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mu_daemon::storage::Node;
+    use tempfile::TempDir;
+
+    fn setup_mubase(dir: &TempDir) -> MUbase {
+        std::fs::create_dir_all(dir.path().join(".mu")).unwrap();
+        MUbase::open(dir.path().join(".mu").join("mubase")).unwrap()
+    }
+
+    #[test]
+    fn test_heatmap_returns_function_ranges() {
+        let dir = TempDir::new().unwrap();
+        let mubase = setup_mubase(&dir);
+        mubase
+            .insert_nodes(&[
+                Node::module("src/app.py"),
+                Node::function("src/app.py", "handle", Some("Server"), 10, 30, 7),
+                Node::function("src/app.py", "main", None, 1, 5, 2),
+                Node::function("src/other.py", "helper", None, 1, 3, 9),
+            ])
+            .unwrap();
+
+        let heatmap = load_heatmap(&mubase, "src/app.py").unwrap();
+        assert_eq!(heatmap.ranges.len(), 2);
+
+        assert_eq!(heatmap.ranges[0].name, "main");
+        assert_eq!(heatmap.ranges[0].complexity, 2);
+        assert_eq!(
+            (heatmap.ranges[0].start_line, heatmap.ranges[0].end_line),
+            (1, 5)
+        );
+
+        assert_eq!(heatmap.ranges[1].name, "Server.handle");
+        assert_eq!(heatmap.ranges[1].complexity, 7);
+        assert_eq!(
+            (heatmap.ranges[1].start_line, heatmap.ranges[1].end_line),
+            (10, 30)
+        );
+
+        let json = serde_json::to_value(&heatmap).unwrap();
+        assert!(json.is_array());
+    }
+
+    #[test]
+    fn test_heatmap_empty_file_and_absolute_path() {
+        let dir = TempDir::new().unwrap();
+        let mubase = setup_mubase(&dir);
+        mubase
+            .insert_nodes(&[
+                Node::module("src/empty.py"),
+                Node::function("src/app.py", "main", None, 1, 5, 2),
+            ])
+            .unwrap();
+
+        let heatmap = load_heatmap(&mubase, "src/empty.py").unwrap();
+        assert!(heatmap.ranges.is_empty());
+        assert_eq!(serde_json::to_string(&heatmap).unwrap(), "[]");
+
+        let absolute = dir.path().canonicalize().unwrap().join("src/app.py");
+        let heatmap = load_heatmap(&mubase, absolute.to_str().unwrap()).unwrap();
+        assert_eq!(heatmap.file, "src/app.py");
+        assert_eq!(heatmap.ranges.len(), 1);
+    }
+}
@@ -3,45 +3,14 @@
 //! Uses git log to find commits that touched a node's file and shows
 //! the history of changes with hash, date, author, and change type.
 
+use crate::mubase::find_mubase;
 use crate::output::{Output, OutputFormat, TableDisplay};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use duckdb::{params, Connection};
 use serde::Serialize;
-use std::path::PathBuf;
 use std::process::Command;
 
-/// Find the MUbase database in the given directory or its parents.
-fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize()?;
-    let mut current = start.as_path();
-
-    loop {
-        // New standard path: .mu/mubase
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Ok(db_path);
-        }
-
-        // Legacy path: .mubase
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Ok(legacy_path);
-        }
-
-        // Move up to parent
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "No MUbase found. Run 'mu bootstrap' first to create the database."
-                ))
-            }
-        }
-    }
-}
-
 /// A single commit in the history
 #[derive(Debug, Clone, Serialize)]
 pub struct HistoryCommit {
@@ -240,7 +209,7 @@ fn resolve_node(conn: &Connection, node_id: &str) -> Result<NodeInfo> {
             // Sort matches by type priority (class > module > function) then by name
             matches.sort_by(|a, b| {
                 let type_priority = |t: &str| match t {
-                    "class" => 0,
+                    "class" | "interface" | "enum" => 0,
                     "module" => 1,
                     "function" => 2,
                     _ => 3,
@@ -5,38 +5,11 @@
 
 mod server;
 
-use std::path::Path;
 use rmcp::{transport::stdio, ServiceExt};
 
-pub use server::MuMcpServer;
-
-/// Find the mubase path starting from the given directory
-fn find_mubase_path(start_dir: &Path) -> Option<std::path::PathBuf> {
-    let mut current = start_dir.to_path_buf();
-    loop {
-        let mubase_path = current.join(".mu").join("mubase");
-        if mubase_path.exists() {
-            return Some(mubase_path);
-        }
-        if !current.pop() {
-            return None;
-        }
-    }
-}
+use crate::mubase::find_mubase_from;
 
-/// Find the project root (directory containing .mu)
-fn find_project_root(start_dir: &Path) -> Option<std::path::PathBuf> {
-    let mut current = start_dir.to_path_buf();
-    loop {
-        let mu_dir = current.join(".mu");
-        if mu_dir.exists() {
-            return Some(current);
-        }
-        if !current.pop() {
-            return None;
-        }
-    }
-}
+pub use server::MuMcpServer;
 
 /// Run the MCP server
 pub async fn run(path: &str) -> anyhow::Result<()> {
@@ -46,15 +19,13 @@ pub async fn run(path: &str) -> anyhow::Result<()> {
         std::fs::canonicalize(path)?
     };
 
-    // Find project root and mubase
-    let project_root = find_project_root(&start_dir)
-        .ok_or_else(|| anyhow::anyhow!("No .mu directory found. Run 'mu bootstrap' first."))?;
-
-    let mubase_path = find_mubase_path(&start_dir)
+    // Find mubase (honors MU_MUBASE and [storage].path)
+    let mubase_path = find_mubase_from(&start_dir)
         .ok_or_else(|| anyhow::anyhow!("No .mu/mubase found. Run 'mu bootstrap' first."))?;
 
     // Open database in read-only mode
     let mubase = mu_daemon::storage::MUbase::open_read_only(&mubase_path)?;
+    let project_root = mubase.project_root().to_path_buf();
 
     // Create and run MCP server
     let server = MuMcpServer::new(mubase, project_root);
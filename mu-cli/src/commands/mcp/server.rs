@@ -89,7 +89,7 @@ impl MuMcpServer {
     #[tool(description = "Find and show relevant code for a question. Returns actual code snippets, not just locations. Use this to understand how something works.")]
     async fn mu_grok(&self, Parameters(params): Parameters<GrokParams>) -> Result<CallToolResult, McpError> {
         let start = Instant::now();
-        let limit = params.limit.unwrap_or(3).min(10).max(1);
+        let limit = params.limit.unwrap_or(3).clamp(1, 10);
 
         // Get search results
         let results = if self.mubase.has_embeddings().unwrap_or(false) {
@@ -156,7 +156,7 @@ impl MuMcpServer {
         output.push_str(&format!("# {} matches\n\n", result.rows.len()));
 
         for row in &result.rows {
-            let node_type = row.get(0).and_then(|v| v.as_str()).unwrap_or("?");
+            let node_type = row.first().and_then(|v| v.as_str()).unwrap_or("?");
             let name = row.get(1).and_then(|v| v.as_str()).unwrap_or("?");
             let file_path = row.get(2).and_then(|v| v.as_str()).unwrap_or("?");
             let start_line = row.get(3).and_then(|v| v.as_i64()).unwrap_or(0);
@@ -212,7 +212,7 @@ impl MuMcpServer {
 
         let (node_count, edge_count, file_count) = stats.rows.first()
             .map(|r| (
-                r.get(0).and_then(|v| v.as_i64()).unwrap_or(0),
+                r.first().and_then(|v| v.as_i64()).unwrap_or(0),
                 r.get(1).and_then(|v| v.as_i64()).unwrap_or(0),
                 r.get(2).and_then(|v| v.as_i64()).unwrap_or(0),
             ))
@@ -234,7 +234,7 @@ impl MuMcpServer {
             .map_err(|e| McpError::internal_error(e.to_string(), None))?;
 
         let languages: Vec<String> = langs.rows.iter()
-            .filter_map(|r| r.get(0).and_then(|v| v.as_str()).map(|s| s.to_string()))
+            .filter_map(|r| r.first().and_then(|v| v.as_str()).map(|s| s.to_string()))
             .filter(|s| s != "Other")
             .collect();
 
@@ -252,7 +252,7 @@ impl MuMcpServer {
         let mut current_file = String::new();
 
         for row in &nodes_result.rows {
-            let node_type = row.get(0).and_then(|v| v.as_str()).unwrap_or("");
+            let node_type = row.first().and_then(|v| v.as_str()).unwrap_or("");
             let name = row.get(1).and_then(|v| v.as_str()).unwrap_or("");
             let file_path = row.get(2).and_then(|v| v.as_str()).unwrap_or("");
             let complexity = row.get(3).and_then(|v| v.as_i64()).unwrap_or(0);
@@ -308,7 +308,7 @@ impl MuMcpServer {
         if graph_count > 0 {
             output.push_str(&format!("## Graph Dependencies ({} found)\n", graph_count));
             for row in &result.rows {
-                let name = row.get(0).and_then(|v| v.as_str()).unwrap_or("?");
+                let name = row.first().and_then(|v| v.as_str()).unwrap_or("?");
                 let node_type = row.get(1).and_then(|v| v.as_str()).unwrap_or("?");
                 let file_path = row.get(2).and_then(|v| v.as_str()).unwrap_or("?");
                 output.push_str(&format!("  {} [{}] — {}\n", name, node_type, file_path));
@@ -429,7 +429,7 @@ impl MuMcpServer {
                 if let Ok(nodes) = self.mubase.query(&sql) {
                     let symbols: Vec<String> = nodes.rows.iter()
                         .filter_map(|r| {
-                            let t = r.get(0).and_then(|v| v.as_str())?;
+                            let t = r.first().and_then(|v| v.as_str())?;
                             let n = r.get(1).and_then(|v| v.as_str())?;
                             if t == "module" { return None; }
                             let sigil = match t { "class" => "$", "function" => "#", _ => "@" };
@@ -483,7 +483,7 @@ impl MuMcpServer {
             output.push_str(&format!("## High Complexity (≥{}) — {} found\n", min_complexity, complex.rows.len()));
             output.push_str("Functions that are hard to understand and maintain:\n\n");
             for row in &complex.rows {
-                let name = row.get(0).and_then(|v| v.as_str()).unwrap_or("?");
+                let name = row.first().and_then(|v| v.as_str()).unwrap_or("?");
                 let file_path = row.get(2).and_then(|v| v.as_str()).unwrap_or("?");
                 let complexity = row.get(3).and_then(|v| v.as_i64()).unwrap_or(0);
                 output.push_str(&format!("  #{} c={} — {}\n", name, complexity, file_path));
@@ -508,7 +508,7 @@ impl MuMcpServer {
             output.push_str(&format!("## Security-Sensitive — {} found\n", security.rows.len()));
             output.push_str("Code handling authentication, secrets, or credentials:\n\n");
             for row in &security.rows {
-                let name = row.get(0).and_then(|v| v.as_str()).unwrap_or("?");
+                let name = row.first().and_then(|v| v.as_str()).unwrap_or("?");
                 let node_type = row.get(1).and_then(|v| v.as_str()).unwrap_or("?");
                 let file_path = row.get(2).and_then(|v| v.as_str()).unwrap_or("?");
                 let sigil = match node_type { "class" => "$", "function" => "#", _ => "@" };
@@ -526,7 +526,7 @@ impl MuMcpServer {
                 output.push_str("## Large Functions (by lines)\n");
                 output.push_str("Long functions that might need refactoring:\n\n");
                 for row in &large.rows {
-                    let name = row.get(0).and_then(|v| v.as_str()).unwrap_or("?");
+                    let name = row.first().and_then(|v| v.as_str()).unwrap_or("?");
                     let file_path = row.get(1).and_then(|v| v.as_str()).unwrap_or("?");
                     let lines = row.get(2).and_then(|v| v.as_i64()).unwrap_or(0);
                     if lines > 50 {
@@ -643,7 +643,7 @@ impl MuMcpServer {
             if !nodes.rows.is_empty() {
                 output.push_str("\n## Symbols in file\n");
                 for row in &nodes.rows {
-                    let node_type = row.get(0).and_then(|v| v.as_str()).unwrap_or("?");
+                    let node_type = row.first().and_then(|v| v.as_str()).unwrap_or("?");
                     let name = row.get(1).and_then(|v| v.as_str()).unwrap_or("?");
                     let complexity = row.get(2).and_then(|v| v.as_i64()).unwrap_or(0);
                     if node_type == "module" { continue; }
@@ -689,7 +689,7 @@ impl MuMcpServer {
 
         Ok(result.rows.iter().map(|row| SearchResult {
             name: row.get(1).and_then(|v| v.as_str()).unwrap_or("").to_string(),
-            node_type: row.get(0).and_then(|v| v.as_str()).unwrap_or("").to_string(),
+            node_type: row.first().and_then(|v| v.as_str()).unwrap_or("").to_string(),
             file_path: row.get(2).and_then(|v| v.as_str()).map(|s| s.to_string()),
             start_line: row.get(3).and_then(|v| v.as_i64()),
             end_line: row.get(4).and_then(|v| v.as_i64()),
@@ -731,8 +731,7 @@ impl MuMcpServer {
                     // Try to find the end of the block (simple heuristic)
                     let mut brace_count = 0;
                     let mut found_open = false;
-                    for j in i..lines.len().min(i + 50) {
-                        let l = lines[j];
+                    for (j, l) in lines.iter().enumerate().take(i + 50).skip(i) {
                         for c in l.chars() {
                             if c == '{' || c == '(' && !found_open {
                                 brace_count += 1;
@@ -767,7 +766,9 @@ struct SearchResult {
     name: String,
     node_type: String,
     file_path: Option<String>,
+    #[allow(dead_code)]
     start_line: Option<i64>,
+    #[allow(dead_code)]
     end_line: Option<i64>,
     similarity: f32,
 }
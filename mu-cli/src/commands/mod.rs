@@ -2,21 +2,30 @@
 //!
 //! Each command module provides a `run` function that executes the command logic.
 
+pub mod backup;
 pub mod bootstrap;
 pub mod completions;
 pub mod compress;
+pub mod config;
+pub mod context;
 pub mod deps;
 pub mod diff;
 pub mod doctor;
 pub mod embed;
 pub mod export;
+pub mod flame;
 pub mod graph;
+pub mod grep;
 pub mod grok;
+pub mod heatmap;
 pub mod history;
 pub mod mcp;
+pub mod overview;
 pub mod patterns;
 pub mod query;
 pub mod read;
 pub mod search;
+pub mod stale;
+pub mod stats;
 pub mod status;
 pub mod vibes;
@@ -0,0 +1,448 @@
+//! Overview command - One-shot project summary for onboarding
+//!
+//! Composes pieces MU already computes (graph stats, language breakdown,
+//! module fan-in, entry points, architecture/testing patterns and complexity)
+//! into a single report. `--markdown` renders it for pasting into a README.
+
+use crate::commands::patterns::{
+    detect_architecture_patterns, detect_testing_patterns, DetectedPattern,
+};
+use crate::commands::vibes::conventions::detect_language;
+use crate::mubase::find_mubase;
+use crate::output::{Output, OutputFormat, TableDisplay};
+use anyhow::{Context, Result};
+use colored::Colorize;
+use duckdb::Connection;
+use serde::Serialize;
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::path::Path;
+
+/// Number of entries shown in the ranked sections.
+const TOP_N: usize = 5;
+
+/// File stems that conventionally mark a program entry point.
+const ENTRY_STEMS: &[&str] = &[
+    "main", "__main__", "index", "app", "cli", "server", "program",
+];
+
+/// Complexity histogram buckets as (label, inclusive upper bound).
+const COMPLEXITY_BUCKETS: &[(&str, u32)] =
+    &[("1-5", 5), ("6-10", 10), ("11-20", 20), ("21+", u32::MAX)];
+
+/// Share of modules written in one language.
+#[derive(Debug, Clone, Serialize)]
+pub struct LanguageStat {
+    pub language: String,
+    pub modules: usize,
+    pub percent: f32,
+}
+
+/// A module ranked by how many other modules import it.
+#[derive(Debug, Clone, Serialize)]
+pub struct CentralModule {
+    pub id: String,
+    pub file_path: String,
+    pub fan_in: usize,
+}
+
+/// Function count within one complexity range.
+#[derive(Debug, Clone, Serialize)]
+pub struct ComplexityBucket {
+    pub range: String,
+    pub count: usize,
+}
+
+/// One of the most complex functions.
+#[derive(Debug, Clone, Serialize)]
+pub struct Hotspot {
+    pub id: String,
+    pub name: String,
+    pub file_path: Option<String>,
+    pub complexity: u32,
+}
+
+/// Structured project overview.
+#[derive(Debug, Serialize)]
+pub struct ProjectOverview {
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub nodes_by_type: BTreeMap<String, usize>,
+    pub languages: Vec<LanguageStat>,
+    pub central_modules: Vec<CentralModule>,
+    pub entry_points: Vec<String>,
+    pub patterns: Vec<DetectedPattern>,
+    pub complexity_histogram: Vec<ComplexityBucket>,
+    pub hotspots: Vec<Hotspot>,
+}
+
+impl ProjectOverview {
+    /// Render the overview as Markdown.
+    pub fn to_markdown(&self) -> String {
+        let mut md = String::from("# Project Overview\n\n");
+        md.push_str(&format!(
+            "{} nodes, {} edges ({})\n",
+            self.node_count,
+            self.edge_count,
+            self.type_summary()
+        ));
+
+        md.push_str("\n## Languages\n\n| Language | Modules | Share |\n|---|---|---|\n");
+        for lang in &self.languages {
+            md.push_str(&format!(
+                "| {} | {} | {:.1}% |\n",
+                lang.language, lang.modules, lang.percent
+            ));
+        }
+
+        md.push_str("\n## Entry Points\n\n");
+        if self.entry_points.is_empty() {
+            md.push_str("_None detected._\n");
+        }
+        for entry in &self.entry_points {
+            md.push_str(&format!("- `{}`\n", entry));
+        }
+
+        md.push_str("\n## Most Depended-On Modules\n\n| Module | Imported by |\n|---|---|\n");
+        for module in &self.central_modules {
+            md.push_str(&format!("| `{}` | {} |\n", module.file_path, module.fan_in));
+        }
+
+        md.push_str("\n## Patterns\n\n");
+        if self.patterns.is_empty() {
+            md.push_str("_None detected._\n");
+        }
+        for pattern in &self.patterns {
+            md.push_str(&format!(
+                "- **{}**: {} ({}x)\n",
+                pattern.name, pattern.description, pattern.occurrences
+            ));
+        }
+
+        md.push_str("\n## Complexity\n\n| Range | Functions |\n|---|---|\n");
+        for bucket in &self.complexity_histogram {
+            md.push_str(&format!("| {} | {} |\n", bucket.range, bucket.count));
+        }
+
+        md.push_str("\n### Hotspots\n\n| Function | File | Complexity |\n|---|---|---|\n");
+        for hotspot in &self.hotspots {
+            md.push_str(&format!(
+                "| `{}` | {} | {} |\n",
+                hotspot.name,
+                hotspot.file_path.as_deref().unwrap_or("-"),
+                hotspot.complexity
+            ));
+        }
+
+        md
+    }
+
+    fn type_summary(&self) -> String {
+        self.nodes_by_type
+            .iter()
+            .map(|(node_type, count)| format!("{} {}", count, node_type))
+            .collect::<Vec<_>>()
+            .join(", ")
+    }
+}
+
+impl TableDisplay for ProjectOverview {
+    fn to_table(&self) -> String {
+        let mut lines = vec![format!(
+            "{} {} nodes, {} edges ({})",
+            "OVERVIEW:".cyan().bold(),
+            self.node_count,
+            self.edge_count,
+            self.type_summary()
+        )];
+
+        lines.push(format!("\n{}", "Languages".cyan().bold()));
+        for lang in &self.languages {
+            lines.push(format!(
+                "  {:<12} {:>5} modules  {:>5.1}%",
+                lang.language, lang.modules, lang.percent
+            ));
+        }
+
+        lines.push(format!("\n{}", "Entry Points".cyan().bold()));
+        if self.entry_points.is_empty() {
+            lines.push("  None detected.".dimmed().to_string());
+        }
+        for entry in &self.entry_points {
+            lines.push(format!("  {}", entry));
+        }
+
+        lines.push(format!("\n{}", "Most Depended-On Modules".cyan().bold()));
+        for module in &self.central_modules {
+            lines.push(format!("  {:>4}  {}", module.fan_in, module.file_path));
+        }
+
+        lines.push(format!("\n{}", "Patterns".cyan().bold()));
+        if self.patterns.is_empty() {
+            lines.push("  None detected.".dimmed().to_string());
+        }
+        for pattern in &self.patterns {
+            lines.push(format!(
+                "  {} ({}x) {}",
+                pattern.name.yellow(),
+                pattern.occurrences,
+                pattern.description.dimmed()
+            ));
+        }
+
+        lines.push(format!("\n{}", "Complexity".cyan().bold()));
+        for bucket in &self.complexity_histogram {
+            lines.push(format!("  {:<6} {}", bucket.range, bucket.count));
+        }
+        for hotspot in &self.hotspots {
+            lines.push(format!(
+                "  {} {} {}",
+                format!("{:>4}", hotspot.complexity).red(),
+                hotspot.name,
+                hotspot.file_path.as_deref().unwrap_or("").dimmed()
+            ));
+        }
+
+        lines.join("\n")
+    }
+
+    fn to_mu(&self) -> String {
+        let mut lines = vec![format!(
+            ":: overview nodes={} edges={}",
+            self.node_count, self.edge_count
+        )];
+        for lang in &self.languages {
+            lines.push(format!("# lang {} {}", lang.language, lang.modules));
+        }
+        for entry in &self.entry_points {
+            lines.push(format!("! {} [entry]", entry));
+        }
+        for module in &self.central_modules {
+            lines.push(format!("! {} fan_in={}", module.file_path, module.fan_in));
+        }
+        for pattern in &self.patterns {
+            lines.push(format!("@ {} count={}", pattern.name, pattern.occurrences));
+        }
+        for hotspot in &self.hotspots {
+            lines.push(format!("$ {} c={}", hotspot.name, hotspot.complexity));
+        }
+        lines.join("\n")
+    }
+}
+
+fn count(conn: &Connection, sql: &str) -> Result<usize> {
+    Ok(conn.query_row(sql, [], |row| row.get(0))?)
+}
+
+/// Count modules per language, largest share first.
+fn language_stats(module_paths: &[String]) -> Vec<LanguageStat> {
+    let mut counts: HashMap<&str, usize> = HashMap::new();
+    for path in module_paths {
+        *counts.entry(detect_language(path)).or_default() += 1;
+    }
+
+    let total = module_paths.len().max(1) as f32;
+    let mut stats: Vec<LanguageStat> = counts
+        .into_iter()
+        .map(|(language, modules)| LanguageStat {
+            language: language.to_string(),
+            modules,
+            percent: modules as f32 * 100.0 / total,
+        })
+        .collect();
+    stats.sort_by(|a, b| b.modules.cmp(&a.modules).then(a.language.cmp(&b.language)));
+    stats
+}
+
+/// Modules with a conventional entry-point name, plus any file defining `main`.
+fn entry_points(conn: &Connection, module_paths: &[String]) -> Result<Vec<String>> {
+    let mut entries: BTreeSet<String> = module_paths
+        .iter()
+        .filter(|path| {
+            Path::new(path)
+                .file_stem()
+                .and_then(|s| s.to_str())
+                .is_some_and(|stem| ENTRY_STEMS.contains(&stem))
+        })
+        .cloned()
+        .collect();
+
+    let mut stmt = conn.prepare(
+        "SELECT DISTINCT file_path FROM nodes WHERE type = 'function' AND name = 'main' AND file_path IS NOT NULL",
+    )?;
+    let mains = stmt.query_map([], |row| row.get::<_, String>(0))?;
+    for path in mains {
+        entries.insert(path?);
+    }
+
+    Ok(entries.into_iter().collect())
+}
+
+/// Build the overview from an open MUbase connection.
+pub fn build_overview(conn: &Connection) -> Result<ProjectOverview> {
+    let node_count = count(conn, "SELECT COUNT(*) FROM nodes")?;
+    let edge_count = count(conn, "SELECT COUNT(*) FROM edges")?;
+
+    let mut nodes_by_type = BTreeMap::new();
+    let mut stmt = conn.prepare("SELECT type, COUNT(*) FROM nodes GROUP BY type")?;
+    let rows = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get(1)?)))?;
+    for row in rows {
+        let (node_type, n) = row?;
+        nodes_by_type.insert(node_type, n);
+    }
+
+    let mut stmt = conn.prepare(
+        "SELECT file_path FROM nodes WHERE type = 'module' AND file_path IS NOT NULL ORDER BY file_path",
+    )?;
+    let module_paths = stmt
+        .query_map([], |row| row.get::<_, String>(0))?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let mut stmt = conn.prepare(
+        "SELECT e.target_id, n.file_path, COUNT(DISTINCT e.source_id) AS fan_in
+         FROM edges e JOIN nodes n ON n.id = e.target_id
+         WHERE e.type = 'imports' AND n.type = 'module'
+         GROUP BY e.target_id, n.file_path
+         ORDER BY fan_in DESC, e.target_id
+         LIMIT ?",
+    )?;
+    let central_modules = stmt
+        .query_map([TOP_N as i64], |row| {
+            Ok(CentralModule {
+                id: row.get(0)?,
+                file_path: row.get::<_, Option<String>>(1)?.unwrap_or_default(),
+                fan_in: row.get(2)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let mut stmt = conn.prepare("SELECT complexity FROM nodes WHERE type = 'function'")?;
+    let complexities = stmt
+        .query_map([], |row| Ok(row.get::<_, Option<u32>>(0)?.unwrap_or(0)))?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let mut lower = 0;
+    let complexity_histogram = COMPLEXITY_BUCKETS
+        .iter()
+        .map(|&(range, upper)| {
+            let count = complexities
+                .iter()
+                .filter(|&&c| c > lower && c <= upper)
+                .count();
+            lower = upper;
+            ComplexityBucket {
+                range: range.to_string(),
+                count,
+            }
+        })
+        .collect();
+
+    let mut stmt = conn.prepare(
+        "SELECT id, name, file_path, complexity FROM nodes
+         WHERE type = 'function' AND complexity > 0
+         ORDER BY complexity DESC, id
+         LIMIT ?",
+    )?;
+    let hotspots = stmt
+        .query_map([TOP_N as i64], |row| {
+            Ok(Hotspot {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                file_path: row.get(2)?,
+                complexity: row.get(3)?,
+            })
+        })?
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+
+    let mut patterns = detect_architecture_patterns(conn, false)?;
+    patterns.extend(detect_testing_patterns(conn, false)?);
+
+    Ok(ProjectOverview {
+        node_count,
+        edge_count,
+        nodes_by_type,
+        languages: language_stats(&module_paths),
+        central_modules,
+        entry_points: entry_points(conn, &module_paths)?,
+        patterns,
+        complexity_histogram,
+        hotspots,
+    })
+}
+
+/// Run the overview command
+pub async fn run(path: &str, markdown: bool, format: OutputFormat) -> Result<()> {
+    let db_path = find_mubase(path)?;
+    let conn = Connection::open_with_flags(
+        &db_path,
+        duckdb::Config::default().access_mode(duckdb::AccessMode::ReadOnly)?,
+    )
+    .with_context(|| format!("Failed to open database: {:?}", db_path))?;
+
+    let overview = build_overview(&conn)?;
+    if markdown {
+        print!("{}", overview.to_markdown());
+        return Ok(());
+    }
+    Output::new(overview, format).render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mu_daemon::storage::{Edge, MUbase, Node};
+    use tempfile::TempDir;
+
+    fn seeded_conn(dir: &TempDir) -> Connection {
+        let db_path = dir.path().join("mubase");
+        let mubase = MUbase::open(&db_path).unwrap();
+        mubase
+            .insert_nodes(&[
+                Node::module("src/main.py"),
+                Node::module("src/db.py"),
+                Node::module("web/app.ts"),
+                Node::function("src/main.py", "main", None, 1, 10, 2),
+                Node::function("src/db.py", "migrate", None, 1, 80, 27),
+                Node::function("web/app.ts", "render", None, 1, 20, 8),
+            ])
+            .unwrap();
+        mubase
+            .insert_edges(&[
+                Edge::imports("mod:src/main.py", "mod:src/db.py"),
+                Edge::imports("mod:web/app.ts", "mod:src/db.py"),
+            ])
+            .unwrap();
+        drop(mubase);
+        Connection::open(&db_path).unwrap()
+    }
+
+    #[test]
+    fn test_overview_summarizes_seeded_graph() {
+        let dir = TempDir::new().unwrap();
+        let overview = build_overview(&seeded_conn(&dir)).unwrap();
+
+        assert_eq!(overview.node_count, 6);
+        assert_eq!(overview.languages[0].language, "python");
+        assert_eq!(overview.languages[0].modules, 2);
+        assert!(overview
+            .languages
+            .iter()
+            .any(|l| l.language == "typescript"));
+
+        assert_eq!(overview.hotspots[0].name, "migrate");
+        assert_eq!(overview.hotspots[0].complexity, 27);
+
+        assert_eq!(overview.central_modules[0].file_path, "src/db.py");
+        assert_eq!(overview.central_modules[0].fan_in, 2);
+        assert_eq!(overview.entry_points, vec!["src/main.py", "web/app.ts"]);
+
+        let counts: Vec<usize> = overview
+            .complexity_histogram
+            .iter()
+            .map(|b| b.count)
+            .collect();
+        assert_eq!(counts, vec![1, 1, 0, 1]);
+
+        let md = overview.to_markdown();
+        assert!(md.contains("| python | 2 |"));
+        assert!(md.contains("| `migrate` | src/db.py | 27 |"));
+    }
+}
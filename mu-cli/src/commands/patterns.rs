@@ -3,44 +3,13 @@
 //! Analyzes the code graph to detect common patterns across different categories:
 //! naming conventions, architectural patterns, testing patterns, etc.
 
+use crate::mubase::find_mubase;
 use crate::output::{Output, OutputFormat, TableDisplay};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use duckdb::Connection;
 use serde::Serialize;
 use std::collections::HashMap;
-use std::path::PathBuf;
-
-/// Find the MUbase database in the given directory or its parents.
-fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize()?;
-    let mut current = start.as_path();
-
-    loop {
-        // New standard path: .mu/mubase
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Ok(db_path);
-        }
-
-        // Legacy path: .mubase
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Ok(legacy_path);
-        }
-
-        // Move up to parent
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "No MUbase found. Run 'mu bootstrap' first to create the database."
-                ))
-            }
-        }
-    }
-}
 
 /// Pattern category
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -262,13 +231,13 @@ fn detect_naming_patterns(
 
     // PascalCase classes
     let pascal_count: usize = conn.query_row(
-        "SELECT COUNT(*) FROM nodes WHERE type = 'class' AND name GLOB '[A-Z]*'",
+        "SELECT COUNT(*) FROM nodes WHERE type IN ('class', 'interface', 'enum') AND name GLOB '[A-Z]*'",
         [],
         |row| row.get(0),
     )?;
 
     let total_classes: usize = conn.query_row(
-        "SELECT COUNT(*) FROM nodes WHERE type = 'class'",
+        "SELECT COUNT(*) FROM nodes WHERE type IN ('class', 'interface', 'enum')",
         [],
         |row| row.get(0),
     )?;
@@ -278,7 +247,7 @@ fn detect_naming_patterns(
         let examples = if include_examples {
             get_examples(
                 conn,
-                "SELECT name FROM nodes WHERE type = 'class' AND name GLOB '[A-Z]*' LIMIT 5",
+                "SELECT name FROM nodes WHERE type IN ('class', 'interface', 'enum') AND name GLOB '[A-Z]*' LIMIT 5",
             )?
         } else {
             vec![]
@@ -296,7 +265,7 @@ fn detect_naming_patterns(
 
     // Service suffix pattern
     let service_count: usize = conn.query_row(
-        "SELECT COUNT(*) FROM nodes WHERE type = 'class' AND name LIKE '%Service'",
+        "SELECT COUNT(*) FROM nodes WHERE type IN ('class', 'interface', 'enum') AND name LIKE '%Service'",
         [],
         |row| row.get(0),
     )?;
@@ -305,7 +274,7 @@ fn detect_naming_patterns(
         let examples = if include_examples {
             get_examples(
                 conn,
-                "SELECT name FROM nodes WHERE type = 'class' AND name LIKE '%Service' LIMIT 5",
+                "SELECT name FROM nodes WHERE type IN ('class', 'interface', 'enum') AND name LIKE '%Service' LIMIT 5",
             )?
         } else {
             vec![]
@@ -325,7 +294,7 @@ fn detect_naming_patterns(
 }
 
 /// Detect architectural patterns
-fn detect_architecture_patterns(
+pub(crate) fn detect_architecture_patterns(
     conn: &Connection,
     include_examples: bool,
 ) -> Result<Vec<DetectedPattern>> {
@@ -333,14 +302,14 @@ fn detect_architecture_patterns(
 
     // Repository pattern
     let repo_count: usize = conn.query_row(
-        "SELECT COUNT(*) FROM nodes WHERE type = 'class' AND (name LIKE '%Repository' OR name LIKE '%Repo')",
+        "SELECT COUNT(*) FROM nodes WHERE type IN ('class', 'interface', 'enum') AND (name LIKE '%Repository' OR name LIKE '%Repo')",
         [],
         |row| row.get(0),
     )?;
 
     if repo_count >= 1 {
         let examples = if include_examples {
-            get_examples(conn, "SELECT name FROM nodes WHERE type = 'class' AND (name LIKE '%Repository' OR name LIKE '%Repo') LIMIT 5")?
+            get_examples(conn, "SELECT name FROM nodes WHERE type IN ('class', 'interface', 'enum') AND (name LIKE '%Repository' OR name LIKE '%Repo') LIMIT 5")?
         } else {
             vec![]
         };
@@ -357,14 +326,14 @@ fn detect_architecture_patterns(
 
     // Factory pattern
     let factory_count: usize = conn.query_row(
-        "SELECT COUNT(*) FROM nodes WHERE (type = 'class' OR type = 'function') AND name LIKE '%Factory%'",
+        "SELECT COUNT(*) FROM nodes WHERE type IN ('class', 'interface', 'enum', 'function') AND name LIKE '%Factory%'",
         [],
         |row| row.get(0),
     )?;
 
     if factory_count >= 1 {
         let examples = if include_examples {
-            get_examples(conn, "SELECT name FROM nodes WHERE (type = 'class' OR type = 'function') AND name LIKE '%Factory%' LIMIT 5")?
+            get_examples(conn, "SELECT name FROM nodes WHERE type IN ('class', 'interface', 'enum', 'function') AND name LIKE '%Factory%' LIMIT 5")?
         } else {
             vec![]
         };
@@ -381,14 +350,14 @@ fn detect_architecture_patterns(
 
     // Handler pattern (common in web frameworks)
     let handler_count: usize = conn.query_row(
-        "SELECT COUNT(*) FROM nodes WHERE (type = 'class' OR type = 'function') AND name LIKE '%Handler%'",
+        "SELECT COUNT(*) FROM nodes WHERE type IN ('class', 'interface', 'enum', 'function') AND name LIKE '%Handler%'",
         [],
         |row| row.get(0),
     )?;
 
     if handler_count >= 2 {
         let examples = if include_examples {
-            get_examples(conn, "SELECT name FROM nodes WHERE (type = 'class' OR type = 'function') AND name LIKE '%Handler%' LIMIT 5")?
+            get_examples(conn, "SELECT name FROM nodes WHERE type IN ('class', 'interface', 'enum', 'function') AND name LIKE '%Handler%' LIMIT 5")?
         } else {
             vec![]
         };
@@ -425,7 +394,7 @@ fn detect_architecture_patterns(
 }
 
 /// Detect testing patterns
-fn detect_testing_patterns(
+pub(crate) fn detect_testing_patterns(
     conn: &Connection,
     include_examples: bool,
 ) -> Result<Vec<DetectedPattern>> {
@@ -538,14 +507,14 @@ fn detect_error_handling_patterns(
 
     // Error/Exception classes
     let error_classes: usize = conn.query_row(
-        "SELECT COUNT(*) FROM nodes WHERE type = 'class' AND (name LIKE '%Error' OR name LIKE '%Exception')",
+        "SELECT COUNT(*) FROM nodes WHERE type IN ('class', 'interface', 'enum') AND (name LIKE '%Error' OR name LIKE '%Exception')",
         [],
         |row| row.get(0),
     )?;
 
     if error_classes >= 2 {
         let examples = if include_examples {
-            get_examples(conn, "SELECT name FROM nodes WHERE type = 'class' AND (name LIKE '%Error' OR name LIKE '%Exception') LIMIT 5")?
+            get_examples(conn, "SELECT name FROM nodes WHERE type IN ('class', 'interface', 'enum') AND (name LIKE '%Error' OR name LIKE '%Exception') LIMIT 5")?
         } else {
             vec![]
         };
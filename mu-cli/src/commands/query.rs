@@ -11,49 +11,26 @@
 //!   mu q "SHOW TABLES"
 //!   mu q --format json "SELECT * FROM classes"
 //!   mu q --limit 20 "SELECT * FROM functions"
+//!   mu q --limit 20 --offset 40 "fn c>10"       # Third page of 20
 //!   mu q "fn c>50"                              # Terse syntax
 //!   mu q "cls n%Service"                        # Classes matching pattern
-
+//!   mu q "FIND functions WHERE name MATCHES '^handle.*Request$'"
+//!   mu q "SHOW dependents OF AuthService"       # Graph traversal
+//!   mu q "SHOW dependencies OF main VIA calls"  # Only call edges
+//!   mu q --file health.muql                     # Run a script of queries
+
+use crate::commands::deps::find_dependencies_via;
+use crate::commands::graph::resolve_node;
+use crate::mubase::find_mubase;
 use crate::output::{OutputFormat, TableDisplay};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use duckdb::Connection;
+use mu_core::graph::TraversalDirection;
 use serde::Serialize;
-use std::path::PathBuf;
 use std::time::Instant;
 use tabled::{builder::Builder, settings::Style};
 
-/// Find the MUbase database in the given directory or its parents.
-fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize()?;
-    let mut current = start.as_path();
-
-    loop {
-        // New standard path: .mu/mubase
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Ok(db_path);
-        }
-
-        // Legacy path: .mubase
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Ok(legacy_path);
-        }
-
-        // Move up to parent
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "No MUbase found. Run 'mu bootstrap' first to create the database."
-                ))
-            }
-        }
-    }
-}
-
 /// Result of terse syntax parsing
 enum TerseParseResult {
     /// Successfully converted to SQL
@@ -62,8 +39,21 @@ enum TerseParseResult {
     NotTerse,
     /// Terse graph operation - requires daemon
     RequiresDaemon(String),
+    /// Terse syntax with an invalid argument
+    Invalid(String),
 }
 
+/// Columns selected by terse queries (and therefore valid sort keys)
+const TERSE_COLUMNS: &[&str] = &[
+    "id",
+    "type",
+    "name",
+    "file_path",
+    "line_start",
+    "line_end",
+    "complexity",
+];
+
 /// Try to convert terse MUQL syntax to SQL.
 ///
 /// Terse syntax patterns:
@@ -75,6 +65,10 @@ enum TerseParseResult {
 /// - `cls` -> SELECT * FROM classes
 /// - `mod` -> SELECT * FROM modules
 /// - `meth` -> SELECT * FROM methods (same as functions)
+/// - `fn o:-complexity,name` -> ... ORDER BY complexity DESC, name ASC, id ASC
+/// - `fn l20 off40` / `fn LIMIT 20 OFFSET 40` -> ... LIMIT 20 OFFSET 40
+///
+/// Sorting always ends with `id` so ties come back in a stable order.
 ///
 /// Graph operations that require daemon:
 /// - `deps TARGET [dN]` -> SHOW dependencies
@@ -147,20 +141,45 @@ fn try_convert_terse_to_sql(input: &str) -> TerseParseResult {
         "fn" | "func" | "functions" => "function",
         "cls" | "class" | "classes" => "class",
         "mod" | "module" | "modules" => "module",
+        "iface" | "interface" | "interfaces" => "interface",
+        "enum" | "enums" => "enum",
         "meth" | "method" | "methods" => "function", // methods are functions
         _ => return TerseParseResult::NotTerse,
     };
 
     // Base query
-    let base_columns = "id, type, name, file_path, line_start, line_end, complexity";
+    let base_columns = TERSE_COLUMNS.join(", ");
     let mut conditions: Vec<String> = vec![format!("type = '{}'", sql_type)];
     let mut limit = 100;
-    let mut order_by: Option<String> = None;
+    let mut offset = 0;
+    let mut order_by: Vec<(String, &str)> = Vec::new();
 
     // Parse remaining tokens as filters
-    for token in tokens.iter().skip(1) {
+    let mut rest = tokens.iter().skip(1);
+    while let Some(token) = rest.next() {
         let token_lower = token.to_lowercase();
 
+        // Pagination keywords: LIMIT 10, OFFSET 20 (as appended by --limit/--offset)
+        if token_lower == "limit" || token_lower == "offset" {
+            if let Some(num) = rest.clone().next().and_then(|n| n.parse::<usize>().ok()) {
+                rest.next();
+                if token_lower == "limit" {
+                    limit = num;
+                } else {
+                    offset = num;
+                }
+                continue;
+            }
+        }
+
+        // Offset: off20
+        if let Some(n) = token_lower.strip_prefix("off") {
+            if let Ok(num) = n.parse::<usize>() {
+                offset = num;
+                continue;
+            }
+        }
+
         // Limit: l10 or limit10
         if let Some(n) = token_lower.strip_prefix('l') {
             if let Ok(num) = n.parse::<usize>() {
@@ -211,20 +230,22 @@ fn try_convert_terse_to_sql(input: &str) -> TerseParseResult {
             continue;
         }
 
-        // Order: o:complexity, o:-complexity (descending), o:name
-        if let Some(field) = token_lower.strip_prefix("o:") {
-            let (field_name, desc) = if let Some(f) = field.strip_prefix('-') {
-                (f, true)
-            } else {
-                (field, false)
-            };
-            // Validate field name (basic alphanumeric check)
-            if field_name.chars().all(|c| c.is_alphanumeric() || c == '_') {
-                order_by = Some(if desc {
-                    format!("{} DESC", field_name)
-                } else {
-                    format!("{} ASC", field_name)
-                });
+        // Order: o:complexity, o:-complexity (descending), o:-complexity,name
+        if let Some(fields) = token_lower.strip_prefix("o:") {
+            for field in fields.split(',').filter(|f| !f.is_empty()) {
+                let (field_name, direction) = match field.strip_prefix('-') {
+                    Some(f) => (f, "DESC"),
+                    None => (field, "ASC"),
+                };
+                if !TERSE_COLUMNS.contains(&field_name) {
+                    return TerseParseResult::Invalid(format!(
+                        "Unknown sort column '{}'. Valid columns: {}",
+                        field_name,
+                        TERSE_COLUMNS.join(", ")
+                    ));
+                }
+                order_by.retain(|(name, _)| name != field_name);
+                order_by.push((field_name.to_string(), direction));
             }
             continue;
         }
@@ -248,17 +269,158 @@ fn try_convert_terse_to_sql(input: &str) -> TerseParseResult {
         sql.push_str(&conditions.join(" AND "));
     }
 
-    if let Some(ob) = order_by {
-        sql.push_str(&format!(" ORDER BY {}", ob));
+    if !order_by.is_empty() {
+        if !order_by.iter().any(|(name, _)| name == "id") {
+            order_by.push(("id".to_string(), "ASC"));
+        }
+        let keys: Vec<String> = order_by
+            .iter()
+            .map(|(name, direction)| format!("{} {}", name, direction))
+            .collect();
+        sql.push_str(&format!(" ORDER BY {}", keys.join(", ")));
     }
 
     sql.push_str(&format!(" LIMIT {}", limit));
+    if offset > 0 {
+        sql.push_str(&format!(" OFFSET {}", offset));
+    }
 
     TerseParseResult::Sql(sql)
 }
 
+/// A MUQL graph traversal:
+/// `SHOW <relation> OF <target> [DEPTH <n>] [VIA <types>] [LIMIT <n>] [OFFSET <m>]`.
+#[derive(Debug, PartialEq)]
+struct ShowTraversal {
+    direction: TraversalDirection,
+    target: String,
+    depth: u8,
+    /// Edge types to follow (`VIA calls,imports`); all but `contains` if unset
+    edge_types: Option<Vec<String>>,
+    limit: Option<usize>,
+    offset: usize,
+}
+
+/// Parse a `SHOW dependencies|dependents|ancestors|impact OF <target>` query.
+///
+/// `dependencies` and `ancestors` follow outgoing edges (what the target
+/// uses); `dependents` and `impact` follow incoming edges (what uses the
+/// target). `dependencies`/`dependents` default to depth 1, the transitive
+/// `ancestors`/`impact` to unlimited. `VIA` takes a comma-separated list of
+/// edge types, as `--edge-types` does for `mu impact`.
+fn parse_show_traversal(query: &str) -> Option<ShowTraversal> {
+    let tokens: Vec<&str> = query
+        .trim()
+        .trim_end_matches(';')
+        .split_whitespace()
+        .collect();
+    if tokens.len() < 4
+        || !tokens[0].eq_ignore_ascii_case("show")
+        || !tokens[2].eq_ignore_ascii_case("of")
+    {
+        return None;
+    }
+
+    let (direction, default_depth) = match tokens[1].to_lowercase().as_str() {
+        "dependencies" | "deps" => (TraversalDirection::Dependencies, 1),
+        "ancestors" => (TraversalDirection::Dependencies, u8::MAX),
+        "dependents" | "usedby" => (TraversalDirection::Dependents, 1),
+        "impact" => (TraversalDirection::Dependents, u8::MAX),
+        _ => return None,
+    };
+
+    let mut depth = default_depth;
+    let mut edge_types = None;
+    let mut limit = None;
+    let mut offset = 0;
+    for clause in tokens[4..].chunks(2) {
+        match clause {
+            [keyword, n] if keyword.eq_ignore_ascii_case("depth") => depth = n.parse().ok()?,
+            [keyword, types] if keyword.eq_ignore_ascii_case("via") => {
+                edge_types = Some(
+                    types
+                        .split(',')
+                        .filter(|t| !t.is_empty())
+                        .map(str::to_lowercase)
+                        .collect(),
+                )
+            }
+            [keyword, n] if keyword.eq_ignore_ascii_case("limit") => limit = Some(n.parse().ok()?),
+            [keyword, n] if keyword.eq_ignore_ascii_case("offset") => offset = n.parse().ok()?,
+            _ => return None,
+        }
+    }
+
+    Some(ShowTraversal {
+        direction,
+        target: tokens[3]
+            .trim_matches(|c| c == '\'' || c == '"')
+            .to_string(),
+        depth,
+        edge_types,
+        limit,
+        offset,
+    })
+}
+
+/// Execute a `SHOW` traversal with the same BFS as `mu deps` / `mu usedby`.
+fn execute_show_traversal(show: &ShowTraversal, start: Instant) -> Result<QueryResult> {
+    let db_path = find_mubase(".")?;
+    let conn = Connection::open_with_flags(
+        &db_path,
+        duckdb::Config::default().access_mode(duckdb::AccessMode::ReadOnly)?,
+    )
+    .with_context(|| format!("Failed to open database: {:?}", db_path))?;
+
+    let node_id = resolve_node(&conn, &show.target, None)?;
+    let deps = find_dependencies_via(
+        &conn,
+        &node_id,
+        show.direction,
+        show.depth,
+        false,
+        show.edge_types.as_deref(),
+    )?;
+    let total_count = (show.limit.is_some() || show.offset > 0).then_some(deps.len());
+    let rows: Vec<Vec<String>> = deps
+        .into_iter()
+        .skip(show.offset)
+        .take(show.limit.unwrap_or(usize::MAX))
+        .map(|dep| {
+            vec![
+                dep.id,
+                dep.name,
+                dep.node_type,
+                dep.edge_type,
+                dep.depth.to_string(),
+                dep.file_path.unwrap_or_else(|| "NULL".to_string()),
+            ]
+        })
+        .collect();
+
+    Ok(QueryResult {
+        columns: ["id", "name", "type", "edge_type", "depth", "file_path"]
+            .iter()
+            .map(|c| c.to_string())
+            .collect(),
+        row_count: rows.len(),
+        total_count,
+        rows,
+        execution_time_ms: start.elapsed().as_millis() as u64,
+        error: None,
+    })
+}
+
+/// The query without its trailing `LIMIT n [OFFSET m]` or `OFFSET m`, if it has one.
+fn strip_pagination(sql: &str) -> Option<&str> {
+    let re = regex::Regex::new(r"(?i)(\s+LIMIT\s+\d+(\s+OFFSET\s+\d+)?|\s+OFFSET\s+\d+)\s*;?\s*$")
+        .unwrap();
+    re.find(sql).map(|m| &sql[..m.start()])
+}
+
 /// Normalize type values in SQL queries to lowercase.
-/// Database stores types as: 'function', 'class', 'module', 'external'
+/// Database stores types as: 'function', 'class', 'interface', 'enum',
+/// 'module', 'external'
 fn normalize_type_in_sql(sql: &str) -> String {
     // Match patterns like: type = 'Class' or type='CLASS' or type = "Function"
     let re = regex::Regex::new(r#"(?i)\btype\s*=\s*['"]([^'"]+)['"]"#).unwrap();
@@ -275,6 +437,8 @@ fn normalize_type_in_sql(sql: &str) -> String {
 /// - `FROM functions` -> `FROM nodes WHERE type = 'function'`
 /// - `FROM classes` -> `FROM nodes WHERE type = 'class'`
 /// - `FROM modules` -> `FROM nodes WHERE type = 'module'`
+/// - `FROM interfaces` -> `FROM nodes WHERE type = 'interface'`
+/// - `FROM enums` -> `FROM nodes WHERE type = 'enum'`
 ///
 /// Handles WHERE clause merging:
 /// - `SELECT * FROM functions WHERE complexity > 10`
@@ -285,6 +449,8 @@ fn rewrite_virtual_tables(sql: &str) -> String {
         ("functions", "function"),
         ("classes", "class"),
         ("modules", "module"),
+        ("interfaces", "interface"),
+        ("enums", "enum"),
     ];
 
     let mut result = sql.to_string();
@@ -324,6 +490,149 @@ fn rewrite_virtual_tables(sql: &str) -> String {
     result
 }
 
+/// Rewrite `FIND <table> [WHERE ...]` to `SELECT * FROM <table> [WHERE ...]`.
+fn rewrite_find(sql: &str) -> String {
+    let re = regex::Regex::new(r"(?i)^\s*FIND\s+").unwrap();
+    re.replace(sql, "SELECT * FROM ").to_string()
+}
+
+/// Rewrite `<column> [NOT] MATCHES '<regex>'` to DuckDB's `regexp_matches`.
+///
+/// Patterns are compiled first, so a typo is reported as a MUQL error
+/// instead of a DuckDB one; both engines use RE2-style syntax. Text inside
+/// string literals is left alone.
+fn rewrite_matches(sql: &str) -> Result<String, String> {
+    let re = regex::Regex::new(r"(?i)\b([A-Za-z_][\w.]*)\s+(NOT\s+)?MATCHES\s+'((?:[^']|'')*)'")
+        .unwrap();
+    let mut unquoted = std::collections::HashSet::new();
+    walk_unquoted(sql, |i, _, _| {
+        unquoted.insert(i);
+    });
+
+    let mut result = String::with_capacity(sql.len());
+    let mut last = 0;
+    for caps in re.captures_iter(sql) {
+        let whole = caps.get(0).unwrap();
+        if !unquoted.contains(&whole.start()) {
+            continue;
+        }
+        let pattern = &caps[3];
+        if let Err(e) = regex::Regex::new(&pattern.replace("''", "'")) {
+            return Err(format!("Invalid regex in MATCHES '{}':\n{}", pattern, e));
+        }
+        let negate = if caps.get(2).is_some() { "NOT " } else { "" };
+        result.push_str(&sql[last..whole.start()]);
+        result.push_str(&format!(
+            "{}regexp_matches({}, '{}')",
+            negate, &caps[1], pattern
+        ));
+        last = whole.end();
+    }
+    result.push_str(&sql[last..]);
+    Ok(result)
+}
+
+/// Aggregates that get a column alias when selected without one
+const AGGREGATES: &[&str] = &["count", "avg", "min", "max", "sum"];
+
+/// Walk `sql` outside quotes, calling `visit` with each byte offset, char
+/// and parenthesis depth. Returns the final depth, or `None` if a `)` closes
+/// more than was opened.
+fn walk_unquoted(sql: &str, mut visit: impl FnMut(usize, char, i32)) -> Option<i32> {
+    let mut depth = 0i32;
+    let mut quote: Option<char> = None;
+
+    for (i, c) in sql.char_indices() {
+        match (quote, c) {
+            (Some(q), _) if c == q => quote = None,
+            (Some(_), _) => {}
+            (None, '\'' | '"') => quote = Some(c),
+            (None, '(') => depth += 1,
+            (None, ')') => {
+                depth -= 1;
+                if depth < 0 {
+                    return None;
+                }
+            }
+            (None, _) => visit(i, c, depth),
+        }
+    }
+    Some(depth)
+}
+
+/// Whether every parenthesis in `sql` (outside quotes) is matched.
+fn is_balanced(sql: &str) -> bool {
+    walk_unquoted(sql, |_, _, _| {}) == Some(0)
+}
+
+/// Split `sql` on commas outside parentheses and quotes.
+fn split_top_level(sql: &str) -> Vec<&str> {
+    let mut cuts = Vec::new();
+    walk_unquoted(sql, |i, c, depth| {
+        if c == ',' && depth == 0 {
+            cuts.push(i);
+        }
+    });
+
+    let mut parts = Vec::new();
+    let mut begin = 0;
+    for cut in cuts {
+        parts.push(&sql[begin..cut]);
+        begin = cut + 1;
+    }
+    parts.push(&sql[begin..]);
+    parts
+}
+
+/// Name unaliased aggregate columns after their expression.
+///
+/// DuckDB would call `COUNT(*)` `count_star()`; this keeps the header as
+/// written, so `SELECT file_path, count(*) FROM ...` returns a `COUNT(*)`
+/// column. Items that already have an alias are left alone.
+fn alias_aggregates(sql: &str) -> String {
+    let select_re = regex::Regex::new(r"(?is)^\s*SELECT\s+(.*?)\s+FROM\b").unwrap();
+    let Some(list) = select_re.captures(sql).and_then(|caps| caps.get(1)) else {
+        return sql.to_string();
+    };
+    // The lazy match stops at the first FROM, which may sit inside a subquery
+    if !is_balanced(list.as_str()) {
+        return sql.to_string();
+    }
+
+    let items: Vec<String> = split_top_level(list.as_str())
+        .into_iter()
+        .map(|item| {
+            let expr = item.trim();
+            match aggregate_alias(expr) {
+                Some(alias) => format!("{} AS \"{}\"", expr, alias.replace('"', "\"\"")),
+                None => expr.to_string(),
+            }
+        })
+        .collect();
+
+    format!(
+        "{}{}{}",
+        &sql[..list.start()],
+        items.join(", "),
+        &sql[list.end()..]
+    )
+}
+
+/// `COUNT(*)`-style alias for a bare aggregate call, or `None`.
+fn aggregate_alias(expr: &str) -> Option<String> {
+    let open = expr.find('(')?;
+    let function = expr[..open].trim();
+    if !AGGREGATES.contains(&function.to_lowercase().as_str()) || !expr.ends_with(')') {
+        return None;
+    }
+    // The call's own parenthesis must be the one that closes the item
+    let args = &expr[open + 1..expr.len() - 1];
+    if !is_balanced(args) {
+        return None;
+    }
+    Some(format!("{}({})", function.to_uppercase(), args.trim()))
+}
+
 /// Execute a SQL query directly against the database (standalone mode).
 ///
 /// Opens the database in read-only mode and executes raw SQL.
@@ -337,7 +646,7 @@ fn execute_query_direct(query_str: &str) -> Result<QueryResult> {
         TerseParseResult::NotTerse => {
             // Not terse syntax - rewrite virtual tables (functions, classes, modules)
             // to nodes table with type filter for standalone mode
-            rewrite_virtual_tables(query_str)
+            rewrite_virtual_tables(&rewrite_find(query_str))
         }
         TerseParseResult::RequiresDaemon(suggestion) => {
             return Err(anyhow::anyhow!(
@@ -345,11 +654,33 @@ fn execute_query_direct(query_str: &str) -> Result<QueryResult> {
                 suggestion
             ));
         }
+        TerseParseResult::Invalid(message) => {
+            return Err(anyhow::anyhow!(
+                "{}\n\nHint: Run 'mu query --examples' for valid query examples.",
+                message
+            ));
+        }
     };
 
+    // Graph traversals run against the edges table rather than as SQL
+    if let Some(show) = parse_show_traversal(&final_query) {
+        return execute_show_traversal(&show, start);
+    }
+
     // Normalize type values in WHERE clauses (case-insensitive)
     let final_query = normalize_type_in_sql(&final_query);
 
+    // Regex filters run as DuckDB's regexp_matches
+    let final_query = rewrite_matches(&final_query).map_err(|message| {
+        anyhow::anyhow!(
+            "{}\n\nHint: MATCHES takes a regular expression, e.g. name MATCHES '^handle.*Request$'",
+            message
+        )
+    })?;
+
+    // Aggregate columns are named after their expression
+    let final_query = alias_aggregates(&final_query);
+
     // Find the database
     let db_path = find_mubase(".")?;
 
@@ -437,11 +768,23 @@ fn execute_query_direct(query_str: &str) -> Result<QueryResult> {
         rows_data.push(row_values);
     }
 
+    // Paged queries also report how many rows the unpaged query has
+    let total_count = strip_pagination(&final_query).and_then(|base| {
+        conn.query_row(
+            &format!("SELECT COUNT(*) FROM ({}) AS page", base),
+            [],
+            |row| row.get::<_, i64>(0),
+        )
+        .ok()
+        .map(|n| n as usize)
+    });
+
     let duration_ms = start.elapsed().as_millis() as u64;
 
     Ok(QueryResult {
         columns,
         row_count: rows_data.len(),
+        total_count,
         rows: rows_data,
         execution_time_ms: duration_ms,
         error: None,
@@ -456,6 +799,7 @@ MUQL Query Examples
 Basic SELECT queries:
   SELECT * FROM functions                    # All functions
   SELECT * FROM functions LIMIT 10           # First 10 functions
+  SELECT * FROM functions LIMIT 10 OFFSET 20 # Third page of 10
   SELECT name, complexity FROM functions     # Specific columns
   SELECT * FROM classes WHERE complexity > 20
 
@@ -464,10 +808,18 @@ Filtering:
   SELECT * FROM functions WHERE name = 'parse_file'
   SELECT * FROM classes WHERE file_path LIKE 'src/api/%'
 
-Aggregation:
+Regex filters (FIND <table> is SELECT * FROM <table>):
+  FIND functions WHERE name MATCHES 'handle.*Request'
+  FIND classes WHERE name MATCHES '(?i)^user' AND file_path NOT MATCHES 'test'
+
+Aggregation (COUNT, AVG, MIN, MAX, SUM):
   SELECT COUNT(*) FROM functions
   SELECT type, COUNT(*) FROM nodes GROUP BY type
   SELECT AVG(complexity) FROM functions
+  SELECT file_path, COUNT(*), AVG(complexity) FROM functions GROUP BY file_path
+
+Sorting:
+  SELECT name, complexity FROM functions ORDER BY complexity DESC, name ASC
 
 Terse syntax (shortcuts):
   fn                                         # All functions
@@ -477,11 +829,21 @@ Terse syntax (shortcuts):
   cls                                        # All classes
   mod                                        # All modules
   fn c>10 l5 o:-complexity                   # Combined: filter, limit, order
+  fn o:-complexity,name                      # Sort by several columns
+  fn c>10 l20 off40                          # Third page of 20
 
 Schema info:
   SHOW TABLES                                # List available tables
   DESCRIBE nodes                             # Schema for nodes table
 
+Graph traversal (edges point from dependent to dependency):
+  SHOW dependencies OF MyClass               # What MyClass uses (outgoing)
+  SHOW dependents OF MyClass DEPTH 2         # What uses MyClass (incoming)
+  SHOW ancestors OF Parser                   # Transitive dependencies
+  SHOW impact OF Parser                      # Transitive dependents
+  SHOW dependencies OF main VIA calls        # Follow only call edges
+  SHOW impact OF Parser LIMIT 20 OFFSET 20   # Second page of dependents
+
 Graph operations (use dedicated commands):
   mu deps MyClass                            # Dependencies of MyClass
   mu deps MyClass -r                         # What depends on MyClass
@@ -508,10 +870,12 @@ Virtual tables (auto-rewritten to nodes with type filter):
   functions   -> nodes WHERE type = 'function'
   classes     -> nodes WHERE type = 'class'
   modules     -> nodes WHERE type = 'module'
+  interfaces  -> nodes WHERE type = 'interface'
+  enums       -> nodes WHERE type = 'enum'
 
 Node columns:
   id            VARCHAR   Node identifier (e.g., "cls:src/auth.py:AuthService")
-  type          VARCHAR   Node type: module, class, function
+  type          VARCHAR   Node type: module, class, interface, enum, function
   name          VARCHAR   Simple name (e.g., "AuthService")
   qualified_name VARCHAR  Full qualified name
   file_path     VARCHAR   Source file path
@@ -525,15 +889,17 @@ Edge columns:
   target_id     VARCHAR   Target node ID
   type          VARCHAR   Edge type (see below)
 
-Edge types:
+Edge types (source depends on target):
   contains   - Module->Class, Class->Function (structural)
   imports    - Module->Module (import dependencies)
   inherits   - Class->Class (inheritance)
+  implements - Class->Class (Go struct satisfies interface)
   calls      - Function->Function (call graph)
 
 Common filters:
   WHERE complexity > 20        # High complexity
   WHERE name LIKE 'test_%'     # Name pattern
+  WHERE name MATCHES '^get_'   # Name regex (NOT MATCHES to exclude)
   WHERE file_path LIKE 'src/%' # Path pattern
   WHERE type = 'function'      # Node type
 
@@ -546,6 +912,9 @@ pub struct QueryResult {
     pub columns: Vec<String>,
     pub rows: Vec<Vec<String>>,
     pub row_count: usize,
+    /// Rows the query matches before LIMIT/OFFSET; only set for paged queries
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_count: Option<usize>,
     pub execution_time_ms: u64,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub error: Option<String>,
@@ -576,10 +945,15 @@ impl TableDisplay for QueryResult {
         table.with(Style::rounded());
 
         // Add footer with stats
+        let total = match self.total_count {
+            Some(total) => format!(" of {}", total.to_string().cyan()),
+            None => String::new(),
+        };
         let footer = format!(
-            "\n{} {} row(s) in {}ms",
+            "\n{} {}{} row(s) in {}ms",
             "Returned".dimmed(),
             self.row_count.to_string().cyan(),
+            total,
             self.execution_time_ms.to_string().yellow()
         );
 
@@ -610,9 +984,13 @@ impl TableDisplay for QueryResult {
             output.push_str(&format!("(row {})\n", values.join(" ")));
         }
 
+        let total = match self.total_count {
+            Some(total) => format!(" of {}", total),
+            None => String::new(),
+        };
         output.push_str(&format!(
-            "; {} rows, {}ms\n",
-            self.row_count, self.execution_time_ms
+            "; {}{} rows, {}ms\n",
+            self.row_count, total, self.execution_time_ms
         ));
         output
     }
@@ -685,7 +1063,7 @@ fn print_result(result: &QueryResult, format: OutputFormat) -> Result<()> {
         OutputFormat::Table => {
             println!("{}", result.to_table());
         }
-        OutputFormat::Json => {
+        OutputFormat::Json | OutputFormat::Sarif => {
             println!("{}", serde_json::to_string_pretty(result)?);
         }
         OutputFormat::Mu => {
@@ -708,6 +1086,7 @@ pub async fn run_extended(
     interactive: bool,
     format: OutputFormat,
     limit: Option<usize>,
+    offset: Option<usize>,
     examples: bool,
     schema: bool,
 ) -> Result<()> {
@@ -741,17 +1120,8 @@ pub async fn run_extended(
 
     let query_str = query_str.unwrap();
 
-    // Apply limit override if specified
-    let final_query = if let Some(limit) = limit {
-        // Check if query already has LIMIT
-        if query_str.to_uppercase().contains(" LIMIT ") {
-            query_str.to_string()
-        } else {
-            format!("{} LIMIT {}", query_str, limit)
-        }
-    } else {
-        query_str.to_string()
-    };
+    // Apply limit/offset overrides if specified
+    let final_query = apply_pagination(query_str, limit, offset);
 
     let result = execute_query_direct(&final_query)?;
     print_result(&result, format)?;
@@ -764,6 +1134,147 @@ pub async fn run_extended(
     Ok(())
 }
 
+/// Split a MUQL script into statements.
+///
+/// Statements are separated by `;`. Lines starting with `--` are comments.
+/// Semicolons inside single-quoted strings do not end a statement.
+pub fn split_script(script: &str) -> Vec<String> {
+    let mut statements = Vec::new();
+    let mut current = String::new();
+    let mut in_string = false;
+
+    for line in script.lines() {
+        if !in_string && line.trim_start().starts_with("--") {
+            continue;
+        }
+        for c in line.chars() {
+            match c {
+                '\'' => {
+                    in_string = !in_string;
+                    current.push(c);
+                }
+                ';' if !in_string => {
+                    statements.push(std::mem::take(&mut current));
+                }
+                _ => current.push(c),
+            }
+        }
+        current.push('\n');
+    }
+    statements.push(current);
+
+    statements
+        .into_iter()
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// A statement from a script together with its result
+#[derive(Debug, Serialize)]
+pub struct ScriptEntry {
+    pub query: String,
+    pub result: QueryResult,
+}
+
+/// Render script results, labeling each section with its query.
+fn format_script(entries: &[ScriptEntry], format: OutputFormat) -> Result<String> {
+    if format == OutputFormat::Json {
+        return Ok(serde_json::to_string_pretty(entries)?);
+    }
+
+    let total = entries.len();
+    let sections: Vec<String> = entries
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let (header, body) = match format {
+                OutputFormat::Mu => (
+                    format!(":: [{}/{}] {}", i + 1, total, entry.query),
+                    entry.result.to_mu(),
+                ),
+                OutputFormat::Csv => (
+                    format!("# [{}/{}] {}", i + 1, total, entry.query),
+                    format_csv(&entry.result),
+                ),
+                _ => (
+                    format!("-- [{}/{}] {}", i + 1, total, entry.query)
+                        .cyan()
+                        .bold()
+                        .to_string(),
+                    entry.result.to_table(),
+                ),
+            };
+            format!("{}\n{}", header, body.trim_end())
+        })
+        .collect();
+
+    Ok(sections.join("\n\n"))
+}
+
+/// Run every statement in a MUQL script file, in order.
+///
+/// All statements run even if one fails; the command exits non-zero if any did.
+pub async fn run_script(
+    path: &str,
+    format: OutputFormat,
+    limit: Option<usize>,
+    offset: Option<usize>,
+) -> Result<()> {
+    let script = std::fs::read_to_string(path)
+        .with_context(|| format!("Failed to read query file: {}", path))?;
+
+    let statements = split_script(&script);
+    if statements.is_empty() {
+        anyhow::bail!("No queries found in {}", path);
+    }
+
+    let entries: Vec<ScriptEntry> = statements
+        .into_iter()
+        .map(|query| {
+            let result = execute_query_direct(&apply_pagination(&query, limit, offset))
+                .unwrap_or_else(|e| QueryResult {
+                    columns: vec![],
+                    rows: vec![],
+                    row_count: 0,
+                    total_count: None,
+                    execution_time_ms: 0,
+                    error: Some(e.to_string()),
+                });
+            ScriptEntry { query, result }
+        })
+        .collect();
+
+    println!("{}", format_script(&entries, format)?);
+
+    if entries.iter().any(|e| e.result.error.is_some()) {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+/// Append LIMIT and OFFSET clauses unless the query already has them.
+fn apply_pagination(query_str: &str, limit: Option<usize>, offset: Option<usize>) -> String {
+    let upper = query_str.to_uppercase();
+    let mut clauses = String::new();
+    match limit {
+        // Check if query already has LIMIT
+        Some(limit) if !upper.contains(" LIMIT ") => clauses.push_str(&format!(" LIMIT {}", limit)),
+        _ => {}
+    }
+    match offset {
+        Some(offset) if !upper.contains(" OFFSET ") => {
+            clauses.push_str(&format!(" OFFSET {}", offset))
+        }
+        _ => {}
+    }
+    if clauses.is_empty() {
+        return query_str.to_string();
+    }
+    format!("{}{}", query_str.trim_end().trim_end_matches(';'), clauses)
+}
+
 /// Format result as CSV string
 #[allow(dead_code)]
 pub fn to_csv(result: &QueryResult) -> String {
@@ -784,6 +1295,7 @@ mod tests {
                 vec!["with\"quote".to_string(), "ok".to_string()],
             ],
             row_count: 3,
+            total_count: None,
             execution_time_ms: 10,
             error: None,
         };
@@ -801,6 +1313,7 @@ mod tests {
             columns: vec![],
             rows: vec![],
             row_count: 0,
+            total_count: None,
             execution_time_ms: 5,
             error: None,
         };
@@ -815,6 +1328,7 @@ mod tests {
             columns: vec![],
             rows: vec![],
             row_count: 0,
+            total_count: None,
             execution_time_ms: 0,
             error: Some("Syntax error".to_string()),
         };
@@ -913,6 +1427,35 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_terse_multi_column_order() {
+        match try_convert_terse_to_sql("fn o:-complexity,name") {
+            TerseParseResult::Sql(sql) => {
+                assert!(sql.ends_with("ORDER BY complexity DESC, name ASC, id ASC LIMIT 100"));
+            }
+            _ => panic!("Expected Sql result"),
+        }
+
+        // Repeated o: tokens append keys; a repeated column keeps its latest direction
+        match try_convert_terse_to_sql("fn o:name o:-id o:-name") {
+            TerseParseResult::Sql(sql) => {
+                assert!(sql.contains("ORDER BY id DESC, name DESC LIMIT"));
+            }
+            _ => panic!("Expected Sql result"),
+        }
+    }
+
+    #[test]
+    fn test_terse_order_unknown_column() {
+        match try_convert_terse_to_sql("fn o:-complexity,nmae") {
+            TerseParseResult::Invalid(message) => {
+                assert!(message.contains("Unknown sort column 'nmae'"));
+                assert!(message.contains("complexity"));
+            }
+            _ => panic!("Expected Invalid result"),
+        }
+    }
+
     #[test]
     fn test_terse_with_limit() {
         match try_convert_terse_to_sql("fn l20") {
@@ -996,6 +1539,25 @@ mod tests {
         assert_eq!(result, "SELECT name FROM nodes WHERE type = 'class'");
     }
 
+    #[test]
+    fn test_virtual_table_rewrite_interfaces() {
+        let result =
+            rewrite_virtual_tables("SELECT * FROM interfaces WHERE file_path LIKE 'src/%'");
+        assert_eq!(
+            result,
+            "SELECT * FROM nodes WHERE type = 'interface' AND file_path LIKE 'src/%'"
+        );
+    }
+
+    #[test]
+    fn test_virtual_table_rewrite_enums() {
+        let result = rewrite_virtual_tables("SELECT name, properties FROM enums");
+        assert_eq!(
+            result,
+            "SELECT name, properties FROM nodes WHERE type = 'enum'"
+        );
+    }
+
     #[test]
     fn test_virtual_table_rewrite_modules() {
         let result = rewrite_virtual_tables("SELECT * FROM modules WHERE name LIKE '%api%'");
@@ -1041,4 +1603,198 @@ mod tests {
         let result = rewrite_virtual_tables(query);
         assert_eq!(result, query);
     }
+
+    #[test]
+    fn test_rewrite_find() {
+        assert_eq!(
+            rewrite_virtual_tables(&rewrite_find("find functions WHERE complexity > 5")),
+            "SELECT * FROM nodes WHERE type = 'function' AND complexity > 5"
+        );
+        let sql = "SELECT * FROM nodes";
+        assert_eq!(rewrite_find(sql), sql);
+    }
+
+    #[test]
+    fn test_rewrite_matches() {
+        assert_eq!(
+            rewrite_matches("SELECT * FROM nodes WHERE name MATCHES 'handle.*Request'").unwrap(),
+            "SELECT * FROM nodes WHERE regexp_matches(name, 'handle.*Request')"
+        );
+        assert_eq!(
+            rewrite_matches("SELECT * FROM nodes n WHERE n.file_path not matches '^tests/'")
+                .unwrap(),
+            "SELECT * FROM nodes n WHERE NOT regexp_matches(n.file_path, '^tests/')"
+        );
+        // Escaped quotes stay escaped; literals mentioning MATCHES are untouched
+        assert_eq!(
+            rewrite_matches("SELECT 'a MATCHES ''b''' WHERE name MATCHES 'it''s'").unwrap(),
+            "SELECT 'a MATCHES ''b''' WHERE regexp_matches(name, 'it''s')"
+        );
+
+        let err = rewrite_matches("SELECT * FROM nodes WHERE name MATCHES 'handle(['").unwrap_err();
+        assert!(
+            err.starts_with("Invalid regex in MATCHES 'handle(['"),
+            "{}",
+            err
+        );
+    }
+
+    #[test]
+    fn test_alias_aggregates() {
+        assert_eq!(
+            alias_aggregates(
+                "SELECT file_path, count(*), AVG(complexity) FROM nodes GROUP BY file_path"
+            ),
+            r#"SELECT file_path, count(*) AS "COUNT(*)", AVG(complexity) AS "AVG(complexity)" FROM nodes GROUP BY file_path"#
+        );
+        assert_eq!(
+            alias_aggregates("SELECT MAX(complexity) AS worst, MIN(a) + SUM(b) FROM nodes"),
+            "SELECT MAX(complexity) AS worst, MIN(a) + SUM(b) FROM nodes"
+        );
+
+        // FROM inside a subquery is not the end of the select list
+        let nested = "SELECT (SELECT COUNT(*) FROM edges), name FROM nodes";
+        assert_eq!(alias_aggregates(nested), nested);
+        assert_eq!(alias_aggregates("SHOW TABLES"), "SHOW TABLES");
+    }
+
+    #[test]
+    fn test_parse_show_traversal_directions() {
+        let show = parse_show_traversal("SHOW dependencies OF Auth").unwrap();
+        assert_eq!(show.direction, TraversalDirection::Dependencies);
+        assert_eq!(show.target, "Auth");
+        assert_eq!(show.depth, 1);
+
+        let show = parse_show_traversal("show DEPENDENTS of 'Auth' depth 3;").unwrap();
+        assert_eq!(show.direction, TraversalDirection::Dependents);
+        assert_eq!(show.target, "Auth");
+        assert_eq!(show.depth, 3);
+
+        let show = parse_show_traversal("SHOW impact OF Parser").unwrap();
+        assert_eq!(show.direction, TraversalDirection::Dependents);
+        assert_eq!(show.depth, u8::MAX);
+
+        let show = parse_show_traversal("SHOW ancestors OF Parser").unwrap();
+        assert_eq!(show.direction, TraversalDirection::Dependencies);
+
+        assert!(parse_show_traversal("SHOW TABLES").is_none());
+        assert!(parse_show_traversal("SHOW dependents OF Auth LIMIT x").is_none());
+    }
+
+    #[test]
+    fn test_parse_show_traversal_pagination() {
+        let show = parse_show_traversal("SHOW impact OF Parser LIMIT 20 OFFSET 40").unwrap();
+        assert_eq!(show.depth, u8::MAX);
+        assert_eq!(show.limit, Some(20));
+        assert_eq!(show.offset, 40);
+
+        let show = parse_show_traversal("SHOW dependents OF Auth DEPTH 2 LIMIT 5").unwrap();
+        assert_eq!((show.depth, show.limit, show.offset), (2, Some(5), 0));
+        assert_eq!(show.edge_types, None);
+
+        assert!(parse_show_traversal("SHOW dependents OF Auth LIMIT").is_none());
+    }
+
+    #[test]
+    fn test_parse_show_traversal_edge_types() {
+        let show =
+            parse_show_traversal("SHOW dependencies OF main DEPTH 3 VIA Calls,imports").unwrap();
+        assert_eq!(show.depth, 3);
+        assert_eq!(
+            show.edge_types,
+            Some(vec!["calls".to_string(), "imports".to_string()])
+        );
+
+        let show = parse_show_traversal("show impact of Parser via inherits limit 5").unwrap();
+        assert_eq!(show.edge_types, Some(vec!["inherits".to_string()]));
+        assert_eq!(show.limit, Some(5));
+
+        assert!(parse_show_traversal("SHOW dependents OF Auth VIA").is_none());
+    }
+
+    #[test]
+    fn test_terse_pagination() {
+        for query in ["fn c>10 l20 off40", "fn c>10 LIMIT 20 OFFSET 40"] {
+            match try_convert_terse_to_sql(query) {
+                TerseParseResult::Sql(sql) => {
+                    assert!(
+                        sql.ends_with(
+                            "WHERE type = 'function' AND complexity > 10 LIMIT 20 OFFSET 40"
+                        ),
+                        "{}",
+                        sql
+                    )
+                }
+                _ => panic!("Expected SQL for {}", query),
+            }
+        }
+    }
+
+    #[test]
+    fn test_apply_pagination() {
+        assert_eq!(
+            apply_pagination("SELECT * FROM nodes;", Some(20), Some(40)),
+            "SELECT * FROM nodes LIMIT 20 OFFSET 40"
+        );
+        assert_eq!(
+            apply_pagination("SELECT * FROM nodes LIMIT 5", Some(20), Some(40)),
+            "SELECT * FROM nodes LIMIT 5 OFFSET 40"
+        );
+        assert_eq!(apply_pagination("SHOW TABLES", None, None), "SHOW TABLES");
+    }
+
+    #[test]
+    fn test_strip_pagination() {
+        assert_eq!(
+            strip_pagination("SELECT * FROM nodes ORDER BY id LIMIT 20 OFFSET 40;"),
+            Some("SELECT * FROM nodes ORDER BY id")
+        );
+        assert_eq!(
+            strip_pagination("SELECT * FROM nodes offset 10"),
+            Some("SELECT * FROM nodes")
+        );
+        assert_eq!(
+            strip_pagination("SELECT * FROM (SELECT * FROM nodes LIMIT 5)"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_split_script_skips_comments() {
+        let script = "-- Health checks\n\
+                      SELECT name FROM functions WHERE name = 'a;b';\n\
+                      \n\
+                      -- SELECT * FROM nodes;\n\
+                      fn c>50\n";
+        let statements = split_script(script);
+        assert_eq!(
+            statements,
+            vec!["SELECT name FROM functions WHERE name = 'a;b'", "fn c>50"]
+        );
+    }
+
+    #[test]
+    fn test_format_script_labels_sections() {
+        let entry = |query: &str| ScriptEntry {
+            query: query.to_string(),
+            result: QueryResult {
+                columns: vec!["n".to_string()],
+                rows: vec![vec!["1".to_string()]],
+                row_count: 1,
+                total_count: None,
+                execution_time_ms: 0,
+                error: None,
+            },
+        };
+        let entries = vec![entry("SELECT 1"), entry("SELECT 2")];
+
+        let output = format_script(&entries, OutputFormat::Mu).unwrap();
+        assert!(output.contains(":: [1/2] SELECT 1"));
+        assert!(output.contains(":: [2/2] SELECT 2"));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&format_script(&entries, OutputFormat::Json).unwrap()).unwrap();
+        assert_eq!(json.as_array().unwrap().len(), 2);
+        assert_eq!(json[1]["query"], "SELECT 2");
+    }
 }
@@ -4,67 +4,17 @@
 //! When given a node ID, it resolves to the file and line range from the MUbase.
 //! Provides syntax highlighting and optional line numbers.
 
+use crate::mubase::find_mubase;
 use crate::output::{Output, OutputFormat, TableDisplay};
 use anyhow::{Context, Result};
 use colored::Colorize;
 use duckdb::{params, Connection};
 use serde::Serialize;
-use std::path::{Path, PathBuf};
 use syntect::easy::HighlightLines;
 use syntect::highlighting::{Style, ThemeSet};
 use syntect::parsing::SyntaxSet;
 use syntect::util::{as_24_bit_terminal_escaped, LinesWithEndings};
 
-/// Find the MUbase database in the given directory or its parents.
-fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize()?;
-    let mut current = start.as_path();
-
-    loop {
-        // New standard path: .mu/mubase
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Ok(db_path);
-        }
-
-        // Legacy path: .mubase
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Ok(legacy_path);
-        }
-
-        // Move up to parent
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "No MUbase found. Run 'mu bootstrap' first to create the database."
-                ))
-            }
-        }
-    }
-}
-
-/// Get the root directory of the MUbase (where .mu/ exists)
-fn get_mubase_root(db_path: &Path) -> Result<PathBuf> {
-    // The db_path is either .mu/mubase or .mubase
-    let parent = db_path
-        .parent()
-        .ok_or_else(|| anyhow::anyhow!("Invalid database path"))?;
-
-    if parent.file_name().and_then(|s| s.to_str()) == Some(".mu") {
-        // .mu/mubase -> go up one more level
-        Ok(parent
-            .parent()
-            .ok_or_else(|| anyhow::anyhow!("Invalid .mu directory structure"))?
-            .to_path_buf())
-    } else {
-        // .mubase -> parent is the root
-        Ok(parent.to_path_buf())
-    }
-}
-
 /// Node information from the database
 #[derive(Debug)]
 struct NodeInfo {
@@ -133,7 +83,7 @@ fn resolve_node(conn: &Connection, partial: &str) -> Result<NodeInfo> {
             // Sort matches by type priority (class > module > function) then by name
             matches.sort_by(|a, b| {
                 let type_priority = |t: &str| match t {
-                    "class" => 0,
+                    "class" | "interface" | "enum" => 0,
                     "module" => 1,
                     "function" => 2,
                     _ => 3,
@@ -350,9 +300,7 @@ fn format_with_line_numbers(code: &str, line_start: u32, show_line_numbers: bool
     }
 
     let mut output = String::new();
-    let mut line_num = line_start;
-
-    for line in code.lines() {
+    for (line_num, line) in (line_start..).zip(code.lines()) {
         let num_width = 4.max(format!("{}", line_num).len());
         output.push_str(&format!(
             "{:>width$} │ {}\n",
@@ -360,7 +308,6 @@ fn format_with_line_numbers(code: &str, line_start: u32, show_line_numbers: bool
             line,
             width = num_width
         ));
-        line_num += 1;
     }
 
     output
@@ -407,7 +354,7 @@ pub async fn run(path_or_node: &str, line_numbers: bool, format: OutputFormat) -
         let node = resolve_node(&conn, path_or_node)?;
 
         // Get the root directory to construct absolute path
-        let root = get_mubase_root(&db_path)?;
+        let root = crate::mubase::project_root(&conn, &db_path);
         let absolute_path = root.join(&node.file_path);
 
         if !absolute_path.exists() {
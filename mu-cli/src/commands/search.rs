@@ -1,15 +1,22 @@
 //! Search command - Semantic search across the codebase
 //!
 //! Uses mu-sigma-v2 (or all-MiniLM-L6-v2) embeddings to find
-//! semantically similar code nodes to the query.
+//! semantically similar code nodes to the query. By default the vector
+//! ranking is fused with a BM25 keyword ranking, so exact identifiers
+//! ("getUserById") and concepts ("retry backoff logic") both find their
+//! targets.
 
+use std::collections::HashMap;
 use std::path::Path;
 use std::time::Instant;
 
 use colored::Colorize;
 use serde::{Deserialize, Serialize};
 
+use crate::mubase::find_mubase_from;
 use crate::output::{Output, OutputFormat, TableDisplay};
+use crate::query_cache::embed_query;
+use mu_daemon::storage::{EmbeddingSpace, SimilarityCalibration, VectorSearchResult};
 
 /// Search result item
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -20,6 +27,165 @@ pub struct SearchResult {
     pub file_path: Option<String>,
     pub line_start: Option<usize>,
     pub similarity: f32,
+    /// Number of other hits collapsed into this one by `--group-by`
+    #[serde(default, skip_serializing_if = "is_zero")]
+    pub other_matches: usize,
+}
+
+fn is_zero(n: &usize) -> bool {
+    *n == 0
+}
+
+/// Minimum similarity for semantic search when neither `--threshold` nor
+/// `--relevance` is given.
+pub const DEFAULT_THRESHOLD: f32 = 0.1;
+
+/// Candidates fetched per requested result when grouping.
+const GROUP_FETCH_FACTOR: usize = 5;
+
+/// Random node pairs sampled by `--calibrate`.
+const CALIBRATION_PAIRS: usize = 2000;
+
+/// Human relevance level, mapped to a similarity threshold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Relevance {
+    /// Above the median random-pair similarity
+    Low,
+    /// Above 90% of random pairs
+    Medium,
+    /// Above 99% of random pairs
+    High,
+}
+
+impl Relevance {
+    /// Similarity threshold for this level.
+    ///
+    /// Uses the corpus calibration when available, otherwise fixed defaults.
+    pub fn threshold(&self, calibration: Option<&SimilarityCalibration>) -> f32 {
+        let threshold = match (self, calibration) {
+            (Relevance::Low, Some(c)) => c.p50,
+            (Relevance::Medium, Some(c)) => c.p90,
+            (Relevance::High, Some(c)) => c.p99,
+            (Relevance::Low, None) => 0.1,
+            (Relevance::Medium, None) => 0.3,
+            (Relevance::High, None) => 0.5,
+        };
+        threshold.clamp(0.0, 1.0)
+    }
+}
+
+/// Which embedding index to search
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum SearchOver {
+    /// Node names and signatures
+    #[default]
+    Code,
+    /// Docstrings only; undocumented nodes never match
+    Docs,
+}
+
+impl SearchOver {
+    fn space(&self) -> EmbeddingSpace {
+        match self {
+            SearchOver::Code => EmbeddingSpace::Code,
+            SearchOver::Docs => EmbeddingSpace::Docs,
+        }
+    }
+}
+
+/// Which rankings to search with
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum SearchMode {
+    /// BM25 keyword and vector rankings, fused
+    Hybrid,
+    /// Vector similarity only
+    #[default]
+    Semantic,
+    /// BM25 over names and docstrings only; needs no embeddings
+    Keyword,
+}
+
+impl SearchMode {
+    fn label(&self) -> &'static str {
+        match self {
+            SearchMode::Hybrid => "Hybrid",
+            SearchMode::Semantic => "Semantic",
+            SearchMode::Keyword => "Keyword",
+        }
+    }
+}
+
+/// How to collapse search results
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum GroupBy {
+    /// One row per source file
+    File,
+    /// One row per module directory (package)
+    Module,
+    /// No grouping
+    #[default]
+    None,
+}
+
+impl GroupBy {
+    /// Grouping key for a result, or `None` if it can't be grouped.
+    fn key(&self, result: &SearchResult) -> Option<String> {
+        let file_path = result.file_path.as_deref()?;
+        match self {
+            GroupBy::File => Some(file_path.to_string()),
+            GroupBy::Module => Some(
+                Path::new(file_path)
+                    .parent()
+                    .map(|p| p.to_string_lossy().to_string())
+                    .unwrap_or_default(),
+            ),
+            GroupBy::None => None,
+        }
+    }
+}
+
+/// Collapse results so each file/module appears once.
+///
+/// The best-scoring hit of each group is kept and carries the count of the
+/// other hits it replaced. Results without a file path are kept as-is.
+/// Group order follows the best score of each group.
+pub fn group_results(results: Vec<SearchResult>, group_by: GroupBy) -> Vec<SearchResult> {
+    if group_by == GroupBy::None {
+        return results;
+    }
+
+    let mut grouped: Vec<SearchResult> = Vec::new();
+    let mut index_by_key: HashMap<String, usize> = HashMap::new();
+
+    for result in results {
+        let Some(key) = group_by.key(&result) else {
+            grouped.push(result);
+            continue;
+        };
+
+        match index_by_key.get(&key) {
+            Some(&idx) => {
+                let best = &mut grouped[idx];
+                let others = best.other_matches + result.other_matches + 1;
+                if result.similarity > best.similarity {
+                    *best = result;
+                }
+                best.other_matches = others;
+            }
+            None => {
+                index_by_key.insert(key, grouped.len());
+                grouped.push(result);
+            }
+        }
+    }
+
+    grouped.sort_by(|a, b| {
+        b.similarity
+            .partial_cmp(&a.similarity)
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+    grouped
 }
 
 /// Search results collection
@@ -29,6 +195,9 @@ pub struct SearchResults {
     pub results: Vec<SearchResult>,
     pub total_found: usize,
     pub has_embeddings: bool,
+    /// Mode actually used; keyword when there are no embeddings
+    #[serde(default)]
+    pub mode: SearchMode,
     #[serde(default)]
     pub duration_ms: u64,
 }
@@ -46,8 +215,9 @@ impl TableDisplay for SearchResults {
             output.push_str("Using keyword search fallback:\n\n");
         } else {
             output.push_str(&format!(
-                "{} Semantic search: \"{}\"\n",
+                "{} {} search: \"{}\"\n",
                 "SEARCH:".cyan().bold(),
+                self.mode.label(),
                 self.query
             ));
             output.push_str(&format!(
@@ -82,6 +252,14 @@ impl TableDisplay for SearchResults {
                 format!("{}%", score_pct).to_string().as_str()
             ));
 
+            if result.other_matches > 0 {
+                output.push_str(&format!(
+                    "    {} +{} more in this group\n",
+                    "Also:".dimmed(),
+                    result.other_matches
+                ));
+            }
+
             output.push_str(&format!("    {} {}\n", "Score:".dimmed(), score_color));
 
             if let Some(ref file_path) = result.file_path {
@@ -113,14 +291,18 @@ impl TableDisplay for SearchResults {
         for result in &self.results {
             let sigil = match result.node_type.as_str() {
                 "module" => "!",
-                "class" => "@",
+                "class" | "interface" | "enum" => "@",
                 "function" => "$",
                 _ => "#",
             };
             output.push_str(&format!(
-                "{}{} [{}] score={:.2}\n",
+                "{}{} [{}] score={:.2}",
                 sigil, result.name, result.node_type, result.similarity
             ));
+            if result.other_matches > 0 {
+                output.push_str(&format!(" +{}", result.other_matches));
+            }
+            output.push('\n');
             if let Some(ref path) = result.file_path {
                 output.push_str(&format!("  | {}\n", path));
             }
@@ -130,25 +312,17 @@ impl TableDisplay for SearchResults {
     }
 }
 
-/// Find the mubase path starting from the given directory
-fn find_mubase_path(start_dir: &Path) -> Option<std::path::PathBuf> {
-    let mut current = start_dir.to_path_buf();
-    loop {
-        let mubase_path = current.join(".mu").join("mubase");
-        if mubase_path.exists() {
-            return Some(mubase_path);
-        }
-        if !current.pop() {
-            return None;
-        }
-    }
-}
-
 /// Run the search command
+#[allow(clippy::too_many_arguments)]
 pub async fn run(
     query: &str,
     limit: usize,
     threshold: f32,
+    relevance: Option<Relevance>,
+    group_by: GroupBy,
+    over: SearchOver,
+    mode: SearchMode,
+    alpha: Option<f32>,
     format: OutputFormat,
 ) -> anyhow::Result<()> {
     // Validate query is not empty
@@ -157,20 +331,29 @@ pub async fn run(
     }
 
     let start = Instant::now();
-    run_direct(query, limit, threshold, format, start).await
+    run_direct(
+        query, limit, threshold, relevance, group_by, over, mode, alpha, format, start,
+    )
+    .await
 }
 
 /// Run search directly against the database
+#[allow(clippy::too_many_arguments)]
 async fn run_direct(
     query: &str,
     limit: usize,
     threshold: f32,
+    relevance: Option<Relevance>,
+    group_by: GroupBy,
+    over: SearchOver,
+    mode: SearchMode,
+    alpha: Option<f32>,
     format: OutputFormat,
     start: Instant,
 ) -> anyhow::Result<()> {
     // Find mubase
     let cwd = std::env::current_dir()?;
-    let mubase_path = match find_mubase_path(&cwd) {
+    let mubase_path = match find_mubase_from(&cwd) {
         Some(path) => path,
         None => {
             anyhow::bail!(
@@ -183,16 +366,39 @@ async fn run_direct(
     let mubase = mu_daemon::storage::MUbase::open_read_only(&mubase_path)?;
 
     // Check if we have embeddings
-    let has_embeddings = mubase.has_embeddings()?;
+    let has_embeddings = mubase.has_embeddings_in(over.space())?;
+
+    // A relevance level overrides the numeric threshold. Calibration is
+    // sampled from code embeddings, so docs searches use the fixed defaults.
+    let threshold = match (relevance, over) {
+        (Some(level), SearchOver::Code) => {
+            level.threshold(mubase.similarity_calibration()?.as_ref())
+        }
+        (Some(level), SearchOver::Docs) => level.threshold(None),
+        (None, _) => threshold,
+    };
 
-    let results = if has_embeddings {
-        // Semantic search path
-        run_semantic_search(&mubase, query, limit, threshold)?
+    // Grouping collapses hits, so fetch extra candidates to still fill the limit
+    let fetch_limit = if group_by == GroupBy::None {
+        limit
     } else {
-        // Fallback to keyword search
-        run_keyword_search(&mubase, query, limit)?
+        limit * GROUP_FETCH_FACTOR
     };
 
+    // Without embeddings only the keyword ranking is available
+    let mode = if has_embeddings {
+        mode
+    } else {
+        SearchMode::Keyword
+    };
+    let results = match mode {
+        SearchMode::Hybrid => run_hybrid_search(&mubase, query, fetch_limit, alpha, over)?,
+        SearchMode::Semantic => run_semantic_search(&mubase, query, fetch_limit, threshold, over)?,
+        SearchMode::Keyword => run_keyword_search(&mubase, query, fetch_limit, over)?,
+    };
+    let mut results = group_results(results, group_by);
+    results.truncate(limit);
+
     let duration_ms = start.elapsed().as_millis() as u64;
 
     let search_results = SearchResults {
@@ -200,49 +406,152 @@ async fn run_direct(
         total_found: results.len(),
         results,
         has_embeddings,
+        mode,
         duration_ms,
     };
 
     Output::new(search_results, format).render()
 }
 
+/// Result of `mu search --calibrate`
+#[derive(Debug, Serialize)]
+pub struct CalibrationReport {
+    pub calibration: SimilarityCalibration,
+    pub low: f32,
+    pub medium: f32,
+    pub high: f32,
+    pub duration_ms: u64,
+}
+
+impl CalibrationReport {
+    fn new(calibration: SimilarityCalibration, duration_ms: u64) -> Self {
+        Self {
+            low: Relevance::Low.threshold(Some(&calibration)),
+            medium: Relevance::Medium.threshold(Some(&calibration)),
+            high: Relevance::High.threshold(Some(&calibration)),
+            calibration,
+            duration_ms,
+        }
+    }
+}
+
+impl TableDisplay for CalibrationReport {
+    fn to_table(&self) -> String {
+        let mut output = String::new();
+        output.push_str(&format!(
+            "{} Sampled {} random pairs in {}ms\n\n",
+            "CALIBRATED:".green().bold(),
+            self.calibration.sample_size,
+            self.duration_ms
+        ));
+        output.push_str(&format!("  {:<8} {:.3}\n", "low", self.low));
+        output.push_str(&format!("  {:<8} {:.3}\n", "medium", self.medium));
+        output.push_str(&format!("  {:<8} {:.3}\n\n", "high", self.high));
+        output.push_str(&format!(
+            "{}\n",
+            "Tip: Use 'mu search <query> --relevance high' to apply a level".dimmed()
+        ));
+        output
+    }
+
+    fn to_mu(&self) -> String {
+        format!(
+            ":: calibrate\n# pairs: {}\nlow={:.3} medium={:.3} high={:.3}\n",
+            self.calibration.sample_size, self.low, self.medium, self.high
+        )
+    }
+}
+
+/// Calibrate relevance levels against this corpus's similarity distribution
+pub async fn run_calibrate(format: OutputFormat) -> anyhow::Result<()> {
+    let start = Instant::now();
+
+    let cwd = std::env::current_dir()?;
+    let mubase_path = find_mubase_from(&cwd).ok_or_else(|| {
+        anyhow::anyhow!(
+            "No .mu/mubase found. Run 'mu bootstrap' first to initialize MU for this project."
+        )
+    })?;
+
+    // Calibration is stored in metadata, so this needs write access
+    let mubase = mu_daemon::storage::MUbase::open(&mubase_path)?;
+    let calibration = mubase.calibrate_similarity(CALIBRATION_PAIRS)?;
+
+    let report = CalibrationReport::new(calibration, start.elapsed().as_millis() as u64);
+    Output::new(report, format).render()
+}
+
 /// Run semantic search using embeddings
 fn run_semantic_search(
     mubase: &mu_daemon::storage::MUbase,
     query: &str,
     limit: usize,
     threshold: f32,
+    over: SearchOver,
 ) -> anyhow::Result<Vec<SearchResult>> {
-    // Load the embedding model from embedded weights (zero-config)
-    let model = mu_embeddings::MuSigmaModel::embedded()?;
-
-    // Embed the query
-    let query_embedding = model.embed_one(query)?;
+    // Embed the query, or reuse the vector from a recent identical search
+    let query_embedding = embed_query(mubase.project_root(), query)?;
 
     // Perform vector search
-    let results = mubase.vector_search(&query_embedding, limit, Some(threshold))?;
-
-    // Convert to SearchResult
-    let search_results: Vec<SearchResult> = results
-        .into_iter()
-        .map(|result| SearchResult {
-            node_id: result.node_id,
-            name: result.name,
-            node_type: result.node_type,
-            file_path: result.file_path,
-            line_start: None, // VectorSearchResult doesn't include line info
-            similarity: result.similarity,
-        })
-        .collect();
+    let results =
+        mubase.vector_search_in(over.space(), &query_embedding, limit, Some(threshold))?;
 
-    Ok(search_results)
+    Ok(results.into_iter().map(to_search_result).collect())
+}
+
+/// Run hybrid search, fusing the BM25 and vector rankings
+///
+/// Fused scores are not cosine similarities, so `--threshold` and
+/// `--relevance` don't apply here.
+fn run_hybrid_search(
+    mubase: &mu_daemon::storage::MUbase,
+    query: &str,
+    limit: usize,
+    alpha: Option<f32>,
+    over: SearchOver,
+) -> anyhow::Result<Vec<SearchResult>> {
+    let query_embedding = embed_query(mubase.project_root(), query)?;
+
+    let results = mubase.hybrid_search_in(over.space(), query, &query_embedding, limit, alpha)?;
+    Ok(results.into_iter().map(to_search_result).collect())
 }
 
-/// Run keyword search (fallback when no embeddings)
+/// Convert a storage search hit to a search result
+fn to_search_result(result: VectorSearchResult) -> SearchResult {
+    SearchResult {
+        node_id: result.node_id,
+        name: result.name,
+        node_type: result.node_type,
+        file_path: result.file_path,
+        line_start: None, // VectorSearchResult doesn't include line info
+        similarity: result.similarity,
+        other_matches: 0,
+    }
+}
+
+/// Run BM25 keyword search
+///
+/// Over code, falls back to substring matching of names when no node shares
+/// a whole term with the query, so partial names like "auth" still find
+/// "authenticate".
 fn run_keyword_search(
     mubase: &mu_daemon::storage::MUbase,
     query: &str,
     limit: usize,
+    over: SearchOver,
+) -> anyhow::Result<Vec<SearchResult>> {
+    let results = mubase.bm25_search_in(over.space(), query, limit)?;
+    if !results.is_empty() || over == SearchOver::Docs {
+        return Ok(results.into_iter().map(to_search_result).collect());
+    }
+    run_substring_search(mubase, query, limit)
+}
+
+/// Run substring search over node names
+fn run_substring_search(
+    mubase: &mu_daemon::storage::MUbase,
+    query: &str,
+    limit: usize,
 ) -> anyhow::Result<Vec<SearchResult>> {
     // Search by name pattern
     let sql = format!(
@@ -292,7 +601,8 @@ fn run_keyword_search(
                 node_type,
                 file_path,
                 line_start,
-                similarity: 1.0, // No real similarity for keyword search
+                similarity: 1.0, // No real similarity for substring search
+                other_matches: 0,
             }
         })
         .collect();
@@ -313,10 +623,126 @@ mod tests {
             file_path: Some("test.py".to_string()),
             line_start: Some(10),
             similarity: 0.85,
+            other_matches: 0,
         };
 
         let json = serde_json::to_string(&result).unwrap();
         assert!(json.contains("main"));
         assert!(json.contains("0.85"));
     }
+
+    fn hit(name: &str, file_path: &str, similarity: f32) -> SearchResult {
+        SearchResult {
+            node_id: format!("fn:{}:{}", file_path, name),
+            name: name.to_string(),
+            node_type: "function".to_string(),
+            file_path: Some(file_path.to_string()),
+            line_start: None,
+            similarity,
+            other_matches: 0,
+        }
+    }
+
+    #[test]
+    fn test_group_by_file_keeps_best_hit() {
+        let results = vec![
+            hit("login", "src/auth.py", 0.6),
+            hit("logout", "src/db.py", 0.7),
+            hit("check_token", "src/auth.py", 0.9),
+            hit("hash_password", "src/auth.py", 0.5),
+        ];
+
+        let grouped = group_results(results, GroupBy::File);
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].name, "check_token");
+        assert_eq!(grouped[0].similarity, 0.9);
+        assert_eq!(grouped[0].other_matches, 2);
+        assert_eq!(grouped[1].name, "logout");
+        assert_eq!(grouped[1].other_matches, 0);
+    }
+
+    #[test]
+    fn test_group_by_module_and_none() {
+        let results = vec![
+            hit("a", "src/api/users.py", 0.4),
+            hit("b", "src/api/orders.py", 0.8),
+            hit("c", "src/db/models.py", 0.6),
+        ];
+
+        let grouped = group_results(results.clone(), GroupBy::Module);
+        assert_eq!(grouped.len(), 2);
+        assert_eq!(grouped[0].name, "b");
+        assert_eq!(grouped[0].other_matches, 1);
+
+        assert_eq!(group_results(results, GroupBy::None).len(), 3);
+    }
+
+    #[test]
+    fn test_relevance_thresholds_are_ordered() {
+        let calibration = SimilarityCalibration {
+            sample_size: 100,
+            p50: 0.12,
+            p90: 0.31,
+            p99: 0.48,
+        };
+
+        for calibration in [Some(&calibration), None] {
+            let low = Relevance::Low.threshold(calibration);
+            let medium = Relevance::Medium.threshold(calibration);
+            let high = Relevance::High.threshold(calibration);
+            assert!(low < medium && medium < high);
+        }
+
+        assert_eq!(Relevance::High.threshold(Some(&calibration)), 0.48);
+    }
+
+    #[test]
+    fn test_docs_search_matches_docstring_not_identifier() {
+        use mu_daemon::storage::{MUbase, Node};
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let mubase = MUbase::open(dir.path().join("mubase")).unwrap();
+        let nodes = vec![
+            Node::function("src/util.py", "xq_run", None, 1, 9, 1).with_properties(
+                serde_json::json!({"docstring": "Send the user an email with a link to reset their forgotten password"}),
+            ),
+            Node::function("src/util.py", "parse_config", None, 10, 20, 1),
+            Node::function("src/util.py", "render_chart", None, 21, 30, 1),
+            Node::function("src/util.py", "connect_database", None, 31, 40, 1),
+        ];
+        mubase.insert_nodes(&nodes).unwrap();
+
+        let model = mu_embeddings::MuSigmaModel::embedded().unwrap();
+        let code: Vec<_> = nodes
+            .iter()
+            .map(|n| {
+                let text = format!(
+                    "function {} {}",
+                    n.name,
+                    n.qualified_name.as_deref().unwrap_or("")
+                );
+                (n.id.clone(), model.embed_one(&text).unwrap(), Some(text))
+            })
+            .collect();
+        let doc = nodes[0].docstring().unwrap();
+        let docs = vec![(nodes[0].id.clone(), model.embed_one(doc).unwrap(), None)];
+        mubase.replace_embeddings(&code, None, || Ok(())).unwrap();
+        mubase
+            .replace_embeddings_in(EmbeddingSpace::Docs, &docs, None, || Ok(()))
+            .unwrap();
+
+        let query = "how do users recover a lost password";
+        let doc_hits = run_semantic_search(&mubase, query, 10, 0.3, SearchOver::Docs).unwrap();
+        assert_eq!(doc_hits.len(), 1);
+        assert_eq!(doc_hits[0].name, "xq_run");
+
+        // The opaque identifier alone doesn't surface the function
+        let code_hits = run_semantic_search(&mubase, query, 10, 0.0, SearchOver::Code).unwrap();
+        assert_ne!(code_hits[0].name, "xq_run");
+        let code_score = code_hits
+            .iter()
+            .find(|h| h.name == "xq_run")
+            .map(|h| h.similarity);
+        assert!(code_score.unwrap_or(0.0) < doc_hits[0].similarity);
+    }
 }
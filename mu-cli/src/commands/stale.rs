@@ -0,0 +1,283 @@
+//! Stale command - Check whether the MUbase is out of date with the working tree
+//!
+//! Scans the project, hashes every file, and compares against the content
+//! hashes `mu bootstrap` stores on module nodes. Nothing is written; the
+//! report says which files were added, changed, or deleted and whether an
+//! incremental update or a full rebuild is the better next step.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use colored::Colorize;
+use serde::Serialize;
+
+use crate::output::{Output, OutputFormat, TableDisplay};
+
+/// Above this share of touched files, a full rebuild is recommended.
+const REBUILD_RATIO: f64 = 0.3;
+
+/// Suggested next step for a stale MUbase.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Recommendation {
+    UpToDate,
+    Incremental,
+    Rebuild,
+}
+
+impl Recommendation {
+    fn command(&self) -> &'static str {
+        match self {
+            Recommendation::UpToDate => "nothing to do",
+            Recommendation::Incremental => "mu embed",
+            Recommendation::Rebuild => "mu bootstrap --force",
+        }
+    }
+}
+
+/// Result of comparing the working tree with stored file hashes.
+#[derive(Debug, Serialize)]
+pub struct StalenessReport {
+    pub total_files: usize,
+    pub added: Vec<String>,
+    pub changed: Vec<String>,
+    pub deleted: Vec<String>,
+    pub unchanged: usize,
+    pub recommendation: Recommendation,
+}
+
+impl StalenessReport {
+    /// Compare current hashes against stored ones.
+    pub fn compare(
+        stored: &HashMap<String, String>,
+        current: &HashMap<String, String>,
+        stale: Vec<String>,
+    ) -> Self {
+        let (mut changed, mut added): (Vec<_>, Vec<_>) = stale
+            .into_iter()
+            .partition(|path| stored.contains_key(path));
+        let mut deleted: Vec<String> = stored
+            .keys()
+            .filter(|path| !current.contains_key(*path))
+            .cloned()
+            .collect();
+        changed.sort();
+        added.sort();
+        deleted.sort();
+
+        let touched = added.len() + changed.len() + deleted.len();
+        let recommendation = if touched == 0 {
+            Recommendation::UpToDate
+        } else if stored.is_empty() || touched as f64 > current.len().max(1) as f64 * REBUILD_RATIO
+        {
+            Recommendation::Rebuild
+        } else {
+            Recommendation::Incremental
+        };
+
+        Self {
+            total_files: current.len(),
+            unchanged: current.len() - added.len() - changed.len(),
+            added,
+            changed,
+            deleted,
+            recommendation,
+        }
+    }
+}
+
+impl TableDisplay for StalenessReport {
+    fn to_table(&self) -> String {
+        let mut output = String::new();
+
+        if self.recommendation == Recommendation::UpToDate {
+            output.push_str(&format!(
+                "{} MUbase is up to date ({} files)\n",
+                "OK:".green().bold(),
+                self.total_files
+            ));
+            return output;
+        }
+
+        output.push_str(&format!("{}\n", "MUbase is stale".yellow().bold()));
+        output.push_str(&format!("{}\n", "-".repeat(40).dimmed()));
+        output.push_str(&format!(
+            "  Added:     {}\n",
+            self.added.len().to_string().green()
+        ));
+        output.push_str(&format!(
+            "  Changed:   {}\n",
+            self.changed.len().to_string().yellow()
+        ));
+        output.push_str(&format!(
+            "  Deleted:   {}\n",
+            self.deleted.len().to_string().red()
+        ));
+        output.push_str(&format!("  Unchanged: {}\n", self.unchanged));
+
+        for (label, files) in [
+            ("+", &self.added),
+            ("~", &self.changed),
+            ("-", &self.deleted),
+        ] {
+            for file in files.iter().take(10) {
+                output.push_str(&format!("  {} {}\n", label, file));
+            }
+        }
+
+        output.push_str(&format!(
+            "\n{} {}\n",
+            "Recommended:".cyan().bold(),
+            self.recommendation.command()
+        ));
+        output
+    }
+
+    fn to_mu(&self) -> String {
+        let mut lines = vec![
+            ":: stale".to_string(),
+            format!(
+                "# added: {} changed: {} deleted: {} unchanged: {}",
+                self.added.len(),
+                self.changed.len(),
+                self.deleted.len(),
+                self.unchanged
+            ),
+        ];
+        lines.extend(self.added.iter().map(|f| format!("+ {}", f)));
+        lines.extend(self.changed.iter().map(|f| format!("~ {}", f)));
+        lines.extend(self.deleted.iter().map(|f| format!("- {}", f)));
+        lines.push(format!("# recommend: {}", self.recommendation.command()));
+        lines.join("\n")
+    }
+}
+
+/// Content hash of each indexed file, from its module node.
+///
+/// Modules indexed before hashes were recorded map to an empty hash, so
+/// they show up as changed.
+fn indexed_hashes(mubase: &mu_daemon::storage::MUbase) -> anyhow::Result<HashMap<String, String>> {
+    Ok(mubase
+        .get_nodes_by_type(mu_daemon::storage::NodeType::Module)?
+        .into_iter()
+        .filter_map(|node| {
+            let hash = node
+                .properties
+                .as_ref()
+                .and_then(|props| props.get("content_hash"))
+                .and_then(|hash| hash.as_str())
+                .unwrap_or_default()
+                .to_string();
+            Some((node.file_path?, hash))
+        })
+        .collect())
+}
+
+/// Check the MUbase under `root` against the working tree.
+pub fn check_staleness(root: &Path) -> anyhow::Result<StalenessReport> {
+    let mubase_path = crate::mubase::mubase_path(root);
+    if !mubase_path.exists() {
+        anyhow::bail!(
+            "MU database not found. Run 'mu bootstrap' first.\n  Path: {}",
+            mubase_path.display()
+        );
+    }
+
+    let root_str = root.to_str().unwrap_or(".");
+    let scan_result =
+        mu_core::scanner::scan_directory_sync(root_str, None, None, false, false, false)
+            .map_err(|e| anyhow::anyhow!(e))?;
+    let current_hashes: HashMap<String, String> = scan_result
+        .files
+        .iter()
+        .filter_map(|file| {
+            let content = std::fs::read(root.join(&file.path)).ok()?;
+            Some((file.path.clone(), mu_core::scanner::content_hash(&content)))
+        })
+        .collect();
+
+    // Read-only: checking staleness must never touch the database
+    let mubase = mu_daemon::storage::MUbase::open_read_only(&mubase_path)?;
+    let stored_hashes = indexed_hashes(&mubase)?;
+    let stale = current_hashes
+        .iter()
+        .filter(|(path, hash)| stored_hashes.get(*path) != Some(*hash))
+        .map(|(path, _)| path.clone())
+        .collect();
+
+    Ok(StalenessReport::compare(
+        &stored_hashes,
+        &current_hashes,
+        stale,
+    ))
+}
+
+/// Run the stale command
+pub async fn run(path: &str, format: OutputFormat) -> anyhow::Result<()> {
+    let root = Path::new(path)
+        .canonicalize()
+        .unwrap_or_else(|_| Path::new(path).to_path_buf());
+
+    let report = check_staleness(&root)?;
+    Output::new(report, format).render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mu_daemon::storage::Node;
+    use serde_json::json;
+    use std::fs;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_stale_reports_one_changed_file() {
+        let dir = TempDir::new().unwrap();
+        fs::write(dir.path().join("a.py"), "def a():\n    pass\n").unwrap();
+        fs::write(dir.path().join("b.py"), "def b():\n    pass\n").unwrap();
+        fs::create_dir_all(dir.path().join(".mu")).unwrap();
+
+        // Seed hashes matching the files as they are now
+        {
+            let mubase = mu_daemon::storage::MUbase::open(dir.path().join(".mu/mubase")).unwrap();
+            let seeded: Vec<Node> = ["a.py", "b.py"]
+                .iter()
+                .map(|name| {
+                    let content = fs::read(dir.path().join(name)).unwrap();
+                    let hash = mu_core::scanner::content_hash(&content);
+                    Node::module(name).with_properties(json!({ "content_hash": hash }))
+                })
+                .collect();
+            mubase.insert_nodes(&seeded).unwrap();
+        }
+
+        let report = check_staleness(dir.path()).unwrap();
+        assert_eq!(report.recommendation, Recommendation::UpToDate);
+
+        fs::write(dir.path().join("b.py"), "def b():\n    return 2\n").unwrap();
+
+        let report = check_staleness(dir.path()).unwrap();
+        assert_eq!(report.changed, vec!["b.py"]);
+        assert!(report.added.is_empty());
+        assert!(report.deleted.is_empty());
+        assert_eq!(report.unchanged, 1);
+    }
+
+    #[test]
+    fn test_compare_detects_added_and_deleted() {
+        let stored = HashMap::from([
+            ("keep.py".to_string(), "1".to_string()),
+            ("gone.py".to_string(), "2".to_string()),
+        ]);
+        let current = HashMap::from([
+            ("keep.py".to_string(), "1".to_string()),
+            ("new.py".to_string(), "3".to_string()),
+        ]);
+
+        let report = StalenessReport::compare(&stored, &current, vec!["new.py".to_string()]);
+        assert_eq!(report.added, vec!["new.py"]);
+        assert_eq!(report.deleted, vec!["gone.py"]);
+        assert!(report.changed.is_empty());
+        assert_eq!(report.recommendation, Recommendation::Rebuild);
+    }
+}
@@ -0,0 +1,280 @@
+//! Stats command - Codebase metrics in one place
+//!
+//! Where `mu status` reports on the database, `mu stats` reports on the code
+//! it describes: files and lines, entity counts, complexity, the language
+//! breakdown and the most depended-on nodes. `--format json` gives CI
+//! dashboards the same numbers.
+
+use std::collections::{BTreeMap, HashMap};
+
+use anyhow::Result;
+use colored::Colorize;
+use mu_daemon::storage::MUbase;
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::commands::vibes::conventions::detect_language;
+use crate::output::{Output, OutputFormat, TableDisplay};
+
+/// Number of most depended-on nodes listed.
+const TOP_N: usize = 10;
+
+/// Files and lines written in one language.
+#[derive(Debug, Clone, Serialize)]
+pub struct LanguageLines {
+    pub language: String,
+    pub files: usize,
+    pub lines: u64,
+    /// Share of all lines
+    pub percent: f32,
+}
+
+/// A node ranked by how many others depend on it.
+#[derive(Debug, Clone, Serialize)]
+pub struct DependedOn {
+    pub id: String,
+    pub name: String,
+    pub node_type: String,
+    /// Distinct nodes with an import, call, inherits, implements or uses
+    /// edge to this one
+    pub dependents: usize,
+}
+
+/// Codebase-level metrics.
+#[derive(Debug, Serialize)]
+pub struct CodebaseStats {
+    pub files: usize,
+    pub lines: u64,
+    pub node_count: usize,
+    pub edge_count: usize,
+    pub nodes_by_type: BTreeMap<String, usize>,
+    pub functions: usize,
+    pub classes: usize,
+    pub avg_complexity: f64,
+    pub max_complexity: u32,
+    pub languages: Vec<LanguageLines>,
+    pub most_depended_on: Vec<DependedOn>,
+}
+
+impl TableDisplay for CodebaseStats {
+    fn to_table(&self) -> String {
+        let mut lines = vec![format!("{}", "Codebase Stats".cyan().bold())];
+        lines.push(format!("  Files:      {}", self.files.to_string().green()));
+        lines.push(format!("  Lines:      {}", self.lines.to_string().green()));
+        lines.push(format!("  Functions:  {}", self.functions));
+        lines.push(format!("  Classes:    {}", self.classes));
+        lines.push(format!(
+            "  Nodes:      {} ({} edges)",
+            self.node_count, self.edge_count
+        ));
+        lines.push(format!(
+            "  Complexity: {:.1} avg, {} max",
+            self.avg_complexity,
+            self.max_complexity.to_string().yellow()
+        ));
+
+        lines.push(format!("\n{}", "Languages".cyan().bold()));
+        for lang in &self.languages {
+            lines.push(format!(
+                "  {:<12} {:>5} files {:>8} lines  {:>5.1}%",
+                lang.language, lang.files, lang.lines, lang.percent
+            ));
+        }
+
+        if !self.most_depended_on.is_empty() {
+            lines.push(format!("\n{}", "Most Depended-On".cyan().bold()));
+            for node in &self.most_depended_on {
+                lines.push(format!(
+                    "  {:>4}  {} {}",
+                    node.dependents,
+                    node.name,
+                    format!("[{}]", node.node_type).dimmed()
+                ));
+            }
+        }
+
+        lines.join("\n")
+    }
+
+    fn to_mu(&self) -> String {
+        let mut lines = vec![format!(
+            ":: stats files={} lines={} functions={} classes={}",
+            self.files, self.lines, self.functions, self.classes
+        )];
+        lines.push(format!(
+            "# complexity avg={:.1} max={}",
+            self.avg_complexity, self.max_complexity
+        ));
+        for lang in &self.languages {
+            lines.push(format!(
+                "# lang {} files={} lines={}",
+                lang.language, lang.files, lang.lines
+            ));
+        }
+        for node in &self.most_depended_on {
+            lines.push(format!("! {} dependents={}", node.id, node.dependents));
+        }
+        lines.join("\n")
+    }
+}
+
+fn as_u64(value: Option<&Value>) -> u64 {
+    value.and_then(Value::as_u64).unwrap_or(0)
+}
+
+/// Group per-file line counts by language, most lines first.
+fn language_lines(files: &[(String, u64)]) -> Vec<LanguageLines> {
+    let mut by_language: HashMap<&str, (usize, u64)> = HashMap::new();
+    for (path, lines) in files {
+        let entry = by_language.entry(detect_language(path)).or_default();
+        entry.0 += 1;
+        entry.1 += lines;
+    }
+
+    let total = files.iter().map(|(_, lines)| lines).sum::<u64>().max(1) as f32;
+    let mut languages: Vec<LanguageLines> = by_language
+        .into_iter()
+        .map(|(language, (files, lines))| LanguageLines {
+            language: language.to_string(),
+            files,
+            lines,
+            percent: lines as f32 * 100.0 / total,
+        })
+        .collect();
+    languages.sort_by(|a, b| b.lines.cmp(&a.lines).then(a.language.cmp(&b.language)));
+    languages
+}
+
+/// Aggregate the metrics over the graph.
+pub fn build_stats(mubase: &MUbase) -> Result<CodebaseStats> {
+    let graph = mubase.stats()?;
+
+    // A module's line count is its end line; graphs built before modules
+    // recorded one fall back to the last line of anything in the file
+    let files: Vec<(String, u64)> = mubase
+        .query(
+            "SELECT m.file_path, COALESCE(MAX(n.line_end), 0) AS lines
+             FROM nodes m LEFT JOIN nodes n ON n.file_path = m.file_path
+             WHERE m.type = 'module' AND m.file_path IS NOT NULL
+             GROUP BY m.file_path",
+        )?
+        .rows
+        .iter()
+        .filter_map(|row| Some((row.first()?.as_str()?.to_string(), as_u64(row.get(1)))))
+        .collect();
+
+    let complexity = mubase.query(
+        "SELECT COALESCE(AVG(complexity), 0)::DOUBLE, COALESCE(MAX(complexity), 0)
+         FROM nodes WHERE type = 'function'",
+    )?;
+    let row = complexity.rows.first();
+    let avg_complexity = row
+        .and_then(|r| r.first())
+        .and_then(Value::as_f64)
+        .unwrap_or(0.0);
+    let max_complexity = as_u64(row.and_then(|r| r.get(1))) as u32;
+
+    let most_depended_on = mubase
+        .query_with_params(
+            "SELECT n.id, n.name, n.type, COUNT(DISTINCT e.source_id) AS dependents
+             FROM edges e JOIN nodes n ON n.id = e.target_id
+             WHERE e.type != 'contains' AND n.type != 'external'
+             GROUP BY n.id, n.name, n.type
+             ORDER BY dependents DESC, n.id
+             LIMIT ?",
+            &[&(TOP_N as i64)],
+        )?
+        .rows
+        .iter()
+        .filter_map(|row| {
+            Some(DependedOn {
+                id: row.first()?.as_str()?.to_string(),
+                name: row.get(1)?.as_str()?.to_string(),
+                node_type: row.get(2)?.as_str()?.to_string(),
+                dependents: as_u64(row.get(3)) as usize,
+            })
+        })
+        .collect();
+
+    let type_count = |node_type: &str| graph.type_counts.get(node_type).copied().unwrap_or(0);
+    Ok(CodebaseStats {
+        files: files.len(),
+        lines: files.iter().map(|(_, lines)| lines).sum(),
+        node_count: graph.node_count,
+        edge_count: graph.edge_count,
+        functions: type_count("function"),
+        classes: type_count("class"),
+        nodes_by_type: graph.type_counts.into_iter().collect(),
+        avg_complexity,
+        max_complexity,
+        languages: language_lines(&files),
+        most_depended_on,
+    })
+}
+
+/// Run the stats command
+pub async fn run(path: &str, format: OutputFormat) -> Result<()> {
+    let db_path = crate::mubase::find_mubase(path)?;
+    let mubase = MUbase::open_read_only(&db_path)?;
+    Output::new(build_stats(&mubase)?, format).render()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use mu_daemon::storage::{Edge, Node};
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_stats_over_seeded_graph() {
+        let dir = TempDir::new().unwrap();
+        let mubase = MUbase::open(dir.path().join("mubase")).unwrap();
+
+        let mut main = Node::module("src/main.py");
+        main.line_end = Some(40);
+        mubase
+            .insert_nodes(&[
+                main,
+                // No recorded end line: falls back to its last function
+                Node::module("src/db.py"),
+                Node::module("web/app.ts"),
+                Node::function("src/main.py", "main", None, 1, 10, 2),
+                Node::function("src/db.py", "migrate", None, 1, 80, 27),
+                Node::function("web/app.ts", "render", None, 1, 20, 7),
+                Node::external("yaml"),
+            ])
+            .unwrap();
+        mubase
+            .insert_edges(&[
+                Edge::imports("mod:src/main.py", "mod:src/db.py"),
+                Edge::imports("mod:web/app.ts", "mod:src/db.py"),
+                Edge::imports("mod:src/db.py", "ext:yaml"),
+                Edge::imports("mod:src/main.py", "ext:yaml"),
+                Edge::imports("mod:web/app.ts", "ext:yaml"),
+                Edge::calls("fn:src/main.py:main", "fn:src/db.py:migrate"),
+                Edge::contains("mod:src/db.py", "fn:src/db.py:migrate"),
+            ])
+            .unwrap();
+
+        let stats = build_stats(&mubase).unwrap();
+        assert_eq!(stats.files, 3);
+        assert_eq!(stats.lines, 140);
+        assert_eq!(stats.functions, 3);
+        assert_eq!(stats.max_complexity, 27);
+        assert!((stats.avg_complexity - 12.0).abs() < 1e-9);
+
+        assert_eq!(stats.languages[0].language, "python");
+        assert_eq!(
+            (stats.languages[0].files, stats.languages[0].lines),
+            (2, 120)
+        );
+
+        // External and containment edges don't count as dependents
+        let top: Vec<(&str, usize)> = stats
+            .most_depended_on
+            .iter()
+            .map(|n| (n.id.as_str(), n.dependents))
+            .collect();
+        assert_eq!(top, vec![("mod:src/db.py", 2), ("fn:src/db.py:migrate", 1)]);
+    }
+}
@@ -355,6 +355,25 @@ pub fn check_convention(name: &str, conv: NamingConvention) -> Option<String> {
     }
 }
 
+/// Detect which convention a name follows.
+///
+/// Single words fit several conventions; they are reported as the most
+/// specific one, checking SCREAMING_SNAKE_CASE, PascalCase, snake_case and
+/// camelCase in that order. Returns `None` for mixed names like `Get_user`.
+pub fn detect_convention(name: &str) -> Option<NamingConvention> {
+    if is_screaming_snake_case(name) {
+        Some(NamingConvention::ScreamingSnakeCase)
+    } else if is_pascal_case(name) {
+        Some(NamingConvention::PascalCase)
+    } else if is_snake_case(name) {
+        Some(NamingConvention::SnakeCase)
+    } else if is_camel_case(name) {
+        Some(NamingConvention::CamelCase)
+    } else {
+        None
+    }
+}
+
 // ============================================================================
 // Case Detection Functions
 // ============================================================================
@@ -860,7 +879,7 @@ pub fn detect_language(file_path: &str) -> &'static str {
         "php" => "php",
         "kt" | "kts" => "kotlin",
         "swift" => "swift",
-        "scala" => "scala",
+        "scala" | "sc" => "scala",
         "ex" | "exs" => "elixir",
         "erl" | "hrl" => "erlang",
         "hs" | "lhs" => "haskell",
@@ -1027,6 +1046,17 @@ mod tests {
         assert!(check_convention("HELLO_WORLD", NamingConvention::ScreamingSnakeCase).is_none());
     }
 
+    #[test]
+    fn test_detect_convention() {
+        use NamingConvention::*;
+        assert_eq!(detect_convention("MAX_SIZE"), Some(ScreamingSnakeCase));
+        assert_eq!(detect_convention("UserService"), Some(PascalCase));
+        assert_eq!(detect_convention("get_user"), Some(SnakeCase));
+        assert_eq!(detect_convention("simple"), Some(SnakeCase));
+        assert_eq!(detect_convention("getUser"), Some(CamelCase));
+        assert_eq!(detect_convention("Get_user"), None);
+    }
+
     #[test]
     fn test_check_convention_returns_suggestion_when_invalid() {
         assert_eq!(
@@ -4,11 +4,13 @@
 //! ranking based on complexity, connectivity, and semantic centrality.
 
 use std::collections::{HashMap, HashSet};
-use std::path::Path;
+use std::sync::OnceLock;
 
 use colored::Colorize;
 use mu_daemon::storage::{MUbase, Node, NodeType};
+use mu_embeddings::MuTokenizer;
 
+use crate::mubase::find_mubase_from;
 use crate::output::OutputFormat;
 
 /// Estimated tokens per node in output (avg ~15 chars/line, ~4 chars/token)
@@ -48,7 +50,7 @@ pub async fn run(
 ) -> anyhow::Result<()> {
     // Find mubase
     let cwd = std::env::current_dir()?;
-    let mubase_path = match find_mubase_path(&cwd) {
+    let mubase_path = match find_mubase_from(&cwd) {
         Some(path) => path,
         None => {
             // No database - show helpful message
@@ -96,20 +98,6 @@ pub async fn run(
     Ok(())
 }
 
-/// Find the mubase path starting from the given directory
-fn find_mubase_path(start_dir: &Path) -> Option<std::path::PathBuf> {
-    let mut current = start_dir.to_path_buf();
-    loop {
-        let mubase_path = current.join(".mu").join("mubase");
-        if mubase_path.exists() {
-            return Some(mubase_path);
-        }
-        if !current.pop() {
-            return None;
-        }
-    }
-}
-
 /// Score a node for importance ranking
 fn score_node(node: &Node, edge_count: usize) -> f32 {
     // Complexity score: 0-5 points (normalize to max 5)
@@ -118,9 +106,10 @@ fn score_node(node: &Node, edge_count: usize) -> f32 {
     // Connectivity score: 0-5 points (more edges = more important)
     let connectivity_score = (edge_count as f32 / 5.0).min(5.0);
 
-    // Type bonus: classes are structural, functions contain logic
+    // Type bonus: classes, interfaces and enums are structural, functions
+    // contain logic
     let type_bonus = match node.node_type {
-        NodeType::Class => 2.0,
+        NodeType::Class | NodeType::Interface | NodeType::Enum => 2.0,
         NodeType::Function => 1.0,
         NodeType::Module => 0.5,
         NodeType::External => 0.0,
@@ -174,7 +163,7 @@ fn select_top_nodes(
 }
 
 /// Select edges that connect selected nodes
-fn select_edges(
+pub(crate) fn select_edges(
     all_edges: &[(String, String, String)],
     selected_node_ids: &HashSet<String>,
     max_tokens: usize,
@@ -219,7 +208,9 @@ fn generate_omega_context(
 ) -> anyhow::Result<OmgResult> {
     // Get all nodes
     let modules = mubase.get_nodes_by_type(NodeType::Module)?;
-    let classes = mubase.get_nodes_by_type(NodeType::Class)?;
+    let mut classes = mubase.get_nodes_by_type(NodeType::Class)?;
+    classes.extend(mubase.get_nodes_by_type(NodeType::Interface)?);
+    classes.extend(mubase.get_nodes_by_type(NodeType::Enum)?);
     let functions = mubase.get_nodes_by_type(NodeType::Function)?;
 
     let total_nodes_in_db = modules.len() + classes.len() + functions.len();
@@ -239,23 +230,35 @@ fn generate_omega_context(
     let edge_counts = compute_edge_counts(all_edges);
 
     // Select top nodes within budget
-    let selected_nodes = select_top_nodes(all_nodes, &edge_counts, max_tokens);
-    let selected_node_ids: HashSet<String> = selected_nodes.iter().map(|n| n.id.clone()).collect();
-
-    // Select edges if requested
-    let selected_edges = if include_edges {
-        select_edges(all_edges, &selected_node_ids, max_tokens)
-    } else {
-        Vec::new()
-    };
+    let mut selected_nodes = select_top_nodes(all_nodes, &edge_counts, max_tokens);
 
     // Generate schema seed
     let seed = generate_schema_seed(include_edges);
     let seed_tokens = estimate_tokens(&seed);
 
-    // Generate compressed body
-    let body = generate_compressed_body(&selected_nodes, &selected_edges);
-    let body_tokens = estimate_tokens(&body);
+    // The per-node estimates can undershoot real token counts, so drop the
+    // lowest-ranked nodes until the measured output fits the budget
+    let (selected_edges, body, body_tokens) = loop {
+        let selected_node_ids: HashSet<String> =
+            selected_nodes.iter().map(|n| n.id.clone()).collect();
+
+        // Select edges if requested
+        let selected_edges = if include_edges {
+            select_edges(all_edges, &selected_node_ids, max_tokens)
+        } else {
+            Vec::new()
+        };
+
+        // Generate compressed body
+        let body = generate_compressed_body(&selected_nodes, &selected_edges);
+        let body_tokens = estimate_tokens(&body);
+
+        if seed_tokens + body_tokens <= max_tokens || selected_nodes.is_empty() {
+            break (selected_edges, body, body_tokens);
+        }
+        let keep = selected_nodes.len() - (selected_nodes.len() / 10).max(1);
+        selected_nodes.truncate(keep);
+    };
 
     let total_tokens = seed_tokens + body_tokens;
 
@@ -297,7 +300,7 @@ struct RawOmegaData<'a> {
 }
 
 /// Generate schema seed with macro definitions (cacheable)
-fn generate_schema_seed(include_edges: bool) -> String {
+pub(crate) fn generate_schema_seed(include_edges: bool) -> String {
     let edge_schema = if include_edges {
         "\n  (-> source target type)  ; Edge: source -> target via type"
     } else {
@@ -317,7 +320,10 @@ fn generate_schema_seed(include_edges: bool) -> String {
 }
 
 /// Generate compressed body with nodes grouped by file and edges section
-fn generate_compressed_body(nodes: &[Node], edges: &[(String, String, String)]) -> String {
+pub(crate) fn generate_compressed_body(
+    nodes: &[Node],
+    edges: &[(String, String, String)],
+) -> String {
     let mut output = String::from("(context\n");
 
     // Group nodes by file
@@ -347,7 +353,7 @@ fn generate_compressed_body(nodes: &[Node], edges: &[(String, String, String)])
         let mut sorted_nodes = file_nodes.clone();
         sorted_nodes.sort_by(|a, b| {
             let type_order = |n: &Node| match n.node_type {
-                NodeType::Class => 0,
+                NodeType::Class | NodeType::Interface | NodeType::Enum => 0,
                 NodeType::Function => 1,
                 NodeType::Module => 2,
                 NodeType::External => 3,
@@ -369,6 +375,16 @@ fn generate_compressed_body(nodes: &[Node], edges: &[(String, String, String)])
                     }
                     output.push_str(")\n");
                 }
+                NodeType::Interface => {
+                    output.push_str(&format!("    (iface \"{}\")\n", node.name));
+                }
+                NodeType::Enum => {
+                    output.push_str(&format!(
+                        "    (enum \"{}\" :variants [{}])\n",
+                        node.name,
+                        node.variants().join(" ")
+                    ));
+                }
                 NodeType::Function => {
                     // Skip test functions in overview (less important)
                     if node.name.starts_with("test_") || node.name.starts_with("Test") {
@@ -457,9 +473,14 @@ fn simplify_path(path: &str) -> String {
         .to_string()
 }
 
-/// Estimate token count (rough approximation: 4 chars per token)
-fn estimate_tokens(text: &str) -> usize {
-    text.len() / 4
+/// Count tokens with the embedded model tokenizer, falling back to the
+/// 4-chars-per-token approximation if it cannot be loaded
+pub(crate) fn estimate_tokens(text: &str) -> usize {
+    static TOKENIZER: OnceLock<Option<MuTokenizer>> = OnceLock::new();
+    match TOKENIZER.get_or_init(|| MuTokenizer::embedded().ok()) {
+        Some(tokenizer) => tokenizer.count_tokens(text),
+        None => text.len() / 4,
+    }
 }
 
 fn print_omg_output(result: &OmgResult) {
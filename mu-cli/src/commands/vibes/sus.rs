@@ -6,25 +6,24 @@
 //! - Security sensitive (auth/crypto logic)
 //! - No tests detected
 //! - High complexity
+//! - Deeply nested logic (cognitive complexity far above cyclomatic)
+//! - Low Maintainability Index (whole files)
+//! - Clusters of functions nothing calls (whole files)
+//! - Unused or duplicate imports
+//! - God classes and oversized files (thresholds from `[sus]` in `.murc.toml`)
 //!
 //! When run without arguments, scans the entire codebase for suspicious files.
 
 use anyhow::{Context, Result};
 use colored::Colorize;
 use duckdb::{params, Connection};
-use std::path::PathBuf;
+use std::path::Path;
 
+use crate::config::{MuConfig, SusConfig};
+use crate::gate::{self, WarningLevel};
+use crate::mubase::find_mubase;
 use crate::output::OutputFormat;
 
-/// Warning level for sus checks
-#[derive(Debug, Clone, Copy, serde::Serialize, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum WarningLevel {
-    Info,
-    Warn,
-    Error,
-}
-
 /// A single warning from the sus check
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct SusWarning {
@@ -61,7 +60,14 @@ pub struct ScanResult {
 }
 
 /// Run the sus command - risk assessment with personality
-pub async fn run(path: &str, threshold: u8, format: OutputFormat) -> Result<()> {
+///
+/// With `fail_on`, exits with status 1 if a shown warning reaches that level.
+pub async fn run(
+    path: &str,
+    threshold: u8,
+    fail_on: Option<WarningLevel>,
+    format: OutputFormat,
+) -> Result<()> {
     // Find the MUbase database
     let db_path = match find_mubase(".") {
         Ok(path) => path,
@@ -93,9 +99,13 @@ pub async fn run(path: &str, threshold: u8, format: OutputFormat) -> Result<()>
     )
     .with_context(|| format!("Failed to open database: {:?}", db_path))?;
 
+    // Source files are read relative to the project root for import checks
+    let root = crate::mubase::project_root(&conn, &db_path);
+    let limits = MuConfig::load(&root).sus;
+
     // If path is "." or empty, scan the entire codebase
     if path == "." || path.is_empty() {
-        let scan_result = scan_all_nodes(&conn, threshold)?;
+        let scan_result = scan_all_nodes(&conn, &root, &limits, threshold)?;
         match format {
             OutputFormat::Json => {
                 println!("{}", serde_json::to_string_pretty(&scan_result)?);
@@ -104,6 +114,11 @@ pub async fn run(path: &str, threshold: u8, format: OutputFormat) -> Result<()>
                 print_scan_output(&scan_result);
             }
         }
+        let levels = scan_result
+            .results
+            .iter()
+            .flat_map(|r| r.warnings.iter().map(|w| w.level));
+        gate::exit_on_findings(levels, fail_on);
         return Ok(());
     }
 
@@ -137,7 +152,7 @@ pub async fn run(path: &str, threshold: u8, format: OutputFormat) -> Result<()>
     };
 
     // Perform risk assessment on single node
-    let result = analyze_risk(&conn, &node_id, threshold)?;
+    let result = analyze_risk(&conn, &root, &node_id, &limits, threshold)?;
 
     match format {
         OutputFormat::Json => {
@@ -148,11 +163,17 @@ pub async fn run(path: &str, threshold: u8, format: OutputFormat) -> Result<()>
         }
     }
 
+    gate::exit_on_findings(result.warnings.iter().map(|w| w.level), fail_on);
     Ok(())
 }
 
 /// Scan all nodes in the codebase for suspicious patterns
-fn scan_all_nodes(conn: &Connection, threshold: u8) -> Result<ScanResult> {
+fn scan_all_nodes(
+    conn: &Connection,
+    root: &Path,
+    limits: &SusConfig,
+    threshold: u8,
+) -> Result<ScanResult> {
     // Query all module-type nodes (files)
     let mut stmt = conn.prepare(
         "SELECT id, file_path FROM nodes
@@ -174,7 +195,7 @@ fn scan_all_nodes(conn: &Connection, threshold: u8) -> Result<ScanResult> {
         total_scanned += 1;
 
         // Analyze each node
-        if let Ok(result) = analyze_risk(conn, &node_id, threshold) {
+        if let Ok(result) = analyze_risk(conn, root, &node_id, limits, threshold) {
             // Only keep results with risk score > 0
             if result.risk_score > 0 {
                 all_results.push(result);
@@ -183,7 +204,7 @@ fn scan_all_nodes(conn: &Connection, threshold: u8) -> Result<ScanResult> {
     }
 
     // Sort by risk score descending
-    all_results.sort_by(|a, b| b.risk_score.cmp(&a.risk_score));
+    all_results.sort_by_key(|r| std::cmp::Reverse(r.risk_score));
 
     // Keep top 20 most suspicious
     all_results.truncate(20);
@@ -326,37 +347,6 @@ fn print_sus_output(result: &SusResult) {
     println!();
 }
 
-/// Find the MUbase database in the given directory or its parents.
-fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize()?;
-    let mut current = start.as_path();
-
-    loop {
-        // New standard path: .mu/mubase
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Ok(db_path);
-        }
-
-        // Legacy path: .mubase
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Ok(legacy_path);
-        }
-
-        // Move up to parent
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "No MUbase found. Run 'mu bootstrap' first to create the database."
-                ))
-            }
-        }
-    }
-}
-
 /// Try to resolve a partial node ID or file path to a full node ID
 fn resolve_node_id(conn: &Connection, partial: &str) -> Result<String> {
     // First try exact match
@@ -420,7 +410,13 @@ fn resolve_node_id(conn: &Connection, partial: &str) -> Result<String> {
 }
 
 /// Analyze risk factors for a node
-fn analyze_risk(conn: &Connection, node_id: &str, threshold: u8) -> Result<SusResult> {
+fn analyze_risk(
+    conn: &Connection,
+    root: &Path,
+    node_id: &str,
+    limits: &SusConfig,
+    threshold: u8,
+) -> Result<SusResult> {
     let mut warnings = Vec::new();
     let mut risk_score = 0u8;
 
@@ -481,6 +477,12 @@ fn analyze_risk(conn: &Connection, node_id: &str, threshold: u8) -> Result<SusRe
         risk_score += 2;
     }
 
+    // Check 2b: Nesting that cyclomatic complexity understates
+    if let Some(warning) = check_cognitive(conn, root, node_id, file_path_str)? {
+        warnings.push(warning);
+        risk_score += 2;
+    }
+
     // Check 3: Security sensitive (auth, crypto, password, token, secret, key)
     let security_keywords = [
         "auth",
@@ -535,6 +537,38 @@ fn analyze_risk(conn: &Connection, node_id: &str, threshold: u8) -> Result<SusRe
         risk_score += 2;
     }
 
+    // Check 5: Import hygiene (modules only - imports are file-level)
+    if node_id.starts_with("mod:") {
+        let import_warnings = check_imports(root, file_path_str);
+        if !import_warnings.is_empty() {
+            risk_score += 1;
+        }
+        warnings.extend(import_warnings);
+    }
+
+    // Check 6: Maintainability Index (modules only)
+    if node_id.starts_with("mod:") {
+        if let Some(warning) = check_maintainability(root, file_path_str) {
+            warnings.push(warning);
+            risk_score += 2;
+        }
+    }
+
+    // Check 7: Dead code candidates (modules only)
+    if node_id.starts_with("mod:") {
+        if let Some(warning) = check_dead_code(conn, file_path_str)? {
+            warnings.push(warning);
+            risk_score += 1;
+        }
+    }
+
+    // Check 8: God classes and oversized files
+    if node_id.starts_with("mod:") || node_id.starts_with("cls:") {
+        let size_warnings = check_size(conn, node_id, limits)?;
+        risk_score += 2 * size_warnings.len().min(2) as u8;
+        warnings.extend(size_warnings);
+    }
+
     // Cap risk score at 10
     risk_score = risk_score.min(10);
 
@@ -560,6 +594,304 @@ fn analyze_risk(conn: &Connection, node_id: &str, threshold: u8) -> Result<SusRe
     })
 }
 
+/// Cognitive complexity past which nested code gets hard to follow (SonarSource's default).
+const COGNITIVE_THRESHOLD: u32 = 15;
+
+/// Find the function whose cognitive complexity most exceeds its cyclomatic complexity.
+///
+/// Checks the node itself for functions and every function in the file for
+/// modules. Only functions at or above [`COGNITIVE_THRESHOLD`] and at least
+/// twice their cyclomatic complexity are reported.
+fn check_cognitive(
+    conn: &Connection,
+    root: &Path,
+    node_id: &str,
+    file_path: &str,
+) -> Result<Option<SusWarning>> {
+    if file_path.is_empty() {
+        return Ok(None);
+    }
+    let language = super::conventions::detect_language(file_path);
+    let Ok(source) = std::fs::read_to_string(root.join(file_path)) else {
+        return Ok(None);
+    };
+    let lines: Vec<&str> = source.lines().collect();
+
+    let mut stmt = conn.prepare(
+        "SELECT name, line_start, line_end, complexity FROM nodes
+         WHERE type = 'function' AND (id = ? OR (? LIKE 'mod:%' AND file_path = ?))",
+    )?;
+    let mut rows = stmt.query(params![node_id, node_id, file_path])?;
+
+    let mut worst: Option<(String, u32, u32)> = None;
+    while let Some(row) = rows.next()? {
+        let name: String = row.get(0)?;
+        let (Some(start), Some(end)) =
+            (row.get::<_, Option<i64>>(1)?, row.get::<_, Option<i64>>(2)?)
+        else {
+            continue;
+        };
+        let cyclomatic: u32 = row.get(3).unwrap_or(0);
+        let start = (start.max(1) - 1) as usize;
+        let end = (end.max(0) as usize).min(lines.len());
+        if start >= end {
+            continue;
+        }
+
+        let cognitive =
+            mu_core::calculate_cognitive_complexity(&dedent(&lines[start..end]), language);
+        let exceeds = cognitive >= COGNITIVE_THRESHOLD && cognitive >= 2 * cyclomatic.max(1);
+        if exceeds && worst.as_ref().is_none_or(|(_, c, _)| cognitive > *c) {
+            worst = Some((name, cognitive, cyclomatic));
+        }
+    }
+
+    Ok(worst.map(|(name, cognitive, cyclomatic)| SusWarning {
+        level: WarningLevel::Warn,
+        category: "cognitive complexity".to_string(),
+        message: format!(
+            "{} has cognitive complexity {} but cyclomatic {} (deeply nested)",
+            name, cognitive, cyclomatic
+        ),
+        suggestion: Some("Flatten nesting with early returns or extract inner blocks.".to_string()),
+    }))
+}
+
+/// Warn when a whole file scores in the "hard to maintain" band of the
+/// Maintainability Index.
+fn check_maintainability(root: &Path, file_path: &str) -> Option<SusWarning> {
+    use mu_core::reducer::maintainability::LOW_THRESHOLD;
+
+    if file_path.is_empty() {
+        return None;
+    }
+    let language = super::conventions::detect_language(file_path);
+    let source = std::fs::read_to_string(root.join(file_path)).ok()?;
+    let index = mu_core::calculate_maintainability(&source, language);
+    if index >= LOW_THRESHOLD {
+        return None;
+    }
+
+    Some(SusWarning {
+        level: WarningLevel::Warn,
+        category: "maintainability".to_string(),
+        message: format!(
+            "Maintainability Index is {:.0}/100 (hard to maintain)",
+            index
+        ),
+        suggestion: Some("Split the file or extract its most complex functions.".to_string()),
+    })
+}
+
+/// Unreferenced functions in one file that add up to a dead-code warning.
+const DEAD_CODE_CLUSTER: usize = 3;
+
+/// Warn when a file holds several functions nothing calls.
+///
+/// Exported functions are left out, since callers outside the project are
+/// invisible. See [`mu_core::graph::unreferenced_functions`] for the other
+/// heuristics.
+fn check_dead_code(conn: &Connection, file_path: &str) -> Result<Option<SusWarning>> {
+    if file_path.is_empty() {
+        return Ok(None);
+    }
+
+    let mut nodes = Vec::new();
+    let mut exported = std::collections::HashSet::new();
+    let mut stmt = conn.prepare(
+        "SELECT id, COALESCE(properties::TEXT LIKE '%\"exported\"%', false) FROM nodes
+         WHERE type = 'function' AND file_path = ?",
+    )?;
+    let mut rows = stmt.query(params![file_path])?;
+    while let Some(row) = rows.next()? {
+        let id: String = row.get(0)?;
+        if row.get::<_, bool>(1)? {
+            exported.insert(id.clone());
+        }
+        nodes.push(id);
+    }
+
+    let mut edges = Vec::new();
+    let mut stmt = conn.prepare(
+        "SELECT e.source_id, e.target_id, e.type FROM edges e
+         JOIN nodes n ON n.id = e.target_id
+         WHERE e.type = 'calls' AND n.file_path = ?",
+    )?;
+    let mut rows = stmt.query(params![file_path])?;
+    while let Some(row) = rows.next()? {
+        edges.push((row.get(0)?, row.get(1)?, row.get(2)?));
+    }
+
+    let dead = mu_core::graph::unreferenced_functions(&nodes, &edges, &exported);
+    if dead.len() < DEAD_CODE_CLUSTER {
+        return Ok(None);
+    }
+    let names: Vec<&str> = dead
+        .iter()
+        .map(|id| id.rsplit(':').next().unwrap_or(id))
+        .collect();
+
+    Ok(Some(SusWarning {
+        level: WarningLevel::Warn,
+        category: "dead code".to_string(),
+        message: format!(
+            "{} functions are never called (candidates): {}",
+            dead.len(),
+            names.join(", ")
+        ),
+        suggestion: Some(
+            "Check for dynamic callers, then delete what is really unused.".to_string(),
+        ),
+    }))
+}
+
+/// Warn about classes with too many methods and files with too many
+/// functions or lines.
+///
+/// Counts follow `contains` edges: a class's methods, and a module's
+/// functions plus the methods of its classes. A module is also checked for
+/// God classes, so a codebase scan surfaces them.
+fn check_size(conn: &Connection, node_id: &str, limits: &SusConfig) -> Result<Vec<SusWarning>> {
+    let mut warnings = Vec::new();
+
+    let mut stmt = conn.prepare(
+        "SELECT c.name, COUNT(m.id) AS methods FROM nodes c
+         JOIN edges e ON e.source_id = c.id AND e.type = 'contains'
+         JOIN nodes m ON m.id = e.target_id AND m.type = 'function'
+         WHERE c.type IN ('class', 'interface', 'enum') AND (c.id = ? OR c.id IN (
+             SELECT target_id FROM edges WHERE source_id = ? AND type = 'contains'))
+         GROUP BY c.id, c.name
+         HAVING COUNT(m.id) > ?
+         ORDER BY methods DESC, c.name",
+    )?;
+    let mut rows = stmt.query(params![node_id, node_id, limits.max_methods as i64])?;
+    while let Some(row) = rows.next()? {
+        let name: String = row.get(0)?;
+        let methods: i64 = row.get(1)?;
+        warnings.push(SusWarning {
+            level: WarningLevel::Warn,
+            category: "god class".to_string(),
+            message: format!(
+                "{} has {} methods (limit {})",
+                name, methods, limits.max_methods
+            ),
+            suggestion: Some("Split it along its responsibilities.".to_string()),
+        });
+    }
+
+    if !node_id.starts_with("mod:") {
+        return Ok(warnings);
+    }
+
+    let (functions, lines): (i64, i64) = conn.query_row(
+        "WITH members AS (
+             SELECT target_id AS id FROM edges WHERE source_id = ? AND type = 'contains'
+         )
+         SELECT
+             (SELECT COUNT(*) FROM nodes n WHERE n.type = 'function' AND (
+                 n.id IN (SELECT id FROM members) OR n.id IN (
+                     SELECT e.target_id FROM edges e
+                     WHERE e.type = 'contains' AND e.source_id IN (SELECT id FROM members)))),
+             COALESCE((SELECT MAX(line_end) FROM nodes WHERE id = ? OR file_path =
+                 (SELECT file_path FROM nodes WHERE id = ?)), 0)",
+        params![node_id, node_id, node_id],
+        |row| Ok((row.get(0)?, row.get(1)?)),
+    )?;
+
+    let mut sizes = Vec::new();
+    if functions as usize > limits.max_functions {
+        sizes.push(format!(
+            "{} functions (limit {})",
+            functions, limits.max_functions
+        ));
+    }
+    if lines as usize > limits.max_lines {
+        sizes.push(format!("{} lines (limit {})", lines, limits.max_lines));
+    }
+    if !sizes.is_empty() {
+        warnings.push(SusWarning {
+            level: WarningLevel::Warn,
+            category: "large file".to_string(),
+            message: format!("File has {}", sizes.join(" and ")),
+            suggestion: Some(
+                "Move cohesive groups of functions into their own modules.".to_string(),
+            ),
+        });
+    }
+
+    Ok(warnings)
+}
+
+/// Strip the indentation common to all non-blank lines, so methods parse on their own.
+fn dedent(lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|l| !l.trim().is_empty())
+        .map(|l| l.len() - l.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|l| l.get(indent..).unwrap_or_else(|| l.trim_start()))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Check a source file for unused and duplicate imports.
+///
+/// Unreadable files and unsupported languages yield no warnings.
+fn check_imports(root: &Path, file_path: &str) -> Vec<SusWarning> {
+    use mu_core::reducer::imports::{self, ImportIssueKind};
+
+    let path = Path::new(file_path);
+    let language = match path.extension().and_then(|e| e.to_str()) {
+        Some("py") => "python",
+        Some("ts") => "typescript",
+        Some("tsx") => "tsx",
+        Some("js" | "jsx" | "mjs") => "javascript",
+        Some("rs") => "rust",
+        _ => return Vec::new(),
+    };
+    let Ok(source) = std::fs::read_to_string(root.join(path)) else {
+        return Vec::new();
+    };
+    let Ok(issues) = imports::check_imports(&source, language) else {
+        return Vec::new();
+    };
+
+    // Package __init__ files import names to re-export them
+    let is_reexport_module = path.file_name().and_then(|n| n.to_str()) == Some("__init__.py");
+
+    let describe = |kind: ImportIssueKind| {
+        issues
+            .iter()
+            .filter(|i| i.kind == kind)
+            .map(|i| format!("{} (line {})", i.name, i.line))
+            .collect::<Vec<_>>()
+    };
+
+    let mut warnings = Vec::new();
+    let unused = describe(ImportIssueKind::Unused);
+    if !unused.is_empty() && !is_reexport_module {
+        warnings.push(SusWarning {
+            level: WarningLevel::Warn,
+            category: "imports".to_string(),
+            message: format!("Unused imports: {}", unused.join(", ")),
+            suggestion: Some("Remove imports that are never referenced.".to_string()),
+        });
+    }
+    let duplicate = describe(ImportIssueKind::Duplicate);
+    if !duplicate.is_empty() {
+        warnings.push(SusWarning {
+            level: WarningLevel::Warn,
+            category: "imports".to_string(),
+            message: format!("Duplicate imports: {}", duplicate.join(", ")),
+            suggestion: Some("Import each name once.".to_string()),
+        });
+    }
+    warnings
+}
+
 /// Node information for analysis
 struct NodeInfo {
     name: String,
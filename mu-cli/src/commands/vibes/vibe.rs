@@ -1,7 +1,9 @@
 //! Vibe command - Pattern conformance checking
 //!
 //! Checks if code matches established codebase patterns.
-//! Useful for ensuring consistency and can be used in CI.
+//! Useful for ensuring consistency and can be used in CI: naming violations
+//! are listed with their locations (`--format json` or `csv`), and the command
+//! exits with status 1 when there are any (see `--fail-on`).
 //!
 //! Categories:
 //! - naming: Naming conventions (language-aware)
@@ -15,14 +17,15 @@ use anyhow::{Context, Result};
 use colored::Colorize;
 use duckdb::Connection;
 use std::collections::{HashMap, HashSet};
-use std::path::PathBuf;
 
 use super::conventions::{
-    check_convention, convention_for_entity_with_context, detect_language, is_csharp_test_method,
-    is_dunder, is_kebab_case, is_pascal_case, should_skip_naming_check, EntityType,
-    NamingConvention,
+    check_convention, convention_for_entity_with_context, detect_convention, detect_language,
+    is_csharp_test_method, is_dunder, is_kebab_case, is_pascal_case, should_skip_naming_check,
+    EntityType, NamingConvention,
 };
-use crate::output::OutputFormat;
+use crate::gate::{self, WarningLevel};
+use crate::mubase::find_mubase;
+use crate::output::{Column, CsvOutput, Output, OutputConfig, OutputFormat, Outputter};
 
 /// Framework-required file names that should be skipped for naming convention checks.
 /// These are Next.js and similar framework conventions that use kebab-case intentionally.
@@ -85,13 +88,52 @@ pub struct VibeIssue {
     pub suggestion: Option<String>,
 }
 
+/// A name that breaks its naming convention
+#[derive(Debug, Clone, serde::Serialize)]
+pub struct NamingViolation {
+    pub file: String,
+    /// Line the entity starts on
+    pub line: Option<u32>,
+    pub symbol: String,
+    /// Entity kind (function, class, ...)
+    pub entity: String,
+    pub language: String,
+    pub expected_convention: String,
+    /// Convention the name follows, or "mixed"
+    pub actual: String,
+    /// The name rewritten in the expected convention
+    pub suggestion: String,
+}
+
+impl NamingViolation {
+    fn to_issue(&self) -> VibeIssue {
+        VibeIssue {
+            file: self.file.clone(),
+            category: "naming".to_string(),
+            message: format!(
+                "{} '{}' should use {} ({})",
+                capitalize_first(&self.entity),
+                self.symbol,
+                self.expected_convention,
+                self.language
+            ),
+            suggestion: Some(format!("Rename to '{}'", self.suggestion)),
+        }
+    }
+}
+
 /// Result of a vibe check
 #[derive(Debug, serde::Serialize)]
 pub struct VibeResult {
     pub path: String,
+    /// Convention forced with `--convention`
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub convention: Option<String>,
     pub files_checked: usize,
     pub patterns_detected: usize,
     pub issues: Vec<VibeIssue>,
+    /// Naming issues with their locations; any of these fail the check
+    pub violations: Vec<NamingViolation>,
 }
 
 impl VibeResult {
@@ -100,36 +142,6 @@ impl VibeResult {
     }
 }
 
-/// Find the MUbase database in the given directory or its parents.
-fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize()?;
-    let mut current = start.as_path();
-
-    loop {
-        // New standard path: .mu/mubase
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Ok(db_path);
-        }
-
-        // Legacy path: .mubase
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Ok(legacy_path);
-        }
-
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "No MUbase found. Run 'mu bootstrap' first to create the database."
-                ))
-            }
-        }
-    }
-}
-
 /// Node data loaded from database
 #[derive(Debug, Clone)]
 struct NodeData {
@@ -137,14 +149,15 @@ struct NodeData {
     node_type: String,
     name: String,
     file_path: Option<String>,
+    line: Option<u32>,
 }
 
 /// Check naming conventions (language-aware)
 fn check_naming_conventions(
     nodes: &[NodeData],
     convention_override: Option<NamingConvention>,
-) -> Vec<VibeIssue> {
-    let mut issues = Vec::new();
+) -> Vec<NamingViolation> {
+    let mut violations = Vec::new();
 
     for node in nodes {
         // Skip empty names (parser artifacts)
@@ -260,22 +273,23 @@ fn check_naming_conventions(
 
         // Check if the name follows the expected convention
         if let Some(suggestion) = check_convention(&node.name, expected_convention) {
-            issues.push(VibeIssue {
+            violations.push(NamingViolation {
                 file: node.file_path.clone().unwrap_or_else(|| node.id.clone()),
-                category: "naming".to_string(),
-                message: format!(
-                    "{} '{}' should use {} ({})",
-                    capitalize_first(entity_type),
-                    node.name,
-                    expected_convention,
-                    language
-                ),
-                suggestion: Some(format!("Rename to '{}'", suggestion)),
+                line: node.line,
+                symbol: node.name.clone(),
+                entity: entity_type.to_string(),
+                language: language.to_string(),
+                expected_convention: expected_convention.to_string(),
+                actual: detect_convention(&node.name)
+                    .map(|c| c.to_string())
+                    .unwrap_or_else(|| "mixed".to_string()),
+                suggestion,
             });
         }
     }
 
-    issues
+    violations.sort_by(|a, b| (&a.file, a.line).cmp(&(&b.file, b.line)));
+    violations
 }
 
 /// Capitalize the first letter of a string
@@ -585,14 +599,19 @@ fn check_async_patterns(conn: &Connection, path: &str) -> Result<Vec<VibeIssue>>
 
 /// Run the vibe command - pattern conformance with personality
 ///
+/// Exits with status 1 when a finding reaches `fail_on`: naming violations
+/// are errors, the other hints warnings.
+///
 /// # Arguments
 /// * `path` - Path to check
 /// * `format` - Output format (table, json, etc.)
 /// * `convention_override` - Optional convention to use for all entities (overrides language detection)
+/// * `fail_on` - Lowest finding level that fails the check
 pub async fn run(
     path: &str,
     format: OutputFormat,
     convention_override: Option<&str>,
+    fail_on: WarningLevel,
 ) -> anyhow::Result<()> {
     // Parse convention override if provided
     let convention = convention_override
@@ -607,20 +626,13 @@ pub async fn run(
         Err(_) => {
             let result = VibeResult {
                 path: path.to_string(),
+                convention: convention.map(|c| c.to_string()),
                 files_checked: 0,
                 patterns_detected: 0,
                 issues: vec![],
+                violations: vec![],
             };
-
-            match format {
-                OutputFormat::Json => {
-                    println!("{}", serde_json::to_string_pretty(&result)?);
-                }
-                _ => {
-                    print_vibe_output(&result, convention);
-                }
-            }
-            return Ok(());
+            return Output::new(result, format).render();
         }
     };
 
@@ -637,8 +649,8 @@ pub async fn run(
     let files_checked = count_unique_files(&nodes);
 
     patterns_checked += 1;
-    let naming_issues = check_naming_conventions(&nodes, convention);
-    all_issues.extend(naming_issues);
+    let violations = check_naming_conventions(&nodes, convention);
+    all_issues.extend(violations.iter().map(NamingViolation::to_issue));
 
     patterns_checked += 1;
     let circular_issues = check_circular_imports(&conn, path)?;
@@ -665,20 +677,23 @@ pub async fn run(
 
     let result = VibeResult {
         path: path.to_string(),
+        convention: convention.map(|c| c.to_string()),
         files_checked,
         patterns_detected: patterns_checked,
         issues: all_issues,
+        violations,
     };
+    let levels: Vec<WarningLevel> = result
+        .issues
+        .iter()
+        .map(|issue| match issue.category.as_str() {
+            "naming" => WarningLevel::Error,
+            _ => WarningLevel::Warn,
+        })
+        .collect();
+    Output::new(result, format).render()?;
 
-    match format {
-        OutputFormat::Json => {
-            println!("{}", serde_json::to_string_pretty(&result)?);
-        }
-        _ => {
-            print_vibe_output(&result, convention);
-        }
-    }
-
+    gate::exit_on_findings(levels, Some(fail_on));
     Ok(())
 }
 
@@ -687,10 +702,11 @@ fn load_nodes_from_path(conn: &Connection, path: &str) -> Result<Vec<NodeData>>
     let mut nodes = Vec::new();
 
     let query = if path == "." {
-        "SELECT id, type, name, file_path FROM nodes WHERE file_path IS NOT NULL".to_string()
+        "SELECT id, type, name, file_path, line_start FROM nodes WHERE file_path IS NOT NULL"
+            .to_string()
     } else {
         format!(
-            "SELECT id, type, name, file_path FROM nodes WHERE file_path IS NOT NULL AND file_path LIKE '%{}%'",
+            "SELECT id, type, name, file_path, line_start FROM nodes WHERE file_path IS NOT NULL AND file_path LIKE '%{}%'",
             path.replace("'", "''")
         )
     };
@@ -703,12 +719,14 @@ fn load_nodes_from_path(conn: &Connection, path: &str) -> Result<Vec<NodeData>>
         let node_type: String = row.get(1)?;
         let name: String = row.get(2)?;
         let file_path: Option<String> = row.get(3)?;
+        let line: Option<u32> = row.get(4)?;
 
         nodes.push(NodeData {
             id,
             node_type,
             name,
             file_path,
+            line,
         });
     }
 
@@ -817,130 +835,181 @@ fn infer_test_path(file_path: &str) -> String {
     }
 }
 
-fn print_vibe_output(result: &VibeResult, convention_override: Option<NamingConvention>) {
-    println!();
-    println!("{} {}", "Vibe Check:".magenta().bold(), result.path.bold());
-
-    // Show convention override if specified
-    if let Some(conv) = convention_override {
-        println!("{}", format!("Convention override: {}", conv).cyan());
-    }
+impl Outputter for VibeResult {
+    fn to_table(&self, _config: &OutputConfig) -> String {
+        let mut lines = Vec::new();
+        lines.push(String::new());
+        lines.push(format!(
+            "{} {}",
+            "Vibe Check:".magenta().bold(),
+            self.path.bold()
+        ));
+
+        // Show convention override if specified
+        if let Some(conv) = &self.convention {
+            lines.push(format!("Convention override: {}", conv).cyan().to_string());
+        }
 
-    println!();
+        lines.push(String::new());
 
-    if result.files_checked == 0 {
-        println!(
-            "{}",
-            "No MU database found. Run 'mu bootstrap' first.".yellow()
-        );
-        println!();
-        println!(
-            "{}",
-            "Once indexed, I'll check if your code matches the codebase vibe.".dimmed()
-        );
-        println!();
-        println!("{}", "Pattern Categories:".cyan());
-        println!(
-            "  {} naming       - Naming conventions (language-aware)",
-            "*".dimmed()
-        );
-        println!("  {} architecture - Architectural patterns", "*".dimmed());
-        println!("  {} testing      - Test patterns", "*".dimmed());
-        println!("  {} imports      - Import organization", "*".dimmed());
-        println!("  {} api          - API patterns", "*".dimmed());
-        println!("  {} async        - Async patterns", "*".dimmed());
-        println!();
-        println!("{}", "Usage:".cyan());
-        println!(
-            "  {} mu vibe                       # Check uncommitted changes",
-            "$".dimmed()
-        );
-        println!(
-            "  {} mu vibe --staged              # Check staged changes",
-            "$".dimmed()
-        );
-        println!(
-            "  {} mu vibe src/new.py            # Check specific file",
-            "$".dimmed()
-        );
-        println!(
-            "  {} mu vibe --convention snake    # Force snake_case convention",
-            "$".dimmed()
-        );
-        println!(
-            "  {} mu vibe --convention pascal   # Force PascalCase convention",
-            "$".dimmed()
-        );
-        println!(
-            "  {} mu vibe -c naming             # Only check naming",
-            "$".dimmed()
-        );
-        println!(
-            "  {} mu vibe --strict              # Exit 1 on issues (CI)",
-            "$".dimmed()
-        );
-        println!();
-        println!("{}", "Supported Conventions:".cyan());
-        println!(
-            "  {} snake        - snake_case (Python, Rust functions)",
-            "*".dimmed()
-        );
-        println!(
-            "  {} pascal       - PascalCase (C# methods, most classes)",
-            "*".dimmed()
-        );
-        println!(
-            "  {} camel        - camelCase (JavaScript, Java methods)",
-            "*".dimmed()
-        );
-        println!(
-            "  {} screaming    - SCREAMING_SNAKE_CASE (constants)",
-            "*".dimmed()
-        );
-    } else if result.is_immaculate() {
-        println!(
-            "{}",
-            "[OK] All good. The vibe is immaculate.".green().bold()
-        );
-        println!();
-        println!(
-            "{}",
-            format!(
-                "Checked {} files against {} patterns",
-                result.files_checked, result.patterns_detected
-            )
-            .dimmed()
-        );
-    } else {
-        println!(
-            "{}",
-            format!("{} hints (opinionated)", result.issues.len())
-                .yellow()
-                .bold()
-        );
-        println!();
-
-        for issue in &result.issues {
-            println!(
-                "{} {} {}",
-                "~".yellow(),
-                format!("[{}]", issue.category).cyan(),
-                issue.message
+        if self.files_checked == 0 {
+            lines.push(
+                "No MU database found. Run 'mu bootstrap' first."
+                    .yellow()
+                    .to_string(),
+            );
+            lines.push(String::new());
+            lines.push(
+                "Once indexed, I'll check if your code matches the codebase vibe."
+                    .dimmed()
+                    .to_string(),
+            );
+            lines.push(String::new());
+            lines.push("Pattern Categories:".cyan().to_string());
+            lines.push(format!(
+                "  {} naming       - Naming conventions (language-aware)",
+                "*".dimmed()
+            ));
+            lines.push(format!(
+                "  {} architecture - Architectural patterns",
+                "*".dimmed()
+            ));
+            lines.push(format!("  {} testing      - Test patterns", "*".dimmed()));
+            lines.push(format!(
+                "  {} imports      - Import organization",
+                "*".dimmed()
+            ));
+            lines.push(format!("  {} api          - API patterns", "*".dimmed()));
+            lines.push(format!("  {} async        - Async patterns", "*".dimmed()));
+            lines.push(String::new());
+            lines.push("Usage:".cyan().to_string());
+            lines.push(format!(
+                "  {} mu vibe                       # Check uncommitted changes",
+                "$".dimmed()
+            ));
+            lines.push(format!(
+                "  {} mu vibe --staged              # Check staged changes",
+                "$".dimmed()
+            ));
+            lines.push(format!(
+                "  {} mu vibe src/new.py            # Check specific file",
+                "$".dimmed()
+            ));
+            lines.push(format!(
+                "  {} mu vibe --convention snake    # Force snake_case convention",
+                "$".dimmed()
+            ));
+            lines.push(format!(
+                "  {} mu vibe --convention pascal   # Force PascalCase convention",
+                "$".dimmed()
+            ));
+            lines.push(format!(
+                "  {} mu vibe -c naming             # Only check naming",
+                "$".dimmed()
+            ));
+            lines.push(format!(
+                "  {} mu vibe --format json         # Naming violations for CI",
+                "$".dimmed()
+            ));
+            lines.push(String::new());
+            lines.push("Supported Conventions:".cyan().to_string());
+            lines.push(format!(
+                "  {} snake        - snake_case (Python, Rust functions)",
+                "*".dimmed()
+            ));
+            lines.push(format!(
+                "  {} pascal       - PascalCase (C# methods, most classes)",
+                "*".dimmed()
+            ));
+            lines.push(format!(
+                "  {} camel        - camelCase (JavaScript, Java methods)",
+                "*".dimmed()
+            ));
+            lines.push(format!(
+                "  {} screaming    - SCREAMING_SNAKE_CASE (constants)",
+                "*".dimmed()
+            ));
+        } else if self.is_immaculate() {
+            lines.push(
+                "[OK] All good. The vibe is immaculate."
+                    .green()
+                    .bold()
+                    .to_string(),
+            );
+            lines.push(String::new());
+            lines.push(
+                format!(
+                    "Checked {} files against {} patterns",
+                    self.files_checked, self.patterns_detected
+                )
+                .dimmed()
+                .to_string(),
+            );
+        } else {
+            lines.push(
+                format!("{} hints (opinionated)", self.issues.len())
+                    .yellow()
+                    .bold()
+                    .to_string(),
             );
-            println!("  {}", issue.file.dimmed());
-            if let Some(suggestion) = &issue.suggestion {
-                println!("  {} {}", "->".dimmed(), suggestion);
+            lines.push(String::new());
+
+            for issue in &self.issues {
+                lines.push(format!(
+                    "{} {} {}",
+                    "~".yellow(),
+                    format!("[{}]", issue.category).cyan(),
+                    issue.message
+                ));
+                lines.push(format!("  {}", issue.file.dimmed()));
+                if let Some(suggestion) = &issue.suggestion {
+                    lines.push(format!("  {} {}", "->".dimmed(), suggestion));
+                }
+                lines.push(String::new());
             }
-            println!();
+
+            lines.push(
+                "Naming violations fail the check; the other hints are suggestions."
+                    .dimmed()
+                    .to_string(),
+            );
         }
 
-        println!(
-            "{}",
-            "These are suggestions, not errors. Use --strict for CI enforcement.".dimmed()
-        );
+        lines.push(String::new());
+        lines.join("\n")
     }
 
-    println!();
+    fn to_csv(&self, config: &OutputConfig) -> String {
+        let columns = [
+            Column::new("file", "file"),
+            Column::new("line", "line"),
+            Column::new("symbol", "symbol"),
+            Column::new("expected_convention", "expected_convention"),
+            Column::new("actual", "actual"),
+        ];
+        CsvOutput::format_with_columns(&self.violations, &columns, config)
+    }
+
+    fn to_mu(&self, _config: &OutputConfig) -> String {
+        let mut lines = vec![format!(
+            ":: vibe {} files={} issues={} violations={}",
+            self.path,
+            self.files_checked,
+            self.issues.len(),
+            self.violations.len()
+        )];
+        for v in &self.violations {
+            lines.push(format!(
+                "! {}:{} {} expected={} actual={}",
+                v.file,
+                v.line.unwrap_or(0),
+                v.symbol,
+                v.expected_convention,
+                v.actual
+            ));
+        }
+        lines.join("\n")
+    }
 }
 
 #[cfg(test)]
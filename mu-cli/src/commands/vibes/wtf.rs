@@ -13,6 +13,7 @@ use std::collections::{HashMap, HashSet};
 use std::path::{Path, PathBuf};
 use std::process::Command;
 
+use crate::mubase::find_mubase;
 use crate::output::OutputFormat;
 
 /// Information about a commit
@@ -110,36 +111,6 @@ fn is_git_repo() -> Result<bool> {
     Ok(output.status.success())
 }
 
-/// Find the MUbase database in the given directory or its parents
-fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize()?;
-    let mut current = start.as_path();
-
-    loop {
-        // New standard path: .mu/mubase
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Ok(db_path);
-        }
-
-        // Legacy path: .mubase
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Ok(legacy_path);
-        }
-
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "No MUbase found. Run 'mu bootstrap' first to create the database."
-                ))
-            }
-        }
-    }
-}
-
 /// Resolve a node ID to its file path and line range
 fn resolve_node_to_file(node_id: &str) -> Result<(PathBuf, Option<(usize, usize)>)> {
     let db_path = find_mubase(".")?;
@@ -457,7 +428,7 @@ fn get_cochanged_files(file_path: &str, limit: usize) -> Result<Vec<String>> {
 
     // Sort by frequency
     let mut sorted: Vec<(String, usize)> = file_counts.into_iter().collect();
-    sorted.sort_by(|a, b| b.1.cmp(&a.1));
+    sorted.sort_by_key(|f| std::cmp::Reverse(f.1));
 
     Ok(sorted.into_iter().take(limit).map(|(f, _)| f).collect())
 }
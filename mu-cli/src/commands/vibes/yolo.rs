@@ -29,8 +29,8 @@ use anyhow::{Context, Result};
 use colored::Colorize;
 use duckdb::{params, Connection};
 use std::collections::{HashSet, VecDeque};
-use std::path::PathBuf;
 
+use crate::mubase::find_mubase;
 use crate::output::OutputFormat;
 
 /// Impact analysis result for a target
@@ -208,36 +208,6 @@ fn print_yolo_output(result: &YoloResult) {
     println!("{}", result.risk_level.message().dimmed());
 }
 
-/// Find the MUbase database in the given directory or its parents.
-fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    let start = std::path::Path::new(start_path).canonicalize()?;
-    let mut current = start.as_path();
-
-    loop {
-        // New standard path: .mu/mubase
-        let mu_dir = current.join(".mu");
-        let db_path = mu_dir.join("mubase");
-        if db_path.exists() {
-            return Ok(db_path);
-        }
-
-        // Legacy path: .mubase
-        let legacy_path = current.join(".mubase");
-        if legacy_path.exists() {
-            return Ok(legacy_path);
-        }
-
-        match current.parent() {
-            Some(parent) => current = parent,
-            None => {
-                return Err(anyhow::anyhow!(
-                    "No MUbase found. Run 'mu bootstrap' first to create the database."
-                ))
-            }
-        }
-    }
-}
-
 /// Resolve a target (file path or node ID) to a node ID.
 fn resolve_target(conn: &Connection, target: &str) -> Result<String> {
     let mut stmt = conn.prepare("SELECT id FROM nodes WHERE id = ?")?;
@@ -4,6 +4,7 @@
 //! for the codebase analysis tools.
 //!
 //! With `--reset`, removes ALL MU-generated files for a fresh start.
+//! With `--compact`, rewrites the database to reclaim space, keeping its data.
 
 use std::fs;
 use std::io::{self, Write};
@@ -33,6 +34,14 @@ impl ZenStats {
     }
 }
 
+/// Result of compacting the database (--compact flag)
+#[derive(Debug, serde::Serialize)]
+pub struct CompactStats {
+    pub database: String,
+    pub bytes_before: u64,
+    pub bytes_after: u64,
+}
+
 /// Find all cache directories and temp files
 fn find_cache_targets(root: &Path) -> Vec<PathBuf> {
     let mut targets = Vec::new();
@@ -138,8 +147,57 @@ fn delete_path(path: &Path) -> Result<(), std::io::Error> {
     }
 }
 
+/// Rewrite the MUbase to reclaim the space left by deleted rows
+fn run_compact(root: &Path, format: OutputFormat) -> anyhow::Result<()> {
+    let db_path = crate::mubase::find_mubase_in(root).ok_or_else(|| {
+        anyhow::anyhow!("No MUbase found. Run 'mu bootstrap' first to create the database.")
+    })?;
+
+    let mubase = mu_daemon::storage::MUbase::open(&db_path)?;
+    let (bytes_before, bytes_after) = mubase.compact()?;
+    let stats = CompactStats {
+        database: db_path.display().to_string(),
+        bytes_before,
+        bytes_after,
+    };
+
+    match format {
+        OutputFormat::Json => {
+            println!("{}", serde_json::to_string_pretty(&stats)?);
+        }
+        _ => {
+            println!();
+            println!("{}", "Zen - Compact".cyan().bold());
+            println!();
+            println!(
+                "{} {:.1}MB -> {:.1}MB",
+                "[OK]".green(),
+                stats.bytes_before as f64 / 1_000_000.0,
+                stats.bytes_after as f64 / 1_000_000.0
+            );
+            let freed = stats.bytes_before.saturating_sub(stats.bytes_after);
+            if freed > 10_000 {
+                println!(
+                    "{} Freed {:.1}MB",
+                    "[OK]".green(),
+                    freed as f64 / 1_000_000.0
+                );
+            }
+            println!();
+        }
+    }
+
+    Ok(())
+}
+
 /// Run the zen command - cache cleanup with personality
-pub async fn run(path: &str, yes: bool, reset: bool, format: OutputFormat) -> anyhow::Result<()> {
+pub async fn run(
+    path: &str,
+    yes: bool,
+    reset: bool,
+    compact: bool,
+    format: OutputFormat,
+) -> anyhow::Result<()> {
     let root = Path::new(path)
         .canonicalize()
         .unwrap_or_else(|_| Path::new(path).to_path_buf());
@@ -152,6 +210,10 @@ pub async fn run(path: &str, yes: bool, reset: bool, format: OutputFormat) -> an
         anyhow::bail!("Path is not a directory: {}", root.display());
     }
 
+    if compact {
+        return run_compact(&root, format);
+    }
+
     // Find targets based on mode
     let targets = if reset {
         find_reset_targets(&root)
@@ -327,6 +389,10 @@ fn print_zen_output(stats: &ZenStats) {
             "  {} mu zen --reset      # Full reset (removes database)",
             "$".dimmed()
         );
+        println!(
+            "  {} mu zen --compact    # Shrink the database file",
+            "$".dimmed()
+        );
         println!("  {} mu zen --yes        # Skip confirmation", "$".dimmed());
     } else {
         if stats.reset_mode {
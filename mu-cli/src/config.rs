@@ -14,6 +14,7 @@
 //! ignore = ["vendor/", "dist/", "generated/"]
 //! include_hidden = false
 //! max_file_size_kb = 1024
+//! dedupe_identical = false
 //!
 //! [parser]
 //! languages = ["python", "typescript", "rust"]
@@ -25,9 +26,17 @@
 //! [cache]
 //! enabled = true
 //! directory = ".mu/cache"
+//!
+//! [storage]
+//! path = "/scratch/myproject.mubase"
+//!
+//! [sus]
+//! max_methods = 20
+//! max_functions = 40
+//! max_lines = 1000
 //! ```
 
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
 use std::path::{Path, PathBuf};
 use thiserror::Error;
 
@@ -47,6 +56,58 @@ pub enum ConfigError {
     },
 }
 
+/// A problem found by [`MuConfig::validate`].
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ConfigIssue {
+    /// Dotted key the issue refers to (e.g. `scanner.ignore`), empty for
+    /// file-level syntax errors.
+    pub key: String,
+    /// What is wrong and, where possible, how to fix it.
+    pub message: String,
+}
+
+impl ConfigIssue {
+    fn new(key: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Keys accepted in each `.murc.toml` section. Keep in sync with the structs below.
+const KNOWN_KEYS: &[(&str, &[&str])] = &[
+    ("mu", &["version"]),
+    (
+        "scanner",
+        &[
+            "ignore",
+            "include_hidden",
+            "max_file_size_kb",
+            "dedupe_identical",
+            "respect_gitignore",
+            "skip_generated",
+        ],
+    ),
+    ("parser", &["languages"]),
+    ("output", &["format", "color"]),
+    ("cache", &["enabled", "directory"]),
+    ("storage", &["path"]),
+    ("sus", &["max_methods", "max_functions", "max_lines"]),
+];
+
+/// Format a TOML error as `line N: message`.
+fn describe_toml_error(content: &str, error: &toml::de::Error) -> String {
+    match error.span() {
+        Some(span) => {
+            let before = content.get(..span.start).unwrap_or(content);
+            let line = before.matches('\n').count() + 1;
+            format!("line {}: {}", line, error.message())
+        }
+        None => error.message().to_string(),
+    }
+}
+
 /// Suggest a fix for common TOML parsing errors.
 fn suggest_config_fix(error: &toml::de::Error) -> String {
     let msg = error.to_string();
@@ -69,7 +130,7 @@ fn suggest_config_fix(error: &toml::de::Error) -> String {
 ///
 /// All sections are optional and will use defaults if not specified.
 /// The configuration file is loaded from the project root directory.
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MuConfig {
     /// General MU settings (version tracking).
     /// Parsed from config but reserved for future use.
@@ -92,12 +153,20 @@ pub struct MuConfig {
     /// Cache configuration for incremental builds.
     #[serde(default)]
     pub cache: CacheConfig,
+
+    /// Where the MU database lives.
+    #[serde(default)]
+    pub storage: StorageConfig,
+
+    /// Size thresholds for `mu sus`.
+    #[serde(default)]
+    pub sus: SusConfig,
 }
 
 /// General MU configuration section.
 ///
 /// Contains metadata about the configuration itself.
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct MuSection {
     /// Configuration schema version for future compatibility.
     /// Currently informational only; reserved for future use.
@@ -110,7 +179,7 @@ pub struct MuSection {
 ///
 /// These settings affect which files are discovered during `mu bootstrap`
 /// and other scanning operations.
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ScannerConfig {
     /// Additional glob patterns to ignore during scanning.
     ///
@@ -138,12 +207,57 @@ pub struct ScannerConfig {
     /// Default: no limit (all files processed).
     #[serde(default)]
     pub max_file_size_kb: Option<u64>,
+
+    /// Whether to parse identical-content files only once.
+    ///
+    /// Vendored copies of the same file are hashed during scanning; only the
+    /// first copy by path is parsed, and the others become alias module nodes
+    /// with a `duplicate_of` property pointing at the canonical module.
+    ///
+    /// Defaults to `false`.
+    #[serde(default)]
+    pub dedupe_identical: bool,
+
+    /// Whether `.gitignore` files (root and nested) exclude files from scanning.
+    ///
+    /// The `ignore` patterns above apply in addition to these rules. Defaults
+    /// to `true`, including for projects that are not git checkouts.
+    #[serde(default = "default_respect_gitignore")]
+    pub respect_gitignore: bool,
+
+    /// Whether to skip binary files and minified bundles detected by content.
+    ///
+    /// Catches files such as a minified `.ts` bundle whose name does not match
+    /// any ignore pattern. Defaults to `true`.
+    #[serde(default = "default_skip_generated")]
+    pub skip_generated: bool,
+}
+
+fn default_respect_gitignore() -> bool {
+    true
+}
+
+fn default_skip_generated() -> bool {
+    true
+}
+
+impl Default for ScannerConfig {
+    fn default() -> Self {
+        Self {
+            ignore: Vec::new(),
+            include_hidden: false,
+            max_file_size_kb: None,
+            dedupe_identical: false,
+            respect_gitignore: true,
+            skip_generated: true,
+        }
+    }
 }
 
 /// Parser configuration controlling language processing.
 ///
 /// These settings affect which languages are parsed and how.
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct ParserConfig {
     /// Languages to parse.
     ///
@@ -169,11 +283,11 @@ pub struct ParserConfig {
 ///
 /// Note: This is distinct from the runtime `OutputConfig` in the output module,
 /// which handles actual rendering. These settings provide user preferences.
-#[derive(Debug, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct OutputSettings {
     /// Default output format for CLI commands.
     ///
-    /// Valid values: `table`, `json`, `csv`, `mu`, `tree`
+    /// Valid values: `table`, `json`, `csv`, `mu`, `tree`, `sarif`
     /// Default: `table`
     #[serde(default)]
     pub format: Option<String>,
@@ -189,7 +303,7 @@ pub struct OutputSettings {
 ///
 /// MU can cache parse results and embeddings to speed up subsequent runs.
 /// The cache is stored in the `.mu/` directory by default.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CacheConfig {
     /// Whether caching is enabled.
     ///
@@ -207,6 +321,62 @@ pub struct CacheConfig {
     pub directory: Option<String>,
 }
 
+/// Storage configuration for the MU database.
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct StorageConfig {
+    /// Custom database path.
+    ///
+    /// Relative paths are resolved against the project root. The `MU_MUBASE`
+    /// environment variable takes precedence. If neither is set, defaults to
+    /// `.mu/mubase` in the project root.
+    #[serde(default)]
+    pub path: Option<String>,
+}
+
+/// Size thresholds past which `mu sus` flags classes and files.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SusConfig {
+    /// Methods a class may have before it is flagged as a God class.
+    ///
+    /// Default: `20`
+    #[serde(default = "default_max_methods")]
+    pub max_methods: usize,
+
+    /// Functions (methods included) a file may define before it is flagged.
+    ///
+    /// Default: `40`
+    #[serde(default = "default_max_functions")]
+    pub max_functions: usize,
+
+    /// Lines a file may have before it is flagged.
+    ///
+    /// Default: `1000`
+    #[serde(default = "default_max_lines")]
+    pub max_lines: usize,
+}
+
+fn default_max_methods() -> usize {
+    20
+}
+
+fn default_max_functions() -> usize {
+    40
+}
+
+fn default_max_lines() -> usize {
+    1000
+}
+
+impl Default for SusConfig {
+    fn default() -> Self {
+        Self {
+            max_methods: default_max_methods(),
+            max_functions: default_max_functions(),
+            max_lines: default_max_lines(),
+        }
+    }
+}
+
 fn default_cache_enabled() -> bool {
     true
 }
@@ -312,6 +482,87 @@ impl MuConfig {
         })
     }
 
+    /// Validate `.murc.toml` content, collecting every problem found.
+    ///
+    /// Unlike [`load`](Self::load), which silently ignores unknown keys and
+    /// falls back to defaults on errors, this reports:
+    /// - TOML syntax errors
+    /// - Unknown sections and keys (typos would otherwise be ignored)
+    /// - Values of the wrong type
+    /// - `[scanner].ignore` entries that are not valid glob patterns
+    ///
+    /// Returns an empty list when the content is valid.
+    pub fn validate(content: &str) -> Vec<ConfigIssue> {
+        let table: toml::Table = match content.parse() {
+            Ok(table) => table,
+            Err(e) => return vec![ConfigIssue::new("", describe_toml_error(content, &e))],
+        };
+
+        let mut issues = Vec::new();
+
+        for (section, value) in &table {
+            let Some((_, keys)) = KNOWN_KEYS.iter().find(|(name, _)| name == section) else {
+                let sections: Vec<&str> = KNOWN_KEYS.iter().map(|(name, _)| *name).collect();
+                issues.push(ConfigIssue::new(
+                    section.as_str(),
+                    format!(
+                        "unknown section [{}] (expected one of: {})",
+                        section,
+                        sections.join(", ")
+                    ),
+                ));
+                continue;
+            };
+            let Some(entries) = value.as_table() else {
+                continue; // Reported as a type error below
+            };
+            for key in entries.keys() {
+                if !keys.contains(&key.as_str()) {
+                    issues.push(ConfigIssue::new(
+                        format!("{}.{}", section, key),
+                        format!("unknown key (expected one of: {})", keys.join(", ")),
+                    ));
+                }
+            }
+        }
+
+        if let Err(e) = toml::from_str::<MuConfig>(content) {
+            issues.push(ConfigIssue::new(
+                "",
+                format!(
+                    "{}\n  Hint: {}",
+                    describe_toml_error(content, &e),
+                    suggest_config_fix(&e)
+                ),
+            ));
+        }
+
+        let patterns = table
+            .get("scanner")
+            .and_then(|s| s.get("ignore"))
+            .and_then(|i| i.as_array());
+        for pattern in patterns.into_iter().flatten().filter_map(|p| p.as_str()) {
+            if let Err(e) = mu_core::scanner::validate_ignore_pattern(pattern) {
+                issues.push(ConfigIssue::new(
+                    "scanner.ignore",
+                    format!("invalid glob pattern '{}': {}", pattern, e),
+                ));
+            }
+        }
+
+        issues
+    }
+
+    /// The effective configuration: this config with built-in defaults merged in.
+    ///
+    /// Currently this means `[scanner].ignore` includes the default patterns
+    /// returned by [`ignore_patterns`](Self::ignore_patterns).
+    pub fn resolved(&self) -> Self {
+        let mut resolved = self.clone();
+        resolved.scanner.ignore = self.ignore_patterns();
+        resolved
+    }
+
     /// Get ignore patterns for the scanner, with defaults included.
     ///
     /// Combines user-specified patterns from `[scanner].ignore` with
@@ -377,16 +628,69 @@ impl MuConfig {
     pub fn cache_directory(&self) -> Option<&str> {
         self.cache.directory.as_deref()
     }
+
+    /// Get the configured database path, if any.
+    pub fn storage_path(&self) -> Option<&str> {
+        self.storage.path.as_deref()
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_validate_reports_unknown_keys() {
+        let issues = MuConfig::validate(
+            r#"
+[scanner]
+ignor = ["vendor/"]
+
+[colors]
+enabled = true
+"#,
+        );
+        assert_eq!(issues.len(), 2);
+        let typo = issues.iter().find(|i| i.key == "scanner.ignor").unwrap();
+        assert!(typo.message.contains("include_hidden"));
+        assert!(issues.iter().any(|i| i.key == "colors"));
+    }
+
+    #[test]
+    fn test_validate_reports_type_and_glob_errors() {
+        let issues = MuConfig::validate("[scanner]\ninclude_hidden = \"yes\"\n");
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].message.contains("invalid type"));
+
+        let issues = MuConfig::validate("[scanner]\nignore = [\"src/[abc\"]\n");
+        assert_eq!(issues.len(), 1);
+        assert_eq!(issues[0].key, "scanner.ignore");
+
+        let issues = MuConfig::validate("[scanner\n");
+        assert_eq!(issues.len(), 1);
+        assert!(issues[0].key.is_empty());
+
+        assert!(MuConfig::validate("[scanner]\nignore = [\"vendor/\"]\n").is_empty());
+    }
+
+    #[test]
+    fn test_resolved_merges_default_ignores() {
+        let config: MuConfig = toml::from_str("[scanner]\nignore = [\"vendor/\"]\n").unwrap();
+        let resolved = config.resolved();
+        assert_eq!(resolved.scanner.ignore[0], "vendor/");
+        assert!(resolved
+            .scanner
+            .ignore
+            .contains(&"node_modules/".to_string()));
+        assert!(resolved.cache.enabled);
+    }
+
     #[test]
     fn test_default_config() {
         let config = MuConfig::default();
         assert!(!config.scanner.include_hidden);
+        assert!(config.scanner.respect_gitignore);
+        assert!(config.scanner.skip_generated);
         assert!(config.cache.enabled);
         assert!(config.parser.languages.is_none());
         assert!(config.output.format.is_none());
@@ -402,6 +706,8 @@ version = "1.0"
 ignore = ["vendor/", "dist/"]
 include_hidden = true
 max_file_size_kb = 512
+dedupe_identical = true
+respect_gitignore = false
 
 [parser]
 languages = ["python", "typescript"]
@@ -413,6 +719,13 @@ color = false
 [cache]
 enabled = false
 directory = "/tmp/mu-cache"
+
+[storage]
+path = "/scratch/project.mubase"
+
+[sus]
+max_methods = 12
+max_lines = 500
 "#;
         let config: MuConfig = toml::from_str(toml_content).unwrap();
 
@@ -424,6 +737,8 @@ directory = "/tmp/mu-cache"
         assert!(config.scanner.include_hidden);
         assert_eq!(config.scanner.max_file_size_kb, Some(512));
         assert_eq!(config.max_file_size_bytes(), Some(512 * 1024));
+        assert!(config.scanner.dedupe_identical);
+        assert!(!config.scanner.respect_gitignore);
 
         // Parser section
         assert_eq!(
@@ -439,6 +754,15 @@ directory = "/tmp/mu-cache"
         // Cache section
         assert!(!config.cache.enabled);
         assert_eq!(config.cache.directory, Some("/tmp/mu-cache".to_string()));
+
+        // Storage section
+        assert_eq!(config.storage_path(), Some("/scratch/project.mubase"));
+
+        // Sus section; unset thresholds keep their defaults
+        assert_eq!(config.sus.max_methods, 12);
+        assert_eq!(config.sus.max_functions, 40);
+        assert_eq!(config.sus.max_lines, 500);
+        assert!(MuConfig::validate(toml_content).is_empty());
     }
 
     #[test]
@@ -0,0 +1,47 @@
+//! CI gating for analysis commands.
+//!
+//! `mu sus`, `mu vibe` and `mu diff` take `--fail-on <level>`: once the
+//! result is rendered, the process exits with status 1 if any finding is at
+//! or above that level, so the command can fail a CI job or pre-commit hook.
+
+/// Severity of a finding, lowest first
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, serde::Serialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum WarningLevel {
+    Info,
+    Warn,
+    Error,
+}
+
+/// Whether any finding reaches `fail_on`. Without a level nothing fails.
+pub fn fails(
+    levels: impl IntoIterator<Item = WarningLevel>,
+    fail_on: Option<WarningLevel>,
+) -> bool {
+    fail_on.is_some_and(|fail_on| levels.into_iter().any(|level| level >= fail_on))
+}
+
+/// Exit with status 1 if any finding reaches `fail_on`.
+pub fn exit_on_findings(
+    levels: impl IntoIterator<Item = WarningLevel>,
+    fail_on: Option<WarningLevel>,
+) {
+    if fails(levels, fail_on) {
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fails_at_or_above_level() {
+        let levels = [WarningLevel::Info, WarningLevel::Warn];
+        assert!(fails(levels, Some(WarningLevel::Info)));
+        assert!(fails(levels, Some(WarningLevel::Warn)));
+        assert!(!fails(levels, Some(WarningLevel::Error)));
+        assert!(!fails(levels, None));
+        assert!(!fails([], Some(WarningLevel::Info)));
+    }
+}
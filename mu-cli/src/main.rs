@@ -8,13 +8,21 @@ use tracing_subscriber::{fmt, prelude::*, EnvFilter};
 
 /// Parse and validate threshold value (must be between 0.0 and 1.0)
 fn parse_threshold(s: &str) -> Result<f32, String> {
+    parse_unit_interval("threshold", s)
+}
+
+fn parse_alpha(s: &str) -> Result<f32, String> {
+    parse_unit_interval("alpha", s)
+}
+
+fn parse_unit_interval(what: &str, s: &str) -> Result<f32, String> {
     let value: f32 = s
         .parse()
         .map_err(|_| format!("'{}' is not a valid number", s))?;
     if !(0.0..=1.0).contains(&value) {
         return Err(format!(
-            "threshold must be between 0.0 and 1.0, got {}",
-            value
+            "{} must be between 0.0 and 1.0, got {}",
+            what, value
         ));
     }
     Ok(value)
@@ -23,7 +31,10 @@ fn parse_threshold(s: &str) -> Result<f32, String> {
 mod cache;
 mod commands;
 mod config;
+mod gate;
+mod mubase;
 mod output;
+mod query_cache;
 mod tsconfig;
 
 use commands::*;
@@ -100,6 +111,18 @@ enum Commands {
         /// Fail on .murc.toml errors instead of silently using defaults
         #[arg(long)]
         strict: bool,
+
+        /// Remove files deleted from disk without a full rebuild
+        #[arg(long, conflicts_with = "force")]
+        sync: bool,
+
+        /// Only reparse files git reports changed since this ref
+        #[arg(long, value_name = "GITREF", conflicts_with_all = ["force", "sync"])]
+        since: Option<String>,
+
+        /// With --since, also re-embed the nodes of the changed files
+        #[arg(long, requires = "since", conflicts_with = "embed")]
+        live_embed: bool,
     },
 
     /// Compress codebase into hierarchical MU sigil format
@@ -126,6 +149,13 @@ enum Commands {
         path: String,
     },
 
+    /// Check whether the MUbase is out of date with the working tree
+    Stale {
+        /// Path to check (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: String,
+    },
+
     /// Generate or update embeddings (incremental by default)
     Embed {
         /// Path to analyze (defaults to current directory)
@@ -144,15 +174,59 @@ enum Commands {
     /// Semantic search across the codebase
     Search {
         /// Search query
-        query: String,
+        #[arg(required_unless_present = "calibrate")]
+        query: Option<String>,
 
         /// Maximum results to return
         #[arg(short = 'n', long = "limit", default_value = "10")]
         limit: usize,
 
-        /// Minimum similarity threshold (0.0-1.0)
-        #[arg(short, long, default_value = "0.1", value_parser = parse_threshold)]
-        threshold: f32,
+        /// Minimum similarity threshold (0.0-1.0) [default: 0.1]
+        #[arg(short, long, value_parser = parse_threshold)]
+        threshold: Option<f32>,
+
+        /// Collapse results by file or module, keeping the best hit per group
+        #[arg(long, value_enum, default_value = "none")]
+        group_by: search::GroupBy,
+
+        /// Relevance level; overrides --threshold with a corpus-calibrated cutoff
+        #[arg(long, value_enum)]
+        relevance: Option<search::Relevance>,
+
+        /// Embedding index to search: node identifiers or docstrings
+        #[arg(long, value_enum, default_value = "code")]
+        over: search::SearchOver,
+
+        /// Ranking to search with; --threshold and --relevance need semantic
+        #[arg(long, value_enum, default_value = "semantic")]
+        mode: search::SearchMode,
+
+        /// Blend hybrid scores linearly (1.0 = all vector, 0.0 = all keyword)
+        /// instead of reciprocal-rank fusion
+        #[arg(long, value_parser = parse_alpha)]
+        alpha: Option<f32>,
+
+        /// Sample the embedding corpus and store relevance cutoffs
+        #[arg(long)]
+        calibrate: bool,
+    },
+
+    /// Regex search over node names, with each match's deps and dependents
+    Grep {
+        /// Regular expression to match against node names
+        pattern: String,
+
+        /// Also match the source lines of functions and classes
+        #[arg(long)]
+        source: bool,
+
+        /// Case-insensitive matching
+        #[arg(short, long)]
+        ignore_case: bool,
+
+        /// Maximum matches to show
+        #[arg(short = 'n', long = "limit", default_value = "20")]
+        limit: usize,
     },
 
     /// Find relevant code context for a question (semantic search)
@@ -165,6 +239,20 @@ enum Commands {
         depth: u8,
     },
 
+    /// Extract token-budgeted context for a question (MU or OMEGA)
+    Context {
+        /// Question or topic to extract context for
+        question: String,
+
+        /// Maximum tokens for the extracted text
+        #[arg(short = 't', long, default_value = "8000")]
+        max_tokens: usize,
+
+        /// Encode as OMEGA S-expressions instead of MU
+        #[arg(long)]
+        omega: bool,
+    },
+
     /// Execute a MUQL query
     #[command(visible_alias = "q")]
     Query {
@@ -179,6 +267,10 @@ enum Commands {
         #[arg(short, long)]
         limit: Option<usize>,
 
+        /// Skip this many results first, for paging with --limit
+        #[arg(long)]
+        offset: Option<usize>,
+
         /// Show MUQL query examples
         #[arg(long)]
         examples: bool,
@@ -186,6 +278,10 @@ enum Commands {
         /// Show MUQL schema reference (tables, columns, edge types)
         #[arg(long)]
         schema: bool,
+
+        /// Run a script of `;`-separated MUQL statements (`--` starts a comment line)
+        #[arg(long, value_name = "FILE", conflicts_with = "query")]
+        file: Option<String>,
     },
 
     /// Show dependencies of a node (what this node depends on)
@@ -204,6 +300,14 @@ enum Commands {
         /// Include 'contains' edges (classes/functions within modules)
         #[arg(long)]
         include_contains: bool,
+
+        /// Explain which edge direction this query follows
+        #[arg(long)]
+        explain_direction: bool,
+
+        /// Node type to pick when the name matches several nodes
+        #[arg(long, value_parser = graph::PREFER_TYPES)]
+        prefer: Option<String>,
     },
 
     /// Show what depends on a node (reverse dependencies)
@@ -219,6 +323,14 @@ enum Commands {
         /// Include 'contains' edges (classes/functions within modules)
         #[arg(long)]
         include_contains: bool,
+
+        /// Explain which edge direction this query follows
+        #[arg(long)]
+        explain_direction: bool,
+
+        /// Node type to pick when the name matches several nodes
+        #[arg(long, value_parser = graph::PREFER_TYPES)]
+        prefer: Option<String>,
     },
 
     /// Read and display a file with MU context
@@ -231,6 +343,12 @@ enum Commands {
         line_numbers: bool,
     },
 
+    /// Show per-function complexity by line range for a file
+    Heatmap {
+        /// File path to analyze
+        file: String,
+    },
+
     /// Semantic diff between git refs
     Diff {
         /// Base git ref (branch, commit, tag)
@@ -239,6 +357,11 @@ enum Commands {
         /// Head git ref (branch, commit, tag) - defaults to HEAD
         #[arg(default_value = "HEAD")]
         head_ref: String,
+
+        /// Exit 1 if a change reaches this level (error = breaking,
+        /// warn = removal, info = any change)
+        #[arg(long, value_enum)]
+        fail_on: Option<gate::WarningLevel>,
     },
 
     /// Find downstream impact (what might break if this node changes)
@@ -253,6 +376,10 @@ enum Commands {
         /// Filter by edge types (e.g., imports,calls)
         #[arg(short, long, value_delimiter = ',')]
         edge_types: Option<Vec<String>>,
+
+        /// Node type to pick when the name matches several nodes
+        #[arg(long, value_parser = graph::PREFER_TYPES)]
+        prefer: Option<String>,
     },
 
     /// Find upstream ancestors (what this node depends on)
@@ -267,6 +394,10 @@ enum Commands {
         /// Filter by edge types (e.g., imports,calls)
         #[arg(short, long, value_delimiter = ',')]
         edge_types: Option<Vec<String>>,
+
+        /// Node type to pick when the name matches several nodes
+        #[arg(long, value_parser = graph::PREFER_TYPES)]
+        prefer: Option<String>,
     },
 
     /// Detect circular dependencies in the codebase
@@ -287,6 +418,28 @@ enum Commands {
         /// Filter by edge types (e.g., imports,calls)
         #[arg(short, long, value_delimiter = ',')]
         edge_types: Option<Vec<String>>,
+
+        /// Node type to pick when the name matches several nodes
+        #[arg(long, value_parser = graph::PREFER_TYPES)]
+        prefer: Option<String>,
+    },
+
+    /// Export the call tree under a function for flamegraph tools
+    Flame {
+        /// Root function
+        node: String,
+
+        /// Maximum call depth to descend
+        #[arg(short, long, default_value = "10")]
+        depth: usize,
+
+        /// Print folded stacks (flamegraph.pl) instead of a tree
+        #[arg(long)]
+        folded: bool,
+
+        /// Node type to pick when the name matches several nodes
+        #[arg(long, value_parser = graph::PREFER_TYPES)]
+        prefer: Option<String>,
     },
 
     // ==================== Vibes ====================
@@ -306,6 +459,10 @@ enum Commands {
         /// Minimum warning level to show (1=info, 2=warn, 3=error)
         #[arg(short, long, default_value = "1")]
         threshold: u8,
+
+        /// Exit 1 if a shown warning reaches this level
+        #[arg(long, value_enum)]
+        fail_on: Option<gate::WarningLevel>,
     },
 
     /// Git archaeology - why does this code exist?
@@ -335,6 +492,11 @@ enum Commands {
         /// Valid options: snake, pascal, camel, screaming
         #[arg(long)]
         convention: Option<String>,
+
+        /// Exit 1 if a finding reaches this level (error = naming
+        /// violation, warn = any other hint)
+        #[arg(long, value_enum, default_value = "error")]
+        fail_on: gate::WarningLevel,
     },
 
     /// Achieve enlightenment - clear caches and temp files
@@ -350,6 +512,10 @@ enum Commands {
         /// Full reset: remove ALL MU files (.mu/, .murc.toml, .mubase)
         #[arg(short, long)]
         reset: bool,
+
+        /// Rewrite the database to reclaim unused space (keeps all data)
+        #[arg(long, conflicts_with = "reset")]
+        compact: bool,
     },
 
     // ==================== Analysis & Export ====================
@@ -368,10 +534,28 @@ enum Commands {
         examples: bool,
     },
 
+    /// Summarize languages, entry points, key modules, patterns and hotspots
+    Overview {
+        /// Path to the project (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: String,
+
+        /// Render as Markdown (for a README)
+        #[arg(long)]
+        markdown: bool,
+    },
+
+    /// Show codebase metrics: lines, entities, complexity, languages
+    Stats {
+        /// Path to the project (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: String,
+    },
+
     /// Export the code graph to various formats
     Export {
-        /// Export format (mu, json, mermaid, d2, cytoscape)
-        #[arg(short = 'F', long = "export-format", default_value = "mu", value_parser = ["mu", "json", "mermaid", "d2", "cytoscape"])]
+        /// Export format (mu, json, mermaid, d2, cytoscape, dot, graphml, plantuml, jsonl)
+        #[arg(short = 'F', long = "export-format", default_value = "mu", value_parser = ["mu", "json", "mermaid", "d2", "cytoscape", "dot", "graphml", "plantuml", "jsonl"])]
         export_format: String,
 
         /// Output file path (default: stdout)
@@ -382,9 +566,17 @@ enum Commands {
         #[arg(short, long)]
         node: Option<String>,
 
+        /// Also export everything within this many hops of --node
+        #[arg(short, long, requires = "node")]
+        depth: Option<u8>,
+
         /// Maximum number of nodes to export
         #[arg(short = 'l', long = "limit")]
         limit: Option<usize>,
+
+        /// Only export these edge types (e.g., calls)
+        #[arg(short, long, value_delimiter = ',')]
+        edge_types: Option<Vec<String>>,
     },
 
     /// Show change history for a node
@@ -411,6 +603,44 @@ enum Commands {
         /// Path to check (defaults to current directory)
         #[arg(default_value = ".")]
         path: String,
+
+        /// Show what each language parser extracts instead of running checks
+        #[arg(long)]
+        capabilities: bool,
+
+        /// Also parse every source file and report where its syntax errors are
+        #[arg(long)]
+        syntax: bool,
+    },
+
+    /// Back up the MUbase (graph, embeddings and indexes) to a directory
+    Backup {
+        /// Directory to write the backup to (must not exist or be empty)
+        dest: String,
+
+        /// Project path (defaults to current directory)
+        #[arg(short, long, default_value = ".")]
+        path: String,
+    },
+
+    /// Replace the MUbase with a backup made by 'mu backup'
+    Restore {
+        /// Backup directory to restore from
+        src: String,
+
+        /// Project path (defaults to current directory)
+        #[arg(short, long, default_value = ".")]
+        path: String,
+
+        /// Skip confirmation prompt
+        #[arg(short, long)]
+        yes: bool,
+    },
+
+    /// Validate .murc.toml or show the effective configuration
+    Config {
+        #[command(subcommand)]
+        action: ConfigAction,
     },
 
     /// Generate shell completion scripts
@@ -425,6 +655,23 @@ enum Commands {
     },
 }
 
+#[derive(Subcommand)]
+enum ConfigAction {
+    /// Report unknown keys, type errors, and invalid glob patterns
+    Validate {
+        /// Project root containing .murc.toml (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: String,
+    },
+
+    /// Print the resolved configuration (file merged with defaults)
+    Show {
+        /// Project root containing .murc.toml (defaults to current directory)
+        #[arg(default_value = ".")]
+        path: String,
+    },
+}
+
 fn setup_logging(verbose: bool, quiet: bool) {
     let filter = if quiet {
         "error"
@@ -502,13 +749,30 @@ async fn main() -> anyhow::Result<()> {
             embed,
             no_embed,
             strict,
-        } => bootstrap::run(&path, force, embed, no_embed, strict, format).await,
+            sync,
+            since,
+            live_embed,
+        } => {
+            bootstrap::run(
+                &path,
+                force,
+                embed,
+                no_embed,
+                strict,
+                sync,
+                since.as_deref(),
+                live_embed,
+                format,
+            )
+            .await
+        }
         Commands::Compress {
             path,
             output,
             detail,
         } => compress::run(&path, output.as_deref(), &detail, format).await,
         Commands::Status { path } => status::run(&path, format).await,
+        Commands::Stale { path } => stale::run(&path, format).await,
         Commands::Embed {
             path,
             force,
@@ -524,69 +788,171 @@ async fn main() -> anyhow::Result<()> {
             query,
             limit,
             threshold,
-        } => search::run(&query, limit, threshold, format).await,
+            group_by,
+            relevance,
+            over,
+            mode,
+            alpha,
+            calibrate,
+        } => {
+            if calibrate {
+                search::run_calibrate(format).await
+            } else {
+                // Fused and BM25 scores are not similarities, so a cutoff
+                // would silently do nothing
+                if mode != search::SearchMode::Semantic
+                    && (threshold.is_some() || relevance.is_some())
+                {
+                    let mut cli = Cli::command();
+                    cli.build();
+                    cli.find_subcommand_mut("search")
+                        .expect("search subcommand")
+                        .error(
+                            clap::error::ErrorKind::ArgumentConflict,
+                            "--threshold and --relevance only apply to --mode semantic",
+                        )
+                        .exit();
+                }
+                let query = query.unwrap_or_default();
+                let threshold = threshold.unwrap_or(search::DEFAULT_THRESHOLD);
+                search::run(
+                    &query, limit, threshold, relevance, group_by, over, mode, alpha, format,
+                )
+                .await
+            }
+        }
+        Commands::Grep {
+            pattern,
+            source,
+            ignore_case,
+            limit,
+        } => grep::run(&pattern, source, ignore_case, limit, format).await,
         Commands::Grok { question, depth } => grok::run(&question, depth, format).await,
+        Commands::Context {
+            question,
+            max_tokens,
+            omega,
+        } => context::run(&question, max_tokens, omega, format).await,
         Commands::Query {
             query,
             interactive,
             limit,
+            offset,
             examples,
             schema,
-        } => {
-            query::run_extended(
-                query.as_deref(),
-                interactive,
-                format,
-                limit,
-                examples,
-                schema,
-            )
-            .await
-        }
+            file,
+        } => match file {
+            Some(file) if !examples && !schema => {
+                query::run_script(&file, format, limit, offset).await
+            }
+            _ => {
+                query::run_extended(
+                    query.as_deref(),
+                    interactive,
+                    format,
+                    limit,
+                    offset,
+                    examples,
+                    schema,
+                )
+                .await
+            }
+        },
         Commands::Deps {
             node,
             reverse,
             depth,
             include_contains,
-        } => deps::run(&node, reverse, depth, include_contains, format).await,
+            explain_direction,
+            prefer,
+        } => {
+            deps::run(
+                &node,
+                prefer.as_deref(),
+                reverse,
+                depth,
+                include_contains,
+                explain_direction,
+                format,
+            )
+            .await
+        }
         Commands::Usedby {
             node,
             depth,
             include_contains,
-        } => deps::run(&node, true, depth, include_contains, format).await,
+            explain_direction,
+            prefer,
+        } => {
+            deps::run(
+                &node,
+                prefer.as_deref(),
+                true,
+                depth,
+                include_contains,
+                explain_direction,
+                format,
+            )
+            .await
+        }
         Commands::Read { path, line_numbers } => read::run(&path, line_numbers, format).await,
-        Commands::Diff { base_ref, head_ref } => diff::run(&base_ref, &head_ref, format).await,
+        Commands::Heatmap { file } => heatmap::run(&file, format).await,
+        Commands::Diff {
+            base_ref,
+            head_ref,
+            fail_on,
+        } => diff::run(&base_ref, &head_ref, fail_on, format).await,
 
         // Graph analysis commands
         Commands::Impact {
             node,
             depth,
             edge_types,
-        } => graph::run_impact(&node, edge_types, depth, format).await,
+            prefer,
+        } => graph::run_impact(&node, prefer.as_deref(), edge_types, depth, format).await,
         Commands::Ancestors {
             node,
             depth,
             edge_types,
-        } => graph::run_ancestors(&node, edge_types, depth, format).await,
+            prefer,
+        } => graph::run_ancestors(&node, prefer.as_deref(), edge_types, depth, format).await,
         Commands::Cycles { edge_types } => graph::run_cycles(edge_types, format).await,
         Commands::Path {
             from,
             to,
             edge_types,
-        } => graph::run_path(&from, &to, edge_types, format).await,
+            prefer,
+        } => graph::run_path(&from, &to, prefer.as_deref(), edge_types, format).await,
+        Commands::Flame {
+            node,
+            depth,
+            folded,
+            prefer,
+        } => flame::run(&node, prefer.as_deref(), depth, folded, format).await,
 
         // Vibe commands
         Commands::Yolo { path } => vibes::yolo::run(&path, format).await,
-        Commands::Sus { path, threshold } => vibes::sus::run(&path, threshold, format).await,
+        Commands::Sus {
+            path,
+            threshold,
+            fail_on,
+        } => vibes::sus::run(&path, threshold, fail_on, format).await,
         Commands::Wtf { target } => vibes::wtf::run(target.as_deref(), format).await,
         Commands::Omg {
             max_tokens,
             no_edges,
         } => vibes::omg::run(max_tokens, !no_edges, format).await,
-        Commands::Vibe { path, convention } => {
-            vibes::vibe::run(&path, format, convention.as_deref()).await
-        }
-        Commands::Zen { path, yes, reset } => vibes::zen::run(&path, yes, reset, format).await,
+        Commands::Vibe {
+            path,
+            convention,
+            fail_on,
+        } => vibes::vibe::run(&path, format, convention.as_deref(), fail_on).await,
+        Commands::Zen {
+            path,
+            yes,
+            reset,
+            compact,
+        } => vibes::zen::run(&path, yes, reset, compact, format).await,
 
         // Analysis commands
         Commands::Patterns {
@@ -594,18 +960,24 @@ async fn main() -> anyhow::Result<()> {
             refresh,
             examples,
         } => patterns::run(category.as_deref(), refresh, examples, format).await,
+        Commands::Overview { path, markdown } => overview::run(&path, markdown, format).await,
+        Commands::Stats { path } => stats::run(&path, format).await,
 
         Commands::Export {
             export_format,
             output,
             node,
+            depth,
             limit,
+            edge_types,
         } => {
             export::run(
                 &export_format,
                 output.as_deref(),
                 node.as_deref(),
+                depth,
                 limit,
+                edge_types.as_deref(),
                 format,
             )
             .await
@@ -617,7 +989,17 @@ async fn main() -> anyhow::Result<()> {
         Commands::Mcp { path } => mcp::run(&path).await,
 
         // Utility commands
-        Commands::Doctor { path } => doctor::run(&path, format).await,
+        Commands::Doctor {
+            path,
+            capabilities,
+            syntax,
+        } => doctor::run(&path, capabilities, syntax, format).await,
+        Commands::Backup { dest, path } => backup::run_backup(&path, &dest, format).await,
+        Commands::Restore { src, path, yes } => backup::run_restore(&path, &src, yes, format).await,
+        Commands::Config { action } => match action {
+            ConfigAction::Validate { path } => commands::config::run_validate(&path, format).await,
+            ConfigAction::Show { path } => commands::config::run_show(&path, format).await,
+        },
         Commands::Completions {
             shell,
             instructions,
@@ -3,10 +3,16 @@
 //! This module provides functions to locate the MU database file (`.mu/mubase`)
 //! by searching from a starting directory upward through parent directories.
 //! Also supports legacy `.mubase` path for backwards compatibility.
+//!
+//! The location can be overridden with the `MU_MUBASE` environment variable
+//! or `[storage].path` in `.murc.toml`, in that order of precedence.
 
 use anyhow::Result;
+use std::ffi::OsString;
 use std::path::{Path, PathBuf};
 
+use crate::config::MuConfig;
+
 /// MU directory name.
 pub const MU_DIR: &str = ".mu";
 
@@ -16,6 +22,24 @@ pub const MUBASE_FILE: &str = "mubase";
 /// Legacy database filename (deprecated, but still supported).
 pub const LEGACY_MUBASE: &str = ".mubase";
 
+/// Environment variable overriding the database location.
+pub const MUBASE_ENV: &str = "MU_MUBASE";
+
+/// Configured database path for a project root, if any.
+///
+/// `MU_MUBASE` wins over `[storage].path`; relative config paths are
+/// resolved against `root`. The path is returned whether or not it exists.
+pub fn configured_mubase(root: &Path) -> Option<PathBuf> {
+    resolve_configured(root, std::env::var_os(MUBASE_ENV))
+}
+
+fn resolve_configured(root: &Path, env: Option<OsString>) -> Option<PathBuf> {
+    if let Some(path) = env.filter(|p| !p.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    MuConfig::load(root).storage_path().map(|p| root.join(p))
+}
+
 /// Find the MU database by walking up from `start_path`.
 ///
 /// Returns the path to the database file (`.mu/mubase` or legacy `.mubase`).
@@ -27,8 +51,9 @@ pub const LEGACY_MUBASE: &str = ".mubase";
 /// let store = NodeStore::open(db_path)?;
 /// ```
 pub fn find_mubase(start_path: &str) -> Result<PathBuf> {
-    find_mubase_optional(start_path)
-        .ok_or_else(|| anyhow::anyhow!("No MU database found. Run 'mu bootstrap' first."))
+    find_mubase_optional(start_path).ok_or_else(|| {
+        anyhow::anyhow!("No MUbase found. Run 'mu bootstrap' first to create the database.")
+    })
 }
 
 /// Find the MU database by walking up from `start_path`.
@@ -43,11 +68,15 @@ pub fn find_mubase_optional(start_path: &str) -> Option<PathBuf> {
 ///
 /// Takes a `&Path` instead of `&str` for convenience.
 pub fn find_mubase_from(start: &Path) -> Option<PathBuf> {
+    find_mubase_from_with(start, std::env::var_os(MUBASE_ENV))
+}
+
+fn find_mubase_from_with(start: &Path, env: Option<OsString>) -> Option<PathBuf> {
     let mut current = start;
 
     loop {
         // Check in current directory
-        if let Some(path) = find_mubase_in(current) {
+        if let Some(path) = find_mubase_in_with(current, env.clone()) {
             return Some(path);
         }
 
@@ -62,8 +91,20 @@ pub fn find_mubase_from(start: &Path) -> Option<PathBuf> {
 /// Check for MU database in a specific directory (no traversal).
 ///
 /// Returns `Some(path)` if found, `None` otherwise.
-/// Checks both new path (`.mu/mubase`) and legacy path (`.mubase`).
+/// Checks the configured path first, then the new path (`.mu/mubase`) and
+/// legacy path (`.mubase`).
 pub fn find_mubase_in(root: &Path) -> Option<PathBuf> {
+    find_mubase_in_with(root, std::env::var_os(MUBASE_ENV))
+}
+
+fn find_mubase_in_with(root: &Path, env: Option<OsString>) -> Option<PathBuf> {
+    // Configured location: MU_MUBASE or [storage].path
+    if let Some(path) = resolve_configured(root, env) {
+        if path.exists() {
+            return Some(path);
+        }
+    }
+
     // New standard path: .mu/mubase
     let new_path = root.join(MU_DIR).join(MUBASE_FILE);
     if new_path.exists() {
@@ -88,9 +129,23 @@ pub fn mu_dir(root: &Path) -> PathBuf {
 
 /// Get the MU database path for a project root.
 ///
-/// Returns `.mu/mubase` path without checking if it exists.
+/// Returns the configured path, or `.mu/mubase`, without checking if it exists.
 pub fn mubase_path(root: &Path) -> PathBuf {
-    mu_dir(root).join(MUBASE_FILE)
+    configured_mubase(root).unwrap_or_else(|| mu_dir(root).join(MUBASE_FILE))
+}
+
+/// Project root for an open database.
+///
+/// Prefers the root recorded at bootstrap (needed when the database lives
+/// outside the project tree), falling back to the database location.
+pub fn project_root(conn: &duckdb::Connection, db_path: &Path) -> PathBuf {
+    conn.query_row(
+        "SELECT value FROM metadata WHERE key = ?",
+        [mu_daemon::storage::MUbase::PROJECT_ROOT_KEY],
+        |row| row.get::<_, String>(0),
+    )
+    .map(PathBuf::from)
+    .unwrap_or_else(|_| mu_daemon::storage::MUbase::default_project_root(db_path))
 }
 
 #[cfg(test)]
@@ -161,4 +216,48 @@ mod tests {
         let result = find_mubase_from(&nested);
         assert!(result.is_some());
     }
+
+    #[test]
+    fn test_find_mubase_honors_env_override() {
+        let project = TempDir::new().unwrap();
+        let scratch = TempDir::new().unwrap();
+        let external = scratch.path().join("project.mubase");
+        fs::write(&external, "").unwrap();
+
+        // A local database exists too, but the override wins
+        fs::create_dir_all(project.path().join(".mu")).unwrap();
+        fs::write(project.path().join(".mu").join("mubase"), "").unwrap();
+
+        let env = Some(external.clone().into_os_string());
+        let nested = project.path().join("src");
+        fs::create_dir_all(&nested).unwrap();
+        assert_eq!(
+            find_mubase_from_with(&nested, env.clone()),
+            Some(external.clone())
+        );
+        assert_eq!(resolve_configured(project.path(), env), Some(external));
+    }
+
+    #[test]
+    fn test_find_mubase_honors_storage_config() {
+        let project = TempDir::new().unwrap();
+        fs::write(
+            project.path().join(".murc.toml"),
+            "[storage]\npath = \"index/db.mubase\"\n",
+        )
+        .unwrap();
+
+        let configured = project.path().join("index").join("db.mubase");
+        assert_eq!(
+            resolve_configured(project.path(), None),
+            Some(configured.clone())
+        );
+
+        // Not created yet: nothing to find
+        assert!(find_mubase_in_with(project.path(), None).is_none());
+
+        fs::create_dir_all(configured.parent().unwrap()).unwrap();
+        fs::write(&configured, "").unwrap();
+        assert_eq!(find_mubase_in_with(project.path(), None), Some(configured));
+    }
 }
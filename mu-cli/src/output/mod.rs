@@ -2,7 +2,7 @@
 //!
 //! Provides unified output formatting across all commands with support for
 //! multiple formats: table (human-readable), json (machine-readable), csv,
-//! mu (sigil format), and tree (hierarchical display).
+//! mu (sigil format), tree (hierarchical display) and sarif (code scanning).
 //!
 //! Automatically detects TTY context to adjust colors and truncation behavior.
 
@@ -18,6 +18,7 @@ use std::str::FromStr;
 mod csv;
 mod json;
 mod mu;
+mod sarif;
 mod table;
 mod tree;
 
@@ -27,6 +28,7 @@ pub use self::csv::CsvOutput;
 pub use self::json::JsonOutput;
 #[allow(unused_imports)]
 pub use self::mu::{MuOutput, Sigil};
+pub use self::sarif::{SarifOutput, SarifResult, SarifRule};
 #[allow(unused_imports)]
 pub use self::table::{AsTable, TableOutput};
 #[allow(unused_imports)]
@@ -46,6 +48,8 @@ pub enum OutputFormat {
     Mu,
     /// Tree format for hierarchical data
     Tree,
+    /// SARIF log for code-scanning tools (JSON for commands without findings)
+    Sarif,
 }
 
 impl FromStr for OutputFormat {
@@ -58,6 +62,7 @@ impl FromStr for OutputFormat {
             "csv" => Ok(OutputFormat::Csv),
             "mu" => Ok(OutputFormat::Mu),
             "tree" => Ok(OutputFormat::Tree),
+            "sarif" => Ok(OutputFormat::Sarif),
             _ => Err(format!("Unknown output format: '{}'", s)),
         }
     }
@@ -248,6 +253,12 @@ pub trait Outputter: Serialize + Sized {
         self.to_table(config)
     }
 
+    /// Render as SARIF
+    fn to_sarif(&self, config: &OutputConfig) -> String {
+        // Default implementation falls back to JSON
+        self.to_json(config)
+    }
+
     /// Render using the format specified in config
     fn render(&self, config: &OutputConfig) -> String {
         match config.format {
@@ -256,6 +267,7 @@ pub trait Outputter: Serialize + Sized {
             OutputFormat::Csv => self.to_csv(config),
             OutputFormat::Mu => self.to_mu(config),
             OutputFormat::Tree => self.to_tree(config),
+            OutputFormat::Sarif => self.to_sarif(config),
         }
     }
 
@@ -0,0 +1,134 @@
+//! SARIF output for code-scanning tools and review bots.
+//!
+//! Produces a single-run SARIF 2.1.0 log with `mu` as the tool. Commands
+//! with findings to annotate map them to [`SarifResult`]s; everything else
+//! renders `--format sarif` as JSON.
+
+use super::{JsonOutput, OutputConfig};
+use serde_json::{json, Value};
+
+/// SARIF spec version written.
+const SARIF_VERSION: &str = "2.1.0";
+
+/// Schema URI for SARIF 2.1.0 logs.
+const SARIF_SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+/// A rule findings can refer to.
+#[derive(Debug, Clone)]
+pub struct SarifRule {
+    pub id: &'static str,
+    pub description: &'static str,
+}
+
+/// One finding at a location.
+#[derive(Debug, Clone)]
+pub struct SarifResult {
+    pub rule_id: &'static str,
+    /// `error`, `warning` or `note`
+    pub level: &'static str,
+    pub message: String,
+    /// Path relative to the repository root
+    pub file: String,
+    /// Line span, omitted when the finding covers the whole file
+    pub lines: Option<(u32, u32)>,
+}
+
+/// SARIF output formatter
+pub struct SarifOutput;
+
+impl SarifOutput {
+    /// Format results as a SARIF log.
+    pub fn format(rules: &[SarifRule], results: &[SarifResult], config: &OutputConfig) -> String {
+        let rules: Vec<Value> = rules
+            .iter()
+            .map(|rule| {
+                json!({
+                    "id": rule.id,
+                    "shortDescription": { "text": rule.description },
+                })
+            })
+            .collect();
+
+        let results: Vec<Value> = results
+            .iter()
+            .map(|result| {
+                let mut location = json!({
+                    "artifactLocation": { "uri": result.file },
+                });
+                if let Some((start, end)) = result.lines {
+                    location["region"] = json!({
+                        "startLine": start.max(1),
+                        "endLine": end.max(start).max(1),
+                    });
+                }
+                json!({
+                    "ruleId": result.rule_id,
+                    "level": result.level,
+                    "message": { "text": result.message },
+                    "locations": [{ "physicalLocation": location }],
+                })
+            })
+            .collect();
+
+        let log = json!({
+            "$schema": SARIF_SCHEMA,
+            "version": SARIF_VERSION,
+            "runs": [{
+                "tool": {
+                    "driver": {
+                        "name": "mu",
+                        "version": env!("CARGO_PKG_VERSION"),
+                        "rules": rules,
+                    },
+                },
+                "results": results,
+            }],
+        });
+        JsonOutput::format(&log, config)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::output::OutputFormat;
+
+    #[test]
+    fn test_sarif_log_shape() {
+        let config = OutputConfig::new(OutputFormat::Sarif);
+        let rules = [SarifRule {
+            id: "breaking-change",
+            description: "Removed entity",
+        }];
+        let results = [
+            SarifResult {
+                rule_id: "breaking-change",
+                level: "error",
+                message: "Removed function parse".to_string(),
+                file: "src/app.py".to_string(),
+                lines: Some((3, 7)),
+            },
+            SarifResult {
+                rule_id: "breaking-change",
+                level: "error",
+                message: "File deleted".to_string(),
+                file: "src/old.py".to_string(),
+                lines: None,
+            },
+        ];
+
+        let log: Value = serde_json::from_str(&SarifOutput::format(&rules, &results, &config))
+            .expect("valid JSON");
+        assert_eq!(log["version"], "2.1.0");
+        let run = &log["runs"][0];
+        assert_eq!(run["tool"]["driver"]["rules"][0]["id"], "breaking-change");
+
+        let location = &run["results"][0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/app.py");
+        assert_eq!(location["region"]["startLine"], 3);
+        assert_eq!(location["region"]["endLine"], 7);
+        assert!(run["results"][1]["locations"][0]["physicalLocation"]
+            .get("region")
+            .is_none());
+    }
+}
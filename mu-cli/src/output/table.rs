@@ -79,11 +79,10 @@ impl TableOutput {
         // Apply column widths and alignments
         let term_width = config.effective_width();
         let col_count = columns.len();
-        let available_width = if col_count > 0 {
-            (term_width.saturating_sub(col_count * 3)) / col_count
-        } else {
-            term_width
-        };
+        let available_width = term_width
+            .saturating_sub(col_count * 3)
+            .checked_div(col_count)
+            .unwrap_or(term_width);
 
         for (i, col) in columns.iter().enumerate() {
             let max_width = col.max_width.unwrap_or(available_width);
@@ -0,0 +1,221 @@
+//! Query embedding cache for repeated searches.
+//!
+//! Loading the embedded model takes seconds, far longer than the search
+//! itself. The vectors of recent queries are kept in
+//! `query_embeddings.json` under the `[cache].directory` (default
+//! `.mu/cache`), so repeating a search (or refining its flags) skips both
+//! the model load and inference.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+
+use crate::config::MuConfig;
+
+/// Current cache format version. Increment when cache format changes.
+const CACHE_VERSION: &str = "1";
+
+/// Cache file name within the cache directory.
+const CACHE_FILE: &str = "query_embeddings.json";
+
+/// Default cache file path relative to project root.
+const DEFAULT_CACHE_PATH: &str = ".mu/cache/query_embeddings.json";
+
+/// Most recent queries kept.
+const MAX_ENTRIES: usize = 64;
+
+/// A cached query vector.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryEntry {
+    pub query: String,
+    pub embedding: Vec<f32>,
+}
+
+/// Recent query embeddings, most recent first.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryCache {
+    /// Cache format version for compatibility checking.
+    pub version: String,
+    /// Model the vectors came from; entries from another model are dropped.
+    pub model: String,
+    pub entries: Vec<QueryEntry>,
+}
+
+impl Default for QueryCache {
+    fn default() -> Self {
+        Self {
+            version: CACHE_VERSION.to_string(),
+            model: mu_embeddings::MODEL_NAME.to_string(),
+            entries: Vec::new(),
+        }
+    }
+}
+
+impl QueryCache {
+    /// Load the cache from disk.
+    ///
+    /// Returns an empty cache if the file doesn't exist, is invalid, or was
+    /// written by another format version or model.
+    pub fn load(cache_dir: Option<&str>, project_root: &Path) -> Self {
+        let cache_path = Self::cache_path(cache_dir, project_root);
+        let Ok(content) = fs::read_to_string(&cache_path) else {
+            return Self::default();
+        };
+
+        match serde_json::from_str::<QueryCache>(&content) {
+            Ok(cache)
+                if cache.version == CACHE_VERSION && cache.model == mu_embeddings::MODEL_NAME =>
+            {
+                cache
+            }
+            Ok(_) => {
+                tracing::debug!("Query cache is from another version or model, starting fresh");
+                Self::default()
+            }
+            Err(e) => {
+                tracing::warn!("Failed to parse query cache: {}", e);
+                Self::default()
+            }
+        }
+    }
+
+    /// Save the cache to disk.
+    pub fn save(&self, cache_dir: Option<&str>, project_root: &Path) -> anyhow::Result<()> {
+        let cache_path = Self::cache_path(cache_dir, project_root);
+        let parent = cache_path.parent().unwrap_or(project_root);
+        fs::create_dir_all(parent)?;
+
+        // Write to a uniquely named temp file and rename, so concurrent
+        // searches neither read a half-written cache nor clobber each
+        // other's write. The temp file is removed if anything fails.
+        let mut tmp = tempfile::NamedTempFile::new_in(parent)?;
+        tmp.write_all(serde_json::to_string(self)?.as_bytes())?;
+        tmp.persist(&cache_path)?;
+        Ok(())
+    }
+
+    fn cache_path(cache_dir: Option<&str>, project_root: &Path) -> PathBuf {
+        match cache_dir {
+            Some(dir) => project_root.join(dir).join(CACHE_FILE),
+            None => project_root.join(DEFAULT_CACHE_PATH),
+        }
+    }
+
+    /// The cached vector for a query, if any.
+    pub fn get(&self, query: &str) -> Option<&[f32]> {
+        self.entries
+            .iter()
+            .find(|entry| entry.query == query)
+            .map(|entry| entry.embedding.as_slice())
+    }
+
+    /// Record a query's vector as the most recent, evicting the oldest
+    /// entries past the cap.
+    pub fn insert(&mut self, query: &str, embedding: Vec<f32>) {
+        self.entries.retain(|entry| entry.query != query);
+        self.entries.insert(
+            0,
+            QueryEntry {
+                query: query.to_string(),
+                embedding,
+            },
+        );
+        self.entries.truncate(MAX_ENTRIES);
+    }
+}
+
+/// Embed a search query, reusing the cached vector when there is one.
+///
+/// A cache that can't be written only costs the next search its speed-up,
+/// so save errors are logged rather than returned.
+pub fn embed_query(project_root: &Path, query: &str) -> anyhow::Result<Vec<f32>> {
+    let config = MuConfig::load(project_root);
+    let cache_dir = config.cache_directory();
+    let mut cache = QueryCache::load(cache_dir, project_root);
+    if let Some(embedding) = cache.get(query) {
+        tracing::debug!("Query embedding cache hit for {:?}", query);
+        return Ok(embedding.to_vec());
+    }
+
+    // Load the embedding model from embedded weights (zero-config)
+    let model = mu_embeddings::MuSigmaModel::embedded()?;
+    let embedding = model.embed_one(query)?;
+
+    cache.insert(query, embedding.clone());
+    if let Err(e) = cache.save(cache_dir, project_root) {
+        tracing::debug!("Failed to save query cache: {}", e);
+    }
+    Ok(embedding)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::TempDir;
+
+    #[test]
+    fn test_insert_keeps_most_recent() {
+        let mut cache = QueryCache::default();
+        for i in 0..MAX_ENTRIES + 2 {
+            cache.insert(&format!("q{}", i), vec![i as f32]);
+        }
+        assert_eq!(cache.entries.len(), MAX_ENTRIES);
+        assert!(cache.get("q0").is_none());
+        assert!(cache.get("q1").is_none());
+
+        // Re-inserting moves a query to the front without duplicating it
+        cache.insert("q5", vec![0.5]);
+        assert_eq!(cache.entries[0].query, "q5");
+        assert_eq!(cache.get("q5"), Some(&[0.5][..]));
+        assert_eq!(cache.entries.len(), MAX_ENTRIES);
+    }
+
+    #[test]
+    fn test_save_and_load() {
+        let dir = TempDir::new().unwrap();
+        let mut cache = QueryCache::default();
+        cache.insert("retry logic", vec![0.1, 0.2]);
+        cache.save(None, dir.path()).unwrap();
+
+        let loaded = QueryCache::load(None, dir.path());
+        assert_eq!(loaded.get("retry logic"), Some(&[0.1, 0.2][..]));
+
+        // Vectors from another model are not reused
+        let mut other = loaded.clone();
+        other.model = "all-MiniLM-L6-v2".to_string();
+        other.save(None, dir.path()).unwrap();
+        assert!(QueryCache::load(None, dir.path()).entries.is_empty());
+    }
+
+    #[test]
+    fn test_save_honors_cache_directory() {
+        let dir = TempDir::new().unwrap();
+        let mut cache = QueryCache::default();
+        cache.insert("retry logic", vec![0.1, 0.2]);
+        cache.save(Some("build/mu-cache"), dir.path()).unwrap();
+
+        assert!(dir.path().join("build/mu-cache").join(CACHE_FILE).exists());
+        assert!(!dir.path().join(DEFAULT_CACHE_PATH).exists());
+        let loaded = QueryCache::load(Some("build/mu-cache"), dir.path());
+        assert_eq!(loaded.get("retry logic"), Some(&[0.1, 0.2][..]));
+        // No temp files are left behind
+        assert_eq!(
+            fs::read_dir(dir.path().join("build/mu-cache"))
+                .unwrap()
+                .count(),
+            1
+        );
+    }
+
+    #[test]
+    fn test_load_missing_or_invalid() {
+        let dir = TempDir::new().unwrap();
+        assert!(QueryCache::load(None, dir.path()).entries.is_empty());
+
+        let path = QueryCache::cache_path(None, dir.path());
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, "not json").unwrap();
+        assert!(QueryCache::load(None, dir.path()).entries.is_empty());
+    }
+}
@@ -475,10 +475,7 @@ impl WorkspaceResolver {
         // Find the longest matching package name
         for (pkg_name, module_id) in &self.packages {
             if import_path.starts_with(pkg_name)
-                && import_path
-                    .chars()
-                    .nth(pkg_name.len())
-                    .map_or(false, |c| c == '/')
+                && import_path.chars().nth(pkg_name.len()) == Some('/')
             {
                 // Extract subpath and resolve
                 let subpath = &import_path[pkg_name.len() + 1..];
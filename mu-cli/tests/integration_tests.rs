@@ -198,7 +198,7 @@ fn test_status_json_format_after_bootstrap() {
 
     let stdout_str = stdout(&output);
     let json: serde_json::Value = serde_json::from_str(&stdout_str)
-        .expect(&format!("Output should be valid JSON: {}", stdout_str));
+        .unwrap_or_else(|_| panic!("Output should be valid JSON: {}", stdout_str));
 
     // Check expected fields
     assert_eq!(
@@ -360,7 +360,7 @@ fn test_bootstrap_json_format() {
 
     let stdout_str = stdout(&output);
     let json: serde_json::Value = serde_json::from_str(&stdout_str)
-        .expect(&format!("Output should be valid JSON: {}", stdout_str));
+        .unwrap_or_else(|_| panic!("Output should be valid JSON: {}", stdout_str));
 
     // Check expected fields
     assert!(json.get("success").is_some(), "Should have success field");
@@ -456,6 +456,466 @@ fn test_query_alias_works() {
     );
 }
 
+#[test]
+fn test_query_group_by_aggregates() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("busy.py"),
+        "def a(x):\n    if x:\n        return 1\n    return 0\n\ndef b():\n    pass\n",
+    )
+    .expect("Failed to write python file");
+    fs::write(temp_dir.path().join("quiet.py"), "def c():\n    pass\n")
+        .expect("Failed to write python file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT file_path, COUNT(*), MAX(complexity) FROM functions GROUP BY file_path ORDER BY file_path",
+            "--format",
+            "json",
+        ],
+    );
+    assert!(output.status.success(), "query failed: {}", stderr(&output));
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+
+    assert_eq!(
+        json["columns"],
+        serde_json::json!(["file_path", "COUNT(*)", "MAX(complexity)"])
+    );
+    let rows = json["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 2);
+    assert!(rows[0][0].as_str().unwrap().ends_with("busy.py"));
+    assert_eq!(rows[0][1], "2");
+    assert_eq!(rows[1][1], "1");
+}
+
+#[test]
+fn test_query_find_matches_regex() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("handlers.py"),
+        "def handleGetRequest():\n    pass\n\ndef handlePostRequest():\n    pass\n\ndef handle_error():\n    pass\n",
+    )
+    .expect("Failed to write python file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "FIND functions WHERE name MATCHES 'handle.*Request' ORDER BY name",
+            "--format",
+            "json",
+        ],
+    );
+    assert!(output.status.success(), "query failed: {}", stderr(&output));
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let name = json["columns"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .position(|c| c == "name")
+        .unwrap();
+    let names: Vec<&str> = json["rows"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| row[name].as_str().unwrap())
+        .collect();
+    assert_eq!(names, vec!["handleGetRequest", "handlePostRequest"]);
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT name FROM functions WHERE name NOT MATCHES 'Request$'",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["rows"], serde_json::json!([["handle_error"]]));
+
+    // Bad patterns fail before reaching DuckDB
+    let output = run_mu(
+        temp_dir.path(),
+        &["query", "FIND functions WHERE name MATCHES 'handle(['"],
+    );
+    assert!(!output.status.success());
+    let err = stderr(&output);
+    assert!(
+        err.contains("Invalid regex in MATCHES 'handle(['"),
+        "{}",
+        err
+    );
+    assert!(!err.contains("regexp_matches"), "{}", err);
+}
+
+#[test]
+fn test_context_reports_token_budget() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("auth.py"),
+        "def login(user):\n    return check_password(user)\n\ndef check_password(user):\n    return user.ok\n",
+    )
+    .expect("Failed to write python file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "context",
+            "login",
+            "--max-tokens",
+            "4000",
+            "--format",
+            "json",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "context failed: {}",
+        stderr(&output)
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["encoding"], "mu");
+    assert_eq!(json["max_tokens"], 4000);
+    assert!(json["tokens"].as_u64().unwrap() <= 4000);
+    assert!(json["text"].as_str().unwrap().contains("login"));
+
+    // --format mu prints the bare OMEGA text for piping into a prompt
+    let output = run_mu(
+        temp_dir.path(),
+        &["context", "login", "--omega", "--format", "mu"],
+    );
+    assert!(
+        output.status.success(),
+        "context failed: {}",
+        stderr(&output)
+    );
+    let text = stdout(&output);
+    assert!(text.starts_with(";; OMEGA Schema"), "{}", text);
+    assert!(text.contains("(fn \"login\")"), "{}", text);
+}
+
+#[test]
+fn test_query_offset_pages_and_reports_total() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source: String = (0..5)
+        .map(|i| format!("def f{}():\n    pass\n\n", i))
+        .collect();
+    fs::write(temp_dir.path().join("many.py"), source).expect("Failed to write python file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT name FROM functions ORDER BY name",
+            "--limit",
+            "2",
+            "--offset",
+            "2",
+            "--format",
+            "json",
+        ],
+    );
+    assert!(output.status.success(), "query failed: {}", stderr(&output));
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+
+    assert_eq!(json["rows"], serde_json::json!([["f2"], ["f3"]]));
+    assert_eq!(json["row_count"], 2);
+    assert_eq!(json["total_count"], 5);
+
+    // Unpaged queries leave total_count out
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT COUNT(*) FROM functions",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert!(json.get("total_count").is_none());
+}
+
+#[test]
+fn test_bootstrap_since_reparses_only_git_changes() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let dir = temp_dir.path();
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .current_dir(dir)
+            .args(["-c", "user.name=mu", "-c", "user.email=mu@example.com"])
+            .args(args)
+            .output()
+            .expect("Failed to run git")
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    };
+    let function_names = || {
+        let output = run_mu(
+            dir,
+            &[
+                "query",
+                "SELECT name FROM functions ORDER BY name",
+                "--format",
+                "json",
+            ],
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+        json["rows"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|row| row[0].as_str().unwrap().to_string())
+            .collect::<Vec<_>>()
+    };
+
+    fs::write(dir.join("a.py"), "def alpha():\n    pass\n").unwrap();
+    fs::write(dir.join("b.py"), "def beta():\n    pass\n").unwrap();
+    git(&["init", "-q"]);
+    git(&["add", "."]);
+    git(&["commit", "-q", "-m", "initial"]);
+    assert!(run_mu(dir, &["bootstrap"]).status.success());
+
+    // Committed after the build: git sees no change since HEAD, so the
+    // cached parse is reused and the rename stays invisible
+    fs::write(dir.join("a.py"), "def alpha_renamed():\n    pass\n").unwrap();
+    git(&["commit", "-q", "-am", "rename"]);
+    // Uncommitted and untracked changes are picked up
+    fs::write(dir.join("b.py"), "def beta():\n    pass\n\ndef gamma():\n    pass\n").unwrap();
+    fs::write(dir.join("c.py"), "def delta():\n    pass\n").unwrap();
+
+    let output = run_mu(dir, &["bootstrap", "--since", "HEAD", "--format", "json"]);
+    assert!(output.status.success(), "bootstrap failed: {}", stderr(&output));
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["files_parsed"], 2);
+    assert_eq!(function_names(), vec!["alpha", "beta", "delta", "gamma"]);
+}
+
+#[test]
+fn test_bootstrap_live_embed_reembeds_changed_files_only() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let dir = temp_dir.path();
+    let git = |args: &[&str]| {
+        let status = Command::new("git")
+            .current_dir(dir)
+            .args(["-c", "user.name=mu", "-c", "user.email=mu@example.com"])
+            .args(args)
+            .output()
+            .expect("Failed to run git")
+            .status;
+        assert!(status.success(), "git {:?} failed", args);
+    };
+
+    fs::write(dir.join("a.py"), "def alpha():\n    pass\n").unwrap();
+    fs::write(dir.join("b.py"), "def beta():\n    pass\n").unwrap();
+    git(&["init", "-q"]);
+    git(&["add", "."]);
+    git(&["commit", "-q", "-m", "initial"]);
+    assert!(run_mu(dir, &["bootstrap", "--no-embed"]).status.success());
+
+    fs::write(dir.join("b.py"), "def gamma():\n    pass\n").unwrap();
+    let output = run_mu(
+        dir,
+        &[
+            "bootstrap",
+            "--since",
+            "HEAD",
+            "--live-embed",
+            "--format",
+            "json",
+        ],
+    );
+    assert!(
+        output.status.success(),
+        "bootstrap failed: {}",
+        stderr(&output)
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["embeddings_generated"], 2, "{}", json);
+
+    // Only the changed file's nodes were embedded
+    let output = run_mu(
+        dir,
+        &[
+            "query",
+            "SELECT node_id FROM embeddings ORDER BY node_id",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(
+        json["rows"],
+        serde_json::json!([["fn:b.py:gamma"], ["mod:b.py"]]),
+        "{}",
+        json
+    );
+}
+
+#[test]
+fn test_bootstrap_since_outside_git_does_full_build() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_sample_project(temp_dir.path());
+    assert!(run_mu(temp_dir.path(), &["bootstrap"]).status.success());
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--since", "HEAD"]);
+    assert!(output.status.success(), "bootstrap failed: {}", stderr(&output));
+    assert!(stderr(&output).contains("doing a full build"));
+}
+
+#[test]
+fn test_export_node_depth_exports_neighborhood() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("chain.py"),
+        "def delta():\n    pass\n\ndef charlie():\n    delta()\n\ndef bravo():\n    charlie()\n\ndef alpha():\n    bravo()\n",
+    )
+    .expect("Failed to write python file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let export = |depth: &str| {
+        let output = run_mu(
+            temp_dir.path(),
+            &[
+                "export", "-F", "json", "--node", "bravo", "--depth", depth, "-e", "calls",
+            ],
+        );
+        assert!(
+            output.status.success(),
+            "export failed: {}",
+            stderr(&output)
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+        let mut ids: Vec<String> = json["nodes"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|n| {
+                n["id"]
+                    .as_str()
+                    .unwrap()
+                    .trim_start_matches("fn:chain.py:")
+                    .to_string()
+            })
+            .collect();
+        ids.sort();
+        (ids, json["edges"].as_array().unwrap().len())
+    };
+
+    // Callers and callees within one hop, and the calls between them
+    assert_eq!(
+        export("1"),
+        (vec!["alpha".into(), "bravo".into(), "charlie".into()], 2)
+    );
+    assert_eq!(
+        export("2"),
+        (
+            vec![
+                "alpha".into(),
+                "bravo".into(),
+                "charlie".into(),
+                "delta".into()
+            ],
+            3
+        )
+    );
+
+    // --depth only makes sense around a node
+    let output = run_mu(temp_dir.path(), &["export", "--depth", "2"]);
+    assert!(!output.status.success());
+}
+
+#[test]
+fn test_export_plantuml_interface_and_implementor() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("shapes.ts"),
+        "export interface Shape {\n  area(): number;\n}\n\nexport class Square implements Shape {\n  area(): number {\n    return 1;\n  }\n}\n",
+    )
+    .expect("Failed to write typescript file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(temp_dir.path(), &["export", "-F", "plantuml"]);
+    assert!(
+        output.status.success(),
+        "export failed: {}",
+        stderr(&output)
+    );
+    let uml = stdout(&output);
+    assert!(uml.contains("interface \"Shape\" as c0"), "{}", uml);
+    assert!(uml.contains("class \"Square\" as c1"), "{}", uml);
+    assert!(uml.contains("c1 ..|> c0"), "{}", uml);
+}
+
+#[test]
+fn test_export_edge_types_filter() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("main.py"),
+        "import os\n\ndef greet(n):\n    return n\n\ndef main():\n    greet(1)\n\nclass Idle:\n    pass\n",
+    )
+    .expect("Failed to write python file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &["export", "-F", "json", "--edge-types", "calls"],
+    );
+    assert!(
+        output.status.success(),
+        "export failed: {}",
+        stderr(&output)
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+
+    let edges = json["edges"].as_array().unwrap();
+    assert_eq!(edges.len(), 1);
+    assert_eq!(edges[0]["source"], "fn:main.py:main");
+    assert_eq!(edges[0]["target"], "fn:main.py:greet");
+    // Only the nodes the call edges connect are kept
+    let mut ids: Vec<&str> = json["nodes"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["id"].as_str().unwrap())
+        .collect();
+    ids.sort();
+    assert_eq!(ids, vec!["fn:main.py:greet", "fn:main.py:main"]);
+}
+
 // ============================================================================
 // Output Format Tests
 // ============================================================================
@@ -592,6 +1052,29 @@ fn test_quiet_flag() {
     // Just verify command succeeds with the flag
 }
 
+#[test]
+fn test_search_cutoffs_need_semantic_mode() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+
+    for args in [
+        ["search", "auth", "--mode", "hybrid", "--threshold", "0.5"],
+        ["search", "auth", "--mode", "keyword", "--relevance", "high"],
+    ] {
+        let output = run_mu(temp_dir.path(), &args);
+        assert_eq!(
+            output.status.code(),
+            Some(2),
+            "{:?} should be rejected",
+            args
+        );
+        assert!(
+            stderr(&output).contains("only apply to --mode semantic"),
+            "{}",
+            stderr(&output)
+        );
+    }
+}
+
 // ============================================================================
 // Command Alias Tests
 // ============================================================================
@@ -777,3 +1260,1489 @@ fn test_bootstrap_json_has_required_fields() {
         );
     }
 }
+
+// ============================================================================
+// Language Support Tests
+// ============================================================================
+
+#[test]
+fn test_bootstrap_dart_inheritance_edge() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("animals.dart"),
+        r#"
+class Animal {
+  void speak() {}
+}
+
+class Dog extends Animal {
+  Dog.named();
+  factory Dog.create() => Dog.named();
+}
+
+void main() {
+  Dog.create().speak();
+}
+"#,
+    )
+    .expect("Failed to write dart file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--format", "json"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["files_parsed"], 1);
+
+    // 1 module + 2 classes + speak + named + create + main
+    assert_eq!(json["node_count"], 7);
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT source_id, target_id FROM edges WHERE type = 'inherits'",
+            "--format",
+            "json",
+        ],
+    );
+    let stdout_str = stdout(&output);
+    assert!(
+        stdout_str.contains("cls:animals.dart:Dog")
+            && stdout_str.contains("cls:animals.dart:Animal"),
+        "Dog should inherit from Animal: {} {}",
+        stdout_str,
+        stderr(&output)
+    );
+}
+
+#[test]
+fn test_bootstrap_enum_nodes_carry_variants() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("color.rs"),
+        r#"
+pub enum Color {
+    Red,
+    Green(u8),
+    Blue { shade: u8 },
+}
+
+impl Color {
+    pub fn is_red(&self) -> bool {
+        matches!(self, Color::Red)
+    }
+}
+
+pub struct Palette;
+"#,
+    )
+    .expect("Failed to write rust file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT name, type, properties FROM nodes WHERE type IN ('class', 'enum') ORDER BY name",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let rows = json["rows"].as_array().unwrap();
+    assert_eq!(rows.len(), 2, "{}", json);
+
+    // The impl block in the same file doesn't turn the enum back into a class
+    assert_eq!(rows[0][0], "Color");
+    assert_eq!(rows[0][1], "enum");
+    let properties: serde_json::Value = serde_json::from_str(rows[0][2].as_str().unwrap()).unwrap();
+    assert_eq!(
+        properties["variants"],
+        serde_json::json!(["Red", "Green", "Blue"])
+    );
+    assert_eq!(rows[1][0], "Palette");
+    assert_eq!(rows[1][1], "class");
+
+    let output = run_mu(temp_dir.path(), &["compress"]);
+    assert!(output.status.success(), "compress should succeed");
+    assert!(
+        stdout(&output).contains("$ Color = Red | Green | Blue"),
+        "compress should list the variants: {}",
+        stdout(&output)
+    );
+}
+
+#[test]
+fn test_bootstrap_interfaces_are_queryable() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("repo.ts"),
+        r#"
+export interface Repository {
+    find(id: string): string;
+}
+
+export class SqlRepository implements Repository {
+    find(id: string): string {
+        return id;
+    }
+}
+"#,
+    )
+    .expect("Failed to write ts file");
+    fs::write(
+        temp_dir.path().join("shape.rs"),
+        "pub trait Shape {\n    fn area(&self) -> f64;\n}\n\npub struct Square;\n",
+    )
+    .expect("Failed to write rust file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT id FROM interfaces ORDER BY id",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(
+        json["rows"],
+        serde_json::json!([["cls:repo.ts:Repository"], ["cls:shape.rs:Shape"]]),
+        "only interfaces and traits should be listed"
+    );
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT name FROM classes ORDER BY name",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(
+        json["rows"],
+        serde_json::json!([["SqlRepository"], ["Square"]])
+    );
+}
+
+#[test]
+fn test_bootstrap_parses_latin1_sources() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    // Latin-1 "é" in a comment and an identifier; invalid as UTF-8
+    fs::write(
+        temp_dir.path().join("legacy.py"),
+        b"# R\xe9sum\xe9 helpers\ndef format_resume(name):\n    return name\n",
+    )
+    .expect("Failed to write python file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--format", "json"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["warnings"][0]["path"], "legacy.py", "{}", json);
+    assert!(json["warnings"][0]["message"]
+        .as_str()
+        .unwrap()
+        .contains("Latin-1"));
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT name FROM nodes WHERE type = 'function'",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["rows"][0][0], "format_resume", "{}", json);
+}
+
+#[test]
+fn test_doctor_reports_syntax_error_locations() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(temp_dir.path().join("ok.py"), "def ok():\n    pass\n").unwrap();
+    fs::write(
+        temp_dir.path().join("broken.rs"),
+        "fn main() {\n    let x = 1\n}\n",
+    )
+    .unwrap();
+
+    let output = run_mu(temp_dir.path(), &["doctor", "--syntax", "--format", "json"]);
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let files = json["syntax_errors"].as_array().unwrap();
+    assert_eq!(files.len(), 1, "{}", json);
+    assert_eq!(files[0]["path"], "broken.rs");
+    assert_eq!(
+        files[0]["errors"][0],
+        serde_json::json!({"line": 2, "column": 14, "message": "Missing `;`"})
+    );
+
+    let syntax = json["checks"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|check| check["label"] == "Syntax")
+        .expect("Syntax check should run");
+    assert_eq!(syntax["value"], "errors in 1 of 2 files");
+}
+
+#[test]
+fn test_stats_json_counts_lines_and_languages() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("app.py"),
+        "import util\n\n\ndef main():\n    return util.helper()\n\n# trailing comment\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("util.py"),
+        "def helper():\n    if True:\n        return 1\n    return 2\n",
+    )
+    .unwrap();
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(temp_dir.path(), &["stats", "--format", "json"]);
+    assert!(output.status.success(), "stats should succeed");
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["files"], 2, "{}", json);
+    // Module-level lines after the last function are counted too
+    assert_eq!(json["lines"], 11, "{}", json);
+    assert_eq!(json["functions"], 2);
+    assert_eq!(json["languages"][0]["language"], "python");
+    assert!(json["max_complexity"].as_u64().unwrap() >= 2, "{}", json);
+}
+
+#[test]
+fn test_show_traversal_via_edge_types() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("app.ts"),
+        "import { helper } from './helpers';\nexport function run() { return helper(); }\n",
+    )
+    .expect("Failed to write app");
+    fs::write(
+        temp_dir.path().join("helpers.ts"),
+        "export function helper() { return 1; }\n",
+    )
+    .expect("Failed to write helpers");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let rows = |query: &str| -> Vec<(String, String)> {
+        let output = run_mu(temp_dir.path(), &["query", query, "--format", "json"]);
+        assert!(
+            output.status.success(),
+            "{} failed: {}",
+            query,
+            stderr(&output)
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+        json["rows"]
+            .as_array()
+            .expect("rows array")
+            .iter()
+            .map(|r| {
+                (
+                    r[0].as_str().unwrap().to_string(),
+                    r[3].as_str().unwrap().to_string(),
+                )
+            })
+            .collect()
+    };
+
+    // Without VIA, containment is skipped and only the import is followed
+    let all = rows("SHOW dependencies OF mod:app.ts DEPTH 2");
+    assert!(all.iter().all(|(_, edge)| edge == "imports"), "{:?}", all);
+
+    // VIA follows exactly the listed types, contains included
+    let via = rows("SHOW dependencies OF mod:app.ts DEPTH 2 VIA contains,calls");
+    assert_eq!(
+        via,
+        vec![
+            ("fn:app.ts:run".to_string(), "contains".to_string()),
+            ("fn:helpers.ts:helper".to_string(), "calls".to_string()),
+        ]
+    );
+
+    let output = run_mu(
+        temp_dir.path(),
+        &["query", "SHOW dependencies OF mod:app.ts VIA bogus"],
+    );
+    assert!(!output.status.success());
+    assert!(stderr(&output).contains("Unknown edge type 'bogus'"));
+}
+
+#[test]
+fn test_bootstrap_scala_inheritance_and_members() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("shapes.scala"),
+        r#"
+trait Shape {
+  def area: Double
+}
+
+class Circle(r: Double) extends Shape {
+  def area: Double = 3.14 * r * r
+}
+
+object Geometry {
+  def total(shapes: Seq[Shape]): Double = shapes.map(_.area).sum
+}
+"#,
+    )
+    .expect("Failed to write scala file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--format", "json"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["files_parsed"], 1);
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT source_id, target_id, type FROM edges WHERE type IN ('inherits', 'contains')",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let has_edge = |source: &str, target: &str, edge_type: &str| {
+        json["rows"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|row| row[0] == source && row[1] == target && row[2] == edge_type)
+    };
+
+    assert!(
+        has_edge(
+            "cls:shapes.scala:Circle",
+            "cls:shapes.scala:Shape",
+            "inherits"
+        ),
+        "Circle should inherit from Shape: {}",
+        json
+    );
+    assert!(has_edge(
+        "cls:shapes.scala:Circle",
+        "fn:shapes.scala:Circle.area",
+        "contains"
+    ));
+    assert!(
+        has_edge(
+            "cls:shapes.scala:Geometry",
+            "fn:shapes.scala:Geometry.total",
+            "contains"
+        ),
+        "Geometry should contain total: {}",
+        json
+    );
+}
+
+#[test]
+fn test_bootstrap_ruby_mixins_and_requires() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::create_dir_all(temp_dir.path().join("lib")).unwrap();
+    fs::write(
+        temp_dir.path().join("lib/models.rb"),
+        r#"
+module Greeting
+  def greet
+    "hi"
+  end
+end
+
+class User
+  include Greeting
+  attr_accessor :name
+
+  def self.find(id)
+    new
+  end
+end
+"#,
+    )
+    .expect("Failed to write ruby file");
+    fs::write(
+        temp_dir.path().join("app.rb"),
+        "require 'json'\nrequire_relative 'lib/models'\n\ndef main\n  User.find(1)\nend\n",
+    )
+    .expect("Failed to write ruby file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--format", "json"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["files_parsed"], 2);
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT source_id, target_id, type FROM edges WHERE type IN ('inherits', 'contains', 'imports')",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let has_edge = |source: &str, target: &str, edge_type: &str| {
+        json["rows"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|row| row[0] == source && row[1] == target && row[2] == edge_type)
+    };
+
+    assert!(
+        has_edge(
+            "cls:lib/models.rb:User",
+            "cls:lib/models.rb:Greeting",
+            "inherits"
+        ),
+        "include should produce an inheritance edge: {}",
+        json
+    );
+    assert!(has_edge(
+        "cls:lib/models.rb:User",
+        "fn:lib/models.rb:User.find",
+        "contains"
+    ));
+    assert!(
+        has_edge("mod:app.rb", "mod:lib/models.rb", "imports"),
+        "require_relative should import the required file: {}",
+        json
+    );
+}
+
+#[test]
+fn test_bootstrap_php_namespaces_link_across_files() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::create_dir_all(temp_dir.path().join("src/Models")).unwrap();
+    fs::create_dir_all(temp_dir.path().join("src/Http")).unwrap();
+    fs::write(
+        temp_dir.path().join("src/Models/Model.php"),
+        "<?php\nnamespace App\\Models;\n\nabstract class Model {}\n",
+    )
+    .expect("Failed to write php file");
+    fs::write(
+        temp_dir.path().join("src/Http/UserController.php"),
+        r#"<?php
+namespace App\Http;
+
+use App\Models\Model as BaseModel;
+
+require_once __DIR__ . '/helpers.php';
+
+class UserController extends BaseModel
+{
+    public function show() {}
+}
+"#,
+    )
+    .expect("Failed to write php file");
+    fs::write(
+        temp_dir.path().join("src/Http/helpers.php"),
+        "<?php\nfunction helper() {}\n",
+    )
+    .expect("Failed to write php file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--format", "json"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["files_parsed"], 3);
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT source_id, target_id, type FROM edges WHERE type IN ('inherits', 'imports')",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let has_edge = |source: &str, target: &str, edge_type: &str| {
+        json["rows"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .any(|row| row[0] == source && row[1] == target && row[2] == edge_type)
+    };
+
+    assert!(
+        has_edge(
+            "cls:src/Http/UserController.php:UserController",
+            "cls:src/Models/Model.php:Model",
+            "inherits"
+        ),
+        "aliased base class should resolve across files: {}",
+        json
+    );
+    assert!(
+        has_edge(
+            "mod:src/Http/UserController.php",
+            "mod:src/Models/Model.php",
+            "imports"
+        ),
+        "use should import the declaring file: {}",
+        json
+    );
+    assert!(
+        has_edge(
+            "mod:src/Http/UserController.php",
+            "mod:src/Http/helpers.php",
+            "imports"
+        ),
+        "require_once should import the included file: {}",
+        json
+    );
+}
+
+#[test]
+fn test_impact_of_c_header_lists_including_files() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::create_dir_all(temp_dir.path().join("src/drivers")).unwrap();
+    fs::write(
+        temp_dir.path().join("src/drivers/uart.h"),
+        "#ifndef UART_H\n#define UART_H\nvoid uart_write(char c);\n#endif\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("src/drivers/uart.c"),
+        "#include \"uart.h\"\n\nvoid uart_write(char c) {}\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("src/main.cpp"),
+        "#include <cstdio>\n#include \"drivers/uart.h\"\n\nint main() {\n    uart_write('x');\n    return 0;\n}\n",
+    )
+    .unwrap();
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--format", "json"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["files_parsed"], 3);
+
+    let output = run_mu(
+        temp_dir.path(),
+        &["impact", "src/drivers/uart.h", "--format", "json"],
+    );
+    assert!(
+        output.status.success(),
+        "impact should succeed: {}",
+        stderr(&output)
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["node"], "mod:src/drivers/uart.h");
+
+    let affected: Vec<&str> = json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["target"].as_str().unwrap())
+        .collect();
+    assert!(
+        affected.contains(&"mod:src/drivers/uart.c"),
+        "{:?}",
+        affected
+    );
+    assert!(affected.contains(&"mod:src/main.cpp"), "{:?}", affected);
+}
+
+#[test]
+fn test_rust_trait_impl_ignores_same_name_in_other_language() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("main.rs"),
+        "trait Shape {\n    fn area(&self) -> f64;\n}\n\nstruct Sq(f64);\n\nimpl Shape for Sq {\n    fn area(&self) -> f64 { self.0 * self.0 }\n}\n\nimpl Named for Sq {}\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("main.go"),
+        "package main\n\ntype Shape interface {\n\tArea() float64\n}\n\ntype Sq struct{}\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("zz.go"),
+        "package other\n\ntype Shape interface {\n\tPerimeter() float64\n}\n\ntype Named interface {\n\tName() string\n}\n",
+    )
+    .unwrap();
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--format", "json"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT source_id, target_id FROM edges WHERE type = 'implements'",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let mut edges: Vec<(&str, &str)> = json["rows"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| (row[0].as_str().unwrap(), row[1].as_str().unwrap()))
+        .collect();
+    edges.sort();
+    // A trait only the Go code declares stays external
+    assert_eq!(
+        edges,
+        vec![
+            ("cls:main.rs:Sq", "cls:main.rs:Shape"),
+            ("cls:main.rs:Sq", "ext:Named"),
+        ]
+    );
+}
+
+#[test]
+fn test_impact_of_rust_trait_lists_implementors() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+    fs::write(
+        temp_dir.path().join("src/shape.rs"),
+        "pub trait Shape {\n    fn area(&self) -> f64;\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("src/square.rs"),
+        "pub struct Square(f64);\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("src/impls.rs"),
+        r#"use crate::shape::Shape;
+use crate::square::Square;
+
+impl Shape for Square {
+    fn area(&self) -> f64 { self.0 * self.0 }
+}
+
+impl<T> std::fmt::Debug for Square {}
+"#,
+    )
+    .unwrap();
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--format", "json"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT source_id, target_id FROM edges WHERE type = 'implements'",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let mut edges: Vec<(&str, &str)> = json["rows"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|row| (row[0].as_str().unwrap(), row[1].as_str().unwrap()))
+        .collect();
+    edges.sort();
+    assert_eq!(
+        edges,
+        vec![
+            ("cls:src/square.rs:Square", "cls:src/shape.rs:Shape"),
+            ("cls:src/square.rs:Square", "ext:std::fmt::Debug"),
+        ]
+    );
+
+    let output = run_mu(
+        temp_dir.path(),
+        &["impact", "cls:src/shape.rs:Shape", "--format", "json"],
+    );
+    assert!(
+        output.status.success(),
+        "impact should succeed: {}",
+        stderr(&output)
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let affected: Vec<&str> = json["edges"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|n| n["target"].as_str().unwrap())
+        .collect();
+    assert!(
+        affected.contains(&"cls:src/square.rs:Square"),
+        "{:?}",
+        affected
+    );
+}
+
+#[test]
+fn test_tsx_components_use_props_types() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::create_dir_all(temp_dir.path().join("src")).unwrap();
+    fs::write(
+        temp_dir.path().join("src/types.ts"),
+        "export interface CardProps {\n    title: string;\n    footer?: string;\n}\n",
+    )
+    .unwrap();
+    fs::write(
+        temp_dir.path().join("src/Button.tsx"),
+        r#"import { CardProps } from './types';
+
+type ButtonProps = { label: string; onClick: () => void };
+
+export function Button({ label, onClick }: ButtonProps) {
+    return <button onClick={onClick}>{label}</button>;
+}
+
+export const Card = (props: CardProps) => <div>{props.title}</div>;
+"#,
+    )
+    .unwrap();
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--format", "json"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT e.source_id, e.target_id, n.properties FROM edges e JOIN nodes n ON n.id = e.source_id WHERE e.type = 'uses' ORDER BY e.source_id",
+            "--format",
+            "json",
+        ],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let rows = json["rows"].as_array().unwrap();
+    let edges: Vec<(&str, &str)> = rows
+        .iter()
+        .map(|row| (row[0].as_str().unwrap(), row[1].as_str().unwrap()))
+        .collect();
+    assert_eq!(
+        edges,
+        vec![
+            ("fn:src/Button.tsx:Button", "cls:src/Button.tsx:ButtonProps"),
+            ("fn:src/Button.tsx:Card", "cls:src/types.ts:CardProps"),
+        ]
+    );
+
+    // Destructured names, or the props type's members when not destructured
+    let props: Vec<serde_json::Value> = rows
+        .iter()
+        .map(|row| {
+            let properties: serde_json::Value = match &row[2] {
+                serde_json::Value::String(s) => serde_json::from_str(s).unwrap(),
+                other => other.clone(),
+            };
+            properties["props"].clone()
+        })
+        .collect();
+    assert_eq!(
+        props,
+        vec![
+            serde_json::json!(["label", "onClick"]),
+            serde_json::json!(["title", "footer"]),
+        ]
+    );
+}
+
+#[test]
+fn test_bootstrap_dedupes_identical_files() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let source = "def helper():\n    return 1\n";
+    fs::create_dir_all(temp_dir.path().join("vendor")).expect("Failed to create vendor dir");
+    fs::write(temp_dir.path().join("helper.py"), source).expect("Failed to write file");
+    fs::write(temp_dir.path().join("vendor/helper.py"), source).expect("Failed to write copy");
+    fs::write(
+        temp_dir.path().join(".murc.toml"),
+        "[scanner]\ndedupe_identical = true\n",
+    )
+    .expect("Failed to write config");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--format", "json"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["files_scanned"], 2);
+    assert_eq!(
+        json["files_parsed"], 1,
+        "identical source should parse once"
+    );
+    assert_eq!(json["duplicates"][0]["path"], "vendor/helper.py");
+    assert_eq!(json["duplicates"][0]["duplicate_of"], "helper.py");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SELECT id, properties FROM nodes WHERE file_path = 'vendor/helper.py'",
+            "--format",
+            "json",
+        ],
+    );
+    let stdout_str = stdout(&output);
+    assert!(
+        stdout_str.contains("mod:vendor/helper.py") && stdout_str.contains("mod:helper.py"),
+        "duplicate should be an alias module: {}",
+        stdout_str
+    );
+    assert!(
+        !stdout_str.contains("fn:vendor/helper.py"),
+        "duplicate should not be parsed: {}",
+        stdout_str
+    );
+}
+
+#[test]
+fn test_deps_and_usedby_directions_agree() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("app.ts"),
+        "import { helper } from './helpers';\nexport function run() { return helper(); }\n",
+    )
+    .expect("Failed to write app");
+    fs::write(
+        temp_dir.path().join("helpers.ts"),
+        "export function helper() { return 1; }\n",
+    )
+    .expect("Failed to write helpers");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let ids = |args: &[&str]| -> Vec<String> {
+        let output = run_mu(temp_dir.path(), args);
+        assert!(
+            output.status.success(),
+            "{:?} failed: {}",
+            args,
+            stderr(&output)
+        );
+        let json: serde_json::Value =
+            serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+        json["edges"]
+            .as_array()
+            .expect("edges array")
+            .iter()
+            .map(|d| d["target"].as_str().unwrap().to_string())
+            .collect()
+    };
+
+    // run -> helper: helper is a dependency of run, run a dependent of helper
+    assert!(ids(&["deps", "fn:app.ts:run", "--format", "json"])
+        .contains(&"fn:helpers.ts:helper".to_string()));
+    assert_eq!(
+        ids(&["usedby", "fn:helpers.ts:helper", "--format", "json"]),
+        vec!["fn:app.ts:run"]
+    );
+    assert!(ids(&["usedby", "fn:app.ts:run", "--format", "json"]).is_empty());
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "deps",
+            "fn:app.ts:run",
+            "--explain-direction",
+            "--format",
+            "json",
+        ],
+    );
+    assert!(stderr(&output).contains("outgoing edges"));
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "query",
+            "SHOW dependents OF fn:helpers.ts:helper",
+            "--format",
+            "json",
+        ],
+    );
+    let stdout_str = stdout(&output);
+    assert!(
+        stdout_str.contains("fn:app.ts:run"),
+        "SHOW dependents should list run: {} {}",
+        stdout_str,
+        stderr(&output)
+    );
+}
+
+#[test]
+fn test_deps_tree_format_marks_cycles() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("game.py"),
+        "def ping(n):\n    return pong(n - 1)\n\n\ndef pong(n):\n    return ping(n - 1)\n",
+    )
+    .expect("Failed to write game");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &[
+            "deps",
+            "fn:game.py:ping",
+            "--depth",
+            "3",
+            "--format",
+            "tree",
+        ],
+    );
+    assert!(output.status.success(), "deps failed: {}", stderr(&output));
+    let lines: Vec<String> = stdout(&output).lines().map(|l| l.to_string()).collect();
+    assert_eq!(lines[0], "ping", "{:?}", lines);
+    assert!(lines[1].ends_with("pong (calls)"), "{:?}", lines);
+    // The call back to ping is shown once, nested under pong
+    assert!(lines[2].starts_with("    "), "{:?}", lines);
+    assert!(lines[2].ends_with("ping (calls) (cycle)"), "{:?}", lines);
+    assert_eq!(lines.len(), 3, "{:?}", lines);
+}
+
+#[test]
+fn test_deps_lists_external_crates_of_single_rust_file() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(
+        temp_dir.path().join("main.rs"),
+        "use serde::Deserialize;\nuse anyhow::{Context, Result};\n\nfn main() -> Result<()> {\n    Ok(())\n}\n",
+    )
+    .expect("Failed to write main.rs");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(temp_dir.path(), &["deps", "main", "--format", "json"]);
+    assert!(output.status.success(), "deps failed: {}", stderr(&output));
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let deps = json["edges"].as_array().expect("edges array");
+    let mut external: Vec<&str> = deps
+        .iter()
+        .filter(|d| d["edge_type"] == "imports")
+        .map(|d| d["target"].as_str().unwrap())
+        .collect();
+    external.sort();
+    assert_eq!(external, vec!["ext:anyhow", "ext:serde"]);
+    assert!(deps.iter().all(|d| d["depth"] == 1), "{:?}", deps);
+}
+
+#[test]
+fn test_config_validate_and_show() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let config_path = temp_dir.path().join(".murc.toml");
+
+    fs::write(&config_path, "[scanner]\nincude_hidden = true\n").expect("Failed to write config");
+    let output = run_mu(temp_dir.path(), &["config", "validate", "--format", "json"]);
+    assert!(
+        !output.status.success(),
+        "unknown key should fail validation"
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["issues"][0]["key"], "scanner.incude_hidden");
+
+    fs::write(&config_path, "[scanner]\ninclude_hidden = true\n").expect("Failed to write config");
+    let output = run_mu(temp_dir.path(), &["config", "validate"]);
+    assert!(output.status.success(), "valid config: {}", stdout(&output));
+
+    let output = run_mu(temp_dir.path(), &["config", "show", "--format", "json"]);
+    assert!(output.status.success());
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["config"]["scanner"]["include_hidden"], true);
+    assert_eq!(json["config"]["cache"]["enabled"], true);
+}
+
+#[test]
+fn test_query_file_runs_each_statement() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_sample_project(temp_dir.path());
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    fs::write(
+        temp_dir.path().join("health.muql"),
+        "-- Standing health checks\n\
+         SELECT COUNT(*) AS modules FROM modules;\n\
+         -- SELECT * FROM nodes;\n\
+         SELECT COUNT(*) AS classes FROM classes;\n",
+    )
+    .expect("Failed to write query file");
+
+    let output = run_mu(
+        temp_dir.path(),
+        &["query", "--file", "health.muql", "--format", "mu"],
+    );
+    let stdout_str = stdout(&output);
+    assert!(
+        output.status.success(),
+        "script should succeed: {}",
+        stderr(&output)
+    );
+    assert!(stdout_str.contains(":: [1/2] SELECT COUNT(*) AS modules FROM modules"));
+    assert!(stdout_str.contains(":: [2/2] SELECT COUNT(*) AS classes FROM classes"));
+    assert!(!stdout_str.contains("SELECT * FROM nodes"));
+}
+
+#[test]
+fn test_overview_markdown_after_bootstrap() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_sample_project(temp_dir.path());
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(temp_dir.path(), &["overview", "--markdown"]);
+    assert!(
+        output.status.success(),
+        "overview failed: {}",
+        stderr(&output)
+    );
+    let md = stdout(&output);
+    assert!(md.starts_with("# Project Overview"));
+    assert!(md.contains("| python |"));
+
+    let output = run_mu(temp_dir.path(), &["overview", "--format", "json"]);
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert!(!json["languages"].as_array().unwrap().is_empty());
+}
+
+#[test]
+fn test_mubase_env_var_relocates_database() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let scratch = TempDir::new().expect("Failed to create temp dir");
+    setup_sample_project(temp_dir.path());
+    let db_path = scratch.path().join("index").join("sample.mubase");
+
+    let output = mu_binary()
+        .current_dir(temp_dir.path())
+        .env("MU_MUBASE", &db_path)
+        .args(["bootstrap"])
+        .output()
+        .expect("Failed to execute mu command");
+    assert!(
+        output.status.success(),
+        "bootstrap failed: {}",
+        stderr(&output)
+    );
+    assert!(db_path.exists(), "database should be at MU_MUBASE");
+    assert!(!temp_dir.path().join(".mu").join("mubase").exists());
+
+    let output = mu_binary()
+        .current_dir(temp_dir.path())
+        .env("MU_MUBASE", &db_path)
+        .args(["read", "fn:main.py:main"])
+        .output()
+        .expect("Failed to execute mu command");
+    // Source is found relative to the project, not the database location
+    assert!(output.status.success(), "read failed: {}", stderr(&output));
+    assert!(stdout(&output).contains("[fn] main"));
+}
+
+#[test]
+fn test_sus_flags_deeply_nested_function() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    create_sample_python_file(
+        temp_dir.path(),
+        "tangled.py",
+        r#"class Grid:
+    def tangled(self, rows):
+        for r in rows:
+            if r:
+                for c in r:
+                    if c:
+                        while c:
+                            if c > 3:
+                                break
+                            c -= 1
+
+
+def flat(x):
+    if x:
+        return 1
+    return 0
+"#,
+    );
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(temp_dir.path(), &["sus", "tangled.py", "--format", "json"]);
+    assert!(output.status.success(), "sus failed: {}", stderr(&output));
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let warning = json["warnings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|w| w["category"] == "cognitive complexity")
+        .unwrap_or_else(|| panic!("no cognitive warning: {}", json));
+    assert!(warning["message"].as_str().unwrap().starts_with("tangled "));
+
+    let output = run_mu(
+        temp_dir.path(),
+        &["sus", "fn:tangled.py:flat", "--format", "json"],
+    );
+    assert!(!stdout(&output).contains("cognitive complexity"));
+}
+
+#[test]
+fn test_sus_flags_dead_code_cluster() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    create_sample_python_file(
+        temp_dir.path(),
+        "leftovers.py",
+        r#"def main():
+    return used()
+
+
+def used():
+    return 1
+
+
+def old_a():
+    return 2
+
+
+def old_b():
+    return 3
+
+
+def old_c():
+    return 4
+"#,
+    );
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(temp_dir.path(), &["sus", "leftovers.py", "--format", "json"]);
+    assert!(output.status.success(), "sus failed: {}", stderr(&output));
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let warning = json["warnings"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|w| w["category"] == "dead code")
+        .unwrap_or_else(|| panic!("no dead code warning: {}", json));
+    let message = warning["message"].as_str().unwrap();
+    assert!(message.contains("old_a, old_b, old_c"), "{}", message);
+    assert!(!message.contains("used") && !message.contains("main"));
+}
+
+#[test]
+fn test_sus_flags_low_maintainability_file() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let mut sprawl = String::from("def sprawl(a, b, c):\n");
+    for i in 0..300 {
+        sprawl.push_str(&format!(
+            "    if a > {i} and b < {i} or c == {i}:\n        a = a * b + c - {i}\n"
+        ));
+    }
+    create_sample_python_file(temp_dir.path(), "sprawl.py", &sprawl);
+    create_sample_python_file(temp_dir.path(), "tidy.py", "def tidy(x):\n    return x\n");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(temp_dir.path(), &["sus", "sprawl.py", "--format", "json"]);
+    assert!(output.status.success(), "sus failed: {}", stderr(&output));
+    assert!(
+        stdout(&output).contains("\"maintainability\""),
+        "no maintainability warning: {}",
+        stdout(&output)
+    );
+
+    let output = run_mu(temp_dir.path(), &["sus", "tidy.py", "--format", "json"]);
+    assert!(!stdout(&output).contains("\"maintainability\""));
+}
+
+#[test]
+fn test_sus_flags_god_class_and_large_file() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let mut source = String::from("class Big:\n");
+    for i in 0..6 {
+        source.push_str(&format!("    def m{i}(self):\n        return {i}\n"));
+    }
+    for i in 0..3 {
+        source.push_str(&format!("def f{i}():\n    return {i}\n"));
+    }
+    create_sample_python_file(temp_dir.path(), "big.py", &source);
+    fs::write(
+        temp_dir.path().join(".murc.toml"),
+        "[sus]\nmax_methods = 5\nmax_functions = 8\nmax_lines = 10\n",
+    )
+    .expect("Failed to write config");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(temp_dir.path(), &["sus", "big.py", "--format", "json"]);
+    assert!(output.status.success(), "sus failed: {}", stderr(&output));
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let message = |category: &str| -> String {
+        json["warnings"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|w| w["category"] == category)
+            .and_then(|w| w["message"].as_str())
+            .unwrap_or_else(|| panic!("no {} warning: {}", category, json))
+            .to_string()
+    };
+    assert_eq!(message("god class"), "Big has 6 methods (limit 5)");
+    let large = message("large file");
+    assert!(
+        large.starts_with("File has 9 functions (limit 8) and ")
+            && large.ends_with("lines (limit 10)"),
+        "{}",
+        large
+    );
+
+    // Default thresholds leave a file this size alone
+    fs::remove_file(temp_dir.path().join(".murc.toml")).unwrap();
+    let output = run_mu(temp_dir.path(), &["sus", "big.py", "--format", "json"]);
+    let stdout_str = stdout(&output);
+    assert!(!stdout_str.contains("god class") && !stdout_str.contains("large file"));
+}
+
+#[test]
+fn test_vibe_reports_naming_violations_and_fails() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    create_sample_python_file(
+        temp_dir.path(),
+        "app.py",
+        "def getUser():\n    return 1\n\nclass user_store:\n    pass\n",
+    );
+    create_sample_python_file(temp_dir.path(), "tidy.py", "def tidy(x):\n    return x\n");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    let output = run_mu(temp_dir.path(), &["vibe", "app.py", "--format", "json"]);
+    assert_eq!(output.status.code(), Some(1), "{}", stderr(&output));
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    let violations: Vec<(u64, &str, &str, &str)> = json["violations"]
+        .as_array()
+        .expect("violations array")
+        .iter()
+        .map(|v| {
+            (
+                v["line"].as_u64().unwrap(),
+                v["symbol"].as_str().unwrap(),
+                v["expected_convention"].as_str().unwrap(),
+                v["actual"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        violations,
+        vec![
+            (1, "getUser", "snake_case", "camelCase"),
+            (4, "user_store", "PascalCase", "snake_case"),
+        ]
+    );
+
+    let output = run_mu(temp_dir.path(), &["vibe", "tidy.py", "--format", "json"]);
+    assert!(output.status.success(), "{}", stdout(&output));
+}
+
+#[test]
+fn test_fail_on_sets_exit_code() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    create_sample_python_file(
+        temp_dir.path(),
+        "app.py",
+        "import os\n\ndef getUser():\n    return 1\n",
+    );
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(output.status.success(), "bootstrap should succeed");
+
+    // The unused import is a warning; sus only fails when asked to
+    let code = |args: &[&str]| run_mu(temp_dir.path(), args).status.code();
+    assert_eq!(code(&["sus", "app.py"]), Some(0));
+    assert_eq!(code(&["sus", "app.py", "--fail-on", "error"]), Some(0));
+    assert_eq!(code(&["sus", "app.py", "--fail-on", "warn"]), Some(1));
+
+    // Naming violations are errors, so vibe fails by default
+    assert_eq!(code(&["vibe", "app.py"]), Some(1));
+
+    let output = run_mu(temp_dir.path(), &["sus", "app.py", "--fail-on", "loud"]);
+    assert!(!output.status.success());
+    assert!(
+        stderr(&output).contains("possible values"),
+        "{}",
+        stderr(&output)
+    );
+}
+
+#[test]
+fn test_bootstrap_skips_minified_bundle() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_sample_project(temp_dir.path());
+    fs::write(
+        temp_dir.path().join("vendor.ts"),
+        format!(
+            "export function a(){{return {}}}\n",
+            "x&&y||".repeat(2000) + "z"
+        ),
+    )
+    .expect("Failed to write bundle");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--format", "json"]);
+    assert!(
+        output.status.success(),
+        "bootstrap failed: {}",
+        stderr(&output)
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["generated"][0]["path"], "vendor.ts");
+    assert_eq!(json["generated"][0]["reason"], "minified");
+
+    let output = run_mu(temp_dir.path(), &["read", "fn:vendor.ts:a"]);
+    assert!(!output.status.success(), "bundle should not be indexed");
+}
+
+#[test]
+fn test_stale_is_clean_after_bootstrap() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    setup_sample_project(temp_dir.path());
+
+    let output = run_mu(temp_dir.path(), &["bootstrap", "--no-embed"]);
+    assert!(
+        output.status.success(),
+        "bootstrap failed: {}",
+        stderr(&output)
+    );
+
+    let output = run_mu(temp_dir.path(), &["stale", "--format", "json"]);
+    assert!(output.status.success(), "stale failed: {}", stderr(&output));
+    let report: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(report["recommendation"], "up_to_date", "{}", report);
+    assert_eq!(report["added"], serde_json::json!([]));
+    assert_eq!(report["changed"], serde_json::json!([]));
+    assert_eq!(report["deleted"], serde_json::json!([]));
+}
+
+#[test]
+fn test_backup_and_restore_round_trip() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    let dir = temp_dir.path();
+    fs::write(dir.join("app.py"), "def keep():\n    pass\n").expect("Failed to write python file");
+
+    let output = run_mu(dir, &["bootstrap"]);
+    assert!(
+        output.status.success(),
+        "bootstrap failed: {}",
+        stderr(&output)
+    );
+
+    let output = run_mu(dir, &["backup", "snapshot"]);
+    assert!(
+        output.status.success(),
+        "backup failed: {}",
+        stderr(&output)
+    );
+    assert!(dir.join("snapshot").join("schema.sql").exists());
+
+    // Rebuild with different code, then recover the backed-up graph
+    fs::write(dir.join("app.py"), "def replaced():\n    pass\n")
+        .expect("Failed to write python file");
+    let output = run_mu(dir, &["bootstrap", "--force"]);
+    assert!(
+        output.status.success(),
+        "rebuild failed: {}",
+        stderr(&output)
+    );
+
+    let output = run_mu(dir, &["restore", "snapshot", "--yes", "--format", "json"]);
+    assert!(
+        output.status.success(),
+        "restore failed: {}",
+        stderr(&output)
+    );
+    let report: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(report["action"], "restore");
+
+    let output = run_mu(
+        dir,
+        &["query", "SELECT name FROM functions", "--format", "json"],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["rows"], serde_json::json!([["keep"]]));
+}
+
+#[test]
+fn test_zen_compact_keeps_graph() {
+    let temp_dir = TempDir::new().expect("Failed to create temp dir");
+    fs::write(temp_dir.path().join("app.py"), "def keep():\n    pass\n")
+        .expect("Failed to write python file");
+
+    let output = run_mu(temp_dir.path(), &["bootstrap"]);
+    assert!(
+        output.status.success(),
+        "bootstrap failed: {}",
+        stderr(&output)
+    );
+
+    let output = run_mu(temp_dir.path(), &["zen", "--compact", "--format", "json"]);
+    assert!(
+        output.status.success(),
+        "compact failed: {}",
+        stderr(&output)
+    );
+    let stats: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert!(stats["bytes_before"].as_u64().unwrap() > 0);
+    assert!(stats["bytes_after"].as_u64().unwrap() > 0);
+
+    let output = run_mu(
+        temp_dir.path(),
+        &["query", "SELECT name FROM functions", "--format", "json"],
+    );
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&output)).expect("Should be valid JSON");
+    assert_eq!(json["rows"], serde_json::json!([["keep"]]));
+}
@@ -501,6 +501,10 @@ fn diff_single_module(base: &ModuleDef, head: &ModuleDef) -> Vec<EntityChange> {
 }
 
 /// Compute semantic diff between two sets of modules.
+///
+/// Modules are diffed in parallel, one task per path. Changes come out
+/// grouped by path in sorted order, each module's own change first and its
+/// entity changes sorted by name, so the result is the same on every run.
 pub fn semantic_diff_modules(
     base_modules: &[ModuleDef],
     head_modules: &[ModuleDef],
@@ -514,77 +518,91 @@ pub fn semantic_diff_modules(
     let head_by_path: HashMap<&str, &ModuleDef> =
         head_modules.iter().map(|m| (m.path.as_str(), m)).collect();
 
-    let base_paths: HashSet<&str> = base_by_path.keys().copied().collect();
-    let head_paths: HashSet<&str> = head_by_path.keys().copied().collect();
-
-    // Added modules
-    for path in head_paths.difference(&base_paths) {
-        let module = head_by_path[*path];
-        let mut change = EntityChange::create(
-            ChangeType::Added,
-            EntityType::Module,
-            module.name.clone(),
-            (*path).to_string(),
-        );
+    let mut paths: Vec<&str> = base_by_path
+        .keys()
+        .chain(head_by_path.keys())
+        .copied()
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    paths.sort_unstable();
 
-        let func_count = module.functions.len();
-        let class_count = module.classes.len();
-        if func_count > 0 || class_count > 0 {
-            change.details = Some(format!("{} functions, {} classes", func_count, class_count));
-        }
+    let module_changes: Vec<Vec<EntityChange>> = paths
+        .par_iter()
+        .map(
+            |path| match (base_by_path.get(path), head_by_path.get(path)) {
+                (Some(base), Some(head)) => diff_common_module(base, head),
+                (None, Some(head)) => vec![added_module(head)],
+                (Some(base), None) => vec![removed_module(base)],
+                (None, None) => unreachable!("path comes from one of the indexes"),
+            },
+        )
+        .collect();
 
+    for change in module_changes.into_iter().flatten() {
         result.add_change(change);
     }
 
-    // Removed modules (breaking)
-    for path in base_paths.difference(&head_paths) {
-        let module = base_by_path[*path];
-        let change = EntityChange::create(
-            ChangeType::Removed,
-            EntityType::Module,
-            module.name.clone(),
-            (*path).to_string(),
-        )
-        .mark_breaking();
+    result.finalize(start.elapsed().as_secs_f64() * 1000.0);
+    result
+}
 
-        result.add_change(change);
-    }
+/// Change for a module that only exists in head.
+fn added_module(module: &ModuleDef) -> EntityChange {
+    let mut change = EntityChange::create(
+        ChangeType::Added,
+        EntityType::Module,
+        module.name.clone(),
+        module.path.clone(),
+    );
 
-    // Common modules - diff in parallel
-    let common_paths: Vec<&str> = base_paths.intersection(&head_paths).copied().collect();
+    let func_count = module.functions.len();
+    let class_count = module.classes.len();
+    if func_count > 0 || class_count > 0 {
+        change.details = Some(format!("{} functions, {} classes", func_count, class_count));
+    }
+    change
+}
 
-    let module_changes: Vec<Vec<EntityChange>> = common_paths
-        .par_iter()
-        .map(|path| {
-            let base = base_by_path[*path];
-            let head = head_by_path[*path];
-            diff_single_module(base, head)
-        })
-        .collect();
+/// Change for a module that only exists in base (breaking).
+fn removed_module(module: &ModuleDef) -> EntityChange {
+    EntityChange::create(
+        ChangeType::Removed,
+        EntityType::Module,
+        module.name.clone(),
+        module.path.clone(),
+    )
+    .mark_breaking()
+}
 
-    // Collect changes from modified modules
-    for (path, changes) in common_paths.iter().zip(module_changes.into_iter()) {
-        if !changes.is_empty() {
-            // Add module-level modified change
-            let base = base_by_path[*path];
-            let mut module_change = EntityChange::create(
-                ChangeType::Modified,
-                EntityType::Module,
-                base.name.clone(),
-                (*path).to_string(),
-            );
-            module_change.details = Some(format!("{} entity changes", changes.len()));
-            result.add_change(module_change);
-
-            // Add all entity changes
-            for change in changes {
-                result.add_change(change);
-            }
-        }
+/// Changes for a module present on both sides: a module-level modified
+/// change followed by its entity changes in name order, or nothing.
+fn diff_common_module(base: &ModuleDef, head: &ModuleDef) -> Vec<EntityChange> {
+    let mut changes = diff_single_module(base, head);
+    if changes.is_empty() {
+        return changes;
     }
+    changes.sort_by(|a, b| {
+        (a.full_name(), &a.entity_type, &a.change_type, &a.details).cmp(&(
+            b.full_name(),
+            &b.entity_type,
+            &b.change_type,
+            &b.details,
+        ))
+    });
+
+    let mut module_change = EntityChange::create(
+        ChangeType::Modified,
+        EntityType::Module,
+        base.name.clone(),
+        head.path.clone(),
+    );
+    module_change.details = Some(format!("{} entity changes", changes.len()));
 
-    result.finalize(start.elapsed().as_secs_f64() * 1000.0);
-    result
+    let mut all = Vec::with_capacity(changes.len() + 1);
+    all.push(module_change);
+    all.extend(changes);
+    all
 }
 
 /// Compute semantic diff between two sets of modules (wrapper for API).
@@ -650,7 +668,6 @@ pub fn semantic_diff_files(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::types::ImportDef;
 
     fn make_param(name: &str, type_ann: Option<&str>, default: Option<&str>) -> ParameterDef {
         ParameterDef {
@@ -681,8 +698,11 @@ mod tests {
             body_complexity: 1,
             body_source: None,
             call_sites: vec![],
+            props: vec![],
             start_line: 0,
             end_line: 0,
+            start_column: 0,
+            end_column: 0,
         }
     }
 
@@ -696,6 +716,8 @@ mod tests {
             docstring: None,
             start_line: 0,
             end_line: 0,
+            start_column: 0,
+            end_column: 0,
             referenced_types: vec![],
         }
     }
@@ -712,10 +734,12 @@ mod tests {
             language: "python".to_string(),
             imports: vec![],
             classes,
+            enums: vec![],
             functions,
             module_docstring: None,
             total_lines: 0,
             namespace: None,
+            syntax_errors: vec![],
         }
     }
 
@@ -1034,4 +1058,57 @@ mod tests {
         let class_changes = result.filter_entity_type("class");
         assert_eq!(class_changes.len(), 1);
     }
+
+    #[test]
+    fn test_diff_order_is_deterministic() {
+        let functions = |names: &[&str]| -> Vec<FunctionDef> {
+            names
+                .iter()
+                .map(|n| make_function(n, vec![], None))
+                .collect()
+        };
+        let mut base = Vec::new();
+        let mut head = Vec::new();
+        for i in (0..40).rev() {
+            let path = format!("src/m{:02}.py", i);
+            base.push(make_module(
+                "m",
+                &path,
+                functions(&["keep", "zeta", "alpha"]),
+                vec![],
+            ));
+            head.push(make_module(
+                "m",
+                &path,
+                functions(&["keep", "omega", "beta"]),
+                vec![],
+            ));
+        }
+        base.push(make_module("gone", "src/gone.py", vec![], vec![]));
+        head.push(make_module("new", "src/new.py", vec![], vec![]));
+
+        let order = |result: &SemanticDiffResult| -> Vec<(String, String, String)> {
+            result
+                .changes
+                .iter()
+                .map(|c| (c.file_path.clone(), c.full_name(), c.change_type.clone()))
+                .collect()
+        };
+        let first = order(&semantic_diff_modules(&base, &head));
+        for _ in 0..5 {
+            assert_eq!(order(&semantic_diff_modules(&base, &head)), first);
+        }
+
+        // Grouped by path in sorted order, module change first, then by name
+        let paths: Vec<&str> = first.iter().map(|(p, _, _)| p.as_str()).collect();
+        let mut sorted = paths.clone();
+        sorted.sort();
+        assert_eq!(paths, sorted);
+        let m00: Vec<&str> = first
+            .iter()
+            .filter(|(p, _, _)| p == "src/m00.py")
+            .map(|(_, n, _)| n.as_str())
+            .collect();
+        assert_eq!(m00, vec!["m", "alpha", "beta", "omega", "zeta"]);
+    }
 }
@@ -0,0 +1,207 @@
+//! JSON Lines exporter.
+//!
+//! Writes one JSON object per line, each a node or an edge, straight to a
+//! writer. Nothing is buffered beyond the current record, so memory stays
+//! flat however large the graph is.
+//!
+//! Node IDs follow the graph convention (`mod:path`, `cls:path:Name`,
+//! `fn:path:name`, `fn:path:Class.method`). `contains` edges link those IDs;
+//! `imports` and `inherits` edges point at the names as written in source,
+//! since resolving them needs the whole project.
+
+use std::io::{self, Write};
+
+use serde::Serialize;
+
+use crate::types::{FunctionDef, ModuleDef};
+
+/// One line of output.
+#[derive(Serialize)]
+#[serde(tag = "kind", rename_all = "lowercase")]
+enum Record<'a> {
+    Node {
+        id: String,
+        #[serde(rename = "type")]
+        node_type: &'static str,
+        name: &'a str,
+        file_path: &'a str,
+        line_start: u32,
+        line_end: u32,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        complexity: Option<u32>,
+    },
+    Edge {
+        source: String,
+        target: String,
+        #[serde(rename = "type")]
+        edge_type: &'static str,
+    },
+}
+
+fn write_record<W: Write>(w: &mut W, record: &Record) -> io::Result<()> {
+    serde_json::to_writer(&mut *w, record)?;
+    w.write_all(b"\n")
+}
+
+fn function_node<'a>(id: String, func: &'a FunctionDef, path: &'a str) -> Record<'a> {
+    Record::Node {
+        id,
+        node_type: "function",
+        name: &func.name,
+        file_path: path,
+        line_start: func.start_line,
+        line_end: func.end_line,
+        complexity: Some(func.body_complexity),
+    }
+}
+
+fn contains<'a>(source: &str, target: &str) -> Record<'a> {
+    Record::Edge {
+        source: source.to_string(),
+        target: target.to_string(),
+        edge_type: "contains",
+    }
+}
+
+/// Stream a module's nodes and edges to `w` as JSON Lines.
+///
+/// Emits the module node, its imports, then each class with its bases and
+/// methods, then free functions.
+pub fn export_stream<W: Write>(module: &ModuleDef, w: &mut W) -> io::Result<()> {
+    let path = module.path.as_str();
+    let module_id = format!("mod:{}", path);
+
+    write_record(
+        w,
+        &Record::Node {
+            id: module_id.clone(),
+            node_type: "module",
+            name: &module.name,
+            file_path: path,
+            line_start: 1,
+            line_end: module.total_lines,
+            complexity: None,
+        },
+    )?;
+
+    for import in &module.imports {
+        write_record(
+            w,
+            &Record::Edge {
+                source: module_id.clone(),
+                target: import.module.clone(),
+                edge_type: "imports",
+            },
+        )?;
+    }
+
+    for class in &module.classes {
+        let class_id = format!("cls:{}:{}", path, class.name);
+        write_record(
+            w,
+            &Record::Node {
+                id: class_id.clone(),
+                node_type: "class",
+                name: &class.name,
+                file_path: path,
+                line_start: class.start_line,
+                line_end: class.end_line,
+                complexity: None,
+            },
+        )?;
+        write_record(w, &contains(&module_id, &class_id))?;
+
+        for base in &class.bases {
+            write_record(
+                w,
+                &Record::Edge {
+                    source: class_id.clone(),
+                    target: base.clone(),
+                    edge_type: "inherits",
+                },
+            )?;
+        }
+
+        for method in &class.methods {
+            let method_id = format!("fn:{}:{}.{}", path, class.name, method.name);
+            write_record(w, &function_node(method_id.clone(), method, path))?;
+            write_record(w, &contains(&class_id, &method_id))?;
+        }
+    }
+
+    for func in &module.functions {
+        let func_id = format!("fn:{}:{}", path, func.name);
+        write_record(w, &function_node(func_id.clone(), func, path))?;
+        write_record(w, &contains(&module_id, &func_id))?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{ClassDef, ImportDef};
+
+    #[test]
+    fn test_export_stream_one_record_per_line() {
+        let module = ModuleDef {
+            name: "app".to_string(),
+            path: "src/app.py".to_string(),
+            language: "python".to_string(),
+            total_lines: 20,
+            imports: vec![ImportDef {
+                module: "os".to_string(),
+                ..Default::default()
+            }],
+            classes: vec![ClassDef {
+                name: "App".to_string(),
+                bases: vec!["Base".to_string()],
+                methods: vec![FunctionDef {
+                    name: "run".to_string(),
+                    body_complexity: 2,
+                    ..Default::default()
+                }],
+                ..Default::default()
+            }],
+            functions: vec![FunctionDef {
+                name: "main".to_string(),
+                ..Default::default()
+            }],
+            ..Default::default()
+        };
+
+        let mut buffer = Vec::new();
+        export_stream(&module, &mut buffer).unwrap();
+        let lines: Vec<serde_json::Value> = String::from_utf8(buffer)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+
+        let summary: Vec<(&str, &str)> = lines
+            .iter()
+            .map(|r| {
+                let kind = r["kind"].as_str().unwrap();
+                let label = if kind == "node" { &r["id"] } else { &r["type"] };
+                (kind, label.as_str().unwrap())
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("node", "mod:src/app.py"),
+                ("edge", "imports"),
+                ("node", "cls:src/app.py:App"),
+                ("edge", "contains"),
+                ("edge", "inherits"),
+                ("node", "fn:src/app.py:App.run"),
+                ("edge", "contains"),
+                ("node", "fn:src/app.py:main"),
+                ("edge", "contains"),
+            ]
+        );
+        assert_eq!(lines[5]["complexity"], 2);
+        assert_eq!(lines[4]["target"], "Base");
+    }
+}
@@ -1,5 +1,7 @@
 //! Export module for various output formats.
 
 pub mod json;
+pub mod jsonl;
 pub mod markdown;
 pub mod mu_format;
+pub mod sarif;
@@ -0,0 +1,253 @@
+//! SARIF 2.1.0 exporter for secret findings.
+//!
+//! Produces a single-run SARIF log that GitHub code scanning and other SARIF
+//! viewers can render inline. Each pattern name becomes a rule; each match
+//! becomes a result pointing at its file, line and column. Severity maps to the
+//! SARIF `level` and to GitHub's numeric `security-severity` property.
+
+use std::collections::HashMap;
+
+use serde::Serialize;
+
+use crate::types::{SecretMatch, Severity};
+
+const SCHEMA: &str = "https://json.schemastore.org/sarif-2.1.0.json";
+
+#[derive(Serialize)]
+struct Log<'a> {
+    #[serde(rename = "$schema")]
+    schema: &'static str,
+    version: &'static str,
+    runs: [Run<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct Run<'a> {
+    tool: Tool<'a>,
+    results: Vec<SarifResult<'a>>,
+}
+
+#[derive(Serialize)]
+struct Tool<'a> {
+    driver: Driver<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Driver<'a> {
+    name: &'static str,
+    version: &'static str,
+    information_uri: &'static str,
+    rules: Vec<Rule<'a>>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Rule<'a> {
+    id: &'a str,
+    name: &'a str,
+    short_description: Message,
+    default_configuration: Configuration,
+    properties: RuleProperties,
+}
+
+#[derive(Serialize)]
+struct Configuration {
+    level: &'static str,
+}
+
+#[derive(Serialize)]
+struct RuleProperties {
+    tags: [&'static str; 2],
+    #[serde(rename = "security-severity")]
+    security_severity: &'static str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SarifResult<'a> {
+    rule_id: &'a str,
+    rule_index: usize,
+    level: &'static str,
+    message: Message,
+    locations: [Location<'a>; 1],
+}
+
+#[derive(Serialize)]
+struct Message {
+    text: String,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Location<'a> {
+    physical_location: PhysicalLocation<'a>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct PhysicalLocation<'a> {
+    artifact_location: ArtifactLocation<'a>,
+    region: Region,
+}
+
+#[derive(Serialize)]
+struct ArtifactLocation<'a> {
+    uri: &'a str,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Region {
+    start_line: u32,
+    start_column: u32,
+    byte_offset: usize,
+    byte_length: usize,
+}
+
+/// SARIF result level for a severity.
+fn level(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical | Severity::High => "error",
+        Severity::Medium => "warning",
+        Severity::Low => "note",
+    }
+}
+
+/// GitHub's `security-severity` score (0.0-10.0) for a severity.
+fn security_severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Critical => "9.5",
+        Severity::High => "8.0",
+        Severity::Medium => "5.5",
+        Severity::Low => "2.0",
+    }
+}
+
+/// Export secret findings as a SARIF 2.1.0 log.
+///
+/// `findings` pairs each file path (used as the artifact URI) with the
+/// matches found in it. Rules appear in the order their pattern is first
+/// seen; results keep the input order.
+pub fn export(findings: &[(String, Vec<SecretMatch>)]) -> String {
+    let mut rules = Vec::new();
+    let mut rule_index: HashMap<&str, usize> = HashMap::new();
+    let mut results = Vec::new();
+
+    for (path, matches) in findings {
+        for secret in matches {
+            let id = secret.pattern_name.as_str();
+            let index = *rule_index.entry(id).or_insert_with(|| {
+                rules.push(Rule {
+                    id,
+                    name: id,
+                    short_description: Message {
+                        text: format!("Hardcoded {}", id.replace('_', " ")),
+                    },
+                    default_configuration: Configuration {
+                        level: level(secret.severity),
+                    },
+                    properties: RuleProperties {
+                        tags: ["security", "secret"],
+                        security_severity: security_severity(secret.severity),
+                    },
+                });
+                rules.len() - 1
+            });
+
+            results.push(SarifResult {
+                rule_id: id,
+                rule_index: index,
+                level: level(secret.severity),
+                message: Message {
+                    text: format!("Possible {} found", id.replace('_', " ")),
+                },
+                locations: [Location {
+                    physical_location: PhysicalLocation {
+                        artifact_location: ArtifactLocation { uri: path },
+                        region: Region {
+                            start_line: secret.line,
+                            start_column: secret.column,
+                            byte_offset: secret.start,
+                            byte_length: secret.end.saturating_sub(secret.start),
+                        },
+                    },
+                }],
+            });
+        }
+    }
+
+    let log = Log {
+        schema: SCHEMA,
+        version: "2.1.0",
+        runs: [Run {
+            tool: Tool {
+                driver: Driver {
+                    name: "mu",
+                    version: env!("CARGO_PKG_VERSION"),
+                    information_uri: "https://github.com/0ximu/mu",
+                    rules,
+                },
+            },
+            results,
+        }],
+    };
+    serde_json::to_string_pretty(&log).expect("SARIF log is plain data and always serializes")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_export_sarif() {
+        let findings = vec![
+            (
+                "src/config.py".to_string(),
+                vec![
+                    SecretMatch::new("aws_access_key_id".to_string(), 10, 30, 2, 5),
+                    SecretMatch::new("generic_secret".to_string(), 50, 70, 4, 1),
+                ],
+            ),
+            (
+                "src/other.py".to_string(),
+                vec![SecretMatch::new(
+                    "aws_access_key_id".to_string(),
+                    0,
+                    20,
+                    1,
+                    1,
+                )],
+            ),
+        ];
+
+        let sarif: serde_json::Value = serde_json::from_str(&export(&findings)).unwrap();
+        assert_eq!(sarif["version"], "2.1.0");
+
+        let run = &sarif["runs"][0];
+        let rules = run["tool"]["driver"]["rules"].as_array().unwrap();
+        assert_eq!(rules.len(), 2);
+        assert_eq!(rules[0]["id"], "aws_access_key_id");
+        assert_eq!(rules[1]["defaultConfiguration"]["level"], "warning");
+
+        let results = run["results"].as_array().unwrap();
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[2]["ruleIndex"], 0);
+        assert_eq!(results[0]["level"], "error");
+        let location = &results[0]["locations"][0]["physicalLocation"];
+        assert_eq!(location["artifactLocation"]["uri"], "src/config.py");
+        assert_eq!(location["region"]["startLine"], 2);
+        assert_eq!(location["region"]["startColumn"], 5);
+        assert_eq!(location["region"]["byteLength"], 20);
+    }
+
+    #[test]
+    fn test_export_sarif_empty() {
+        let sarif: serde_json::Value = serde_json::from_str(&export(&[])).unwrap();
+        assert!(sarif["runs"][0]["results"].as_array().unwrap().is_empty());
+        assert!(sarif["runs"][0]["tool"]["driver"]["rules"]
+            .as_array()
+            .unwrap()
+            .is_empty());
+    }
+}
@@ -19,11 +19,14 @@
 //! ```
 
 use serde::{Deserialize, Serialize};
+use std::collections::hash_map::DefaultHasher;
+use std::collections::{HashMap, HashSet};
+use std::hash::{Hash, Hasher};
 use std::time::Instant;
 use tree_sitter::{InputEdit, Parser, Point, Tree};
 
 use crate::parser;
-use crate::types::ModuleDef;
+use crate::types::{ClassDef, ModuleDef};
 
 /// Result of an incremental parse operation.
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -338,7 +341,7 @@ fn byte_offset_to_point(source: &str, byte_offset: usize) -> Point {
 }
 
 /// Get the tree-sitter language for a given language identifier.
-fn get_tree_sitter_language(language: &str) -> Result<tree_sitter::Language, String> {
+pub(crate) fn get_tree_sitter_language(language: &str) -> Result<tree_sitter::Language, String> {
     match normalize_language(language) {
         "python" => Ok(tree_sitter_python::LANGUAGE.into()),
         "typescript" => Ok(tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into()),
@@ -347,12 +350,19 @@ fn get_tree_sitter_language(language: &str) -> Result<tree_sitter::Language, Str
         "java" => Ok(tree_sitter_java::LANGUAGE.into()),
         "rust" => Ok(tree_sitter_rust::LANGUAGE.into()),
         "csharp" => Ok(tree_sitter_c_sharp::LANGUAGE.into()),
+        "dart" => Ok(tree_sitter_dart::language()),
+        "scala" => Ok(tree_sitter_scala::LANGUAGE.into()),
+        "kotlin" => Ok(tree_sitter_kotlin_ng::LANGUAGE.into()),
+        "php" => Ok(tree_sitter_php::LANGUAGE_PHP.into()),
+        "ruby" => Ok(tree_sitter_ruby::LANGUAGE.into()),
+        "c" => Ok(tree_sitter_c::LANGUAGE.into()),
+        "cpp" => Ok(tree_sitter_cpp::LANGUAGE.into()),
         lang => Err(format!("Unsupported language: {}", lang)),
     }
 }
 
 /// Normalize language identifier to canonical form.
-fn normalize_language(language: &str) -> &str {
+pub(crate) fn normalize_language(language: &str) -> &str {
     match language.to_lowercase().as_str() {
         "python" | "py" => "python",
         "typescript" | "ts" | "tsx" => "typescript",
@@ -361,10 +371,152 @@ fn normalize_language(language: &str) -> &str {
         "java" => "java",
         "rust" | "rs" => "rust",
         "csharp" | "cs" | "c#" => "csharp",
+        "dart" => "dart",
+        "scala" | "sc" => "scala",
+        "kotlin" | "kt" | "kts" => "kotlin",
+        "php" => "php",
+        "ruby" | "rb" => "ruby",
+        "cpp" | "c++" | "cc" | "cxx" => "cpp",
         _ => language,
     }
 }
 
+/// How a definition changed between two parses of a file.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    Modified,
+}
+
+/// Kind of definition that changed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EntityKind {
+    Class,
+    Function,
+}
+
+/// A class or function that was added, removed or modified.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EntityChange {
+    pub kind: ChangeKind,
+    pub entity: EntityKind,
+    /// Name as used in node IDs: `func`, `Class`, or `Class.method`.
+    pub name: String,
+    /// Line range in the new module, or in the old one for removals.
+    pub start_line: u32,
+    pub end_line: u32,
+}
+
+/// A definition's identity, line range and content fingerprint.
+struct EntityEntry {
+    key: (EntityKind, String, usize),
+    start_line: u32,
+    end_line: u32,
+    fingerprint: u64,
+}
+
+/// Hash of a definition's serialized form, line numbers included.
+fn fingerprint<T: Serialize>(value: &T) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_vec(value)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    hasher.finish()
+}
+
+/// Flatten a module into classes, free functions and methods.
+///
+/// A class's fingerprint excludes its methods, so editing one method does not
+/// mark the whole class as modified. Repeated names (overloads) are told
+/// apart by their occurrence index.
+fn module_entities(module: &ModuleDef) -> Vec<EntityEntry> {
+    let mut entries = Vec::new();
+    let mut seen: HashMap<(EntityKind, String), usize> = HashMap::new();
+    let mut push = |entity: EntityKind, name: String, lines: (u32, u32), fingerprint: u64| {
+        let occurrence = seen.entry((entity, name.clone())).or_default();
+        entries.push(EntityEntry {
+            key: (entity, name, *occurrence),
+            start_line: lines.0,
+            end_line: lines.1,
+            fingerprint,
+        });
+        *occurrence += 1;
+    };
+
+    for class in &module.classes {
+        let shell = ClassDef {
+            methods: Vec::new(),
+            ..class.clone()
+        };
+        push(
+            EntityKind::Class,
+            class.name.clone(),
+            (class.start_line, class.end_line),
+            fingerprint(&shell),
+        );
+        for method in &class.methods {
+            push(
+                EntityKind::Function,
+                format!("{}.{}", class.name, method.name),
+                (method.start_line, method.end_line),
+                fingerprint(method),
+            );
+        }
+    }
+    for func in &module.functions {
+        push(
+            EntityKind::Function,
+            func.name.clone(),
+            (func.start_line, func.end_line),
+            fingerprint(func),
+        );
+    }
+
+    entries
+}
+
+/// Compare two parses of a file and list the definitions that changed.
+///
+/// A definition counts as modified when its content or line range differs,
+/// since stored nodes carry line numbers. Unchanged definitions are omitted,
+/// so callers can leave their nodes and edges in place. Additions and
+/// modifications come first in new-module order, then removals.
+pub fn changed_entities(old: &ModuleDef, new: &ModuleDef) -> Vec<EntityChange> {
+    let old_entries = module_entities(old);
+    let new_entries = module_entities(new);
+    let old_by_key: HashMap<_, _> = old_entries.iter().map(|e| (&e.key, e)).collect();
+    let new_keys: HashSet<_> = new_entries.iter().map(|e| &e.key).collect();
+
+    let change = |kind: ChangeKind, entry: &EntityEntry| EntityChange {
+        kind,
+        entity: entry.key.0,
+        name: entry.key.1.clone(),
+        start_line: entry.start_line,
+        end_line: entry.end_line,
+    };
+
+    let mut changes = Vec::new();
+    for entry in &new_entries {
+        match old_by_key.get(&entry.key) {
+            None => changes.push(change(ChangeKind::Added, entry)),
+            Some(previous) if previous.fingerprint != entry.fingerprint => {
+                changes.push(change(ChangeKind::Modified, entry))
+            }
+            Some(_) => {}
+        }
+    }
+    for entry in &old_entries {
+        if !new_keys.contains(&entry.key) {
+            changes.push(change(ChangeKind::Removed, entry));
+        }
+    }
+
+    changes
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -415,6 +567,13 @@ mod tests {
         assert!(get_tree_sitter_language("java").is_ok());
         assert!(get_tree_sitter_language("rust").is_ok());
         assert!(get_tree_sitter_language("csharp").is_ok());
+        assert!(get_tree_sitter_language("dart").is_ok());
+        assert!(get_tree_sitter_language("scala").is_ok());
+        assert!(get_tree_sitter_language("kotlin").is_ok());
+        assert!(get_tree_sitter_language("php").is_ok());
+        assert!(get_tree_sitter_language("ruby").is_ok());
+        assert!(get_tree_sitter_language("c").is_ok());
+        assert!(get_tree_sitter_language("cpp").is_ok());
         assert!(get_tree_sitter_language("unknown").is_err());
     }
 
@@ -525,4 +684,47 @@ mod tests {
         let parser = IncrementalParser::new("def hello(\n    pass", "python", "test.py").unwrap();
         assert!(parser.tree.as_ref().unwrap().root_node().has_error());
     }
+
+    fn parse_python(source: &str) -> ModuleDef {
+        parser::parse_source(source, "app.py", "python")
+            .module
+            .unwrap()
+    }
+
+    #[test]
+    fn test_changed_entities() {
+        let old = parse_python(
+            "class Cart:\n    def add(self, item):\n        return item\n\n    def total(self):\n        return 0\n\ndef helper():\n    return 1\n\ndef legacy():\n    pass\n",
+        );
+        assert!(changed_entities(&old, &old).is_empty());
+
+        // Edit one method body, drop `legacy`, add `fresh` in its place
+        let new = parse_python(
+            "class Cart:\n    def add(self, item):\n        return [item]\n\n    def total(self):\n        return 0\n\ndef helper():\n    return 1\n\ndef fresh():\n    pass\n",
+        );
+        let changes = changed_entities(&old, &new);
+        let changes: Vec<(ChangeKind, &str)> =
+            changes.iter().map(|c| (c.kind, c.name.as_str())).collect();
+        assert_eq!(
+            changes,
+            vec![
+                (ChangeKind::Modified, "Cart.add"),
+                (ChangeKind::Added, "fresh"),
+                (ChangeKind::Removed, "legacy"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_changed_entities_line_shift() {
+        let old = parse_python("def a():\n    pass\n\ndef b():\n    pass\n");
+        let new = parse_python("def a():\n    x = 1\n    pass\n\ndef b():\n    pass\n");
+        let changes = changed_entities(&old, &new);
+
+        // `b` is textually identical but moved down a line
+        assert_eq!(changes.len(), 2);
+        assert!(changes.iter().all(|c| c.kind == ChangeKind::Modified));
+        assert_eq!(changes[1].name, "b");
+        assert_eq!((changes[1].start_line, changes[1].end_line), (5, 6));
+    }
 }
@@ -7,9 +7,9 @@
 //!
 //! - **Parallel parsing**: Parse multiple files concurrently using Rayon
 //! - **Multi-language support**: Python, TypeScript, JavaScript, Go, Java, Rust, C#
-//! - **Cyclomatic complexity**: Calculate code complexity metrics
+//! - **Complexity**: Calculate cyclomatic, cognitive, Halstead and maintainability metrics
 //! - **Secret redaction**: Detect and redact sensitive information
-//! - **Multiple export formats**: MU, JSON, Markdown
+//! - **Multiple export formats**: MU, JSON, Markdown, SARIF for secret findings
 
 pub mod differ;
 pub mod exporter;
@@ -52,6 +52,26 @@ pub fn parse_file(source: &str, file_path: &str, language: &str) -> ParseResult
     parser::parse_source(source, file_path, language)
 }
 
+/// Parse a single file from source, timing the parse.
+///
+/// # Arguments
+///
+/// * `source` - Source code content
+/// * `file_path` - Path to the file (for naming)
+/// * `language` - Language identifier
+///
+/// # Returns
+///
+/// The same ParseResult as [`parse_file`], plus the parse duration, the
+/// number of entities extracted and the source size.
+pub fn parse_file_with_stats(
+    source: &str,
+    file_path: &str,
+    language: &str,
+) -> (ParseResult, ParseStats) {
+    parser::parse_source_with_stats(source, file_path, language)
+}
+
 /// Calculate cyclomatic complexity for a code snippet.
 ///
 /// # Arguments
@@ -61,11 +81,73 @@ pub fn parse_file(source: &str, file_path: &str, language: &str) -> ParseResult
 ///
 /// # Returns
 ///
-/// Complexity score (minimum 1).
+/// Complexity score (minimum 1). Pass `"generic"` as the language to count
+/// branching keywords and operators for languages MU doesn't parse.
 pub fn calculate_complexity(source: &str, language: &str) -> u32 {
     reducer::complexity::calculate(source, language)
 }
 
+/// Calculate cyclomatic complexity, rejecting unsupported languages.
+///
+/// # Arguments
+///
+/// * `source` - Source code
+/// * `language` - Language identifier, or `"generic"`
+///
+/// # Returns
+///
+/// Complexity score (minimum 1), or an error naming the unsupported language.
+pub fn try_calculate_complexity(source: &str, language: &str) -> Result<u32, String> {
+    reducer::complexity::try_calculate(source, language)
+}
+
+/// Calculate cognitive complexity for a code snippet.
+///
+/// Unlike cyclomatic complexity, nested control flow costs more than flat
+/// control flow, so deeply nested code scores higher.
+///
+/// # Arguments
+///
+/// * `source` - Source code
+/// * `language` - Language identifier
+///
+/// # Returns
+///
+/// Complexity score (0 for unsupported languages).
+pub fn calculate_cognitive_complexity(source: &str, language: &str) -> u32 {
+    reducer::complexity::calculate_cognitive(source, language)
+}
+
+/// Calculate Halstead metrics for a code snippet.
+///
+/// # Arguments
+///
+/// * `source` - Source code
+/// * `language` - Language identifier
+///
+/// # Returns
+///
+/// Operator/operand counts with volume, difficulty and effort
+/// (all zero for unsupported languages).
+pub fn calculate_halstead(source: &str, language: &str) -> reducer::halstead::HalsteadMetrics {
+    reducer::halstead::calculate(source, language)
+}
+
+/// Calculate the Maintainability Index for a code snippet.
+///
+/// # Arguments
+///
+/// * `source` - Source code
+/// * `language` - Language identifier
+///
+/// # Returns
+///
+/// Score from 0 (hard to maintain) to 100, from Halstead volume, cyclomatic
+/// complexity and lines of code (100 for unsupported languages).
+pub fn calculate_maintainability(source: &str, language: &str) -> f64 {
+    reducer::maintainability::calculate(source, language)
+}
+
 /// Find secrets in text.
 ///
 /// # Arguments
@@ -74,19 +156,13 @@ pub fn calculate_complexity(source: &str, language: &str) -> u32 {
 ///
 /// # Returns
 ///
-/// List of SecretMatch objects.
+/// List of SecretMatch objects, each with the severity of its pattern.
 pub fn find_secrets(text: &str) -> Vec<SecretMatch> {
     security::patterns::find_secrets(text)
         .into_iter()
         .map(|(name, start, end)| {
             let (line, column) = security::redact::position_to_line_col(text, start);
-            SecretMatch {
-                pattern_name: name.to_string(),
-                start,
-                end,
-                line,
-                column,
-            }
+            SecretMatch::new(name.to_string(), start, end, line, column)
         })
         .collect()
 }
@@ -99,9 +175,23 @@ pub fn find_secrets(text: &str) -> Vec<SecretMatch> {
 ///
 /// # Returns
 ///
-/// Text with secrets replaced by [REDACTED].
+/// Text with secrets replaced by [REDACTED]. Lines marked
+/// `mu:allow-secret` are left as they are.
 pub fn redact_secrets(text: &str) -> String {
-    security::redact::redact(text)
+    security::redact::redact_with_allowlist(text, &[])
+}
+
+/// Export secret findings as a SARIF 2.1.0 report.
+///
+/// # Arguments
+///
+/// * `findings` - (file path, matches in that file) pairs, e.g. from [`find_secrets`]
+///
+/// # Returns
+///
+/// SARIF JSON string, ready for GitHub code scanning upload.
+pub fn export_secrets_sarif(findings: &[(String, Vec<SecretMatch>)]) -> String {
+    exporter::sarif::export(findings)
 }
 
 /// Export module to MU format.
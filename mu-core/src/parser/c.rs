@@ -0,0 +1,545 @@
+//! C AST extractor using tree-sitter.
+//!
+//! Function definitions become functions (prototypes are skipped, the
+//! definition is the node callers resolve to). Structs, unions and enums with
+//! a body become `ClassDef`s tagged `struct`/`union`/`enum`, named by their
+//! typedef when there is one. `#include "x.h"` keeps the quoted path and
+//! `#include <x.h>` keeps the angle brackets, so the graph builder can tell
+//! project headers from system ones.
+//!
+//! Declarations inside `#if`/`#ifdef` blocks (include guards included) are
+//! extracted as if the condition held. The declarator, call-site and comment
+//! helpers are shared with the C++ extractor.
+
+use std::path::Path;
+use tree_sitter::{Node, Parser};
+
+use super::helpers::{
+    call_arguments, count_lines, get_end_column, get_end_line, get_node_text, get_start_column,
+    get_start_line, import_span, syntax_errors,
+};
+use crate::reducer::complexity;
+use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
+
+/// Parse C source code.
+pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_c::LANGUAGE.into())
+        .map_err(|e| format!("Failed to set C language: {}", e))?;
+
+    let tree = parser
+        .parse(source, None)
+        .ok_or("Failed to parse C source")?;
+    let root = tree.root_node();
+
+    let mut module = new_module(source, file_path, "c");
+    extract_definitions(&root, source, &mut module);
+
+    module.syntax_errors = syntax_errors(&root, source);
+    Ok(module)
+}
+
+/// An empty `ModuleDef` named after the file stem.
+pub(super) fn new_module(source: &str, file_path: &str, language: &str) -> ModuleDef {
+    let file_name = Path::new(file_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    ModuleDef {
+        name: file_name,
+        path: file_path.to_string(),
+        language: language.to_string(),
+        total_lines: count_lines(source),
+        ..Default::default()
+    }
+}
+
+/// Collect top-level definitions, descending into preprocessor conditionals.
+fn extract_definitions(node: &Node, source: &str, module: &mut ModuleDef) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "preproc_include" => module.imports.extend(extract_include(&child, source)),
+            "function_definition" => {
+                module.functions.push(extract_function(&child, source, "c"));
+            }
+            "type_definition" | "declaration" | "struct_specifier" | "union_specifier"
+            | "enum_specifier" => module.classes.extend(extract_record(&child, source)),
+            kind if is_preproc_block(kind) => extract_definitions(&child, source, module),
+            _ => {}
+        }
+    }
+}
+
+/// Preprocessor blocks whose contents are still top-level declarations.
+pub(super) fn is_preproc_block(kind: &str) -> bool {
+    matches!(
+        kind,
+        "preproc_if" | "preproc_ifdef" | "preproc_else" | "preproc_elif" | "preproc_elifdef"
+    )
+}
+
+/// Extract an `#include` directive.
+pub(super) fn extract_include(node: &Node, source: &str) -> Option<ImportDef> {
+    let path = node.child_by_field_name("path")?;
+    let module = match path.kind() {
+        "string_literal" => get_node_text(&path, source).trim_matches('"').to_string(),
+        // <stdio.h> keeps its brackets to mark it as a system header
+        "system_lib_string" => get_node_text(&path, source).to_string(),
+        _ => return None,
+    };
+
+    // The directive node swallows its line break, so end the span at the path
+    Some(ImportDef {
+        module,
+        end_line: get_end_line(&path),
+        end_column: get_end_column(&path),
+        ..import_span(node)
+    })
+}
+
+/// Extract a struct, union or enum with a body from a typedef, a declaration
+/// (`struct s {...} x;`) or a bare specifier.
+pub(super) fn extract_record(node: &Node, source: &str) -> Option<ClassDef> {
+    let (specifier, typedef_name) = match node.kind() {
+        "type_definition" => (
+            node.child_by_field_name("type")?,
+            node.child_by_field_name("declarator")
+                .map(|d| get_node_text(&d, source).to_string()),
+        ),
+        "declaration" => (node.child_by_field_name("type")?, None),
+        _ => (*node, None),
+    };
+
+    let tag = match specifier.kind() {
+        "struct_specifier" => "struct",
+        "union_specifier" => "union",
+        "enum_specifier" => "enum",
+        _ => return None,
+    };
+    let body = specifier.child_by_field_name("body")?;
+
+    let name = typedef_name.or_else(|| {
+        specifier
+            .child_by_field_name("name")
+            .map(|n| get_node_text(&n, source).to_string())
+    })?;
+
+    let mut class_def = ClassDef {
+        name,
+        decorators: vec![tag.to_string()],
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_comment_doc(node, source),
+        ..Default::default()
+    };
+
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        match member.kind() {
+            "field_declaration" => class_def.attributes.extend(field_names(&member, source)),
+            "enumerator" => {
+                if let Some(name) = member.child_by_field_name("name") {
+                    class_def
+                        .attributes
+                        .push(get_node_text(&name, source).to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+
+    Some(class_def)
+}
+
+/// Names declared by a field declaration (`int a, *b;` yields `a`, `b`).
+pub(super) fn field_names(node: &Node, source: &str) -> Vec<String> {
+    let mut cursor = node.walk();
+    node.children_by_field_name("declarator", &mut cursor)
+        .filter_map(|d| declarator_name(&d, source))
+        .collect()
+}
+
+/// Extract a function definition.
+pub(super) fn extract_function(node: &Node, source: &str, language: &str) -> FunctionDef {
+    let mut func_def = FunctionDef {
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_comment_doc(node, source),
+        ..Default::default()
+    };
+
+    apply_specifiers(node, source, &mut func_def);
+
+    if let Some(declarator) = node.child_by_field_name("declarator") {
+        if let Some(function) = find_function_declarator(&declarator) {
+            if let Some(name) = function.child_by_field_name("declarator") {
+                func_def.name = get_node_text(&name, source).to_string();
+            }
+            if let Some(params) = function.child_by_field_name("parameters") {
+                func_def.parameters = extract_parameters(&params, source);
+            }
+        }
+        func_def.return_type = node
+            .child_by_field_name("type")
+            .map(|t| declared_type(&t, &declarator, source));
+    }
+
+    if let Some(body) = node.child_by_field_name("body") {
+        func_def.body_complexity = complexity::calculate_for_node(&body, source, language);
+        func_def.body_source = Some(get_node_text(&body, source).to_string());
+        func_def.call_sites = extract_call_sites(&body, source);
+    }
+
+    func_def
+}
+
+/// Record `static`/`inline`/`extern` and similar keywords as decorators.
+pub(super) fn apply_specifiers(node: &Node, source: &str, func_def: &mut FunctionDef) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "storage_class_specifier" | "virtual" | "explicit_function_specifier" => {
+                func_def
+                    .decorators
+                    .push(get_node_text(&child, source).to_string());
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Find the `function_declarator` under pointer/reference declarators.
+pub(super) fn find_function_declarator<'a>(node: &Node<'a>) -> Option<Node<'a>> {
+    match node.kind() {
+        "function_declarator" => Some(*node),
+        "pointer_declarator"
+        | "reference_declarator"
+        | "parenthesized_declarator"
+        | "init_declarator" => {
+            let inner = node
+                .child_by_field_name("declarator")
+                .or_else(|| node.named_children(&mut node.walk()).last())?;
+            find_function_declarator(&inner)
+        }
+        _ => None,
+    }
+}
+
+/// The identifier a (possibly pointer or array) declarator introduces.
+pub(super) fn declarator_name(node: &Node, source: &str) -> Option<String> {
+    match node.kind() {
+        "identifier"
+        | "field_identifier"
+        | "type_identifier"
+        | "destructor_name"
+        | "operator_name"
+        | "qualified_identifier" => Some(get_node_text(node, source).to_string()),
+        _ => {
+            let inner = node
+                .child_by_field_name("declarator")
+                .or_else(|| node.named_children(&mut node.walk()).last())?;
+            declarator_name(&inner, source)
+        }
+    }
+}
+
+/// The type text plus one `*`/`&` per pointer or reference declarator level.
+pub(super) fn declared_type(type_node: &Node, declarator: &Node, source: &str) -> String {
+    let mut text = get_node_text(type_node, source).to_string();
+    let mut current = Some(*declarator);
+    while let Some(node) = current {
+        match node.kind() {
+            "pointer_declarator" | "abstract_pointer_declarator" => text.push('*'),
+            "reference_declarator" | "abstract_reference_declarator" => text.push('&'),
+            "function_declarator" | "identifier" | "field_identifier" => break,
+            _ => {}
+        }
+        current = node
+            .child_by_field_name("declarator")
+            .or_else(|| node.named_children(&mut node.walk()).last());
+    }
+    text
+}
+
+/// Extract a `parameter_list`.
+pub(super) fn extract_parameters(node: &Node, source: &str) -> Vec<ParameterDef> {
+    let mut params = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "parameter_declaration" | "optional_parameter_declaration" => {
+                let Some(type_node) = child.child_by_field_name("type") else {
+                    continue;
+                };
+                let declarator = child.child_by_field_name("declarator");
+                // f(void) declares no parameters
+                if declarator.is_none() && get_node_text(&type_node, source) == "void" {
+                    continue;
+                }
+
+                params.push(ParameterDef {
+                    name: declarator
+                        .and_then(|d| declarator_name(&d, source))
+                        .unwrap_or_default(),
+                    type_annotation: Some(match declarator {
+                        Some(d) => declared_type(&type_node, &d, source),
+                        None => get_node_text(&type_node, source).to_string(),
+                    }),
+                    default_value: child
+                        .child_by_field_name("default_value")
+                        .map(|v| get_node_text(&v, source).to_string()),
+                    ..Default::default()
+                });
+            }
+            "variadic_parameter" | "variadic_parameter_declaration" => {
+                params.push(ParameterDef {
+                    name: "...".to_string(),
+                    is_variadic: true,
+                    ..Default::default()
+                });
+            }
+            _ => {}
+        }
+    }
+
+    params
+}
+
+/// Read the run of comments directly above a declaration.
+pub(super) fn extract_comment_doc(node: &Node, source: &str) -> Option<String> {
+    let mut blocks = Vec::new();
+    let mut expected_line = node.start_position().row;
+    let mut prev = node.prev_sibling();
+
+    while let Some(comment) = prev {
+        // Stop at anything but a comment ending on the line just above
+        if comment.kind() != "comment" || comment.end_position().row + 1 != expected_line {
+            break;
+        }
+        blocks.push(clean_comment(get_node_text(&comment, source)));
+        expected_line = comment.start_position().row;
+        prev = comment.prev_sibling();
+    }
+
+    blocks.reverse();
+    let doc = blocks
+        .into_iter()
+        .filter(|block| !block.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc)
+    }
+}
+
+/// Strip `//`, `///`, `/* */` and leading `*` decoration from a comment.
+fn clean_comment(text: &str) -> String {
+    let body = if let Some(block) = text.strip_prefix("/*") {
+        block.strip_suffix("*/").unwrap_or(block)
+    } else {
+        text.trim_start_matches('/').trim_start_matches('!')
+    };
+
+    body.lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+/// Extract all call sites from a function body.
+pub(super) fn extract_call_sites(body: &Node, source: &str) -> Vec<CallSiteDef> {
+    let mut call_sites = Vec::new();
+    find_call_sites_recursive(body, source, &mut call_sites);
+    call_sites
+}
+
+/// Recursively search for call and `new` expressions.
+fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSiteDef>) {
+    match node.kind() {
+        "call_expression" => {
+            if let Some(call_site) = extract_call(node, source) {
+                results.push(call_site.with_arg_count(call_arguments(node).len()));
+            }
+        }
+        "new_expression" => {
+            if let Some(type_node) = node.child_by_field_name("type") {
+                let type_node = if type_node.kind() == "template_type" {
+                    type_node.child_by_field_name("name").unwrap_or(type_node)
+                } else {
+                    type_node
+                };
+                results.push(
+                    CallSiteDef {
+                        callee: format!("new {}", get_node_text(&type_node, source)),
+                        line: get_start_line(node),
+                        is_method_call: false,
+                        receiver: None,
+                        ..Default::default()
+                    }
+                    .with_arg_count(call_arguments(node).len()),
+                );
+            }
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        find_call_sites_recursive(&child, source, results);
+    }
+}
+
+/// Extract a call site from `f(x)`, `s.f(x)`, `p->f(x)`, `this->f()` or `Ns::f()`.
+fn extract_call(node: &Node, source: &str) -> Option<CallSiteDef> {
+    let function = node.child_by_field_name("function")?;
+    let line = get_start_line(node);
+
+    match function.kind() {
+        "identifier" => Some(CallSiteDef {
+            callee: get_node_text(&function, source).to_string(),
+            line,
+            is_method_call: false,
+            receiver: None,
+            ..Default::default()
+        }),
+        "field_expression" => {
+            let method_name = get_node_text(&function.child_by_field_name("field")?, source);
+            let receiver = get_node_text(&function.child_by_field_name("argument")?, source);
+
+            Some(CallSiteDef {
+                callee: if receiver == "this" {
+                    method_name.to_string()
+                } else {
+                    format!("{}.{}", receiver, method_name)
+                },
+                line,
+                is_method_call: true,
+                receiver: Some(receiver.to_string()),
+                ..Default::default()
+            })
+        }
+        // Class::method and ns::fn resolve like Class.method
+        "qualified_identifier" => {
+            let scope = function
+                .child_by_field_name("scope")
+                .map(|s| get_node_text(&s, source).to_string());
+            Some(CallSiteDef {
+                callee: get_node_text(&function, source).replace("::", "."),
+                line,
+                is_method_call: false,
+                receiver: scope,
+                ..Default::default()
+            })
+        }
+        // Explicit template arguments: max<int>(a, b)
+        "template_function" => Some(CallSiteDef {
+            callee: get_node_text(&function.child_by_field_name("name")?, source).to_string(),
+            line,
+            is_method_call: false,
+            receiver: None,
+            ..Default::default()
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+#ifndef RING_H
+#define RING_H
+
+#include <stdint.h>
+#include "drivers/uart.h"
+
+/* A byte ring. */
+typedef struct ring {
+    int head, tail;
+    char *buf;
+} ring_t;
+
+enum color { RED, GREEN };
+
+int ring_len(const ring_t *r);
+
+// Push one byte.
+// Returns the new head.
+static int *ring_push(ring_t *r, const char c, ...) {
+    if (r->head > 0 && c) {
+        uart_write(c);
+    }
+    r->ops->flush(r);
+    return 0;
+}
+
+void reset(void) {}
+
+#endif
+"#;
+
+    #[test]
+    fn test_parse_functions_and_records() {
+        let module = parse(SAMPLE, "src/ring.h").unwrap();
+        assert_eq!(module.language, "c");
+        assert_eq!(module.name, "ring");
+
+        // Prototypes are skipped; only definitions become functions
+        let names: Vec<_> = module.functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["ring_push", "reset"]);
+
+        let push = &module.functions[0];
+        assert_eq!(
+            push.docstring.as_deref(),
+            Some("Push one byte.\nReturns the new head.")
+        );
+        assert!(push.decorators.contains(&"static".to_string()));
+        assert_eq!(push.return_type.as_deref(), Some("int*"));
+        assert_eq!(push.parameters.len(), 3);
+        assert_eq!(push.parameters[0].name, "r");
+        assert_eq!(
+            push.parameters[0].type_annotation.as_deref(),
+            Some("ring_t*")
+        );
+        assert!(push.parameters[2].is_variadic);
+        assert_eq!(push.body_complexity, 3);
+
+        let callees: Vec<_> = push.call_sites.iter().map(|c| c.callee.as_str()).collect();
+        assert_eq!(callees, vec!["uart_write", "r->ops.flush"]);
+
+        assert!(module.functions[1].parameters.is_empty());
+
+        // Typedef name wins over the struct tag
+        let ring = &module.classes[0];
+        assert_eq!(ring.name, "ring_t");
+        assert_eq!(ring.decorators, vec!["struct"]);
+        assert_eq!(ring.attributes, vec!["head", "tail", "buf"]);
+        assert_eq!(ring.docstring.as_deref(), Some("A byte ring."));
+
+        let color = &module.classes[1];
+        assert_eq!(color.name, "color");
+        assert_eq!(color.attributes, vec!["RED", "GREEN"]);
+    }
+
+    #[test]
+    fn test_parse_includes() {
+        let module = parse(SAMPLE, "ring.h").unwrap();
+        let imports: Vec<_> = module.imports.iter().map(|i| i.module.as_str()).collect();
+        assert_eq!(imports, vec!["<stdint.h>", "drivers/uart.h"]);
+        assert_eq!(module.imports[0].end_line, module.imports[0].start_line);
+    }
+}
@@ -0,0 +1,430 @@
+//! What each language extractor captures.
+//!
+//! Extractors differ in coverage: every one of them records call sites, but
+//! only some read docstrings or decorators. Missing edges are usually a gap
+//! listed here rather than a bug. A `false` means the extractor does not
+//! produce that information, whether or not the language has the construct.
+
+use serde::Serialize;
+
+/// Constructs a language extractor records in its `ModuleDef`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct ParserCapabilities {
+    /// Classes, structs, interfaces, traits and similar type declarations
+    pub classes: bool,
+    /// Free functions and methods
+    pub functions: bool,
+    /// Static import/use/include declarations
+    pub imports: bool,
+    /// Runtime imports such as `import()` or `importlib.import_module`
+    pub dynamic_imports: bool,
+    /// Call sites inside function bodies (drives `calls` edges)
+    pub call_sites: bool,
+    /// Source-level decorators, annotations or attributes
+    pub decorators: bool,
+    /// Base classes, implemented interfaces, embedded or super types
+    pub inheritance: bool,
+    /// Docstrings or doc comments
+    pub docstrings: bool,
+    /// Whether functions are flagged as async
+    pub async_functions: bool,
+}
+
+impl ParserCapabilities {
+    /// Names of the capabilities this extractor lacks.
+    pub fn missing(&self) -> Vec<&'static str> {
+        self.flags()
+            .into_iter()
+            .filter(|(_, supported)| !supported)
+            .map(|(name, _)| name)
+            .collect()
+    }
+
+    /// Every capability with its name, in declaration order.
+    pub fn flags(&self) -> [(&'static str, bool); 9] {
+        [
+            ("classes", self.classes),
+            ("functions", self.functions),
+            ("imports", self.imports),
+            ("dynamic_imports", self.dynamic_imports),
+            ("call_sites", self.call_sites),
+            ("decorators", self.decorators),
+            ("inheritance", self.inheritance),
+            ("docstrings", self.docstrings),
+            ("async_functions", self.async_functions),
+        ]
+    }
+}
+
+/// Capabilities common to every extractor; the per-language table overrides the rest.
+const BASELINE: ParserCapabilities = ParserCapabilities {
+    classes: true,
+    functions: true,
+    imports: true,
+    dynamic_imports: false,
+    call_sites: true,
+    decorators: false,
+    inheritance: true,
+    docstrings: false,
+    async_functions: false,
+};
+
+/// Report what the extractor for `language` captures.
+///
+/// Accepts the same names and aliases as [`parse_source`](super::parse_source).
+/// Returns `None` for unsupported languages.
+pub fn capabilities(language: &str) -> Option<ParserCapabilities> {
+    let caps = match language.to_lowercase().as_str() {
+        "python" | "py" => ParserCapabilities {
+            dynamic_imports: true,
+            decorators: true,
+            docstrings: true,
+            async_functions: true,
+            ..BASELINE
+        },
+        "typescript" | "ts" | "tsx" | "javascript" | "js" | "jsx" => ParserCapabilities {
+            dynamic_imports: true,
+            async_functions: true,
+            ..BASELINE
+        },
+        "go" => BASELINE,
+        "java" => ParserCapabilities {
+            decorators: true,
+            ..BASELINE
+        },
+        "rust" | "rs" => ParserCapabilities {
+            async_functions: true,
+            ..BASELINE
+        },
+        "csharp" | "cs" | "c#" => ParserCapabilities {
+            decorators: true,
+            async_functions: true,
+            ..BASELINE
+        },
+        "dart" => ParserCapabilities {
+            decorators: true,
+            async_functions: true,
+            ..BASELINE
+        },
+        "scala" | "sc" => ParserCapabilities {
+            docstrings: true,
+            ..BASELINE
+        },
+        "kotlin" | "kt" | "kts" => ParserCapabilities {
+            decorators: true,
+            docstrings: true,
+            async_functions: true,
+            ..BASELINE
+        },
+        "php" => ParserCapabilities {
+            dynamic_imports: true,
+            decorators: true,
+            docstrings: true,
+            ..BASELINE
+        },
+        "ruby" | "rb" => ParserCapabilities {
+            docstrings: true,
+            ..BASELINE
+        },
+        "c" => ParserCapabilities {
+            inheritance: false,
+            docstrings: true,
+            ..BASELINE
+        },
+        "cpp" | "c++" | "cc" | "cxx" => ParserCapabilities {
+            docstrings: true,
+            ..BASELINE
+        },
+        _ => return None,
+    };
+    Some(caps)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parser::parse_source;
+    use crate::types::{FunctionDef, ModuleDef};
+
+    /// Samples that use every construct the capability table knows about,
+    /// so anything an extractor picks up shows in the parse result.
+    const SAMPLES: &[(&str, &str, &str)] = &[
+        (
+            "python",
+            "app.py",
+            r#""""Module doc."""
+import os
+import importlib
+
+plugin = importlib.import_module("plugins.core")
+
+class Base:
+    pass
+
+@marker
+class App(Base):
+    """App doc."""
+
+    async def run(self):
+        os.getcwd()
+"#,
+        ),
+        (
+            "typescript",
+            "app.ts",
+            r#"import { readFileSync } from "fs";
+
+/** Base doc. */
+class Base {}
+
+@Marker()
+class App extends Base {
+    /** Run doc. */
+    async run(): Promise<void> {
+        readFileSync("x");
+        await import("./plugin");
+    }
+}
+"#,
+        ),
+        (
+            "go",
+            "app.go",
+            r#"package main
+
+import "fmt"
+
+type Base struct{}
+
+// App does things.
+type App struct {
+    Base
+}
+
+// Run runs.
+func (a *App) Run() {
+    fmt.Println("run")
+}
+"#,
+        ),
+        (
+            "java",
+            "App.java",
+            r#"import java.util.List;
+
+/** App doc. */
+@Marker
+public class App extends Base {
+    /** Run doc. */
+    @Marker
+    public void run() {
+        System.out.println("run");
+    }
+}
+"#,
+        ),
+        (
+            "rust",
+            "app.rs",
+            r#"use std::fmt;
+
+/// Base doc.
+pub trait Base {}
+
+/// App doc.
+#[marker]
+pub trait App: Base {}
+
+/// Run doc.
+#[marker]
+pub async fn run() {
+    fmt::format(format_args!("run"));
+}
+"#,
+        ),
+        (
+            "csharp",
+            "App.cs",
+            r#"using System;
+
+/// <summary>App doc.</summary>
+[Marker]
+public class App : Base
+{
+    /// <summary>Run doc.</summary>
+    [Marker]
+    public async Task Run()
+    {
+        Console.WriteLine("run");
+    }
+}
+"#,
+        ),
+        (
+            "dart",
+            "app.dart",
+            r#"import 'package:flutter/material.dart';
+
+/// App doc.
+@marker
+class App extends Base {
+  /// Run doc.
+  Future<void> run() async {
+    print('run');
+  }
+}
+"#,
+        ),
+        (
+            "scala",
+            "App.scala",
+            r#"import scala.util.Try
+
+/** App doc. */
+@marker
+class App extends Base {
+  /** Run doc. */
+  def run(): Unit = {
+    println("run")
+  }
+}
+"#,
+        ),
+        (
+            "kotlin",
+            "App.kt",
+            r#"import kotlinx.coroutines.delay
+
+/** App doc. */
+@Marker
+class App : Base() {
+    /** Run doc. */
+    suspend fun run() {
+        println("run")
+    }
+}
+"#,
+        ),
+        (
+            "php",
+            "App.php",
+            r#"<?php
+use App\Support\Logger;
+
+require $path;
+
+/** App doc. */
+#[Marker]
+class App extends Base {
+    /** Run doc. */
+    public function run() {
+        echo strlen("run");
+    }
+}
+"#,
+        ),
+        (
+            "c",
+            "app.c",
+            r#"#include "app.h"
+
+/* App doc. */
+struct app {
+    int id;
+};
+
+// Run doc.
+void run(void) {
+    puts("run");
+}
+"#,
+        ),
+        (
+            "cpp",
+            "app.cpp",
+            r#"#include "app.hpp"
+
+/// App doc.
+class App : public Base {
+public:
+    /// Run doc.
+    void run() { puts("run"); }
+};
+"#,
+        ),
+        (
+            "ruby",
+            "app.rb",
+            r#"require 'json'
+
+# App doc.
+class App < Base
+  include Marker
+
+  # Run doc.
+  def run
+    puts("run")
+  end
+end
+"#,
+        ),
+    ];
+
+    fn all_functions(module: &ModuleDef) -> Vec<&FunctionDef> {
+        module
+            .functions
+            .iter()
+            .chain(module.classes.iter().flat_map(|c| c.methods.iter()))
+            .collect()
+    }
+
+    /// Capabilities as observed in an actual parse result.
+    fn observed(module: &ModuleDef) -> ParserCapabilities {
+        let functions = all_functions(module);
+        let is_marker = |d: &String| d.to_lowercase().contains("marker");
+
+        ParserCapabilities {
+            classes: !module.classes.is_empty(),
+            functions: !functions.is_empty(),
+            imports: module.imports.iter().any(|i| !i.is_dynamic),
+            dynamic_imports: module.imports.iter().any(|i| i.is_dynamic),
+            call_sites: functions.iter().any(|f| !f.call_sites.is_empty()),
+            decorators: module
+                .classes
+                .iter()
+                .any(|c| c.decorators.iter().any(is_marker))
+                || functions.iter().any(|f| f.decorators.iter().any(is_marker)),
+            inheritance: module.classes.iter().any(|c| !c.bases.is_empty()),
+            docstrings: module.module_docstring.is_some()
+                || module.classes.iter().any(|c| c.docstring.is_some())
+                || functions.iter().any(|f| f.docstring.is_some()),
+            async_functions: functions.iter().any(|f| f.is_async),
+        }
+    }
+
+    #[test]
+    fn test_capabilities_match_sample_parses() {
+        for (language, path, source) in SAMPLES {
+            let result = parse_source(source, path, language);
+            let module = result
+                .module
+                .unwrap_or_else(|| panic!("{} sample failed: {:?}", language, result.error));
+
+            assert_eq!(
+                observed(&module),
+                capabilities(language).unwrap(),
+                "capabilities for {} disagree with its extractor",
+                language
+            );
+        }
+    }
+
+    #[test]
+    fn test_capabilities_cover_supported_languages() {
+        for language in crate::parser::supported_languages() {
+            assert!(capabilities(language).is_some(), "{}", language);
+        }
+        assert!(capabilities("cobol").is_none());
+        assert_eq!(capabilities("TS"), capabilities("javascript"));
+    }
+}
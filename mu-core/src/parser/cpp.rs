@@ -0,0 +1,439 @@
+//! C++ AST extractor using tree-sitter.
+//!
+//! Builds on the C extractor's declarator, include and call-site handling.
+//! Classes and structs become `ClassDef`s with their base classes in `bases`.
+//! Methods declared in the class body are recorded even without a body, so a
+//! header's class shows its interface. Out-of-line definitions
+//! (`void Foo::bar() {}`) fold into `Foo` when it is defined in the same file,
+//! and otherwise into a `Foo` entry tagged `impl`, the way Rust impl blocks do.
+//! Namespaces, `extern "C"` blocks and templates are descended into; template
+//! parameters are kept as a `generic:` decorator.
+
+use tree_sitter::{Node, Parser};
+
+use super::c::{
+    apply_specifiers, declared_type, extract_comment_doc, extract_function, extract_include,
+    extract_parameters, extract_record, field_names, find_function_declarator, is_preproc_block,
+    new_module,
+};
+use super::helpers::{
+    get_end_column, get_end_line, get_node_text, get_start_column, get_start_line, syntax_errors,
+};
+use crate::types::{ClassDef, FunctionDef, ModuleDef};
+
+/// Parse C++ source code.
+pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_cpp::LANGUAGE.into())
+        .map_err(|e| format!("Failed to set C++ language: {}", e))?;
+
+    let tree = parser
+        .parse(source, None)
+        .ok_or("Failed to parse C++ source")?;
+    let root = tree.root_node();
+
+    let mut module = new_module(source, file_path, "cpp");
+    let mut impls: Vec<ClassDef> = Vec::new();
+    extract_definitions(&root, source, &mut module, &mut impls, None);
+
+    for impl_def in impls {
+        match module.classes.iter_mut().find(|c| c.name == impl_def.name) {
+            Some(class_def) => merge_impl(class_def, impl_def),
+            None => module.classes.push(impl_def),
+        }
+    }
+
+    module.syntax_errors = syntax_errors(&root, source);
+    Ok(module)
+}
+
+/// Collect definitions, descending into namespaces, linkage blocks,
+/// templates and preprocessor conditionals.
+fn extract_definitions(
+    node: &Node,
+    source: &str,
+    module: &mut ModuleDef,
+    impls: &mut Vec<ClassDef>,
+    generic: Option<&str>,
+) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "preproc_include" => module.imports.extend(extract_include(&child, source)),
+            "namespace_definition" => {
+                if module.namespace.is_none() {
+                    module.namespace = child
+                        .child_by_field_name("name")
+                        .map(|n| get_node_text(&n, source).to_string());
+                }
+                if let Some(body) = child.child_by_field_name("body") {
+                    extract_definitions(&body, source, module, impls, None);
+                }
+            }
+            // extern "C" { ... }
+            "linkage_specification" => {
+                if let Some(body) = child.child_by_field_name("body") {
+                    if body.kind() == "declaration_list" {
+                        extract_definitions(&body, source, module, impls, None);
+                    } else {
+                        extract_definitions(&child, source, module, impls, None);
+                    }
+                }
+            }
+            "template_declaration" => {
+                let params = child
+                    .child_by_field_name("parameters")
+                    .map(|p| get_node_text(&p, source));
+                extract_definitions(&child, source, module, impls, params);
+            }
+            "function_definition" => {
+                let mut func_def = extract_function(&child, source, "cpp");
+                if let Some(generic) = generic {
+                    func_def.decorators.push(format!("generic:{}", generic));
+                }
+                match out_of_line_owner(&child, source) {
+                    Some((owner, name)) => {
+                        func_def.name = name;
+                        func_def.is_method = true;
+                        add_impl_method(impls, &owner, func_def, &child);
+                    }
+                    None => module.functions.push(func_def),
+                }
+            }
+            "class_specifier" | "struct_specifier" | "union_specifier" => {
+                if let Some(class_def) = extract_class(&child, source, generic) {
+                    module.classes.push(class_def);
+                }
+            }
+            // Typedef'd structs, enums and `struct s {...} x;`
+            "type_definition" | "declaration" | "enum_specifier" => {
+                module.classes.extend(extract_record(&child, source));
+            }
+            kind if is_preproc_block(kind) => {
+                extract_definitions(&child, source, module, impls, generic);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// For `Foo::bar() {}` (or `ns::Foo::bar`), the owning class and method name.
+fn out_of_line_owner(node: &Node, source: &str) -> Option<(String, String)> {
+    let declarator = find_function_declarator(&node.child_by_field_name("declarator")?)?;
+    let mut name = declarator.child_by_field_name("declarator")?;
+    if name.kind() != "qualified_identifier" {
+        return None;
+    }
+
+    // Qualification nests to the right: a::(b::(Foo::bar))
+    let mut scope = None;
+    while name.kind() == "qualified_identifier" {
+        scope = name.child_by_field_name("scope");
+        name = name.child_by_field_name("name")?;
+    }
+
+    let scope = scope?;
+    let owner = match scope.kind() {
+        "template_type" => scope.child_by_field_name("name").unwrap_or(scope),
+        _ => scope,
+    };
+
+    Some((
+        get_node_text(&owner, source).to_string(),
+        get_node_text(&name, source).to_string(),
+    ))
+}
+
+/// Add an out-of-line method to the `impl` entry for `owner`.
+fn add_impl_method(impls: &mut Vec<ClassDef>, owner: &str, method: FunctionDef, node: &Node) {
+    let index = match impls.iter().position(|c| c.name == owner) {
+        Some(index) => index,
+        None => {
+            impls.push(ClassDef {
+                name: owner.to_string(),
+                decorators: vec!["impl".to_string()],
+                start_line: get_start_line(node),
+                end_line: get_end_line(node),
+                start_column: get_start_column(node),
+                end_column: get_end_column(node),
+                ..Default::default()
+            });
+            impls.len() - 1
+        }
+    };
+
+    let impl_def = &mut impls[index];
+    let start = (get_start_line(node), get_start_column(node));
+    if start < (impl_def.start_line, impl_def.start_column) {
+        (impl_def.start_line, impl_def.start_column) = start;
+    }
+    let end = (get_end_line(node), get_end_column(node));
+    if end > (impl_def.end_line, impl_def.end_column) {
+        (impl_def.end_line, impl_def.end_column) = end;
+    }
+    impl_def.methods.push(method);
+}
+
+/// Fold out-of-line definitions into the class declared in the same file.
+///
+/// A definition replaces the in-class declaration of the same name, so the
+/// method carries its body, complexity and call sites.
+fn merge_impl(class_def: &mut ClassDef, impl_def: ClassDef) {
+    for method in impl_def.methods {
+        match class_def
+            .methods
+            .iter_mut()
+            .find(|m| m.name == method.name && m.body_source.is_none())
+        {
+            Some(declared) => {
+                let decorators = std::mem::take(&mut declared.decorators);
+                let is_static = declared.is_static;
+                *declared = method;
+                declared.decorators = decorators;
+                declared.is_static = is_static;
+            }
+            None => class_def.methods.push(method),
+        }
+    }
+}
+
+/// Extract a class, struct, or union with a body.
+fn extract_class(node: &Node, source: &str, generic: Option<&str>) -> Option<ClassDef> {
+    let body = node.child_by_field_name("body")?;
+    let name = node.child_by_field_name("name")?;
+
+    let mut class_def = ClassDef {
+        name: get_node_text(&name, source).to_string(),
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_comment_doc(node, source),
+        ..Default::default()
+    };
+
+    match node.kind() {
+        "struct_specifier" => class_def.decorators.push("struct".to_string()),
+        "union_specifier" => class_def.decorators.push("union".to_string()),
+        _ => {}
+    }
+    if let Some(generic) = generic {
+        class_def.decorators.push(format!("generic:{}", generic));
+    }
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if child.kind() == "base_class_clause" {
+            extract_bases(&child, source, &mut class_def.bases);
+        }
+    }
+
+    // Members of a class are private until an access specifier says otherwise
+    let mut access = if node.kind() == "class_specifier" {
+        "private".to_string()
+    } else {
+        "public".to_string()
+    };
+
+    let mut cursor = body.walk();
+    for member in body.named_children(&mut cursor) {
+        extract_member(&member, source, &mut class_def, &mut access);
+    }
+
+    Some(class_def)
+}
+
+/// Record base classes, dropping access specifiers and template arguments.
+fn extract_bases(node: &Node, source: &str, bases: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let base = match child.kind() {
+            "type_identifier" | "qualified_identifier" => child,
+            "template_type" => child.child_by_field_name("name").unwrap_or(child),
+            _ => continue,
+        };
+        bases.push(get_node_text(&base, source).to_string());
+    }
+}
+
+/// Extract one member of a class body.
+fn extract_member(member: &Node, source: &str, class_def: &mut ClassDef, access: &mut String) {
+    match member.kind() {
+        "access_specifier" => {
+            *access = get_node_text(member, source)
+                .trim_end_matches(':')
+                .trim()
+                .to_string();
+        }
+        "function_definition" => {
+            let mut method = extract_function(member, source, "cpp");
+            finish_method(&mut method, access);
+            class_def.methods.push(method);
+        }
+        "field_declaration" | "declaration" => {
+            if let Some(mut method) = extract_method_declaration(member, source) {
+                finish_method(&mut method, access);
+                class_def.methods.push(method);
+                return;
+            }
+
+            // Nested type: add as attribute for reference
+            if let Some(nested) = member.child_by_field_name("type") {
+                if matches!(nested.kind(), "class_specifier" | "struct_specifier")
+                    && nested.child_by_field_name("body").is_some()
+                {
+                    if let Some(name) = nested.child_by_field_name("name") {
+                        class_def
+                            .attributes
+                            .push(format!("class:{}", get_node_text(&name, source)));
+                    }
+                }
+            }
+            class_def.attributes.extend(field_names(member, source));
+        }
+        "template_declaration" => {
+            let mut cursor = member.walk();
+            for inner in member.named_children(&mut cursor) {
+                extract_member(&inner, source, class_def, access);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Mark a member function as a method and record non-public access.
+fn finish_method(method: &mut FunctionDef, access: &str) {
+    method.is_method = true;
+    method.is_static = method.decorators.iter().any(|d| d == "static");
+    if access != "public" {
+        method.decorators.push(access.to_string());
+    }
+}
+
+/// Extract a method declared without a body (`virtual void reset() = 0;`).
+fn extract_method_declaration(node: &Node, source: &str) -> Option<FunctionDef> {
+    let declarator = node.child_by_field_name("declarator")?;
+    let function = find_function_declarator(&declarator)?;
+
+    let mut func_def = FunctionDef {
+        name: get_node_text(&function.child_by_field_name("declarator")?, source).to_string(),
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_comment_doc(node, source),
+        ..Default::default()
+    };
+
+    apply_specifiers(node, source, &mut func_def);
+    if let Some(params) = function.child_by_field_name("parameters") {
+        func_def.parameters = extract_parameters(&params, source);
+    }
+    func_def.return_type = node
+        .child_by_field_name("type")
+        .map(|t| declared_type(&t, &declarator, source));
+
+    // Pure virtual: = 0
+    if node
+        .child_by_field_name("default_value")
+        .is_some_and(|v| get_node_text(&v, source) == "0")
+    {
+        func_def.decorators.push("abstract".to_string());
+    }
+
+    Some(func_def)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+#include <vector>
+#include "base.hpp"
+
+namespace hw {
+
+/// A device on the bus.
+class Device : public Base, private Tracked<Device> {
+public:
+    Device(int id);
+    virtual ~Device();
+    virtual void reset() = 0;
+    static Device* create();
+    int id() const { return id_; }
+private:
+    int id_;
+    void poll();
+};
+
+template <typename T>
+T clamp(T v, T lo = 0) { return v < lo ? lo : v; }
+
+void Device::poll() {
+    auto d = new Device(1);
+    this->reset();
+    Device::create();
+    for (auto x : items) {
+        try { run(x); } catch (...) {}
+    }
+}
+
+void Sensor::read() {}
+
+}
+"#;
+
+    #[test]
+    fn test_parse_class_members_and_bases() {
+        let module = parse(SAMPLE, "src/device.cpp").unwrap();
+        assert_eq!(module.language, "cpp");
+        assert_eq!(module.namespace.as_deref(), Some("hw"));
+
+        let device = module.classes.iter().find(|c| c.name == "Device").unwrap();
+        assert_eq!(device.bases, vec!["Base", "Tracked"]);
+        assert_eq!(device.docstring.as_deref(), Some("A device on the bus."));
+        assert_eq!(device.attributes, vec!["id_"]);
+
+        let names: Vec<_> = device.methods.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["Device", "~Device", "reset", "create", "id", "poll"]
+        );
+        assert!(device.methods.iter().all(|m| m.is_method));
+
+        let reset = &device.methods[2];
+        assert!(reset.decorators.contains(&"virtual".to_string()));
+        assert!(reset.decorators.contains(&"abstract".to_string()));
+        assert!(device.methods[3].is_static);
+
+        // The out-of-line definition replaces the private declaration
+        let poll = &device.methods[5];
+        assert!(poll.decorators.contains(&"private".to_string()));
+        assert_eq!(poll.body_complexity, 3);
+        let callees: Vec<_> = poll.call_sites.iter().map(|c| c.callee.as_str()).collect();
+        assert_eq!(callees, vec!["new Device", "reset", "Device.create", "run"]);
+    }
+
+    #[test]
+    fn test_parse_templates_and_foreign_impls() {
+        let module = parse(SAMPLE, "device.cpp").unwrap();
+
+        let clamp = &module.functions[0];
+        assert_eq!(clamp.name, "clamp");
+        assert!(clamp
+            .decorators
+            .contains(&"generic:<typename T>".to_string()));
+        assert_eq!(clamp.parameters[1].default_value.as_deref(), Some("0"));
+        assert_eq!(clamp.body_complexity, 2);
+
+        // Methods of a class defined elsewhere group under an impl entry
+        let sensor = module.classes.iter().find(|c| c.name == "Sensor").unwrap();
+        assert_eq!(sensor.decorators, vec!["impl"]);
+        assert_eq!(sensor.methods[0].name, "read");
+
+        let imports: Vec<_> = module.imports.iter().map(|i| i.module.as_str()).collect();
+        assert_eq!(imports, vec!["<vector>", "base.hpp"]);
+    }
+}
@@ -4,8 +4,9 @@ use std::path::Path;
 use tree_sitter::{Node, Parser};
 
 use super::helpers::{
-    collect_type_strings_from_methods, count_lines, extract_referenced_types, find_child_by_type,
-    get_end_line, get_node_text, get_start_line,
+    call_arguments, collect_type_strings_from_methods, count_lines, extract_referenced_types,
+    find_child_by_type, get_end_column, get_end_line, get_node_text, get_start_column,
+    get_start_line, import_span, syntax_errors,
 };
 use crate::reducer::complexity;
 use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
@@ -39,6 +40,7 @@ pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
     // Process compilation unit
     process_node(&root, source, &mut module);
 
+    module.syntax_errors = syntax_errors(&root, source);
     Ok(module)
 }
 
@@ -72,7 +74,7 @@ fn process_node(node: &Node, source: &str, module: &mut ModuleDef) {
                 module.classes.push(extract_struct(&child, source));
             }
             "enum_declaration" => {
-                module.classes.push(extract_enum(&child, source));
+                module.push_enum(extract_enum(&child, source));
             }
             "record_declaration" => {
                 module.classes.push(extract_record(&child, source));
@@ -136,7 +138,7 @@ fn extract_using(node: &Node, source: &str) -> Option<ImportDef> {
     let mut import = ImportDef {
         module,
         alias,
-        ..Default::default()
+        ..import_span(node)
     };
 
     if is_static {
@@ -154,6 +156,8 @@ fn extract_class(node: &Node, source: &str) -> ClassDef {
     let mut class_def = ClassDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -162,10 +166,8 @@ fn extract_class(node: &Node, source: &str) -> ClassDef {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
             }
             "type_parameter_list" => {
                 // Generic parameters
@@ -266,25 +268,24 @@ fn extract_method(node: &Node, source: &str) -> FunctionDef {
         is_method: true,
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
     extract_modifiers(node, source, &mut func_def.decorators);
 
+    // The return type may itself be a bare identifier, so go by field
+    if let Some(name) = node.child_by_field_name("name") {
+        func_def.name = get_node_text(&name, source).to_string();
+    }
+    if let Some(returns) = node.child_by_field_name("returns") {
+        func_def.return_type = Some(get_node_text(&returns, source).to_string());
+    }
+
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "identifier" => {
-                if func_def.name.is_empty() && func_def.return_type.is_some() {
-                    func_def.name = get_node_text(&child, source).to_string();
-                }
-            }
-            "predefined_type" | "nullable_type" | "array_type" | "generic_name"
-            | "qualified_name" => {
-                if func_def.return_type.is_none() {
-                    func_def.return_type = Some(get_node_text(&child, source).to_string());
-                }
-            }
             "parameter_list" => {
                 func_def.parameters = extract_parameters(&child, source);
             }
@@ -314,6 +315,8 @@ fn extract_constructor(node: &Node, source: &str) -> FunctionDef {
         is_method: true,
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -348,38 +351,50 @@ fn extract_property(node: &Node, source: &str) -> FunctionDef {
         is_property: true,
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
     extract_modifiers(node, source, &mut func_def.decorators);
 
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        match child.kind() {
-            "identifier" => {
-                if func_def.name.is_empty() && func_def.return_type.is_some() {
-                    func_def.name = get_node_text(&child, source).to_string();
-                }
-            }
-            "predefined_type" | "nullable_type" | "array_type" | "generic_name" => {
-                if func_def.return_type.is_none() {
-                    func_def.return_type = Some(get_node_text(&child, source).to_string());
-                }
-            }
-            "accessor_list" => {
-                // Check for get/set
-                let text = get_node_text(&child, source);
-                if text.contains("get") {
-                    func_def.decorators.push("get".to_string());
-                }
-                if text.contains("set") {
-                    func_def.decorators.push("set".to_string());
+    if let Some(name) = node.child_by_field_name("name") {
+        func_def.name = get_node_text(&name, source).to_string();
+    }
+    if let Some(type_node) = node.child_by_field_name("type") {
+        func_def.return_type = Some(get_node_text(&type_node, source).to_string());
+    }
+
+    // `Total => ...` is a getter; otherwise record each accessor
+    let mut body = None;
+    match node.child_by_field_name("value") {
+        Some(value) if value.kind() == "arrow_expression_clause" => {
+            func_def.decorators.push("get".to_string());
+            body = Some(value);
+        }
+        _ => {
+            if let Some(accessors) = node.child_by_field_name("accessors") {
+                let mut cursor = accessors.walk();
+                for accessor in accessors.named_children(&mut cursor) {
+                    if let Some(keyword) = accessor.child_by_field_name("name") {
+                        func_def
+                            .decorators
+                            .push(get_node_text(&keyword, source).to_string());
+                    }
+                    if accessor.child_by_field_name("body").is_some() {
+                        body = Some(accessors);
+                    }
                 }
             }
-            _ => {}
         }
     }
 
+    if let Some(body) = body {
+        func_def.body_complexity = complexity::calculate_for_node(&body, source, "csharp");
+        func_def.body_source = Some(get_node_text(&body, source).to_string());
+        func_def.call_sites = extract_call_sites(&body, source);
+    }
+
     func_def
 }
 
@@ -443,6 +458,8 @@ fn extract_interface(node: &Node, source: &str) -> ClassDef {
     let mut class_def = ClassDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -452,10 +469,8 @@ fn extract_interface(node: &Node, source: &str) -> ClassDef {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
             }
             "base_list" => {
                 extract_base_list(&child, source, &mut class_def.bases);
@@ -498,6 +513,8 @@ fn extract_enum(node: &Node, source: &str) -> ClassDef {
     let mut class_def = ClassDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -507,10 +524,8 @@ fn extract_enum(node: &Node, source: &str) -> ClassDef {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
             }
             "enum_member_declaration_list" => {
                 let mut inner_cursor = child.walk();
@@ -551,13 +566,13 @@ fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSi
         "invocation_expression" => {
             // Method calls: foo.Bar(), this.Method(), SomeMethod()
             if let Some(call_site) = extract_invocation_call_site(node, source) {
-                results.push(call_site);
+                results.push(call_site.with_args(argument_types(node, source)));
             }
         }
         "object_creation_expression" => {
             // Constructor calls: new Foo()
             if let Some(call_site) = extract_object_creation_call_site(node, source) {
-                results.push(call_site);
+                results.push(call_site.with_args(argument_types(node, source)));
             }
         }
         _ => {}
@@ -585,6 +600,7 @@ fn extract_invocation_call_site(node: &Node, source: &str) -> Option<CallSiteDef
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         "member_access_expression" | "member_binding_expression" => {
@@ -617,6 +633,7 @@ fn extract_invocation_call_site(node: &Node, source: &str) -> Option<CallSiteDef
                 line,
                 is_method_call: true,
                 receiver,
+                ..Default::default()
             })
         }
         "conditional_access_expression" => {
@@ -642,6 +659,7 @@ fn extract_invocation_call_site(node: &Node, source: &str) -> Option<CallSiteDef
                 line,
                 is_method_call: true,
                 receiver,
+                ..Default::default()
             })
         }
         "generic_name" => {
@@ -652,6 +670,7 @@ fn extract_invocation_call_site(node: &Node, source: &str) -> Option<CallSiteDef
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         _ => {
@@ -662,6 +681,7 @@ fn extract_invocation_call_site(node: &Node, source: &str) -> Option<CallSiteDef
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
     }
@@ -684,9 +704,68 @@ fn extract_object_creation_call_site(node: &Node, source: &str) -> Option<CallSi
         line,
         is_method_call: false,
         receiver: None,
+        ..Default::default()
     })
 }
 
+/// Static types of a call's arguments, as far as each expression shows.
+///
+/// Literals map to their built-in type, `new Foo<T>()` and `(Foo)x` to
+/// `Foo`; anything else is unknown. Named (`x: 1`) and `ref`/`out`
+/// arguments are typed by their value.
+fn argument_types(call: &Node, source: &str) -> Vec<Option<String>> {
+    call_arguments(call)
+        .iter()
+        .map(|arg| {
+            let mut cursor = arg.walk();
+            let value = arg.named_children(&mut cursor).last().unwrap_or(*arg);
+            argument_type(&value, source)
+        })
+        .collect()
+}
+
+fn argument_type(arg: &Node, source: &str) -> Option<String> {
+    let text = get_node_text(arg, source).to_lowercase();
+    let name = match arg.kind() {
+        "integer_literal" => {
+            if text.ends_with("ul") || text.ends_with("lu") {
+                "ulong"
+            } else if text.ends_with('l') {
+                "long"
+            } else if text.ends_with('u') {
+                "uint"
+            } else {
+                "int"
+            }
+        }
+        "real_literal" => {
+            if text.ends_with('f') {
+                "float"
+            } else if text.ends_with('m') {
+                "decimal"
+            } else {
+                "double"
+            }
+        }
+        "string_literal"
+        | "verbatim_string_literal"
+        | "raw_string_literal"
+        | "interpolated_string_expression" => "string",
+        "character_literal" => "char",
+        "boolean_literal" => "bool",
+        "object_creation_expression" | "cast_expression" => {
+            let type_node = arg.child_by_field_name("type")?;
+            let type_node = match type_node.kind() {
+                "generic_name" => find_child_by_type(&type_node, "identifier").unwrap_or(type_node),
+                _ => type_node,
+            };
+            return Some(get_node_text(&type_node, source).to_string());
+        }
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -708,6 +787,79 @@ public class Hello {
         assert_eq!(result.classes[0].methods.len(), 1);
     }
 
+    #[test]
+    fn test_parse_enum() {
+        let source = r#"
+namespace Shop {
+    public enum OrderState { Pending, Paid = 2, Shipped }
+}
+"#;
+        let result = parse(source, "OrderState.cs").unwrap();
+        assert_eq!(result.enums.len(), 1);
+        assert_eq!(result.enums[0].name, "OrderState");
+        assert_eq!(result.enums[0].variants, vec!["Pending", "Paid", "Shipped"]);
+    }
+
+    #[test]
+    fn test_properties_and_attributes() {
+        let source = r#"
+[ApiController]
+public class OrdersController : ControllerBase
+{
+    [HttpGet("{id}")]
+    public Order Find(int id) { return null; }
+
+    [Required, MaxLength(50)]
+    public string Name { get; private set; }
+
+    public Customer Customer { get; init; }
+
+    public decimal Total => Compute();
+
+    public string Code { get { return Reset(); } }
+}
+"#;
+        let result = parse(source, "OrdersController.cs").unwrap();
+        let class = &result.classes[0];
+        assert_eq!(class.decorators, vec!["ApiController", "public"]);
+
+        let members: Vec<_> = class
+            .methods
+            .iter()
+            .map(|m| {
+                (
+                    m.name.as_str(),
+                    m.is_property,
+                    m.return_type.as_deref().unwrap_or(""),
+                )
+            })
+            .collect();
+        assert_eq!(
+            members,
+            vec![
+                ("Find", false, "Order"),
+                ("Name", true, "string"),
+                ("Customer", true, "Customer"),
+                ("Total", true, "decimal"),
+                ("Code", true, "string"),
+            ]
+        );
+        assert_eq!(
+            class.methods[0].decorators,
+            vec!["HttpGet(\"{id}\")", "public"]
+        );
+        assert_eq!(
+            class.methods[1].decorators,
+            vec!["Required", "MaxLength(50)", "public", "get", "set"]
+        );
+        assert_eq!(class.methods[2].decorators, vec!["public", "get", "init"]);
+        assert_eq!(class.methods[3].decorators, vec!["public", "get"]);
+        assert_eq!(class.methods[3].call_sites[0].callee, "Compute");
+        // "Reset" must not read as a setter
+        assert_eq!(class.methods[4].decorators, vec!["public", "get"]);
+        assert!(class.referenced_types.contains(&"Customer".to_string()));
+    }
+
     #[test]
     fn test_parse_interface() {
         let source = r#"
@@ -818,6 +970,38 @@ public class Factory {
             .any(|c| c.callee == "Initialize"));
     }
 
+    #[test]
+    fn test_call_site_argument_types() {
+        let source = r#"
+public class Job {
+    public void Run() {
+        Schedule("nightly", 3, 2.5f, true, count: 4L, new List<int>(), (Task)item, item);
+    }
+}
+"#;
+        let result = parse(source, "Job.cs").unwrap();
+        let call = result.classes[0].methods[0]
+            .call_sites
+            .iter()
+            .find(|c| c.callee == "Schedule")
+            .unwrap();
+        assert_eq!(call.arg_count, 8);
+        let types: Vec<Option<&str>> = call.arg_types.iter().map(|t| t.as_deref()).collect();
+        assert_eq!(
+            types,
+            vec![
+                Some("string"),
+                Some("int"),
+                Some("float"),
+                Some("bool"),
+                Some("long"),
+                Some("List"),
+                Some("Task"),
+                None,
+            ]
+        );
+    }
+
     #[test]
     fn test_object_creation_expression() {
         let source = r#"
@@ -0,0 +1,697 @@
+//! Dart AST extractor using tree-sitter.
+
+use std::path::Path;
+use tree_sitter::{Node, Parser};
+
+use super::helpers::{
+    call_arguments, collect_type_strings_from_methods, count_lines, extract_referenced_types,
+    find_child_by_type, get_end_column, get_end_line, get_node_text, get_start_column,
+    get_start_line, import_span, syntax_errors,
+};
+use crate::reducer::complexity;
+use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
+
+/// Parse Dart source code.
+pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_dart::language())
+        .map_err(|e| format!("Failed to set Dart language: {}", e))?;
+
+    let tree = parser
+        .parse(source, None)
+        .ok_or("Failed to parse Dart source")?;
+    let root = tree.root_node();
+
+    let name = Path::new(file_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    let mut module = ModuleDef {
+        name,
+        path: file_path.to_string(),
+        language: "dart".to_string(),
+        total_lines: count_lines(source),
+        ..Default::default()
+    };
+
+    // Annotations are siblings of the declaration they decorate
+    let mut annotations: Vec<String> = Vec::new();
+
+    let mut cursor = root.walk();
+    let children: Vec<_> = root.children(&mut cursor).collect();
+    for (i, child) in children.iter().enumerate() {
+        match child.kind() {
+            "import_or_export" => {
+                if let Some(import) = extract_import(child, source) {
+                    module.imports.push(import);
+                }
+            }
+            "part_directive" => {
+                if let Some(uri) = find_child_by_type(child, "uri") {
+                    module.imports.push(ImportDef {
+                        module: uri_text(&uri, source),
+                        ..import_span(child)
+                    });
+                }
+            }
+            "marker_annotation" | "annotation" => {
+                annotations.push(get_node_text(child, source).to_string());
+            }
+            "class_definition" => {
+                let mut class_def = extract_class(child, source);
+                class_def.decorators.splice(0..0, annotations.drain(..));
+                module.classes.push(class_def);
+            }
+            "mixin_declaration" => {
+                let mut class_def = extract_class(child, source);
+                class_def.decorators.splice(0..0, annotations.drain(..));
+                class_def.decorators.push("mixin".to_string());
+                module.classes.push(class_def);
+            }
+            "enum_declaration" => {
+                let mut class_def = extract_class(child, source);
+                class_def.decorators.splice(0..0, annotations.drain(..));
+                class_def.decorators.push("enum".to_string());
+                module.classes.push(class_def);
+            }
+            // Top-level functions parse as a signature followed by a body
+            "lambda_expression" => {
+                if let Some(signature) = find_child_by_type(child, "function_signature") {
+                    let body = find_child_by_type(child, "function_body");
+                    let mut func_def = extract_function(&signature, body.as_ref(), child, source);
+                    func_def.decorators.splice(0..0, annotations.drain(..));
+                    module.functions.push(func_def);
+                }
+            }
+            "function_signature" | "getter_signature" | "setter_signature" => {
+                let body = children
+                    .get(i + 1)
+                    .filter(|next| next.kind() == "function_body");
+                let mut func_def = extract_function(child, body, child, source);
+                if let Some(body) = body {
+                    func_def.end_line = get_end_line(body);
+                    func_def.end_column = get_end_column(body);
+                }
+                func_def.decorators.splice(0..0, annotations.drain(..));
+                module.functions.push(func_def);
+            }
+            "comment" | "documentation_comment" => {}
+            _ => annotations.clear(),
+        }
+    }
+
+    module.syntax_errors = syntax_errors(&root, source);
+    Ok(module)
+}
+
+/// Strip the quotes from a `uri` node.
+fn uri_text(node: &Node, source: &str) -> String {
+    get_node_text(node, source)
+        .trim_matches(|c| c == '\'' || c == '"')
+        .to_string()
+}
+
+/// Extract an `import '...' as x show a, b;` statement.
+fn extract_import(node: &Node, source: &str) -> Option<ImportDef> {
+    let library_import = find_child_by_type(node, "library_import")?;
+    let spec = find_child_by_type(&library_import, "import_specification")?;
+
+    let uri = find_child_by_type(&spec, "configurable_uri")
+        .and_then(|c| find_child_by_type(&c, "uri"))
+        .or_else(|| find_child_by_type(&spec, "uri"))?;
+
+    let mut import = ImportDef {
+        module: uri_text(&uri, source),
+        ..import_span(node)
+    };
+
+    let mut cursor = spec.walk();
+    let mut after_as = false;
+    for child in spec.children(&mut cursor) {
+        match child.kind() {
+            "as" => after_as = true,
+            "identifier" if after_as => {
+                import.alias = Some(get_node_text(&child, source).to_string());
+                after_as = false;
+            }
+            // Only `show` narrows the imported names
+            "combinator" if find_child_by_type(&child, "show").is_some() => {
+                let mut inner_cursor = child.walk();
+                for inner in child.children(&mut inner_cursor) {
+                    if inner.kind() == "identifier" {
+                        import.names.push(get_node_text(&inner, source).to_string());
+                    }
+                }
+                import.is_from = true;
+            }
+            _ => {}
+        }
+    }
+
+    Some(import)
+}
+
+/// Extract a class, mixin, or enum declaration.
+fn extract_class(node: &Node, source: &str) -> ClassDef {
+    let mut class_def = ClassDef {
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        ..Default::default()
+    };
+
+    let mut cursor = node.walk();
+    let mut after_on = false;
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "abstract" => class_def.decorators.push("abstract".to_string()),
+            "identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
+            }
+            // extends Base with Mixin
+            "superclass" => {
+                extract_type_list(&child, source, &mut class_def.bases);
+                if let Some(mixins) = find_child_by_type(&child, "mixins") {
+                    extract_type_list(&mixins, source, &mut class_def.bases);
+                }
+            }
+            // class Foo with Mixin (no extends)
+            "mixins" | "interfaces" => {
+                extract_type_list(&child, source, &mut class_def.bases);
+            }
+            // mixin Foo on Base
+            "on" => after_on = true,
+            "type_identifier" if after_on => {
+                class_def
+                    .bases
+                    .push(get_node_text(&child, source).to_string());
+            }
+            "class_body" | "enum_body" => {
+                after_on = false;
+                extract_class_body(&child, source, &mut class_def);
+            }
+            _ => {}
+        }
+    }
+
+    let type_strings = collect_type_strings_from_methods(&class_def.methods);
+    class_def.referenced_types = extract_referenced_types(
+        type_strings.iter().map(|s| s.as_str()),
+        &class_def.name,
+        "dart",
+    );
+
+    class_def
+}
+
+/// Collect type names from an `extends`/`with`/`implements` clause.
+fn extract_type_list(node: &Node, source: &str, bases: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.kind() == "type_identifier" {
+            bases.push(get_node_text(&child, source).to_string());
+        }
+    }
+}
+
+/// Extract methods, constructors, and fields from a class body.
+fn extract_class_body(node: &Node, source: &str, class_def: &mut ClassDef) {
+    let mut annotations: Vec<String> = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "marker_annotation" | "annotation" => {
+                annotations.push(get_node_text(&child, source).to_string());
+            }
+            "class_member_definition" => {
+                if let Some(mut func_def) = extract_member(&child, source, &class_def.name) {
+                    func_def.decorators.splice(0..0, annotations.drain(..));
+                    class_def.methods.push(func_def);
+                } else {
+                    extract_fields(&child, source, &mut class_def.attributes);
+                    annotations.clear();
+                }
+            }
+            "enum_constant" => {
+                if let Some(id) = find_child_by_type(&child, "identifier") {
+                    class_def
+                        .attributes
+                        .push(get_node_text(&id, source).to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Extract a method or constructor from a class member, if it is one.
+fn extract_member(member: &Node, source: &str, class_name: &str) -> Option<FunctionDef> {
+    // Members with a body wrap their signature in `method_signature`;
+    // abstract methods and initializer-only constructors use `declaration`.
+    let (wrapper, body) = match find_child_by_type(member, "method_signature") {
+        Some(sig) => (sig, find_child_by_type(member, "function_body")),
+        None => (find_child_by_type(member, "declaration")?, None),
+    };
+
+    let is_static = find_child_by_type(&wrapper, "static").is_some();
+
+    let mut cursor = wrapper.walk();
+    let signature = wrapper.children(&mut cursor).find(|c| {
+        matches!(
+            c.kind(),
+            "function_signature"
+                | "getter_signature"
+                | "setter_signature"
+                | "operator_signature"
+                | "constructor_signature"
+                | "constant_constructor_signature"
+                | "factory_constructor_signature"
+                | "redirecting_factory_constructor_signature"
+        )
+    })?;
+
+    let mut func_def = match signature.kind() {
+        "constructor_signature"
+        | "constant_constructor_signature"
+        | "factory_constructor_signature"
+        | "redirecting_factory_constructor_signature" => {
+            extract_constructor(&signature, body.as_ref(), member, source, class_name)
+        }
+        _ => extract_function(&signature, body.as_ref(), member, source),
+    };
+
+    func_def.is_method = true;
+    func_def.is_static = is_static;
+    if is_static {
+        func_def.decorators.push("static".to_string());
+    }
+
+    Some(func_def)
+}
+
+/// Extract a function, method, getter, or setter from its signature.
+///
+/// `span` is the node whose lines the function covers.
+fn extract_function(
+    signature: &Node,
+    body: Option<&Node>,
+    span: &Node,
+    source: &str,
+) -> FunctionDef {
+    let mut func_def = FunctionDef {
+        start_line: get_start_line(span),
+        end_line: get_end_line(span),
+        start_column: get_start_column(span),
+        end_column: get_end_column(span),
+        ..Default::default()
+    };
+
+    let mut cursor = signature.walk();
+    for child in signature.children(&mut cursor) {
+        match child.kind() {
+            "identifier" if func_def.name.is_empty() => {
+                func_def.name = get_node_text(&child, source).to_string();
+                // Everything before the name is the return type
+                let return_type = source[signature.start_byte()..child.start_byte()].trim();
+                if !return_type.is_empty() {
+                    func_def.return_type = Some(return_type.to_string());
+                }
+            }
+            "get" => {
+                func_def.is_property = true;
+                func_def.decorators.push("getter".to_string());
+            }
+            "set" => func_def.decorators.push("setter".to_string()),
+            "binary_operator" | "tilde_operator" | "[]" | "[]=" | "==" => {
+                func_def.name = format!("operator {}", get_node_text(&child, source));
+            }
+            "formal_parameter_list" => {
+                func_def.parameters = extract_parameters(&child, source);
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(body) = body {
+        apply_body(&mut func_def, body, source);
+    }
+
+    func_def
+}
+
+/// Extract a constructor. Named and factory constructors use the part after
+/// the dot as their name (`Dog.named` -> `named`).
+fn extract_constructor(
+    signature: &Node,
+    body: Option<&Node>,
+    span: &Node,
+    source: &str,
+    class_name: &str,
+) -> FunctionDef {
+    let mut func_def = FunctionDef {
+        start_line: get_start_line(span),
+        end_line: get_end_line(span),
+        start_column: get_start_column(span),
+        end_column: get_end_column(span),
+        return_type: Some(class_name.to_string()),
+        ..Default::default()
+    };
+
+    let mut names = Vec::new();
+    let mut cursor = signature.walk();
+    for child in signature.children(&mut cursor) {
+        match child.kind() {
+            "identifier" => names.push(get_node_text(&child, source).to_string()),
+            "factory" => func_def.decorators.push("factory".to_string()),
+            "const_builtin" => func_def.decorators.push("const".to_string()),
+            "formal_parameter_list" => {
+                func_def.parameters = extract_parameters(&child, source);
+            }
+            _ => {}
+        }
+    }
+    func_def.decorators.push("constructor".to_string());
+    func_def.name = names.pop().unwrap_or_else(|| class_name.to_string());
+
+    if let Some(body) = body {
+        apply_body(&mut func_def, body, source);
+    }
+
+    func_def
+}
+
+/// Fill complexity, source, call sites, and async-ness from a function body.
+fn apply_body(func_def: &mut FunctionDef, body: &Node, source: &str) {
+    func_def.is_async = find_child_by_type(body, "async").is_some();
+    func_def.body_complexity = complexity::calculate_for_node(body, source, "dart");
+    func_def.body_source = Some(get_node_text(body, source).to_string());
+    func_def.call_sites = extract_call_sites(body, source);
+}
+
+/// Extract parameters, including optional positional `[...]` and named `{...}` ones.
+fn extract_parameters(node: &Node, source: &str) -> Vec<ParameterDef> {
+    let mut params = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "formal_parameter" => {
+                if let Some(param) = extract_parameter(&child, source, false) {
+                    params.push(param);
+                }
+            }
+            "optional_formal_parameters" => {
+                let is_named = find_child_by_type(&child, "{").is_some();
+                let mut inner_cursor = child.walk();
+                let mut last: Option<usize> = None;
+                let mut after_eq = false;
+                for inner in child.children(&mut inner_cursor) {
+                    match inner.kind() {
+                        "formal_parameter" => {
+                            if let Some(param) = extract_parameter(&inner, source, is_named) {
+                                params.push(param);
+                                last = Some(params.len() - 1);
+                            }
+                        }
+                        "=" | ":" => after_eq = true,
+                        "," | "]" | "}" | "required" => after_eq = false,
+                        _ if after_eq => {
+                            if let Some(idx) = last {
+                                params[idx].default_value =
+                                    Some(get_node_text(&inner, source).to_string());
+                            }
+                            after_eq = false;
+                        }
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    params
+}
+
+/// Extract a single formal parameter.
+fn extract_parameter(node: &Node, source: &str, is_named: bool) -> Option<ParameterDef> {
+    let mut param = ParameterDef {
+        is_keyword: is_named,
+        ..Default::default()
+    };
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "identifier" => {
+                param.name = get_node_text(&child, source).to_string();
+                let type_text = source[node.start_byte()..child.start_byte()].trim();
+                if !type_text.is_empty() {
+                    param.type_annotation = Some(type_text.to_string());
+                }
+            }
+            // this.name / super.name initializing formals
+            "constructor_param" | "super_formal_parameter" => {
+                if let Some(id) = find_child_by_type(&child, "identifier") {
+                    param.name = get_node_text(&id, source).to_string();
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if param.name.is_empty() {
+        None
+    } else {
+        Some(param)
+    }
+}
+
+/// Extract field names from a non-method class member.
+fn extract_fields(member: &Node, source: &str, attributes: &mut Vec<String>) {
+    let Some(declaration) = find_child_by_type(member, "declaration") else {
+        return;
+    };
+    let mut cursor = declaration.walk();
+    for child in declaration.children(&mut cursor) {
+        if matches!(
+            child.kind(),
+            "initialized_identifier_list" | "static_final_declaration_list"
+        ) {
+            let mut inner_cursor = child.walk();
+            for inner in child.children(&mut inner_cursor) {
+                if let Some(id) = find_child_by_type(&inner, "identifier") {
+                    attributes.push(get_node_text(&id, source).to_string());
+                }
+            }
+        }
+    }
+}
+
+/// Extract all call sites from a function body.
+fn extract_call_sites(body: &Node, source: &str) -> Vec<CallSiteDef> {
+    let mut call_sites = Vec::new();
+    find_call_sites_recursive(body, source, &mut call_sites);
+    call_sites
+}
+
+/// Recursively search for calls in the AST.
+fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSiteDef>) {
+    match node.kind() {
+        "member_access" => extract_member_calls(node, source, results),
+        "new_expression" | "const_object_expression" => {
+            if let Some(type_id) = find_child_by_type(node, "type_identifier") {
+                results.push(
+                    CallSiteDef {
+                        callee: get_node_text(&type_id, source).to_string(),
+                        line: get_start_line(node),
+                        is_method_call: false,
+                        receiver: None,
+                        ..Default::default()
+                    }
+                    .with_arg_count(call_arguments(node).len()),
+                );
+            }
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        find_call_sites_recursive(&child, source, results);
+    }
+}
+
+/// Extract calls from a `member_access` chain such as `a.b(x).c()`.
+///
+/// Dart's grammar represents calls as a base expression followed by
+/// selectors: `.name` selectors extend the path, argument selectors call it.
+fn extract_member_calls(node: &Node, source: &str, results: &mut Vec<CallSiteDef>) {
+    let mut receiver: Option<String> = None;
+    let mut name: Option<String> = None;
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "identifier" if name.is_none() && receiver.is_none() => {
+                name = Some(get_node_text(&child, source).to_string());
+            }
+            "this" | "super" if name.is_none() && receiver.is_none() => {
+                receiver = Some(get_node_text(&child, source).to_string());
+            }
+            "selector" => {
+                if find_child_by_type(&child, "argument_part").is_some() {
+                    let Some(method) = name.take() else {
+                        continue;
+                    };
+                    let is_self = matches!(receiver.as_deref(), Some("this") | Some("super"));
+                    let callee = match (&receiver, is_self) {
+                        (Some(recv), false) => format!("{}.{}", recv, method),
+                        _ => method.clone(),
+                    };
+                    results.push(
+                        CallSiteDef {
+                            callee: callee.clone(),
+                            line: get_start_line(&child),
+                            is_method_call: receiver.is_some(),
+                            receiver: receiver.clone(),
+                            ..Default::default()
+                        }
+                        .with_arg_count(call_arguments(&child).len()),
+                    );
+                    receiver = Some(format!("{}()", callee));
+                } else if let Some(id) =
+                    find_child_by_type(&child, "unconditional_assignable_selector")
+                        .or_else(|| find_child_by_type(&child, "conditional_assignable_selector"))
+                        .and_then(|s| find_child_by_type(&s, "identifier"))
+                {
+                    if let Some(prev) = name.take() {
+                        receiver = Some(match receiver {
+                            Some(recv) => format!("{}.{}", recv, prev),
+                            None => prev,
+                        });
+                    }
+                    name = Some(get_node_text(&id, source).to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = r#"
+import 'package:flutter/material.dart';
+import 'src/utils.dart' as utils show format;
+part 'dog.g.dart';
+
+mixin Logging on Object {
+  void log(String msg) {
+    print(msg);
+  }
+}
+
+abstract class Animal {
+  String name;
+  Animal(this.name);
+  void speak();
+}
+
+class Dog extends Animal with Logging implements Comparable<Dog> {
+  Dog(String name) : super(name);
+  Dog.named({required String name}) : super(name);
+
+  factory Dog.create(String n) {
+    return Dog(n);
+  }
+
+  @override
+  void speak() {
+    if (name.isEmpty && true) {
+      log('woof');
+    }
+  }
+
+  static Dog build() => Dog('x');
+  String get label => name;
+}
+
+int add(int a, [int b = 0]) {
+  return a + b;
+}
+
+Future<void> main() async {
+  final dog = Dog.create('rex');
+  dog.speak();
+}
+"#;
+
+    #[test]
+    fn test_parse_dart_classes_and_functions() {
+        let module = parse(SOURCE, "lib/dog.dart").unwrap();
+        assert_eq!(module.language, "dart");
+        assert_eq!(module.classes.len(), 3);
+        assert_eq!(module.functions.len(), 2);
+
+        let dog = module.classes.iter().find(|c| c.name == "Dog").unwrap();
+        assert_eq!(dog.bases, vec!["Animal", "Logging", "Comparable"]);
+
+        let mixin = module.classes.iter().find(|c| c.name == "Logging").unwrap();
+        assert!(mixin.decorators.contains(&"mixin".to_string()));
+        assert_eq!(mixin.bases, vec!["Object"]);
+
+        let add = &module.functions[0];
+        assert_eq!(add.name, "add");
+        assert_eq!(add.return_type.as_deref(), Some("int"));
+        assert_eq!(add.parameters.len(), 2);
+        assert_eq!(add.parameters[1].default_value.as_deref(), Some("0"));
+
+        let main = &module.functions[1];
+        assert!(main.is_async);
+        assert!(main
+            .call_sites
+            .iter()
+            .any(|c| c.callee == "Dog.create" && c.receiver.as_deref() == Some("Dog")));
+    }
+
+    #[test]
+    fn test_parse_dart_constructors_and_methods() {
+        let module = parse(SOURCE, "lib/dog.dart").unwrap();
+        let dog = module.classes.iter().find(|c| c.name == "Dog").unwrap();
+        let names: Vec<_> = dog.methods.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(
+            names,
+            vec!["Dog", "named", "create", "speak", "build", "label"]
+        );
+
+        let factory = &dog.methods[2];
+        assert!(factory.decorators.contains(&"factory".to_string()));
+        assert!(factory.decorators.contains(&"constructor".to_string()));
+
+        let speak = &dog.methods[3];
+        assert!(speak.decorators.contains(&"@override".to_string()));
+        assert_eq!(speak.body_complexity, 3);
+
+        assert!(dog.methods[4].is_static);
+        assert!(dog.methods[5].is_property);
+    }
+
+    #[test]
+    fn test_parse_dart_imports() {
+        let module = parse(SOURCE, "lib/dog.dart").unwrap();
+        assert_eq!(module.imports.len(), 3);
+        assert_eq!(module.imports[0].module, "package:flutter/material.dart");
+        assert_eq!(module.imports[1].module, "src/utils.dart");
+        assert_eq!(module.imports[1].alias.as_deref(), Some("utils"));
+        assert_eq!(module.imports[1].names, vec!["format"]);
+        assert_eq!(module.imports[2].module, "dog.g.dart");
+    }
+}
@@ -4,8 +4,9 @@ use std::path::Path;
 use tree_sitter::{Node, Parser};
 
 use super::helpers::{
-    collect_type_strings_from_methods, count_lines, extract_referenced_types, find_child_by_type,
-    get_end_line, get_node_text, get_start_line,
+    call_arguments, collect_type_strings_from_methods, count_lines, extract_referenced_types,
+    find_child_by_type, get_end_column, get_end_line, get_node_text, get_start_column,
+    get_start_line, import_span, syntax_errors,
 };
 use crate::reducer::complexity;
 use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
@@ -60,6 +61,7 @@ pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
         }
     }
 
+    module.syntax_errors = syntax_errors(&root, source);
     Ok(module)
 }
 
@@ -81,8 +83,9 @@ fn extract_imports(node: &Node, source: &str, imports: &mut Vec<ImportDef>) {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
+            // A lone spec spans the whole `import x "y"` statement
             "import_spec" => {
-                if let Some(import) = extract_import_spec(&child, source) {
+                if let Some(import) = extract_import_spec(&child, node, source) {
                     imports.push(import);
                 }
             }
@@ -90,7 +93,7 @@ fn extract_imports(node: &Node, source: &str, imports: &mut Vec<ImportDef>) {
                 let mut inner_cursor = child.walk();
                 for spec in child.children(&mut inner_cursor) {
                     if spec.kind() == "import_spec" {
-                        if let Some(import) = extract_import_spec(&spec, source) {
+                        if let Some(import) = extract_import_spec(&spec, &spec, source) {
                             imports.push(import);
                         }
                     }
@@ -104,7 +107,7 @@ fn extract_imports(node: &Node, source: &str, imports: &mut Vec<ImportDef>) {
                     .to_string();
                 imports.push(ImportDef {
                     module,
-                    ..Default::default()
+                    ..import_span(node)
                 });
             }
             _ => {}
@@ -112,8 +115,8 @@ fn extract_imports(node: &Node, source: &str, imports: &mut Vec<ImportDef>) {
     }
 }
 
-/// Extract a single import spec.
-fn extract_import_spec(node: &Node, source: &str) -> Option<ImportDef> {
+/// Extract a single import spec, reporting the span of `span`.
+fn extract_import_spec(node: &Node, span: &Node, source: &str) -> Option<ImportDef> {
     let mut module = String::new();
     let mut alias = None;
 
@@ -140,7 +143,7 @@ fn extract_import_spec(node: &Node, source: &str) -> Option<ImportDef> {
     Some(ImportDef {
         module,
         alias,
-        ..Default::default()
+        ..import_span(span)
     })
 }
 
@@ -149,6 +152,8 @@ fn extract_function(node: &Node, source: &str) -> FunctionDef {
     let mut func_def = FunctionDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -156,10 +161,8 @@ fn extract_function(node: &Node, source: &str) -> FunctionDef {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "identifier" => {
-                if func_def.name.is_empty() {
-                    func_def.name = get_node_text(&child, source).to_string();
-                }
+            "identifier" if func_def.name.is_empty() => {
+                func_def.name = get_node_text(&child, source).to_string();
             }
             "parameter_list" => {
                 if !found_params {
@@ -202,6 +205,8 @@ fn extract_method(node: &Node, source: &str) -> FunctionDef {
         is_method: true,
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -373,16 +378,16 @@ fn extract_type_spec(node: &Node, source: &str) -> Option<ClassDef> {
     let mut class_def = ClassDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "type_identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "type_identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
             }
             "struct_type" => {
                 class_def.decorators.push("struct".to_string());
@@ -453,6 +458,8 @@ fn extract_interface_methods(node: &Node, source: &str, class_def: &mut ClassDef
                 is_method: true,
                 start_line: get_start_line(&child),
                 end_line: get_end_line(&child),
+                start_column: get_start_column(&child),
+                end_column: get_end_column(&child),
                 ..Default::default()
             };
 
@@ -516,7 +523,7 @@ fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSi
     // Go uses "call_expression" for function calls
     if node.kind() == "call_expression" {
         if let Some(call_site) = extract_call_site(node, source) {
-            results.push(call_site);
+            results.push(call_site.with_arg_count(call_arguments(node).len()));
         }
     }
 
@@ -541,6 +548,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         "selector_expression" => {
@@ -556,6 +564,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: true,
                 receiver,
+                ..Default::default()
             })
         }
         "parenthesized_expression" => {
@@ -566,6 +575,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         "call_expression" => {
@@ -576,6 +586,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         "index_expression" => {
@@ -586,6 +597,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         "type_assertion_expression" => {
@@ -596,6 +608,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         _ => {
@@ -606,6 +619,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
     }
@@ -228,6 +228,93 @@ static CSHARP_BUILTINS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
     ])
 });
 
+/// Dart built-in types to filter out from referenced_types.
+static DART_BUILTINS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    HashSet::from([
+        "String",
+        "Object",
+        "Null",
+        "Never",
+        "Function",
+        "Type",
+        "Symbol",
+        "List",
+        "Map",
+        "Set",
+        "Iterable",
+        "Iterator",
+        "Future",
+        "FutureOr",
+        "Stream",
+        "Duration",
+        "DateTime",
+        "Exception",
+        "Error",
+        "StackTrace",
+        "Comparable",
+    ])
+});
+
+/// Scala built-in types to filter out from referenced_types.
+static SCALA_BUILTINS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    HashSet::from([
+        "Any", "AnyRef", "AnyVal", "Nothing", "Null", "Unit", "Boolean", "Byte", "Short", "Int",
+        "Long", "Float", "Double", "Char", "String", "Option", "Some", "None", "Either", "Left",
+        "Right", "Try", "Future", "List", "Seq", "Vector", "Array", "Map", "Set", "Iterable",
+        "Iterator",
+    ])
+});
+
+static KOTLIN_BUILTINS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    HashSet::from([
+        "Any",
+        "Unit",
+        "Nothing",
+        "Boolean",
+        "Byte",
+        "Short",
+        "Int",
+        "Long",
+        "Float",
+        "Double",
+        "Char",
+        "String",
+        "Array",
+        "List",
+        "MutableList",
+        "Set",
+        "MutableSet",
+        "Map",
+        "MutableMap",
+        "Sequence",
+        "Iterable",
+        "Pair",
+        "Triple",
+        "Result",
+    ])
+});
+
+static PHP_BUILTINS: Lazy<HashSet<&'static str>> = Lazy::new(|| {
+    HashSet::from([
+        "Closure",
+        "Generator",
+        "Traversable",
+        "Iterator",
+        "IteratorAggregate",
+        "ArrayAccess",
+        "Countable",
+        "Stringable",
+        "Throwable",
+        "Exception",
+        "Error",
+        "DateTime",
+        "DateTimeImmutable",
+        "DateTimeInterface",
+        "ArrayObject",
+        "JsonSerializable",
+    ])
+});
+
 /// Get the appropriate builtin set for a language.
 fn get_builtins_for_language(language: &str) -> &'static HashSet<&'static str> {
     match language {
@@ -237,6 +324,10 @@ fn get_builtins_for_language(language: &str) -> &'static HashSet<&'static str> {
         "java" => &JAVA_BUILTINS,
         "rust" | "rs" => &RUST_BUILTINS,
         "csharp" | "cs" | "c#" => &CSHARP_BUILTINS,
+        "dart" => &DART_BUILTINS,
+        "scala" | "sc" => &SCALA_BUILTINS,
+        "kotlin" | "kt" | "kts" => &KOTLIN_BUILTINS,
+        "php" => &PHP_BUILTINS,
         _ => &PYTHON_BUILTINS, // Default fallback
     }
 }
@@ -366,6 +457,40 @@ pub fn count_nodes(node: &Node) -> u32 {
     count
 }
 
+/// Argument expressions of a call node, in order.
+///
+/// Looks for the grammar's `arguments` field, falling back to an argument
+/// list child (`argument_list`, `arguments`, Kotlin's `value_arguments`
+/// inside `call_suffix`, Dart's `arguments` inside `argument_part`).
+/// Comments are skipped; a bare generator argument (`f(x for x in y)`)
+/// counts as one.
+pub fn call_arguments<'a>(call: &Node<'a>) -> Vec<Node<'a>> {
+    let list = call.child_by_field_name("arguments").or_else(|| {
+        let mut cursor = call.walk();
+        let found = call.named_children(&mut cursor).find(|child| {
+            matches!(
+                child.kind(),
+                "argument_list" | "arguments" | "value_arguments" | "call_suffix" | "argument_part"
+            )
+        });
+        found
+    });
+    let Some(list) = list else {
+        return Vec::new();
+    };
+
+    match list.kind() {
+        "call_suffix" | "argument_part" => call_arguments(&list),
+        "generator_expression" => vec![list],
+        _ => {
+            let mut cursor = list.walk();
+            list.named_children(&mut cursor)
+                .filter(|child| !child.kind().contains("comment"))
+                .collect()
+        }
+    }
+}
+
 /// Extract a docstring from a node (if it's a string literal).
 #[allow(dead_code)]
 pub fn extract_docstring(node: Option<Node>, source: &str) -> Option<String> {
@@ -408,6 +533,81 @@ pub fn get_end_line(node: &Node) -> u32 {
     node.end_position().row as u32 + 1
 }
 
+/// Get start column (1-indexed, in bytes) from a node.
+pub fn get_start_column(node: &Node) -> u32 {
+    node.start_position().column as u32 + 1
+}
+
+/// Get end column (1-indexed, in bytes, just past the last character) from a node.
+pub fn get_end_column(node: &Node) -> u32 {
+    node.end_position().column as u32 + 1
+}
+
+/// An `ImportDef` with only the span of `node` set, for filling in the rest
+/// with struct update syntax: `ImportDef { module, ..import_span(node) }`.
+pub fn import_span(node: &Node) -> crate::types::ImportDef {
+    crate::types::ImportDef {
+        start_line: get_start_line(node),
+        start_column: get_start_column(node),
+        end_line: get_end_line(node),
+        end_column: get_end_column(node),
+        ..Default::default()
+    }
+}
+
+/// Most syntax errors reported per file; past this the source is likely
+/// not the language it was parsed as.
+const MAX_SYNTAX_ERRORS: usize = 100;
+
+/// Collect the ERROR and MISSING nodes tree-sitter recovered from, in
+/// source order.
+pub fn syntax_errors(root: &Node, source: &str) -> Vec<crate::types::ParseError> {
+    let mut errors = Vec::new();
+    if root.has_error() {
+        collect_syntax_errors(root, source, &mut errors);
+    }
+    errors
+}
+
+fn collect_syntax_errors(node: &Node, source: &str, errors: &mut Vec<crate::types::ParseError>) {
+    if errors.len() >= MAX_SYNTAX_ERRORS {
+        return;
+    }
+
+    let message = if node.is_missing() {
+        Some(format!("Missing `{}`", node.kind()))
+    } else if node.is_error() {
+        let text = get_node_text(node, source)
+            .lines()
+            .next()
+            .unwrap_or("")
+            .trim();
+        // Quote at most 40 characters of the unparsed text
+        Some(match text.char_indices().nth(40) {
+            Some((end, _)) => format!("Unexpected `{}...`", &text[..end]),
+            None if text.is_empty() => "Syntax error".to_string(),
+            None => format!("Unexpected `{}`", text),
+        })
+    } else {
+        None
+    };
+    if let Some(message) = message {
+        errors.push(crate::types::ParseError {
+            line: get_start_line(node),
+            column: get_start_column(node),
+            message,
+        });
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        if child.has_error() {
+            collect_syntax_errors(&child, source, errors);
+        }
+    }
+}
+
 /// Count total lines in source.
 pub fn count_lines(source: &str) -> u32 {
     source.lines().count() as u32
@@ -462,32 +662,31 @@ mod tests {
     #[test]
     fn test_extract_referenced_types_python() {
         // Test Python type extraction
-        let type_strings = vec!["Node | None", "list[Edge]", "str", "MyClass"];
-        let result = extract_referenced_types(type_strings.iter().map(|s| *s), "MUbase", "python");
+        let type_strings = ["Node | None", "list[Edge]", "str", "MyClass"];
+        let result = extract_referenced_types(type_strings.iter().copied(), "MUbase", "python");
         assert_eq!(result, vec!["Edge", "MyClass", "Node"]);
     }
 
     #[test]
     fn test_extract_referenced_types_filters_class_name() {
         // Should filter out the class's own name
-        let type_strings = vec!["MUbase", "Node"];
-        let result = extract_referenced_types(type_strings.iter().map(|s| *s), "MUbase", "python");
+        let type_strings = ["MUbase", "Node"];
+        let result = extract_referenced_types(type_strings.iter().copied(), "MUbase", "python");
         assert_eq!(result, vec!["Node"]);
     }
 
     #[test]
     fn test_extract_referenced_types_filters_builtins() {
         // Should filter out Python builtins
-        let type_strings = vec!["Optional[Node]", "List[str]", "Dict[str, Edge]", "Any"];
-        let result =
-            extract_referenced_types(type_strings.iter().map(|s| *s), "TestClass", "python");
+        let type_strings = ["Optional[Node]", "List[str]", "Dict[str, Edge]", "Any"];
+        let result = extract_referenced_types(type_strings.iter().copied(), "TestClass", "python");
         assert_eq!(result, vec!["Edge", "Node"]);
     }
 
     #[test]
     fn test_extract_referenced_types_typescript() {
         // Test TypeScript type extraction
-        let type_strings = vec![
+        let type_strings = [
             "Node | null",
             "Array<Edge>",
             "string",
@@ -495,37 +694,34 @@ mod tests {
             "Promise<void>",
         ];
         let result =
-            extract_referenced_types(type_strings.iter().map(|s| *s), "Service", "typescript");
+            extract_referenced_types(type_strings.iter().copied(), "Service", "typescript");
         assert_eq!(result, vec!["Edge", "MyClass", "Node"]);
     }
 
     #[test]
     fn test_extract_referenced_types_deduplicates() {
         // Should deduplicate types
-        let type_strings = vec!["Node", "Edge", "Node", "Edge", "Node"];
-        let result =
-            extract_referenced_types(type_strings.iter().map(|s| *s), "TestClass", "python");
+        let type_strings = ["Node", "Edge", "Node", "Edge", "Node"];
+        let result = extract_referenced_types(type_strings.iter().copied(), "TestClass", "python");
         assert_eq!(result, vec!["Edge", "Node"]);
     }
 
     #[test]
     fn test_extract_referenced_types_complex_annotations() {
         // Should handle complex type annotations
-        let type_strings = vec![
+        let type_strings = [
             "Callable[[Request, Response], Handler]",
             "dict[str, list[ConfigItem]]",
         ];
-        let result =
-            extract_referenced_types(type_strings.iter().map(|s| *s), "TestClass", "python");
+        let result = extract_referenced_types(type_strings.iter().copied(), "TestClass", "python");
         assert_eq!(result, vec!["ConfigItem", "Handler", "Request", "Response"]);
     }
 
     #[test]
     fn test_extract_referenced_types_http_style() {
         // Should handle HTTP-style type names
-        let type_strings = vec!["HTTPClient", "URLParser", "JSONResponse"];
-        let result =
-            extract_referenced_types(type_strings.iter().map(|s| *s), "TestClass", "python");
+        let type_strings = ["HTTPClient", "URLParser", "JSONResponse"];
+        let result = extract_referenced_types(type_strings.iter().copied(), "TestClass", "python");
         assert_eq!(result, vec!["HTTPClient", "JSONResponse", "URLParser"]);
     }
 }
@@ -4,8 +4,9 @@ use std::path::Path;
 use tree_sitter::{Node, Parser};
 
 use super::helpers::{
-    collect_type_strings_from_methods, count_lines, extract_referenced_types, find_child_by_type,
-    get_end_line, get_node_text, get_start_line,
+    call_arguments, collect_type_strings_from_methods, count_lines, extract_referenced_types,
+    find_child_by_type, get_end_column, get_end_line, get_node_text, get_start_column,
+    get_start_line, import_span, syntax_errors,
 };
 use crate::reducer::complexity;
 use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
@@ -57,12 +58,13 @@ pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
                 module.classes.push(extract_interface(&child, source));
             }
             "enum_declaration" => {
-                module.classes.push(extract_enum(&child, source));
+                module.push_enum(extract_enum(&child, source));
             }
             _ => {}
         }
     }
 
+    module.syntax_errors = syntax_errors(&root, source);
     Ok(module)
 }
 
@@ -115,7 +117,7 @@ fn extract_import(node: &Node, source: &str) -> Option<ImportDef> {
 
     let mut import = ImportDef {
         module,
-        ..Default::default()
+        ..import_span(node)
     };
 
     // Mark static imports with alias (consistent with Python parser)
@@ -131,6 +133,8 @@ fn extract_class(node: &Node, source: &str) -> ClassDef {
     let mut class_def = ClassDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -140,17 +144,11 @@ fn extract_class(node: &Node, source: &str) -> ClassDef {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
             }
             "type_parameters" => {
-                // Generic type parameters - add as decorator like "generic:<T>"
-                let generics = get_node_text(&child, source);
-                if !generics.is_empty() {
-                    class_def.decorators.push(format!("generic:{}", generics));
-                }
+                push_type_parameters(&child, source, &mut class_def.decorators);
             }
             "superclass" => {
                 // Get the full type including generics (e.g., AbstractList<E>)
@@ -217,6 +215,14 @@ fn extract_modifiers(node: &Node, source: &str, decorators: &mut Vec<String>) {
     }
 }
 
+/// Record generic type parameters as a decorator like "generic:<T extends Entity>".
+fn push_type_parameters(node: &Node, source: &str, decorators: &mut Vec<String>) {
+    let generics = get_node_text(node, source);
+    if !generics.is_empty() {
+        decorators.push(format!("generic:{}", generics));
+    }
+}
+
 /// Extract implemented interfaces.
 fn extract_interfaces(node: &Node, source: &str, bases: &mut Vec<String>) {
     let mut cursor = node.walk();
@@ -265,6 +271,8 @@ fn extract_method(node: &Node, source: &str) -> FunctionDef {
         is_method: true,
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -274,15 +282,16 @@ fn extract_method(node: &Node, source: &str) -> FunctionDef {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "identifier" => {
-                if func_def.name.is_empty() {
-                    func_def.name = get_node_text(&child, source).to_string();
-                }
+            "identifier" if func_def.name.is_empty() => {
+                func_def.name = get_node_text(&child, source).to_string();
             }
-            "type_identifier" | "generic_type" | "array_type" | "void_type" => {
-                if func_def.return_type.is_none() && func_def.name.is_empty() {
-                    func_def.return_type = Some(get_node_text(&child, source).to_string());
-                }
+            "type_parameters" => {
+                push_type_parameters(&child, source, &mut func_def.decorators);
+            }
+            "type_identifier" | "generic_type" | "array_type" | "void_type"
+                if func_def.return_type.is_none() && func_def.name.is_empty() =>
+            {
+                func_def.return_type = Some(get_node_text(&child, source).to_string());
             }
             "formal_parameters" => {
                 func_def.parameters = extract_parameters(&child, source);
@@ -310,6 +319,8 @@ fn extract_constructor(node: &Node, source: &str) -> FunctionDef {
         is_method: true,
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -322,6 +333,9 @@ fn extract_constructor(node: &Node, source: &str) -> FunctionDef {
             "identifier" => {
                 func_def.name = get_node_text(&child, source).to_string();
             }
+            "type_parameters" => {
+                push_type_parameters(&child, source, &mut func_def.decorators);
+            }
             "formal_parameters" => {
                 func_def.parameters = extract_parameters(&child, source);
             }
@@ -408,12 +422,12 @@ fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSi
     match node.kind() {
         "method_invocation" => {
             if let Some(call_site) = extract_method_invocation(node, source) {
-                results.push(call_site);
+                results.push(call_site.with_args(argument_types(node, source)));
             }
         }
         "object_creation_expression" => {
             if let Some(call_site) = extract_object_creation(node, source) {
-                results.push(call_site);
+                results.push(call_site.with_args(argument_types(node, source)));
             }
         }
         _ => {}
@@ -489,6 +503,7 @@ fn extract_method_invocation(node: &Node, source: &str) -> Option<CallSiteDef> {
         line,
         is_method_call: receiver.is_some(),
         receiver,
+        ..Default::default()
     })
 }
 
@@ -508,6 +523,7 @@ fn extract_object_creation(node: &Node, source: &str) -> Option<CallSiteDef> {
                     line,
                     is_method_call: false,
                     receiver: None,
+                    ..Default::default()
                 });
             }
             "generic_type" => {
@@ -519,6 +535,7 @@ fn extract_object_creation(node: &Node, source: &str) -> Option<CallSiteDef> {
                         line,
                         is_method_call: false,
                         receiver: None,
+                        ..Default::default()
                     });
                 }
             }
@@ -530,6 +547,7 @@ fn extract_object_creation(node: &Node, source: &str) -> Option<CallSiteDef> {
                     line,
                     is_method_call: false,
                     receiver: None,
+                    ..Default::default()
                 });
             }
             _ => {}
@@ -539,11 +557,62 @@ fn extract_object_creation(node: &Node, source: &str) -> Option<CallSiteDef> {
     None
 }
 
+/// Static types of a call's arguments, as far as each expression shows.
+///
+/// Literals map to their primitive or `String` type, `new Foo<T>()` and
+/// `(Foo) x` to `Foo`; anything else is unknown.
+fn argument_types(call: &Node, source: &str) -> Vec<Option<String>> {
+    call_arguments(call)
+        .iter()
+        .map(|arg| argument_type(arg, source))
+        .collect()
+}
+
+fn argument_type(arg: &Node, source: &str) -> Option<String> {
+    let text = get_node_text(arg, source);
+    let name = match arg.kind() {
+        "decimal_integer_literal"
+        | "hex_integer_literal"
+        | "octal_integer_literal"
+        | "binary_integer_literal" => {
+            if text.ends_with(['l', 'L']) {
+                "long"
+            } else {
+                "int"
+            }
+        }
+        "decimal_floating_point_literal" | "hex_floating_point_literal" => {
+            if text.ends_with(['f', 'F']) {
+                "float"
+            } else {
+                "double"
+            }
+        }
+        "string_literal" | "text_block" => "String",
+        "character_literal" => "char",
+        "true" | "false" => "boolean",
+        "object_creation_expression" | "cast_expression" => {
+            let type_node = arg.child_by_field_name("type")?;
+            let type_node = match type_node.kind() {
+                "generic_type" => find_child_by_type(&type_node, "type_identifier")
+                    .or_else(|| find_child_by_type(&type_node, "scoped_type_identifier"))
+                    .unwrap_or(type_node),
+                _ => type_node,
+            };
+            return Some(get_node_text(&type_node, source).to_string());
+        }
+        _ => return None,
+    };
+    Some(name.to_string())
+}
+
 /// Extract interface declaration.
 fn extract_interface(node: &Node, source: &str) -> ClassDef {
     let mut class_def = ClassDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -553,10 +622,11 @@ fn extract_interface(node: &Node, source: &str) -> ClassDef {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
+            }
+            "type_parameters" => {
+                push_type_parameters(&child, source, &mut class_def.decorators);
             }
             "extends_interfaces" => {
                 extract_interfaces(&child, source, &mut class_def.bases);
@@ -594,6 +664,8 @@ fn extract_enum(node: &Node, source: &str) -> ClassDef {
     let mut class_def = ClassDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -603,10 +675,8 @@ fn extract_enum(node: &Node, source: &str) -> ClassDef {
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
             }
             "enum_body" => {
                 // Extract enum constants as attributes
@@ -649,6 +719,57 @@ public class Hello {
         assert_eq!(result.classes[0].methods.len(), 1);
     }
 
+    #[test]
+    fn test_annotations_and_generics() {
+        let source = r#"
+@RestController
+@RequestMapping("/api")
+public class UserController<T extends Entity> {
+    @Override
+    public String toString() { return ""; }
+
+    @GetMapping("/{id}")
+    public <R> ResponseEntity<R> get(Long id) { return null; }
+}
+
+public interface Repository<T, ID> {
+    <S extends T> S save(S entity);
+}
+"#;
+        let result = parse(source, "UserController.java").unwrap();
+        let controller = &result.classes[0];
+        assert_eq!(
+            controller.decorators,
+            vec![
+                "@RestController",
+                "@RequestMapping(\"/api\")",
+                "public",
+                "generic:<T extends Entity>"
+            ]
+        );
+        assert_eq!(
+            controller.methods[0].decorators,
+            vec!["@Override", "public"]
+        );
+        assert_eq!(
+            controller.methods[1].decorators,
+            vec!["@GetMapping(\"/{id}\")", "public", "generic:<R>"]
+        );
+        assert_eq!(
+            controller.methods[1].return_type.as_deref(),
+            Some("ResponseEntity<R>")
+        );
+
+        let repository = &result.classes[1];
+        assert!(repository
+            .decorators
+            .contains(&"generic:<T, ID>".to_string()));
+        assert_eq!(
+            repository.methods[0].decorators,
+            vec!["generic:<S extends T>"]
+        );
+    }
+
     #[test]
     fn test_parse_interface() {
         let source = r#"
@@ -663,6 +784,22 @@ public interface Greeter {
             .contains(&"interface".to_string()));
     }
 
+    #[test]
+    fn test_parse_enum() {
+        let source = r#"
+public enum Status {
+    ACTIVE, SUSPENDED("s");
+
+    Status() {}
+    Status(String code) {}
+}
+"#;
+        let result = parse(source, "Status.java").unwrap();
+        assert_eq!(result.enums.len(), 1);
+        assert_eq!(result.enums[0].name, "Status");
+        assert_eq!(result.enums[0].variants, vec!["ACTIVE", "SUSPENDED"]);
+    }
+
     #[test]
     fn test_parse_import() {
         let source = r#"
@@ -707,6 +844,40 @@ public class Service {
         );
     }
 
+    #[test]
+    fn test_call_site_argument_types() {
+        let source = r#"
+public class Job {
+    public void run() {
+        schedule("nightly", 3, 10L, 2.5, 'x', false, new ArrayList<String>(), (Task) item, item);
+        Job copy = new Job(1);
+    }
+}
+"#;
+        let result = parse(source, "Job.java").unwrap();
+        let calls = &result.classes[0].methods[0].call_sites;
+        let schedule = calls.iter().find(|c| c.callee == "schedule").unwrap();
+        assert_eq!(schedule.arg_count, 9);
+        let types: Vec<Option<&str>> = schedule.arg_types.iter().map(|t| t.as_deref()).collect();
+        assert_eq!(
+            types,
+            vec![
+                Some("String"),
+                Some("int"),
+                Some("long"),
+                Some("double"),
+                Some("char"),
+                Some("boolean"),
+                Some("ArrayList"),
+                Some("Task"),
+                None,
+            ]
+        );
+
+        let constructor = calls.iter().find(|c| c.callee == "new Job").unwrap();
+        assert_eq!(constructor.arg_types, vec![Some("int".to_string())]);
+    }
+
     #[test]
     fn test_method_call_detection() {
         let source = r#"
@@ -820,7 +991,7 @@ public class Stream {
 
         // Should capture multiple calls in the chain
         assert!(
-            method.call_sites.len() >= 1,
+            !method.call_sites.is_empty(),
             "Expected at least 1 call site for stream chain"
         );
 
@@ -0,0 +1,668 @@
+//! Kotlin AST extractor using tree-sitter.
+//!
+//! Classes, interfaces and objects become `ClassDef`s (tagged `interface`/`object`
+//! plus modifiers such as `data` or `sealed` in `decorators`), with every
+//! delegation specifier after `:` recorded in `bases`. A `companion object` is
+//! folded into its class as static methods, so `Parcel.of()` resolves to the
+//! class node. Extension functions are plain functions tagged
+//! `extension:<Receiver>`, and `suspend` functions are async.
+
+use std::path::Path;
+use tree_sitter::{Node, Parser};
+
+use super::helpers::{
+    call_arguments, collect_type_strings_from_methods, count_lines, extract_referenced_types,
+    find_child_by_type, get_end_column, get_end_line, get_node_text, get_start_column,
+    get_start_line, import_span, syntax_errors,
+};
+use crate::reducer::complexity;
+use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
+
+/// Type node kinds that can name a receiver, parameter or return type.
+const TYPE_KINDS: &[&str] = &[
+    "user_type",
+    "nullable_type",
+    "non_nullable_type",
+    "function_type",
+    "parenthesized_type",
+];
+
+/// Parse Kotlin source code.
+pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_kotlin_ng::LANGUAGE.into())
+        .map_err(|e| format!("Failed to set Kotlin language: {}", e))?;
+
+    let tree = parser
+        .parse(source, None)
+        .ok_or("Failed to parse Kotlin source")?;
+    let root = tree.root_node();
+
+    let file_name = Path::new(file_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    let mut module = ModuleDef {
+        name: file_name,
+        path: file_path.to_string(),
+        language: "kotlin".to_string(),
+        total_lines: count_lines(source),
+        ..Default::default()
+    };
+
+    let mut cursor = root.walk();
+    for child in root.children(&mut cursor) {
+        match child.kind() {
+            "package_header" => {
+                if let Some(name) = find_child_by_type(&child, "qualified_identifier")
+                    .or_else(|| find_child_by_type(&child, "identifier"))
+                {
+                    module.name = get_node_text(&name, source).to_string();
+                }
+            }
+            "import" => {
+                module.imports.extend(extract_import(&child, source));
+            }
+            "class_declaration" | "object_declaration" => {
+                module.classes.push(extract_class(&child, source));
+            }
+            "function_declaration" => {
+                module.functions.push(extract_function(&child, source));
+            }
+            _ => {}
+        }
+    }
+
+    module.syntax_errors = syntax_errors(&root, source);
+    Ok(module)
+}
+
+/// Extract an import directive.
+///
+/// `import a.b.C as D` yields `a.b.C` aliased `D`; wildcards become `a.b.*`,
+/// matching the Java extractor.
+fn extract_import(node: &Node, source: &str) -> Option<ImportDef> {
+    let mut module = None;
+    let mut alias = None;
+    let mut is_wildcard = false;
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "qualified_identifier" => module = Some(get_node_text(&child, source).to_string()),
+            "identifier" if module.is_none() => {
+                module = Some(get_node_text(&child, source).to_string());
+            }
+            "identifier" => alias = Some(get_node_text(&child, source).to_string()),
+            "*" => is_wildcard = true,
+            _ => {}
+        }
+    }
+
+    let mut module = module?;
+    if is_wildcard {
+        module.push_str(".*");
+    }
+
+    Some(ImportDef {
+        module,
+        alias,
+        ..import_span(node)
+    })
+}
+
+/// Extract a class, interface, or object declaration.
+fn extract_class(node: &Node, source: &str) -> ClassDef {
+    let mut class_def = ClassDef {
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_kdoc(node, source),
+        ..Default::default()
+    };
+
+    if let Some(name) = node.child_by_field_name("name") {
+        class_def.name = get_node_text(&name, source).to_string();
+    }
+
+    let mut companion = None;
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "interface" => class_def.decorators.push("interface".to_string()),
+            "object" => class_def.decorators.push("object".to_string()),
+            "modifiers" => extract_modifiers(&child, source, &mut class_def.decorators),
+            "type_parameters" => {
+                class_def
+                    .decorators
+                    .push(format!("generic:{}", get_node_text(&child, source)));
+            }
+            "primary_constructor" => {
+                if let Some(params) = find_child_by_type(&child, "class_parameters") {
+                    extract_class_properties(&params, source, &mut class_def.attributes);
+                }
+            }
+            "delegation_specifiers" => extract_parents(&child, source, &mut class_def.bases),
+            "class_body" | "enum_class_body" => {
+                companion = extract_class_body(&child, source, &mut class_def);
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(object) = companion {
+        merge_companion(&mut class_def, object);
+    }
+
+    let type_strings = collect_type_strings_from_methods(&class_def.methods);
+    class_def.referenced_types = extract_referenced_types(
+        type_strings.iter().map(|s| s.as_str()),
+        &class_def.name,
+        "kotlin",
+    );
+
+    class_def
+}
+
+/// Record `val`/`var` constructor parameters, which are properties.
+fn extract_class_properties(node: &Node, source: &str, attributes: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for param in node.named_children(&mut cursor) {
+        if param.kind() != "class_parameter" {
+            continue;
+        }
+        let mut inner = param.walk();
+        let children: Vec<Node> = param.children(&mut inner).collect();
+        let is_property = children.iter().any(|c| matches!(c.kind(), "val" | "var"));
+        if let Some(name) = children.iter().find(|c| c.kind() == "identifier") {
+            if is_property {
+                attributes.push(get_node_text(name, source).to_string());
+            }
+        }
+    }
+}
+
+/// Record every supertype named after `:`, without type arguments.
+fn extract_parents(node: &Node, source: &str, bases: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for specifier in node.named_children(&mut cursor) {
+        let mut inner = specifier.walk();
+        for child in specifier.named_children(&mut inner) {
+            // Superclass calls: Base(1); interfaces: Shippable; delegation: Foo by impl
+            let type_node = match child.kind() {
+                "constructor_invocation" | "explicit_delegation" => {
+                    let mut type_cursor = child.walk();
+                    let found = child
+                        .named_children(&mut type_cursor)
+                        .find(|c| c.kind() == "user_type");
+                    found
+                }
+                "user_type" => Some(child),
+                _ => None,
+            };
+            if let Some(type_node) = type_node {
+                bases.push(type_name(&type_node, source));
+            }
+        }
+    }
+}
+
+/// The name of a `user_type`, dropping type arguments (`Comparable<T>` -> `Comparable`).
+fn type_name(node: &Node, source: &str) -> String {
+    let text = get_node_text(node, source);
+    text.split('<').next().unwrap_or(text).trim().to_string()
+}
+
+/// Extract members of a class body; returns the companion object, if any.
+fn extract_class_body(node: &Node, source: &str, class_def: &mut ClassDef) -> Option<ClassDef> {
+    let mut companion = None;
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "function_declaration" => {
+                let mut method = extract_function(&child, source);
+                method.is_method = true;
+                class_def.methods.push(method);
+            }
+            "secondary_constructor" => {
+                let mut constructor = extract_function(&child, source);
+                constructor.name = class_def.name.clone();
+                constructor.is_method = true;
+                constructor.decorators.push("constructor".to_string());
+                class_def.methods.push(constructor);
+            }
+            "property_declaration" => {
+                let mut inner = child.walk();
+                let declarations = child.named_children(&mut inner).filter(|c| {
+                    matches!(
+                        c.kind(),
+                        "variable_declaration" | "multi_variable_declaration"
+                    )
+                });
+                for declaration in declarations {
+                    let mut names = declaration.walk();
+                    for name in declaration
+                        .named_children(&mut names)
+                        .filter(|c| c.kind() == "identifier")
+                    {
+                        class_def
+                            .attributes
+                            .push(get_node_text(&name, source).to_string());
+                    }
+                }
+            }
+            "enum_entry" => {
+                if let Some(name) = find_child_by_type(&child, "identifier") {
+                    class_def
+                        .attributes
+                        .push(get_node_text(&name, source).to_string());
+                }
+            }
+            "companion_object" => {
+                let mut object = ClassDef::default();
+                let mut inner = child.walk();
+                for part in child.children(&mut inner) {
+                    match part.kind() {
+                        "delegation_specifiers" => {
+                            extract_parents(&part, source, &mut object.bases)
+                        }
+                        "class_body" => {
+                            extract_class_body(&part, source, &mut object);
+                        }
+                        _ => {}
+                    }
+                }
+                companion = Some(object);
+            }
+            "class_declaration" | "object_declaration" => {
+                // Nested type - add as attribute for reference
+                if let Some(name) = child.child_by_field_name("name") {
+                    class_def
+                        .attributes
+                        .push(format!("class:{}", get_node_text(&name, source)));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    companion
+}
+
+/// Fold a companion object's members into its class as static methods.
+fn merge_companion(class_def: &mut ClassDef, object: ClassDef) {
+    class_def.decorators.push("companion".to_string());
+    for mut method in object.methods {
+        method.is_static = true;
+        class_def.methods.push(method);
+    }
+    for attribute in object.attributes {
+        if !class_def.attributes.contains(&attribute) {
+            class_def.attributes.push(attribute);
+        }
+    }
+    for base in object.bases {
+        if !class_def.bases.contains(&base) {
+            class_def.bases.push(base);
+        }
+    }
+}
+
+/// Extract a `fun` or secondary constructor, with or without a body.
+fn extract_function(node: &Node, source: &str) -> FunctionDef {
+    let mut func_def = FunctionDef {
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_kdoc(node, source),
+        ..Default::default()
+    };
+
+    if let Some(name) = node.child_by_field_name("name") {
+        func_def.name = get_node_text(&name, source).to_string();
+    }
+
+    let mut seen_parameters = false;
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        let kind = child.kind();
+        match kind {
+            "modifiers" => {
+                extract_modifiers(&child, source, &mut func_def.decorators);
+                func_def.is_async = func_def.decorators.iter().any(|d| d == "suspend");
+            }
+            "type_parameters" => {
+                func_def
+                    .decorators
+                    .push(format!("generic:{}", get_node_text(&child, source)));
+            }
+            "function_value_parameters" => {
+                func_def.parameters = extract_parameters(&child, source);
+                seen_parameters = true;
+            }
+            // Extension receiver: fun String.shout()
+            _ if TYPE_KINDS.contains(&kind) && !seen_parameters => {
+                func_def
+                    .decorators
+                    .push(format!("extension:{}", get_node_text(&child, source)));
+            }
+            _ if TYPE_KINDS.contains(&kind) => {
+                func_def.return_type = Some(get_node_text(&child, source).to_string());
+            }
+            "function_body" | "block" => {
+                func_def.body_complexity = complexity::calculate_for_node(&child, source, "kotlin");
+                func_def.body_source = Some(get_node_text(&child, source).to_string());
+                func_def.call_sites = extract_call_sites(&child, source);
+            }
+            _ => {}
+        }
+    }
+
+    func_def
+}
+
+/// Extract annotations (with `@`) and modifiers such as `data`, `override`, `suspend`.
+fn extract_modifiers(node: &Node, source: &str, decorators: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        decorators.push(get_node_text(&child, source).to_string());
+    }
+}
+
+/// Extract `function_value_parameters`.
+///
+/// Modifiers such as `vararg` and default values are siblings of the
+/// `parameter` node rather than children of it.
+fn extract_parameters(node: &Node, source: &str) -> Vec<ParameterDef> {
+    let mut params: Vec<ParameterDef> = Vec::new();
+    let mut is_variadic = false;
+    let mut expect_default = false;
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "parameter_modifiers" => {
+                is_variadic = get_node_text(&child, source).contains("vararg");
+            }
+            "parameter" => {
+                let mut inner = child.walk();
+                let parts: Vec<Node> = child.named_children(&mut inner).collect();
+                let Some(name) = parts.iter().find(|c| c.kind() == "identifier") else {
+                    continue;
+                };
+                params.push(ParameterDef {
+                    name: get_node_text(name, source).to_string(),
+                    type_annotation: parts
+                        .iter()
+                        .find(|c| TYPE_KINDS.contains(&c.kind()))
+                        .map(|t| get_node_text(t, source).to_string()),
+                    is_variadic,
+                    ..Default::default()
+                });
+                is_variadic = false;
+            }
+            "=" => expect_default = true,
+            "," | "(" | ")" => expect_default = false,
+            _ if expect_default && child.is_named() => {
+                if let Some(param) = params.last_mut() {
+                    param.default_value = Some(get_node_text(&child, source).to_string());
+                }
+                expect_default = false;
+            }
+            _ => {}
+        }
+    }
+
+    params
+}
+
+/// Read the `/** ... */` comment directly above a declaration.
+fn extract_kdoc(node: &Node, source: &str) -> Option<String> {
+    let prev = node.prev_sibling()?;
+    if prev.kind() != "block_comment" {
+        return None;
+    }
+    let text = get_node_text(&prev, source);
+    let body = text.strip_prefix("/**")?.strip_suffix("*/")?;
+
+    let doc = body
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc)
+    }
+}
+
+/// Extract all call sites from a function body.
+fn extract_call_sites(body: &Node, source: &str) -> Vec<CallSiteDef> {
+    let mut call_sites = Vec::new();
+    find_call_sites_recursive(body, source, &mut call_sites);
+    call_sites
+}
+
+/// Recursively search for call expressions.
+fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSiteDef>) {
+    if node.kind() == "call_expression" {
+        if let Some(call_site) = extract_call(node, source) {
+            results.push(call_site.with_arg_count(call_arguments(node).len()));
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        find_call_sites_recursive(&child, source, results);
+    }
+}
+
+/// Extract a call site from `foo(x)`, `obj.foo(x)`, or `this.foo(x)`.
+///
+/// Kotlin has no `new`, so `Parcel(d)` is recorded as a call to `Parcel`.
+fn extract_call(node: &Node, source: &str) -> Option<CallSiteDef> {
+    let function = node.named_child(0)?;
+    let line = get_start_line(node);
+
+    match function.kind() {
+        "identifier" => Some(CallSiteDef {
+            callee: get_node_text(&function, source).to_string(),
+            line,
+            is_method_call: false,
+            receiver: None,
+            ..Default::default()
+        }),
+        "navigation_expression" => {
+            let receiver = function.named_child(0)?;
+            let method = function.named_child(function.named_child_count().checked_sub(1)?)?;
+            if method.kind() != "identifier" || method.id() == receiver.id() {
+                return None;
+            }
+            let method_name = get_node_text(&method, source);
+            let is_this_call = matches!(receiver.kind(), "this_expression" | "super_expression");
+            let receiver = get_node_text(&receiver, source);
+
+            Some(CallSiteDef {
+                callee: if is_this_call {
+                    method_name.to_string()
+                } else {
+                    format!("{}.{}", receiver, method_name)
+                },
+                line,
+                is_method_call: true,
+                receiver: Some(receiver.to_string()),
+                ..Default::default()
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+package com.acme.shipping
+
+import kotlin.math.max
+import com.acme.util.Logger as Log
+import com.acme.io.*
+
+/** Something that can be shipped. */
+interface Shippable {
+    fun ship(to: String): Unit
+}
+
+data class Parcel(val weight: Double, var label: String = "x", count: Int) : Base(1), Shippable {
+    val id: Int = 0
+
+    override fun ship(to: String) {
+        if (weight > 10 && to.isNotEmpty()) println("heavy") else log(to)
+    }
+
+    companion object {
+        fun of(d: Double): Parcel = Parcel(d, "y", 1)
+    }
+}
+
+object Registry {
+    fun run(vararg args: String, retries: Int = 3) = Parcel.of(2.0).ship("home")
+}
+
+fun String.shout(): String = uppercase()
+
+suspend fun <T> fetch(x: T): T {
+    return x
+}
+"#;
+
+    #[test]
+    fn test_parse_classes_objects_and_companions() {
+        let module = parse(SAMPLE, "src/Shipping.kt").unwrap();
+        assert_eq!(module.language, "kotlin");
+        assert_eq!(module.name, "com.acme.shipping");
+
+        let shippable = module
+            .classes
+            .iter()
+            .find(|c| c.name == "Shippable")
+            .unwrap();
+        assert!(shippable.decorators.contains(&"interface".to_string()));
+        assert_eq!(shippable.methods[0].name, "ship");
+        assert_eq!(
+            shippable.docstring.as_deref(),
+            Some("Something that can be shipped.")
+        );
+
+        // The superclass call and the interface both become bases
+        let parcel = module.classes.iter().find(|c| c.name == "Parcel").unwrap();
+        assert!(parcel.decorators.contains(&"data".to_string()));
+        assert_eq!(parcel.bases, vec!["Base", "Shippable"]);
+        // Only val/var constructor parameters are properties
+        assert_eq!(parcel.attributes, vec!["weight", "label", "id"]);
+
+        let ship = parcel.methods.iter().find(|m| m.name == "ship").unwrap();
+        assert!(ship.is_method);
+        assert!(ship.decorators.contains(&"override".to_string()));
+        assert_eq!(ship.parameters[0].name, "to");
+        assert_eq!(
+            ship.parameters[0].type_annotation.as_deref(),
+            Some("String")
+        );
+        assert_eq!(ship.body_complexity, 3);
+        assert!(ship.call_sites.iter().any(|c| c.callee == "log"));
+        assert!(ship
+            .call_sites
+            .iter()
+            .any(|c| c.callee == "to.isNotEmpty" && c.is_method_call));
+
+        // Companion members merge into the class as static methods
+        let of = parcel.methods.iter().find(|m| m.name == "of").unwrap();
+        assert!(of.is_static);
+        assert_eq!(of.return_type.as_deref(), Some("Parcel"));
+        assert!(of.call_sites.iter().any(|c| c.callee == "Parcel"));
+        assert!(parcel.decorators.contains(&"companion".to_string()));
+
+        let registry = module
+            .classes
+            .iter()
+            .find(|c| c.name == "Registry")
+            .unwrap();
+        assert!(registry.decorators.contains(&"object".to_string()));
+        let run = &registry.methods[0];
+        assert!(run.parameters[0].is_variadic);
+        assert!(!run.parameters[1].is_variadic);
+        assert_eq!(run.parameters[1].default_value.as_deref(), Some("3"));
+        assert!(run.call_sites.iter().any(|c| c.callee == "Parcel.of"));
+    }
+
+    #[test]
+    fn test_parse_top_level_and_extension_functions() {
+        let module = parse(SAMPLE, "Shipping.kt").unwrap();
+        assert_eq!(module.functions.len(), 2);
+
+        let shout = &module.functions[0];
+        assert_eq!(shout.name, "shout");
+        assert!(shout.decorators.contains(&"extension:String".to_string()));
+        assert_eq!(shout.return_type.as_deref(), Some("String"));
+        assert!(!shout.is_method);
+
+        let fetch = &module.functions[1];
+        assert!(fetch.is_async);
+        assert!(fetch.decorators.contains(&"generic:<T>".to_string()));
+        assert_eq!(fetch.return_type.as_deref(), Some("T"));
+    }
+
+    #[test]
+    fn test_parse_imports() {
+        let module = parse(SAMPLE, "Shipping.kt").unwrap();
+        let imports: Vec<(&str, Option<&str>)> = module
+            .imports
+            .iter()
+            .map(|i| (i.module.as_str(), i.alias.as_deref()))
+            .collect();
+        assert_eq!(
+            imports,
+            vec![
+                ("kotlin.math.max", None),
+                ("com.acme.util.Logger", Some("Log")),
+                ("com.acme.io.*", None),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_parse_enum_and_secondary_constructor() {
+        let source = r#"
+enum class Color(val rgb: Int) {
+    RED(0xFF0000), GREEN(0x00FF00);
+
+    fun hex(): String = rgb.toString(16)
+}
+
+class Point(val x: Int) {
+    constructor(s: String) : this(s.length)
+}
+"#;
+        let module = parse(source, "Color.kt").unwrap();
+        let color = &module.classes[0];
+        assert!(color.decorators.contains(&"enum".to_string()));
+        assert_eq!(color.attributes, vec!["rgb", "RED", "GREEN"]);
+        assert_eq!(color.methods[0].name, "hex");
+
+        let constructor = &module.classes[1].methods[0];
+        assert_eq!(constructor.name, "Point");
+        assert!(constructor.decorators.contains(&"constructor".to_string()));
+        assert_eq!(constructor.parameters[0].name, "s");
+    }
+}
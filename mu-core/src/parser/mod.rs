@@ -6,17 +6,30 @@
 
 use rayon::prelude::*;
 
-use crate::types::{FileInfo, ParseResult};
+use std::borrow::Cow;
+use std::time::Instant;
 
+use crate::types::{FileInfo, ParseResult, ParseStats};
+
+pub mod c;
+pub mod cpp;
 pub mod csharp;
+pub mod dart;
 pub mod go;
 pub mod java;
+pub mod kotlin;
+pub mod php;
 pub mod python;
+pub mod ruby;
 pub mod rust_lang;
+pub mod scala;
 pub mod typescript;
 
+mod capabilities;
 mod helpers;
 
+pub use capabilities::{capabilities, ParserCapabilities};
+
 /// Parse multiple files in parallel using rayon.
 ///
 /// Files are processed concurrently, with the number of threads controlled
@@ -51,15 +64,87 @@ pub fn parse_source(source: &str, path: &str, language: &str) -> ParseResult {
         "java" => java::parse(source, path),
         "rust" | "rs" => rust_lang::parse(source, path),
         "csharp" | "cs" | "c#" => csharp::parse(source, path),
+        "dart" => dart::parse(source, path),
+        "scala" | "sc" => scala::parse(source, path),
+        "kotlin" | "kt" | "kts" => kotlin::parse(source, path),
+        "php" => php::parse(source, path),
+        "ruby" | "rb" => ruby::parse(source, path),
+        "c" => c::parse(source, path),
+        "cpp" | "c++" | "cc" | "cxx" => cpp::parse(source, path),
         _ => Err(format!("Unsupported language: {}", language)),
     };
 
     match result {
-        Ok(module) => ParseResult::ok(module),
+        Ok(mut module) => {
+            let errors = std::mem::take(&mut module.syntax_errors);
+            ParseResult {
+                errors,
+                ..ParseResult::ok(module)
+            }
+        }
         Err(e) => ParseResult::err(e),
     }
 }
 
+/// Parse raw file contents for a specific language.
+///
+/// The bytes are decoded with [`decode_source`] first, so legacy sources
+/// that aren't valid UTF-8 still parse; how they were decoded is reported
+/// in the result's `warnings`.
+pub fn parse_bytes(source: &[u8], path: &str, language: &str) -> ParseResult {
+    let (text, warning) = decode_source(source);
+    let mut result = parse_source(&text, path, language);
+    result.warnings.extend(warning);
+    result
+}
+
+/// Decode source bytes as UTF-8, falling back to Latin-1.
+///
+/// A leading UTF-8 byte order mark is dropped. Latin-1 maps every byte to a
+/// character, so decoding never fails; the second value describes the
+/// fallback when it was needed.
+pub fn decode_source(source: &[u8]) -> (Cow<'_, str>, Option<String>) {
+    let source = source.strip_prefix(b"\xEF\xBB\xBF").unwrap_or(source);
+    match std::str::from_utf8(source) {
+        Ok(text) => (Cow::Borrowed(text), None),
+        Err(e) => {
+            let text: String = source.iter().map(|&b| b as char).collect();
+            let warning = format!(
+                "Invalid UTF-8 at byte {}; decoded as Latin-1",
+                e.valid_up_to()
+            );
+            (Cow::Owned(text), Some(warning))
+        }
+    }
+}
+
+/// Parse source code like [`parse_source`], also reporting how long it took
+/// and how much it produced.
+pub fn parse_source_with_stats(
+    source: &str,
+    path: &str,
+    language: &str,
+) -> (ParseResult, ParseStats) {
+    let start = Instant::now();
+    let result = parse_source(source, path, language);
+    let parse_duration = start.elapsed();
+
+    let node_count = result.module.as_ref().map_or(0, |module| {
+        1 + module.functions.len()
+            + module
+                .classes
+                .iter()
+                .map(|class| 1 + class.methods.len())
+                .sum::<usize>()
+    });
+    let stats = ParseStats {
+        parse_duration,
+        node_count,
+        byte_count: source.len(),
+    };
+    (result, stats)
+}
+
 /// Get supported languages.
 pub fn supported_languages() -> &'static [&'static str] {
     &[
@@ -78,5 +163,128 @@ pub fn supported_languages() -> &'static [&'static str] {
         "csharp",
         "cs",
         "c#",
+        "dart",
+        "scala",
+        "sc",
+        "kotlin",
+        "kt",
+        "kts",
+        "php",
+        "ruby",
+        "rb",
+        "c",
+        "cpp",
+        "c++",
+        "cc",
+        "cxx",
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_call_site_arg_counts() {
+        let cases = [
+            ("python", "def f():\n    g(1, x, key=2)\n", "g", 3),
+            ("python", "def f():\n    g(x for x in y)\n", "g", 1),
+            ("typescript", "function f() { g(1, 'a'); }", "g", 2),
+            ("go", "package m\nfunc f() { g(1, 2, 3) }\n", "g", 3),
+            ("rust", "fn f() { g(1, 2); }", "g", 2),
+            ("c", "void f() { g(1, /* two */ 2); }", "g", 2),
+            ("cpp", "void f() { p = new Point(1, 2); }", "new Point", 2),
+            ("java", "class A { void f() { g(1, \"s\"); } }", "g", 2),
+            ("csharp", "class A { void F() { G(1, x: 2); } }", "G", 2),
+            ("kotlin", "fun f() { g(1, 2) }", "g", 2),
+            ("php", "<?php function f() { g(1, 2); }", "g", 2),
+            ("ruby", "def f\n  g(1, 2)\nend\n", "g", 2),
+            ("scala", "object O { def f() = g(1, 2) }", "g", 2),
+            ("dart", "void f() { a.g(1, 2); }", "a.g", 2),
+            ("python", "def f():\n    g()\n", "g", 0),
+        ];
+
+        for (language, source, callee, expected) in cases {
+            let module = parse_source(source, "src/a", language)
+                .module
+                .unwrap_or_else(|| panic!("{} failed to parse", language));
+            let call = module
+                .functions
+                .iter()
+                .chain(module.classes.iter().flat_map(|c| &c.methods))
+                .flat_map(|f| &f.call_sites)
+                .find(|c| c.callee == callee)
+                .unwrap_or_else(|| panic!("{}: no call to {}", language, callee));
+            assert_eq!(call.arg_count, expected, "{}: {}", language, source);
+            assert_eq!(call.arg_types.len(), expected, "{}", language);
+        }
+    }
+
+    #[test]
+    fn test_parse_source_with_stats() {
+        let source =
+            "import os\n\nclass A:\n    def m(self):\n        pass\n\ndef f():\n    pass\n";
+        let (result, stats) = parse_source_with_stats(source, "a.py", "python");
+        assert!(result.success);
+        // module, A, A.m, f
+        assert_eq!(stats.node_count, 4);
+        assert_eq!(stats.byte_count, source.len());
+
+        let (result, stats) = parse_source_with_stats("x", "a.cobol", "cobol");
+        assert!(!result.success);
+        assert_eq!((stats.node_count, stats.byte_count), (0, 1));
+    }
+
+    #[test]
+    fn test_parse_source_reports_syntax_errors() {
+        let error = |line, column, message: &str| crate::types::ParseError {
+            line,
+            column,
+            message: message.to_string(),
+        };
+
+        // A partial module is still extracted around the error
+        let source = "def ok():\n    pass\n\ndef broken(:\n    pass\n\nclass A:\n    pass\n";
+        let result = parse_source(source, "a.py", "python");
+        assert!(result.success);
+        assert_eq!(result.errors, vec![error(4, 12, "Missing `)`")]);
+        let module = result.module.unwrap();
+        assert_eq!(module.functions[0].name, "ok");
+        assert_eq!(module.classes[0].name, "A");
+        assert!(module.syntax_errors.is_empty());
+
+        let result = parse_source("const x = 1 @@ 2;\nfunction f() {}\n", "a.ts", "ts");
+        assert_eq!(result.errors, vec![error(1, 13, "Unexpected `@@ 2`")]);
+
+        let result = parse_source("fn main() {\n    let x = 1\n}\n", "main.rs", "rust");
+        assert_eq!(result.errors, vec![error(2, 14, "Missing `;`")]);
+
+        let result = parse_source("def f():\n    pass\n", "a.py", "python");
+        assert!(result.errors.is_empty());
+    }
+
+    #[test]
+    fn test_parse_bytes_decodes_latin1() {
+        // "café" in Latin-1, which is invalid UTF-8
+        let source = b"def caf\xe9():\n    pass\n\ndef ok():\n    pass\n";
+        let result = parse_bytes(source, "legacy.py", "python");
+        assert!(result.success);
+        let module = result.module.unwrap();
+        let names: Vec<&str> = module.functions.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["café", "ok"]);
+        assert_eq!(
+            result.warnings,
+            vec!["Invalid UTF-8 at byte 7; decoded as Latin-1"]
+        );
+
+        // Valid UTF-8 (with or without a BOM) parses without warnings
+        let result = parse_bytes(
+            "\u{feff}def café():\n    pass\n".as_bytes(),
+            "a.py",
+            "python",
+        );
+        assert!(result.success);
+        assert!(result.warnings.is_empty());
+        assert_eq!(result.module.unwrap().functions[0].name, "café");
+    }
+}
@@ -0,0 +1,800 @@
+//! PHP AST extractor using tree-sitter.
+//!
+//! Classes, interfaces, traits and enums become `ClassDef`s, tagged
+//! `interface`/`trait`/`enum` in `decorators`. Names after `extends`,
+//! `implements` and trait `use` are resolved against the file's namespace and
+//! `use` aliases, so `bases` hold fully qualified names (`App\Models\User`)
+//! that match classes declared in other files. `use` declarations and
+//! `require`/`include` expressions become imports; includes whose path is not
+//! a literal are recorded as dynamic.
+
+use std::collections::HashMap;
+use std::path::Path;
+use tree_sitter::{Node, Parser};
+
+use super::helpers::{
+    call_arguments, collect_type_strings_from_methods, count_lines, extract_referenced_types,
+    get_end_column, get_end_line, get_node_text, get_start_column, get_start_line, import_span,
+    syntax_errors,
+};
+use crate::reducer::complexity;
+use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
+
+/// Expression kinds that pull in another file.
+const INCLUDE_KINDS: &[&str] = &[
+    "require_expression",
+    "require_once_expression",
+    "include_expression",
+    "include_once_expression",
+];
+
+/// Namespace and class aliases in effect at a point in the file.
+#[derive(Clone, Default)]
+struct Scope {
+    namespace: Option<String>,
+    /// Lowercased alias -> fully qualified class or namespace name
+    aliases: HashMap<String, String>,
+}
+
+impl Scope {
+    /// Resolve a class name as PHP does: `\A\B` is absolute, a leading alias
+    /// expands to its `use` target, and anything else is relative to the
+    /// current namespace.
+    fn resolve(&self, name: &str) -> String {
+        if let Some(absolute) = name.strip_prefix('\\') {
+            return absolute.to_string();
+        }
+        if let Some(relative) = name.strip_prefix("namespace\\") {
+            return self.qualify(relative);
+        }
+
+        let (first, rest) = match name.split_once('\\') {
+            Some((first, rest)) => (first, Some(rest)),
+            None => (name, None),
+        };
+        if let Some(target) = self.aliases.get(&first.to_lowercase()) {
+            return match rest {
+                Some(rest) => format!("{}\\{}", target, rest),
+                None => target.clone(),
+            };
+        }
+        self.qualify(name)
+    }
+
+    fn qualify(&self, name: &str) -> String {
+        match &self.namespace {
+            Some(namespace) => format!("{}\\{}", namespace, name),
+            None => name.to_string(),
+        }
+    }
+}
+
+/// Parse PHP source code.
+pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_php::LANGUAGE_PHP.into())
+        .map_err(|e| format!("Failed to set PHP language: {}", e))?;
+
+    let tree = parser
+        .parse(source, None)
+        .ok_or("Failed to parse PHP source")?;
+    let root = tree.root_node();
+
+    let file_name = Path::new(file_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    let mut module = ModuleDef {
+        name: file_name,
+        path: file_path.to_string(),
+        language: "php".to_string(),
+        total_lines: count_lines(source),
+        ..Default::default()
+    };
+
+    extract_statements(&root, source, &mut module, &mut Scope::default());
+    find_includes(&root, source, &mut module.imports);
+    module
+        .imports
+        .sort_by_key(|import| (import.start_line, import.start_column));
+
+    module.syntax_errors = syntax_errors(&root, source);
+    Ok(module)
+}
+
+/// Extract declarations from the top level or a braced namespace body.
+fn extract_statements(node: &Node, source: &str, module: &mut ModuleDef, scope: &mut Scope) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "namespace_definition" => {
+                let namespace = child
+                    .child_by_field_name("name")
+                    .map(|name| get_node_text(&name, source).to_string());
+                if module.namespace.is_none() {
+                    module.namespace = namespace.clone();
+                }
+
+                let inner = Scope {
+                    namespace,
+                    aliases: HashMap::new(),
+                };
+                match child.child_by_field_name("body") {
+                    // namespace Foo { ... } scopes only its body
+                    Some(body) => extract_statements(&body, source, module, &mut inner.clone()),
+                    // namespace Foo; applies to the rest of the file
+                    None => *scope = inner,
+                }
+            }
+            "namespace_use_declaration" => {
+                extract_use(&child, source, scope, &mut module.imports);
+            }
+            "class_declaration"
+            | "interface_declaration"
+            | "trait_declaration"
+            | "enum_declaration" => {
+                module.classes.push(extract_class(&child, source, scope));
+            }
+            "function_definition" => {
+                module.functions.push(extract_function(&child, source));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Extract a `use` declaration, registering class aliases in `scope`.
+///
+/// `use A\{B, C as D}` yields one import per clause. `use function` and
+/// `use const` imports are marked with `function`/`const` in `names`, like
+/// C#'s `using static`, and don't alias class names.
+fn extract_use(node: &Node, source: &str, scope: &mut Scope, imports: &mut Vec<ImportDef>) {
+    let declaration_kind = node
+        .child_by_field_name("type")
+        .map(|t| get_node_text(&t, source));
+    let mut prefix = None;
+    let mut clauses = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "namespace_name" => prefix = Some(get_node_text(&child, source)),
+            "namespace_use_clause" => clauses.push(child),
+            "namespace_use_group" => {
+                let mut inner = child.walk();
+                clauses.extend(
+                    child
+                        .named_children(&mut inner)
+                        .filter(|c| c.kind() == "namespace_use_clause"),
+                );
+            }
+            _ => {}
+        }
+    }
+
+    for clause in clauses {
+        let alias = clause
+            .child_by_field_name("alias")
+            .map(|a| get_node_text(&a, source).to_string());
+        let kind = clause
+            .child_by_field_name("type")
+            .map(|t| get_node_text(&t, source))
+            .or(declaration_kind);
+
+        let mut inner = clause.walk();
+        let found = clause
+            .named_children(&mut inner)
+            .find(|c| matches!(c.kind(), "qualified_name" | "name"));
+        let Some(name) = found else {
+            continue;
+        };
+        let name = get_node_text(&name, source).trim_start_matches('\\');
+        let module = match prefix {
+            Some(prefix) => format!("{}\\{}", prefix.trim_start_matches('\\'), name),
+            None => name.to_string(),
+        };
+
+        if kind.is_none() {
+            let local = alias
+                .clone()
+                .unwrap_or_else(|| module.rsplit('\\').next().unwrap_or(&module).to_string());
+            scope.aliases.insert(local.to_lowercase(), module.clone());
+        }
+
+        imports.push(ImportDef {
+            module,
+            names: kind.map(|k| vec![k.to_string()]).unwrap_or_default(),
+            alias,
+            ..import_span(&clause)
+        });
+    }
+}
+
+/// Record every `require`/`include` in the file, wherever it appears.
+fn find_includes(node: &Node, source: &str, imports: &mut Vec<ImportDef>) {
+    if INCLUDE_KINDS.contains(&node.kind()) {
+        imports.extend(extract_include(node, source));
+        return;
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        find_includes(&child, source, imports);
+    }
+}
+
+/// Extract an include path.
+///
+/// A literal path is kept as written and `__DIR__ . '/x.php'` becomes
+/// `./x.php`; anything else is a dynamic import with the expression as its
+/// pattern.
+fn extract_include(node: &Node, source: &str) -> Option<ImportDef> {
+    let keyword = node.child(0).map(|k| get_node_text(&k, source))?;
+    let argument = node.named_child(0)?;
+
+    let path = match argument.kind() {
+        "binary_expression" => {
+            let left = argument.child_by_field_name("left")?;
+            let right = argument.child_by_field_name("right")?;
+            (get_node_text(&left, source) == "__DIR__")
+                .then(|| string_literal(&right, source))
+                .flatten()
+                .map(|rest| format!("./{}", rest.trim_start_matches('/')))
+        }
+        "parenthesized_expression" => argument
+            .named_child(0)
+            .and_then(|inner| string_literal(&inner, source)),
+        _ => string_literal(&argument, source),
+    };
+
+    Some(match path {
+        Some(module) => ImportDef {
+            module,
+            ..import_span(node)
+        },
+        None => ImportDef {
+            module: "<dynamic>".to_string(),
+            is_dynamic: true,
+            dynamic_pattern: Some(get_node_text(&argument, source).to_string()),
+            dynamic_source: Some(keyword.to_string()),
+            ..import_span(node)
+        },
+    })
+}
+
+/// The contents of a string literal without interpolation.
+fn string_literal(node: &Node, source: &str) -> Option<String> {
+    if !matches!(node.kind(), "string" | "encapsed_string") {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let mut text = String::new();
+    for child in node.named_children(&mut cursor) {
+        if child.kind() != "string_content" {
+            return None;
+        }
+        text.push_str(get_node_text(&child, source));
+    }
+    Some(text)
+}
+
+/// Extract a class, interface, trait, or enum declaration.
+fn extract_class(node: &Node, source: &str, scope: &Scope) -> ClassDef {
+    let mut class_def = ClassDef {
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_phpdoc(node, source),
+        ..Default::default()
+    };
+
+    if let Some(name) = node.child_by_field_name("name") {
+        class_def.name = get_node_text(&name, source).to_string();
+    }
+
+    match node.kind() {
+        "interface_declaration" => class_def.decorators.push("interface".to_string()),
+        "trait_declaration" => class_def.decorators.push("trait".to_string()),
+        "enum_declaration" => class_def.decorators.push("enum".to_string()),
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "attribute_list" => extract_attributes(&child, source, &mut class_def.decorators),
+            "abstract_modifier" | "final_modifier" | "readonly_modifier" => {
+                class_def
+                    .decorators
+                    .push(get_node_text(&child, source).to_string());
+            }
+            "base_clause" | "class_interface_clause" => {
+                extract_class_names(&child, source, scope, &mut class_def.bases);
+            }
+            "declaration_list" | "enum_declaration_list" => {
+                extract_class_body(&child, source, scope, &mut class_def);
+            }
+            _ => {}
+        }
+    }
+
+    let type_strings = collect_type_strings_from_methods(&class_def.methods);
+    class_def.referenced_types = extract_referenced_types(
+        type_strings.iter().map(|s| s.as_str()),
+        &class_def.name,
+        "php",
+    );
+
+    class_def
+}
+
+/// Resolve every class name listed directly under `node`.
+fn extract_class_names(node: &Node, source: &str, scope: &Scope, names: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if matches!(child.kind(), "name" | "qualified_name") {
+            names.push(scope.resolve(get_node_text(&child, source)));
+        }
+    }
+}
+
+/// Extract methods, properties, constants, enum cases and used traits.
+fn extract_class_body(node: &Node, source: &str, scope: &Scope, class_def: &mut ClassDef) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "method_declaration" => {
+                let mut method = extract_function(&child, source);
+                method.is_method = true;
+                if method.name == "__construct" {
+                    if let Some(params) = child.child_by_field_name("parameters") {
+                        extract_promoted_properties(&params, source, &mut class_def.attributes);
+                    }
+                }
+                class_def.methods.push(method);
+            }
+            "property_declaration" => {
+                let mut inner = child.walk();
+                for element in child
+                    .named_children(&mut inner)
+                    .filter(|c| c.kind() == "property_element")
+                {
+                    if let Some(name) = element.child_by_field_name("name") {
+                        class_def.attributes.push(variable_name(&name, source));
+                    }
+                }
+            }
+            "const_declaration" => {
+                let mut inner = child.walk();
+                for element in child
+                    .named_children(&mut inner)
+                    .filter(|c| c.kind() == "const_element")
+                {
+                    if let Some(name) = element.named_child(0) {
+                        class_def
+                            .attributes
+                            .push(get_node_text(&name, source).to_string());
+                    }
+                }
+            }
+            "enum_case" => {
+                if let Some(name) = child.child_by_field_name("name") {
+                    class_def
+                        .attributes
+                        .push(get_node_text(&name, source).to_string());
+                }
+            }
+            // Traits are mixins: record them with the other supertypes
+            "use_declaration" => extract_class_names(&child, source, scope, &mut class_def.bases),
+            _ => {}
+        }
+    }
+}
+
+/// Record constructor parameters promoted to properties (`private User $user`).
+fn extract_promoted_properties(node: &Node, source: &str, attributes: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for param in node.named_children(&mut cursor) {
+        if param.kind() == "property_promotion_parameter" {
+            if let Some(name) = param.child_by_field_name("name") {
+                attributes.push(variable_name(&name, source));
+            }
+        }
+    }
+}
+
+/// A `$variable` name without the sigil.
+fn variable_name(node: &Node, source: &str) -> String {
+    get_node_text(node, source)
+        .trim_start_matches('&')
+        .trim_start_matches('$')
+        .to_string()
+}
+
+/// Extract a function or method, with or without a body.
+fn extract_function(node: &Node, source: &str) -> FunctionDef {
+    let mut func_def = FunctionDef {
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_phpdoc(node, source),
+        ..Default::default()
+    };
+
+    if let Some(name) = node.child_by_field_name("name") {
+        func_def.name = get_node_text(&name, source).to_string();
+    }
+    if let Some(params) = node.child_by_field_name("parameters") {
+        func_def.parameters = extract_parameters(&params, source);
+    }
+    if let Some(return_type) = node.child_by_field_name("return_type") {
+        func_def.return_type = Some(get_node_text(&return_type, source).to_string());
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "attribute_list" => extract_attributes(&child, source, &mut func_def.decorators),
+            "visibility_modifier" | "abstract_modifier" | "final_modifier" => {
+                func_def
+                    .decorators
+                    .push(get_node_text(&child, source).to_string());
+            }
+            "static_modifier" => func_def.is_static = true,
+            _ => {}
+        }
+    }
+
+    if let Some(body) = node.child_by_field_name("body") {
+        func_def.body_complexity = complexity::calculate_for_node(&body, source, "php");
+        func_def.body_source = Some(get_node_text(&body, source).to_string());
+        func_def.call_sites = extract_call_sites(&body, source);
+    }
+
+    func_def
+}
+
+/// Record each `#[Attribute(...)]` as its own decorator.
+fn extract_attributes(node: &Node, source: &str, decorators: &mut Vec<String>) {
+    let mut groups = node.walk();
+    for group in node.named_children(&mut groups) {
+        let mut cursor = group.walk();
+        for attribute in group
+            .named_children(&mut cursor)
+            .filter(|c| c.kind() == "attribute")
+        {
+            decorators.push(format!("#[{}]", get_node_text(&attribute, source)));
+        }
+    }
+}
+
+/// Extract `formal_parameters`, including promoted constructor parameters.
+fn extract_parameters(node: &Node, source: &str) -> Vec<ParameterDef> {
+    let mut params = Vec::new();
+
+    let mut cursor = node.walk();
+    for param in node.named_children(&mut cursor) {
+        if !matches!(
+            param.kind(),
+            "simple_parameter" | "variadic_parameter" | "property_promotion_parameter"
+        ) {
+            continue;
+        }
+        let Some(name) = param.child_by_field_name("name") else {
+            continue;
+        };
+        params.push(ParameterDef {
+            name: variable_name(&name, source),
+            type_annotation: param
+                .child_by_field_name("type")
+                .map(|t| get_node_text(&t, source).to_string()),
+            default_value: param
+                .child_by_field_name("default_value")
+                .map(|d| get_node_text(&d, source).to_string()),
+            is_variadic: param.kind() == "variadic_parameter",
+            ..Default::default()
+        });
+    }
+
+    params
+}
+
+/// Read the `/** ... */` comment directly above a declaration.
+fn extract_phpdoc(node: &Node, source: &str) -> Option<String> {
+    let prev = node.prev_sibling()?;
+    if prev.kind() != "comment" {
+        return None;
+    }
+    let text = get_node_text(&prev, source);
+    let body = text.strip_prefix("/**")?.strip_suffix("*/")?;
+
+    let doc = body
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc)
+    }
+}
+
+/// Extract all call sites from a function body.
+fn extract_call_sites(body: &Node, source: &str) -> Vec<CallSiteDef> {
+    let mut call_sites = Vec::new();
+    find_call_sites_recursive(body, source, &mut call_sites);
+    call_sites
+}
+
+/// Recursively search for calls and `new` expressions.
+fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSiteDef>) {
+    if let Some(call_site) = extract_call(node, source) {
+        results.push(call_site.with_arg_count(call_arguments(node).len()));
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        find_call_sites_recursive(&child, source, results);
+    }
+}
+
+/// The last segment of a possibly qualified name (`\App\helper` -> `helper`).
+fn short_name(name: &str) -> &str {
+    name.rsplit('\\').next().unwrap_or(name)
+}
+
+/// Extract a call site from a call or `new` node; `None` for other nodes and
+/// for dynamic calls such as `$fn()` or `$obj->$method()`.
+///
+/// `$this->save()`, `self::make()` and `static::make()` are calls on the
+/// current class; `$repo->find()` and `User::find()` are recorded as
+/// `repo.find` and `User.find`.
+fn extract_call(node: &Node, source: &str) -> Option<CallSiteDef> {
+    let line = get_start_line(node);
+
+    match node.kind() {
+        "function_call_expression" => {
+            let function = node.child_by_field_name("function")?;
+            if !matches!(function.kind(), "name" | "qualified_name") {
+                return None;
+            }
+            Some(CallSiteDef {
+                callee: short_name(get_node_text(&function, source)).to_string(),
+                line,
+                is_method_call: false,
+                receiver: None,
+                ..Default::default()
+            })
+        }
+        "member_call_expression" | "nullsafe_member_call_expression" | "scoped_call_expression" => {
+            let method = node.child_by_field_name("name")?;
+            if method.kind() != "name" {
+                return None;
+            }
+            let method = get_node_text(&method, source);
+            let receiver = node
+                .child_by_field_name("object")
+                .or_else(|| node.child_by_field_name("scope"))?;
+            let receiver = get_node_text(&receiver, source)
+                .replace("?->", ".")
+                .replace("->", ".")
+                .replace('$', "");
+            let receiver = match receiver.as_str() {
+                "static" => "self".to_string(),
+                _ => short_name(&receiver).to_string(),
+            };
+            let is_own_class = matches!(receiver.as_str(), "this" | "self" | "parent");
+
+            Some(CallSiteDef {
+                callee: if is_own_class {
+                    method.to_string()
+                } else {
+                    format!("{}.{}", receiver, method)
+                },
+                line,
+                is_method_call: true,
+                receiver: Some(receiver),
+                ..Default::default()
+            })
+        }
+        "object_creation_expression" => {
+            let mut cursor = node.walk();
+            let found = node
+                .named_children(&mut cursor)
+                .find(|c| matches!(c.kind(), "name" | "qualified_name"));
+            let class = found?;
+            Some(CallSiteDef {
+                callee: format!("new {}", short_name(get_node_text(&class, source))),
+                line,
+                is_method_call: false,
+                receiver: None,
+                ..Default::default()
+            })
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"<?php
+namespace App\Http\Controllers;
+
+use App\Models\User;
+use App\Models\{Post, Comment as Remark};
+use App\Contracts\HasName as Named;
+use function App\Support\helper;
+
+require_once __DIR__ . '/bootstrap.php';
+include 'lib/util.php';
+
+/**
+ * Handles users.
+ */
+#[Route('/users')]
+final class UserController extends Controller implements \JsonSerializable, Named
+{
+    use Loggable;
+
+    const MAX = 10;
+    private ?string $name = null;
+
+    public function __construct(private User $user, protected int $age = 3) {}
+
+    public static function show(int $id, string ...$rest): ?User
+    {
+        if ($id > 0 && $id < 10) {
+            return User::find($id);
+        } elseif ($id) {
+            helper();
+        }
+        $this->log("show");
+        $post = new Post();
+        return parent::show($id);
+    }
+}
+
+interface Shape { public function area(): float; }
+trait Loggable { public function log($msg) { \error_log($msg); } }
+enum Suit: string implements Shape { case Hearts = 'H'; case Spades = 'S'; }
+
+function helper($a = [1, 2]) {
+    require $a;
+    return strlen($a);
+}
+"#;
+
+    #[test]
+    fn test_parse_classes_and_name_resolution() {
+        let module = parse(SAMPLE, "app/Http/Controllers/UserController.php").unwrap();
+        assert_eq!(module.language, "php");
+        assert_eq!(module.namespace.as_deref(), Some("App\\Http\\Controllers"));
+
+        let controller = &module.classes[0];
+        assert_eq!(controller.name, "UserController");
+        assert_eq!(controller.docstring.as_deref(), Some("Handles users."));
+        assert!(controller.decorators.contains(&"final".to_string()));
+        assert!(controller
+            .decorators
+            .contains(&"#[Route('/users')]".to_string()));
+        // Unqualified names are relative to the namespace, aliases expand,
+        // and a leading backslash is absolute
+        assert_eq!(
+            controller.bases,
+            vec![
+                "App\\Http\\Controllers\\Controller",
+                "JsonSerializable",
+                "App\\Contracts\\HasName",
+                "App\\Http\\Controllers\\Loggable",
+            ]
+        );
+        assert_eq!(controller.attributes, vec!["MAX", "name", "user", "age"]);
+
+        let show = &controller.methods[1];
+        assert_eq!(show.name, "show");
+        assert!(show.is_method && show.is_static);
+        assert!(show.decorators.contains(&"public".to_string()));
+        assert_eq!(show.return_type.as_deref(), Some("?User"));
+        assert_eq!(show.parameters[0].type_annotation.as_deref(), Some("int"));
+        assert!(show.parameters[1].is_variadic);
+        assert_eq!(show.parameters[1].name, "rest");
+        // if + && + elseif
+        assert_eq!(show.body_complexity, 4);
+
+        let calls: Vec<(&str, Option<&str>)> = show
+            .call_sites
+            .iter()
+            .map(|c| (c.callee.as_str(), c.receiver.as_deref()))
+            .collect();
+        assert_eq!(
+            calls,
+            vec![
+                ("User.find", Some("User")),
+                ("helper", None),
+                ("log", Some("this")),
+                ("new Post", None),
+                ("show", Some("parent")),
+            ]
+        );
+
+        let kinds: Vec<(&str, &str)> = module.classes[1..]
+            .iter()
+            .map(|c| (c.name.as_str(), c.decorators[0].as_str()))
+            .collect();
+        assert_eq!(
+            kinds,
+            vec![
+                ("Shape", "interface"),
+                ("Loggable", "trait"),
+                ("Suit", "enum")
+            ]
+        );
+        let suit = &module.classes[3];
+        assert_eq!(suit.attributes, vec!["Hearts", "Spades"]);
+        assert_eq!(suit.bases, vec!["App\\Http\\Controllers\\Shape"]);
+        assert_eq!(
+            module.classes[2].methods[0].call_sites[0].callee,
+            "error_log"
+        );
+    }
+
+    #[test]
+    fn test_parse_imports_and_includes() {
+        let module = parse(SAMPLE, "UserController.php").unwrap();
+        let imports: Vec<(&str, Option<&str>, bool)> = module
+            .imports
+            .iter()
+            .map(|i| (i.module.as_str(), i.alias.as_deref(), i.is_dynamic))
+            .collect();
+        assert_eq!(
+            imports,
+            vec![
+                ("App\\Models\\User", None, false),
+                ("App\\Models\\Post", None, false),
+                ("App\\Models\\Comment", Some("Remark"), false),
+                ("App\\Contracts\\HasName", Some("Named"), false),
+                ("App\\Support\\helper", None, false),
+                ("./bootstrap.php", None, false),
+                ("lib/util.php", None, false),
+                ("<dynamic>", None, true),
+            ]
+        );
+        assert_eq!(module.imports[4].names, vec!["function"]);
+        assert_eq!(module.imports[7].dynamic_pattern.as_deref(), Some("$a"));
+        assert_eq!(module.imports[7].dynamic_source.as_deref(), Some("require"));
+
+        let helper = &module.functions[0];
+        assert_eq!(helper.name, "helper");
+        assert_eq!(
+            helper.parameters[0].default_value.as_deref(),
+            Some("[1, 2]")
+        );
+    }
+
+    #[test]
+    fn test_braced_namespaces_scope_their_uses() {
+        let source = r#"<?php
+namespace One {
+    use Lib\Base;
+    class A extends Base {}
+}
+namespace Two {
+    class B extends Base {}
+}
+"#;
+        let module = parse(source, "multi.php").unwrap();
+        assert_eq!(module.namespace.as_deref(), Some("One"));
+        assert_eq!(module.classes[0].bases, vec!["Lib\\Base"]);
+        assert_eq!(module.classes[1].bases, vec!["Two\\Base"]);
+    }
+}
@@ -4,8 +4,9 @@ use std::path::Path;
 use tree_sitter::{Node, Parser};
 
 use super::helpers::{
-    collect_type_strings_from_methods, count_lines, extract_referenced_types, find_child_by_type,
-    get_end_line, get_node_text, get_start_line,
+    call_arguments, collect_type_strings_from_methods, count_lines, extract_referenced_types,
+    find_child_by_type, get_end_column, get_end_line, get_node_text, get_start_column,
+    get_start_line, import_span, syntax_errors,
 };
 use crate::reducer::complexity;
 use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
@@ -79,6 +80,7 @@ pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
     let dynamic_imports = extract_dynamic_imports(&root, source);
     module.imports.extend(dynamic_imports);
 
+    module.syntax_errors = syntax_errors(&root, source);
     Ok(module)
 }
 
@@ -114,7 +116,7 @@ fn extract_import(node: &Node, source: &str) -> ImportDef {
         },
         alias,
         is_from: false,
-        ..Default::default()
+        ..import_span(node)
     }
 }
 
@@ -168,7 +170,7 @@ fn extract_from_import(node: &Node, source: &str) -> ImportDef {
         names,
         alias,
         is_from: true,
-        ..Default::default()
+        ..import_span(node)
     }
 }
 
@@ -178,6 +180,8 @@ fn extract_class(node: &Node, source: &str, decorators: Option<Vec<String>>) ->
         decorators: decorators.unwrap_or_default(),
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -274,6 +278,8 @@ fn extract_function(
         decorators: decorators.clone(),
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -449,10 +455,8 @@ fn extract_typed_default_parameter(node: &Node, source: &str) -> ParameterDef {
             "type" => {
                 type_annotation = Some(get_node_text(child, source).to_string());
             }
-            "=" => {
-                if i + 1 < children.len() {
-                    default = Some(get_node_text(&children[i + 1], source).to_string());
-                }
+            "=" if i + 1 < children.len() => {
+                default = Some(get_node_text(&children[i + 1], source).to_string());
             }
             _ => {}
         }
@@ -480,16 +484,10 @@ fn extract_decorated(node: &Node, source: &str, is_method: bool) -> Decorated {
     for child in node.children(&mut cursor) {
         match child.kind() {
             "decorator" => {
-                let mut dec_text = get_node_text(&child, source).to_string();
-                // Remove @ prefix
-                if dec_text.starts_with('@') {
-                    dec_text = dec_text[1..].to_string();
-                }
-                // Get just the decorator name (not arguments)
-                if let Some(paren_idx) = dec_text.find('(') {
-                    dec_text = dec_text[..paren_idx].to_string();
-                }
-                decorators.push(dec_text);
+                // Keep the whole expression, arguments included, on one line
+                let dec_text = get_node_text(&child, source);
+                let dec_text = dec_text.strip_prefix('@').unwrap_or(dec_text);
+                decorators.push(dec_text.split_whitespace().collect::<Vec<_>>().join(" "));
             }
             "class_definition" => {
                 return Decorated::Class(extract_class(&child, source, Some(decorators)));
@@ -565,23 +563,22 @@ fn check_dynamic_import_call(node: &Node, source: &str) -> Option<ImportDef> {
         find_child_by_type(node, "attribute").or_else(|| find_child_by_type(node, "identifier"))?;
 
     let func_text = get_node_text(&func_node, source);
-    let line_number = get_start_line(node);
 
     // Check for importlib.import_module()
     if func_text == "importlib.import_module" || func_text.ends_with(".import_module") {
-        return extract_importlib_call(node, source, line_number);
+        return extract_importlib_call(node, source);
     }
 
     // Check for __import__()
     if func_text == "__import__" {
-        return extract_builtin_import_call(node, source, line_number);
+        return extract_builtin_import_call(node, source);
     }
 
     None
 }
 
 /// Extract importlib.import_module() call.
-fn extract_importlib_call(node: &Node, source: &str, line_number: u32) -> Option<ImportDef> {
+fn extract_importlib_call(node: &Node, source: &str) -> Option<ImportDef> {
     let args_node = find_child_by_type(node, "argument_list")?;
 
     let mut cursor = args_node.walk();
@@ -604,8 +601,7 @@ fn extract_importlib_call(node: &Node, source: &str, line_number: u32) -> Option
             module: module_name,
             is_dynamic: true,
             dynamic_source: Some("importlib".to_string()),
-            line_number,
-            ..Default::default()
+            ..import_span(node)
         })
     } else {
         // Dynamic pattern
@@ -614,14 +610,13 @@ fn extract_importlib_call(node: &Node, source: &str, line_number: u32) -> Option
             is_dynamic: true,
             dynamic_pattern: Some(arg_text.to_string()),
             dynamic_source: Some("importlib".to_string()),
-            line_number,
-            ..Default::default()
+            ..import_span(node)
         })
     }
 }
 
 /// Extract __import__() call.
-fn extract_builtin_import_call(node: &Node, source: &str, line_number: u32) -> Option<ImportDef> {
+fn extract_builtin_import_call(node: &Node, source: &str) -> Option<ImportDef> {
     let args_node = find_child_by_type(node, "argument_list")?;
 
     let mut cursor = args_node.walk();
@@ -638,8 +633,7 @@ fn extract_builtin_import_call(node: &Node, source: &str, line_number: u32) -> O
             module: module_name,
             is_dynamic: true,
             dynamic_source: Some("__import__".to_string()),
-            line_number,
-            ..Default::default()
+            ..import_span(node)
         })
     } else {
         // Dynamic pattern
@@ -648,8 +642,7 @@ fn extract_builtin_import_call(node: &Node, source: &str, line_number: u32) -> O
             is_dynamic: true,
             dynamic_pattern: Some(arg_text.to_string()),
             dynamic_source: Some("__import__".to_string()),
-            line_number,
-            ..Default::default()
+            ..import_span(node)
         })
     }
 }
@@ -665,7 +658,7 @@ fn extract_call_sites(body: &Node, source: &str) -> Vec<CallSiteDef> {
 fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSiteDef>) {
     if node.kind() == "call" {
         if let Some(call_site) = extract_call_site(node, source) {
-            results.push(call_site);
+            results.push(call_site.with_arg_count(call_arguments(node).len()));
         }
     }
 
@@ -690,6 +683,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         "attribute" => {
@@ -721,6 +715,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: true,
                 receiver,
+                ..Default::default()
             })
         }
         _ => {
@@ -731,6 +726,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
     }
@@ -769,6 +765,37 @@ class MyClass(BaseClass):
         assert_eq!(result.classes[0].methods.len(), 1);
     }
 
+    #[test]
+    fn test_decorators_keep_arguments() {
+        let source = r#"
+@app.get("/users")
+@requires_auth(
+    scope="read",
+)
+async def list_users():
+    pass
+
+class Repo:
+    @staticmethod
+    def create():
+        pass
+
+    @property
+    def size(self):
+        return 0
+"#;
+        let result = parse(source, "routes.py").unwrap();
+        assert_eq!(
+            result.functions[0].decorators,
+            vec![r#"app.get("/users")"#, r#"requires_auth( scope="read", )"#]
+        );
+
+        let methods = &result.classes[0].methods;
+        assert_eq!(methods[0].decorators, vec!["staticmethod"]);
+        assert!(methods[0].is_static);
+        assert!(methods[1].is_property);
+    }
+
     #[test]
     fn test_parse_imports() {
         let source = r#"
@@ -783,6 +810,26 @@ from typing import Optional, List
         assert_eq!(result.imports[1].names, vec!["Path"]);
     }
 
+    #[test]
+    fn test_spans_cover_whole_node() {
+        let source = "from a import (\n    b,\n)\n\nclass C:\n    def m(self):\n        pass\n";
+        let result = parse(source, "test.py").unwrap();
+
+        let import = &result.imports[0];
+        assert_eq!(
+            (import.start_line, import.start_column),
+            (1, 1),
+            "import starts at `from`"
+        );
+        assert_eq!((import.end_line, import.end_column), (3, 2));
+
+        let class = &result.classes[0];
+        assert_eq!((class.start_line, class.start_column), (5, 1));
+        let method = &class.methods[0];
+        assert_eq!((method.start_line, method.start_column), (6, 5));
+        assert_eq!((method.end_line, method.end_column), (7, 13));
+    }
+
     #[test]
     fn test_extract_call_sites() {
         let source = r#"
@@ -0,0 +1,541 @@
+//! Ruby AST extractor using tree-sitter.
+//!
+//! Classes and modules both become `ClassDef`s (modules tagged `module` in
+//! `decorators`). A superclass and every `include`/`extend`/`prepend` mixin is
+//! recorded in `bases`, so mixins produce inheritance edges the same way base
+//! classes do. Nested classes are flattened into the module's class list.
+//! `def self.foo` and methods inside `class << self` are static methods, and
+//! `attr_accessor`/`attr_reader`/`attr_writer` names become attributes.
+//! `require` and `require_relative` become imports; relative requires are
+//! emitted as `./path` so they resolve against the requiring file.
+
+use std::path::Path;
+use tree_sitter::{Node, Parser};
+
+use super::helpers::{
+    call_arguments, count_lines, get_end_column, get_end_line, get_node_text, get_start_column,
+    get_start_line, import_span, syntax_errors,
+};
+use crate::reducer::complexity;
+use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
+
+/// Parse Ruby source code.
+pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_ruby::LANGUAGE.into())
+        .map_err(|e| format!("Failed to set Ruby language: {}", e))?;
+
+    let tree = parser
+        .parse(source, None)
+        .ok_or("Failed to parse Ruby source")?;
+    let root = tree.root_node();
+
+    let file_name = Path::new(file_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    let mut module = ModuleDef {
+        name: file_name,
+        path: file_path.to_string(),
+        language: "ruby".to_string(),
+        total_lines: count_lines(source),
+        ..Default::default()
+    };
+
+    let mut cursor = root.walk();
+    for child in root.children(&mut cursor) {
+        match child.kind() {
+            "call" => {
+                if let Some(import) = extract_require(&child, source) {
+                    module.imports.push(import);
+                }
+            }
+            "class" | "module" => extract_class(&child, source, &mut module.classes),
+            "method" => module.functions.push(extract_method(&child, source)),
+            "singleton_method" => {
+                let mut func_def = extract_method(&child, source);
+                func_def.is_static = true;
+                module.functions.push(func_def);
+            }
+            _ => {}
+        }
+    }
+
+    module.syntax_errors = syntax_errors(&root, source);
+    Ok(module)
+}
+
+/// Extract `require 'x'` or `require_relative 'x'` as an import.
+fn extract_require(node: &Node, source: &str) -> Option<ImportDef> {
+    if node.child_by_field_name("receiver").is_some() {
+        return None;
+    }
+    let method = get_node_text(&node.child_by_field_name("method")?, source);
+    if method != "require" && method != "require_relative" {
+        return None;
+    }
+
+    let path = string_argument(node, source)?;
+    let module = if method == "require_relative" && !path.starts_with('.') {
+        format!("./{}", path)
+    } else {
+        path
+    };
+
+    Some(ImportDef {
+        module,
+        ..import_span(node)
+    })
+}
+
+/// The first argument of a call, if it is a plain string literal.
+fn string_argument(node: &Node, source: &str) -> Option<String> {
+    let arguments = node.child_by_field_name("arguments")?;
+    let first = arguments.named_child(0)?;
+    if first.kind() != "string" {
+        return None;
+    }
+    let mut cursor = first.walk();
+    let content = first
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "string_content")?;
+    Some(get_node_text(&content, source).to_string())
+}
+
+/// Extract a class or module, pushing it and any nested classes onto `classes`.
+fn extract_class(node: &Node, source: &str, classes: &mut Vec<ClassDef>) {
+    let mut class_def = ClassDef {
+        name: node
+            .child_by_field_name("name")
+            .map(|n| constant_name(&n, source))
+            .unwrap_or_default(),
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_comment_doc(node, source),
+        ..Default::default()
+    };
+
+    if node.kind() == "module" {
+        class_def.decorators.push("module".to_string());
+    }
+
+    if let Some(superclass) = node.child_by_field_name("superclass") {
+        if let Some(parent) = superclass.named_child(0) {
+            class_def
+                .bases
+                .push(get_node_text(&parent, source).to_string());
+        }
+    }
+
+    let mut nested = Vec::new();
+    if let Some(body) = node.child_by_field_name("body") {
+        extract_class_body(&body, source, &mut class_def, &mut nested, false);
+    }
+
+    classes.push(class_def);
+    classes.extend(nested);
+}
+
+/// The last segment of a possibly scoped constant (`Shop::Widget` -> `Widget`).
+fn constant_name(node: &Node, source: &str) -> String {
+    match node.kind() {
+        "scope_resolution" => node
+            .child_by_field_name("name")
+            .map(|n| get_node_text(&n, source).to_string())
+            .unwrap_or_default(),
+        _ => get_node_text(node, source).to_string(),
+    }
+}
+
+/// Extract members of a class or module body.
+///
+/// `singleton` is set inside `class << self`, where every method is static.
+fn extract_class_body(
+    node: &Node,
+    source: &str,
+    class_def: &mut ClassDef,
+    nested: &mut Vec<ClassDef>,
+    singleton: bool,
+) {
+    // Bare `private`/`protected`/`public` changes visibility for what follows
+    let mut visibility: Option<String> = None;
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "identifier" => {
+                let text = get_node_text(&child, source);
+                if matches!(text, "private" | "protected" | "public") {
+                    visibility = (text != "public").then(|| text.to_string());
+                }
+            }
+            "method" | "singleton_method" => {
+                let mut method = extract_method(&child, source);
+                method.is_method = true;
+                method.is_static = singleton || child.kind() == "singleton_method";
+                if let Some(ref visibility) = visibility {
+                    method.decorators.push(visibility.clone());
+                }
+                class_def.methods.push(method);
+            }
+            "singleton_class" => {
+                if let Some(body) = child.child_by_field_name("body") {
+                    extract_class_body(&body, source, class_def, nested, true);
+                }
+            }
+            "call" => extract_class_call(&child, source, class_def),
+            "class" | "module" => {
+                let start = nested.len();
+                extract_class(&child, source, nested);
+                if let Some(inner) = nested.get(start) {
+                    class_def.attributes.push(format!("class:{}", inner.name));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Handle macro-style calls in a class body: mixins, attribute accessors and
+/// `private def foo`.
+fn extract_class_call(node: &Node, source: &str, class_def: &mut ClassDef) {
+    if node.child_by_field_name("receiver").is_some() {
+        return;
+    }
+    let Some(method) = node.child_by_field_name("method") else {
+        return;
+    };
+    let Some(arguments) = node.child_by_field_name("arguments") else {
+        return;
+    };
+
+    let name = get_node_text(&method, source);
+    let mut cursor = arguments.walk();
+    for arg in arguments.named_children(&mut cursor) {
+        match (name, arg.kind()) {
+            ("include" | "extend" | "prepend", "constant" | "scope_resolution") => {
+                class_def
+                    .bases
+                    .push(get_node_text(&arg, source).to_string());
+            }
+            ("attr_accessor" | "attr_reader" | "attr_writer", "simple_symbol") => {
+                let attr = get_node_text(&arg, source).trim_start_matches(':');
+                class_def.attributes.push(attr.to_string());
+            }
+            ("private" | "protected" | "public", "method" | "singleton_method") => {
+                let mut func_def = extract_method(&arg, source);
+                func_def.is_method = true;
+                func_def.is_static = arg.kind() == "singleton_method";
+                if name != "public" {
+                    func_def.decorators.push(name.to_string());
+                }
+                class_def.methods.push(func_def);
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Extract a `def`, either instance (`method`) or `def self.foo` (`singleton_method`).
+fn extract_method(node: &Node, source: &str) -> FunctionDef {
+    let mut func_def = FunctionDef {
+        name: node
+            .child_by_field_name("name")
+            .map(|n| get_node_text(&n, source).to_string())
+            .unwrap_or_default(),
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_comment_doc(node, source),
+        ..Default::default()
+    };
+
+    if let Some(params) = node.child_by_field_name("parameters") {
+        func_def.parameters = extract_parameters(&params, source);
+    }
+
+    if let Some(body) = node.child_by_field_name("body") {
+        func_def.body_complexity = complexity::calculate_for_node(&body, source, "ruby");
+        func_def.body_source = Some(get_node_text(&body, source).to_string());
+        func_def.call_sites = extract_call_sites(&body, source);
+    }
+
+    func_def
+}
+
+/// Extract `method_parameters` or `lambda_parameters`.
+fn extract_parameters(node: &Node, source: &str) -> Vec<ParameterDef> {
+    let mut params = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let name_of = |n: &Node| {
+            n.child_by_field_name("name")
+                .map(|name| get_node_text(&name, source).to_string())
+        };
+
+        let param = match child.kind() {
+            "identifier" => ParameterDef {
+                name: get_node_text(&child, source).to_string(),
+                ..Default::default()
+            },
+            "optional_parameter" | "keyword_parameter" => ParameterDef {
+                name: name_of(&child).unwrap_or_default(),
+                default_value: child
+                    .child_by_field_name("value")
+                    .map(|v| get_node_text(&v, source).to_string()),
+                is_keyword: child.kind() == "keyword_parameter",
+                ..Default::default()
+            },
+            // *args
+            "splat_parameter" => ParameterDef {
+                name: name_of(&child).unwrap_or_else(|| "args".to_string()),
+                is_variadic: true,
+                ..Default::default()
+            },
+            // **opts
+            "hash_splat_parameter" => ParameterDef {
+                name: name_of(&child).unwrap_or_else(|| "opts".to_string()),
+                is_keyword: true,
+                ..Default::default()
+            },
+            "block_parameter" => ParameterDef {
+                name: name_of(&child).unwrap_or_else(|| "block".to_string()),
+                ..Default::default()
+            },
+            _ => continue,
+        };
+        params.push(param);
+    }
+
+    params
+}
+
+/// Read the run of `#` comments directly above a definition.
+fn extract_comment_doc(node: &Node, source: &str) -> Option<String> {
+    let mut lines = Vec::new();
+    let mut expected_line = node.start_position().row;
+    // Comments before the first statement of a body attach to the enclosing
+    // class rather than to the body itself
+    let mut prev = node.prev_sibling().or_else(|| {
+        node.parent()
+            .filter(|p| p.kind() == "body_statement")
+            .and_then(|p| p.prev_sibling())
+    });
+
+    while let Some(comment) = prev {
+        // Stop at anything but a comment ending on the line just above
+        if comment.kind() != "comment" || comment.end_position().row + 1 != expected_line {
+            break;
+        }
+        let text = get_node_text(&comment, source);
+        lines.push(text.trim_start_matches('#').trim().to_string());
+        expected_line = comment.start_position().row;
+        prev = comment.prev_sibling();
+    }
+
+    lines.reverse();
+    let doc = lines
+        .into_iter()
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc)
+    }
+}
+
+/// Extract all call sites from a method body.
+fn extract_call_sites(body: &Node, source: &str) -> Vec<CallSiteDef> {
+    let mut call_sites = Vec::new();
+    find_call_sites_recursive(body, source, &mut call_sites);
+    call_sites
+}
+
+/// Recursively search for `call` nodes.
+///
+/// Argument-less calls without a receiver parse as bare identifiers and are
+/// indistinguishable from local variables, so they are not recorded.
+fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSiteDef>) {
+    if node.kind() == "call" {
+        if let Some(call_site) = extract_call(node, source) {
+            results.push(call_site.with_arg_count(call_arguments(node).len()));
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        find_call_sites_recursive(&child, source, results);
+    }
+}
+
+/// Extract a call site from `foo(x)`, `obj.foo`, `self.foo`, or `Klass.new`.
+fn extract_call(node: &Node, source: &str) -> Option<CallSiteDef> {
+    let method = get_node_text(&node.child_by_field_name("method")?, source);
+    let line = get_start_line(node);
+
+    let Some(receiver) = node.child_by_field_name("receiver") else {
+        return Some(CallSiteDef {
+            callee: method.to_string(),
+            line,
+            is_method_call: false,
+            receiver: None,
+            ..Default::default()
+        });
+    };
+
+    let receiver_text = get_node_text(&receiver, source);
+    let callee = if receiver.kind() == "self" {
+        method.to_string()
+    } else if method == "new" && matches!(receiver.kind(), "constant" | "scope_resolution") {
+        format!("new {}", receiver_text)
+    } else {
+        format!("{}.{}", receiver_text, method)
+    };
+
+    Some(CallSiteDef {
+        callee,
+        line,
+        is_method_call: true,
+        receiver: Some(receiver_text.to_string()),
+        ..Default::default()
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+require 'json'
+require_relative 'support/helper'
+
+module Shop
+  # A thing for sale.
+  # Priced in cents.
+  class Widget < Base::Thing
+    include Comparable
+    extend Forwardable
+    attr_accessor :name, :price
+    attr_reader :id
+
+    def initialize(name, price = 1, *rest, key:, **opts, &blk)
+      @name = name
+      notify(name) if price > 1 && name
+      Logger.info("created")
+      self.register
+      @id = Widget.new
+    end
+
+    def self.build
+      new
+    end
+
+    class << self
+      def registry; end
+    end
+
+    private
+
+    def secret?
+      unless @name then 1 end
+    end
+  end
+end
+
+def helper_fn(x)
+  puts x
+end
+"#;
+
+    #[test]
+    fn test_parse_module_class_and_methods() {
+        let module = parse(SAMPLE, "app/models/widget.rb").unwrap();
+        assert_eq!(module.language, "ruby");
+        assert_eq!(module.name, "widget");
+
+        let shop = module.classes.iter().find(|c| c.name == "Shop").unwrap();
+        assert!(shop.decorators.contains(&"module".to_string()));
+        assert!(shop.attributes.contains(&"class:Widget".to_string()));
+
+        // Superclass and mixins all become bases
+        let widget = module.classes.iter().find(|c| c.name == "Widget").unwrap();
+        assert_eq!(
+            widget.bases,
+            vec!["Base::Thing", "Comparable", "Forwardable"]
+        );
+        assert_eq!(widget.attributes, vec!["name", "price", "id"]);
+        assert_eq!(
+            widget.docstring.as_deref(),
+            Some("A thing for sale.\nPriced in cents.")
+        );
+
+        let init = &widget.methods[0];
+        assert_eq!(init.name, "initialize");
+        assert!(init.is_method);
+        assert!(!init.is_static);
+        let names: Vec<_> = init.parameters.iter().map(|p| p.name.as_str()).collect();
+        assert_eq!(names, vec!["name", "price", "rest", "key", "opts", "blk"]);
+        assert_eq!(init.parameters[1].default_value.as_deref(), Some("1"));
+        assert!(init.parameters[2].is_variadic);
+        assert!(init.parameters[3].is_keyword);
+        assert_eq!(init.body_complexity, 3);
+
+        let callees: Vec<_> = init.call_sites.iter().map(|c| c.callee.as_str()).collect();
+        assert_eq!(
+            callees,
+            vec!["notify", "Logger.info", "register", "new Widget"]
+        );
+        assert_eq!(init.call_sites[2].receiver.as_deref(), Some("self"));
+
+        let static_methods: Vec<_> = widget
+            .methods
+            .iter()
+            .filter(|m| m.is_static)
+            .map(|m| m.name.as_str())
+            .collect();
+        assert_eq!(static_methods, vec!["build", "registry"]);
+
+        let secret = widget.methods.iter().find(|m| m.name == "secret?").unwrap();
+        assert!(secret.decorators.contains(&"private".to_string()));
+        assert_eq!(secret.body_complexity, 2);
+
+        assert_eq!(module.functions.len(), 1);
+        assert_eq!(module.functions[0].name, "helper_fn");
+        assert!(!module.functions[0].is_method);
+    }
+
+    #[test]
+    fn test_parse_requires() {
+        let module = parse(SAMPLE, "widget.rb").unwrap();
+        let imports: Vec<_> = module.imports.iter().map(|i| i.module.as_str()).collect();
+        assert_eq!(imports, vec!["json", "./support/helper"]);
+
+        let module = parse("require_relative '../lib/x'\n", "app/y.rb").unwrap();
+        assert_eq!(module.imports[0].module, "../lib/x");
+    }
+
+    #[test]
+    fn test_inline_private_def() {
+        let source = r#"
+class Vault
+  private def combination
+    42
+  end
+end
+"#;
+        let module = parse(source, "vault.rb").unwrap();
+        let method = &module.classes[0].methods[0];
+        assert_eq!(method.name, "combination");
+        assert!(method.decorators.contains(&"private".to_string()));
+    }
+}
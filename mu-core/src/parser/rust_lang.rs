@@ -4,8 +4,9 @@ use std::path::Path;
 use tree_sitter::{Node, Parser};
 
 use super::helpers::{
-    collect_type_strings_from_methods, count_lines, extract_referenced_types, find_child_by_type,
-    get_end_line, get_node_text, get_start_line,
+    call_arguments, collect_type_strings_from_methods, count_lines, extract_referenced_types,
+    find_child_by_type, get_end_column, get_end_line, get_node_text, get_start_column,
+    get_start_line, import_span, syntax_errors,
 };
 use crate::reducer::complexity;
 use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
@@ -37,7 +38,7 @@ pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
     };
 
     // Collect impl blocks to associate with types
-    let mut impl_methods: std::collections::HashMap<String, Vec<FunctionDef>> =
+    let mut impl_methods: std::collections::HashMap<String, ImplBlocks> =
         std::collections::HashMap::new();
 
     // First pass: collect all declarations
@@ -56,7 +57,7 @@ pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
                 module.classes.push(extract_struct(&child, source));
             }
             "enum_item" => {
-                module.classes.push(extract_enum(&child, source));
+                module.push_enum(extract_enum(&child, source));
             }
             "trait_item" => {
                 module.classes.push(extract_trait(&child, source));
@@ -69,7 +70,7 @@ pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
                 if let Some(id) = find_child_by_type(&child, "identifier") {
                     module.imports.push(ImportDef {
                         module: get_node_text(&id, source).to_string(),
-                        ..Default::default()
+                        ..import_span(&child)
                     });
                 }
             }
@@ -77,10 +78,14 @@ pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
         }
     }
 
-    // Second pass: associate impl methods with structs
+    // Second pass: associate impl methods with structs. Implemented traits
+    // become `impl:Trait` decorators.
     for class in &mut module.classes {
-        if let Some(methods) = impl_methods.remove(&class.name) {
-            class.methods.extend(methods);
+        if let Some(blocks) = impl_methods.remove(&class.name) {
+            class.methods.extend(blocks.methods);
+            class
+                .decorators
+                .extend(blocks.traits.iter().map(|t| format!("impl:{}", t)));
         }
         // Collect type annotations from all methods and extract referenced types
         let type_strings = collect_type_strings_from_methods(&class.methods);
@@ -88,23 +93,27 @@ pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
             extract_referenced_types(type_strings.iter().map(|s| s.as_str()), &class.name, "rust");
     }
 
-    // Add remaining impl methods as standalone (for trait impls on external types)
-    for (type_name, methods) in impl_methods {
-        let type_strings = collect_type_strings_from_methods(&methods);
+    // Add remaining impl blocks as standalone (for impls on types declared
+    // in other files)
+    for (type_name, blocks) in impl_methods {
+        if blocks.methods.is_empty() && blocks.traits.is_empty() {
+            continue;
+        }
+        let type_strings = collect_type_strings_from_methods(&blocks.methods);
         let referenced_types =
             extract_referenced_types(type_strings.iter().map(|s| s.as_str()), &type_name, "rust");
-        let class = ClassDef {
+        let mut decorators = vec!["impl".to_string()];
+        decorators.extend(blocks.traits.iter().map(|t| format!("impl:{}", t)));
+        module.classes.push(ClassDef {
             name: type_name,
-            decorators: vec!["impl".to_string()],
-            methods,
+            decorators,
+            methods: blocks.methods,
             referenced_types,
             ..Default::default()
-        };
-        if !class.methods.is_empty() {
-            module.classes.push(class);
-        }
+        });
     }
 
+    module.syntax_errors = syntax_errors(&root, source);
     Ok(module)
 }
 
@@ -143,11 +152,8 @@ fn extract_use(node: &Node, source: &str) -> Option<ImportDef> {
                 let mut inner_cursor = child.walk();
                 for inner in child.children(&mut inner_cursor) {
                     match inner.kind() {
-                        "scoped_identifier" => {
-                            if module.is_empty() {
-                                module =
-                                    get_node_text(&inner, source).replace("::", ".").to_string();
-                            }
+                        "scoped_identifier" if module.is_empty() => {
+                            module = get_node_text(&inner, source).replace("::", ".").to_string();
                         }
                         "identifier" => {
                             if module.is_empty() {
@@ -181,7 +187,7 @@ fn extract_use(node: &Node, source: &str) -> Option<ImportDef> {
         names,
         alias,
         is_from,
-        ..Default::default()
+        ..import_span(node)
     };
 
     if is_pub {
@@ -217,6 +223,8 @@ fn extract_function(node: &Node, source: &str) -> FunctionDef {
     let mut func_def = FunctionDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -239,10 +247,8 @@ fn extract_function(node: &Node, source: &str) -> FunctionDef {
                     func_def.decorators.push("unsafe".to_string());
                 }
             }
-            "identifier" => {
-                if func_def.name.is_empty() {
-                    func_def.name = get_node_text(&child, source).to_string();
-                }
+            "identifier" if func_def.name.is_empty() => {
+                func_def.name = get_node_text(&child, source).to_string();
             }
             "type_parameters" => {
                 // Generic parameters like <T, U>
@@ -261,10 +267,10 @@ fn extract_function(node: &Node, source: &str) -> FunctionDef {
             | "scoped_type_identifier"
             | "unit_type"
             | "pointer_type"
-            | "array_type" => {
-                if func_def.return_type.is_none() {
-                    func_def.return_type = Some(get_node_text(&child, source).to_string());
-                }
+            | "array_type"
+                if func_def.return_type.is_none() =>
+            {
+                func_def.return_type = Some(get_node_text(&child, source).to_string());
             }
             "block" => {
                 func_def.body_complexity = complexity::calculate_for_node(&child, source, "rust");
@@ -299,10 +305,8 @@ fn extract_parameters(node: &Node, source: &str) -> Vec<ParameterDef> {
             let mut inner_cursor = child.walk();
             for inner in child.children(&mut inner_cursor) {
                 match inner.kind() {
-                    "identifier" => {
-                        if param.name.is_empty() {
-                            param.name = get_node_text(&inner, source).to_string();
-                        }
+                    "identifier" if param.name.is_empty() => {
+                        param.name = get_node_text(&inner, source).to_string();
                     }
                     "mutable_specifier" => {
                         param.name = format!("mut {}", param.name);
@@ -333,6 +337,8 @@ fn extract_struct(node: &Node, source: &str) -> ClassDef {
     let mut class_def = ClassDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -344,10 +350,8 @@ fn extract_struct(node: &Node, source: &str) -> ClassDef {
             "visibility_modifier" => {
                 class_def.decorators.push("pub".to_string());
             }
-            "type_identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "type_identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
             }
             "field_declaration_list" => {
                 extract_struct_fields(&child, source, &mut class_def.attributes);
@@ -376,6 +380,8 @@ fn extract_enum(node: &Node, source: &str) -> ClassDef {
     let mut class_def = ClassDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -387,10 +393,8 @@ fn extract_enum(node: &Node, source: &str) -> ClassDef {
             "visibility_modifier" => {
                 class_def.decorators.push("pub".to_string());
             }
-            "type_identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "type_identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
             }
             "enum_variant_list" => {
                 let mut inner_cursor = child.walk();
@@ -416,6 +420,8 @@ fn extract_trait(node: &Node, source: &str) -> ClassDef {
     let mut class_def = ClassDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -427,10 +433,8 @@ fn extract_trait(node: &Node, source: &str) -> ClassDef {
             "visibility_modifier" => {
                 class_def.decorators.push("pub".to_string());
             }
-            "type_identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "type_identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
             }
             "trait_bounds" => {
                 // Supertraits
@@ -463,41 +467,58 @@ fn extract_trait_methods(node: &Node, source: &str, methods: &mut Vec<FunctionDe
     }
 }
 
+/// Methods and implemented traits gathered from a type's impl blocks.
+#[derive(Default)]
+struct ImplBlocks {
+    methods: Vec<FunctionDef>,
+    /// Trait paths as written, without generic arguments (`fmt::Display`)
+    traits: Vec<String>,
+}
+
 /// Extract impl block.
+///
+/// Methods are filed under the implementing type, with generic arguments
+/// stripped, and a trait impl also records the trait.
 fn extract_impl(
     node: &Node,
     source: &str,
-    impl_methods: &mut std::collections::HashMap<String, Vec<FunctionDef>>,
+    impl_methods: &mut std::collections::HashMap<String, ImplBlocks>,
 ) {
-    let mut type_name = String::new();
-    let mut trait_name: Option<String> = None;
+    // Only named types can own methods: skip `impl Trait for &T`, `[T]`, ...
+    let Some(self_type) = node.child_by_field_name("type") else {
+        return;
+    };
+    if !matches!(
+        self_type.kind(),
+        "type_identifier" | "generic_type" | "scoped_type_identifier"
+    ) {
+        return;
+    }
+    let type_path = strip_type_arguments(get_node_text(&self_type, source));
+    let type_name = type_path
+        .rsplit("::")
+        .next()
+        .unwrap_or(type_path)
+        .to_string();
 
-    let mut cursor = node.walk();
-    for child in node.children(&mut cursor) {
-        match child.kind() {
-            "type_identifier" | "generic_type" => {
-                if type_name.is_empty() {
-                    type_name = get_node_text(&child, source).to_string();
-                    // Clean up generic parameters for matching
-                    if let Some(idx) = type_name.find('<') {
-                        type_name = type_name[..idx].to_string();
-                    }
-                }
-            }
-            "trait" => {
-                // This is a trait impl
-                trait_name = Some(get_node_text(&child, source).to_string());
-            }
-            "declaration_list" => {
-                let methods = extract_impl_methods(&child, source, trait_name.as_deref());
-                impl_methods
-                    .entry(type_name.clone())
-                    .or_default()
-                    .extend(methods);
-            }
-            _ => {}
-        }
+    let trait_name = node
+        .child_by_field_name("trait")
+        .map(|t| strip_type_arguments(get_node_text(&t, source)).to_string());
+
+    let entry = impl_methods.entry(type_name).or_default();
+    if let Some(ref trait_name) = trait_name {
+        entry.traits.push(trait_name.clone());
     }
+    if let Some(body) = node.child_by_field_name("body") {
+        entry
+            .methods
+            .extend(extract_impl_methods(&body, source, trait_name.as_deref()));
+    }
+}
+
+/// Drop generic arguments from a type path: `Bar<T>` -> `Bar`.
+fn strip_type_arguments(text: &str) -> &str {
+    text.split('<').next().unwrap_or(text).trim()
 }
 
 /// Extract impl methods.
@@ -532,7 +553,7 @@ fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSi
         // Function calls: foo(), Bar::new(), path::to::func()
         "call_expression" => {
             if let Some(call_site) = extract_call_site(node, source) {
-                results.push(call_site);
+                results.push(call_site.with_arg_count(call_arguments(node).len()));
             }
         }
         // Macro invocations: println!(), vec![], format!()
@@ -565,6 +586,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         "field_expression" => {
@@ -595,6 +617,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: true,
                 receiver,
+                ..Default::default()
             })
         }
         "scoped_identifier" => {
@@ -615,6 +638,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: receiver.is_some(),
                 receiver,
+                ..Default::default()
             })
         }
         "generic_function" => {
@@ -646,6 +670,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                         line,
                         is_method_call: true,
                         receiver,
+                        ..Default::default()
                     });
                 }
             }
@@ -655,6 +680,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         "parenthesized_expression" => {
@@ -665,6 +691,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         "call_expression" => {
@@ -675,6 +702,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         "index_expression" => {
@@ -685,6 +713,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         _ => {
@@ -695,6 +724,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
     }
@@ -725,6 +755,7 @@ fn extract_macro_invocation(node: &Node, source: &str) -> Option<CallSiteDef> {
         line,
         is_method_call: is_scoped,
         receiver,
+        ..Default::default()
     })
 }
 
@@ -758,6 +789,22 @@ pub struct User {
         assert!(result.classes[0].decorators.contains(&"struct".to_string()));
     }
 
+    #[test]
+    fn test_parse_enum() {
+        let source = r#"
+pub enum Shape {
+    Circle(f64),
+    Rect { w: f64, h: f64 },
+    Empty,
+}
+"#;
+        let result = parse(source, "lib.rs").unwrap();
+        assert_eq!(result.classes[0].name, "Shape");
+        assert_eq!(result.enums.len(), 1);
+        assert_eq!(result.enums[0].name, "Shape");
+        assert_eq!(result.enums[0].variants, vec!["Circle", "Rect", "Empty"]);
+    }
+
     #[test]
     fn test_parse_impl() {
         let source = r#"
@@ -777,6 +824,43 @@ impl User {
         assert_eq!(result.classes[0].methods[0].name, "new");
     }
 
+    #[test]
+    fn test_parse_trait_impls() {
+        let source = r#"
+struct Wrapper<T>(T);
+
+impl<T: Clone> Wrapper<T> {
+    fn get(&self) -> T { self.0.clone() }
+}
+
+impl<T> fmt::Display for Wrapper<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result { Ok(()) }
+}
+
+impl<T> From<T> for Wrapper<T> {
+    fn from(value: T) -> Self { Wrapper(value) }
+}
+
+impl Send for Remote {}
+impl Shape for &Square {}
+"#;
+        let result = parse(source, "lib.rs").unwrap();
+        let wrapper = &result.classes[0];
+        assert_eq!(wrapper.name, "Wrapper");
+        assert_eq!(
+            wrapper.decorators,
+            vec!["struct", "impl:fmt::Display", "impl:From"]
+        );
+        let methods: Vec<&str> = wrapper.methods.iter().map(|m| m.name.as_str()).collect();
+        assert_eq!(methods, vec!["get", "fmt", "from"]);
+        assert_eq!(wrapper.methods[1].decorators, vec!["impl:fmt::Display"]);
+
+        // Impls on types declared elsewhere keep the trait; reference types are skipped
+        assert_eq!(result.classes.len(), 2);
+        assert_eq!(result.classes[1].name, "Remote");
+        assert_eq!(result.classes[1].decorators, vec!["impl", "impl:Send"]);
+    }
+
     #[test]
     fn test_extract_call_sites() {
         let source = r#"
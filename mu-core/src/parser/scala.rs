@@ -0,0 +1,597 @@
+//! Scala AST extractor using tree-sitter.
+//!
+//! Classes, traits and objects become `ClassDef`s (tagged `trait`/`object`/`case`
+//! in `decorators`), with every `extends`/`with` parent recorded in `bases`.
+//! A companion object is folded into its class as static methods, so the class
+//! and its object share one node. Implicit defs are plain functions tagged
+//! `implicit`.
+
+use std::path::Path;
+use tree_sitter::{Node, Parser};
+
+use super::helpers::{
+    call_arguments, collect_type_strings_from_methods, count_lines, extract_referenced_types,
+    get_end_column, get_end_line, get_node_text, get_start_column, get_start_line, import_span,
+    syntax_errors,
+};
+use crate::reducer::complexity;
+use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
+
+/// Parse Scala source code.
+pub fn parse(source: &str, file_path: &str) -> Result<ModuleDef, String> {
+    let mut parser = Parser::new();
+    parser
+        .set_language(&tree_sitter_scala::LANGUAGE.into())
+        .map_err(|e| format!("Failed to set Scala language: {}", e))?;
+
+    let tree = parser
+        .parse(source, None)
+        .ok_or("Failed to parse Scala source")?;
+    let root = tree.root_node();
+
+    let file_name = Path::new(file_path)
+        .file_stem()
+        .and_then(|s| s.to_str())
+        .unwrap_or("unknown")
+        .to_string();
+
+    let mut module = ModuleDef {
+        name: file_name,
+        path: file_path.to_string(),
+        language: "scala".to_string(),
+        total_lines: count_lines(source),
+        ..Default::default()
+    };
+
+    let mut objects = Vec::new();
+    extract_definitions(&root, source, &mut module, &mut objects);
+
+    // Companion objects share their class's name; merge them so the class
+    // node carries the object's members instead of colliding with it
+    for object in objects {
+        match module.classes.iter_mut().find(|c| c.name == object.name) {
+            Some(class_def) => merge_companion(class_def, object),
+            None => module.classes.push(object),
+        }
+    }
+
+    module.syntax_errors = syntax_errors(&root, source);
+    Ok(module)
+}
+
+/// Collect top-level definitions, descending into package clauses.
+fn extract_definitions(
+    node: &Node,
+    source: &str,
+    module: &mut ModuleDef,
+    objects: &mut Vec<ClassDef>,
+) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "package_clause" => {
+                if let Some(name) = child.child_by_field_name("name") {
+                    module.name = get_node_text(&name, source).to_string();
+                }
+                // Braced form: package foo { ... }
+                if let Some(body) = child.child_by_field_name("body") {
+                    extract_definitions(&body, source, module, objects);
+                }
+            }
+            "import_declaration" => {
+                module.imports.extend(extract_import(&child, source));
+            }
+            "class_definition" | "trait_definition" => {
+                module.classes.push(extract_class(&child, source));
+            }
+            "object_definition" => {
+                objects.push(extract_class(&child, source));
+            }
+            "function_definition" | "function_declaration" => {
+                module.functions.push(extract_function(&child, source));
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Extract an import declaration.
+///
+/// `import a.b.{C, D => E}` yields one import of `a.b` naming `C` and `D`;
+/// wildcards become `a.b.*`, matching the Java extractor.
+fn extract_import(node: &Node, source: &str) -> Option<ImportDef> {
+    let mut path = Vec::new();
+    let mut names = Vec::new();
+    let mut is_wildcard = false;
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "identifier" => path.push(get_node_text(&child, source)),
+            "namespace_wildcard" => is_wildcard = true,
+            "namespace_selectors" => {
+                let mut inner_cursor = child.walk();
+                for selector in child.named_children(&mut inner_cursor) {
+                    match selector.kind() {
+                        "identifier" => names.push(get_node_text(&selector, source).to_string()),
+                        "arrow_renamed_identifier" | "as_renamed_identifier" => {
+                            if let Some(name) = selector.child_by_field_name("name") {
+                                names.push(get_node_text(&name, source).to_string());
+                            }
+                        }
+                        "namespace_wildcard" => is_wildcard = true,
+                        _ => {}
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    if path.is_empty() {
+        return None;
+    }
+
+    let mut module = path.join(".");
+    if is_wildcard && names.is_empty() {
+        module.push_str(".*");
+    }
+
+    Some(ImportDef {
+        module,
+        is_from: !names.is_empty(),
+        names,
+        ..import_span(node)
+    })
+}
+
+/// Extract a class, trait, or object definition.
+fn extract_class(node: &Node, source: &str) -> ClassDef {
+    let mut class_def = ClassDef {
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_scaladoc(node, source),
+        ..Default::default()
+    };
+
+    match node.kind() {
+        "trait_definition" => class_def.decorators.push("trait".to_string()),
+        "object_definition" => class_def.decorators.push("object".to_string()),
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "case" => class_def.decorators.push("case".to_string()),
+            "modifiers" => extract_modifiers(&child, source, &mut class_def.decorators),
+            "identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
+            }
+            "type_parameters" => {
+                class_def
+                    .decorators
+                    .push(format!("generic:{}", get_node_text(&child, source)));
+            }
+            "class_parameters" => {
+                for param in extract_parameters(&child, source) {
+                    class_def.attributes.push(param.name);
+                }
+            }
+            "extends_clause" => extract_parents(&child, source, &mut class_def.bases),
+            "template_body" => extract_template_body(&child, source, &mut class_def),
+            _ => {}
+        }
+    }
+
+    let type_strings = collect_type_strings_from_methods(&class_def.methods);
+    class_def.referenced_types = extract_referenced_types(
+        type_strings.iter().map(|s| s.as_str()),
+        &class_def.name,
+        "scala",
+    );
+
+    class_def
+}
+
+/// Fold a companion object's members into its class as static methods.
+fn merge_companion(class_def: &mut ClassDef, object: ClassDef) {
+    class_def.decorators.push("companion".to_string());
+    for mut method in object.methods {
+        method.is_static = true;
+        class_def.methods.push(method);
+    }
+    for attribute in object.attributes {
+        if !class_def.attributes.contains(&attribute) {
+            class_def.attributes.push(attribute);
+        }
+    }
+    for base in object.bases {
+        if !class_def.bases.contains(&base) {
+            class_def.bases.push(base);
+        }
+    }
+    let start = (object.start_line, object.start_column);
+    if start < (class_def.start_line, class_def.start_column) {
+        (class_def.start_line, class_def.start_column) = start;
+    }
+    let end = (object.end_line, object.end_column);
+    if end > (class_def.end_line, class_def.end_column) {
+        (class_def.end_line, class_def.end_column) = end;
+    }
+}
+
+/// Record every parent type named in `extends A with B with C`.
+fn extract_parents(node: &Node, source: &str, bases: &mut Vec<String>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "type_identifier" | "generic_type" | "stable_type_identifier" => {
+                bases.push(get_node_text(&child, source).to_string());
+            }
+            // Scala 3 commas: extends A, B
+            "compound_type" => extract_parents(&child, source, bases),
+            _ => {}
+        }
+    }
+}
+
+/// Extract members of a class/trait/object body.
+fn extract_template_body(node: &Node, source: &str, class_def: &mut ClassDef) {
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "function_definition" | "function_declaration" => {
+                let mut method = extract_function(&child, source);
+                method.is_method = true;
+                class_def.methods.push(method);
+            }
+            "val_definition" | "var_definition" | "val_declaration" | "var_declaration" => {
+                if let Some(pattern) = child
+                    .child_by_field_name("pattern")
+                    .or_else(|| child.child_by_field_name("name"))
+                {
+                    class_def
+                        .attributes
+                        .push(get_node_text(&pattern, source).to_string());
+                }
+            }
+            "class_definition" | "trait_definition" | "object_definition" => {
+                // Nested type - add as attribute for reference
+                if let Some(name) = child.child_by_field_name("name") {
+                    class_def
+                        .attributes
+                        .push(format!("class:{}", get_node_text(&name, source)));
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Extract a `def`, with or without a body.
+fn extract_function(node: &Node, source: &str) -> FunctionDef {
+    let mut func_def = FunctionDef {
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        docstring: extract_scaladoc(node, source),
+        ..Default::default()
+    };
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "modifiers" => extract_modifiers(&child, source, &mut func_def.decorators),
+            "identifier" | "operator_identifier" if func_def.name.is_empty() => {
+                func_def.name = get_node_text(&child, source).to_string();
+            }
+            "type_parameters" => {
+                func_def
+                    .decorators
+                    .push(format!("generic:{}", get_node_text(&child, source)));
+            }
+            // Curried defs have several parameter lists
+            "parameters" => {
+                func_def
+                    .parameters
+                    .extend(extract_parameters(&child, source));
+            }
+            _ => {}
+        }
+    }
+
+    if let Some(return_type) = node.child_by_field_name("return_type") {
+        func_def.return_type = Some(get_node_text(&return_type, source).to_string());
+    }
+
+    if let Some(body) = node.child_by_field_name("body") {
+        func_def.body_complexity = complexity::calculate_for_node(&body, source, "scala");
+        func_def.body_source = Some(get_node_text(&body, source).to_string());
+        func_def.call_sites = extract_call_sites(&body, source);
+    }
+
+    func_def
+}
+
+/// Extract modifiers such as `private`, `override`, `implicit`, `sealed`.
+fn extract_modifiers(node: &Node, source: &str, decorators: &mut Vec<String>) {
+    for modifier in get_node_text(node, source).split_whitespace() {
+        decorators.push(modifier.to_string());
+    }
+}
+
+/// Extract `parameters` or `class_parameters`.
+fn extract_parameters(node: &Node, source: &str) -> Vec<ParameterDef> {
+    let mut params = Vec::new();
+
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        if child.kind() != "parameter" && child.kind() != "class_parameter" {
+            continue;
+        }
+
+        let Some(name) = child.child_by_field_name("name") else {
+            continue;
+        };
+        let type_annotation = child
+            .child_by_field_name("type")
+            .map(|t| get_node_text(&t, source).to_string());
+        let is_variadic = type_annotation
+            .as_deref()
+            .is_some_and(|t| t.trim_end().ends_with('*'));
+
+        params.push(ParameterDef {
+            name: get_node_text(&name, source).to_string(),
+            type_annotation,
+            default_value: child
+                .child_by_field_name("default_value")
+                .map(|d| get_node_text(&d, source).to_string()),
+            is_variadic,
+            ..Default::default()
+        });
+    }
+
+    params
+}
+
+/// Read the `/** ... */` comment directly above a definition.
+fn extract_scaladoc(node: &Node, source: &str) -> Option<String> {
+    let prev = node.prev_sibling()?;
+    if prev.kind() != "block_comment" {
+        return None;
+    }
+    let text = get_node_text(&prev, source);
+    let body = text.strip_prefix("/**")?.strip_suffix("*/")?;
+
+    let doc = body
+        .lines()
+        .map(|line| line.trim().trim_start_matches('*').trim())
+        .filter(|line| !line.is_empty())
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if doc.is_empty() {
+        None
+    } else {
+        Some(doc)
+    }
+}
+
+/// Extract all call sites from a function body.
+fn extract_call_sites(body: &Node, source: &str) -> Vec<CallSiteDef> {
+    let mut call_sites = Vec::new();
+    find_call_sites_recursive(body, source, &mut call_sites);
+    call_sites
+}
+
+/// Recursively search for call and `new` expressions.
+fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSiteDef>) {
+    match node.kind() {
+        "call_expression" => {
+            if let Some(call_site) = extract_call(node, source) {
+                results.push(call_site.with_arg_count(call_arguments(node).len()));
+            }
+        }
+        "instance_expression" => {
+            let mut cursor = node.walk();
+            let created = node.named_children(&mut cursor).find(|c| {
+                matches!(
+                    c.kind(),
+                    "type_identifier" | "generic_type" | "stable_type_identifier"
+                )
+            });
+            if let Some(created) = created {
+                let type_node = if created.kind() == "generic_type" {
+                    created.child_by_field_name("type").unwrap_or(created)
+                } else {
+                    created
+                };
+                results.push(
+                    CallSiteDef {
+                        callee: format!("new {}", get_node_text(&type_node, source)),
+                        line: get_start_line(node),
+                        is_method_call: false,
+                        receiver: None,
+                        ..Default::default()
+                    }
+                    .with_arg_count(call_arguments(node).len()),
+                );
+            }
+        }
+        _ => {}
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        find_call_sites_recursive(&child, source, results);
+    }
+}
+
+/// Extract a call site from `foo(x)`, `obj.foo(x)`, or `this.foo(x)`.
+fn extract_call(node: &Node, source: &str) -> Option<CallSiteDef> {
+    let function = node.child_by_field_name("function")?;
+    let line = get_start_line(node);
+
+    match function.kind() {
+        "identifier" => Some(CallSiteDef {
+            callee: get_node_text(&function, source).to_string(),
+            line,
+            is_method_call: false,
+            receiver: None,
+            ..Default::default()
+        }),
+        "field_expression" => {
+            let method_name = get_node_text(&function.child_by_field_name("field")?, source);
+            let receiver = get_node_text(&function.child_by_field_name("value")?, source);
+            let is_this_call = receiver == "this" || receiver == "super";
+
+            Some(CallSiteDef {
+                callee: if is_this_call {
+                    method_name.to_string()
+                } else {
+                    format!("{}.{}", receiver, method_name)
+                },
+                line,
+                is_method_call: true,
+                receiver: Some(receiver.to_string()),
+                ..Default::default()
+            })
+        }
+        // Type-applied calls such as foo[Int](x)
+        "generic_function" => Some(CallSiteDef {
+            callee: get_node_text(&function.child_by_field_name("function")?, source).to_string(),
+            line,
+            is_method_call: false,
+            receiver: None,
+            ..Default::default()
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+package com.acme.shipping
+
+import scala.collection.mutable
+import com.acme.util.{Logger, Timer => T}
+
+/** Something that can be shipped. */
+trait Shippable {
+  def ship(to: String): Unit
+}
+
+class Parcel(weight: Double) extends Base(1) with Shippable with Logging {
+  val label: String = "x"
+  def ship(to: String): Unit = {
+    if (weight > 10 && to.nonEmpty) println("heavy") else log(to)
+  }
+}
+
+object Parcel {
+  implicit def fromDouble(d: Double): Parcel = new Parcel(d)
+}
+
+object Main {
+  def run(args: Array[String]): Unit = Parcel.fromDouble(2.0).ship("home")
+}
+"#;
+
+    #[test]
+    fn test_parse_trait_class_and_object() {
+        let module = parse(SAMPLE, "src/Shipping.scala").unwrap();
+        assert_eq!(module.language, "scala");
+        assert_eq!(module.name, "com.acme.shipping");
+
+        let shippable = module
+            .classes
+            .iter()
+            .find(|c| c.name == "Shippable")
+            .unwrap();
+        assert!(shippable.decorators.contains(&"trait".to_string()));
+        assert_eq!(shippable.methods[0].name, "ship");
+        assert_eq!(
+            shippable.docstring.as_deref(),
+            Some("Something that can be shipped.")
+        );
+
+        // extends and each with become bases (inheritance edges)
+        let parcel = module.classes.iter().find(|c| c.name == "Parcel").unwrap();
+        assert_eq!(parcel.bases, vec!["Base", "Shippable", "Logging"]);
+        assert!(parcel.attributes.contains(&"weight".to_string()));
+        assert!(parcel.attributes.contains(&"label".to_string()));
+
+        let ship = parcel.methods.iter().find(|m| m.name == "ship").unwrap();
+        assert!(ship.is_method);
+        assert_eq!(ship.parameters[0].name, "to");
+        assert_eq!(
+            ship.parameters[0].type_annotation.as_deref(),
+            Some("String")
+        );
+        assert_eq!(ship.return_type.as_deref(), Some("Unit"));
+        assert_eq!(ship.body_complexity, 3);
+        assert!(ship.call_sites.iter().any(|c| c.callee == "log"));
+
+        // The companion object's implicit def merges into the class
+        let implicit = parcel
+            .methods
+            .iter()
+            .find(|m| m.name == "fromDouble")
+            .unwrap();
+        assert!(implicit.is_static);
+        assert!(implicit.decorators.contains(&"implicit".to_string()));
+        assert!(implicit.call_sites.iter().any(|c| c.callee == "new Parcel"));
+        assert_eq!(
+            module.classes.iter().filter(|c| c.name == "Parcel").count(),
+            1
+        );
+
+        // A standalone object keeps its own entry with its members
+        let main = module.classes.iter().find(|c| c.name == "Main").unwrap();
+        assert!(main.decorators.contains(&"object".to_string()));
+        assert_eq!(main.methods[0].name, "run");
+        assert_eq!(main.methods[0].parameters[0].name, "args");
+    }
+
+    #[test]
+    fn test_parse_imports() {
+        let module = parse(SAMPLE, "Shipping.scala").unwrap();
+        assert_eq!(module.imports.len(), 2);
+        assert_eq!(module.imports[0].module, "scala.collection.mutable");
+        assert!(!module.imports[0].is_from);
+        assert_eq!(module.imports[1].module, "com.acme.util");
+        assert_eq!(module.imports[1].names, vec!["Logger", "Timer"]);
+
+        let wildcard = parse("import com.acme.io._\n", "W.scala").unwrap();
+        assert_eq!(wildcard.imports[0].module, "com.acme.io.*");
+    }
+
+    #[test]
+    fn test_parse_case_class_and_top_level_def() {
+        let source = r#"
+case class Point(x: Int, y: Int)
+
+def describe(p: Point): String = p match {
+  case Point(0, 0) => "origin"
+  case _ => "elsewhere"
+}
+"#;
+        let module = parse(source, "Point.scala").unwrap();
+        let point = &module.classes[0];
+        assert_eq!(point.name, "Point");
+        assert!(point.decorators.contains(&"case".to_string()));
+        assert_eq!(point.attributes, vec!["x", "y"]);
+
+        assert_eq!(module.functions.len(), 1);
+        assert_eq!(module.functions[0].name, "describe");
+        assert!(!module.functions[0].is_method);
+        assert_eq!(module.functions[0].body_complexity, 3);
+    }
+}
@@ -4,8 +4,9 @@ use std::path::Path;
 use tree_sitter::{Node, Parser};
 
 use super::helpers::{
-    collect_type_strings_from_methods, count_lines, extract_referenced_types, find_child_by_type,
-    get_end_line, get_node_text, get_start_line,
+    call_arguments, collect_type_strings_from_methods, count_lines, extract_referenced_types,
+    find_child_by_type, get_end_column, get_end_line, get_node_text, get_start_column,
+    get_start_line, import_span, syntax_errors,
 };
 use crate::reducer::complexity;
 use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, ParameterDef};
@@ -16,11 +17,15 @@ use crate::types::{CallSiteDef, ClassDef, FunctionDef, ImportDef, ModuleDef, Par
 /// * `source` - Source code
 /// * `file_path` - Path to the file
 /// * `is_javascript` - True for JavaScript, false for TypeScript
+///
+/// `.tsx` files use the TSX grammar; the JavaScript grammar parses JSX as is.
 pub fn parse(source: &str, file_path: &str, is_javascript: bool) -> Result<ModuleDef, String> {
     let mut parser = Parser::new();
 
     let language = if is_javascript {
         tree_sitter_javascript::LANGUAGE.into()
+    } else if file_path.ends_with(".tsx") {
+        tree_sitter_typescript::LANGUAGE_TSX.into()
     } else {
         tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into()
     };
@@ -79,6 +84,12 @@ pub fn parse(source: &str, file_path: &str, is_javascript: bool) -> Result<Modul
             "interface_declaration" => {
                 module.classes.push(extract_interface(&child, source));
             }
+            "type_alias_declaration" => {
+                module.classes.extend(extract_type_alias(&child, source));
+            }
+            "enum_declaration" => {
+                module.push_enum(extract_enum(&child, source));
+            }
             _ => {}
         }
     }
@@ -87,6 +98,7 @@ pub fn parse(source: &str, file_path: &str, is_javascript: bool) -> Result<Modul
     let dynamic_imports = extract_dynamic_imports(&root, source);
     module.imports.extend(dynamic_imports);
 
+    module.syntax_errors = syntax_errors(&root, source);
     Ok(module)
 }
 
@@ -121,7 +133,7 @@ fn extract_import(node: &Node, source: &str) -> Option<ImportDef> {
         names,
         alias,
         is_from: true,
-        ..Default::default()
+        ..import_span(node)
     })
 }
 
@@ -164,16 +176,16 @@ fn extract_class(node: &Node, source: &str) -> ClassDef {
     let mut class_def = ClassDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "type_identifier" | "identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "type_identifier" | "identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
             }
             "class_heritage" => {
                 extract_heritage(&child, source, &mut class_def.bases);
@@ -237,16 +249,16 @@ fn extract_method(node: &Node, source: &str) -> FunctionDef {
         is_method: true,
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "property_identifier" | "identifier" => {
-                if func_def.name.is_empty() {
-                    func_def.name = get_node_text(&child, source).to_string();
-                }
+            "property_identifier" | "identifier" if func_def.name.is_empty() => {
+                func_def.name = get_node_text(&child, source).to_string();
             }
             "formal_parameters" => {
                 func_def.parameters = extract_parameters(&child, source);
@@ -286,19 +298,20 @@ fn extract_function(node: &Node, source: &str, is_method: bool) -> FunctionDef {
         is_method,
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "identifier" => {
-                if func_def.name.is_empty() {
-                    func_def.name = get_node_text(&child, source).to_string();
-                }
+            "identifier" if func_def.name.is_empty() => {
+                func_def.name = get_node_text(&child, source).to_string();
             }
             "formal_parameters" => {
                 func_def.parameters = extract_parameters(&child, source);
+                func_def.props = destructured_props(&child, source);
             }
             "statement_block" => {
                 func_def.body_complexity =
@@ -340,8 +353,13 @@ fn extract_parameters(node: &Node, source: &str) -> Vec<ParameterDef> {
                 } else {
                     let mut inner_cursor = child.walk();
                     for inner in child.children(&mut inner_cursor) {
-                        if inner.kind() == "identifier" {
-                            param.name = get_node_text(&inner, source).to_string();
+                        if param.name.is_empty()
+                            && matches!(
+                                inner.kind(),
+                                "identifier" | "object_pattern" | "array_pattern"
+                            )
+                        {
+                            param.name = pattern_name(&inner, source);
                         } else if inner.kind() == "type_annotation" {
                             let mut type_cursor = inner.walk();
                             for type_child in inner.children(&mut type_cursor) {
@@ -359,6 +377,13 @@ fn extract_parameters(node: &Node, source: &str) -> Vec<ParameterDef> {
                     params.push(param);
                 }
             }
+            // JavaScript destructuring
+            "object_pattern" | "array_pattern" => {
+                params.push(ParameterDef {
+                    name: pattern_name(&child, source),
+                    ..Default::default()
+                });
+            }
             "rest_pattern" => {
                 if let Some(id) = find_child_by_type(&child, "identifier") {
                     params.push(ParameterDef {
@@ -375,6 +400,66 @@ fn extract_parameters(node: &Node, source: &str) -> Vec<ParameterDef> {
     params
 }
 
+/// A parameter's name, or its pattern with whitespace collapsed when it
+/// destructures (`{ label, onClick }`).
+fn pattern_name(node: &Node, source: &str) -> String {
+    get_node_text(node, source)
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Names the first parameter destructures from an object, e.g. a
+/// component's props.
+fn destructured_props(params: &Node, source: &str) -> Vec<String> {
+    let mut cursor = params.walk();
+    let Some(first) = params
+        .named_children(&mut cursor)
+        .find(|child| child.kind() != "comment")
+    else {
+        return Vec::new();
+    };
+    let pattern = match first.kind() {
+        "required_parameter" | "optional_parameter" => first.child_by_field_name("pattern"),
+        "assignment_pattern" => first.child_by_field_name("left"),
+        _ => Some(first),
+    };
+    let Some(pattern) = pattern.filter(|p| p.kind() == "object_pattern") else {
+        return Vec::new();
+    };
+
+    let mut cursor = pattern.walk();
+    pattern
+        .named_children(&mut cursor)
+        .filter_map(|prop| {
+            let name = match prop.kind() {
+                "shorthand_property_identifier_pattern" => Some(prop),
+                // `label: text` and `size = 'md'`
+                "pair_pattern" => prop.child_by_field_name("key"),
+                "object_assignment_pattern" => prop.child_by_field_name("left"),
+                _ => None,
+            }?;
+            let name = get_node_text(&name, source).trim_matches(|c| c == '"' || c == '\'');
+            Some(name.to_string())
+        })
+        .collect()
+}
+
+/// The props type of a `const Button: React.FC<ButtonProps>` declaration.
+fn component_type_props(declarator: &Node, source: &str) -> Option<String> {
+    let annotation = declarator.child_by_field_name("type")?;
+    let generic = find_child_by_type(&annotation, "generic_type")?;
+    let wrapper = get_node_text(&generic.child_by_field_name("name")?, source);
+    let wrapper = wrapper.rsplit('.').next().unwrap_or(wrapper);
+    if !matches!(wrapper, "FC" | "FunctionComponent" | "VFC") {
+        return None;
+    }
+    let arguments = find_child_by_type(&generic, "type_arguments")?;
+    let mut cursor = arguments.walk();
+    let props = arguments.named_children(&mut cursor).next()?;
+    Some(get_node_text(&props, source).to_string())
+}
+
 /// Extract functions from variable declarations.
 fn extract_variable_functions(node: &Node, source: &str, functions: &mut Vec<FunctionDef>) {
     let mut cursor = node.walk();
@@ -385,6 +470,12 @@ fn extract_variable_functions(node: &Node, source: &str, functions: &mut Vec<Fun
                 if let Some(name) = find_child_by_type(&child, "identifier") {
                     func.name = get_node_text(&name, source).to_string();
                 }
+                // `React.FC<Props>` types the props parameter from outside
+                if let Some(props_type) = component_type_props(&child, source) {
+                    if let Some(first) = func.parameters.first_mut() {
+                        first.type_annotation.get_or_insert(props_type);
+                    }
+                }
                 if !func.name.is_empty() {
                     functions.push(func);
                 }
@@ -398,6 +489,8 @@ fn extract_arrow_function(node: &Node, source: &str) -> FunctionDef {
     let mut func_def = FunctionDef {
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
@@ -406,16 +499,16 @@ fn extract_arrow_function(node: &Node, source: &str) -> FunctionDef {
         match child.kind() {
             "formal_parameters" => {
                 func_def.parameters = extract_parameters(&child, source);
+                func_def.props = destructured_props(&child, source);
             }
-            "identifier" => {
+            "identifier"
                 // Single parameter arrow function
-                if func_def.parameters.is_empty() {
+                if func_def.parameters.is_empty() => {
                     func_def.parameters.push(ParameterDef {
                         name: get_node_text(&child, source).to_string(),
                         ..Default::default()
                     });
                 }
-            }
             "statement_block" => {
                 func_def.body_complexity =
                     complexity::calculate_for_node(&child, source, "typescript");
@@ -441,15 +534,27 @@ fn extract_export(node: &Node, source: &str, module: &mut ModuleDef) {
                 module.classes.push(extract_class(&child, source));
             }
             "function_declaration" => {
-                module
-                    .functions
-                    .push(extract_function(&child, source, false));
+                let mut func_def = extract_function(&child, source, false);
+                func_def.decorators.push("export".to_string());
+                module.functions.push(func_def);
             }
             "interface_declaration" => {
                 module.classes.push(extract_interface(&child, source));
             }
+            "type_alias_declaration" => {
+                module.classes.extend(extract_type_alias(&child, source));
+            }
+            "enum_declaration" => {
+                let mut class_def = extract_enum(&child, source);
+                class_def.decorators.push("export".to_string());
+                module.push_enum(class_def);
+            }
             "lexical_declaration" => {
+                let first = module.functions.len();
                 extract_variable_functions(&child, source, &mut module.functions);
+                for func_def in &mut module.functions[first..] {
+                    func_def.decorators.push("export".to_string());
+                }
             }
             _ => {}
         }
@@ -462,16 +567,16 @@ fn extract_interface(node: &Node, source: &str) -> ClassDef {
         decorators: vec!["interface".to_string()],
         start_line: get_start_line(node),
         end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
         ..Default::default()
     };
 
     let mut cursor = node.walk();
     for child in node.children(&mut cursor) {
         match child.kind() {
-            "type_identifier" | "identifier" => {
-                if class_def.name.is_empty() {
-                    class_def.name = get_node_text(&child, source).to_string();
-                }
+            "type_identifier" | "identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
             }
             "extends_type_clause" => {
                 let mut inner_cursor = child.walk();
@@ -501,6 +606,60 @@ fn extract_interface(node: &Node, source: &str) -> ClassDef {
     class_def
 }
 
+/// Extract an object type alias (`type Props = { ... }`) as an interface.
+///
+/// Aliases of other types (unions, intersections, primitives) have no
+/// members of their own and are skipped.
+fn extract_type_alias(node: &Node, source: &str) -> Option<ClassDef> {
+    if node.child_by_field_name("value")?.kind() != "object_type" {
+        return None;
+    }
+    let mut class_def = extract_interface(node, source);
+    class_def.decorators.push("type".to_string());
+    Some(class_def)
+}
+
+/// Extract enum declaration, with its members as attributes.
+fn extract_enum(node: &Node, source: &str) -> ClassDef {
+    let mut class_def = ClassDef {
+        decorators: vec!["enum".to_string()],
+        start_line: get_start_line(node),
+        end_line: get_end_line(node),
+        start_column: get_start_column(node),
+        end_column: get_end_column(node),
+        ..Default::default()
+    };
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        match child.kind() {
+            "const" => class_def.decorators.push("const".to_string()),
+            "identifier" if class_def.name.is_empty() => {
+                class_def.name = get_node_text(&child, source).to_string();
+            }
+            "enum_body" => {
+                let mut inner_cursor = child.walk();
+                for member in child.named_children(&mut inner_cursor) {
+                    let name = match member.kind() {
+                        "enum_assignment" => member.child_by_field_name("name"),
+                        "comment" => None,
+                        _ => Some(member),
+                    };
+                    if let Some(name) = name {
+                        let text = get_node_text(&name, source);
+                        class_def
+                            .attributes
+                            .push(text.trim_matches(|c| c == '"' || c == '\'').to_string());
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+
+    class_def
+}
+
 /// Extract interface body.
 fn extract_interface_body(node: &Node, source: &str, class_def: &mut ClassDef) {
     let mut cursor = node.walk();
@@ -511,6 +670,8 @@ fn extract_interface_body(node: &Node, source: &str, class_def: &mut ClassDef) {
                     is_method: true,
                     start_line: get_start_line(&child),
                     end_line: get_end_line(&child),
+                    start_column: get_start_column(&child),
+                    end_column: get_end_column(&child),
                     ..Default::default()
                 };
 
@@ -597,8 +758,7 @@ fn check_dynamic_import(node: &Node, source: &str) -> Option<ImportDef> {
                         module,
                         is_dynamic: true,
                         dynamic_source: Some("import()".to_string()),
-                        line_number: get_start_line(node),
-                        ..Default::default()
+                        ..import_span(node)
                     });
                 }
                 "template_string" => {
@@ -609,8 +769,7 @@ fn check_dynamic_import(node: &Node, source: &str) -> Option<ImportDef> {
                         is_dynamic: true,
                         dynamic_pattern: Some(pattern.to_string()),
                         dynamic_source: Some("import()".to_string()),
-                        line_number: get_start_line(node),
-                        ..Default::default()
+                        ..import_span(node)
                     });
                 }
                 "identifier" | "member_expression" | "binary_expression" => {
@@ -621,8 +780,7 @@ fn check_dynamic_import(node: &Node, source: &str) -> Option<ImportDef> {
                         is_dynamic: true,
                         dynamic_pattern: Some(pattern.to_string()),
                         dynamic_source: Some("import()".to_string()),
-                        line_number: get_start_line(node),
-                        ..Default::default()
+                        ..import_span(node)
                     });
                 }
                 _ => {}
@@ -648,8 +806,7 @@ fn check_dynamic_import(node: &Node, source: &str) -> Option<ImportDef> {
                         is_dynamic: true,
                         dynamic_pattern: Some(pattern.to_string()),
                         dynamic_source: Some("require()".to_string()),
-                        line_number: get_start_line(node),
-                        ..Default::default()
+                        ..import_span(node)
                     });
                 }
                 _ => {}
@@ -672,7 +829,7 @@ fn find_call_sites_recursive(node: &Node, source: &str, results: &mut Vec<CallSi
     // TypeScript/JS uses "call_expression" not "call"
     if node.kind() == "call_expression" {
         if let Some(call_site) = extract_call_site(node, source) {
-            results.push(call_site);
+            results.push(call_site.with_arg_count(call_arguments(node).len()));
         }
     }
 
@@ -697,6 +854,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
         "member_expression" => {
@@ -724,6 +882,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: true,
                 receiver,
+                ..Default::default()
             })
         }
         _ => {
@@ -734,6 +893,7 @@ fn extract_call_site(node: &Node, source: &str) -> Option<CallSiteDef> {
                 line,
                 is_method_call: false,
                 receiver: None,
+                ..Default::default()
             })
         }
     }
@@ -755,6 +915,30 @@ function hello(name: string): string {
         assert_eq!(result.functions[0].name, "hello");
     }
 
+    #[test]
+    fn test_exported_functions_are_marked() {
+        let source = r#"
+export function api() {}
+export const handler = () => {};
+function internal() {}
+"#;
+        let result = parse(source, "test.ts", false).unwrap();
+        let exported: Vec<_> = result
+            .functions
+            .iter()
+            .map(|f| {
+                (
+                    f.name.as_str(),
+                    f.decorators.contains(&"export".to_string()),
+                )
+            })
+            .collect();
+        assert_eq!(
+            exported,
+            vec![("api", true), ("handler", true), ("internal", false)]
+        );
+    }
+
     #[test]
     fn test_parse_class() {
         let source = r#"
@@ -769,6 +953,33 @@ class MyClass extends BaseClass {
         assert_eq!(result.classes[0].name, "MyClass");
     }
 
+    #[test]
+    fn test_parse_enum() {
+        let source = r#"
+export enum Direction {
+    Up = 1,
+    Down,
+    // sideways
+    "Left" = "L",
+}
+
+const enum Flag { A, B }
+"#;
+        let result = parse(source, "dir.ts", false).unwrap();
+        let direction = result
+            .classes
+            .iter()
+            .find(|c| c.name == "Direction")
+            .unwrap();
+        assert!(direction.decorators.contains(&"export".to_string()));
+        assert_eq!(result.enums[0].name, "Direction");
+        assert_eq!(result.enums[0].variants, vec!["Up", "Down", "Left"]);
+
+        let flag = result.enum_named("Flag").unwrap();
+        assert_eq!(flag.variants, vec!["A", "B"]);
+        assert!(result.classes[1].decorators.contains(&"const".to_string()));
+    }
+
     #[test]
     fn test_parse_import() {
         let source = r#"
@@ -777,6 +988,9 @@ import { foo, bar } from './module';
         let result = parse(source, "test.ts", false).unwrap();
         assert_eq!(result.imports.len(), 1);
         assert_eq!(result.imports[0].module, "./module");
+        let import = &result.imports[0];
+        assert_eq!((import.start_line, import.start_column), (2, 1));
+        assert_eq!((import.end_line, import.end_column), (2, 37));
     }
 
     #[test]
@@ -829,7 +1043,7 @@ const process = (data: string) => {
         let result = parse(source, "test.ts", false).unwrap();
         // Arrow functions are extracted as functions
         assert!(
-            result.functions.len() >= 1,
+            !result.functions.is_empty(),
             "Expected at least 1 function, got {}",
             result.functions.len()
         );
@@ -908,4 +1122,75 @@ interface APIClient {
             vec!["BatchItem", "HTTPRequest", "HTTPResponse", "ResultData"]
         );
     }
+
+    #[test]
+    fn test_tsx_component_props() {
+        let source = r#"
+interface ButtonProps {
+    label: string;
+    onClick?: () => void;
+}
+
+type CardProps = {
+    title: string;
+};
+
+type Size = "sm" | "md";
+
+export function Button({ label, onClick: handle, size = "md", ...rest }: ButtonProps) {
+    return <button onClick={handle}>{label}</button>;
+}
+
+export const Card: React.FC<CardProps> = ({ title }) => <div>{title}</div>;
+"#;
+        let result = parse(source, "Button.tsx", false).unwrap();
+        assert!(result.syntax_errors.is_empty());
+
+        // Object type aliases are interfaces; other aliases are skipped
+        let interfaces: Vec<_> = result
+            .classes
+            .iter()
+            .map(|c| (c.name.as_str(), c.attributes.clone()))
+            .collect();
+        assert_eq!(
+            interfaces,
+            vec![
+                (
+                    "ButtonProps",
+                    vec!["label".to_string(), "onClick".to_string()]
+                ),
+                ("CardProps", vec!["title".to_string()]),
+            ]
+        );
+
+        let button = &result.functions[0];
+        assert_eq!(button.props, vec!["label", "onClick", "size"]);
+        assert_eq!(
+            button.parameters[0].type_annotation.as_deref(),
+            Some("ButtonProps")
+        );
+
+        let card = &result.functions[1];
+        assert_eq!(card.name, "Card");
+        assert_eq!(card.props, vec!["title"]);
+        assert_eq!(card.parameters[0].name, "{ title }");
+        assert_eq!(
+            card.parameters[0].type_annotation.as_deref(),
+            Some("CardProps")
+        );
+    }
+
+    #[test]
+    fn test_jsx_component_props() {
+        let source = r#"
+function Badge({ text, tone = "info" }, ref) {
+    return <span className={tone}>{text}</span>;
+}
+"#;
+        let result = parse(source, "Badge.jsx", true).unwrap();
+        assert!(result.syntax_errors.is_empty());
+        let badge = &result.functions[0];
+        assert_eq!(badge.props, vec!["text", "tone"]);
+        assert_eq!(badge.parameters.len(), 2);
+    }
 }
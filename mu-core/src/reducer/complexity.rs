@@ -1,11 +1,15 @@
-//! Cyclomatic complexity calculation.
+//! Cyclomatic and cognitive complexity calculation.
 //!
 //! Calculates McCabe cyclomatic complexity by counting decision points
 //! in the AST. Each language has its own set of decision point node types.
+//! The `generic` language approximates it for anything else by counting
+//! branching keywords and operators in the text. Cognitive complexity
+//! follows the SonarSource rules and weights nesting.
 
 use once_cell::sync::Lazy;
+use rayon::prelude::*;
 use std::collections::{HashMap, HashSet};
-use tree_sitter::Node;
+use tree_sitter::{Node, Parser};
 
 /// Decision point node types by language (tree-sitter node names).
 static DECISION_POINTS: Lazy<HashMap<&str, HashSet<&str>>> = Lazy::new(|| {
@@ -118,6 +122,104 @@ static DECISION_POINTS: Lazy<HashMap<&str, HashSet<&str>>> = Lazy::new(|| {
         ]),
     );
 
+    m.insert(
+        "dart",
+        HashSet::from([
+            "if_statement",
+            "for_statement",
+            "while_statement",
+            "do_statement",
+            "switch_label",
+            "catch_clause",
+            "conditional_expression",
+            "logical_and_expression",
+            "logical_or_expression",
+            "if_null_expression",
+            // Collection `for`/`if` elements in list and map literals
+            "for_element",
+            "if_element",
+        ]),
+    );
+
+    m.insert(
+        "scala",
+        HashSet::from([
+            "if_expression",
+            "while_expression",
+            "do_while_expression",
+            "for_expression",
+            "case_clause",
+            "catch_clause",
+            "infix_expression", // SPECIAL: check operator
+        ]),
+    );
+
+    m.insert(
+        "kotlin",
+        HashSet::from([
+            "if_expression",
+            "for_statement",
+            "while_statement",
+            "do_while_statement",
+            "when_entry",
+            "catch_block",
+            "binary_expression", // SPECIAL: check operator
+        ]),
+    );
+
+    m.insert(
+        "php",
+        HashSet::from([
+            "if_statement",
+            "else_if_clause",
+            "for_statement",
+            "foreach_statement",
+            "while_statement",
+            "do_statement",
+            "case_statement",
+            "catch_clause",
+            "conditional_expression",
+            "match_conditional_expression",
+            "binary_expression", // SPECIAL: check operator
+        ]),
+    );
+
+    m.insert(
+        "ruby",
+        HashSet::from([
+            "if",
+            "elsif",
+            "unless",
+            "while",
+            "until",
+            "for",
+            "when",
+            "rescue",
+            "conditional", // ternary
+            "if_modifier",
+            "unless_modifier",
+            "while_modifier",
+            "until_modifier",
+            "rescue_modifier",
+            "binary", // SPECIAL: check operator
+        ]),
+    );
+
+    let c_family = [
+        "if_statement",
+        "for_statement",
+        "while_statement",
+        "do_statement",
+        "case_statement",
+        "conditional_expression",
+        "binary_expression", // SPECIAL: check operator
+    ];
+    m.insert("c", HashSet::from(c_family));
+
+    let mut cpp = HashSet::from(c_family);
+    cpp.extend(["for_range_loop", "catch_clause"]);
+    m.insert("cpp", cpp);
+
     m
 });
 
@@ -125,11 +227,22 @@ static DECISION_POINTS: Lazy<HashMap<&str, HashSet<&str>>> = Lazy::new(|| {
 static DECISION_OPERATORS: Lazy<HashSet<&str>> =
     Lazy::new(|| HashSet::from(["&&", "||", "and", "or", "??"]));
 
+/// Language name selecting [`calculate_generic`].
+pub const GENERIC: &str = "generic";
+
+/// Branching keywords counted by [`calculate_generic`].
+const GENERIC_KEYWORDS: &[&str] = &["if", "for", "while", "case"];
+
 /// Calculate cyclomatic complexity for a code snippet.
 ///
 /// Base complexity is 1. Each decision point adds 1.
 /// Decision points: if, for, while, case, catch, &&, ||, ternary, etc.
+/// Pass [`GENERIC`] as the language for [`calculate_generic`].
 pub fn calculate(source: &str, language: &str) -> u32 {
+    if language == GENERIC {
+        return calculate_generic(source);
+    }
+
     // This is a simplified version - for full accuracy, we'd need to parse
     // the source and walk the AST. For now, we'll use heuristics.
     let _decision_types = DECISION_POINTS.get(language).cloned().unwrap_or_default();
@@ -149,6 +262,63 @@ pub fn calculate(source: &str, language: &str) -> u32 {
     complexity
 }
 
+/// Like [`calculate`], but errors for languages without decision point
+/// rules instead of scoring them by keyword anyway.
+pub fn try_calculate(source: &str, language: &str) -> Result<u32, String> {
+    if language == GENERIC {
+        return Ok(calculate_generic(source));
+    }
+    let language = crate::incremental::normalize_language(language);
+    if !DECISION_POINTS.contains_key(language) {
+        return Err(format!(
+            "Unsupported language: {} (use \"{}\" to count branching keywords)",
+            language, GENERIC
+        ));
+    }
+    Ok(calculate(source, language))
+}
+
+/// Approximate cyclomatic complexity for any language, such as shell or SQL.
+///
+/// Counts the words `if`, `for`, `while` and `case` (whole words, any case)
+/// and the operators `&&`, `||` and `?` (a `??` counts once), on top of the
+/// base of 1. Comments and strings are not skipped.
+pub fn calculate_generic(source: &str) -> u32 {
+    let is_keyword = |word: &str| {
+        GENERIC_KEYWORDS
+            .iter()
+            .any(|k| word.eq_ignore_ascii_case(k))
+    };
+    let mut complexity = 1u32;
+    let mut word = String::new();
+    let mut chars = source.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c.is_alphanumeric() || c == '_' {
+            word.push(c);
+            continue;
+        }
+        if is_keyword(&word) {
+            complexity += 1;
+        }
+        word.clear();
+
+        match (c, chars.peek()) {
+            ('&', Some('&')) | ('|', Some('|')) | ('?', Some('?')) => {
+                chars.next();
+                complexity += 1;
+            }
+            ('?', _) => complexity += 1,
+            _ => {}
+        }
+    }
+    if is_keyword(&word) {
+        complexity += 1;
+    }
+
+    complexity
+}
+
 /// Calculate cyclomatic complexity for a tree-sitter node.
 ///
 /// This is the accurate version that walks the AST.
@@ -172,8 +342,12 @@ pub fn calculate_for_node(node: &Node, source: &str, language: &str) -> u32 {
     }
 
     fn traverse(node: &Node, source: &str, decision_types: &HashSet<&str>, complexity: &mut u32) {
-        if decision_types.contains(node.kind()) {
-            if node.kind() == "binary_expression" {
+        // Named nodes only: Ruby's `if` node shares its kind with the keyword token
+        if node.is_named() && decision_types.contains(node.kind()) {
+            if matches!(
+                node.kind(),
+                "binary_expression" | "infix_expression" | "binary"
+            ) {
                 // Only count if operator is && || or ??
                 if is_decision_operator(node, source) {
                     *complexity += 1;
@@ -193,6 +367,415 @@ pub fn calculate_for_node(node: &Node, source: &str, language: &str) -> u32 {
     complexity
 }
 
+/// Cyclomatic complexity of every function in `source`, from a single parse.
+///
+/// Methods are named `Class.method`, matching their node IDs. Returns an
+/// empty list for unsupported languages or sources that fail to parse.
+pub fn per_function(source: &str, language: &str) -> Vec<(String, u32)> {
+    let Some(module) = crate::parser::parse_source(source, "snippet", language).module else {
+        return Vec::new();
+    };
+
+    let functions = module
+        .functions
+        .iter()
+        .map(|f| (f.name.clone(), f.body_complexity));
+    let methods = module.classes.iter().flat_map(|class| {
+        class
+            .methods
+            .iter()
+            .map(move |m| (format!("{}.{}", class.name, m.name), m.body_complexity))
+    });
+    functions.chain(methods).collect()
+}
+
+/// Cyclomatic complexity of many `(source, language)` snippets, in input order.
+///
+/// Each snippet is parsed and scored with [`calculate_for_node`]. Snippets are
+/// spread over rayon's thread pool, and each worker keeps one parser per
+/// language for the whole batch instead of building one per snippet.
+/// Languages without a grammar, and sources that fail to parse, fall back to
+/// the keyword count of [`calculate`].
+pub fn calculate_batch(inputs: &[(String, String)]) -> Vec<u32> {
+    inputs
+        .par_iter()
+        .map_init(
+            HashMap::<String, Option<Parser>>::new,
+            |parsers, (source, language)| {
+                let language = crate::incremental::normalize_language(language);
+                if !parsers.contains_key(language) {
+                    let parser = crate::incremental::get_tree_sitter_language(language)
+                        .ok()
+                        .and_then(|ts_language| {
+                            let mut parser = Parser::new();
+                            parser.set_language(&ts_language).ok()?;
+                            Some(parser)
+                        });
+                    parsers.insert(language.to_string(), parser);
+                }
+                let tree = parsers
+                    .get_mut(language)
+                    .and_then(Option::as_mut)
+                    .and_then(|parser| parser.parse(source, None));
+                match tree {
+                    Some(tree) => calculate_for_node(&tree.root_node(), source, language),
+                    None => calculate(source, language),
+                }
+            },
+        )
+        .collect()
+}
+
+/// How a node contributes to cognitive complexity.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum CognitiveRole {
+    /// `if`: +1 plus nesting, or a flat +1 when it continues an else-chain
+    If,
+    /// `elif` / `elsif`: flat +1, continues the chain
+    ElseIf,
+    /// `else`: flat +1 unless it only wraps another `if`
+    Else,
+    /// Loops, switches, catches and ternaries: +1 plus nesting
+    Structural,
+    /// Lambdas and closures: no increment, but nest their bodies when inside a function
+    Nesting,
+    /// Named function definitions
+    Function,
+    /// `&&`, `||` and friends: +1 per sequence of like operators
+    Logical,
+    /// `goto`: +1
+    Jump,
+    /// `break` / `continue`: +1 when they carry a label
+    LabeledJump,
+    /// Calls, checked for direct recursion
+    Call,
+    Other,
+}
+
+fn cognitive_role(kind: &str) -> CognitiveRole {
+    match kind {
+        "if_statement" | "if_expression" | "if" | "unless" => CognitiveRole::If,
+        "elif_clause" | "elsif" | "else_if_clause" => CognitiveRole::ElseIf,
+        "else_clause" | "else" => CognitiveRole::Else,
+        // Loops
+        "for_statement"
+        | "for_in_statement"
+        | "for_range_loop"
+        | "enhanced_for_statement"
+        | "foreach_statement"
+        | "while_statement"
+        | "do_statement"
+        | "for_expression"
+        | "while_expression"
+        | "loop_expression"
+        | "do_while_expression"
+        | "do_while_statement"
+        | "while"
+        | "until"
+        | "for"
+        | "while_modifier"
+        | "until_modifier"
+        // Switches
+        | "switch_statement"
+        | "switch_expression"
+        | "expression_switch_statement"
+        | "type_switch_statement"
+        | "select_statement"
+        | "match_statement"
+        | "match_expression"
+        | "when_expression"
+        | "case"
+        // Catches
+        | "catch_clause"
+        | "catch_block"
+        | "except_clause"
+        | "rescue"
+        | "rescue_modifier"
+        // Ternaries and statement modifiers
+        | "conditional_expression"
+        | "ternary_expression"
+        | "conditional"
+        | "if_modifier"
+        | "unless_modifier" => CognitiveRole::Structural,
+        "lambda"
+        | "lambda_expression"
+        | "arrow_function"
+        | "function_expression"
+        | "func_literal"
+        | "closure_expression"
+        | "anonymous_method_expression"
+        | "lambda_literal"
+        | "anonymous_function"
+        | "do_block" => CognitiveRole::Nesting,
+        "function_definition"
+        | "function_declaration"
+        | "function_item"
+        | "method_definition"
+        | "method_declaration"
+        | "constructor_declaration"
+        | "local_function_statement"
+        | "method"
+        | "singleton_method" => CognitiveRole::Function,
+        "binary_expression"
+        | "boolean_operator"
+        | "binary"
+        | "infix_expression"
+        | "logical_and_expression"
+        | "logical_or_expression"
+        | "if_null_expression" => CognitiveRole::Logical,
+        "goto_statement" => CognitiveRole::Jump,
+        "break_statement" | "continue_statement" | "break_expression" | "continue_expression" => {
+            CognitiveRole::LabeledJump
+        }
+        "call_expression"
+        | "call"
+        | "method_invocation"
+        | "invocation_expression"
+        | "function_call_expression"
+        | "member_call_expression"
+        | "nullsafe_member_call_expression"
+        | "scoped_call_expression" => CognitiveRole::Call,
+        _ => CognitiveRole::Other,
+    }
+}
+
+/// Calculate cognitive complexity for a code snippet.
+///
+/// Follows the SonarSource rules: each break in linear flow (`if`, loops,
+/// `switch`, `catch`, ternaries) adds 1 plus its nesting depth, `else`
+/// branches add a flat 1, each run of like logical operators adds 1, and
+/// so do labeled jumps and direct recursion. Lambdas and nested functions
+/// deepen nesting without adding anything themselves.
+///
+/// Returns 0 for unsupported languages or sources that fail to parse.
+pub fn calculate_cognitive(source: &str, language: &str) -> u32 {
+    let Ok(ts_language) = crate::incremental::get_tree_sitter_language(language) else {
+        return 0;
+    };
+    let mut parser = tree_sitter::Parser::new();
+    if parser.set_language(&ts_language).is_err() {
+        return 0;
+    }
+    match parser.parse(source, None) {
+        Some(tree) => calculate_cognitive_for_node(&tree.root_node(), source, language),
+        None => 0,
+    }
+}
+
+/// Calculate cognitive complexity for a tree-sitter node.
+pub fn calculate_cognitive_for_node(node: &Node, source: &str, language: &str) -> u32 {
+    let mut walker = CognitiveWalker {
+        source,
+        ruby: crate::incremental::normalize_language(language) == "ruby",
+        function_depth: 0,
+        function_name: None,
+        total: 0,
+    };
+    walker.visit(*node, 0, false);
+    walker.total
+}
+
+struct CognitiveWalker<'a> {
+    source: &'a str,
+    /// Ruby `{ ... }` blocks are closures; elsewhere `block` is a plain statement block
+    ruby: bool,
+    function_depth: u32,
+    /// Name of the outermost enclosing function, for recursion checks
+    function_name: Option<&'a str>,
+    total: u32,
+}
+
+impl<'a> CognitiveWalker<'a> {
+    fn text(&self, node: Node) -> &'a str {
+        self.source.get(node.byte_range()).unwrap_or("")
+    }
+
+    /// Visit `node`; `else_branch` is set when it is the alternative of an `if`.
+    fn visit(&mut self, node: Node<'_>, nesting: u32, else_branch: bool) {
+        // Named nodes only: Ruby's `if` node shares its kind with the keyword token
+        if !node.is_named() {
+            return;
+        }
+        let role = match node.kind() {
+            "block" if self.ruby => CognitiveRole::Nesting,
+            kind => cognitive_role(kind),
+        };
+
+        if else_branch {
+            match role {
+                CognitiveRole::If | CognitiveRole::ElseIf => {
+                    self.total += 1;
+                    self.visit_if_children(node, nesting);
+                }
+                CognitiveRole::Else => match sole_if(node) {
+                    Some(inner) => self.visit(inner, nesting, true),
+                    None => {
+                        self.total += 1;
+                        self.visit_children(node, nesting + 1);
+                    }
+                },
+                // A bare block as the alternative (Go, Java, C#)
+                _ => {
+                    self.total += 1;
+                    self.visit_children(node, nesting + 1);
+                }
+            }
+            return;
+        }
+
+        match role {
+            CognitiveRole::If => {
+                self.total += 1 + nesting;
+                self.visit_if_children(node, nesting);
+            }
+            CognitiveRole::ElseIf => {
+                self.total += 1;
+                self.visit_if_children(node, nesting);
+            }
+            // `else` outside an if-chain: for/else, case/else, begin/rescue/else
+            CognitiveRole::Else => self.visit_children(node, nesting),
+            CognitiveRole::Structural => {
+                self.total += 1 + nesting;
+                self.visit_children(node, nesting + 1);
+            }
+            CognitiveRole::Nesting | CognitiveRole::Function if self.function_depth > 0 => {
+                self.function_depth += 1;
+                self.visit_children(node, nesting + 1);
+                self.function_depth -= 1;
+            }
+            CognitiveRole::Nesting | CognitiveRole::Function => {
+                self.function_depth = 1;
+                self.function_name = self.function_name(node);
+                self.visit_children(node, nesting);
+                self.function_depth = 0;
+                self.function_name = None;
+            }
+            CognitiveRole::Logical => {
+                if let Some(op) = self.logical_operator(node) {
+                    let continues_sequence = node
+                        .parent()
+                        .filter(|p| cognitive_role(p.kind()) == CognitiveRole::Logical)
+                        .and_then(|p| self.logical_operator(p))
+                        .is_some_and(|parent_op| parent_op == op);
+                    if !continues_sequence {
+                        self.total += 1;
+                    }
+                }
+                self.visit_children(node, nesting);
+            }
+            CognitiveRole::Jump => self.total += 1,
+            CognitiveRole::LabeledJump => {
+                let mut cursor = node.walk();
+                let labeled = node.named_children(&mut cursor).any(|c| {
+                    matches!(c.kind(), "label" | "statement_identifier" | "label_name")
+                        || (c.kind() == "identifier" && node.kind().ends_with("_statement"))
+                });
+                if labeled {
+                    self.total += 1;
+                }
+            }
+            CognitiveRole::Call => {
+                if self.is_recursive_call(node) {
+                    self.total += 1;
+                }
+                self.visit_children(node, nesting);
+            }
+            CognitiveRole::Other => self.visit_children(node, nesting),
+        }
+    }
+
+    fn visit_children(&mut self, node: Node<'_>, nesting: u32) {
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.visit(child, nesting, false);
+        }
+    }
+
+    /// The condition and body nest one level; the alternative continues the chain.
+    ///
+    /// Grammars without an `alternative` field (Kotlin) mark it with a bare
+    /// `else` token instead.
+    fn visit_if_children(&mut self, node: Node<'_>, nesting: u32) {
+        let mut cursor = node.walk();
+        if !cursor.goto_first_child() {
+            return;
+        }
+        let mut after_else = false;
+        loop {
+            let child = cursor.node();
+            if !child.is_named() {
+                after_else = child.kind() == "else";
+            } else if std::mem::take(&mut after_else) || cursor.field_name() == Some("alternative")
+            {
+                self.visit(child, nesting, true);
+            } else {
+                self.visit(child, nesting + 1, false);
+            }
+            if !cursor.goto_next_sibling() {
+                break;
+            }
+        }
+    }
+
+    /// The declared name of a function, digging through C declarators.
+    fn function_name(&self, node: Node<'_>) -> Option<&'a str> {
+        if let Some(name) = node.child_by_field_name("name") {
+            return Some(self.text(name));
+        }
+        let mut declarator = node.child_by_field_name("declarator")?;
+        while let Some(inner) = declarator.child_by_field_name("declarator") {
+            declarator = inner;
+        }
+        Some(self.text(declarator))
+    }
+
+    /// The logical operator of a binary node, if it is one.
+    fn logical_operator(&self, node: Node<'_>) -> Option<&'a str> {
+        match node.kind() {
+            "logical_and_expression" => return Some("&&"),
+            "logical_or_expression" => return Some("||"),
+            "if_null_expression" => return Some("??"),
+            _ => {}
+        }
+        if let Some(op) = node.child_by_field_name("operator") {
+            let text = self.text(op);
+            return DECISION_OPERATORS.contains(text).then_some(text);
+        }
+        let mut cursor = node.walk();
+        let found = node
+            .children(&mut cursor)
+            .map(|c| self.text(c))
+            .find(|text| DECISION_OPERATORS.contains(text));
+        found
+    }
+
+    fn is_recursive_call(&self, node: Node<'_>) -> bool {
+        let Some(name) = self.function_name else {
+            return false;
+        };
+        // Kotlin calls have no fields; the callee is the first child
+        let callee = ["function", "method", "name"]
+            .iter()
+            .find_map(|field| node.child_by_field_name(field))
+            .or_else(|| node.named_child(0))
+            .map(|n| self.text(n))
+            .unwrap_or("");
+        let last = callee.rsplit(['.', ':', '\\']).next().unwrap_or("");
+        last == name
+    }
+}
+
+/// The `if` wrapped by an `else` clause with nothing else in it (`else if`).
+fn sole_if<'t>(node: Node<'t>) -> Option<Node<'t>> {
+    let mut cursor = node.walk();
+    let mut children = node
+        .named_children(&mut cursor)
+        .filter(|c| !c.kind().contains("comment"));
+    let first = children.next()?;
+    let only = children.next().is_none();
+    (only && matches!(cognitive_role(first.kind()), CognitiveRole::If)).then_some(first)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -215,4 +798,142 @@ mod tests {
         // 1 (base) + 1 (if) + 1 (and) + 1 (or) = 4
         assert_eq!(calculate(source, "python"), 4);
     }
+
+    #[test]
+    fn test_generic_complexity() {
+        let shell =
+            "if [ -f \"$1\" ] && [ -r \"$1\" ]; then\n  for f in *; do echo \"$f\"; done\nfi\n";
+        // 1 (base) + if + && + for
+        assert_eq!(calculate(shell, GENERIC), 4);
+
+        let sql = "SELECT CASE WHEN a IS NULL THEN 0 ELSE a END FROM t WHERE x = ?";
+        // 1 (base) + CASE + ? placeholder; identifiers containing keywords don't count
+        assert_eq!(calculate_generic(sql), 3);
+        assert_eq!(calculate_generic("format(verify) ?? fallback"), 2);
+        assert_eq!(calculate_generic(""), 1);
+    }
+
+    #[test]
+    fn test_try_calculate_reports_unsupported_language() {
+        assert_eq!(try_calculate("if x: pass", "py"), Ok(2));
+        assert_eq!(try_calculate("if true; then :; fi", GENERIC), Ok(2));
+        let err = try_calculate("if true; then :; fi", "shell").unwrap_err();
+        assert!(err.contains("Unsupported language: shell"), "{}", err);
+    }
+
+    #[test]
+    fn test_per_function() {
+        let source = r#"
+def simple():
+    return 1
+
+class Router:
+    def route(self, path):
+        if path == "/" or path == "":
+            return "home"
+        for part in path.split("/"):
+            if part:
+                return part
+"#;
+        assert_eq!(
+            per_function(source, "python"),
+            vec![("simple".to_string(), 1), ("Router.route".to_string(), 5)]
+        );
+        assert!(per_function("x", "cobol").is_empty());
+    }
+
+    #[test]
+    fn test_calculate_batch_keeps_input_order() {
+        let snippet = |source: &str, language: &str| (source.to_string(), language.to_string());
+        let inputs = vec![
+            snippet(
+                "def f(x):\n    if x and x > 1:\n        return 1\n",
+                "python",
+            ),
+            snippet("func f() {}", "go"),
+            snippet("def g(x):\n    return x\n", "py"),
+            snippet(
+                "fn f(x: u8) { while x > 0 { if x == 1 { break; } } }",
+                "rust",
+            ),
+            snippet("if x && y", "cobol"),
+        ];
+
+        assert_eq!(calculate_batch(&inputs), vec![3, 1, 1, 3, 3]);
+        assert!(calculate_batch(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_cognitive_nesting_and_labeled_jump() {
+        // SonarSource's sumOfPrimes example
+        let source = r#"class A {
+    int sumOfPrimes(int max) {
+        int total = 0;
+        OUT: for (int i = 1; i <= max; ++i) {
+            for (int j = 2; j < i; ++j) {
+                if (i % j == 0) {
+                    continue OUT;
+                }
+            }
+            total += i;
+        }
+        return total;
+    }
+}"#;
+        assert_eq!(calculate_cognitive(source, "java"), 7);
+    }
+
+    #[test]
+    fn test_cognitive_else_chains_are_flat() {
+        let python = r#"def classify(n):
+    if n < 0:
+        return "neg"
+    elif n == 0:
+        return "zero"
+    else:
+        for d in range(2, n):
+            if n % d == 0 and d > 1 or n > 100:
+                return "composite"
+    return "prime"
+"#;
+        // if +1, elif +1, else +1, for +2, inner if +3, and/or +2
+        assert_eq!(calculate_cognitive(python, "python"), 10);
+
+        let go = "package main\nfunc f(x int) int {\n\tif x > 0 {\n\t\treturn 1\n\t} else if x < 0 {\n\t\treturn -1\n\t} else {\n\t\treturn 0\n\t}\n}\n";
+        assert_eq!(calculate_cognitive(go, "go"), 3);
+
+        let ruby = "def f(x)\n  if x\n    1\n  elsif y\n    2\n  else\n    3\n  end\nend\n";
+        assert_eq!(calculate_cognitive(ruby, "ruby"), 3);
+
+        let kotlin = "fun f(x: Int): Int {\n    return if (x > 0) 1 else if (x < 0) -1 else 0\n}\n";
+        assert_eq!(calculate_cognitive(kotlin, "kotlin"), 3);
+
+        let php = "<?php\nfunction f($x) {\n    if ($x > 0) {\n        return 1;\n    } elseif ($x < 0) {\n        return -1;\n    } else {\n        return 0;\n    }\n}\n";
+        assert_eq!(calculate_cognitive(php, "php"), 3);
+    }
+
+    #[test]
+    fn test_cognitive_logical_sequences() {
+        // `a && b && c` is one sequence, `|| d` starts another
+        let source = "function f(a, b, c, d) { if (a && b && c || d) { return 1; } }";
+        assert_eq!(calculate_cognitive(source, "javascript"), 3);
+    }
+
+    #[test]
+    fn test_cognitive_closures_nest() {
+        let source = "function f(xs) { xs.forEach(x => { if (x) { log(x); } }); }";
+        assert_eq!(calculate_cognitive(source, "javascript"), 2);
+    }
+
+    #[test]
+    fn test_cognitive_recursion() {
+        let source = "def fact(n):\n    return 1 if n <= 1 else n * fact(n - 1)\n";
+        // ternary +1, recursive call +1
+        assert_eq!(calculate_cognitive(source, "python"), 2);
+    }
+
+    #[test]
+    fn test_cognitive_unsupported_language() {
+        assert_eq!(calculate_cognitive("if x then y", "cobol"), 0);
+    }
 }
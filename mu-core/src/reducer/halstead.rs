@@ -0,0 +1,185 @@
+//! Halstead software science metrics.
+//!
+//! Splits the tokens of a parse tree into operators and operands and derives
+//! volume, difficulty and effort from their counts. The grammar does the
+//! per-language work: keywords and punctuation are anonymous tokens
+//! (operators), while identifiers and literals are named leaves (operands).
+//! String literals count as one operand however the grammar splits them.
+
+use serde::Serialize;
+use std::collections::HashSet;
+use tree_sitter::{Node, Parser};
+
+/// Halstead counts and the measures derived from them.
+#[allow(non_snake_case)]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize)]
+pub struct HalsteadMetrics {
+    /// Distinct operators
+    pub n1: u32,
+    /// Distinct operands
+    pub n2: u32,
+    /// Total operators
+    pub N1: u32,
+    /// Total operands
+    pub N2: u32,
+    /// `N * log2(n)`: size of the implementation in bits
+    pub volume: f64,
+    /// `(n1 / 2) * (N2 / n2)`: how hard the code is to write or read
+    pub difficulty: f64,
+    /// `difficulty * volume`: mental effort to produce the code
+    pub effort: f64,
+}
+
+impl HalsteadMetrics {
+    fn from_counts(n1: u32, n2: u32, total_operators: u32, total_operands: u32) -> Self {
+        let vocabulary = f64::from(n1 + n2);
+        let length = f64::from(total_operators + total_operands);
+        let volume = if vocabulary > 0.0 {
+            length * vocabulary.log2()
+        } else {
+            0.0
+        };
+        let difficulty = if n2 > 0 {
+            (f64::from(n1) / 2.0) * (f64::from(total_operands) / f64::from(n2))
+        } else {
+            0.0
+        };
+
+        Self {
+            n1,
+            n2,
+            N1: total_operators,
+            N2: total_operands,
+            volume,
+            difficulty,
+            effort: difficulty * volume,
+        }
+    }
+}
+
+/// Closing delimiters; each pair counts once, as its opening token.
+const CLOSING_DELIMITERS: &[&str] = &[")", "]", "}", "end"];
+
+/// Calculate Halstead metrics for a code snippet.
+///
+/// Returns all-zero metrics for unsupported languages or unparsable input.
+pub fn calculate(source: &str, language: &str) -> HalsteadMetrics {
+    let Ok(ts_language) = crate::incremental::get_tree_sitter_language(language) else {
+        return HalsteadMetrics::default();
+    };
+    let mut parser = Parser::new();
+    if parser.set_language(&ts_language).is_err() {
+        return HalsteadMetrics::default();
+    }
+    match parser.parse(source, None) {
+        Some(tree) => calculate_for_node(&tree.root_node(), source),
+        None => HalsteadMetrics::default(),
+    }
+}
+
+/// Calculate Halstead metrics for a tree-sitter node.
+pub fn calculate_for_node(node: &Node, source: &str) -> HalsteadMetrics {
+    let mut counts = TokenCounts::default();
+    counts.visit(*node, source);
+    HalsteadMetrics::from_counts(
+        counts.operators.len() as u32,
+        counts.operands.len() as u32,
+        counts.total_operators,
+        counts.total_operands,
+    )
+}
+
+#[derive(Default)]
+struct TokenCounts<'a> {
+    operators: HashSet<&'a str>,
+    operands: HashSet<&'a str>,
+    total_operators: u32,
+    total_operands: u32,
+}
+
+impl<'a> TokenCounts<'a> {
+    fn visit(&mut self, node: Node<'_>, source: &'a str) {
+        let kind = node.kind();
+        if kind.contains("comment") || node.is_missing() {
+            return;
+        }
+        let text = source.get(node.byte_range()).unwrap_or("").trim();
+
+        // Strings may be split into start/content/end children
+        if node.is_named() && is_string_literal(kind) {
+            self.add_operand(text);
+            return;
+        }
+
+        if node.child_count() == 0 {
+            if text.is_empty() {
+                return;
+            }
+            if node.is_named() {
+                self.add_operand(text);
+            } else if !CLOSING_DELIMITERS.contains(&text) {
+                self.operators.insert(text);
+                self.total_operators += 1;
+            }
+            return;
+        }
+
+        let mut cursor = node.walk();
+        for child in node.children(&mut cursor) {
+            self.visit(child, source);
+        }
+    }
+
+    fn add_operand(&mut self, text: &'a str) {
+        self.operands.insert(text);
+        self.total_operands += 1;
+    }
+}
+
+fn is_string_literal(kind: &str) -> bool {
+    kind == "string"
+        || kind.ends_with("string_literal")
+        || matches!(
+            kind,
+            "template_string"
+                | "raw_string"
+                | "interpolated_string_expression"
+                | "heredoc_body"
+                | "encapsed_string"
+        )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_halstead_counts() {
+        // Operators: def ( , : return +   Operands: add a b
+        let metrics = calculate("def add(a, b):\n    return a + b\n", "python");
+
+        assert_eq!(metrics.n1, 6);
+        assert_eq!(metrics.n2, 3);
+        assert_eq!(metrics.N1, 6);
+        assert_eq!(metrics.N2, 5);
+
+        let volume = 11.0 * 9f64.log2();
+        let difficulty = 3.0 * (5.0 / 3.0);
+        assert!((metrics.volume - volume).abs() < 1e-9);
+        assert!((metrics.difficulty - difficulty).abs() < 1e-9);
+        assert!((metrics.effort - volume * difficulty).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_strings_are_single_operands() {
+        let metrics = calculate(r#"const s = "a b c";"#, "javascript");
+        // Operands: s "a b c"   Operators: const = ;
+        assert_eq!((metrics.n2, metrics.N2), (2, 2));
+        assert_eq!((metrics.n1, metrics.N1), (3, 3));
+    }
+
+    #[test]
+    fn test_unsupported_language() {
+        assert_eq!(calculate("x = 1", "cobol"), HalsteadMetrics::default());
+    }
+}
@@ -0,0 +1,428 @@
+//! Import hygiene checks: unused and duplicate imports.
+//!
+//! Cross-references the names bound by top-level import statements against
+//! the identifiers used in the rest of the module. Only top-level imports are
+//! checked, so conditional imports (`try: import json` / `except: import
+//! simplejson as json`) and function-local imports are left alone.
+//!
+//! Supported languages: Python, TypeScript/JavaScript, Rust.
+
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use tree_sitter::{Node, Parser};
+
+/// Kind of import problem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ImportIssueKind {
+    /// Name is imported but never referenced.
+    Unused,
+    /// Name is imported more than once.
+    Duplicate,
+}
+
+/// An import problem found in a module.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportIssue {
+    pub kind: ImportIssueKind,
+    /// The locally bound name (alias if the import is renamed).
+    pub name: String,
+    /// 1-based line of the offending import.
+    pub line: u32,
+}
+
+/// Rust traits that are usually imported only to call their methods, which
+/// leaves no identifier behind. These are never reported as unused.
+const RUST_METHOD_TRAITS: &[&str] = &[
+    "Read",
+    "Write",
+    "BufRead",
+    "Seek",
+    "FromStr",
+    "Borrow",
+    "BorrowMut",
+    "Context",
+    "Colorize",
+    "IsTerminal",
+];
+
+/// A name bound by an import statement.
+struct Binding {
+    name: String,
+    line: u32,
+}
+
+/// Check a module's imports for unused and duplicate names.
+///
+/// Returns an empty list for languages without import support.
+pub fn check_imports(source: &str, language: &str) -> Result<Vec<ImportIssue>, String> {
+    let (ts_language, lang) = match language.to_lowercase().as_str() {
+        "python" | "py" => (tree_sitter_python::LANGUAGE.into(), Lang::Python),
+        "typescript" | "ts" => (
+            tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            Lang::TypeScript,
+        ),
+        "tsx" => (
+            tree_sitter_typescript::LANGUAGE_TSX.into(),
+            Lang::TypeScript,
+        ),
+        "javascript" | "js" | "jsx" => (tree_sitter_javascript::LANGUAGE.into(), Lang::TypeScript),
+        "rust" | "rs" => (tree_sitter_rust::LANGUAGE.into(), Lang::Rust),
+        _ => return Ok(Vec::new()),
+    };
+
+    let mut parser = Parser::new();
+    parser
+        .set_language(&ts_language)
+        .map_err(|e| format!("Failed to set language: {}", e))?;
+    let tree = parser
+        .parse(source, None)
+        .ok_or("Failed to parse source for import check")?;
+    let root = tree.root_node();
+
+    // Bindings from top-level imports, in source order
+    let mut bindings = Vec::new();
+    let mut cursor = root.walk();
+    for child in root.children(&mut cursor) {
+        if lang.is_import(&child) {
+            lang.collect_bindings(&child, source, &mut bindings);
+        }
+    }
+
+    let mut used = HashSet::new();
+    collect_used_identifiers(&root, source, lang, &mut used);
+
+    let mut issues = Vec::new();
+    let mut seen: HashMap<&str, u32> = HashMap::new();
+    for binding in &bindings {
+        if seen.insert(&binding.name, binding.line).is_some() {
+            issues.push(ImportIssue {
+                kind: ImportIssueKind::Duplicate,
+                name: binding.name.clone(),
+                line: binding.line,
+            });
+            continue;
+        }
+
+        let exempt = lang == Lang::Rust
+            && (RUST_METHOD_TRAITS.contains(&binding.name.as_str())
+                || binding.name.ends_with("Ext"));
+        if !exempt && !used.contains(binding.name.as_str()) {
+            issues.push(ImportIssue {
+                kind: ImportIssueKind::Unused,
+                name: binding.name.clone(),
+                line: binding.line,
+            });
+        }
+    }
+
+    Ok(issues)
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Lang {
+    Python,
+    TypeScript,
+    Rust,
+}
+
+impl Lang {
+    /// Whether a node is an import statement whose bindings should be checked.
+    fn is_import(&self, node: &Node) -> bool {
+        match self {
+            Lang::Python => matches!(node.kind(), "import_statement" | "import_from_statement"),
+            Lang::TypeScript => node.kind() == "import_statement",
+            // `pub use` is a re-export, not a local use
+            Lang::Rust => {
+                node.kind() == "use_declaration" && !has_child_kind(node, "visibility_modifier")
+            }
+        }
+    }
+
+    /// Whether identifiers under this node belong to an import statement.
+    fn is_import_subtree(&self, node: &Node) -> bool {
+        match self {
+            Lang::Python => matches!(
+                node.kind(),
+                "import_statement" | "import_from_statement" | "future_import_statement"
+            ),
+            Lang::TypeScript => node.kind() == "import_statement",
+            Lang::Rust => node.kind() == "use_declaration",
+        }
+    }
+
+    fn collect_bindings(&self, node: &Node, source: &str, out: &mut Vec<Binding>) {
+        let line = node.start_position().row as u32 + 1;
+        match self {
+            Lang::Python => collect_python_bindings(node, source, line, out),
+            Lang::TypeScript => collect_ts_bindings(node, source, line, out),
+            Lang::Rust => {
+                if let Some(argument) = node.child_by_field_name("argument") {
+                    collect_rust_bindings(&argument, source, None, line, out);
+                }
+            }
+        }
+    }
+
+    /// Node kinds that count as a use of an imported name.
+    fn is_usage(&self, kind: &str) -> bool {
+        match self {
+            Lang::Python => kind == "identifier",
+            Lang::TypeScript => matches!(
+                kind,
+                "identifier" | "type_identifier" | "shorthand_property_identifier"
+            ),
+            Lang::Rust => matches!(kind, "identifier" | "type_identifier"),
+        }
+    }
+}
+
+fn has_child_kind(node: &Node, kind: &str) -> bool {
+    let mut cursor = node.walk();
+    let found = node.children(&mut cursor).any(|c| c.kind() == kind);
+    found
+}
+
+fn text<'a>(node: &Node, source: &'a str) -> &'a str {
+    node.utf8_text(source.as_bytes()).unwrap_or("")
+}
+
+/// `import a.b` binds `a`; `import a as b` and `from x import y as b` bind `b`.
+fn collect_python_bindings(node: &Node, source: &str, line: u32, out: &mut Vec<Binding>) {
+    let is_from = node.kind() == "import_from_statement";
+    let mut cursor = node.walk();
+    for name in node.children_by_field_name("name", &mut cursor) {
+        let bound = match name.kind() {
+            "aliased_import" => name
+                .child_by_field_name("alias")
+                .map(|a| text(&a, source).to_string()),
+            "dotted_name" if is_from => Some(text(&name, source).to_string()),
+            "dotted_name" => text(&name, source).split('.').next().map(str::to_string),
+            _ => None,
+        };
+        if let Some(name) = bound {
+            out.push(Binding { name, line });
+        }
+    }
+}
+
+/// Default, namespace (`* as x`), and named (`{ a, b as c }`) imports.
+fn collect_ts_bindings(node: &Node, source: &str, line: u32, out: &mut Vec<Binding>) {
+    let mut cursor = node.walk();
+    let Some(clause) = node
+        .children(&mut cursor)
+        .find(|c| c.kind() == "import_clause")
+    else {
+        return;
+    };
+
+    let mut cursor = clause.walk();
+    for child in clause.children(&mut cursor) {
+        match child.kind() {
+            "identifier" => out.push(Binding {
+                name: text(&child, source).to_string(),
+                line,
+            }),
+            "namespace_import" => {
+                let mut inner = child.walk();
+                let id = child
+                    .children(&mut inner)
+                    .find(|c| c.kind() == "identifier");
+                if let Some(id) = id {
+                    out.push(Binding {
+                        name: text(&id, source).to_string(),
+                        line,
+                    });
+                }
+            }
+            "named_imports" => {
+                let mut inner = child.walk();
+                for spec in child.children(&mut inner) {
+                    if spec.kind() != "import_specifier" {
+                        continue;
+                    }
+                    let bound = spec
+                        .child_by_field_name("alias")
+                        .or_else(|| spec.child_by_field_name("name"));
+                    if let Some(bound) = bound {
+                        out.push(Binding {
+                            name: text(&bound, source).to_string(),
+                            line,
+                        });
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Walk a `use` tree. `path_tail` is the last segment of the enclosing path,
+/// which `self` in a use list binds (`use std::io::{self, Read}` binds `io`).
+fn collect_rust_bindings(
+    node: &Node,
+    source: &str,
+    path_tail: Option<&str>,
+    line: u32,
+    out: &mut Vec<Binding>,
+) {
+    let bound = match node.kind() {
+        "identifier" => Some(text(node, source).to_string()),
+        "scoped_identifier" => node
+            .child_by_field_name("name")
+            .map(|n| text(&n, source).to_string()),
+        "use_as_clause" => node
+            .child_by_field_name("alias")
+            .map(|n| text(&n, source).to_string()),
+        "self" => path_tail.map(str::to_string),
+        "scoped_use_list" => {
+            let tail = node.child_by_field_name("path").map(|p| {
+                text(&p, source)
+                    .rsplit("::")
+                    .next()
+                    .unwrap_or("")
+                    .to_string()
+            });
+            if let Some(list) = node.child_by_field_name("list") {
+                collect_rust_bindings(&list, source, tail.as_deref(), line, out);
+            }
+            None
+        }
+        "use_list" => {
+            let mut cursor = node.walk();
+            for child in node.named_children(&mut cursor) {
+                collect_rust_bindings(&child, source, path_tail, line, out);
+            }
+            None
+        }
+        _ => None,
+    };
+
+    if let Some(name) = bound {
+        // `use crate::foo::self`-style leftovers bind nothing useful
+        if !matches!(name.as_str(), "" | "crate" | "super" | "self") {
+            out.push(Binding { name, line });
+        }
+    }
+}
+
+/// Collect every identifier used outside of import statements.
+fn collect_used_identifiers<'a>(
+    node: &Node,
+    source: &'a str,
+    lang: Lang,
+    used: &mut HashSet<&'a str>,
+) {
+    if lang.is_import_subtree(node) {
+        return;
+    }
+
+    if lang.is_usage(node.kind()) {
+        used.insert(text(node, source));
+    } else if lang == Lang::Python && node.kind() == "string_content" {
+        // Forward references ("Foo") and `__all__` entries name imports in strings
+        for word in text(node, source).split(|c: char| !(c.is_alphanumeric() || c == '_')) {
+            if !word.is_empty() {
+                used.insert(word);
+            }
+        }
+    }
+
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_used_identifiers(&child, source, lang, used);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(issues: &[ImportIssue], kind: ImportIssueKind) -> Vec<&str> {
+        issues
+            .iter()
+            .filter(|i| i.kind == kind)
+            .map(|i| i.name.as_str())
+            .collect()
+    }
+
+    #[test]
+    fn test_python_unused_and_duplicate() {
+        let source = r#"
+import os
+import sys
+import os.path
+from typing import List, Dict as D
+from collections import OrderedDict
+
+try:
+    import json
+except ImportError:
+    import simplejson as json
+
+def main(items: "List[str]") -> D:
+    print(sys.argv, json)
+    return {}
+"#;
+        let issues = check_imports(source, "python").unwrap();
+        assert_eq!(
+            names(&issues, ImportIssueKind::Unused),
+            vec!["os", "OrderedDict"]
+        );
+        assert_eq!(names(&issues, ImportIssueKind::Duplicate), vec!["os"]);
+
+        let dup = issues
+            .iter()
+            .find(|i| i.kind == ImportIssueKind::Duplicate)
+            .unwrap();
+        assert_eq!(dup.line, 4);
+    }
+
+    #[test]
+    fn test_typescript_unused_imports() {
+        let source = r#"
+import React, { useState, useEffect as effect } from 'react';
+import * as utils from './utils';
+import type { Props } from './types';
+import './styles.css';
+
+export function App(props: Props) {
+  const [count] = useState(0);
+  return <div>{count}</div>;
+}
+"#;
+        let issues = check_imports(source, "tsx").unwrap();
+        assert_eq!(
+            names(&issues, ImportIssueKind::Unused),
+            vec!["React", "effect", "utils"]
+        );
+    }
+
+    #[test]
+    fn test_rust_unused_imports() {
+        let source = r#"
+use std::collections::{HashMap, HashSet};
+use std::io::{self, Write};
+use std::fmt::Display as Show;
+pub use crate::types::ModuleDef;
+
+fn render(out: &mut dyn Write) -> io::Result<HashMap<String, u32>> {
+    writeln!(out, "hi")?;
+    Ok(HashMap::new())
+}
+"#;
+        let issues = check_imports(source, "rust").unwrap();
+        assert_eq!(
+            names(&issues, ImportIssueKind::Unused),
+            vec!["HashSet", "Show"]
+        );
+        assert!(names(&issues, ImportIssueKind::Duplicate).is_empty());
+    }
+
+    #[test]
+    fn test_unsupported_language_has_no_issues() {
+        assert!(check_imports("import java.util.List;", "java")
+            .unwrap()
+            .is_empty());
+    }
+}
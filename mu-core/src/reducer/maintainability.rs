@@ -0,0 +1,84 @@
+//! Maintainability Index.
+//!
+//! Combines Halstead volume, cyclomatic complexity and lines of code into a
+//! single 0-100 score, using the variant Visual Studio reports:
+//!
+//! `MI = max(0, (171 - 5.2 ln(V) - 0.23 G - 16.2 ln(LOC)) * 100 / 171)`
+//!
+//! Higher is better. Microsoft rates 20-100 as maintainable, 10-19 as
+//! moderate and 0-9 as hard to maintain. Both inputs come from one parse: the
+//! Halstead and cyclomatic passes run over the same tree.
+
+use tree_sitter::Parser;
+
+use super::{complexity, halstead};
+
+/// Scores below this are hard to maintain (Visual Studio's red band).
+pub const LOW_THRESHOLD: f64 = 10.0;
+
+/// Calculate the Maintainability Index of a code snippet.
+///
+/// Lines of code counts non-blank lines. Returns 100.0 for empty input,
+/// unsupported languages and unparsable input, so callers only flag code
+/// that was actually measured.
+pub fn calculate(source: &str, language: &str) -> f64 {
+    let Ok(ts_language) = crate::incremental::get_tree_sitter_language(language) else {
+        return 100.0;
+    };
+    let mut parser = Parser::new();
+    if parser.set_language(&ts_language).is_err() {
+        return 100.0;
+    }
+    let Some(tree) = parser.parse(source, None) else {
+        return 100.0;
+    };
+    let root = tree.root_node();
+
+    let volume = halstead::calculate_for_node(&root, source).volume;
+    let cyclomatic = complexity::calculate_for_node(&root, source, language);
+    let lines = source.lines().filter(|l| !l.trim().is_empty()).count();
+    from_metrics(volume, cyclomatic, lines)
+}
+
+/// The Maintainability Index for already computed metrics.
+///
+/// Zero volume or zero lines contribute nothing rather than `ln(0)`.
+pub fn from_metrics(volume: f64, cyclomatic: u32, lines: usize) -> f64 {
+    let ln = |x: f64| if x > 0.0 { x.ln() } else { 0.0 };
+    let raw = 171.0 - 5.2 * ln(volume) - 0.23 * f64::from(cyclomatic) - 16.2 * ln(lines as f64);
+    (raw * 100.0 / 171.0).clamp(0.0, 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_metrics() {
+        let expected = (171.0 - 5.2 * 100f64.ln() - 0.23 * 3.0 - 16.2 * 10f64.ln()) * 100.0 / 171.0;
+        assert!((from_metrics(100.0, 3, 10) - expected).abs() < 1e-9);
+        assert_eq!(from_metrics(0.0, 0, 0), 100.0);
+        assert_eq!(from_metrics(1e12, 500, 100_000), 0.0);
+    }
+
+    #[test]
+    fn test_simple_code_scores_higher() {
+        let simple = calculate("def f(x):\n    return x\n", "python");
+        let mut branchy = String::from("def g(a, b, c):\n");
+        for i in 0..40 {
+            branchy.push_str(&format!(
+                "    if a > {i} and b < {i} or c == {i}:\n        a = a * b + c - {i}\n"
+            ));
+        }
+        let branchy = calculate(&branchy, "python");
+
+        assert!(simple > 60.0, "simple code scored {}", simple);
+        assert!(branchy < simple);
+        assert!((0.0..=100.0).contains(&branchy));
+    }
+
+    #[test]
+    fn test_unsupported_language() {
+        assert_eq!(calculate("x = 1", "cobol"), 100.0);
+    }
+}
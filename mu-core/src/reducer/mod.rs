@@ -1,4 +1,7 @@
 //! Reducer module for code transformation and complexity analysis.
 
 pub mod complexity;
+pub mod halstead;
+pub mod imports;
+pub mod maintainability;
 pub mod rules;
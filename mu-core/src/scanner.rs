@@ -12,6 +12,9 @@
 //! - File size filtering
 //! - Hidden file control
 //! - File hashing for cache invalidation
+//! - Identical-content deduplication (vendored copies)
+//! - Binary and minified file detection by content
+//! - Symlink cycle protection when following links
 //!
 //! # Performance
 //!
@@ -37,11 +40,11 @@
 use ignore::WalkBuilder;
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicUsize, Ordering};
-use std::sync::Mutex;
+use std::sync::{Arc, Mutex};
 use std::time::Instant;
 use xxhash_rust::xxh3::xxh3_64;
 
@@ -55,7 +58,7 @@ use xxhash_rust::xxh3::xxh3_64;
 ///     .with_max_file_size(512 * 1024)
 ///     .include_hidden(true);
 /// ```
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct ScanOptions {
     /// File extensions to include (e.g., `["py", "ts"]`).
     /// If empty, all supported language extensions are included.
@@ -65,6 +68,11 @@ pub struct ScanOptions {
     pub ignore_patterns: Option<Vec<String>>,
 
     /// Whether to follow symbolic links.
+    ///
+    /// When false, symlinked files and directories are skipped. When true,
+    /// each directory is entered once by its canonical path: links back into
+    /// the scan root or to an already-scanned target are skipped and
+    /// reported in [`ScanResult::skipped_links`]. Default: false.
     pub follow_symlinks: bool,
 
     /// Whether to compute file content hashes.
@@ -84,6 +92,42 @@ pub struct ScanOptions {
     /// Languages to include. If specified, only files with these languages are included.
     /// Default: None (all supported languages).
     pub languages: Option<Vec<String>>,
+
+    /// Whether to collapse files with identical content into one entry.
+    /// The canonical copy (first by path) stays in [`ScanResult::files`];
+    /// the rest are reported in [`ScanResult::duplicates`].
+    /// Implies `compute_hashes`. Default: false.
+    pub dedupe_identical: bool,
+
+    /// Whether to honor `.gitignore` files (root and nested), the global
+    /// gitignore and `.git/info/exclude`. Applies outside git repositories
+    /// too. `.muignore` and `ignore_patterns` apply either way.
+    /// Default: true.
+    pub respect_gitignore: bool,
+
+    /// Whether to skip files whose content looks binary or machine-generated
+    /// (a NUL byte, a line over [`MAX_LINE_LENGTH`] characters, or an average
+    /// line over [`MAX_AVERAGE_LINE_LENGTH`]). Skipped files are reported in
+    /// [`ScanResult::generated`]. Default: false.
+    pub skip_generated: bool,
+}
+
+impl Default for ScanOptions {
+    fn default() -> Self {
+        Self {
+            extensions: None,
+            ignore_patterns: None,
+            follow_symlinks: false,
+            compute_hashes: false,
+            count_lines: false,
+            include_hidden: false,
+            max_file_size: None,
+            languages: None,
+            dedupe_identical: false,
+            respect_gitignore: true,
+            skip_generated: false,
+        }
+    }
 }
 
 impl ScanOptions {
@@ -139,6 +183,24 @@ impl ScanOptions {
         self.languages = Some(languages);
         self
     }
+
+    /// Set whether to deduplicate identical-content files.
+    pub fn dedupe_identical(mut self, dedupe: bool) -> Self {
+        self.dedupe_identical = dedupe;
+        self
+    }
+
+    /// Set whether to honor `.gitignore` rules.
+    pub fn respect_gitignore(mut self, respect: bool) -> Self {
+        self.respect_gitignore = respect;
+        self
+    }
+
+    /// Set whether to skip binary and minified files.
+    pub fn skip_generated(mut self, skip: bool) -> Self {
+        self.skip_generated = skip;
+        self
+    }
 }
 
 /// Language detection from file extension.
@@ -155,12 +217,14 @@ fn detect_language(path: &Path) -> Option<&'static str> {
         "go" => Some("go"),
         "rs" => Some("rust"),
         "java" => Some("java"),
+        "dart" => Some("dart"),
+        "scala" | "sc" => Some("scala"),
         "kt" | "kts" => Some("kotlin"),
         "rb" => Some("ruby"),
         "php" => Some("php"),
         "swift" => Some("swift"),
         "c" | "h" => Some("c"),
-        "cpp" | "hpp" | "cc" => Some("cpp"),
+        "cpp" | "hpp" | "cc" | "cxx" | "hh" | "hxx" => Some("cpp"),
         "yaml" | "yml" => Some("yaml"),
         "json" => Some("json"),
         "toml" => Some("toml"),
@@ -184,6 +248,13 @@ fn is_supported_language(lang: &str) -> bool {
             | "go"
             | "rust"
             | "java"
+            | "dart"
+            | "scala"
+            | "kotlin"
+            | "php"
+            | "ruby"
+            | "c"
+            | "cpp"
             | "yaml"
             | "json"
             | "toml"
@@ -228,12 +299,59 @@ impl ScannedFile {
     }
 }
 
+/// A file whose content is identical to another scanned file.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DuplicateFile {
+    /// Relative path of the duplicate copy.
+    pub path: String,
+
+    /// Relative path of the canonical copy kept in [`ScanResult::files`].
+    pub duplicate_of: String,
+}
+
+/// A file left out of the scan because of its content.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkippedFile {
+    /// Relative path of the skipped file.
+    pub path: String,
+
+    /// Why the file was skipped (e.g. `binary`, `minified`).
+    pub reason: String,
+}
+
+/// A directory symlink left out of the scan to avoid entering its target twice.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SkippedLink {
+    /// Relative path of the symlink.
+    pub path: String,
+
+    /// Where the link points: relative to the scan root when inside it,
+    /// otherwise absolute.
+    pub target: String,
+}
+
 /// Result of scanning a directory.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ScanResult {
     /// List of discovered files.
     pub files: Vec<ScannedFile>,
 
+    /// Files skipped because their content matches a file in `files`.
+    /// Only populated when [`ScanOptions::dedupe_identical`] is set.
+    #[serde(default)]
+    pub duplicates: Vec<DuplicateFile>,
+
+    /// Files skipped because they look binary or generated, sorted by path.
+    /// Only populated when [`ScanOptions::skip_generated`] is set.
+    #[serde(default)]
+    pub generated: Vec<SkippedFile>,
+
+    /// Directory symlinks not followed because their target is inside the
+    /// scan root or was already scanned, sorted by path.
+    /// Only populated when [`ScanOptions::follow_symlinks`] is set.
+    #[serde(default)]
+    pub skipped_links: Vec<SkippedLink>,
+
     /// Number of files skipped due to ignore patterns.
     pub skipped_count: usize,
 
@@ -256,11 +374,45 @@ impl ScanResult {
     }
 }
 
+/// Lines longer than this mark a file as minified.
+pub const MAX_LINE_LENGTH: usize = 5000;
+
+/// Average line lengths above this mark a file as generated.
+pub const MAX_AVERAGE_LINE_LENGTH: usize = 300;
+
+/// Classify content that should not be parsed as source code.
+///
+/// Returns `binary` for content with a NUL byte, `minified` when a line
+/// exceeds [`MAX_LINE_LENGTH`], and `generated` when the average line exceeds
+/// [`MAX_AVERAGE_LINE_LENGTH`].
+pub fn generated_content_reason(content: &[u8]) -> Option<&'static str> {
+    if content.contains(&0) {
+        return Some("binary");
+    }
+
+    let mut lines = 0usize;
+    for line in content.split(|&b| b == b'\n') {
+        if line.len() > MAX_LINE_LENGTH {
+            return Some("minified");
+        }
+        lines += 1;
+    }
+    if content.len() / lines.max(1) > MAX_AVERAGE_LINE_LENGTH {
+        return Some("generated");
+    }
+
+    None
+}
+
 /// Compute xxHash3 hash of file content.
 fn compute_file_hash(path: &Path) -> Option<String> {
     let content = fs::read(path).ok()?;
-    let hash = xxh3_64(&content);
-    Some(format!("xxh3:{:016x}", hash))
+    Some(content_hash(&content))
+}
+
+/// The xxHash3 hash the scanner reports for `content`, as `xxh3:<hex>`.
+pub fn content_hash(content: &[u8]) -> String {
+    format!("xxh3:{:016x}", xxh3_64(content))
 }
 
 /// Count lines in a file efficiently.
@@ -317,9 +469,10 @@ pub fn scan_with_options(root_path: &str, options: ScanOptions) -> Result<ScanRe
     let mut builder = WalkBuilder::new(root);
     builder
         .hidden(!options.include_hidden) // hidden(true) = skip hidden files
-        .git_ignore(true) // Respect .gitignore
-        .git_global(true) // Respect global gitignore
-        .git_exclude(true) // Respect .git/info/exclude
+        .git_ignore(options.respect_gitignore) // Respect .gitignore
+        .git_global(options.respect_gitignore) // Respect global gitignore
+        .git_exclude(options.respect_gitignore) // Respect .git/info/exclude
+        .require_git(false) // ...even when the root is not a git checkout
         .follow_links(options.follow_symlinks)
         .add_custom_ignore_filename(".muignore"); // Support .muignore
 
@@ -338,11 +491,61 @@ pub fn scan_with_options(root_path: &str, options: ScanOptions) -> Result<ScanRe
         }
     }
 
+    // Enter each directory once. Directories inside the root are scanned at
+    // their real path, so links to them (including cycles back to an
+    // ancestor) are never followed; links leaving the root are followed the
+    // first time their target is seen.
+    let skipped_links = Arc::new(Mutex::new(Vec::new()));
+    let canonical_root = fs::canonicalize(root).unwrap_or_else(|_| root.to_path_buf());
+    if options.follow_symlinks {
+        let root = root.to_path_buf();
+        let canonical_root = canonical_root.clone();
+        let visited: Mutex<HashSet<PathBuf>> = Mutex::new(HashSet::new());
+        let skipped_links = Arc::clone(&skipped_links);
+        builder.filter_entry(move |entry| {
+            if entry.depth() == 0 || !entry.file_type().is_some_and(|ft| ft.is_dir()) {
+                return true;
+            }
+            let Ok(target) = fs::canonicalize(entry.path()) else {
+                return true;
+            };
+
+            let is_link = entry.path_is_symlink();
+            let inside_root = target.starts_with(&canonical_root);
+            let first_visit = !(is_link && inside_root)
+                && visited.lock().is_ok_and(|mut v| v.insert(target.clone()));
+            if !first_visit && is_link {
+                if let Ok(mut skipped) = skipped_links.lock() {
+                    skipped.push(SkippedLink {
+                        path: relative_path(&root, entry.path()),
+                        target: link_target(&canonical_root, &target),
+                    });
+                }
+            }
+            first_visit
+        });
+    }
+
     let walker = builder.build();
 
-    // Collect file paths first
+    // Collect file paths first. The walker reports links back to a directory
+    // it is still inside as loop errors before they reach the filter above.
     let files: Vec<_> = walker
-        .filter_map(|entry| entry.ok())
+        .filter_map(|entry| match entry {
+            Ok(entry) => Some(entry),
+            Err(e) => {
+                if let Some(child) = loop_link(&e) {
+                    let target = fs::canonicalize(child).unwrap_or_else(|_| child.to_path_buf());
+                    if let Ok(mut skipped) = skipped_links.lock() {
+                        skipped.push(SkippedLink {
+                            path: relative_path(root, child),
+                            target: link_target(&canonical_root, &target),
+                        });
+                    }
+                }
+                None
+            }
+        })
         .filter(|entry| entry.file_type().map(|ft| ft.is_file()).unwrap_or(false))
         .map(|entry| entry.into_path())
         .collect();
@@ -351,8 +554,9 @@ pub fn scan_with_options(root_path: &str, options: ScanOptions) -> Result<ScanRe
     let skipped = AtomicUsize::new(0);
     let errors = AtomicUsize::new(0);
     let result_files = Mutex::new(Vec::new());
+    let generated_files = Mutex::new(Vec::new());
     let max_size = options.max_file_size;
-    let compute_hashes = options.compute_hashes;
+    let compute_hashes = options.compute_hashes || options.dedupe_identical;
     let count_lines_flag = options.count_lines;
 
     // Process files in parallel
@@ -410,11 +614,25 @@ pub fn scan_with_options(root_path: &str, options: ScanOptions) -> Result<ScanRe
             }
         }
 
-        // Compute relative path
-        let rel_path = path
-            .strip_prefix(root)
-            .map(|p| p.to_string_lossy().to_string())
-            .unwrap_or_else(|_| path.to_string_lossy().to_string());
+        let rel_path = relative_path(root, path);
+
+        // Skip binary and minified content
+        if options.skip_generated {
+            let Ok(content) = fs::read(path) else {
+                errors.fetch_add(1, Ordering::Relaxed);
+                return;
+            };
+            if let Some(reason) = generated_content_reason(&content) {
+                skipped.fetch_add(1, Ordering::Relaxed);
+                if let Ok(mut generated) = generated_files.lock() {
+                    generated.push(SkippedFile {
+                        path: rel_path,
+                        reason: reason.to_string(),
+                    });
+                }
+                return;
+            }
+        }
 
         // Optionally compute hash
         let hash = if compute_hashes {
@@ -443,17 +661,104 @@ pub fn scan_with_options(root_path: &str, options: ScanOptions) -> Result<ScanRe
         }
     });
 
+    let mut files = result_files.into_inner().unwrap_or_default();
+    let duplicates = if options.dedupe_identical {
+        dedupe_identical_files(&mut files)
+    } else {
+        Vec::new()
+    };
+    let mut generated = generated_files.into_inner().unwrap_or_default();
+    generated.sort_by(|a, b| a.path.cmp(&b.path));
+    let mut skipped_links = skipped_links
+        .lock()
+        .map(|links| links.clone())
+        .unwrap_or_default();
+    skipped_links.sort_by(|a, b| a.path.cmp(&b.path));
     let duration = start.elapsed();
-    let files = result_files.into_inner().unwrap_or_default();
 
     Ok(ScanResult {
         files,
+        duplicates,
+        generated,
+        skipped_links,
         skipped_count: skipped.load(Ordering::Relaxed),
         error_count: errors.load(Ordering::Relaxed),
         duration_ms: duration.as_secs_f64() * 1000.0,
     })
 }
 
+/// Path of `path` relative to the scan root, or the whole path outside it.
+fn relative_path(root: &Path, path: &Path) -> String {
+    path.strip_prefix(root)
+        .map(|p| p.to_string_lossy().to_string())
+        .unwrap_or_else(|_| path.to_string_lossy().to_string())
+}
+
+/// Where a skipped link points, relative to the canonical scan root when
+/// inside it (`.` for the root itself).
+fn link_target(canonical_root: &Path, target: &Path) -> String {
+    match target.strip_prefix(canonical_root) {
+        Ok(rel) if rel.as_os_str().is_empty() => ".".to_string(),
+        Ok(rel) => rel.to_string_lossy().to_string(),
+        Err(_) => target.to_string_lossy().to_string(),
+    }
+}
+
+/// The symlink path of a walker loop error, if `err` is one.
+fn loop_link(err: &ignore::Error) -> Option<&Path> {
+    match err {
+        ignore::Error::Loop { child, .. } => Some(child),
+        ignore::Error::WithPath { err, .. } | ignore::Error::WithDepth { err, .. } => {
+            loop_link(err)
+        }
+        _ => None,
+    }
+}
+
+/// Check that an ignore pattern is a valid glob for [`ScanOptions::with_ignore_patterns`].
+///
+/// Invalid patterns are otherwise only reported as a warning during scanning.
+pub fn validate_ignore_pattern(pattern: &str) -> Result<(), String> {
+    ignore::overrides::OverrideBuilder::new(".")
+        .add(&format!("!{}", pattern))
+        .map(|_| ())
+        .map_err(|e| e.to_string())
+}
+
+/// Remove identical-content files from `files`, keeping the first copy by path.
+///
+/// Files are grouped by language and content hash, so the same bytes under
+/// two languages (e.g. `.js` and `.ts`) are still parsed separately.
+/// Files without a hash are always kept.
+fn dedupe_identical_files(files: &mut Vec<ScannedFile>) -> Vec<DuplicateFile> {
+    files.sort_by(|a, b| a.path.cmp(&b.path));
+
+    let mut canonical: HashMap<(String, String), String> = HashMap::new();
+    let mut duplicates = Vec::new();
+
+    files.retain(|file| {
+        let Some(hash) = &file.hash else {
+            return true;
+        };
+        let key = (file.language.clone(), hash.clone());
+        match canonical.get(&key) {
+            Some(original) => {
+                duplicates.push(DuplicateFile {
+                    path: file.path.clone(),
+                    duplicate_of: original.clone(),
+                });
+                false
+            }
+            None => {
+                canonical.insert(key, file.path.clone());
+                true
+            }
+        }
+    });
+
+    duplicates
+}
+
 /// Scan a directory for source files.
 ///
 /// Uses the `ignore` crate for fast, parallel traversal with gitignore support.
@@ -492,6 +797,9 @@ pub fn scan_directory(
         include_hidden: false, // Default: exclude hidden files
         max_file_size: None,
         languages: None,
+        dedupe_identical: false,
+        respect_gitignore: true,
+        skip_generated: false,
     };
     scan_with_options(root_path, options)
 }
@@ -570,6 +878,14 @@ mod tests {
         assert_eq!(detect_language(Path::new("test.tsx")), Some("tsx"));
         assert_eq!(detect_language(Path::new("test.rs")), Some("rust"));
         assert_eq!(detect_language(Path::new("test.go")), Some("go"));
+        assert_eq!(detect_language(Path::new("Job.scala")), Some("scala"));
+        assert_eq!(detect_language(Path::new("build.sc")), Some("scala"));
+        assert_eq!(detect_language(Path::new("Main.kt")), Some("kotlin"));
+        assert_eq!(detect_language(Path::new("build.gradle.kts")), Some("kotlin"));
+        assert_eq!(detect_language(Path::new("User.php")), Some("php"));
+        assert_eq!(detect_language(Path::new("widget.rb")), Some("ruby"));
+        assert_eq!(detect_language(Path::new("uart.h")), Some("c"));
+        assert_eq!(detect_language(Path::new("device.hpp")), Some("cpp"));
         assert_eq!(detect_language(Path::new("test.unknown")), None);
     }
 
@@ -578,8 +894,10 @@ mod tests {
         assert!(is_supported_language("python"));
         assert!(is_supported_language("typescript"));
         assert!(is_supported_language("rust"));
-        assert!(!is_supported_language("kotlin")); // Not in supported list
-        assert!(!is_supported_language("ruby"));
+        assert!(is_supported_language("kotlin"));
+        assert!(is_supported_language("ruby"));
+        assert!(is_supported_language("php"));
+        assert!(!is_supported_language("swift"));
     }
 
     #[test]
@@ -672,6 +990,39 @@ mod tests {
         assert!(!paths.iter().any(|p| p.contains("debug.log")));
     }
 
+    #[test]
+    fn test_scan_gitignore_outside_git_repo() {
+        let dir = create_test_dir();
+        fs::create_dir_all(dir.path().join("gen/nested")).unwrap();
+        File::create(dir.path().join("gen/models.py"))
+            .unwrap()
+            .write_all(b"class Generated: pass\n")
+            .unwrap();
+        File::create(dir.path().join("src/.gitignore"))
+            .unwrap()
+            .write_all(b"*_pb.rs\n")
+            .unwrap();
+        File::create(dir.path().join("src/api_pb.rs"))
+            .unwrap()
+            .write_all(b"fn generated() {}\n")
+            .unwrap();
+        fs::write(dir.path().join(".gitignore"), "gen/\n").unwrap();
+
+        let root = dir.path().to_str().unwrap();
+        let options = ScanOptions::new().with_ignore_patterns(vec!["utils.ts".to_string()]);
+        let result = scan_with_options(root, options.clone()).unwrap();
+        let mut paths: Vec<&str> = result.files.iter().map(|f| f.path.as_str()).collect();
+        paths.sort();
+        // Root and nested .gitignore rules compose with the extra patterns
+        assert_eq!(paths, vec!["README.md", "main.py", "src/lib.rs"]);
+
+        let result = scan_with_options(root, options.respect_gitignore(false)).unwrap();
+        let paths: Vec<&str> = result.files.iter().map(|f| f.path.as_str()).collect();
+        assert!(paths.contains(&"gen/models.py"));
+        assert!(paths.contains(&"src/api_pb.rs"));
+        assert!(!paths.contains(&"utils.ts"));
+    }
+
     #[test]
     fn test_scan_directory_muignore() {
         let dir = create_test_dir();
@@ -697,6 +1048,142 @@ mod tests {
         assert!(!paths.iter().any(|p| p.ends_with(".ts")));
     }
 
+    #[test]
+    fn test_scan_dedupe_identical_files() {
+        let dir = create_test_dir();
+        fs::create_dir_all(dir.path().join("vendor/copy")).unwrap();
+        fs::copy(
+            dir.path().join("main.py"),
+            dir.path().join("vendor/copy/main.py"),
+        )
+        .unwrap();
+
+        let options = ScanOptions::new().with_extensions(vec!["py".to_string()]);
+        let result = scan_with_options(dir.path().to_str().unwrap(), options.clone()).unwrap();
+        assert_eq!(result.files.len(), 2);
+        assert!(result.duplicates.is_empty());
+
+        let result =
+            scan_with_options(dir.path().to_str().unwrap(), options.dedupe_identical(true))
+                .unwrap();
+        assert_eq!(result.files.len(), 1);
+        assert_eq!(result.files[0].path, "main.py");
+        assert!(result.files[0].hash.is_some());
+        assert_eq!(
+            result.duplicates,
+            vec![DuplicateFile {
+                path: "vendor/copy/main.py".to_string(),
+                duplicate_of: "main.py".to_string(),
+            }]
+        );
+    }
+
+    #[test]
+    fn test_generated_content_reason() {
+        assert_eq!(generated_content_reason(b"def f():\n    pass\n"), None);
+        assert_eq!(generated_content_reason(b"\x7fELF\x00\x01"), Some("binary"));
+        let minified = format!("var a={};", "x".repeat(MAX_LINE_LENGTH));
+        assert_eq!(
+            generated_content_reason(minified.as_bytes()),
+            Some("minified")
+        );
+        let dense = format!("{}\n", "y".repeat(400)).repeat(10);
+        assert_eq!(
+            generated_content_reason(dense.as_bytes()),
+            Some("generated")
+        );
+    }
+
+    #[test]
+    fn test_scan_skips_generated_files() {
+        let dir = create_test_dir();
+        fs::write(
+            dir.path().join("bundle.ts"),
+            format!("export const a = {};\n", "1+".repeat(4000)),
+        )
+        .unwrap();
+        fs::write(dir.path().join("blob.py"), b"\x00\x01\x02").unwrap();
+
+        let root = dir.path().to_str().unwrap();
+        let result = scan_with_options(root, ScanOptions::new()).unwrap();
+        assert!(result.files.iter().any(|f| f.path == "bundle.ts"));
+        assert!(result.generated.is_empty());
+
+        let result = scan_with_options(root, ScanOptions::new().skip_generated(true)).unwrap();
+        let paths: Vec<&str> = result.files.iter().map(|f| f.path.as_str()).collect();
+        assert!(!paths.contains(&"bundle.ts"));
+        assert!(paths.contains(&"main.py"));
+        assert_eq!(
+            result.generated,
+            vec![
+                SkippedFile {
+                    path: "blob.py".to_string(),
+                    reason: "binary".to_string(),
+                },
+                SkippedFile {
+                    path: "bundle.ts".to_string(),
+                    reason: "minified".to_string(),
+                },
+            ]
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_scan_symlink_cycles() {
+        use std::os::unix::fs::symlink;
+
+        let dir = create_test_dir();
+        let external = TempDir::new().unwrap();
+        fs::write(external.path().join("shared.py"), "def shared(): pass\n").unwrap();
+        symlink(dir.path(), dir.path().join("src/loop")).unwrap();
+        symlink(dir.path().join("src"), dir.path().join("src_alias")).unwrap();
+        symlink(external.path(), dir.path().join("vendor_a")).unwrap();
+        symlink(external.path(), dir.path().join("vendor_b")).unwrap();
+        symlink(external.path(), external.path().join("self")).unwrap();
+
+        let root = dir.path().to_str().unwrap();
+        let options = ScanOptions::new().with_extensions(vec!["py".to_string(), "rs".to_string()]);
+
+        // Not following: symlinked directories are skipped without a report
+        let result = scan_with_options(root, options.clone()).unwrap();
+        let mut paths: Vec<&str> = result.files.iter().map(|f| f.path.as_str()).collect();
+        paths.sort();
+        assert_eq!(paths, vec!["main.py", "src/lib.rs"]);
+        assert!(result.skipped_links.is_empty());
+
+        // Following: every directory is scanned exactly once
+        let result = scan_with_options(root, options.follow_symlinks(true)).unwrap();
+        let mut paths: Vec<&str> = result.files.iter().map(|f| f.path.as_str()).collect();
+        paths.sort();
+        assert_eq!(paths.len(), 3, "{:?}", paths);
+        assert_eq!(paths[..2], ["main.py", "src/lib.rs"]);
+        assert!(paths[2].ends_with("/shared.py"));
+
+        let links: Vec<(&str, &str)> = result
+            .skipped_links
+            .iter()
+            .map(|l| (l.path.as_str(), l.target.as_str()))
+            .collect();
+        assert_eq!(links.len(), 4, "{:?}", links);
+        assert!(links.contains(&("src/loop", ".")));
+        assert!(links.contains(&("src_alias", "src")));
+        let followed = if paths[2].starts_with("vendor_a") {
+            "vendor_a"
+        } else {
+            "vendor_b"
+        };
+        let self_link = format!("{}/self", followed);
+        assert!(links.iter().any(|(path, _)| *path == self_link));
+    }
+
+    #[test]
+    fn test_validate_ignore_pattern() {
+        assert!(validate_ignore_pattern("vendor/").is_ok());
+        assert!(validate_ignore_pattern("*.generated.ts").is_ok());
+        assert!(validate_ignore_pattern("src/[abc").is_err());
+    }
+
     #[test]
     fn test_scan_directory_nonexistent() {
         let result = scan_directory_sync(
@@ -3,4 +3,5 @@
 pub mod patterns;
 pub mod redact;
 
+pub use patterns::SecretScanner;
 pub use redact::redact_secrets;
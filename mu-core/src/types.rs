@@ -44,6 +44,14 @@ pub struct CallSiteDef {
     pub is_method_call: bool,
     /// The receiver, e.g., "self", "user_service"
     pub receiver: Option<String>,
+    /// Number of arguments passed
+    #[serde(default)]
+    pub arg_count: usize,
+    /// Static type of each argument where the extractor can tell from the
+    /// expression alone (literals, `new Foo()`, casts), else `None`.
+    /// Always `arg_count` long.
+    #[serde(default)]
+    pub arg_types: Vec<Option<String>>,
 }
 
 impl CallSiteDef {
@@ -53,8 +61,21 @@ impl CallSiteDef {
             line,
             is_method_call,
             receiver,
+            ..Default::default()
         }
     }
+
+    /// Set the arguments from their static types, one entry per argument.
+    pub fn with_args(mut self, arg_types: Vec<Option<String>>) -> Self {
+        self.arg_count = arg_types.len();
+        self.arg_types = arg_types;
+        self
+    }
+
+    /// Set the argument count when no argument types are known.
+    pub fn with_arg_count(self, arg_count: usize) -> Self {
+        self.with_args(vec![None; arg_count])
+    }
 }
 
 /// A function or method definition.
@@ -63,6 +84,8 @@ pub struct FunctionDef {
     pub name: String,
     pub parameters: Vec<ParameterDef>,
     pub return_type: Option<String>,
+    /// Decorator expressions without the `@`, e.g. `app.get("/users")`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
     pub decorators: Vec<String>,
     pub is_async: bool,
     pub is_method: bool,
@@ -73,8 +96,18 @@ pub struct FunctionDef {
     pub body_complexity: u32,
     pub body_source: Option<String>,
     pub call_sites: Vec<CallSiteDef>,
+    /// Names destructured from the first parameter, e.g. the props of
+    /// `function Button({ label, onClick }: ButtonProps)`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub props: Vec<String>,
     pub start_line: u32,
     pub end_line: u32,
+    /// 1-indexed byte column of the first character
+    #[serde(default)]
+    pub start_column: u32,
+    /// 1-indexed byte column just past the last character
+    #[serde(default)]
+    pub end_column: u32,
 }
 
 impl FunctionDef {
@@ -95,6 +128,8 @@ impl FunctionDef {
         call_sites: Vec<CallSiteDef>,
         start_line: u32,
         end_line: u32,
+        start_column: u32,
+        end_column: u32,
     ) -> Self {
         Self {
             name,
@@ -110,8 +145,11 @@ impl FunctionDef {
             body_complexity,
             body_source,
             call_sites,
+            props: Vec::new(),
             start_line,
             end_line,
+            start_column,
+            end_column,
         }
     }
 }
@@ -127,6 +165,12 @@ pub struct ClassDef {
     pub docstring: Option<String>,
     pub start_line: u32,
     pub end_line: u32,
+    /// 1-indexed byte column of the first character
+    #[serde(default)]
+    pub start_column: u32,
+    /// 1-indexed byte column just past the last character
+    #[serde(default)]
+    pub end_column: u32,
     pub referenced_types: Vec<String>,
 }
 
@@ -141,6 +185,8 @@ impl ClassDef {
         docstring: Option<String>,
         start_line: u32,
         end_line: u32,
+        start_column: u32,
+        end_column: u32,
         referenced_types: Vec<String>,
     ) -> Self {
         Self {
@@ -152,9 +198,36 @@ impl ClassDef {
             docstring,
             start_line,
             end_line,
+            start_column,
+            end_column,
             referenced_types,
         }
     }
+
+    /// Whether this is an interface-like contract rather than a class.
+    ///
+    /// Covers interfaces (TypeScript, Java, C#, Go, Kotlin, PHP) and traits
+    /// in Rust and Scala. PHP traits are mixins of shared code, so they stay
+    /// classes.
+    pub fn is_interface(&self, language: &str) -> bool {
+        self.decorators.iter().any(|d| match d.as_str() {
+            "interface" => true,
+            "trait" => language != "php",
+            _ => false,
+        })
+    }
+}
+
+/// An enum's variants.
+///
+/// The enum itself is also in [`ModuleDef::classes`], which keeps its
+/// methods, bases and span; this records which class is an enum and which
+/// variants it has.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnumDef {
+    pub name: String,
+    /// Variant names in declaration order
+    pub variants: Vec<String>,
 }
 
 /// An import statement.
@@ -167,7 +240,17 @@ pub struct ImportDef {
     pub is_dynamic: bool,
     pub dynamic_pattern: Option<String>,
     pub dynamic_source: Option<String>,
-    pub line_number: u32,
+    /// Span of the whole statement (or call, for dynamic imports). Lines are
+    /// 1-indexed; columns are 1-indexed bytes, ending just past the last
+    /// character.
+    #[serde(default)]
+    pub start_line: u32,
+    #[serde(default)]
+    pub start_column: u32,
+    #[serde(default)]
+    pub end_line: u32,
+    #[serde(default)]
+    pub end_column: u32,
 }
 
 impl ImportDef {
@@ -180,7 +263,10 @@ impl ImportDef {
         is_dynamic: bool,
         dynamic_pattern: Option<String>,
         dynamic_source: Option<String>,
-        line_number: u32,
+        start_line: u32,
+        start_column: u32,
+        end_line: u32,
+        end_column: u32,
     ) -> Self {
         Self {
             module,
@@ -190,7 +276,10 @@ impl ImportDef {
             is_dynamic,
             dynamic_pattern,
             dynamic_source,
-            line_number,
+            start_line,
+            start_column,
+            end_line,
+            end_column,
         }
     }
 }
@@ -203,11 +292,18 @@ pub struct ModuleDef {
     pub language: String,
     pub imports: Vec<ImportDef>,
     pub classes: Vec<ClassDef>,
+    /// Enums among `classes`, with their variants
+    #[serde(default)]
+    pub enums: Vec<EnumDef>,
     pub functions: Vec<FunctionDef>,
     pub module_docstring: Option<String>,
     pub total_lines: u32,
     /// Namespace declaration (for C#, Java, Go packages)
     pub namespace: Option<String>,
+    /// Syntax errors found while extracting; moved into
+    /// [`ParseResult::errors`] by the parser dispatcher.
+    #[serde(skip)]
+    pub syntax_errors: Vec<ParseError>,
 }
 
 impl ModuleDef {
@@ -218,6 +314,7 @@ impl ModuleDef {
         language: String,
         imports: Vec<ImportDef>,
         classes: Vec<ClassDef>,
+        enums: Vec<EnumDef>,
         functions: Vec<FunctionDef>,
         module_docstring: Option<String>,
         total_lines: u32,
@@ -229,12 +326,28 @@ impl ModuleDef {
             language,
             imports,
             classes,
+            enums,
             functions,
             module_docstring,
             total_lines,
             namespace,
+            syntax_errors: Vec::new(),
         }
     }
+
+    /// Add an enum parsed as a class whose attributes are its variants.
+    pub fn push_enum(&mut self, class_def: ClassDef) {
+        self.enums.push(EnumDef {
+            name: class_def.name.clone(),
+            variants: class_def.attributes.clone(),
+        });
+        self.classes.push(class_def);
+    }
+
+    /// The variants of the enum named `name`, if it is one.
+    pub fn enum_named(&self, name: &str) -> Option<&EnumDef> {
+        self.enums.iter().find(|e| e.name == name)
+    }
 }
 
 /// File information for parsing.
@@ -255,12 +368,31 @@ impl FileInfo {
     }
 }
 
+/// A syntax error tree-sitter recovered from.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ParseError {
+    /// Line of the error (1-indexed)
+    pub line: u32,
+    /// Column of the error (1-indexed, in bytes)
+    pub column: u32,
+    pub message: String,
+}
+
 /// Result of parsing a file.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ParseResult {
     pub success: bool,
     pub module: Option<ModuleDef>,
     pub error: Option<String>,
+    /// Problems that didn't stop the parse, such as source that wasn't
+    /// valid UTF-8 and had to be decoded another way.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub warnings: Vec<String>,
+    /// Syntax errors in the source. Parsing is error-tolerant, so a
+    /// successful result can still have these, with `module` holding
+    /// whatever was extracted around them.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<ParseError>,
 }
 
 impl ParseResult {
@@ -269,6 +401,8 @@ impl ParseResult {
             success,
             module,
             error,
+            warnings: Vec::new(),
+            errors: Vec::new(),
         }
     }
 
@@ -278,6 +412,8 @@ impl ParseResult {
             success: true,
             module: Some(module),
             error: None,
+            warnings: Vec::new(),
+            errors: Vec::new(),
         }
     }
 
@@ -287,10 +423,24 @@ impl ParseResult {
             success: false,
             module: None,
             error: Some(error),
+            warnings: Vec::new(),
+            errors: Vec::new(),
         }
     }
 }
 
+/// Cost and size of parsing one file.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ParseStats {
+    /// Wall-clock time spent parsing and extracting
+    pub parse_duration: std::time::Duration,
+    /// Entities extracted: the module, its classes, methods and functions
+    /// (0 when parsing failed)
+    pub node_count: usize,
+    /// Size of the source in bytes
+    pub byte_count: usize,
+}
+
 /// Configuration for export operations.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct ExportConfig {
@@ -329,6 +479,23 @@ impl RedactedSecret {
     }
 }
 
+/// How damaging a leaked secret is, from least to most.
+#[derive(
+    Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    /// Test keys and high-entropy literals that may not be secrets at all
+    Low,
+    /// Generic credential assignments and custom patterns
+    #[default]
+    Medium,
+    /// Live, scoped service tokens and connection strings with passwords
+    High,
+    /// Private keys and credentials that grant broad account access
+    Critical,
+}
+
 /// A detected secret in source code.
 #[derive(Clone, Debug, Default, Serialize, Deserialize)]
 pub struct SecretMatch {
@@ -337,11 +504,13 @@ pub struct SecretMatch {
     pub end: usize,
     pub line: u32,
     pub column: u32,
+    pub severity: Severity,
 }
 
 impl SecretMatch {
     pub fn new(pattern_name: String, start: usize, end: usize, line: u32, column: u32) -> Self {
         Self {
+            severity: crate::security::patterns::severity(&pattern_name),
             pattern_name,
             start,
             end,
@@ -370,6 +539,20 @@ mod tests {
         assert_eq!(func.body_complexity, 0);
     }
 
+    #[test]
+    fn test_class_def_is_interface() {
+        let with = |decorator: &str| ClassDef {
+            decorators: vec!["pub".to_string(), decorator.to_string()],
+            ..Default::default()
+        };
+        assert!(with("interface").is_interface("java"));
+        assert!(with("trait").is_interface("rust"));
+        assert!(with("trait").is_interface("scala"));
+        assert!(!with("trait").is_interface("php"));
+        assert!(!with("struct").is_interface("rust"));
+        assert!(!with("impl:Display").is_interface("rust"));
+    }
+
     #[test]
     fn test_module_def_serialization() {
         let module = ModuleDef {
@@ -382,4 +565,18 @@ mod tests {
         let json = serde_json::to_string(&module).unwrap();
         assert!(json.contains("\"name\":\"test\""));
     }
+
+    #[test]
+    fn test_function_def_decorators_optional_in_json() {
+        let func = FunctionDef {
+            name: "handler".to_string(),
+            ..Default::default()
+        };
+        let json = serde_json::to_string(&func).unwrap();
+        assert!(!json.contains("decorators"));
+
+        // JSON written before the field existed still loads
+        let parsed: FunctionDef = serde_json::from_str(&json).unwrap();
+        assert!(parsed.decorators.is_empty());
+    }
 }
@@ -46,6 +46,11 @@ impl Edge {
         Self::new(child_class, parent_class, EdgeType::Inherits)
     }
 
+    /// Create an IMPLEMENTS edge (type satisfies an interface).
+    pub fn implements(implementor: &str, interface: &str) -> Self {
+        Self::new(implementor, interface, EdgeType::Implements)
+    }
+
     /// Create a CALLS edge (function calls another function).
     pub fn calls(caller: &str, callee: &str) -> Self {
         Self::new(caller, callee, EdgeType::Calls)
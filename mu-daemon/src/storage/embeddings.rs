@@ -1,6 +1,6 @@
 //! Embedding storage types and utilities.
 
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 
 /// Result of a vector similarity search.
 #[derive(Debug, Clone, Serialize)]
@@ -19,6 +19,35 @@ pub struct VectorSearchResult {
     pub qualified_name: Option<String>,
 }
 
+/// Which embedding index a vector lives in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum EmbeddingSpace {
+    /// Vectors of a node's identifiers and structure.
+    #[default]
+    Code,
+    /// Vectors of a node's docstring. Nodes without a docstring are absent.
+    Docs,
+}
+
+impl EmbeddingSpace {
+    /// Table holding this space's vectors.
+    pub fn table(self) -> &'static str {
+        match self {
+            EmbeddingSpace::Code => "embeddings",
+            EmbeddingSpace::Docs => "doc_embeddings",
+        }
+    }
+
+    /// Metadata key recording the dimension of this space's vectors.
+    pub fn dimension_key(self) -> &'static str {
+        match self {
+            EmbeddingSpace::Code => "embeddings_dimension",
+            EmbeddingSpace::Docs => "doc_embeddings_dimension",
+        }
+    }
+}
+
 /// Statistics about embeddings coverage.
 #[derive(Debug, Clone, Serialize)]
 pub struct EmbeddingStats {
@@ -32,6 +61,50 @@ pub struct EmbeddingStats {
     pub coverage_percent: f32,
 }
 
+/// Similarity distribution of random node pairs in a codebase.
+///
+/// Random pairs are mostly unrelated, so their percentiles give
+/// corpus-specific cutoffs for "how similar is similar".
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SimilarityCalibration {
+    /// Number of pairs sampled
+    pub sample_size: usize,
+    /// Median similarity of random pairs
+    pub p50: f32,
+    /// 90th percentile similarity of random pairs
+    pub p90: f32,
+    /// 99th percentile similarity of random pairs
+    pub p99: f32,
+}
+
+impl SimilarityCalibration {
+    /// Metadata key the calibration is stored under.
+    pub const METADATA_KEY: &'static str = "similarity_calibration";
+
+    /// Build a calibration from sampled pair similarities.
+    ///
+    /// Returns `None` if there are no samples.
+    pub fn from_samples(mut samples: Vec<f32>) -> Option<Self> {
+        if samples.is_empty() {
+            return None;
+        }
+        samples.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        // Nearest-rank percentile
+        let percentile = |p: f32| {
+            let rank = ((p / 100.0) * samples.len() as f32).ceil() as usize;
+            samples[rank.clamp(1, samples.len()) - 1]
+        };
+
+        Some(Self {
+            sample_size: samples.len(),
+            p50: percentile(50.0),
+            p90: percentile(90.0),
+            p99: percentile(99.0),
+        })
+    }
+}
+
 /// Compute cosine similarity between two vectors.
 /// Assumes query_magnitude is pre-computed for efficiency.
 pub fn cosine_similarity(query: &[f32], stored: &[f32], query_magnitude: f32) -> f32 {
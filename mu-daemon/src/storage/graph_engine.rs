@@ -2,6 +2,9 @@
 
 use std::collections::HashMap;
 
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
 /// Graph engine wrapper for in-memory graph operations.
 pub struct GraphEngine {
     nodes: Vec<String>,
@@ -56,6 +59,53 @@ impl GraphEngine {
     pub fn get_edges(&self) -> &[(String, String, String)] {
         &self.edges
     }
+
+    /// Load a graph from a JSON snapshot, without a database.
+    ///
+    /// The format is the one [`to_json`](Self::to_json) writes:
+    /// `{"nodes": ["mod:a.py", ...], "edges": [{"source", "target", "type"}, ...]}`.
+    pub fn from_json(json: &str) -> Result<Self> {
+        let snapshot: Snapshot = serde_json::from_str(json).context("Invalid graph snapshot")?;
+        let edges = snapshot
+            .edges
+            .into_iter()
+            .map(|e| (e.source, e.target, e.edge_type))
+            .collect();
+        Ok(Self::from_data(snapshot.nodes, edges))
+    }
+
+    /// Serialize the node and edge lists as a JSON snapshot.
+    pub fn to_json(&self) -> String {
+        let snapshot = Snapshot {
+            nodes: self.nodes.clone(),
+            edges: self
+                .edges
+                .iter()
+                .map(|(source, target, edge_type)| SnapshotEdge {
+                    source: source.clone(),
+                    target: target.clone(),
+                    edge_type: edge_type.clone(),
+                })
+                .collect(),
+        };
+        serde_json::to_string_pretty(&snapshot).expect("graph snapshot serializes")
+    }
+}
+
+/// On-disk form of a [`GraphEngine`].
+#[derive(Serialize, Deserialize)]
+struct Snapshot {
+    nodes: Vec<String>,
+    #[serde(default)]
+    edges: Vec<SnapshotEdge>,
+}
+
+#[derive(Serialize, Deserialize)]
+struct SnapshotEdge {
+    source: String,
+    target: String,
+    #[serde(rename = "type")]
+    edge_type: String,
 }
 
 impl Default for GraphEngine {
@@ -63,3 +113,37 @@ impl Default for GraphEngine {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_round_trip() {
+        let json = r#"{
+            "nodes": ["mod:a.py", "mod:b.py", "fn:b.py:run"],
+            "edges": [
+                {"source": "mod:a.py", "target": "mod:b.py", "type": "imports"},
+                {"source": "mod:b.py", "target": "fn:b.py:run", "type": "contains"}
+            ]
+        }"#;
+        let graph = GraphEngine::from_json(json).unwrap();
+        assert_eq!(graph.node_count(), 3);
+        assert!(graph.has_node("fn:b.py:run"));
+        assert_eq!(
+            graph.get_edges()[0],
+            (
+                "mod:a.py".to_string(),
+                "mod:b.py".to_string(),
+                "imports".to_string()
+            )
+        );
+
+        let reloaded = GraphEngine::from_json(&graph.to_json()).unwrap();
+        assert_eq!(reloaded.get_nodes(), graph.get_nodes());
+        assert_eq!(reloaded.get_edges(), graph.get_edges());
+
+        assert!(GraphEngine::from_json(r#"{"nodes": ["a"]}"#).is_ok());
+        assert!(GraphEngine::from_json("{\"edges\": []}").is_err());
+    }
+}
@@ -5,17 +5,25 @@
 //! - Node and edge CRUD operations
 //! - Graph loading into petgraph
 //! - Embedding storage and vector search
+//! - BM25 keyword search and hybrid (keyword + vector) search
+//! - Node source retrieval from disk
+//! - A `GraphStore` trait for backend-neutral graph access
 
 mod edges;
 mod embeddings;
 mod graph_engine;
 mod mubase;
 mod nodes;
+mod ranking;
 mod schema;
+mod source;
+mod store;
 
 pub use edges::Edge;
-pub use embeddings::{EmbeddingStats, VectorSearchResult};
+pub use embeddings::{EmbeddingSpace, EmbeddingStats, SimilarityCalibration, VectorSearchResult};
 pub use graph_engine::GraphEngine;
-pub use mubase::{AccessMode, MUbase, QueryResult};
+pub use mubase::{AccessMode, GraphStats, MUbase, QueryResult};
 pub use nodes::Node;
 pub use schema::{EdgeType, NodeType};
+pub use source::NodeSource;
+pub use store::GraphStore;
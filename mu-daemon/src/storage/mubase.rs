@@ -3,7 +3,7 @@
 use anyhow::{Context, Result};
 use duckdb::{params, Config, Connection};
 use std::collections::HashMap;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::{Arc, Mutex};
 
 /// Database access mode for concurrent access control.
@@ -17,20 +17,33 @@ pub enum AccessMode {
 }
 
 use super::edges::Edge;
-use super::embeddings::{cosine_similarity, EmbeddingStats, VectorSearchResult};
+use super::embeddings::{
+    cosine_similarity, EmbeddingSpace, EmbeddingStats, SimilarityCalibration, VectorSearchResult,
+};
 use super::graph_engine::GraphEngine;
 use super::nodes::Node;
+use super::ranking;
 use super::schema::{NodeType, SCHEMA_SQL, SCHEMA_VERSION};
+use super::source::{slice_lines, NodeSource};
+
+/// Candidates each ranking contributes to hybrid search, per requested result.
+const HYBRID_CANDIDATE_FACTOR: usize = 4;
+
+/// Fewest candidates each ranking contributes to hybrid search.
+const HYBRID_MIN_CANDIDATES: usize = 50;
 
 /// MUbase - DuckDB-based storage for code graphs.
 pub struct MUbase {
     conn: Arc<Mutex<Connection>>,
-    /// Path to the database file (stored for potential future use in queries/debugging).
-    #[allow(dead_code)]
-    path: std::path::PathBuf,
+    /// Root directory of the indexed project.
+    root: PathBuf,
 }
 
 impl MUbase {
+    /// Metadata key recording the project root, for databases stored
+    /// outside the project tree.
+    pub const PROJECT_ROOT_KEY: &'static str = "project_root";
+
     /// Open or create a MUbase database in read-write mode.
     pub fn open(path: impl AsRef<Path>) -> Result<Self> {
         Self::open_with_mode(path, AccessMode::ReadWrite)
@@ -65,9 +78,9 @@ impl MUbase {
             }
         };
 
-        let mubase = Self {
+        let mut mubase = Self {
             conn: Arc::new(Mutex::new(conn)),
-            path: path.to_path_buf(),
+            root: Self::default_project_root(path),
         };
 
         // Only initialize schema in read-write mode
@@ -75,6 +88,11 @@ impl MUbase {
             mubase.init_schema()?;
         }
 
+        // A missing metadata table (e.g. read-only on a foreign file) keeps the default
+        if let Ok(Some(root)) = mubase.get_metadata(Self::PROJECT_ROOT_KEY) {
+            mubase.root = PathBuf::from(root);
+        }
+
         Ok(mubase)
     }
 
@@ -132,12 +150,6 @@ impl MUbase {
         Ok(())
     }
 
-    /// Clear all data from the database (no-op since insert functions handle this).
-    pub fn clear(&self) -> Result<()> {
-        // Clearing is now handled by insert_nodes and insert_edges
-        Ok(())
-    }
-
     /// Insert a node into the database.
     pub fn insert_node(&self, node: &Node) -> Result<()> {
         let conn = self.acquire_conn()?;
@@ -170,6 +182,12 @@ impl MUbase {
 
     /// Insert multiple nodes in a batch using an appender for better performance.
     pub fn insert_nodes(&self, nodes: &[Node]) -> Result<()> {
+        let conn = self.acquire_conn()?;
+        Self::write_nodes(&conn, nodes)
+    }
+
+    /// Replace the nodes table contents on the given connection.
+    fn write_nodes(conn: &Connection, nodes: &[Node]) -> Result<()> {
         // Deduplicate by ID (keep last occurrence)
         let mut unique_nodes: std::collections::HashMap<&str, &Node> =
             std::collections::HashMap::new();
@@ -184,8 +202,6 @@ impl MUbase {
             nodes.len()
         );
 
-        let conn = self.acquire_conn()?;
-
         // First clear the table
         conn.execute("DELETE FROM nodes", [])?;
 
@@ -251,6 +267,12 @@ impl MUbase {
 
     /// Insert multiple edges in a batch using an appender for better performance.
     pub fn insert_edges(&self, edges: &[Edge]) -> Result<()> {
+        let conn = self.acquire_conn()?;
+        Self::write_edges(&conn, edges)
+    }
+
+    /// Replace the edges table contents on the given connection.
+    fn write_edges(conn: &Connection, edges: &[Edge]) -> Result<()> {
         // Count by type before dedup
         let mut type_counts: std::collections::HashMap<&str, usize> =
             std::collections::HashMap::new();
@@ -283,8 +305,6 @@ impl MUbase {
             edges.len()
         );
 
-        let conn = self.acquire_conn()?;
-
         // First clear the table
         conn.execute("DELETE FROM edges", [])?;
 
@@ -318,6 +338,29 @@ impl MUbase {
         Ok(())
     }
 
+    /// Replace the whole graph (nodes and edges) in a single transaction.
+    ///
+    /// `before_commit` runs after all rows are written but before the
+    /// transaction commits. If it (or any write) fails, the transaction is
+    /// rolled back and the previously stored graph is left untouched. Bootstrap
+    /// uses this hook to abort cleanly when the user presses Ctrl-C.
+    pub fn replace_graph<F>(&self, nodes: &[Node], edges: &[Edge], before_commit: F) -> Result<()>
+    where
+        F: FnOnce() -> Result<()>,
+    {
+        let mut conn = self.acquire_conn()?;
+        let tx = conn
+            .transaction()
+            .context("Failed to begin graph transaction")?;
+
+        Self::write_nodes(&tx, nodes)?;
+        Self::write_edges(&tx, edges)?;
+        before_commit()?;
+
+        tx.commit().context("Failed to commit graph transaction")?;
+        Ok(())
+    }
+
     /// Get a node by ID.
     pub fn get_node(&self, id: &str) -> Result<Option<Node>> {
         let conn = self.acquire_conn()?;
@@ -410,11 +453,56 @@ impl MUbase {
         Ok(nodes)
     }
 
+    /// Get all nodes of a specific type in a file, ordered by start line.
+    pub fn get_nodes_in_file(&self, file_path: &str, node_type: NodeType) -> Result<Vec<Node>> {
+        let conn = self.acquire_conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, type, name, qualified_name, file_path, line_start, line_end, properties, complexity
+             FROM nodes WHERE file_path = ? AND type = ?
+             ORDER BY line_start, id",
+        )?;
+
+        let mut rows = stmt.query(params![file_path, node_type.as_str()])?;
+        let mut nodes = Vec::new();
+
+        while let Some(row) = rows.next()? {
+            let node_type_str: String = row.get(1)?;
+            let properties_str: Option<String> = row.get(7)?;
+
+            nodes.push(Node {
+                id: row.get(0)?,
+                node_type: NodeType::parse(&node_type_str).unwrap_or(NodeType::Module),
+                name: row.get(2)?,
+                qualified_name: row.get(3)?,
+                file_path: row.get(4)?,
+                line_start: row.get(5)?,
+                line_end: row.get(6)?,
+                properties: properties_str.and_then(|s| serde_json::from_str(&s).ok()),
+                complexity: row.get(8)?,
+            });
+        }
+
+        Ok(nodes)
+    }
+
     /// Delete nodes for a specific file (for incremental updates).
+    ///
+    /// Edges touching those nodes and their embeddings are removed too.
     pub fn delete_nodes_for_file(&self, file_path: &str) -> Result<usize> {
         let conn = self.acquire_conn()?;
 
-        // First delete edges referencing these nodes
+        for space in [EmbeddingSpace::Code, EmbeddingSpace::Docs] {
+            conn.execute(
+                &format!(
+                    "DELETE FROM {} WHERE node_id IN (SELECT id FROM nodes WHERE file_path = ?)",
+                    space.table()
+                ),
+                params![file_path],
+            )
+            .with_context(|| format!("Failed to delete embeddings for file: {}", file_path))?;
+        }
+
+        // Then delete edges referencing these nodes
         conn.execute(
             "DELETE FROM edges WHERE source_id IN (SELECT id FROM nodes WHERE file_path = ?)
              OR target_id IN (SELECT id FROM nodes WHERE file_path = ?)",
@@ -430,6 +518,104 @@ impl MUbase {
         Ok(deleted)
     }
 
+    /// Distinct file paths that have nodes in the graph.
+    pub fn indexed_files(&self) -> Result<Vec<String>> {
+        let conn = self.acquire_conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT DISTINCT file_path FROM nodes WHERE file_path IS NOT NULL ORDER BY file_path",
+        )?;
+        let mut rows = stmt.query([])?;
+        let mut files = Vec::new();
+
+        while let Some(row) = rows.next()? {
+            files.push(row.get(0)?);
+        }
+
+        Ok(files)
+    }
+
+    /// Root directory of the project this database indexes.
+    ///
+    /// The root recorded with [`set_project_root`](Self::set_project_root)
+    /// if any, otherwise derived from the database path.
+    pub fn project_root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Record the project root, so a database stored outside the project
+    /// tree can still resolve relative file paths.
+    pub fn set_project_root(&mut self, root: &Path) -> Result<()> {
+        self.set_metadata(Self::PROJECT_ROOT_KEY, &root.to_string_lossy())?;
+        self.root = root.to_path_buf();
+        Ok(())
+    }
+
+    /// Project root implied by a database path: `.mu/mubase` or legacy `.mubase`.
+    pub fn default_project_root(db_path: &Path) -> PathBuf {
+        let parent = db_path.parent().unwrap_or_else(|| Path::new("."));
+        if parent.file_name().and_then(|s| s.to_str()) == Some(".mu") {
+            parent.parent().unwrap_or(parent).to_path_buf()
+        } else {
+            parent.to_path_buf()
+        }
+    }
+
+    /// Read a node's source from disk with `context_lines` lines of leading context.
+    ///
+    /// The header is the signature line of the enclosing class, if any.
+    /// Returns `None` if the node has no location or its file no longer exists.
+    /// If the file changed since indexing and the stored line range no longer
+    /// fits, the range is clamped and the result is flagged as stale.
+    pub fn node_source(&self, node: &Node, context_lines: usize) -> Result<Option<NodeSource>> {
+        let (Some(file_path), Some(line_start)) = (node.file_path.as_deref(), node.line_start)
+        else {
+            return Ok(None);
+        };
+
+        let full_path = self.project_root().join(file_path);
+        let content = match std::fs::read_to_string(&full_path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(e)
+                    .with_context(|| format!("Failed to read source file: {:?}", full_path))
+            }
+        };
+
+        let (context, body, start_line, stale) =
+            slice_lines(&content, line_start, node.line_end, context_lines);
+
+        let header = self
+            .enclosing_class_line(&node.id)?
+            .and_then(|line| content.lines().nth(line.saturating_sub(1) as usize))
+            .map(|line| line.trim_end().to_string());
+
+        Ok(Some(NodeSource {
+            header,
+            context,
+            body,
+            start_line,
+            stale,
+        }))
+    }
+
+    /// Start line of the class containing a node, if any.
+    fn enclosing_class_line(&self, node_id: &str) -> Result<Option<u32>> {
+        let conn = self.acquire_conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT n.line_start FROM edges e
+             JOIN nodes n ON n.id = e.source_id
+             WHERE e.target_id = ? AND e.type = 'contains' AND n.type = 'class'
+             LIMIT 1",
+        )?;
+
+        let mut rows = stmt.query(params![node_id])?;
+        match rows.next()? {
+            Some(row) => Ok(row.get(0)?),
+            None => Ok(None),
+        }
+    }
+
     /// Execute a raw SQL query and return results.
     pub fn query(&self, sql: &str) -> Result<QueryResult> {
         self.query_with_params(sql, &[])
@@ -444,6 +630,42 @@ impl MUbase {
         sql: &str,
         params: &[&dyn duckdb::ToSql],
     ) -> Result<QueryResult> {
+        // Collect rows and extract column information
+        let mut rows_data: Vec<Vec<serde_json::Value>> = Vec::new();
+        let mut columns: Vec<String> = Vec::new();
+
+        self.query_each(sql, params, |row_columns, row| {
+            if columns.is_empty() {
+                columns = row_columns.to_vec();
+            }
+            rows_data.push(row);
+            Ok(())
+        })?;
+
+        // For empty result sets, columns will be empty which is acceptable
+        // The caller can use DESCRIBE or other methods to get schema info if needed
+
+        Ok(QueryResult {
+            columns,
+            rows: rows_data,
+        })
+    }
+
+    /// Execute a SQL query, handing each row to `on_row` as DuckDB yields it.
+    ///
+    /// Unlike [`query_with_params`](Self::query_with_params) nothing is
+    /// collected, so large results can be streamed (e.g. as NDJSON) without
+    /// holding them in memory. `on_row` receives the column names and the
+    /// row's values; returning an error stops the query and is passed on.
+    pub fn query_each<F>(
+        &self,
+        sql: &str,
+        params: &[&dyn duckdb::ToSql],
+        mut on_row: F,
+    ) -> Result<()>
+    where
+        F: FnMut(&[String], Vec<serde_json::Value>) -> Result<()>,
+    {
         let conn = self.acquire_conn()?;
         let mut stmt = conn
             .prepare(sql)
@@ -452,8 +674,6 @@ impl MUbase {
         // Execute the query with parameters
         let mut rows = stmt.query(params).context("Failed to execute query")?;
 
-        // Collect rows and extract column information
-        let mut rows_data: Vec<Vec<serde_json::Value>> = Vec::new();
         let mut columns: Vec<String> = Vec::new();
         let mut column_count = 0;
 
@@ -486,16 +706,110 @@ impl MUbase {
                 };
                 row_data.push(value);
             }
-            rows_data.push(row_data);
+            on_row(&columns, row_data)?;
         }
 
-        // For empty result sets, columns will be empty which is acceptable
-        // The caller can use DESCRIBE or other methods to get schema info if needed
+        Ok(())
+    }
 
-        Ok(QueryResult {
-            columns,
-            rows: rows_data,
-        })
+    /// Export the whole database to `dest` with DuckDB's `EXPORT DATABASE`.
+    ///
+    /// The backup is a directory holding the schema (tables and indexes) and
+    /// one data file per table, so it covers the graph, embeddings, file
+    /// hashes and metadata. `dest` must not exist yet or be empty.
+    pub fn backup(&self, dest: &Path) -> Result<()> {
+        if dest.exists() && std::fs::read_dir(dest)?.next().is_some() {
+            anyhow::bail!("Backup directory is not empty: {}", dest.display());
+        }
+
+        let conn = self.acquire_conn()?;
+        conn.execute_batch(&format!("EXPORT DATABASE {}", sql_string_literal(dest)))
+            .with_context(|| format!("Failed to export database to {}", dest.display()))?;
+        Ok(())
+    }
+
+    /// Replace the database contents with a backup made by [`backup`](Self::backup).
+    ///
+    /// Existing tables are dropped and the backup imported in one
+    /// transaction, so a failed import leaves the current data in place.
+    pub fn restore(&self, src: &Path) -> Result<()> {
+        for file in ["schema.sql", "load.sql"] {
+            if !src.join(file).is_file() {
+                anyhow::bail!("Not a MU backup (missing {}): {}", file, src.display());
+            }
+        }
+
+        let mut conn = self.acquire_conn()?;
+        let tx = conn
+            .transaction()
+            .context("Failed to begin restore transaction")?;
+
+        let tables: Vec<String> = {
+            let mut stmt = tx.prepare(
+                "SELECT table_name FROM information_schema.tables WHERE table_schema = 'main'",
+            )?;
+            let rows = stmt.query_map([], |row| row.get(0))?;
+            rows.collect::<std::result::Result<_, _>>()?
+        };
+        for table in &tables {
+            tx.execute_batch(&format!("DROP TABLE \"{}\"", table.replace('"', "\"\"")))
+                .with_context(|| format!("Failed to drop table {}", table))?;
+        }
+
+        tx.execute_batch(&format!("IMPORT DATABASE {}", sql_string_literal(src)))
+            .with_context(|| format!("Failed to import database from {}", src.display()))?;
+        tx.commit()
+            .context("Failed to commit restore transaction")?;
+        Ok(())
+    }
+
+    /// Reclaim unused space by rewriting the database file.
+    ///
+    /// DuckDB does not shrink its file when rows are deleted, so after many
+    /// incremental updates the file can be far larger than the graph. This
+    /// checkpoints, copies every table into a fresh file next to the
+    /// original and swaps it in. Returns the file size in bytes before and
+    /// after.
+    pub fn compact(&self) -> Result<(u64, u64)> {
+        let mut conn = self.acquire_conn()?;
+        conn.execute_batch("CHECKPOINT")
+            .context("Failed to checkpoint database")?;
+
+        let (name, path): (String, Option<String>) = conn.query_row(
+            "SELECT database_name, path FROM duckdb_databases() WHERE database_name = current_database()",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        let Some(path) = path.map(PathBuf::from) else {
+            anyhow::bail!("Cannot compact an in-memory database");
+        };
+        let before = std::fs::metadata(&path)?.len();
+
+        let tmp = path.with_extension("compact.tmp");
+        if tmp.exists() {
+            std::fs::remove_file(&tmp)?;
+        }
+        conn.execute_batch(&format!(
+            "ATTACH {} AS mu_compact; COPY FROM DATABASE \"{}\" TO mu_compact; DETACH mu_compact",
+            sql_string_literal(&tmp),
+            name.replace('"', "\"\"")
+        ))
+        .context("Failed to copy database for compaction")?;
+
+        // The original file has to be closed before it can be replaced
+        let old = std::mem::replace(&mut *conn, Connection::open_in_memory()?);
+        old.close().map_err(|(_, e)| e)?;
+        let swapped = std::fs::rename(&tmp, &path);
+        *conn = Connection::open(&path)
+            .with_context(|| format!("Failed to reopen database: {:?}", path))?;
+        swapped.with_context(|| format!("Failed to replace {:?} with compacted copy", path))?;
+
+        // Recreate indexes, which are not part of COPY FROM DATABASE
+        conn.execute_batch(SCHEMA_SQL)
+            .context("Failed to recreate indexes")?;
+        conn.execute_batch("CHECKPOINT")?;
+
+        Ok((before, std::fs::metadata(&path)?.len()))
     }
 
     /// Get graph statistics.
@@ -557,16 +871,116 @@ impl MUbase {
 
     /// Check if the embeddings table has any data.
     pub fn has_embeddings(&self) -> Result<bool> {
+        self.has_embeddings_in(EmbeddingSpace::Code)
+    }
+
+    /// Check if the given embedding space has any data.
+    ///
+    /// Databases created before the space existed (and opened read-only, so
+    /// the schema was never upgraded) report `false`.
+    pub fn has_embeddings_in(&self, space: EmbeddingSpace) -> Result<bool> {
         let conn = self.acquire_conn()?;
-        let count: usize =
-            conn.query_row("SELECT COUNT(*) FROM embeddings", [], |row| row.get(0))?;
+        let table_exists: usize = conn.query_row(
+            "SELECT COUNT(*) FROM duckdb_tables() WHERE table_name = ?",
+            params![space.table()],
+            |row| row.get(0),
+        )?;
+        if table_exists == 0 {
+            return Ok(false);
+        }
+
+        let count: usize = conn.query_row(
+            &format!("SELECT COUNT(*) FROM {}", space.table()),
+            [],
+            |row| row.get(0),
+        )?;
         Ok(count > 0)
     }
 
-    /// Clear all embeddings from the database.
+    /// Clear all embeddings (code and docstring) from the database.
     pub fn clear_embeddings(&self) -> Result<()> {
         let conn = self.acquire_conn()?;
         conn.execute("DELETE FROM embeddings", [])?;
+        conn.execute("DELETE FROM doc_embeddings", [])?;
+        conn.execute(
+            "DELETE FROM metadata WHERE key IN (?, ?)",
+            params![
+                EmbeddingSpace::Code.dimension_key(),
+                EmbeddingSpace::Docs.dimension_key()
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Dimension of the vectors stored in a space, if it has any.
+    pub fn embedding_dimension(&self, space: EmbeddingSpace) -> Result<Option<usize>> {
+        let conn = self.acquire_conn()?;
+        Self::stored_dimension(&conn, space)
+    }
+
+    /// Dimension recorded for a space when its vectors were written.
+    ///
+    /// Databases embedded before the dimension was recorded fall back to
+    /// the length of a stored vector.
+    fn stored_dimension(conn: &Connection, space: EmbeddingSpace) -> Result<Option<usize>> {
+        let mut stmt = conn.prepare("SELECT value FROM metadata WHERE key = ?")?;
+        let mut rows = stmt.query(params![space.dimension_key()])?;
+        if let Some(row) = rows.next()? {
+            if let Ok(dimension) = row.get::<_, String>(0)?.parse() {
+                return Ok(Some(dimension));
+            }
+        }
+
+        let mut stmt = conn.prepare(&format!("SELECT embedding FROM {} LIMIT 1", space.table()))?;
+        let mut rows = stmt.query([])?;
+        Ok(match rows.next()? {
+            Some(row) => serde_json::from_str::<Vec<f32>>(&row.get::<_, String>(0)?)
+                .ok()
+                .map(|v| v.len()),
+            None => None,
+        })
+    }
+
+    /// Check that a batch has one dimension and record it for the space.
+    ///
+    /// Unless `replacing` (the batch becomes the space's only vectors, as
+    /// after switching models), the dimension must match what is stored.
+    fn record_dimension(
+        conn: &Connection,
+        space: EmbeddingSpace,
+        batch: &[(String, Vec<f32>, Option<String>)],
+        replacing: bool,
+    ) -> Result<()> {
+        let Some((_, first, _)) = batch.first() else {
+            return Ok(());
+        };
+        let dimension = first.len();
+        if let Some((node_id, embedding, _)) = batch.iter().find(|(_, e, _)| e.len() != dimension) {
+            anyhow::bail!(
+                "Embedding for {} has {} dimensions, expected {}",
+                node_id,
+                embedding.len(),
+                dimension
+            );
+        }
+
+        if !replacing {
+            if let Some(stored) = Self::stored_dimension(conn, space)? {
+                if stored != dimension {
+                    anyhow::bail!(
+                        "Embeddings have {} dimensions but the database stores {}-dimensional vectors.\n\
+                         Re-embed with 'mu embed --force' after switching models.",
+                        dimension,
+                        stored
+                    );
+                }
+            }
+        }
+
+        conn.execute(
+            "INSERT OR REPLACE INTO metadata (key, value) VALUES (?, ?)",
+            params![space.dimension_key(), dimension.to_string()],
+        )?;
         Ok(())
     }
 
@@ -579,14 +993,26 @@ impl MUbase {
         &self,
         batch: &[(String, Vec<f32>, Option<String>)],
         model: Option<&str>,
+    ) -> Result<()> {
+        self.insert_embeddings_batch_in(EmbeddingSpace::Code, batch, model)
+    }
+
+    /// Insert or update embeddings in one space, keeping the rest of it.
+    pub fn insert_embeddings_batch_in(
+        &self,
+        space: EmbeddingSpace,
+        batch: &[(String, Vec<f32>, Option<String>)],
+        model: Option<&str>,
     ) -> Result<()> {
         let conn = self.acquire_conn()?;
         let model_name = model.unwrap_or("mu-sigma-v2");
+        Self::record_dimension(&conn, space, batch, false)?;
 
-        let mut stmt = conn.prepare(
-            r#"INSERT OR REPLACE INTO embeddings (node_id, embedding, model, created_at)
+        let mut stmt = conn.prepare(&format!(
+            r#"INSERT OR REPLACE INTO {} (node_id, embedding, model, created_at)
                VALUES (?, ?, ?, CURRENT_TIMESTAMP)"#,
-        )?;
+            space.table()
+        ))?;
 
         for (node_id, embedding, _text) in batch {
             // Convert Vec<f32> to a DuckDB-compatible array representation
@@ -598,6 +1024,61 @@ impl MUbase {
         Ok(())
     }
 
+    /// Replace all stored embeddings in a single transaction.
+    ///
+    /// Like [`MUbase::replace_graph`], `before_commit` runs just before the
+    /// commit; if it fails the previous embeddings are kept.
+    pub fn replace_embeddings<F>(
+        &self,
+        batch: &[(String, Vec<f32>, Option<String>)],
+        model: Option<&str>,
+        before_commit: F,
+    ) -> Result<()>
+    where
+        F: FnOnce() -> Result<()>,
+    {
+        self.replace_embeddings_in(EmbeddingSpace::Code, batch, model, before_commit)
+    }
+
+    /// Replace all embeddings in one space, leaving the other untouched.
+    ///
+    /// Same transaction semantics as [`MUbase::replace_embeddings`].
+    pub fn replace_embeddings_in<F>(
+        &self,
+        space: EmbeddingSpace,
+        batch: &[(String, Vec<f32>, Option<String>)],
+        model: Option<&str>,
+        before_commit: F,
+    ) -> Result<()>
+    where
+        F: FnOnce() -> Result<()>,
+    {
+        let mut conn = self.acquire_conn()?;
+        let tx = conn
+            .transaction()
+            .context("Failed to begin embeddings transaction")?;
+        let model_name = model.unwrap_or("mu-sigma-v2");
+
+        tx.execute(&format!("DELETE FROM {}", space.table()), [])?;
+        Self::record_dimension(&tx, space, batch, true)?;
+        {
+            let mut stmt = tx.prepare(&format!(
+                r#"INSERT OR REPLACE INTO {} (node_id, embedding, model, created_at)
+                   VALUES (?, ?, ?, CURRENT_TIMESTAMP)"#,
+                space.table()
+            ))?;
+            for (node_id, embedding, _text) in batch {
+                let embedding_json = serde_json::to_string(embedding)?;
+                stmt.execute(params![node_id, embedding_json, model_name])?;
+            }
+        }
+        before_commit()?;
+
+        tx.commit()
+            .context("Failed to commit embeddings transaction")?;
+        Ok(())
+    }
+
     /// Get files that have changed compared to stored hashes.
     ///
     /// # Arguments
@@ -638,16 +1119,42 @@ impl MUbase {
         query_embedding: &[f32],
         limit: usize,
         threshold: Option<f32>,
+    ) -> Result<Vec<VectorSearchResult>> {
+        self.vector_search_in(EmbeddingSpace::Code, query_embedding, limit, threshold)
+    }
+
+    /// Search one embedding space using cosine similarity.
+    ///
+    /// See [`MUbase::vector_search`]; `space` selects code or docstring vectors.
+    pub fn vector_search_in(
+        &self,
+        space: EmbeddingSpace,
+        query_embedding: &[f32],
+        limit: usize,
+        threshold: Option<f32>,
     ) -> Result<Vec<VectorSearchResult>> {
         let conn = self.acquire_conn()?;
 
+        // Vectors of another dimension (i.e. from another model) can't be compared
+        if let Some(dimension) = Self::stored_dimension(&conn, space)? {
+            if query_embedding.len() != dimension {
+                anyhow::bail!(
+                    "Query embedding has {} dimensions but the stored embeddings have {}.\n\
+                     Was the database embedded with a different model? Re-embed with 'mu embed --force'.",
+                    query_embedding.len(),
+                    dimension
+                );
+            }
+        }
+
         // Fetch all embeddings with node metadata
         // DuckDB doesn't have native vector similarity, so we compute in Rust
-        let mut stmt = conn.prepare(
+        let mut stmt = conn.prepare(&format!(
             r#"SELECT e.node_id, e.embedding, n.name, n.type, n.file_path, n.qualified_name
-               FROM embeddings e
+               FROM {} e
                JOIN nodes n ON e.node_id = n.id"#,
-        )?;
+            space.table()
+        ))?;
 
         let mut rows = stmt.query([])?;
         let mut results: Vec<VectorSearchResult> = Vec::new();
@@ -700,6 +1207,137 @@ impl MUbase {
         Ok(results)
     }
 
+    /// Rank nodes against a keyword query with BM25.
+    ///
+    /// Each node's name, qualified name and docstring are split into
+    /// identifier terms (see the `ranking` module), so `getUserById` matches
+    /// `get_user_by_id`. External nodes are skipped. Scores are scaled so the
+    /// best hit is 1.0; nodes sharing no term with the query are left out.
+    pub fn bm25_search(&self, query: &str, limit: usize) -> Result<Vec<VectorSearchResult>> {
+        self.bm25_search_in(EmbeddingSpace::Code, query, limit)
+    }
+
+    /// BM25 search over the text behind one embedding space.
+    ///
+    /// See [`MUbase::bm25_search`]. The docs space ranks docstrings alone,
+    /// so nodes without one are left out.
+    pub fn bm25_search_in(
+        &self,
+        space: EmbeddingSpace,
+        query: &str,
+        limit: usize,
+    ) -> Result<Vec<VectorSearchResult>> {
+        let query_terms = ranking::terms(query);
+        if query_terms.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let conn = self.acquire_conn()?;
+        let mut stmt = conn.prepare(
+            "SELECT id, name, type, file_path, qualified_name, properties
+             FROM nodes
+             WHERE type != 'external'",
+        )?;
+        let mut rows = stmt.query([])?;
+
+        let mut candidates = Vec::new();
+        let mut documents = Vec::new();
+        while let Some(row) = rows.next()? {
+            let name: String = row.get(1)?;
+            let qualified_name: Option<String> = row.get(4)?;
+            let properties: Option<String> = row.get(5)?;
+            let docstring = properties
+                .and_then(|s| serde_json::from_str::<serde_json::Value>(&s).ok())
+                .and_then(|p| p.get("docstring")?.as_str().map(str::to_string));
+
+            let text = match space {
+                EmbeddingSpace::Code => {
+                    let mut text = name.clone();
+                    for extra in [&qualified_name, &docstring].into_iter().flatten() {
+                        text.push(' ');
+                        text.push_str(extra);
+                    }
+                    text
+                }
+                EmbeddingSpace::Docs => match docstring {
+                    Some(docstring) => docstring,
+                    None => continue,
+                },
+            };
+            documents.push(ranking::terms(&text));
+            candidates.push(VectorSearchResult {
+                node_id: row.get(0)?,
+                similarity: 0.0,
+                name,
+                node_type: row.get(2)?,
+                file_path: row.get(3)?,
+                qualified_name,
+            });
+        }
+
+        let scores = ranking::bm25_scores(&documents, &query_terms);
+        let best = scores.iter().copied().fold(0.0f32, f32::max);
+        if best == 0.0 {
+            return Ok(Vec::new());
+        }
+
+        let mut results: Vec<VectorSearchResult> = candidates
+            .into_iter()
+            .zip(scores)
+            .filter(|(_, score)| *score > 0.0)
+            .map(|(mut result, score)| {
+                result.similarity = score / best;
+                result
+            })
+            .collect();
+        results.sort_by(|a, b| {
+            b.similarity
+                .total_cmp(&a.similarity)
+                .then_with(|| a.node_id.cmp(&b.node_id))
+        });
+        results.truncate(limit);
+
+        Ok(results)
+    }
+
+    /// Search with both BM25 and vector similarity and fuse the rankings.
+    ///
+    /// `query` drives the keyword side and `query_embedding` (the same query,
+    /// embedded) the vector side. With `alpha` unset the rankings are merged
+    /// with reciprocal-rank fusion; with `alpha` in 0.0-1.0 the normalized
+    /// scores are blended, 1.0 being pure vector and 0.0 pure keyword.
+    pub fn hybrid_search(
+        &self,
+        query: &str,
+        query_embedding: &[f32],
+        limit: usize,
+        alpha: Option<f32>,
+    ) -> Result<Vec<VectorSearchResult>> {
+        self.hybrid_search_in(EmbeddingSpace::Code, query, query_embedding, limit, alpha)
+    }
+
+    /// Hybrid search over one embedding space.
+    ///
+    /// See [`MUbase::hybrid_search`]; `space` selects both the vectors and
+    /// the text the keyword side ranks (see [`MUbase::bm25_search_in`]).
+    pub fn hybrid_search_in(
+        &self,
+        space: EmbeddingSpace,
+        query: &str,
+        query_embedding: &[f32],
+        limit: usize,
+        alpha: Option<f32>,
+    ) -> Result<Vec<VectorSearchResult>> {
+        // A hit just past either list's cutoff can still win on the other
+        let candidates = (limit * HYBRID_CANDIDATE_FACTOR).max(HYBRID_MIN_CANDIDATES);
+        let vector = self.vector_search_in(space, query_embedding, candidates, None)?;
+        let keyword = self.bm25_search_in(space, query, candidates)?;
+
+        let mut results = ranking::fuse(vector, keyword, alpha);
+        results.truncate(limit);
+        Ok(results)
+    }
+
     /// Get embedding statistics.
     pub fn embedding_stats(&self) -> Result<EmbeddingStats> {
         let conn = self.acquire_conn()?;
@@ -732,6 +1370,89 @@ impl MUbase {
         })
     }
 
+    /// Sample random pairs of embeddings, compute their similarity
+    /// distribution, and store the percentiles in metadata.
+    ///
+    /// Sampling is seeded so repeated calibrations of the same corpus agree.
+    pub fn calibrate_similarity(&self, sample_pairs: usize) -> Result<SimilarityCalibration> {
+        let embeddings: Vec<Vec<f32>> = {
+            let conn = self.acquire_conn()?;
+            let mut stmt = conn.prepare("SELECT embedding FROM embeddings ORDER BY node_id")?;
+            let mut rows = stmt.query([])?;
+            let mut embeddings = Vec::new();
+            while let Some(row) = rows.next()? {
+                let embedding_json: String = row.get(0)?;
+                if let Ok(v) = serde_json::from_str::<Vec<f32>>(&embedding_json) {
+                    embeddings.push(v);
+                }
+            }
+            embeddings
+        };
+
+        if embeddings.len() < 2 {
+            anyhow::bail!("Need at least two embeddings to calibrate. Run 'mu embed' first.");
+        }
+
+        // xorshift64: deterministic and dependency-free
+        let mut state: u64 = 0x9E37_79B9_7F4A_7C15;
+        let mut next_index = || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            (state % embeddings.len() as u64) as usize
+        };
+
+        let mut samples = Vec::with_capacity(sample_pairs);
+        while samples.len() < sample_pairs {
+            let (i, j) = (next_index(), next_index());
+            if i == j {
+                continue;
+            }
+            let a = &embeddings[i];
+            let magnitude = a.iter().map(|x| x * x).sum::<f32>().sqrt();
+            samples.push(cosine_similarity(a, &embeddings[j], magnitude));
+        }
+
+        let calibration = SimilarityCalibration::from_samples(samples)
+            .ok_or_else(|| anyhow::anyhow!("No pairs sampled for calibration"))?;
+
+        self.set_metadata(
+            SimilarityCalibration::METADATA_KEY,
+            &serde_json::to_string(&calibration)?,
+        )?;
+
+        Ok(calibration)
+    }
+
+    /// Get the stored similarity calibration, if `calibrate_similarity` has run.
+    pub fn similarity_calibration(&self) -> Result<Option<SimilarityCalibration>> {
+        Ok(self
+            .get_metadata(SimilarityCalibration::METADATA_KEY)?
+            .and_then(|s| serde_json::from_str(&s).ok()))
+    }
+
+    /// Get a metadata value by key.
+    pub fn get_metadata(&self, key: &str) -> Result<Option<String>> {
+        let conn = self.acquire_conn()?;
+        let mut stmt = conn.prepare("SELECT value FROM metadata WHERE key = ?")?;
+        let mut rows = stmt.query(params![key])?;
+        match rows.next()? {
+            Some(row) => Ok(row.get(0)?),
+            None => Ok(None),
+        }
+    }
+
+    /// Set a metadata value, replacing any existing value for the key.
+    pub fn set_metadata(&self, key: &str, value: &str) -> Result<()> {
+        let conn = self.acquire_conn()?;
+        conn.execute(
+            "INSERT OR REPLACE INTO metadata (key, value) VALUES (?, ?)",
+            params![key, value],
+        )
+        .with_context(|| format!("Failed to set metadata: {}", key))?;
+        Ok(())
+    }
+
     /// Get all stored file hashes.
     pub fn get_all_file_hashes(&self) -> Result<HashMap<String, String>> {
         let conn = self.acquire_conn()?;
@@ -770,10 +1491,16 @@ impl MUbase {
     /// Useful for cleanup after incremental updates.
     pub fn cleanup_orphaned_embeddings(&self) -> Result<usize> {
         let conn = self.acquire_conn()?;
-        let deleted = conn.execute(
-            "DELETE FROM embeddings WHERE node_id NOT IN (SELECT id FROM nodes)",
-            [],
-        )?;
+        let mut deleted = 0;
+        for space in [EmbeddingSpace::Code, EmbeddingSpace::Docs] {
+            deleted += conn.execute(
+                &format!(
+                    "DELETE FROM {} WHERE node_id NOT IN (SELECT id FROM nodes)",
+                    space.table()
+                ),
+                [],
+            )?;
+        }
         Ok(deleted)
     }
 
@@ -785,10 +1512,9 @@ impl MUbase {
         &self,
         current_files: &std::collections::HashSet<String>,
     ) -> Result<usize> {
-        let conn = self.acquire_conn()?;
-
-        // Get all stored file paths
+        // Read stored paths before taking the lock; the mutex is not reentrant
         let stored_hashes = self.get_all_file_hashes()?;
+        let conn = self.acquire_conn()?;
         let mut deleted_count = 0;
 
         for file_path in stored_hashes.keys() {
@@ -833,6 +1559,11 @@ impl QueryResult {
     }
 }
 
+/// Quote a path as a SQL string literal.
+fn sql_string_literal(path: &Path) -> String {
+    format!("'{}'", path.display().to_string().replace('\'', "''"))
+}
+
 /// Statistics about the graph.
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct GraphStats {
@@ -910,6 +1641,184 @@ mod tests {
         assert_eq!(graph.edge_count(), 1);
     }
 
+    #[test]
+    fn test_replace_graph_commits() {
+        let db = create_test_db();
+        db.insert_node(&Node::module("src/old.py")).unwrap();
+
+        let nodes = vec![Node::module("src/a.py"), Node::module("src/b.py")];
+        let edges = vec![Edge::imports("mod:src/a.py", "mod:src/b.py")];
+        db.replace_graph(&nodes, &edges, || Ok(())).unwrap();
+
+        let stats = db.stats().unwrap();
+        assert_eq!(stats.node_count, 2);
+        assert_eq!(stats.edge_count, 1);
+        assert!(db.get_node("mod:src/old.py").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_replace_graph_failure_keeps_previous_graph() {
+        let db = create_test_db();
+        let old_nodes = vec![Node::module("src/a.py"), Node::module("src/b.py")];
+        let old_edges = vec![Edge::imports("mod:src/a.py", "mod:src/b.py")];
+        db.replace_graph(&old_nodes, &old_edges, || Ok(())).unwrap();
+
+        // Simulate an interruption during the write phase
+        let new_nodes = vec![Node::module("src/c.py")];
+        let result = db.replace_graph(&new_nodes, &[], || anyhow::bail!("interrupted"));
+        assert!(result.is_err());
+
+        let stats = db.stats().unwrap();
+        assert_eq!(stats.node_count, 2);
+        assert_eq!(stats.edge_count, 1);
+        assert!(db.get_node("mod:src/a.py").unwrap().is_some());
+        assert!(db.get_node("mod:src/c.py").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_replace_graph_write_failure_keeps_previous_graph() {
+        let db = create_test_db();
+        let old_nodes = vec![Node::module("src/a.py"), Node::module("src/b.py")];
+        let old_edges = vec![Edge::imports("mod:src/a.py", "mod:src/b.py")];
+        db.replace_graph(&old_nodes, &old_edges, || Ok(())).unwrap();
+
+        // Add a constraint that the next edge write will violate
+        db.conn
+            .lock()
+            .unwrap()
+            .execute_batch(
+                "CREATE TABLE edges_checked AS SELECT * FROM edges;
+                 DROP TABLE edges;
+                 CREATE TABLE edges (
+                     id VARCHAR PRIMARY KEY,
+                     source_id VARCHAR NOT NULL,
+                     target_id VARCHAR NOT NULL CHECK (target_id <> source_id),
+                     type VARCHAR NOT NULL,
+                     properties JSON
+                 );
+                 INSERT INTO edges SELECT * FROM edges_checked;
+                 DROP TABLE edges_checked;",
+            )
+            .unwrap();
+
+        let new_nodes = vec![Node::module("src/c.py")];
+        let new_edges = vec![Edge::imports("mod:src/c.py", "mod:src/c.py")];
+        let result = db.replace_graph(&new_nodes, &new_edges, || Ok(()));
+        assert!(result.is_err());
+
+        let stats = db.stats().unwrap();
+        assert_eq!(stats.node_count, 2);
+        assert_eq!(stats.edge_count, 1);
+        assert!(db.get_node("mod:src/c.py").unwrap().is_none());
+    }
+
+    #[test]
+    fn test_replace_embeddings_failure_keeps_previous() {
+        let db = create_test_db();
+        db.insert_node(&Node::module("src/a.py")).unwrap();
+        let old = vec![("mod:src/a.py".to_string(), vec![1.0, 0.0], None)];
+        db.replace_embeddings(&old, None, || Ok(())).unwrap();
+
+        let new = vec![("mod:src/a.py".to_string(), vec![0.0, 1.0], None)];
+        let result = db.replace_embeddings(&new, None, || anyhow::bail!("interrupted"));
+        assert!(result.is_err());
+
+        let results = db.vector_search(&[1.0, 0.0], 1, None).unwrap();
+        assert_eq!(results.len(), 1);
+        assert!(results[0].similarity > 0.99);
+    }
+
+    #[test]
+    fn test_calibrate_similarity_stores_percentiles() {
+        let db = create_test_db();
+        let vectors = [
+            vec![1.0, 0.0, 0.0],
+            vec![0.9, 0.1, 0.0],
+            vec![0.0, 1.0, 0.0],
+            vec![0.0, 0.2, 1.0],
+            vec![0.5, 0.5, 0.5],
+        ];
+        let batch: Vec<_> = vectors
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                let path = format!("src/m{}.py", i);
+                db.insert_node(&Node::module(&path)).unwrap();
+                (format!("mod:{}", path), v.clone(), None)
+            })
+            .collect();
+        db.insert_embeddings_batch(&batch, None).unwrap();
+
+        assert!(db.similarity_calibration().unwrap().is_none());
+
+        let calibration = db.calibrate_similarity(200).unwrap();
+        assert_eq!(calibration.sample_size, 200);
+        assert!(calibration.p50 <= calibration.p90);
+        assert!(calibration.p90 <= calibration.p99);
+
+        let stored = db.similarity_calibration().unwrap().unwrap();
+        assert_eq!(stored, calibration);
+    }
+
+    #[test]
+    fn test_calibrate_similarity_needs_embeddings() {
+        let db = create_test_db();
+        assert!(db.calibrate_similarity(10).is_err());
+    }
+
+    #[test]
+    fn test_project_root_recorded_for_external_database() {
+        let project = tempdir().unwrap();
+        let scratch = tempdir().unwrap();
+        let db_path = scratch.path().join("index.duckdb");
+
+        {
+            let mut db = MUbase::open(&db_path).unwrap();
+            assert_eq!(db.project_root(), scratch.path());
+            db.set_project_root(project.path()).unwrap();
+        }
+
+        let db = MUbase::open_read_only(&db_path).unwrap();
+        assert_eq!(db.project_root(), project.path());
+    }
+
+    #[test]
+    fn test_node_source_with_context() {
+        let dir = tempdir().unwrap();
+        let root = dir.path();
+        std::fs::create_dir_all(root.join(".mu")).unwrap();
+        std::fs::create_dir_all(root.join("src")).unwrap();
+        std::fs::write(
+            root.join("src/auth.py"),
+            "import os\n\nclass Auth:\n    # Check credentials\n    def login(self):\n        return True\n",
+        )
+        .unwrap();
+
+        let db = MUbase::open(root.join(".mu/mubase")).unwrap();
+        let class = Node::class("src/auth.py", "Auth", 3, 6);
+        let method = Node::function("src/auth.py", "login", Some("Auth"), 5, 6, 1);
+        db.insert_nodes(&[class.clone(), method.clone()]).unwrap();
+        db.insert_edge(&Edge::contains(&class.id, &method.id))
+            .unwrap();
+
+        let source = db.node_source(&method, 1).unwrap().unwrap();
+        assert_eq!(source.header.as_deref(), Some("class Auth:"));
+        assert_eq!(source.context, "    # Check credentials");
+        assert_eq!(source.body, "    def login(self):\n        return True");
+        assert_eq!(source.start_line, 5);
+        assert!(!source.stale);
+
+        // File shrank since indexing: the range is clamped and flagged
+        std::fs::write(
+            root.join("src/auth.py"),
+            "class Auth:\n    def login(self):\n",
+        )
+        .unwrap();
+        let source = db.node_source(&method, 0).unwrap().unwrap();
+        assert!(source.stale);
+        assert_eq!(source.body, "    def login(self):");
+    }
+
     #[test]
     fn test_query_returns_proper_column_names() {
         let db = create_test_db();
@@ -980,6 +1889,115 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_query_each_streams_rows() {
+        let db = create_test_db();
+        for path in ["src/a.py", "src/b.py", "src/c.py"] {
+            db.insert_node(&Node::module(path)).unwrap();
+        }
+
+        let mut ndjson = Vec::new();
+        db.query_each(
+            "SELECT name, file_path FROM nodes WHERE file_path > ? ORDER BY file_path",
+            &[&"src/a.py"],
+            |columns, row| {
+                let object: serde_json::Map<_, _> = columns.iter().cloned().zip(row).collect();
+                serde_json::to_writer(&mut ndjson, &object)?;
+                ndjson.push(b'\n');
+                Ok(())
+            },
+        )
+        .unwrap();
+
+        let lines: Vec<serde_json::Value> = String::from_utf8(ndjson)
+            .unwrap()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 2);
+        assert_eq!(lines[0]["file_path"], "src/b.py");
+        assert_eq!(lines[1]["file_path"], "src/c.py");
+
+        // An error from the callback stops the query
+        let mut seen = 0;
+        let result = db.query_each("SELECT id FROM nodes", &[], |_, _| {
+            seen += 1;
+            anyhow::bail!("client went away")
+        });
+        assert!(result.is_err());
+        assert_eq!(seen, 1);
+    }
+
+    #[test]
+    fn test_backup_and_restore() {
+        let dir = tempdir().unwrap();
+        let db = MUbase::open(dir.path().join("test.mubase")).unwrap();
+
+        let module = Node::module("src/app.py");
+        db.insert_node(&module).unwrap();
+        db.insert_embeddings_batch(&[(module.id.clone(), vec![0.5, 0.25], None)], None)
+            .unwrap();
+        db.set_file_hashes_batch(&[("src/app.py".to_string(), "abc".to_string())])
+            .unwrap();
+
+        let backup = dir.path().join("backup");
+        db.backup(&backup).unwrap();
+        assert!(db.backup(&backup).is_err(), "should refuse a non-empty dir");
+
+        // Diverge from the backup, then restore it
+        db.delete_nodes_for_file("src/app.py").unwrap();
+        db.insert_node(&Node::module("src/other.py")).unwrap();
+        db.restore(&backup).unwrap();
+
+        assert!(db.get_node(&module.id).unwrap().is_some());
+        assert!(db.get_node("mod:src/other.py").unwrap().is_none());
+        assert!(db.has_embeddings().unwrap());
+        assert_eq!(
+            db.get_all_file_hashes()
+                .unwrap()
+                .get("src/app.py")
+                .map(String::as_str),
+            Some("abc")
+        );
+        let indexes: usize = db
+            .query("SELECT COUNT(*) FROM duckdb_indexes()")
+            .unwrap()
+            .rows[0][0]
+            .as_u64()
+            .unwrap() as usize;
+        assert!(indexes > 0, "indexes should be restored");
+
+        // A directory that is not a backup is rejected without touching data
+        assert!(db.restore(dir.path()).is_err());
+        assert!(db.get_node(&module.id).unwrap().is_some());
+    }
+
+    #[test]
+    fn test_compact_reclaims_space() {
+        let dir = tempdir().unwrap();
+        let db = MUbase::open(dir.path().join("test.mubase")).unwrap();
+
+        let nodes: Vec<Node> = (0..20_000)
+            .map(|i| Node::module(&format!("src/gen/file_{}.py", i)))
+            .collect();
+        db.insert_nodes(&nodes).unwrap();
+        db.insert_node(&Node::module("src/keep.py")).unwrap();
+        db.query("CHECKPOINT").unwrap();
+        db.query("DELETE FROM nodes WHERE file_path LIKE 'src/gen/%'")
+            .unwrap();
+
+        let (before, after) = db.compact().unwrap();
+        assert!(after < before, "expected {} < {}", after, before);
+
+        // Data and indexes survive, and the connection keeps working
+        assert!(db.get_node("mod:src/keep.py").unwrap().is_some());
+        assert_eq!(db.stats().unwrap().node_count, 1);
+        let indexes = db.query("SELECT COUNT(*) FROM duckdb_indexes()").unwrap();
+        assert!(indexes.rows[0][0].as_i64().unwrap() > 0);
+        db.insert_node(&Node::module("src/new.py")).unwrap();
+        assert!(!dir.path().join("test.compact.tmp").exists());
+    }
+
     // ========================================================================
     // Embedding Tests
     // ========================================================================
@@ -1058,6 +2076,36 @@ mod tests {
         assert!(results[0].similarity > results[1].similarity);
     }
 
+    #[test]
+    fn test_embedding_dimension_is_enforced() {
+        let db = create_test_db();
+        db.insert_node(&Node::module("src/a.py")).unwrap();
+        db.insert_node(&Node::module("src/b.py")).unwrap();
+
+        let batch = vec![("mod:src/a.py".to_string(), vec![1.0, 0.0, 0.0], None)];
+        db.insert_embeddings_batch(&batch, None).unwrap();
+        assert_eq!(
+            db.embedding_dimension(EmbeddingSpace::Code).unwrap(),
+            Some(3)
+        );
+
+        // Vectors from a model of another shape are rejected
+        let wider = vec![("mod:src/b.py".to_string(), vec![1.0; 4], None)];
+        assert!(db.insert_embeddings_batch(&wider, None).is_err());
+        assert!(db.vector_search(&[1.0, 0.0], 10, None).is_err());
+
+        // Replacing the whole space switches the dimension
+        db.replace_embeddings(&wider, None, || Ok(())).unwrap();
+        assert_eq!(
+            db.embedding_dimension(EmbeddingSpace::Code).unwrap(),
+            Some(4)
+        );
+        assert_eq!(db.vector_search(&[1.0; 4], 10, None).unwrap().len(), 1);
+
+        db.clear_embeddings().unwrap();
+        assert_eq!(db.embedding_dimension(EmbeddingSpace::Code).unwrap(), None);
+    }
+
     #[test]
     fn test_vector_search_with_threshold() {
         let db = create_test_db();
@@ -1087,6 +2135,137 @@ mod tests {
         assert_eq!(results[0].node_id, "mod:src/similar.py");
     }
 
+    #[test]
+    fn test_bm25_and_hybrid_search() {
+        let db = create_test_db();
+        db.insert_nodes(&[
+            Node::function("src/users.py", "get_user_by_id", None, 1, 3, 1),
+            Node::function("src/net.py", "retry_with_backoff", None, 1, 9, 2).with_properties(
+                serde_json::json!({"docstring": "Sleep longer after each failure"}),
+            ),
+            Node::function("src/net.py", "send", None, 10, 12, 1),
+        ])
+        .unwrap();
+        let embeddings = vec![
+            (
+                "fn:src/users.py:get_user_by_id".to_string(),
+                vec![0.0, 1.0, 0.0],
+                None,
+            ),
+            (
+                "fn:src/net.py:retry_with_backoff".to_string(),
+                vec![1.0, 0.0, 0.0],
+                None,
+            ),
+            ("fn:src/net.py:send".to_string(), vec![0.6, 0.8, 0.0], None),
+        ];
+        db.insert_embeddings_batch(&embeddings, None).unwrap();
+
+        // camelCase queries match snake_case names, docstrings are indexed
+        let hits = db.bm25_search("getUserById", 10).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].node_id, "fn:src/users.py:get_user_by_id");
+        assert_eq!(hits[0].similarity, 1.0);
+        let hits = db.bm25_search("failure", 10).unwrap();
+        assert_eq!(hits[0].node_id, "fn:src/net.py:retry_with_backoff");
+        assert!(db.bm25_search("  ", 10).unwrap().is_empty());
+        // Over docs, only docstrings are ranked
+        assert!(db
+            .bm25_search_in(EmbeddingSpace::Docs, "getUserById", 10)
+            .unwrap()
+            .is_empty());
+        let hits = db
+            .bm25_search_in(EmbeddingSpace::Docs, "failure", 10)
+            .unwrap();
+        assert_eq!(hits[0].node_id, "fn:src/net.py:retry_with_backoff");
+
+        // The keyword hit outranks nodes only the vector side found
+        let query = vec![0.0, 0.0, 1.0];
+        let hits = db.hybrid_search("getUserById", &query, 2, None).unwrap();
+        assert_eq!(hits.len(), 2);
+        assert_eq!(hits[0].node_id, "fn:src/users.py:get_user_by_id");
+
+        // alpha = 1.0 ignores the keyword ranking
+        let query = vec![1.0, 0.0, 0.0];
+        let hits = db
+            .hybrid_search("getUserById", &query, 1, Some(1.0))
+            .unwrap();
+        assert_eq!(hits[0].node_id, "fn:src/net.py:retry_with_backoff");
+    }
+
+    #[test]
+    fn test_doc_embeddings_searched_separately() {
+        let db = create_test_db();
+        db.insert_nodes(&[
+            Node::function("src/auth.py", "rst_pw", None, 1, 5, 1)
+                .with_properties(serde_json::json!({"docstring": "Reset a forgotten password"})),
+            Node::function("src/auth.py", "reset_password_form", None, 6, 9, 1),
+        ])
+        .unwrap();
+
+        // The terse identifier sits far from the query in code space, but
+        // its docstring vector is close to it
+        let code = vec![
+            (
+                "fn:src/auth.py:rst_pw".to_string(),
+                vec![0.0, 1.0, 0.0],
+                None,
+            ),
+            (
+                "fn:src/auth.py:reset_password_form".to_string(),
+                vec![0.9, 0.1, 0.0],
+                None,
+            ),
+        ];
+        let docs = vec![(
+            "fn:src/auth.py:rst_pw".to_string(),
+            vec![1.0, 0.0, 0.0],
+            None,
+        )];
+        db.replace_embeddings(&code, None, || Ok(())).unwrap();
+        db.replace_embeddings_in(EmbeddingSpace::Docs, &docs, None, || Ok(()))
+            .unwrap();
+
+        let query = vec![1.0, 0.0, 0.0];
+        let code_hits = db.vector_search(&query, 1, None).unwrap();
+        assert_eq!(code_hits[0].node_id, "fn:src/auth.py:reset_password_form");
+
+        // Only the documented node is in the docs index
+        let doc_hits = db
+            .vector_search_in(EmbeddingSpace::Docs, &query, 10, None)
+            .unwrap();
+        assert_eq!(doc_hits.len(), 1);
+        assert_eq!(doc_hits[0].node_id, "fn:src/auth.py:rst_pw");
+
+        // Replacing one space leaves the other alone
+        db.replace_embeddings(&[], None, || Ok(())).unwrap();
+        assert!(!db.has_embeddings().unwrap());
+        assert!(db.has_embeddings_in(EmbeddingSpace::Docs).unwrap());
+    }
+
+    #[test]
+    fn test_delete_nodes_for_file_drops_embeddings() {
+        let db = create_test_db();
+        let gone = Node::function("src/gone.py", "f", None, 1, 2, 1);
+        let kept = Node::function("src/kept.py", "g", None, 1, 2, 1);
+        db.insert_nodes(&[gone.clone(), kept.clone()]).unwrap();
+
+        let vectors = vec![
+            (gone.id.clone(), vec![1.0, 0.0], None),
+            (kept.id.clone(), vec![0.0, 1.0], None),
+        ];
+        db.replace_embeddings(&vectors, None, || Ok(())).unwrap();
+        db.replace_embeddings_in(EmbeddingSpace::Docs, &vectors[..1], None, || Ok(()))
+            .unwrap();
+
+        assert_eq!(db.delete_nodes_for_file("src/gone.py").unwrap(), 1);
+
+        let hits = db.vector_search(&[1.0, 0.0], 10, None).unwrap();
+        assert_eq!(hits.len(), 1);
+        assert_eq!(hits[0].node_id, kept.id);
+        assert!(!db.has_embeddings_in(EmbeddingSpace::Docs).unwrap());
+    }
+
     #[test]
     fn test_file_hashes() {
         let db = create_test_db();
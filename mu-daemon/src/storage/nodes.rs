@@ -67,6 +67,34 @@ impl Node {
         }
     }
 
+    /// Create a new interface (or trait) node.
+    ///
+    /// Like enums, interfaces share the class ID scheme.
+    pub fn interface(file_path: &str, name: &str, line_start: u32, line_end: u32) -> Self {
+        Self {
+            node_type: NodeType::Interface,
+            ..Self::class(file_path, name, line_start, line_end)
+        }
+    }
+
+    /// Create a new enum node.
+    ///
+    /// Enums share the class ID scheme, so references resolved against
+    /// class IDs still find them.
+    pub fn enumeration(
+        file_path: &str,
+        name: &str,
+        variants: &[String],
+        line_start: u32,
+        line_end: u32,
+    ) -> Self {
+        Self {
+            node_type: NodeType::Enum,
+            properties: Some(serde_json::json!({ "variants": variants })),
+            ..Self::class(file_path, name, line_start, line_end)
+        }
+    }
+
     /// Create a new function node.
     pub fn function(
         file_path: &str,
@@ -119,6 +147,25 @@ impl Node {
         self.properties = Some(properties);
         self
     }
+
+    /// The node's docstring, if one was extracted during parsing.
+    pub fn docstring(&self) -> Option<&str> {
+        self.properties
+            .as_ref()?
+            .get("docstring")?
+            .as_str()
+            .map(str::trim)
+            .filter(|s| !s.is_empty())
+    }
+
+    /// The variants of an enum node.
+    pub fn variants(&self) -> Vec<&str> {
+        self.properties
+            .as_ref()
+            .and_then(|p| p.get("variants")?.as_array())
+            .map(|v| v.iter().filter_map(|s| s.as_str()).collect())
+            .unwrap_or_default()
+    }
 }
 
 #[cfg(test)]
@@ -133,6 +180,18 @@ mod tests {
         assert_eq!(node.node_type, NodeType::Module);
     }
 
+    #[test]
+    fn test_enum_node() {
+        let variants = vec!["Red".to_string(), "Green".to_string()];
+        let node = Node::enumeration("src/color.rs", "Color", &variants, 3, 6);
+        assert_eq!(node.id, "cls:src/color.rs:Color");
+        assert_eq!(node.node_type, NodeType::Enum);
+        assert_eq!(node.variants(), vec!["Red", "Green"]);
+        assert!(Node::class("src/cli.py", "MUbase", 1, 2)
+            .variants()
+            .is_empty());
+    }
+
     #[test]
     fn test_class_node() {
         let node = Node::class("src/cli.py", "MUbase", 10, 100);
@@ -0,0 +1,212 @@
+//! Keyword scoring and rank fusion for search.
+//!
+//! BM25 works on identifier terms: `getUserById`, `get_user_by_id` and
+//! `user.get_by_id` all split into lowercase words, so keyword queries match
+//! regardless of naming convention. Fusion merges a keyword and a vector
+//! ranking into one list for hybrid search.
+
+use std::collections::HashMap;
+
+use super::embeddings::VectorSearchResult;
+
+/// BM25 term frequency saturation.
+const BM25_K1: f32 = 1.2;
+
+/// BM25 document length normalization.
+const BM25_B: f32 = 0.75;
+
+/// Reciprocal-rank fusion constant from the original RRF paper.
+const RRF_K: f32 = 60.0;
+
+/// Split text into lowercase search terms.
+///
+/// Words are split on punctuation, underscores and camelCase boundaries
+/// (`HTTPServer` gives `http` and `server`). A compound identifier also
+/// contributes its parts joined, so `get_user` and `getUser` share the term
+/// `getuser` and exact identifier matches score higher.
+pub(crate) fn terms(text: &str) -> Vec<String> {
+    let mut terms = Vec::new();
+    for word in text
+        .split(|c: char| !c.is_alphanumeric() && c != '_')
+        .filter(|w| !w.is_empty())
+    {
+        let mut parts = Vec::new();
+        for piece in word.split('_').filter(|p| !p.is_empty()) {
+            let chars: Vec<char> = piece.chars().collect();
+            let mut start = 0;
+            for i in 1..chars.len() {
+                let (prev, cur) = (chars[i - 1], chars[i]);
+                let next_lower = chars.get(i + 1).is_some_and(|c| c.is_lowercase());
+                let boundary = (prev.is_lowercase() && cur.is_uppercase())
+                    || (prev.is_uppercase() && cur.is_uppercase() && next_lower);
+                if boundary {
+                    parts.push(chars[start..i].iter().collect::<String>().to_lowercase());
+                    start = i;
+                }
+            }
+            parts.push(chars[start..].iter().collect::<String>().to_lowercase());
+        }
+        if parts.len() > 1 {
+            let compound = parts.concat();
+            parts.push(compound);
+        }
+        terms.extend(parts);
+    }
+    terms
+}
+
+/// BM25 score of each document for the query terms.
+///
+/// Documents are term lists from [`terms`]; scores are in input order and
+/// zero for documents sharing no term with the query.
+pub(crate) fn bm25_scores(documents: &[Vec<String>], query: &[String]) -> Vec<f32> {
+    if documents.is_empty() {
+        return Vec::new();
+    }
+
+    let n = documents.len() as f32;
+    let avg_len = documents.iter().map(Vec::len).sum::<usize>() as f32 / n;
+
+    let mut query_terms: Vec<&String> = query.iter().collect();
+    query_terms.sort();
+    query_terms.dedup();
+
+    let idf: HashMap<&str, f32> = query_terms
+        .iter()
+        .map(|term| {
+            let df = documents.iter().filter(|d| d.contains(term)).count() as f32;
+            (term.as_str(), (1.0 + (n - df + 0.5) / (df + 0.5)).ln())
+        })
+        .collect();
+
+    documents
+        .iter()
+        .map(|doc| {
+            let len_norm = 1.0 - BM25_B + BM25_B * doc.len() as f32 / avg_len.max(1.0);
+            query_terms
+                .iter()
+                .map(|term| {
+                    let tf = doc.iter().filter(|t| t == term).count() as f32;
+                    idf[term.as_str()] * tf * (BM25_K1 + 1.0) / (tf + BM25_K1 * len_norm)
+                })
+                .sum()
+        })
+        .collect()
+}
+
+/// Merge a vector and a keyword ranking, best first.
+///
+/// With `alpha` unset, scores come from reciprocal-rank fusion, scaled so a
+/// node ranked first by both lists scores 1.0. With `alpha`, each list's
+/// scores are min-max normalized and blended as
+/// `alpha * vector + (1 - alpha) * keyword`. Ties break on node ID.
+pub(crate) fn fuse(
+    vector: Vec<VectorSearchResult>,
+    keyword: Vec<VectorSearchResult>,
+    alpha: Option<f32>,
+) -> Vec<VectorSearchResult> {
+    let mut fused: HashMap<String, VectorSearchResult> = HashMap::new();
+    for (weight, results) in [
+        (alpha.unwrap_or(0.5), vector),
+        (1.0 - alpha.unwrap_or(0.5), keyword),
+    ] {
+        let max = results
+            .iter()
+            .map(|r| r.similarity)
+            .fold(f32::MIN, f32::max);
+        let min = results
+            .iter()
+            .map(|r| r.similarity)
+            .fold(f32::MAX, f32::min);
+
+        for (rank, mut result) in results.into_iter().enumerate() {
+            let score = match alpha {
+                None => 1.0 / (RRF_K + rank as f32 + 1.0) / (2.0 / (RRF_K + 1.0)),
+                Some(_) if max > min => weight * (result.similarity - min) / (max - min),
+                Some(_) => weight,
+            };
+            fused
+                .entry(result.node_id.clone())
+                .and_modify(|r| r.similarity += score)
+                .or_insert_with(|| {
+                    result.similarity = score;
+                    result
+                });
+        }
+    }
+
+    let mut fused: Vec<VectorSearchResult> = fused.into_values().collect();
+    fused.sort_by(|a, b| {
+        b.similarity
+            .total_cmp(&a.similarity)
+            .then_with(|| a.node_id.cmp(&b.node_id))
+    });
+    fused
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hit(node_id: &str, similarity: f32) -> VectorSearchResult {
+        VectorSearchResult {
+            node_id: node_id.to_string(),
+            similarity,
+            name: node_id.to_string(),
+            node_type: "function".to_string(),
+            file_path: None,
+            qualified_name: None,
+        }
+    }
+
+    fn ids(results: &[VectorSearchResult]) -> Vec<&str> {
+        results.iter().map(|r| r.node_id.as_str()).collect()
+    }
+
+    #[test]
+    fn test_terms_split_identifiers() {
+        assert_eq!(
+            terms("getUserById"),
+            vec!["get", "user", "by", "id", "getuserbyid"]
+        );
+        assert_eq!(terms("HTTPServer"), vec!["http", "server", "httpserver"]);
+        assert_eq!(
+            terms("auth.get_user"),
+            vec!["auth", "get", "user", "getuser"]
+        );
+        assert_eq!(terms("retry"), vec!["retry"]);
+    }
+
+    #[test]
+    fn test_bm25_prefers_rare_terms() {
+        let docs = vec![
+            terms("get_user"),
+            terms("get_config"),
+            terms("retry_with_backoff"),
+        ];
+        let scores = bm25_scores(&docs, &terms("get backoff"));
+        // "backoff" appears once and outweighs the common "get"
+        assert!(scores[2] > scores[0]);
+        assert_eq!(scores[0], scores[1]);
+
+        let scores = bm25_scores(&docs, &terms("missing"));
+        assert!(scores.iter().all(|&s| s == 0.0));
+    }
+
+    #[test]
+    fn test_fuse_rewards_agreement() {
+        let vector = vec![hit("a", 0.9), hit("b", 0.8), hit("c", 0.1)];
+        let keyword = vec![hit("b", 7.0), hit("d", 3.0)];
+
+        // Ranked high by both lists beats top of one list
+        let rrf = fuse(vector.clone(), keyword.clone(), None);
+        assert_eq!(ids(&rrf), vec!["b", "a", "d", "c"]);
+        assert!(rrf[0].similarity <= 1.0);
+
+        // alpha = 1 is pure vector order, alpha = 0 pure keyword order
+        let blended = fuse(vector.clone(), keyword.clone(), Some(1.0));
+        assert_eq!(ids(&blended)[..2], ["a", "b"]);
+        let blended = fuse(vector, keyword, Some(0.0));
+        assert_eq!(ids(&blended)[0], "b");
+    }
+}
@@ -11,8 +11,12 @@ use std::fmt;
 pub enum NodeType {
     /// File/module level
     Module,
-    /// Class/struct/interface
+    /// Class/struct
     Class,
+    /// Interface, trait or protocol
+    Interface,
+    /// Enum; its variants are in the `variants` property
+    Enum,
     /// Function/method
     Function,
     /// External dependency (package)
@@ -24,6 +28,8 @@ impl NodeType {
         match self {
             NodeType::Module => "module",
             NodeType::Class => "class",
+            NodeType::Interface => "interface",
+            NodeType::Enum => "enum",
             NodeType::Function => "function",
             NodeType::External => "external",
         }
@@ -33,6 +39,8 @@ impl NodeType {
         match s.to_lowercase().as_str() {
             "module" => Some(NodeType::Module),
             "class" => Some(NodeType::Class),
+            "interface" => Some(NodeType::Interface),
+            "enum" => Some(NodeType::Enum),
             "function" => Some(NodeType::Function),
             "external" => Some(NodeType::External),
             _ => None,
@@ -56,6 +64,8 @@ pub enum EdgeType {
     Imports,
     /// Class→Class (inheritance)
     Inherits,
+    /// Class→Interface (implicit interface satisfaction, e.g. Go)
+    Implements,
     /// Function→Function (call relationships)
     Calls,
     /// Function→Variable (usage)
@@ -68,6 +78,7 @@ impl EdgeType {
             EdgeType::Contains => "contains",
             EdgeType::Imports => "imports",
             EdgeType::Inherits => "inherits",
+            EdgeType::Implements => "implements",
             EdgeType::Calls => "calls",
             EdgeType::Uses => "uses",
         }
@@ -78,6 +89,7 @@ impl EdgeType {
             "contains" => Some(EdgeType::Contains),
             "imports" => Some(EdgeType::Imports),
             "inherits" => Some(EdgeType::Inherits),
+            "implements" => Some(EdgeType::Implements),
             "calls" => Some(EdgeType::Calls),
             "uses" => Some(EdgeType::Uses),
             _ => None,
@@ -139,6 +151,15 @@ CREATE TABLE IF NOT EXISTS embeddings (
     created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
 );
 
+-- Docstring embeddings, searched separately from code structure
+-- Only nodes with a docstring have a row here
+CREATE TABLE IF NOT EXISTS doc_embeddings (
+    node_id VARCHAR PRIMARY KEY,
+    embedding VARCHAR NOT NULL,
+    model VARCHAR NOT NULL DEFAULT 'mu-sigma-v2',
+    created_at TIMESTAMP DEFAULT CURRENT_TIMESTAMP
+);
+
 -- File hashes for incremental embedding updates
 CREATE TABLE IF NOT EXISTS file_hashes (
     file_path VARCHAR PRIMARY KEY,
@@ -161,6 +182,8 @@ mod tests {
         for nt in [
             NodeType::Module,
             NodeType::Class,
+            NodeType::Interface,
+            NodeType::Enum,
             NodeType::Function,
             NodeType::External,
         ] {
@@ -176,6 +199,7 @@ mod tests {
             EdgeType::Contains,
             EdgeType::Imports,
             EdgeType::Inherits,
+            EdgeType::Implements,
             EdgeType::Calls,
             EdgeType::Uses,
         ] {
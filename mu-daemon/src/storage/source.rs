@@ -0,0 +1,83 @@
+//! Node source retrieval.
+//!
+//! Reads a node's source text from disk using the line range stored in the
+//! database, along with leading context and the signature of its enclosing
+//! scope.
+
+use serde::Serialize;
+
+/// Source text for a single node.
+#[derive(Debug, Clone, Serialize)]
+pub struct NodeSource {
+    /// Signature line of the enclosing scope (e.g. the class of a method)
+    pub header: Option<String>,
+    /// Leading context lines immediately before the node
+    pub context: String,
+    /// The node's own source lines
+    pub body: String,
+    /// 1-based line number of the first body line
+    pub start_line: u32,
+    /// True if the stored line range no longer fits the file on disk
+    pub stale: bool,
+}
+
+/// Slice `content` to the 1-based inclusive range `line_start..=line_end`.
+///
+/// Ranges that run past the end of the file are clamped and flagged stale.
+/// Returns `(context, body, start_line, stale)`.
+pub(crate) fn slice_lines(
+    content: &str,
+    line_start: u32,
+    line_end: Option<u32>,
+    context_lines: usize,
+) -> (String, String, u32, bool) {
+    let lines: Vec<&str> = content.lines().collect();
+    let total = lines.len();
+
+    let requested_start = line_start.max(1) as usize;
+    let requested_end = line_end.map(|e| e as usize).unwrap_or(total);
+    let stale = requested_start > total || requested_end > total || requested_end < requested_start;
+
+    // Clamp to the file so a shifted range still yields something useful
+    let end = requested_end.min(total).max(1);
+    let start = requested_start.min(end);
+
+    if total == 0 {
+        return (String::new(), String::new(), 1, stale);
+    }
+
+    let context_start = start.saturating_sub(context_lines).max(1);
+    let context = lines[context_start - 1..start - 1].join("\n");
+    let body = lines[start - 1..end].join("\n");
+
+    (context, body, start as u32, stale)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SOURCE: &str = "import os\n\nclass A:\n    def f(self):\n        return 1\n";
+
+    #[test]
+    fn test_slice_lines_with_context() {
+        let (context, body, start, stale) = slice_lines(SOURCE, 4, Some(5), 2);
+        assert_eq!(context, "\nclass A:");
+        assert_eq!(body, "    def f(self):\n        return 1");
+        assert_eq!(start, 4);
+        assert!(!stale);
+    }
+
+    #[test]
+    fn test_slice_lines_clamps_out_of_range() {
+        let (_, body, start, stale) = slice_lines(SOURCE, 4, Some(40), 0);
+        assert_eq!(body, "    def f(self):\n        return 1");
+        assert_eq!(start, 4);
+        assert!(stale);
+
+        let (_, body, start, stale) = slice_lines(SOURCE, 90, Some(95), 0);
+        assert_eq!(body, "        return 1");
+        assert_eq!(start, 5);
+        assert!(stale);
+    }
+}
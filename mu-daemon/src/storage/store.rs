@@ -0,0 +1,168 @@
+//! Backend-neutral graph storage interface.
+//!
+//! `GraphStore` covers the graph side of `MUbase`: node and edge CRUD, raw
+//! queries, statistics, metadata and file hashes. Embedding storage and
+//! vector search stay on `MUbase`, since they rely on DuckDB's array
+//! functions. Code that only reads or writes the graph can take a
+//! `&dyn GraphStore` and work with any backend.
+
+use anyhow::Result;
+use std::collections::HashMap;
+
+use super::edges::Edge;
+use super::graph_engine::GraphEngine;
+use super::mubase::{GraphStats, MUbase, QueryResult};
+use super::nodes::Node;
+use super::schema::NodeType;
+
+/// Storage for the code graph.
+pub trait GraphStore {
+    /// Insert or replace a single node.
+    fn insert_node(&self, node: &Node) -> Result<()>;
+
+    /// Insert or replace nodes in one transaction.
+    fn insert_nodes(&self, nodes: &[Node]) -> Result<()>;
+
+    /// Insert or replace a single edge.
+    fn insert_edge(&self, edge: &Edge) -> Result<()>;
+
+    /// Insert or replace edges in one transaction.
+    fn insert_edges(&self, edges: &[Edge]) -> Result<()>;
+
+    /// Get a node by ID.
+    fn get_node(&self, id: &str) -> Result<Option<Node>>;
+
+    /// Get every node in the graph.
+    fn all_nodes(&self) -> Result<Vec<Node>>;
+
+    /// Get all nodes of a type.
+    fn get_nodes_by_type(&self, node_type: NodeType) -> Result<Vec<Node>>;
+
+    /// Get the nodes of a type defined in a file.
+    fn get_nodes_in_file(&self, file_path: &str, node_type: NodeType) -> Result<Vec<Node>>;
+
+    /// Delete a file's nodes and the edges touching them, returning how
+    /// many nodes were removed.
+    fn delete_nodes_for_file(&self, file_path: &str) -> Result<usize>;
+
+    /// Paths of all files with nodes in the graph.
+    fn indexed_files(&self) -> Result<Vec<String>>;
+
+    /// Execute a raw SQL query.
+    fn query(&self, sql: &str) -> Result<QueryResult>;
+
+    /// Node and edge counts.
+    fn stats(&self) -> Result<GraphStats>;
+
+    /// Load the graph into memory for traversal.
+    fn load_graph(&self) -> Result<GraphEngine>;
+
+    /// Get a metadata value.
+    fn get_metadata(&self, key: &str) -> Result<Option<String>>;
+
+    /// Set a metadata value.
+    fn set_metadata(&self, key: &str, value: &str) -> Result<()>;
+
+    /// Content hashes of all indexed files, keyed by path.
+    fn get_all_file_hashes(&self) -> Result<HashMap<String, String>>;
+
+    /// Record content hashes for files.
+    fn set_file_hashes_batch(&self, updates: &[(String, String)]) -> Result<()>;
+}
+
+impl GraphStore for MUbase {
+    fn insert_node(&self, node: &Node) -> Result<()> {
+        MUbase::insert_node(self, node)
+    }
+
+    fn insert_nodes(&self, nodes: &[Node]) -> Result<()> {
+        MUbase::insert_nodes(self, nodes)
+    }
+
+    fn insert_edge(&self, edge: &Edge) -> Result<()> {
+        MUbase::insert_edge(self, edge)
+    }
+
+    fn insert_edges(&self, edges: &[Edge]) -> Result<()> {
+        MUbase::insert_edges(self, edges)
+    }
+
+    fn get_node(&self, id: &str) -> Result<Option<Node>> {
+        MUbase::get_node(self, id)
+    }
+
+    fn all_nodes(&self) -> Result<Vec<Node>> {
+        MUbase::all_nodes(self)
+    }
+
+    fn get_nodes_by_type(&self, node_type: NodeType) -> Result<Vec<Node>> {
+        MUbase::get_nodes_by_type(self, node_type)
+    }
+
+    fn get_nodes_in_file(&self, file_path: &str, node_type: NodeType) -> Result<Vec<Node>> {
+        MUbase::get_nodes_in_file(self, file_path, node_type)
+    }
+
+    fn delete_nodes_for_file(&self, file_path: &str) -> Result<usize> {
+        MUbase::delete_nodes_for_file(self, file_path)
+    }
+
+    fn indexed_files(&self) -> Result<Vec<String>> {
+        MUbase::indexed_files(self)
+    }
+
+    fn query(&self, sql: &str) -> Result<QueryResult> {
+        MUbase::query(self, sql)
+    }
+
+    fn stats(&self) -> Result<GraphStats> {
+        MUbase::stats(self)
+    }
+
+    fn load_graph(&self) -> Result<GraphEngine> {
+        MUbase::load_graph(self)
+    }
+
+    fn get_metadata(&self, key: &str) -> Result<Option<String>> {
+        MUbase::get_metadata(self, key)
+    }
+
+    fn set_metadata(&self, key: &str, value: &str) -> Result<()> {
+        MUbase::set_metadata(self, key, value)
+    }
+
+    fn get_all_file_hashes(&self) -> Result<HashMap<String, String>> {
+        MUbase::get_all_file_hashes(self)
+    }
+
+    fn set_file_hashes_batch(&self, updates: &[(String, String)]) -> Result<()> {
+        MUbase::set_file_hashes_batch(self, updates)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tempfile::tempdir;
+
+    #[test]
+    fn test_mubase_through_trait_object() {
+        let dir = tempdir().unwrap();
+        let db = MUbase::open(dir.path().join("test.mubase")).unwrap();
+        let store: &dyn GraphStore = &db;
+
+        let module = Node::module("src/app.py");
+        store.insert_nodes(std::slice::from_ref(&module)).unwrap();
+        store.set_metadata("backend", "duckdb").unwrap();
+
+        assert_eq!(store.get_node(&module.id).unwrap().unwrap().id, module.id);
+        assert_eq!(store.indexed_files().unwrap(), vec!["src/app.py"]);
+        assert_eq!(store.stats().unwrap().node_count, 1);
+        assert_eq!(
+            store.get_metadata("backend").unwrap().as_deref(),
+            Some("duckdb")
+        );
+        assert_eq!(store.delete_nodes_for_file("src/app.py").unwrap(), 1);
+        assert!(store.all_nodes().unwrap().is_empty());
+    }
+}
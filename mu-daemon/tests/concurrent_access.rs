@@ -39,7 +39,7 @@ fn test_concurrent_readers() {
 
                 // Open database in read-only mode and perform read query
                 let db = MUbase::open_read_only(&*path)
-                    .expect(&format!("Reader {} failed to open DB", i));
+                    .unwrap_or_else(|_| panic!("Reader {} failed to open DB", i));
                 let result = db.query("SELECT COUNT(*) as cnt FROM nodes");
 
                 match result {
@@ -59,7 +59,7 @@ fn test_concurrent_readers() {
     for (i, handle) in handles.into_iter().enumerate() {
         let result = handle
             .join()
-            .expect(&format!("Reader thread {} panicked", i));
+            .unwrap_or_else(|_| panic!("Reader thread {} panicked", i));
         assert!(result, "Reader {} should succeed", i);
     }
 }
@@ -118,7 +118,7 @@ fn test_daemon_write_cli_read_concurrent() {
 
                 // Open in read-only mode (CLI mode)
                 let db = MUbase::open_read_only(&*path)
-                    .expect(&format!("Reader {} (CLI) failed to open DB", i));
+                    .unwrap_or_else(|_| panic!("Reader {} (CLI) failed to open DB", i));
 
                 // Perform multiple reads while writer is active
                 for j in 0..5 {
@@ -148,7 +148,7 @@ fn test_daemon_write_cli_read_concurrent() {
     for (i, handle) in reader_handles.into_iter().enumerate() {
         let result = handle
             .join()
-            .expect(&format!("Reader thread {} panicked", i));
+            .unwrap_or_else(|_| panic!("Reader thread {} panicked", i));
         assert!(result, "Reader {} should succeed", i);
     }
 }
@@ -407,3 +407,31 @@ fn test_read_only_prevents_writes() {
         assert!(result.is_err(), "Insert should fail in read-only mode");
     }
 }
+
+/// Test that a read-only snapshot serves queries but rejects a rebuild.
+#[test]
+fn test_read_only_snapshot_rejects_rebuild() {
+    let dir = tempdir().expect("Failed to create temp dir");
+    let db_path = dir.path().join("test.mubase");
+
+    {
+        let db = MUbase::open(&db_path).expect("Failed to create database");
+        db.insert_node(&Node::module("src/initial.rs"))
+            .expect("Failed to insert node");
+    }
+
+    let db = MUbase::open_read_only(&db_path).expect("Failed to open read-only");
+
+    let result = db
+        .query("SELECT id FROM nodes")
+        .expect("Query should succeed in read-only mode");
+    assert_eq!(result.row_count(), 1);
+
+    let rebuild = db.replace_graph(&[Node::module("src/new.rs")], &[], || Ok(()));
+    assert!(rebuild.is_err(), "Rebuild should fail in read-only mode");
+
+    let stats = db
+        .stats()
+        .expect("Stats should succeed after failed rebuild");
+    assert_eq!(stats.node_count, 1);
+}
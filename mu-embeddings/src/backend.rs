@@ -0,0 +1,102 @@
+//! Model registry: one interface over the embedding architectures we can run.
+//!
+//! [`load`] reads a model directory's `config.json` and picks the backend
+//! for the architecture it names, so callers can compare models without
+//! knowing which implementation runs them.
+
+use crate::error::{EmbeddingError, Result};
+use crate::model::{ModelConfig, MuSigmaModel};
+use std::path::Path;
+
+/// An embedding model that turns texts into fixed-size vectors.
+pub trait EmbeddingBackend: Send + Sync {
+    /// Embed texts, returning one vector per text in input order.
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>>;
+
+    /// Length of the vectors [`embed`](Self::embed) returns.
+    fn dimension(&self) -> usize;
+}
+
+impl EmbeddingBackend for MuSigmaModel {
+    fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        MuSigmaModel::embed(self, texts)
+    }
+
+    fn dimension(&self) -> usize {
+        self.embedding_dim()
+    }
+}
+
+/// `config.json` architectures run by [`MuSigmaModel`].
+pub const BERT_ARCHITECTURES: &[&str] = &["BertModel"];
+
+/// Load the model in `model_dir` with the backend for its architecture.
+///
+/// # Errors
+///
+/// Returns [`EmbeddingError::ConfigError`] if `config.json` names an
+/// architecture no backend supports, or the backend's own load errors.
+pub fn load<P: AsRef<Path>>(model_dir: P) -> Result<Box<dyn EmbeddingBackend>> {
+    let model_dir = model_dir.as_ref();
+    if !model_dir.exists() {
+        return Err(EmbeddingError::ModelNotFound {
+            path: model_dir.display().to_string(),
+        });
+    }
+
+    let config_path = model_dir.join("config.json");
+    let config = ModelConfig::from_file(&config_path).map_err(|e| EmbeddingError::ConfigError {
+        message: format!(
+            "Failed to load config from {}: {}",
+            config_path.display(),
+            e
+        ),
+    })?;
+
+    check_bert(&config)?;
+    Ok(Box::new(MuSigmaModel::load(model_dir)?))
+}
+
+/// Reject configs whose architecture is not one the BERT backend runs.
+pub(crate) fn check_bert(config: &ModelConfig) -> Result<()> {
+    let architecture = config.architecture();
+    if BERT_ARCHITECTURES.contains(&architecture) {
+        Ok(())
+    } else {
+        Err(EmbeddingError::ConfigError {
+            message: format!(
+                "Unsupported model architecture '{}'. Supported: {}",
+                architecture,
+                BERT_ARCHITECTURES.join(", ")
+            ),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_architecture_dispatch() {
+        let bert: ModelConfig = serde_json::from_slice(crate::embedded::CONFIG_BYTES).unwrap();
+        assert_eq!(bert.architecture(), "BertModel");
+        assert!(check_bert(&bert).is_ok());
+
+        // Configs without the field are treated as BERT
+        assert!(check_bert(&ModelConfig::default()).is_ok());
+
+        let t5 = ModelConfig {
+            architectures: vec!["T5EncoderModel".to_string()],
+            ..ModelConfig::default()
+        };
+        let err = check_bert(&t5).unwrap_err().to_string();
+        assert!(err.contains("T5EncoderModel"), "{}", err);
+    }
+
+    #[test]
+    fn test_load_missing_dir() {
+        let result = load("/nonexistent/mu-model");
+        assert!(matches!(result, Err(EmbeddingError::ModelNotFound { .. })));
+    }
+}
@@ -25,20 +25,35 @@
 //! let texts = vec!["def hello(): pass", "function greet() {}"];
 //! let embeddings = model.embed(&texts)?;
 //!
-//! // Each embedding is a Vec<f32> with dimension 384 (for mu-sigma-v2)
+//! // Each embedding is a unit-length Vec<f32> with dimension 384 (for mu-sigma-v2)
 //! assert_eq!(embeddings[0].len(), 384);
+//!
+//! // Pooled vectors before normalization, for custom distance metrics
+//! let raw = model.embed_raw(&texts)?;
+//! # Ok::<(), mu_embeddings::EmbeddingError>(())
+//! ```
+//!
+//! To compare models, [`load`] picks the backend from the architecture in a
+//! model directory's `config.json` and returns a [`EmbeddingBackend`]:
+//!
+//! ```rust,no_run
+//! let model = mu_embeddings::load("path/to/other-model")?;
+//! let embeddings = model.embed(&["def hello(): pass"])?;
+//! assert_eq!(embeddings[0].len(), model.dimension());
 //! # Ok::<(), mu_embeddings::EmbeddingError>(())
 //! ```
 
 #![warn(missing_docs)]
 #![warn(clippy::all)]
 
+mod backend;
 mod error;
 mod model;
 mod tokenizer;
 
+pub use backend::{load, EmbeddingBackend, BERT_ARCHITECTURES};
 pub use error::{EmbeddingError, Result};
-pub use model::{ModelConfig, MuSigmaModel};
+pub use model::{ModelConfig, MuSigmaModel, Pooling};
 pub use tokenizer::MuTokenizer;
 
 /// Embedded model weights for mu-sigma-v2 (compile-time inclusion).
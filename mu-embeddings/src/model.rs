@@ -10,6 +10,9 @@ use serde::{Deserialize, Serialize};
 use std::path::Path;
 use tracing::{debug, info};
 
+/// Tokens each window shares with the previous one when chunking long texts.
+const CHUNK_OVERLAP_TOKENS: usize = 32;
+
 /// Configuration for the mu-sigma-v2 model.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelConfig {
@@ -40,6 +43,32 @@ pub struct ModelConfig {
     /// Layer norm epsilon.
     #[serde(default = "default_layer_norm_eps")]
     pub layer_norm_eps: f64,
+    /// Maximum texts per forward pass in [`MuSigmaModel::embed`].
+    ///
+    /// Larger batches are faster on big machines; smaller ones use less memory.
+    #[serde(default = "default_batch_size")]
+    pub batch_size: usize,
+    /// How token embeddings are pooled into one vector per text.
+    #[serde(default)]
+    pub pooling: Pooling,
+    /// Whether [`MuSigmaModel::embed`] scales vectors to unit L2 length.
+    ///
+    /// Keep this on for cosine search; [`MuSigmaModel::embed_raw`] skips it
+    /// regardless.
+    #[serde(default = "default_normalize")]
+    pub normalize: bool,
+    /// Model classes named in `config.json`, e.g. `BertModel`.
+    ///
+    /// Selects the backend in [`crate::load`]; empty means BERT.
+    #[serde(default)]
+    pub architectures: Vec<String>,
+    /// Whether [`MuSigmaModel::embed`] splits texts longer than the model's
+    /// sequence length into overlapping windows and averages their vectors.
+    ///
+    /// When off, long texts are truncated and everything past the limit is
+    /// left out of the embedding.
+    #[serde(default)]
+    pub chunk_overflow: bool,
 }
 
 fn default_hidden_act() -> String {
@@ -58,6 +87,14 @@ fn default_layer_norm_eps() -> f64 {
     1e-12
 }
 
+fn default_batch_size() -> usize {
+    32
+}
+
+fn default_normalize() -> bool {
+    true
+}
+
 impl Default for ModelConfig {
     fn default() -> Self {
         Self {
@@ -72,6 +109,11 @@ impl Default for ModelConfig {
             attention_probs_dropout_prob: default_dropout(),
             type_vocab_size: default_type_vocab_size(),
             layer_norm_eps: default_layer_norm_eps(),
+            batch_size: default_batch_size(),
+            pooling: Pooling::default(),
+            normalize: default_normalize(),
+            architectures: Vec::new(),
+            chunk_overflow: false,
         }
     }
 }
@@ -93,6 +135,14 @@ impl ModelConfig {
         Ok(config)
     }
 
+    /// The model architecture, `BertModel` if the config names none.
+    pub fn architecture(&self) -> &str {
+        self.architectures
+            .first()
+            .map(String::as_str)
+            .unwrap_or("BertModel")
+    }
+
     /// Convert to candle BERT config.
     fn to_bert_config(&self) -> BertConfig {
         BertConfig {
@@ -117,16 +167,19 @@ impl ModelConfig {
     }
 }
 
-/// Pooling strategy for generating sentence embeddings.
-#[derive(Debug, Clone, Copy, Default)]
-pub enum PoolingStrategy {
+/// Pooling strategy for turning token embeddings into one sentence embedding.
+///
+/// Applied to the BERT output, ignoring padding tokens.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Pooling {
     /// Mean pooling over all token embeddings (default).
     #[default]
     Mean,
     /// Use the [CLS] token embedding.
     Cls,
-    /// Max pooling over all token embeddings.
-    Max,
+    /// Element-wise max over all token embeddings.
+    MaxTokens,
 }
 
 /// MU-Sigma-V2 embedding model.
@@ -137,7 +190,6 @@ pub struct MuSigmaModel {
     tokenizer: MuTokenizer,
     config: ModelConfig,
     device: Device,
-    pooling: PoolingStrategy,
 }
 
 impl MuSigmaModel {
@@ -177,6 +229,7 @@ impl MuSigmaModel {
                 ),
             })?;
         debug!("Loaded config: hidden_size={}", config.hidden_size);
+        crate::backend::check_bert(&config)?;
 
         // Load tokenizer
         let tokenizer_path = model_dir.join("tokenizer.json");
@@ -218,7 +271,6 @@ impl MuSigmaModel {
             tokenizer,
             config,
             device,
-            pooling: PoolingStrategy::default(),
         })
     }
 
@@ -241,12 +293,41 @@ impl MuSigmaModel {
     ///
     /// Returns error if model cannot be loaded from embedded data.
     pub fn embedded() -> Result<Self> {
-        use crate::embedded::{CONFIG_BYTES, MODEL_BYTES, TOKENIZER_BYTES};
+        use crate::embedded::CONFIG_BYTES;
 
-        Self::from_embedded(
+        let config_json =
             std::str::from_utf8(CONFIG_BYTES).map_err(|e| EmbeddingError::ConfigError {
                 message: format!("Invalid UTF-8 in embedded config: {}", e),
-            })?,
+            })?;
+        Self::embedded_with_config(serde_json::from_str(config_json)?)
+    }
+
+    /// Create a model with embedded weights and a custom configuration.
+    ///
+    /// The architecture fields must match mu-sigma-v2, which
+    /// [`ModelConfig::default`] does, so override only what you need.
+    ///
+    /// # Example
+    ///
+    /// ```rust,no_run
+    /// use mu_embeddings::{ModelConfig, MuSigmaModel, Pooling};
+    ///
+    /// let model = MuSigmaModel::embedded_with_config(ModelConfig {
+    ///     batch_size: 128,
+    ///     pooling: Pooling::Cls,
+    ///     ..ModelConfig::default()
+    /// })?;
+    /// # Ok::<(), mu_embeddings::EmbeddingError>(())
+    /// ```
+    ///
+    /// # Errors
+    ///
+    /// Returns error if model cannot be loaded from embedded data.
+    pub fn embedded_with_config(config: ModelConfig) -> Result<Self> {
+        use crate::embedded::{MODEL_BYTES, TOKENIZER_BYTES};
+
+        Self::from_parts(
+            config,
             std::str::from_utf8(TOKENIZER_BYTES).map_err(|e| EmbeddingError::TokenizerError {
                 message: format!("Invalid UTF-8 in embedded tokenizer: {}", e),
             })?,
@@ -266,10 +347,12 @@ impl MuSigmaModel {
     ///
     /// Returns error if model cannot be created.
     pub fn from_embedded(config_json: &str, tokenizer_json: &str, weights: &[u8]) -> Result<Self> {
-        info!("Loading model from embedded data");
-
-        // Parse config
         let config: ModelConfig = serde_json::from_str(config_json)?;
+        Self::from_parts(config, tokenizer_json, weights)
+    }
+
+    fn from_parts(config: ModelConfig, tokenizer_json: &str, weights: &[u8]) -> Result<Self> {
+        info!("Loading model from embedded data");
         debug!("Loaded config: hidden_size={}", config.hidden_size);
 
         // Load tokenizer
@@ -295,39 +378,115 @@ impl MuSigmaModel {
             tokenizer,
             config,
             device,
-            pooling: PoolingStrategy::default(),
         })
     }
 
     /// Set the pooling strategy for embedding generation.
     ///
+    /// Overrides `config.pooling`.
+    ///
     /// # Arguments
     ///
     /// * `strategy` - Pooling strategy to use
-    pub fn with_pooling(mut self, strategy: PoolingStrategy) -> Self {
-        self.pooling = strategy;
+    pub fn with_pooling(mut self, strategy: Pooling) -> Self {
+        self.config.pooling = strategy;
+        self
+    }
+
+    /// Set the maximum number of tokens per forward pass (default: 512).
+    ///
+    /// Longer texts are truncated, or windowed if `config.chunk_overflow`
+    /// is set.
+    pub fn with_max_length(mut self, max_length: usize) -> Self {
+        self.tokenizer = self.tokenizer.with_max_length(max_length);
         self
     }
 
     /// Generate embeddings for a slice of texts.
     ///
+    /// Texts are run through the model `config.batch_size` at a time, so
+    /// callers can pass any number of them.
+    ///
     /// # Arguments
     ///
     /// * `texts` - Slice of text strings to embed
     ///
     /// # Returns
     ///
-    /// Vector of embeddings, one per input text. Each embedding has dimension
-    /// equal to `hidden_size` (384 for mu-sigma-v2).
+    /// Vector of embeddings, one per input text, in input order. Each
+    /// embedding has dimension equal to `hidden_size` (384 for mu-sigma-v2)
+    /// and unit L2 length unless `config.normalize` is off.
     ///
     /// # Errors
     ///
     /// Returns error if encoding or inference fails.
     pub fn embed(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        let mut embeddings = self.embed_raw(texts)?;
+        if self.config.normalize {
+            for embedding in &mut embeddings {
+                l2_normalize(embedding);
+            }
+        }
+        Ok(embeddings)
+    }
+
+    /// Generate pooled embeddings without L2 normalization.
+    ///
+    /// Same as [`embed`](Self::embed) with `normalize` off, for callers that
+    /// need vector magnitudes, e.g. for a custom distance metric.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if encoding or inference fails.
+    pub fn embed_raw(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
         if texts.is_empty() {
             return Err(EmbeddingError::EmptyInput);
         }
 
+        if self.config.chunk_overflow {
+            return self.embed_chunked(texts);
+        }
+
+        let mut embeddings = Vec::with_capacity(texts.len());
+        for batch in texts.chunks(self.config.batch_size.max(1)) {
+            embeddings.extend(self.embed_batch(batch)?);
+        }
+        Ok(embeddings)
+    }
+
+    /// Embed each text as the mean of its overlapping windows' vectors.
+    fn embed_chunked(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
+        let mut windows = Vec::with_capacity(texts.len());
+        let mut owners = Vec::with_capacity(texts.len());
+        for (i, text) in texts.iter().enumerate() {
+            for window in self.tokenizer.windows(text, CHUNK_OVERLAP_TOKENS) {
+                windows.push(window);
+                owners.push(i);
+            }
+        }
+
+        let mut sums = vec![vec![0.0f32; self.embedding_dim()]; texts.len()];
+        let mut counts = vec![0usize; texts.len()];
+        let mut owner = owners.iter();
+        for batch in windows.chunks(self.config.batch_size.max(1)) {
+            for vector in self.embed_batch(batch)? {
+                let i = *owner.next().expect("one owner per window");
+                for (sum, value) in sums[i].iter_mut().zip(vector) {
+                    *sum += value;
+                }
+                counts[i] += 1;
+            }
+        }
+
+        for (sum, count) in sums.iter_mut().zip(counts) {
+            let count = count.max(1) as f32;
+            sum.iter_mut().for_each(|v| *v /= count);
+        }
+        Ok(sums)
+    }
+
+    /// Run one forward pass over `texts`.
+    fn embed_batch(&self, texts: &[&str]) -> Result<Vec<Vec<f32>>> {
         debug!("Embedding {} texts", texts.len());
 
         // Tokenize all inputs
@@ -461,15 +620,15 @@ impl MuSigmaModel {
     }
 
     fn pool_embeddings(&self, hidden_states: &Tensor, attention_mask: &Tensor) -> Result<Tensor> {
-        match self.pooling {
-            PoolingStrategy::Cls => {
+        match self.config.pooling {
+            Pooling::Cls => {
                 // Take the first token ([CLS]) embedding
                 hidden_states
                     .narrow(1, 0, 1)?
                     .squeeze(1)
                     .map_err(Into::into)
             }
-            PoolingStrategy::Mean => {
+            Pooling::Mean => {
                 // Mean pooling: sum(hidden_states * mask) / sum(mask)
                 let mask = attention_mask.unsqueeze(2)?.to_dtype(DType::F32)?;
                 let masked = hidden_states.broadcast_mul(&mask)?;
@@ -477,7 +636,7 @@ impl MuSigmaModel {
                 let count = mask.sum(1)?;
                 sum.broadcast_div(&count).map_err(Into::into)
             }
-            PoolingStrategy::Max => {
+            Pooling::MaxTokens => {
                 // Max pooling over sequence dimension
                 // Set padding positions to large negative value before max
                 let mask = attention_mask.unsqueeze(2)?.to_dtype(DType::F32)?;
@@ -516,6 +675,16 @@ impl MuSigmaModel {
     }
 }
 
+/// Scale `vector` to unit L2 length; all-zero vectors are left as they are.
+fn l2_normalize(vector: &mut [f32]) {
+    let norm = vector.iter().map(|x| x * x).sum::<f32>().sqrt();
+    if norm > 0.0 {
+        for x in vector.iter_mut() {
+            *x /= norm;
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -526,6 +695,7 @@ mod tests {
         assert_eq!(config.hidden_size, 384);
         assert_eq!(config.num_hidden_layers, 6);
         assert_eq!(config.num_attention_heads, 12);
+        assert_eq!(config.batch_size, 32);
     }
 
     #[test]
@@ -542,11 +712,55 @@ mod tests {
         let config: ModelConfig = serde_json::from_str(json).unwrap();
         assert_eq!(config.hidden_size, 384);
         assert_eq!(config.num_hidden_layers, 6);
+        assert_eq!(config.batch_size, 32);
+        assert!(!config.chunk_overflow);
+    }
+
+    #[test]
+    fn test_chunk_overflow_covers_long_text() {
+        let head = "def load_settings(path):\n    return read(path)\n".repeat(3);
+        let with_tail = format!("{}def send_invoice(customer): charge(customer)\n", head);
+
+        let mut model = MuSigmaModel::embedded().unwrap().with_max_length(24);
+
+        // Truncation drops the tail, so both texts embed the same
+        let truncated = model.embed(&[&head, &with_tail]).unwrap();
+        assert_eq!(truncated[0], truncated[1]);
+        let short = model.embed_one("x = 1").unwrap();
+
+        model.config.chunk_overflow = true;
+        let chunked = model.embed(&[&head, &with_tail, "x = 1"]).unwrap();
+        assert_eq!(chunked.len(), 3);
+        assert_ne!(chunked[0], chunked[1]);
+        let norm: f32 = chunked[1].iter().map(|x| x * x).sum::<f32>().sqrt();
+        assert!((norm - 1.0).abs() < 1e-4);
+        assert_eq!(chunked[2], short);
     }
 
     #[test]
     fn test_pooling_strategy_default() {
-        let strategy = PoolingStrategy::default();
-        assert!(matches!(strategy, PoolingStrategy::Mean));
+        let strategy = Pooling::default();
+        assert!(matches!(strategy, Pooling::Mean));
+        assert_eq!(ModelConfig::default().pooling, Pooling::Mean);
+
+        let config: ModelConfig = serde_json::from_str(
+            r#"{"hidden_size": 384, "num_attention_heads": 12, "num_hidden_layers": 6,
+                "intermediate_size": 1536, "vocab_size": 30522,
+                "max_position_embeddings": 512, "pooling": "max_tokens"}"#,
+        )
+        .unwrap();
+        assert_eq!(config.pooling, Pooling::MaxTokens);
+        assert!(config.normalize);
+    }
+
+    #[test]
+    fn test_l2_normalize() {
+        let mut vector = vec![3.0, 4.0];
+        l2_normalize(&mut vector);
+        assert_eq!(vector, vec![0.6, 0.8]);
+
+        let mut zero = vec![0.0, 0.0];
+        l2_normalize(&mut zero);
+        assert_eq!(zero, vec![0.0, 0.0]);
     }
 }
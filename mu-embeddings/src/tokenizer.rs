@@ -3,11 +3,13 @@
 use crate::error::{EmbeddingError, Result};
 use crate::MAX_SEQUENCE_LENGTH;
 use std::path::Path;
-use tokenizers::Tokenizer;
+use tokenizers::{PostProcessor, Tokenizer};
 
 /// Wrapper around HuggingFace tokenizer for mu-sigma-v2.
 pub struct MuTokenizer {
     tokenizer: Tokenizer,
+    /// Copy of `tokenizer` without truncation or padding, for counting.
+    counter: Tokenizer,
     max_length: usize,
 }
 
@@ -41,11 +43,7 @@ impl MuTokenizer {
         }
 
         let tokenizer = Tokenizer::from_file(path)?;
-
-        Ok(Self {
-            tokenizer,
-            max_length: MAX_SEQUENCE_LENGTH,
-        })
+        Self::new(tokenizer)
     }
 
     /// Load tokenizer from JSON string.
@@ -62,9 +60,35 @@ impl MuTokenizer {
             Tokenizer::from_bytes(json.as_bytes()).map_err(|e| EmbeddingError::TokenizerError {
                 message: format!("Failed to parse tokenizer JSON: {}", e),
             })?;
+        Self::new(tokenizer)
+    }
+
+    /// Load the tokenizer embedded with the mu-sigma-v2 model.
+    ///
+    /// # Errors
+    ///
+    /// Returns error if the embedded tokenizer cannot be parsed.
+    pub fn embedded() -> Result<Self> {
+        let json = std::str::from_utf8(crate::embedded::TOKENIZER_BYTES).map_err(|e| {
+            EmbeddingError::TokenizerError {
+                message: format!("Invalid UTF-8 in embedded tokenizer: {}", e),
+            }
+        })?;
+        Self::from_json(json)
+    }
+
+    fn new(tokenizer: Tokenizer) -> Result<Self> {
+        let mut counter = tokenizer.clone();
+        counter
+            .with_truncation(None)
+            .map_err(|e| EmbeddingError::TokenizerError {
+                message: format!("Failed to disable truncation: {}", e),
+            })?
+            .with_padding(None);
 
         Ok(Self {
             tokenizer,
+            counter,
             max_length: MAX_SEQUENCE_LENGTH,
         })
     }
@@ -165,6 +189,64 @@ impl MuTokenizer {
         Ok(encodings)
     }
 
+    /// Count the tokens in `text` as the model's vocabulary splits it.
+    ///
+    /// Unlike [`encode`](Self::encode), the text is not truncated and no
+    /// special tokens are added, so this is the cost of the text itself.
+    /// Falls back to the 4-characters-per-token heuristic if encoding fails.
+    pub fn count_tokens(&self, text: &str) -> usize {
+        match self.counter.encode(text, false) {
+            Ok(encoding) => encoding.get_ids().len(),
+            Err(_) => text.len() / 4,
+        }
+    }
+
+    /// Most content tokens one encoded text keeps.
+    ///
+    /// The smaller of `max_length` and the tokenizer's own truncation limit,
+    /// less the special tokens [`encode`](Self::encode) adds.
+    pub fn content_length(&self) -> usize {
+        let limit = self
+            .tokenizer
+            .get_truncation()
+            .map_or(self.max_length, |t| t.max_length.min(self.max_length));
+        let special = self
+            .tokenizer
+            .get_post_processor()
+            .map_or(0, |p| p.added_tokens(false));
+        limit.saturating_sub(special).max(1)
+    }
+
+    /// Split `text` into windows of at most [`content_length`](Self::content_length)
+    /// tokens, each sharing `overlap` tokens with the one before.
+    ///
+    /// Text that fits in one window is returned whole. Windows are cut at
+    /// token boundaries, so no token is split between two of them. Overlap
+    /// is capped at half a window so each window moves the text forward.
+    pub fn windows<'a>(&self, text: &'a str, overlap: usize) -> Vec<&'a str> {
+        let limit = self.content_length();
+        let Ok(encoding) = self.counter.encode(text, false) else {
+            return vec![text];
+        };
+        let offsets = encoding.get_offsets();
+        if offsets.len() <= limit {
+            return vec![text];
+        }
+
+        let step = limit - overlap.min(limit / 2);
+        let mut windows = Vec::new();
+        let mut start = 0;
+        loop {
+            let end = (start + limit).min(offsets.len());
+            windows.push(&text[offsets[start].0..offsets[end - 1].1]);
+            if end == offsets.len() {
+                break;
+            }
+            start += step;
+        }
+        windows
+    }
+
     /// Get the vocabulary size.
     pub fn vocab_size(&self) -> usize {
         self.tokenizer.get_vocab_size(true)
@@ -192,4 +274,43 @@ mod tests {
         assert_eq!(input.attention_mask.len(), 6);
         assert_eq!(input.token_type_ids.len(), 6);
     }
+
+    #[test]
+    fn test_count_tokens() {
+        let tokenizer = MuTokenizer::embedded().unwrap();
+        assert_eq!(tokenizer.count_tokens(""), 0);
+
+        let snippet = "def parse_config(path):\n    return load_yaml(path)\n";
+        let count = tokenizer.count_tokens(snippet);
+        assert!(count > 0);
+        assert!(count < snippet.len());
+
+        // Not capped at the model's sequence length
+        let long = snippet.repeat(200);
+        assert!(tokenizer.count_tokens(&long) > MAX_SEQUENCE_LENGTH);
+    }
+
+    #[test]
+    fn test_windows() {
+        let tokenizer = MuTokenizer::embedded().unwrap().with_max_length(16);
+        assert_eq!(tokenizer.content_length(), 14);
+
+        let short = "def f(): pass";
+        assert_eq!(tokenizer.windows(short, 4), vec![short]);
+
+        let long = (0..60)
+            .map(|i| format!("word{}", i))
+            .collect::<Vec<_>>()
+            .join(" ");
+        let windows = tokenizer.windows(&long, 4);
+        assert!(windows.len() > 1);
+        assert!(long.starts_with(windows[0]));
+        assert!(long.ends_with(windows[windows.len() - 1]));
+        for window in &windows {
+            assert!(tokenizer.count_tokens(window) <= 14, "{}", window);
+        }
+        // Consecutive windows overlap
+        let second_start = windows[1].as_ptr() as usize - long.as_ptr() as usize;
+        assert!(second_start < windows[0].len());
+    }
 }